@@ -0,0 +1,15 @@
+-- closure creation, upvalue access and calls
+local function counter()
+    local n = 0
+    return function(k)
+        n = n + k
+        return n
+    end
+end
+local total = 0
+for i = 1, 20000 do
+    local c = counter()
+    c(i)
+    total = total + c(1)
+end
+return total
@@ -0,0 +1,10 @@
+-- string building with .. and table.concat
+local s = ""
+for i = 1, 2000 do
+    s = s .. i .. ","
+end
+local parts = {}
+for i = 1, 20000 do
+    parts[i] = "x" .. i
+end
+return #s + #table.concat(parts, ";")
@@ -0,0 +1,7 @@
+-- the LUA_FOR_LOOP chunk of the reader tests
+local sum = 0
+for i = 1, 100 do
+    if i % 2 == 0 then
+        sum = sum + i
+    end
+end
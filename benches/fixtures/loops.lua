@@ -0,0 +1,14 @@
+-- numeric for, while and repeat loops with integer and float arithmetic
+local s, f = 0, 0.5
+for i = 1, 200000 do
+    s = (s + i * 3 - i // 2) % 1000003
+    f = f * 1.0000001 + 0.25
+end
+local n = 0
+while n < 100000 do
+    n = n + 1
+end
+repeat
+    n = n - 2
+until n <= 0
+return s + n
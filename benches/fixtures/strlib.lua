@@ -0,0 +1,12 @@
+-- string library calls on a long subject; each call used to copy it
+local s = string.rep("abcdefgh", 8192)
+local n = 0
+for i = 1, 4000 do
+    n = n + s:byte(i) + #s:sub(i, i + 3)
+    n = n + s:find("h", i, true)
+    if s:match("^abc", i * 8 + 1) then n = n + 1 end
+end
+for i = 1, 200 do
+    n = n + #string.format("%s", s) + #string.pack("s", s)
+end
+return n
@@ -0,0 +1,17 @@
+-- array inserts and lookups, string keys
+local t = {}
+for i = 1, 50000 do
+    t[#t + 1] = i
+end
+local sum = 0
+for i = 1, #t do
+    sum = sum + t[i]
+end
+local h = {}
+for i = 1, 20000 do
+    h["k" .. i % 500] = i
+end
+for i = 1, 20000 do
+    sum = sum + h["k" .. i % 500]
+end
+return sum
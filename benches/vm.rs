@@ -0,0 +1,233 @@
+/*
+    Timings of the interpreter on the chunks in `fixtures`, compiled by
+    luac 5.3 from the sources next to them. Loading a chunk (`undump`)
+    and running it are timed apart, each as the median of several samples.
+
+        cargo bench --bench vm                          # print the timings
+        cargo bench --bench vm -- --save base.txt       # and save them
+        cargo bench --bench vm -- --compare base.txt    # and compare to a saved run
+        cargo bench --bench vm -- --report base.txt new.txt
+
+    A comparison flags the timings slower than the base by more than the
+    threshold, `--threshold 10` percent by default, and then exits with
+    status 1. `--filter name` only runs the fixtures with `name` in theirs.
+*/
+use rslua::api::consts::LUA_OK;
+use rslua::{undump, Lua, LuaAPI};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+use std::{env, fs, process};
+
+const FIXTURES: &[(&str, &[u8])] = &[
+    ("fib", include_bytes!("fixtures/fib.luac")),
+    ("loops", include_bytes!("fixtures/loops.luac")),
+    ("tables", include_bytes!("fixtures/tables.luac")),
+    ("concat", include_bytes!("fixtures/concat.luac")),
+    ("closures", include_bytes!("fixtures/closures.luac")),
+    ("methods", include_bytes!("fixtures/methods.luac")),
+    ("for_loop", include_bytes!("fixtures/for_loop.luac")),
+    ("strlib", include_bytes!("fixtures/strlib.luac")),
+];
+
+const SAMPLES: usize = 7;
+const MIN_SAMPLE: Duration = Duration::from_millis(20);
+
+// Nanoseconds per iteration by (fixture, phase).
+type Timings = BTreeMap<(String, String), f64>;
+
+struct Options {
+    filter: Option<String>,
+    save: Option<String>,
+    compare: Option<String>,
+    report: Option<(String, String)>,
+    threshold: f64,
+}
+
+fn main() {
+    let opts = parse_args();
+    if let Some((base, new)) = &opts.report {
+        let regressed = compare(&load(base), &load(new), opts.threshold);
+        process::exit(regressed as i32);
+    }
+
+    let mut timings = Timings::new();
+    for (name, chunk) in FIXTURES {
+        if let Some(filter) = &opts.filter {
+            if !name.contains(filter.as_str()) {
+                continue;
+            }
+        }
+        let load = sample(bench_undump(chunk));
+        let run = sample(bench_run(name, chunk));
+        println!("{:<10} undump {:>12}   run {:>12}", name, format_ns(load), format_ns(run));
+        timings.insert((name.to_string(), "undump".to_string()), load);
+        timings.insert((name.to_string(), "run".to_string()), run);
+    }
+
+    if let Some(path) = &opts.save {
+        save(path, &timings);
+    }
+    if let Some(base) = &opts.compare {
+        println!();
+        let regressed = compare(&load(base), &timings, opts.threshold);
+        process::exit(regressed as i32);
+    }
+}
+
+fn parse_args() -> Options {
+    let mut opts = Options {
+        filter: None,
+        save: None,
+        compare: None,
+        report: None,
+        threshold: 10.0,
+    };
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| usage(&format!("{} needs a value", arg)));
+        match arg.as_str() {
+            "--filter" => opts.filter = Some(value()),
+            "--save" => opts.save = Some(value()),
+            "--compare" => opts.compare = Some(value()),
+            "--report" => {
+                let base = value();
+                opts.report = Some((base, value()));
+            }
+            "--threshold" => {
+                let v = value();
+                opts.threshold = v.parse().unwrap_or_else(|_| usage(&format!("bad threshold '{}'", v)));
+            }
+            "--bench" => {} // passed by `cargo bench`
+            _ => usage(&format!("unknown option '{}'", arg)),
+        }
+    }
+    opts
+}
+
+fn usage(msg: &str) -> ! {
+    eprintln!("vm bench: {}", msg);
+    eprintln!("usage: vm [--filter name] [--save file] [--compare base] [--threshold percent]");
+    eprintln!("       vm --report base new [--threshold percent]");
+    process::exit(2)
+}
+
+/* measuring */
+
+// Median time of one iteration over the samples. `f` runs a number of
+// iterations and returns the time they took, which is grown until a
+// sample lasts long enough to be measured.
+fn sample<F: FnMut(usize) -> Duration>(mut f: F) -> f64 {
+    let mut iters = 1;
+    while f(iters) < MIN_SAMPLE && iters < 1 << 20 {
+        iters *= 2;
+    }
+    let mut times: Vec<f64> = (0..SAMPLES).map(|_| f(iters).as_nanos() as f64 / iters as f64).collect();
+    times.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    times[SAMPLES / 2]
+}
+
+fn bench_undump(chunk: &[u8]) -> impl FnMut(usize) -> Duration + '_ {
+    move |iters| {
+        let start = Instant::now();
+        for _ in 0..iters {
+            undump(chunk.to_vec());
+        }
+        start.elapsed()
+    }
+}
+
+// Only the calls are timed, each in a fresh state with the chunk loaded.
+fn bench_run<'a>(name: &'a str, chunk: &'a [u8]) -> impl FnMut(usize) -> Duration + 'a {
+    move |iters| {
+        let mut total = Duration::default();
+        for _ in 0..iters {
+            let lua = Lua::new();
+            let mut ls = lua.state();
+            ls.load(chunk.to_vec(), name, "b", None);
+            let start = Instant::now();
+            let status = ls.pcall(0, 0, 0);
+            total += start.elapsed();
+            if status != LUA_OK {
+                panic!("{}: {}", name, ls.to_string(-1));
+            }
+        }
+        total
+    }
+}
+
+fn format_ns(ns: f64) -> String {
+    if ns >= 1e9 {
+        format!("{:.2} s", ns / 1e9)
+    } else if ns >= 1e6 {
+        format!("{:.2} ms", ns / 1e6)
+    } else if ns >= 1e3 {
+        format!("{:.2} us", ns / 1e3)
+    } else {
+        format!("{:.0} ns", ns)
+    }
+}
+
+/* saved runs */
+
+// One "fixture phase nanoseconds" line per timing.
+fn save(path: &str, timings: &Timings) {
+    let text: String = timings
+        .iter()
+        .map(|((name, phase), ns)| format!("{} {} {:.0}\n", name, phase, ns))
+        .collect();
+    fs::write(path, text).unwrap_or_else(|e| usage(&format!("cannot write '{}': {}", path, e)));
+}
+
+fn load(path: &str) -> Timings {
+    let text = fs::read_to_string(path).unwrap_or_else(|e| usage(&format!("cannot read '{}': {}", path, e)));
+    let mut timings = Timings::new();
+    for (n, line) in text.lines().enumerate() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        match fields.as_slice() {
+            [] => {}
+            [name, phase, ns] => match ns.parse() {
+                Ok(ns) => {
+                    timings.insert((name.to_string(), phase.to_string()), ns);
+                }
+                Err(_) => usage(&format!("{}:{}: bad time '{}'", path, n + 1, ns)),
+            },
+            _ => usage(&format!("{}:{}: expected 'fixture phase nanoseconds'", path, n + 1)),
+        }
+    }
+    timings
+}
+
+// Print the change of each timing found in both runs, and return
+// whether any is a regression.
+fn compare(base: &Timings, new: &Timings, threshold: f64) -> bool {
+    let mut regressed = false;
+    println!("{:<10} {:<6} {:>12} {:>12} {:>8}", "fixture", "phase", "base", "new", "change");
+    for ((name, phase), &after) in new {
+        let before = match base.get(&(name.clone(), phase.clone())) {
+            Some(&before) => before,
+            None => continue,
+        };
+        let change = (after - before) / before * 100.0;
+        let flag = if change > threshold {
+            regressed = true;
+            "  REGRESSION"
+        } else if change < -threshold {
+            "  improved"
+        } else {
+            ""
+        };
+        println!(
+            "{:<10} {:<6} {:>12} {:>12} {:>+7.1}%{}",
+            name,
+            phase,
+            format_ns(before),
+            format_ns(after),
+            change,
+            flag
+        );
+    }
+    if regressed {
+        println!("\nregressions over {}%", threshold);
+    }
+    regressed
+}
@@ -1,6 +1,9 @@
 pub type LuaType = i8;
 pub type ArithOp = u8;
 pub type CompareOp = u8;
+pub const LUA_VERSION: &str = "Lua 5.3";
+
+pub type LuaStatus = u8;
 
 /* basic types */
 pub const LUA_TNONE: i8 = -1;
@@ -14,6 +17,20 @@ pub const LUA_TFUNCTION: i8 = 6;
 pub const LUA_TUSERDATA: i8 = 7;
 pub const LUA_TTHREAD: i8 = 8;
 
+/* thread status */
+pub const LUA_OK: u8 = 0;
+pub const LUA_YIELD: u8 = 1;
+pub const LUA_ERRRUN: u8 = 2;
+pub const LUA_ERRSYNTAX: u8 = 3;
+pub const LUA_ERRMEM: u8 = 4;
+pub const LUA_ERRGCMM: u8 = 5;
+pub const LUA_ERRERR: u8 = 6;
+pub const LUA_ERRFILE: u8 = LUA_ERRERR + 1;
+pub const LUA_ERRLIMIT: u8 = LUA_ERRFILE + 1; // a `LuaLimits` bound was exceeded
+
+/* option for multiple returns in 'call' */
+pub const LUA_MULTRET: isize = -1;
+
 /* arithmetic functions */
 pub const LUA_OPADD: u8 = 0; // +
 pub const LUA_OPSUB: u8 = 1; // -
@@ -44,5 +61,29 @@ pub const LUA_OPLE: u8 = 2; // <=
  */
 pub const LUA_MINSTACK: usize = 20;
 pub const LUA_MAXSTACK: usize = 1000000;
+pub const LUAI_MAXCCALLS: usize = 200; // nested calls of Rust functions
+pub const LUAI_MAXRSTACK: usize = 1 << 20; // bytes of the Rust stack nested calls may use
 pub const LUA_REGISTRY_INDEX: isize = -(LUA_MAXSTACK as isize) - 1000;
 pub const LUA_RIDX_GLOBALS: isize = 2;
+
+// Pseudo-index of the i-th upvalue of the running Rust closure (from 1).
+pub const fn upvalue_index(i: isize) -> isize {
+    LUA_REGISTRY_INDEX - i
+}
+
+/* predefined references */
+pub const LUA_NOREF: i64 = -2;
+pub const LUA_REFNIL: i64 = -1;
+
+/* event codes */
+pub const LUA_HOOKCALL: u8 = 0;
+pub const LUA_HOOKRET: u8 = 1;
+pub const LUA_HOOKLINE: u8 = 2;
+pub const LUA_HOOKCOUNT: u8 = 3;
+pub const LUA_HOOKTAILCALL: u8 = 4;
+
+/* event masks */
+pub const LUA_MASKCALL: u8 = 1 << LUA_HOOKCALL;
+pub const LUA_MASKRET: u8 = 1 << LUA_HOOKRET;
+pub const LUA_MASKLINE: u8 = 1 << LUA_HOOKLINE;
+pub const LUA_MASKCOUNT: u8 = 1 << LUA_HOOKCOUNT;
@@ -0,0 +1,553 @@
+use super::consts::*;
+use super::lua_state::{LuaDebug, LuaState, RustFn};
+use crate::binary::chunk::LUA_SIGNATURE;
+use std::fs::File;
+use std::io::{self, Read};
+use std::rc::Rc;
+
+const FREELIST: i64 = 0; // index of the free list in a reference table
+
+const LEVELS1: isize = 10; // size of the first part of the stack
+const LEVELS2: isize = 11; // size of the second part of the stack
+
+/*
+    Helpers for writing native functions, the equivalent of lauxlib.
+    Every `LuaAPI` gets them for free. Functions named after an API
+    function they extend (`len_l`, `error_l`, ...) carry an `_l` suffix.
+*/
+pub trait LuaAuxLib: LuaState {
+    /* error-report functions */
+
+    // Position of the function at `level` as "chunkname:currentline: ".
+    fn where_l(&mut self, level: isize) -> String {
+        if let Some(mut ar) = self.get_stack(level) {
+            self.get_info("Sl", &mut ar);
+            if ar.current_line > 0 {
+                return format!("{}:{}: ", ar.short_src, ar.current_line);
+            }
+        }
+        String::new()
+    }
+
+    // Raise `msg` prefixed with the position of the calling Lua code.
+    fn error_l(&mut self, msg: &str) -> ! {
+        let msg = format!("{}{}", self.where_l(1), msg);
+        self.push_string(msg);
+        self.error()
+    }
+
+    // Raise "bad argument #arg to 'f' (extra_msg)" for the running function.
+    fn arg_error(&mut self, mut arg: isize, extra_msg: &str) -> ! {
+        let mut ar = match self.get_stack(0) {
+            Some(ar) => ar,
+            None => self.error_l(&format!("bad argument #{} ({})", arg, extra_msg)),
+        };
+        self.get_info("n", &mut ar);
+        if ar.namewhat == "method" {
+            arg -= 1; // do not count 'self'
+            if arg == 0 {
+                let name = ar.name.unwrap_or_default();
+                self.error_l(&format!("calling '{}' on bad self ({})", name, extra_msg));
+            }
+        }
+        let name = match ar.name.take() {
+            Some(name) => name,
+            None => self.global_func_name(&mut ar).unwrap_or_else(|| "?".to_string()),
+        };
+        self.error_l(&format!("bad argument #{} to '{}' ({})", arg, name, extra_msg))
+    }
+
+    fn type_error(&mut self, arg: isize, tname: &str) -> ! {
+        let typearg = if self.get_metafield(arg, "__name") == LUA_TSTRING {
+            self.to_string(-1)
+        } else if self.type_id(arg) == LUA_TLIGHTUSERDATA {
+            "light userdata".to_string()
+        } else {
+            self.type_name(self.type_id(arg)).to_string()
+        };
+        self.arg_error(arg, &format!("{} expected, got {}", tname, typearg))
+    }
+
+    // Name under which the function of `ar` is reachable from a loaded
+    // module, e.g. "string.format" (or "print" for a global).
+    fn global_func_name(&mut self, ar: &mut LuaDebug) -> Option<String> {
+        let top = self.get_top();
+        self.get_info("f", ar); // push function
+        self.get_field(LUA_REGISTRY_INDEX, "_LOADED");
+        let name = self.find_field(top + 1, 2);
+        self.set_top(top);
+        name.map(|name| match name.strip_prefix("_G.") {
+            Some(global) => global.to_string(),
+            None => name,
+        })
+    }
+
+    /* traceback */
+
+    // Name of the function of `ar` as shown in tracebacks.
+    fn func_name_l(&mut self, ar: &mut LuaDebug) -> String {
+        if let Some(name) = self.global_func_name(ar) {
+            // try first a global name
+            format!("function '{}'", name)
+        } else if !ar.namewhat.is_empty() {
+            // is there a name from code?
+            format!("{} '{}'", ar.namewhat, ar.name.as_deref().unwrap_or("?"))
+        } else if ar.what == "main" {
+            "main chunk".to_string()
+        } else if ar.what != "C" {
+            // for Lua functions, use <file:line>
+            format!("function <{}:{}>", ar.short_src, ar.line_defined)
+        } else {
+            // nothing left...
+            "?".to_string()
+        }
+    }
+
+    // Push a traceback of the stack from `level` on, after `msg` if given.
+    fn traceback(&mut self, msg: Option<&str>, mut level: isize) {
+        // search for the last existing level
+        let mut last = level.max(0);
+        while self.get_stack(last + 1).is_some() {
+            last += 1;
+        }
+        let mut n1 = if last - level > LEVELS1 + LEVELS2 { LEVELS1 } else { -1 };
+        let mut tb = String::new();
+        if let Some(msg) = msg {
+            tb.push_str(msg);
+            tb.push('\n');
+        }
+        tb.push_str("stack traceback:");
+        while let Some(mut ar) = self.get_stack(level) {
+            level += 1;
+            if n1 == 0 {
+                // too many levels?
+                tb.push_str("\n\t..."); // add a '...'
+                level = last - LEVELS2 + 1; // and skip to last ones
+            } else {
+                self.get_info("Slnt", &mut ar);
+                tb.push_str(&format!("\n\t{}:", ar.short_src));
+                if ar.current_line > 0 {
+                    tb.push_str(&format!("{}:", ar.current_line));
+                }
+                tb.push_str(" in ");
+                tb.push_str(&self.func_name_l(&mut ar));
+                if ar.is_tail_call {
+                    tb.push_str("\n\t(...tail calls...)");
+                }
+            }
+            n1 -= 1;
+        }
+        self.push_string(tb);
+    }
+
+    // Search the table on top, `level` tables deep, for the object at `objidx`.
+    fn find_field(&mut self, objidx: isize, level: usize) -> Option<String> {
+        if level == 0 || !self.is_table(-1) {
+            return None;
+        }
+        self.push_nil(); // start 'next' loop
+        while self.next(-2) {
+            if self.type_id(-2) == LUA_TSTRING {
+                if self.raw_equal(objidx, -1) {
+                    let key = self.to_string(-2);
+                    self.pop(2);
+                    return Some(key);
+                } else if let Some(name) = self.find_field(objidx, level - 1) {
+                    let key = self.to_string(-2);
+                    self.pop(2);
+                    return Some(format!("{}.{}", key, name));
+                }
+            }
+            self.pop(1); // remove value
+        }
+        None
+    }
+
+    /* argument check functions */
+
+    fn arg_check(&mut self, cond: bool, arg: isize, extra_msg: &str) {
+        if !cond {
+            self.arg_error(arg, extra_msg);
+        }
+    }
+
+    fn check_any(&mut self, arg: isize) {
+        if self.type_id(arg) == LUA_TNONE {
+            self.arg_error(arg, "value expected");
+        }
+    }
+
+    fn check_type(&mut self, arg: isize, t: LuaType) {
+        if self.type_id(arg) != t {
+            let tname = self.type_name(t).to_string();
+            self.type_error(arg, &tname);
+        }
+    }
+
+    fn check_integer(&mut self, arg: isize) -> i64 {
+        match self.to_integerx(arg) {
+            Some(i) => i,
+            None if self.is_number(arg) => self.arg_error(arg, "number has no integer representation"),
+            None => self.type_error(arg, "number"),
+        }
+    }
+
+    fn check_number(&mut self, arg: isize) -> f64 {
+        match self.to_numberx(arg) {
+            Some(n) => n,
+            None => self.type_error(arg, "number"),
+        }
+    }
+
+    fn check_string(&mut self, arg: isize) -> String {
+        match self.to_stringx(arg) {
+            Some(s) => s,
+            None => self.type_error(arg, "string"),
+        }
+    }
+
+    // Like `check_string`, but keeps the raw bytes of the string.
+    fn check_bytes(&mut self, arg: isize) -> Vec<u8> {
+        match self.to_bytes(arg) {
+            Some(s) => s,
+            None => self.type_error(arg, "string"),
+        }
+    }
+
+    // Like `check_bytes`, without copying the string.
+    fn check_shared_bytes(&mut self, arg: isize) -> Rc<Vec<u8>> {
+        match self.to_shared_bytes(arg) {
+            Some(s) => s,
+            None => self.type_error(arg, "string"),
+        }
+    }
+
+    fn opt_integer(&mut self, arg: isize, def: i64) -> i64 {
+        if self.is_none_or_nil(arg) {
+            def
+        } else {
+            self.check_integer(arg)
+        }
+    }
+
+    fn opt_number(&mut self, arg: isize, def: f64) -> f64 {
+        if self.is_none_or_nil(arg) {
+            def
+        } else {
+            self.check_number(arg)
+        }
+    }
+
+    fn opt_string(&mut self, arg: isize, def: &str) -> String {
+        if self.is_none_or_nil(arg) {
+            def.to_string()
+        } else {
+            self.check_string(arg)
+        }
+    }
+
+    // Index in `lst` of the string argument (or of `def` if it is absent).
+    fn check_option(&mut self, arg: isize, def: Option<&str>, lst: &[&str]) -> usize {
+        let name = match def {
+            Some(def) => self.opt_string(arg, def),
+            None => self.check_string(arg),
+        };
+        match lst.iter().position(|s| *s == name) {
+            Some(i) => i,
+            None => self.arg_error(arg, &format!("invalid option '{}'", name)),
+        }
+    }
+
+    /* metatables and userdata */
+
+    // Create the metatable `registry[tname]` (with `__name` set) and push it,
+    // returns false and pushes the existing one if it is already there.
+    fn new_metatable(&mut self, tname: &str) -> bool {
+        if self.get_metatable_l(tname) != LUA_TNIL {
+            return false; // leave previous value on top
+        }
+        self.pop(1);
+        self.create_table(0, 2);
+        self.push_string(tname.to_string());
+        self.set_field(-2, "__name"); // metatable.__name = tname
+        self.push_value(-1);
+        self.set_field(LUA_REGISTRY_INDEX, tname); // registry.name = metatable
+        true
+    }
+
+    fn get_metatable_l(&mut self, tname: &str) -> LuaType {
+        self.get_field(LUA_REGISTRY_INDEX, tname)
+    }
+
+    // Set `registry[tname]` as the metatable of the value on top.
+    fn set_metatable_l(&mut self, tname: &str) {
+        self.get_metatable_l(tname);
+        self.set_metatable(-2);
+    }
+
+    // Push field `e` of the metatable of `obj`, if there is one.
+    fn get_metafield(&mut self, obj: isize, e: &str) -> LuaType {
+        if !self.get_metatable(obj) {
+            return LUA_TNIL; // no metatable
+        }
+        self.push_string(e.to_string());
+        let tt = self.raw_get(-2);
+        if tt == LUA_TNIL {
+            self.pop(2); // remove metatable and metafield
+        } else {
+            self.remove(-2); // remove only metatable
+        }
+        tt
+    }
+
+    // Call metamethod `e` of `obj` with `obj` itself, pushing its result.
+    fn call_meta(&mut self, obj: isize, e: &str) -> bool {
+        let obj = self.abs_index(obj);
+        if self.get_metafield(obj, e) == LUA_TNIL {
+            return false;
+        }
+        self.push_value(obj);
+        self.call(1, 1);
+        true
+    }
+
+    fn test_udata(&mut self, ud: isize, tname: &str) -> Option<Rc<dyn std::any::Any>> {
+        let p = self.to_userdata_any(ud)?;
+        if self.get_metatable(ud) {
+            self.get_metatable_l(tname);
+            let same = self.raw_equal(-1, -2);
+            self.pop(2);
+            if same {
+                return Some(p);
+            }
+        }
+        None
+    }
+
+    // Payload of the full userdata at `ud`, which must have the metatable
+    // created by `new_metatable(tname)`.
+    fn check_udata(&mut self, ud: isize, tname: &str) -> Rc<dyn std::any::Any> {
+        match self.test_udata(ud, tname) {
+            Some(p) => p,
+            None => self.type_error(ud, tname),
+        }
+    }
+
+    /* references */
+
+    // Pop a value, store it in table `t` and return its (integer) key.
+    fn ref_(&mut self, t: isize) -> i64 {
+        if self.is_nil(-1) {
+            self.pop(1);
+            return LUA_REFNIL;
+        }
+        let t = self.abs_index(t);
+        self.raw_get_i(t, FREELIST); // get first free element
+        let r = self.to_integer(-1);
+        self.pop(1);
+        let r = if r != 0 {
+            self.raw_get_i(t, r);
+            self.raw_set_i(t, FREELIST); // t[freelist] = t[ref]
+            r
+        } else {
+            self.raw_len(t) as i64 + 1 // get a new reference
+        };
+        self.raw_set_i(t, r);
+        r
+    }
+
+    // Release reference `r` of table `t`, its key may then be reused.
+    fn unref(&mut self, t: isize, r: i64) {
+        if r >= 0 {
+            let t = self.abs_index(t);
+            self.raw_get_i(t, FREELIST);
+            self.raw_set_i(t, r); // t[ref] = t[freelist]
+            self.push_integer(r);
+            self.raw_set_i(t, FREELIST); // t[freelist] = ref
+        }
+    }
+
+    /* miscellaneous functions */
+
+    // Length of the value at `idx` (honoring `__len`) as an integer.
+    fn len_l(&mut self, idx: isize) -> i64 {
+        self.len(idx);
+        match self.to_integerx(-1) {
+            Some(n) => {
+                self.pop(1);
+                n
+            }
+            None => self.error_l("object length is not an integer"),
+        }
+    }
+
+    // Push and return the string form of any value, honoring `__tostring`
+    // and `__name`, as the `tostring` function does.
+    fn tolstring_l(&mut self, idx: isize) -> String {
+        if self.call_meta(idx, "__tostring") {
+            if !self.is_string(-1) {
+                self.error_l("'__tostring' must return a string");
+            }
+        } else {
+            match self.type_id(idx) {
+                LUA_TNUMBER | LUA_TSTRING => {
+                    let s = self.to_bytes(idx).unwrap();
+                    self.push_bytes(s);
+                }
+                LUA_TBOOLEAN => {
+                    let b = self.to_boolean(idx);
+                    self.push_string(b.to_string());
+                }
+                LUA_TNIL => self.push_string("nil".to_string()),
+                t => {
+                    let p = self.to_pointer(idx);
+                    let tt = self.get_metafield(idx, "__name");
+                    let kind = if tt == LUA_TSTRING {
+                        self.to_string(-1)
+                    } else {
+                        self.type_name(t).to_string()
+                    };
+                    self.push_string(format!("{}: {:p}", kind, p));
+                    if tt != LUA_TNIL {
+                        self.remove(-2); // remove '__name'
+                    }
+                }
+            }
+        }
+        self.to_string(-1)
+    }
+
+    // Push the outcome of a file operation: true on success, otherwise
+    // nil, an error message (prefixed with `fname` if given) and errno.
+    fn file_result(&mut self, res: io::Result<()>, fname: Option<&str>) -> usize {
+        match res {
+            Ok(()) => {
+                self.push_boolean(true);
+                1
+            }
+            Err(e) => {
+                self.push_nil();
+                let msg = match fname {
+                    Some(fname) => format!("{}: {}", fname, strerror(&e)),
+                    None => strerror(&e),
+                };
+                self.push_string(msg);
+                self.push_integer(e.raw_os_error().unwrap_or(0) as i64);
+                3
+            }
+        }
+    }
+
+    /* loading */
+
+    // Load the chunk in file `filename` (or stdin when None) as a function
+    // on top of the stack, named "@filename" for error messages.
+    // Like the C version, skips a UTF-8 BOM and a first line starting with '#'.
+    fn load_file(&mut self, filename: Option<&str>, mode: &str) -> LuaStatus {
+        let (data, chunk_name) = match filename {
+            Some(fname) => {
+                let data = File::open(fname).and_then(|mut f| {
+                    let mut data = Vec::new();
+                    f.read_to_end(&mut data).map(|_| data)
+                });
+                (data, format!("@{}", fname))
+            }
+            None => {
+                let mut data = Vec::new();
+                let data = io::stdin().read_to_end(&mut data).map(|_| data);
+                (data, "=stdin".to_string())
+            }
+        };
+        let mut data = match data {
+            Ok(data) => data,
+            Err(e) => {
+                let what = match e.kind() {
+                    io::ErrorKind::NotFound | io::ErrorKind::PermissionDenied => "open",
+                    _ => "read",
+                };
+                let msg = format!("cannot {} {}: {}", what, &chunk_name[1..], strerror(&e));
+                self.push_string(msg);
+                return LUA_ERRFILE;
+            }
+        };
+        if data.starts_with(b"\xEF\xBB\xBF") {
+            data.drain(..3); // skip BOM
+        }
+        if data.first() == Some(&b'#') {
+            // first line is a comment (Unix exec. file)?
+            let eol = data.iter().position(|&c| c == b'\n').unwrap_or(data.len());
+            data.drain(..eol); // keep the newline, so line numbers stay right
+            if data.get(1..).is_some_and(|d| d.starts_with(LUA_SIGNATURE)) {
+                data.remove(0); // binary chunks start right after the comment
+            }
+        }
+        self.load(data, &chunk_name, mode, None)
+    }
+
+    /* libraries */
+
+    // Register the functions of `l` into the table below the `nup` values
+    // on top, each one gets a copy of them as its upvalues.
+    fn set_funcs(&mut self, l: &[(&str, RustFn)], nup: usize) {
+        self.check_stack(nup);
+        for (name, f) in l.iter() {
+            for _ in 0..nup {
+                self.push_value(-(nup as isize)); // copy upvalues to the top
+            }
+            self.push_rust_closure(*f, nup);
+            self.set_field(-(nup as isize + 2), name);
+        }
+        self.pop(nup); // remove upvalues
+    }
+
+    fn new_lib(&mut self, l: &[(&str, RustFn)]) {
+        self.create_table(0, l.len());
+        self.set_funcs(l, 0);
+    }
+
+    // Push t[fname] of the table at `idx`, creating it if it is not a table.
+    fn get_subtable(&mut self, idx: isize, fname: &str) -> bool {
+        if self.get_field(idx, fname) == LUA_TTABLE {
+            return true; // table already there
+        }
+        self.pop(1); // remove previous result
+        let idx = self.abs_index(idx);
+        self.new_table();
+        self.push_value(-1); // copy to be left at top
+        self.set_field(idx, fname); // assign new table to field
+        false
+    }
+
+    // Open module `modname` with `openf` unless it is already loaded, push
+    // it and keep it in `registry._LOADED` (and as a global if `glb`).
+    fn requiref(&mut self, modname: &str, openf: RustFn, glb: bool) {
+        self.get_subtable(LUA_REGISTRY_INDEX, "_LOADED");
+        self.get_field(-1, modname); // _LOADED[modname]
+        if !self.to_boolean(-1) {
+            // package not already loaded?
+            self.pop(1);
+            self.push_rust_fn(openf);
+            self.push_string(modname.to_string());
+            self.call(1, 1); // call 'openf' to open module
+            self.push_value(-1);
+            self.set_field(-3, modname); // _LOADED[modname] = module
+        }
+        self.remove(-2); // remove _LOADED table
+        if glb {
+            self.push_value(-1);
+            self.set_global(modname); // _G[modname] = module
+        }
+    }
+}
+
+impl<S: LuaState + ?Sized> LuaAuxLib for S {}
+
+// Message of an I/O error as C `strerror` would give it, without the
+// " (os error N)" suffix Rust appends.
+pub fn strerror(e: &io::Error) -> String {
+    let msg = e.to_string();
+    match (e.raw_os_error(), msg.rfind(" (os error ")) {
+        (Some(_), Some(i)) => msg[..i].to_string(),
+        _ => msg,
+    }
+}
@@ -1,6 +1,23 @@
-use super::consts::{ArithOp, CompareOp, LuaType};
+use super::consts::{ArithOp, CompareOp, LuaStatus, LuaType, LUAI_MAXCCALLS, LUA_MAXSTACK};
+use super::lua_auxlib::LuaAuxLib;
+use core::cell::RefCell;
+use std::ffi::c_void;
+use std::rc::Rc;
 
-pub trait LuaState {
+// What handles held by Rust need from the state behind a `dyn LuaAPI`:
+// the `Rc` holding the state, and the registry references they released
+// while the state was borrowed. The trait cannot be named outside the
+// crate, so it also keeps `LuaAPI` from being implemented there, and its
+// methods take an `Internal` since a `dyn LuaAPI` reaches them anyway.
+pub trait StateRefs {
+    fn owner(&self, _: Internal) -> Option<Rc<RefCell<crate::state::LuaState>>>;
+    fn dropped_refs(&self, _: Internal) -> Rc<RefCell<Vec<i64>>>;
+}
+
+// Only the crate can make one.
+pub struct Internal(pub(crate) ());
+
+pub trait LuaState: StateRefs {
     /* basic stack manipulation */
     fn get_top(&self) -> isize;
     fn abs_index(&self, idx: isize) -> isize;
@@ -26,6 +43,8 @@ pub trait LuaState {
     fn is_table(&self, idx: isize) -> bool;
     fn is_thread(&self, idx: isize) -> bool;
     fn is_function(&self, idx: isize) -> bool;
+    fn is_userdata(&self, idx: isize) -> bool;
+    fn is_light_userdata(&self, idx: isize) -> bool;
     fn to_boolean(&self, idx: isize) -> bool;
     fn to_integer(&self, idx: isize) -> i64;
     fn to_integerx(&self, idx: isize) -> Option<i64>;
@@ -33,15 +52,26 @@ pub trait LuaState {
     fn to_numberx(&self, idx: isize) -> Option<f64>;
     fn to_string(&self, idx: isize) -> String;
     fn to_stringx(&self, idx: isize) -> Option<String>;
+    fn to_bytes(&self, idx: isize) -> Option<Vec<u8>>;
+    fn to_shared_bytes(&self, idx: isize) -> Option<Rc<Vec<u8>>>;
+    fn to_light_userdata(&self, idx: isize) -> Option<*mut c_void>;
+    fn to_userdata_any(&self, idx: isize) -> Option<Rc<dyn std::any::Any>>;
+    fn to_pointer(&self, idx: isize) -> *const c_void;
+    fn raw_len(&self, idx: isize) -> usize;
+    fn string_to_number(&mut self, s: &str) -> bool;
     /* push methods (rust -> stack) */
     fn push_nil(&mut self);
     fn push_boolean(&mut self, b: bool);
     fn push_integer(&mut self, n: i64);
     fn push_number(&mut self, n: f64);
     fn push_string(&mut self, s: String);
+    fn push_bytes(&mut self, b: Vec<u8>);
+    fn push_light_userdata(&mut self, p: *mut c_void);
+    fn new_userdata_any(&mut self, data: Rc<dyn std::any::Any>);
     /* comparison and arithmetic methods */
     fn arith(&mut self, op: ArithOp);
-    fn compare(&self, idx1: isize, idx2: isize, op: CompareOp) -> bool;
+    fn compare(&mut self, idx1: isize, idx2: isize, op: CompareOp) -> bool;
+    fn raw_equal(&self, idx1: isize, idx2: isize) -> bool;
     /* miscellaneous methods */
     fn len(&mut self, idx: isize);
     fn concat(&mut self, n: isize);
@@ -51,15 +81,25 @@ pub trait LuaState {
     fn get_table(&mut self, idx: isize) -> i8;
     fn get_field(&mut self, idx: isize, k: &str) -> i8;
     fn get_i(&mut self, idx: isize, i: i64) -> i8;
+    fn raw_get(&mut self, idx: isize) -> LuaType;
+    fn raw_get_i(&mut self, idx: isize, i: i64) -> LuaType;
+    fn get_metatable(&mut self, idx: isize) -> bool;
+    fn get_user_value(&mut self, idx: isize) -> LuaType;
     // set functions (stack -> Lua)
     fn set_table(&mut self, idx: isize);
     fn set_field(&mut self, idx: isize, k: &str);
     fn set_i(&mut self, idx: isize, i: i64);
+    fn raw_set(&mut self, idx: isize);
+    fn raw_set_i(&mut self, idx: isize, i: i64);
+    fn set_metatable(&mut self, idx: isize);
+    fn set_user_value(&mut self, idx: isize);
     // call functions
-    fn load(&mut self, chunk: Vec<u8>, chunk_name: &str, mode: &str) -> u8;
+    fn load(&mut self, chunk: Vec<u8>, chunk_name: &str, mode: &str, env: Option<isize>) -> LuaStatus;
     fn call(&mut self, nargs: usize, nresults: isize);
+    fn pcall(&mut self, nargs: usize, nresults: isize, msgh: isize) -> LuaStatus;
     // call native functions
     fn push_rust_fn(&mut self, f: RustFn);
+    fn push_rust_closure(&mut self, f: RustFn, n: usize);
     fn is_rust_fn(&self, idx: isize) -> bool;
     fn to_rust_fn(&mut self, idx: isize) -> Option<RustFn>;
     // access global table
@@ -67,6 +107,115 @@ pub trait LuaState {
     fn get_global(&mut self, name: &str) -> LuaType;
     fn set_global(&mut self, name: &str);
     fn register(&mut self, name: &str, f: RustFn);
+    // upvalues
+    fn get_upvalue(&mut self, funcidx: isize, n: isize) -> Option<String>;
+    fn set_upvalue(&mut self, funcidx: isize, n: isize) -> Option<String>;
+    fn upvalue_id(&self, funcidx: isize, n: isize) -> Option<*mut c_void>;
+    fn upvalue_join(&mut self, funcidx1: isize, n1: isize, funcidx2: isize, n2: isize);
+    // error handling
+    fn error(&mut self) -> !;
+    // miscellaneous functions
+    fn next(&mut self, idx: isize) -> bool;
+    // debug interface
+    fn get_stack(&self, level: isize) -> Option<LuaDebug>;
+    fn get_info(&mut self, what: &str, ar: &mut LuaDebug) -> bool;
+    fn get_local(&mut self, ar: Option<&LuaDebug>, n: isize) -> Option<String>;
+    fn set_local(&mut self, ar: &LuaDebug, n: isize) -> Option<String>;
+    fn set_hook(&mut self, f: Option<LuaHook>, mask: u8, count: usize);
+    fn get_hook(&self) -> Option<LuaHook>;
+    fn get_hook_mask(&self) -> u8;
+    fn get_hook_count(&self) -> usize;
+    // limits
+    fn set_limits(&mut self, limits: LuaLimits);
+    fn get_limits(&self) -> LuaLimits;
+    fn instruction_count(&self) -> u64;
+    fn allocated_bytes(&self) -> usize;
+    // raise the memory limit error if `bytes` more would go past it, to be
+    // called before a large buffer is allocated rather than after
+    fn check_alloc(&mut self, bytes: usize);
+}
+
+pub type RustFn = fn(&mut dyn LuaState) -> usize;
+
+/*
+    Function called by the VM on the events selected by the hook mask,
+    with `ar.event` telling which one. It runs on the frame of the hooked
+    function (see `get_info`), and can abort the script with `error`.
+*/
+pub type LuaHook = fn(&mut dyn LuaState, &mut LuaDebug);
+
+// Activation record filled by `get_info`, see `lua_Debug` in lua.h
+#[derive(Clone, Debug, Default)]
+pub struct LuaDebug {
+    pub event: u8,
+    pub name: Option<String>,
+    pub namewhat: &'static str, // "global", "local", "method", "field", "upvalue", or ""
+    pub what: &'static str,     // "Lua", "C" or "main"
+    pub source: String,
+    pub short_src: String,
+    pub current_line: isize,
+    pub line_defined: isize,
+    pub last_line_defined: isize,
+    pub nups: usize,
+    pub nparams: usize,
+    pub is_vararg: bool,
+    pub is_tail_call: bool,
+    pub(crate) frame: usize, // active function
+}
+
+/*
+    Bounds on what a state may use, to run untrusted scripts. Going past
+    one raises a catchable error, and `pcall` returns LUA_ERRLIMIT for it.
+    The instruction and memory counters restart when limits are set and
+    whenever the host calls into the state, so each script gets the whole
+    budget. Memory counts the bytes of the tables, table entries, strings
+    and closures created, and is not given back when they are freed. What
+    the host does itself between calls is not counted, but a single
+    buffer larger than the whole memory limit is refused there too.
+    Lua calls nest on the Rust stack as well, so their depth is also kept
+    within LUAI_MAXRSTACK bytes of it, which no limit can raise.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LuaLimits {
+    pub instructions: Option<u64>, // VM instructions executed
+    pub memory: Option<usize>,     // bytes allocated
+    pub call_depth: usize,         // nested calls of Rust functions
+    pub stack_slots: usize,        // stack slots used by all the frames
+}
+
+impl Default for LuaLimits {
+    fn default() -> LuaLimits {
+        LuaLimits {
+            instructions: None,
+            memory: None,
+            call_depth: LUAI_MAXCCALLS,
+            stack_slots: LUA_MAXSTACK,
+        }
+    }
+}
+
+/*
+    Typed access to full userdata. The payload of a userdata created here
+    is a `RefCell<T>`, so Rust code gets a shared `Rc<RefCell<T>>` handle
+    back and borrows are checked at runtime.
+*/
+pub trait LuaUserdata: LuaState {
+    fn new_userdata<T: std::any::Any>(&mut self, data: T) {
+        self.new_userdata_any(Rc::new(RefCell::new(data)));
+    }
+
+    fn to_userdata<T: std::any::Any>(&self, idx: isize) -> Option<Rc<RefCell<T>>> {
+        self.to_userdata_any(idx)?.downcast::<RefCell<T>>().ok()
+    }
+
+    // Like `to_userdata`, but the userdata must also carry the metatable
+    // registered as `registry[tname]`, otherwise an argument error is raised.
+    fn check_userdata<T: std::any::Any>(&mut self, idx: isize, tname: &str) -> Rc<RefCell<T>> {
+        match self.check_udata(idx, tname).downcast::<RefCell<T>>() {
+            Ok(ud) => ud,
+            Err(_) => self.type_error(idx, tname),
+        }
+    }
 }
 
-pub type RustFn = fn(&dyn LuaState) -> usize;
+impl<S: LuaState + ?Sized> LuaUserdata for S {}
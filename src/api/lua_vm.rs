@@ -1,3 +1,5 @@
+use super::consts::{ArithOp, CompareOp};
+
 
 pub trait LuaVM: super::lua_state::LuaState {
     fn pc(&self) -> isize;  // For debug
@@ -8,4 +10,24 @@ pub trait LuaVM: super::lua_state::LuaState {
     fn register_count(&self) -> usize;
     fn load_vararg(&mut self, n: isize);
     fn load_proto(&mut self, idx: usize);
-}
\ No newline at end of file
+    fn get_upval(&mut self, idx: isize);   // push UpValue[idx]
+    fn set_upval(&mut self, idx: isize);   // pop into UpValue[idx]
+    fn close_upvals(&mut self, a: isize);  // close upvalues >= R(a)
+
+    // Instructions on registers, which read and write the slots of the
+    // frame in place where they can instead of going through the top.
+    // MOVE, LOADK, GETUPVAL, arithmetic, comparisons, TEST, TESTSET and
+    // the numeric for loop use them; the table instructions, CALL and
+    // the others still push and replace.
+    fn load_const(&mut self, a: isize, idx: isize); // R(a) := Kst(idx)
+    fn load_upval(&mut self, a: isize, idx: isize); // R(a) := UpValue[idx]
+    fn reg_truth(&self, r: isize) -> bool;         // R(r) is neither nil nor false
+    fn move_reg(&mut self, a: isize, b: isize);    // R(a) := R(b)
+    fn arith_rk(&mut self, op: ArithOp, a: isize, b: isize, c: isize); // R(a) := RK(b) op RK(c)
+    fn compare_rk(&mut self, op: CompareOp, b: isize, c: isize) -> bool; // RK(b) op RK(c)
+
+    // Field access for the table at stack index `idx`, through the field
+    // cache of the current instruction when RK is a constant string.
+    fn get_table_rk(&mut self, idx: isize, rk: isize);           // push t[RK(rk)]
+    fn set_table_rk(&mut self, idx: isize, b: isize, c: isize);  // t[RK(b)] := RK(c)
+}
@@ -1,6 +1,9 @@
 pub mod consts;
-mod lua_vm;
+mod lua_auxlib;
 mod lua_state;
+mod lua_vm;
 
-pub use self::lua_state::{LuaState as LuaAPI, RustFn};
-pub use self::lua_vm::LuaVM;
+pub use self::lua_auxlib::{strerror, LuaAuxLib};
+pub use self::lua_state::{LuaDebug, LuaHook, LuaLimits, LuaState as LuaAPI, LuaUserdata, RustFn};
+pub(crate) use self::lua_state::{Internal, StateRefs};
+pub(crate) use self::lua_vm::LuaVM;
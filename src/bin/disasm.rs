@@ -1,19 +1,11 @@
+use rslua::binary;
+use rslua::opcodes::*;
+use rslua::Instruction;
 use std::env;
 use std::fs::File;
 use std::io;
 use std::io::prelude::*;
 
-mod binary;
-mod vm;
-use crate::vm::instruction::Instruction;
-use crate::vm::opcodes::*;
-
-mod api;
-mod number;
-mod state;
-use crate::api::consts::*;
-use crate::state::LuaState;
-
 fn main() -> io::Result<()> {
     let args: Vec<String> = env::args().collect();
     if args.len() > 1 {
@@ -126,7 +118,7 @@ fn print_detail(proto: &binary::chunk::Prototype) {
             binary::chunk::Constant::Boolean(b) => format!("{}", b),
             binary::chunk::Constant::Integer(i) => format!("{}", i),
             binary::chunk::Constant::Number(f) => format!("{}", f),
-            binary::chunk::Constant::Str(s) => format!("\"{}\"", s),
+            binary::chunk::Constant::Str(s) => format!("\"{}\"", String::from_utf8_lossy(s)),
             _ => "?".to_string(),
         }
     }
@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::rc::Rc;
 
 pub const LUA_SIGNATURE: &'static [u8; 4] = b"\x1BLua";
@@ -55,6 +56,7 @@ pub struct Prototype {
     pub line_info: Vec<u32>,
     pub loc_vars: Vec<LocVar>,
     pub upvalue_names: Vec<String>,
+    pub(crate) field_caches: Vec<Cell<usize>>, // per instruction, the table node of its last field
 }
 
 pub enum Constant {
@@ -62,7 +64,7 @@ pub enum Constant {
     Boolean(bool),
     Integer(i64),
     Number(f64),
-    Str(String),
+    Str(Rc<Vec<u8>>), // shared with the values loading it
 }
 
 pub struct Upvalue {
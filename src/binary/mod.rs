@@ -1,10 +1,19 @@
 pub mod chunk;
-pub mod reader;
+pub(crate) mod reader;
 use std::rc::Rc;
 
 pub fn undump(data: Vec<u8>) -> Rc<chunk::Prototype> {
     let mut reader = reader::Reader::new(data);
     reader.check_header();
     reader.read_byte(); // Skip Upvalue size
-    reader.read_proto()
+    reader.read_proto(None)
+}
+
+// Like `undump`, but a stripped chunk takes `chunk_name` as its source so
+// tracebacks still name where it was loaded from.
+pub fn undump_named(data: Vec<u8>, chunk_name: &str) -> Rc<chunk::Prototype> {
+    let mut reader = reader::Reader::new(data);
+    reader.check_header();
+    reader.read_byte(); // Skip Upvalue size
+    reader.read_proto(Some(chunk_name.to_string()))
 }
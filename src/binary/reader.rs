@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::rc::Rc;
 use crate::binary::chunk;
 
@@ -18,17 +19,38 @@ impl Reader {
     }
 
     pub fn read_u32(&mut self) -> u32 {
-        let a0 = self.read_byte() as u32;
-        let a1 = self.read_byte() as u32;
-        let a2 = self.read_byte() as u32;
-        let a3 = self.read_byte() as u32;
-        (a3 << 24) | (a2 << 16) | (a1 << 8) | a0
+        self.read_uint(4, true) as u32
     }
 
     pub fn read_u64(&mut self) -> u64 {
-        let a0 = self.read_u32() as u64;
-        let a1 = self.read_u32() as u64;
-        (a1 << 32) | a0
+        self.read_uint(8, true)
+    }
+
+    // Unsigned integer of `size` bytes (at most 8), chunks are little endian
+    // but `string.unpack` reads both byte orders.
+    pub fn read_uint(&mut self, size: usize, little: bool) -> u64 {
+        let mut n: u64 = 0;
+        for i in 0..size {
+            let b = self.read_byte() as u64;
+            if little {
+                n |= b << (8 * i);
+            } else {
+                n = (n << 8) | b;
+            }
+        }
+        n
+    }
+
+    pub fn position(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn skip(&mut self, n: usize) {
+        self.cursor += n;
+    }
+
+    pub fn remaining(&self) -> &[u8] {
+        self.data.get(self.cursor..).unwrap_or(&[])
     }
 
     pub fn read_lua_integer(&mut self) -> i64 {
@@ -53,6 +75,16 @@ impl Reader {
     }
 
     fn _read_string(&mut self) -> Option<String> {
+        let bytes = self._read_raw_string()?;
+        Some(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    // String constants may hold any bytes, not only UTF-8.
+    pub fn read_raw_string(&mut self) -> Vec<u8> {
+        self._read_raw_string().unwrap_or_default()
+    }
+
+    fn _read_raw_string(&mut self) -> Option<Vec<u8>> {
         let mut size: usize = self.read_byte() as usize;
 
         if size == 0 {
@@ -63,9 +95,7 @@ impl Reader {
             // Long string
             size = self.read_u64() as usize;
         }
-        let bytes = self.read_bytes(size - 1);
-        let string = String::from_utf8(bytes);
-        string.ok()
+        Some(self.read_bytes(size - 1))
     }
 
     pub fn check_header(&mut self) {
@@ -82,13 +112,13 @@ impl Reader {
         assert_eq!(self.read_lua_number(), chunk::LUAC_NUM, "float format mismatch!");
     }
 
-    pub fn read_proto(&mut self) -> Rc<chunk::Prototype> {
-        self._read_proto(None)
+    pub fn read_proto(&mut self, source: Option<String>) -> Rc<chunk::Prototype> {
+        self._read_proto(source)
     }
 
     fn _read_proto(&mut self, parent_source: Option<String>) -> Rc<chunk::Prototype> {
         let source = self._read_string().or(parent_source);
-        Rc::new(chunk::Prototype {
+        let mut proto = chunk::Prototype {
             source: source.clone(),
             line_defined: self.read_u32(),
             last_line_defined: self.read_u32(),
@@ -102,7 +132,10 @@ impl Reader {
             line_info: self.read_vec(|r| r.read_u32()),
             loc_vars: self.read_vec(|r| r.read_loc_var()),
             upvalue_names: self.read_vec(|r| r.read_string()),
-        })
+            field_caches: vec![],
+        };
+        proto.field_caches = proto.code.iter().map(|_| Cell::new(usize::MAX)).collect();
+        Rc::new(proto)
     }
 
     // A template for read vector
@@ -124,7 +157,7 @@ impl Reader {
             chunk::TAG_BOOLEAN => chunk::Constant::Boolean(self.read_byte() != 0),
             chunk::TAG_INTEGER => chunk::Constant::Integer(self.read_lua_integer()),
             chunk::TAG_NUMBER => chunk::Constant::Number(self.read_lua_number()),
-            chunk::TAG_SHORT_STR | chunk::TAG_LONG_STR => chunk::Constant::Str(self.read_string()),
+            chunk::TAG_SHORT_STR | chunk::TAG_LONG_STR => chunk::Constant::Str(Rc::new(self.read_raw_string())),
             _ => panic!("Corrupted!"),
         }
     }
@@ -0,0 +1,416 @@
+use crate::api::LuaAPI;
+use crate::error::{LuaError, LuaResult};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::hash::Hash;
+
+// A Rust value that can be pushed as one Lua value.
+pub trait IntoLua {
+    fn push_into(self, ls: &mut dyn LuaAPI);
+
+    // Whether the value may go to `ls`, checked before pushing from Rust:
+    // handles only go back to the state they were taken from.
+    fn check_state(&self, _ls: &dyn LuaAPI) -> LuaResult<()> {
+        Ok(())
+    }
+}
+
+// A Rust value that can be made from the Lua value at `idx`, which is
+// left on the stack. A missing value (`idx` above the top) is "no value".
+pub trait FromLua: Sized {
+    fn from_lua(ls: &mut dyn LuaAPI, idx: isize) -> LuaResult<Self>;
+}
+
+// Rust values pushed as any number of Lua values, like call arguments.
+// Tuples push one value per element, `()` none.
+pub trait IntoLuaMulti {
+    // Push the values and return how many were pushed.
+    fn push_into_multi(self, ls: &mut dyn LuaAPI) -> usize;
+
+    // See `IntoLua::check_state`.
+    fn check_state(&self, _ls: &dyn LuaAPI) -> LuaResult<()> {
+        Ok(())
+    }
+}
+
+// Rust values made from the `n` values of the stack from index `first`
+// on, like call results. Missing values are "no value" and extra ones
+// are ignored.
+pub trait FromLuaMulti: Sized {
+    fn from_lua_multi(ls: &mut dyn LuaAPI, first: isize, n: usize) -> LuaResult<Self>;
+
+    // Like `from_lua_multi`, with the stack index of the value that did not
+    // convert, for argument errors.
+    fn from_lua_args(ls: &mut dyn LuaAPI, first: isize, n: usize) -> Result<Self, (isize, LuaError)> {
+        Self::from_lua_multi(ls, first, n).map_err(|e| (first, e))
+    }
+}
+
+// Any number of values of the same type, as the `...` of Lua.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Variadic<T>(pub Vec<T>);
+
+fn type_error<T>(ls: &dyn LuaAPI, idx: isize, to: &'static str, message: Option<&str>) -> LuaResult<T> {
+    Err(LuaError::FromLua {
+        from: ls.type_name(ls.type_id(idx)).to_string(),
+        to,
+        message: message.map(str::to_string),
+    })
+}
+
+/* single values */
+
+impl IntoLua for bool {
+    fn push_into(self, ls: &mut dyn LuaAPI) {
+        ls.push_boolean(self);
+    }
+}
+
+// Any value converts to a boolean, by the truth rules of Lua.
+impl FromLua for bool {
+    fn from_lua(ls: &mut dyn LuaAPI, idx: isize) -> LuaResult<bool> {
+        Ok(ls.to_boolean(idx))
+    }
+}
+
+macro_rules! lua_integer {
+    ($($t:ty),*) => {$(
+        impl IntoLua for $t {
+            fn push_into(self, ls: &mut dyn LuaAPI) {
+                match i64::try_from(self) {
+                    Ok(n) => ls.push_integer(n),
+                    Err(_) => ls.push_number(self as f64), // too large for an integer
+                }
+            }
+        }
+
+        impl FromLua for $t {
+            fn from_lua(ls: &mut dyn LuaAPI, idx: isize) -> LuaResult<$t> {
+                match ls.to_integerx(idx) {
+                    Some(n) => match <$t>::try_from(n) {
+                        Ok(n) => Ok(n),
+                        Err(_) => type_error(ls, idx, stringify!($t), Some("out of range")),
+                    },
+                    None if ls.is_number(idx) => {
+                        type_error(ls, idx, stringify!($t), Some("number has no integer representation"))
+                    }
+                    None => type_error(ls, idx, stringify!($t), None),
+                }
+            }
+        }
+    )*};
+}
+
+lua_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! lua_float {
+    ($($t:ty),*) => {$(
+        impl IntoLua for $t {
+            fn push_into(self, ls: &mut dyn LuaAPI) {
+                ls.push_number(self as f64);
+            }
+        }
+
+        impl FromLua for $t {
+            fn from_lua(ls: &mut dyn LuaAPI, idx: isize) -> LuaResult<$t> {
+                match ls.to_numberx(idx) {
+                    Some(n) => Ok(n as $t),
+                    None => type_error(ls, idx, stringify!($t), None),
+                }
+            }
+        }
+    )*};
+}
+
+lua_float!(f32, f64);
+
+impl IntoLua for &str {
+    fn push_into(self, ls: &mut dyn LuaAPI) {
+        ls.push_string(self.to_string());
+    }
+}
+
+impl IntoLua for String {
+    fn push_into(self, ls: &mut dyn LuaAPI) {
+        ls.push_string(self);
+    }
+}
+
+// Numbers convert to strings too, as in `lua_tolstring`.
+impl FromLua for String {
+    fn from_lua(ls: &mut dyn LuaAPI, idx: isize) -> LuaResult<String> {
+        match ls.to_stringx(idx) {
+            Some(s) => Ok(s),
+            None => type_error(ls, idx, "String", None),
+        }
+    }
+}
+
+// None is nil.
+impl<T: IntoLua> IntoLua for Option<T> {
+    fn push_into(self, ls: &mut dyn LuaAPI) {
+        match self {
+            Some(v) => v.push_into(ls),
+            None => ls.push_nil(),
+        }
+    }
+
+    fn check_state(&self, ls: &dyn LuaAPI) -> LuaResult<()> {
+        self.iter().try_for_each(|v| v.check_state(ls))
+    }
+}
+
+impl<T: FromLua> FromLua for Option<T> {
+    fn from_lua(ls: &mut dyn LuaAPI, idx: isize) -> LuaResult<Option<T>> {
+        if ls.is_none_or_nil(idx) {
+            Ok(None)
+        } else {
+            T::from_lua(ls, idx).map(Some)
+        }
+    }
+}
+
+// A sequence.
+impl<T: IntoLua> IntoLua for Vec<T> {
+    fn push_into(self, ls: &mut dyn LuaAPI) {
+        ls.create_table(self.len(), 0);
+        for (i, v) in self.into_iter().enumerate() {
+            v.push_into(ls);
+            ls.raw_set_i(-2, i as i64 + 1);
+        }
+    }
+
+    fn check_state(&self, ls: &dyn LuaAPI) -> LuaResult<()> {
+        self.iter().try_for_each(|v| v.check_state(ls))
+    }
+}
+
+// The sequence `t[1..#t]` of a table, read without metamethods.
+impl<T: FromLua> FromLua for Vec<T> {
+    fn from_lua(ls: &mut dyn LuaAPI, idx: isize) -> LuaResult<Vec<T>> {
+        if !ls.is_table(idx) {
+            return type_error(ls, idx, "Vec", None);
+        }
+        let idx = ls.abs_index(idx);
+        let n = ls.raw_len(idx);
+        let mut vec = Vec::with_capacity(n);
+        for i in 1..=n {
+            ls.raw_get_i(idx, i as i64);
+            let v = T::from_lua(ls, -1);
+            ls.pop(1);
+            vec.push(v?);
+        }
+        Ok(vec)
+    }
+}
+
+impl<K: IntoLua, V: IntoLua> IntoLua for HashMap<K, V> {
+    fn push_into(self, ls: &mut dyn LuaAPI) {
+        ls.create_table(0, self.len());
+        for (k, v) in self {
+            k.push_into(ls);
+            v.push_into(ls);
+            ls.raw_set(-3);
+        }
+    }
+
+    fn check_state(&self, ls: &dyn LuaAPI) -> LuaResult<()> {
+        self.iter().try_for_each(|(k, v)| k.check_state(ls).and(v.check_state(ls)))
+    }
+}
+
+// All the pairs of a table, read without metamethods.
+impl<K: FromLua + Eq + Hash, V: FromLua> FromLua for HashMap<K, V> {
+    fn from_lua(ls: &mut dyn LuaAPI, idx: isize) -> LuaResult<HashMap<K, V>> {
+        if !ls.is_table(idx) {
+            return type_error(ls, idx, "HashMap", None);
+        }
+        let idx = ls.abs_index(idx);
+        let mut map = HashMap::new();
+        ls.push_nil();
+        while ls.next(idx) {
+            let pair = K::from_lua(ls, -2).and_then(|k| V::from_lua(ls, -1).map(|v| (k, v)));
+            ls.pop(1); // keep the key for `next`
+            match pair {
+                Ok((k, v)) => map.insert(k, v),
+                Err(e) => {
+                    ls.pop(1);
+                    return Err(e);
+                }
+            };
+        }
+        Ok(map)
+    }
+}
+
+/* multiple values */
+
+impl<T: IntoLua> IntoLuaMulti for T {
+    fn push_into_multi(self, ls: &mut dyn LuaAPI) -> usize {
+        self.push_into(ls);
+        1
+    }
+
+    fn check_state(&self, ls: &dyn LuaAPI) -> LuaResult<()> {
+        IntoLua::check_state(self, ls)
+    }
+}
+
+impl<T: FromLua> FromLuaMulti for T {
+    fn from_lua_multi(ls: &mut dyn LuaAPI, first: isize, n: usize) -> LuaResult<T> {
+        let idx = if n == 0 { ls.get_top() + 1 } else { first }; // no value if missing
+        T::from_lua(ls, idx)
+    }
+}
+
+impl<T: IntoLua> IntoLuaMulti for Variadic<T> {
+    fn push_into_multi(self, ls: &mut dyn LuaAPI) -> usize {
+        let n = self.0.len();
+        for v in self.0 {
+            v.push_into(ls);
+        }
+        n
+    }
+
+    fn check_state(&self, ls: &dyn LuaAPI) -> LuaResult<()> {
+        self.0.iter().try_for_each(|v| v.check_state(ls))
+    }
+}
+
+impl<T: FromLua> FromLuaMulti for Variadic<T> {
+    fn from_lua_multi(ls: &mut dyn LuaAPI, first: isize, n: usize) -> LuaResult<Variadic<T>> {
+        let vals = (0..n as isize).map(|i| T::from_lua(ls, first + i));
+        vals.collect::<LuaResult<_>>().map(Variadic)
+    }
+
+    fn from_lua_args(ls: &mut dyn LuaAPI, first: isize, n: usize) -> Result<Variadic<T>, (isize, LuaError)> {
+        let vals = (0..n as isize).map(|i| T::from_lua(ls, first + i).map_err(|e| (first + i, e)));
+        vals.collect::<Result<_, _>>().map(Variadic)
+    }
+}
+
+macro_rules! lua_tuple {
+    ($($name:ident),*) => {
+        impl<$($name: IntoLuaMulti),*> IntoLuaMulti for ($($name,)*) {
+            #[allow(non_snake_case, unused_mut, unused_variables)]
+            fn push_into_multi(self, ls: &mut dyn LuaAPI) -> usize {
+                let ($($name,)*) = self;
+                let mut n = 0;
+                $(n += $name.push_into_multi(ls);)*
+                n
+            }
+
+            #[allow(non_snake_case, unused_variables)]
+            fn check_state(&self, ls: &dyn LuaAPI) -> LuaResult<()> {
+                let ($($name,)*) = self;
+                $($name.check_state(ls)?;)*
+                Ok(())
+            }
+        }
+
+        // The last element may be a `Variadic` taking the remaining values.
+        impl<$($name: FromLuaMulti),*> FromLuaMulti for ($($name,)*) {
+            #[allow(non_snake_case, unused_mut, unused_variables, unused_assignments)]
+            fn from_lua_multi(ls: &mut dyn LuaAPI, first: isize, n: usize) -> LuaResult<Self> {
+                let mut i = 0;
+                $(
+                    let $name = $name::from_lua_multi(ls, first + i as isize, n.saturating_sub(i))?;
+                    i += 1;
+                )*
+                Ok(($($name,)*))
+            }
+
+            #[allow(non_snake_case, unused_mut, unused_variables, unused_assignments)]
+            fn from_lua_args(ls: &mut dyn LuaAPI, first: isize, n: usize) -> Result<Self, (isize, LuaError)> {
+                let mut i = 0;
+                $(
+                    let $name = $name::from_lua_args(ls, first + i as isize, n.saturating_sub(i))?;
+                    i += 1;
+                )*
+                Ok(($($name,)*))
+            }
+        }
+    };
+}
+
+lua_tuple!();
+lua_tuple!(A);
+lua_tuple!(A, B);
+lua_tuple!(A, B, C);
+lua_tuple!(A, B, C, D);
+lua_tuple!(A, B, C, D, E);
+lua_tuple!(A, B, C, D, E, F);
+lua_tuple!(A, B, C, D, E, F, G);
+lua_tuple!(A, B, C, D, E, F, G, H);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lua;
+
+    fn round_trip<T: IntoLua + FromLua>(ls: &mut dyn LuaAPI, v: T) -> LuaResult<T> {
+        v.push_into(ls);
+        let r = T::from_lua(ls, -1);
+        ls.pop(1);
+        r
+    }
+
+    #[test]
+    fn test_conversions() {
+        let lua = Lua::new_bare();
+        let mut ls = lua.state();
+        let ls: &mut dyn LuaAPI = &mut *ls;
+        assert_eq!(round_trip(ls, true), Ok(true));
+        assert_eq!(round_trip(ls, -3i8), Ok(-3));
+        assert_eq!(round_trip(ls, u64::MAX), Err(LuaError::FromLua {
+            from: "number".to_string(),
+            to: "u64",
+            message: Some("number has no integer representation".to_string()),
+        }));
+        assert_eq!(round_trip(ls, 2.5f64), Ok(2.5));
+        assert_eq!(round_trip(ls, "héllo".to_string()), Ok("héllo".to_string()));
+        assert_eq!(round_trip(ls, Some(4u32)), Ok(Some(4)));
+        assert_eq!(round_trip::<Option<u32>>(ls, None), Ok(None));
+        assert_eq!(round_trip(ls, vec![vec![1, 2], vec![], vec![3]]), Ok(vec![vec![1, 2], vec![], vec![3]]));
+        let map: HashMap<String, Vec<bool>> = vec![("a".to_string(), vec![true]), ("b".to_string(), vec![])]
+            .into_iter()
+            .collect();
+        assert_eq!(round_trip(ls, map.clone()), Ok(map));
+
+        // Lua conversions of numbers and strings apply
+        ls.push_string("10".to_string());
+        assert_eq!(i32::from_lua(ls, -1), Ok(10));
+        ls.push_number(3.0);
+        assert_eq!(String::from_lua(ls, -1), Ok("3.0".to_string()));
+        ls.push_integer(300);
+        let err = u8::from_lua(ls, -1).unwrap_err();
+        assert_eq!(err.to_string(), "cannot convert a Lua number to u8 (out of range)");
+        ls.push_nil();
+        assert_eq!(bool::from_lua(ls, -1), Ok(false));
+        assert_eq!(f64::from_lua(ls, -1).unwrap_err().to_string(), "cannot convert a Lua nil to f64");
+        assert_eq!(ls.get_top(), 4);
+
+        // a bad element stops the conversion and leaves the stack as it was
+        ls.set_top(0);
+        let mut map = HashMap::new();
+        map.insert(1, "x");
+        map.insert(2, "y");
+        map.push_into(ls);
+        assert!(HashMap::<i64, i64>::from_lua(ls, 1).is_err());
+        assert!(Vec::<i64>::from_lua(ls, 1).is_err());
+        assert_eq!(ls.get_top(), 1);
+        assert_eq!(Vec::<String>::from_lua(ls, 1), Ok(vec!["x".to_string(), "y".to_string()]));
+
+        // multiple values, missing ones are "no value"
+        ls.set_top(0);
+        assert_eq!((1, "a", Variadic(vec![2.5, 3.5])).push_into_multi(ls), 4);
+        let r = <(i64, String, Variadic<f64>)>::from_lua_multi(ls, 1, 4);
+        assert_eq!(r, Ok((1, "a".to_string(), Variadic(vec![2.5, 3.5]))));
+        let r = <(i64, Option<String>, Option<bool>)>::from_lua_multi(ls, 1, 1);
+        assert_eq!(r, Ok((1, None, None)));
+        let r = <(i64, i64)>::from_lua_multi(ls, 1, 1).unwrap_err();
+        assert_eq!(r.to_string(), "cannot convert a Lua no value to i64");
+        assert_eq!(().push_into_multi(ls), 0);
+        assert_eq!(ls.get_top(), 4);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::api::consts::LuaStatus;
+use std::fmt;
+
+// Errors of the typed API, see `IntoLua`, `FromLua` and `Function`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LuaError {
+    // A Lua value could not be converted to the Rust type `to`.
+    FromLua {
+        from: String, // Lua type name
+        to: &'static str,
+        message: Option<String>,
+    },
+    // A called function raised an error, `status` is the one of `pcall`.
+    Call { status: LuaStatus, message: String },
+    // A value could not be serialized to or deserialized from Lua, see
+    // `to_lua` and `from_lua`.
+    Serde { message: String },
+    // A table or function handle was given to another state than its own.
+    ForeignHandle,
+}
+
+pub type LuaResult<T> = Result<T, LuaError>;
+
+impl fmt::Display for LuaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LuaError::FromLua { from, to, message } => {
+                write!(f, "cannot convert a Lua {} to {}", from, to)?;
+                match message {
+                    Some(msg) => write!(f, " ({})", msg),
+                    None => Ok(()),
+                }
+            }
+            LuaError::Call { message, .. } => f.write_str(message),
+            LuaError::Serde { message } => f.write_str(message),
+            LuaError::ForeignHandle => f.write_str("the handle belongs to another state"),
+        }
+    }
+}
+
+impl std::error::Error for LuaError {}
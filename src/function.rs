@@ -0,0 +1,98 @@
+use crate::api::consts::*;
+use crate::api::LuaAPI;
+use crate::convert::{FromLuaMulti, IntoLuaMulti};
+use crate::error::{LuaError, LuaResult};
+
+/*
+    A function on the stack of a state, called with Rust values:
+        ls.get_global("string");
+        ls.get_field(-1, "rep");
+        let s: String = Function::new(&mut *ls, -1)?.call(("ab", 3))?;
+*/
+pub struct Function<'a> {
+    ls: &'a mut dyn LuaAPI,
+    idx: isize,
+}
+
+impl<'a> Function<'a> {
+    // The function at `idx`, which must stay there while this is used.
+    pub fn new(ls: &'a mut dyn LuaAPI, idx: isize) -> LuaResult<Function<'a>> {
+        if !ls.is_function(idx) {
+            return Err(LuaError::FromLua {
+                from: ls.type_name(ls.type_id(idx)).to_string(),
+                to: "Function",
+                message: None,
+            });
+        }
+        let idx = ls.abs_index(idx);
+        Ok(Function { ls, idx })
+    }
+
+    // Call the function in protected mode with `args` and convert its
+    // results. The stack is left as it was.
+    pub fn call<A: IntoLuaMulti, R: FromLuaMulti>(&mut self, args: A) -> LuaResult<R> {
+        self.ls.push_value(self.idx);
+        call_top(self.ls, args)
+    }
+}
+
+// Call the function on top of the stack, popping it and the results.
+pub(crate) fn call_top<A: IntoLuaMulti, R: FromLuaMulti>(ls: &mut dyn LuaAPI, args: A) -> LuaResult<R> {
+    let base = ls.get_top() - 1;
+    if let Err(e) = args.check_state(ls) {
+        ls.set_top(base);
+        return Err(e);
+    }
+    let nargs = args.push_into_multi(ls);
+    let status = ls.pcall(nargs, LUA_MULTRET, 0);
+    if status != LUA_OK {
+        let message = match ls.to_stringx(-1) {
+            Some(msg) => msg,
+            None => format!("(error object is a {} value)", ls.type_name(ls.type_id(-1))),
+        };
+        ls.set_top(base);
+        return Err(LuaError::Call { status, message });
+    }
+    let nresults = (ls.get_top() - base) as usize;
+    let results = R::from_lua_multi(ls, base + 1, nresults);
+    ls.set_top(base);
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::Variadic;
+    use crate::Lua;
+
+    #[test]
+    fn test_function_call() {
+        let lua = Lua::new();
+        let mut ls = lua.state();
+        let ls: &mut dyn LuaAPI = &mut *ls;
+        ls.get_global("string");
+        ls.get_field(-1, "rep");
+        let mut rep = Function::new(ls, -1).unwrap();
+        assert_eq!(rep.call::<_, String>(("ab", 3, "-")), Ok("ab-ab-ab".to_string()));
+        let err = rep.call::<_, String>(()).unwrap_err();
+        assert_eq!(err.to_string(), "bad argument #1 to 'string.rep' (string expected, got no value)");
+        let err = rep.call::<_, i64>(("x", 2)).unwrap_err();
+        assert_eq!(err.to_string(), "cannot convert a Lua string to i64");
+        assert_eq!(ls.get_top(), 2);
+
+        ls.get_global("select");
+        let mut select = Function::new(ls, -1).unwrap();
+        let r = select.call::<_, Variadic<i64>>((2, Variadic(vec![1, 2, 3])));
+        assert_eq!(r, Ok(Variadic(vec![2, 3])));
+        assert_eq!(select.call::<_, (i64, Option<i64>)>(("#", 7, 8)), Ok((2, None)));
+
+        ls.get_global("error");
+        let r = Function::new(ls, -1).unwrap().call::<_, ()>(("boom", 0));
+        assert_eq!(r, Err(LuaError::Call { status: LUA_ERRRUN, message: "boom".to_string() }));
+        ls.new_table();
+        let r = Function::new(ls, -2).unwrap().call::<_, ()>(());
+        assert_eq!(r.unwrap_err().to_string(), "(error object is a nil value)");
+        assert!(Function::new(ls, -1).is_err());
+        assert_eq!(ls.get_top(), 5);
+    }
+}
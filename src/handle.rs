@@ -0,0 +1,397 @@
+use crate::api::consts::*;
+use crate::api::{Internal, LuaAPI, LuaAuxLib, RustFn};
+use crate::convert::{FromLua, FromLuaMulti, IntoLua, IntoLuaMulti};
+use crate::error::{LuaError, LuaResult};
+use crate::function::call_top;
+use crate::state::LuaState;
+use std::cell::{RefCell, RefMut};
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+/*
+    A value kept in the registry for as long as Rust holds it. The state
+    is borrowed by each use, so handles are for the host between calls
+    into the state: Rust functions called by scripts use the stack. A
+    handle dropped while the state is borrowed releases its reference the
+    next time a handle gets the state.
+*/
+struct Anchor {
+    state: Rc<RefCell<LuaState>>,
+    dropped: Rc<RefCell<Vec<i64>>>, // `LuaState::dropped_refs`
+    r: i64,
+}
+
+impl Anchor {
+    // Anchor the value at `idx`, which must be of type `t`.
+    fn new(ls: &mut dyn LuaAPI, idx: isize, t: LuaType, to: &'static str) -> LuaResult<Anchor> {
+        let message = if ls.type_id(idx) != t {
+            None
+        } else if let Some(state) = ls.owner(Internal(())) {
+            return Ok(Anchor::with_state(ls, idx, state));
+        } else {
+            Some("the state is not held by an Rc".to_string())
+        };
+        let from = ls.type_name(ls.type_id(idx)).to_string();
+        Err(LuaError::FromLua { from, to, message })
+    }
+
+    fn with_state(ls: &mut dyn LuaAPI, idx: isize, state: Rc<RefCell<LuaState>>) -> Anchor {
+        let dropped = ls.dropped_refs(Internal(()));
+        ls.push_value(idx);
+        let r = ls.ref_(LUA_REGISTRY_INDEX);
+        Anchor { state, dropped, r }
+    }
+
+    // Borrow the state, releasing the references dropped meanwhile.
+    fn state(&self) -> RefMut<'_, LuaState> {
+        let mut ls = self.state.borrow_mut();
+        for r in self.dropped.borrow_mut().drain(..) {
+            ls.unref(LUA_REGISTRY_INDEX, r);
+        }
+        ls
+    }
+
+    fn check_state(&self, ls: &dyn LuaAPI) -> LuaResult<()> {
+        match ls.owner(Internal(())) {
+            Some(state) if Rc::ptr_eq(&state, &self.state) => Ok(()),
+            _ => Err(LuaError::ForeignHandle),
+        }
+    }
+
+    // Push the value on `ls`, which must be the state of the handle: the
+    // reference means nothing in the registry of another one.
+    fn push(&self, ls: &mut dyn LuaAPI) {
+        if let Err(e) = self.check_state(ls) {
+            ls.error_l(&e.to_string());
+        }
+        ls.raw_get_i(LUA_REGISTRY_INDEX, self.r);
+    }
+}
+
+impl Clone for Anchor {
+    fn clone(&self) -> Anchor {
+        let mut ls = self.state();
+        self.push(&mut *ls);
+        let r = ls.ref_(LUA_REGISTRY_INDEX);
+        Anchor { state: self.state.clone(), dropped: self.dropped.clone(), r }
+    }
+}
+
+impl Drop for Anchor {
+    fn drop(&mut self) {
+        match self.state.try_borrow_mut() {
+            Ok(mut ls) => ls.unref(LUA_REGISTRY_INDEX, self.r),
+            Err(_) => self.dropped.borrow_mut().push(self.r),
+        }
+    }
+}
+
+impl fmt::Debug for Anchor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "registry[{}]", self.r)
+    }
+}
+
+// Call `f` in protected mode, so errors of metamethods are returned.
+fn protected<A: IntoLuaMulti, R: FromLuaMulti>(ls: &mut dyn LuaAPI, f: RustFn, args: A) -> LuaResult<R> {
+    ls.push_rust_fn(f);
+    call_top(ls, args)
+}
+
+fn table_get(ls: &mut dyn LuaAPI) -> usize {
+    ls.get_table(1);
+    1
+}
+
+fn table_set(ls: &mut dyn LuaAPI) -> usize {
+    ls.set_table(1);
+    0
+}
+
+fn table_raw_set(ls: &mut dyn LuaAPI) -> usize {
+    ls.raw_set(1);
+    0
+}
+
+fn table_len(ls: &mut dyn LuaAPI) -> usize {
+    ls.len(1);
+    1
+}
+
+fn table_next(ls: &mut dyn LuaAPI) -> usize {
+    if ls.next(1) {
+        2
+    } else {
+        0
+    }
+}
+
+/*
+    A table held by Rust. `get`, `set` and `len` go through metamethods
+    like the same operations in Lua, and their errors are returned.
+*/
+#[derive(Clone, Debug)]
+pub struct LuaTable {
+    anchor: Anchor,
+}
+
+impl LuaTable {
+    pub fn get<K: IntoLua, V: FromLua>(&self, key: K) -> LuaResult<V> {
+        let mut ls = self.anchor.state();
+        protected(&mut *ls, table_get, (self, key))
+    }
+
+    pub fn set<K: IntoLua, V: IntoLua>(&self, key: K, value: V) -> LuaResult<()> {
+        let mut ls = self.anchor.state();
+        protected(&mut *ls, table_set, (self, key, value))
+    }
+
+    pub fn raw_get<K: IntoLua, V: FromLua>(&self, key: K) -> LuaResult<V> {
+        let mut ls = self.anchor.state();
+        let ls: &mut dyn LuaAPI = &mut *ls;
+        key.check_state(ls)?;
+        self.anchor.push(ls);
+        key.push_into(ls);
+        ls.raw_get(-2);
+        let v = V::from_lua(ls, -1);
+        ls.pop(2);
+        v
+    }
+
+    // Fails for a nil or NaN key.
+    pub fn raw_set<K: IntoLua, V: IntoLua>(&self, key: K, value: V) -> LuaResult<()> {
+        let mut ls = self.anchor.state();
+        protected(&mut *ls, table_raw_set, (self, key, value))
+    }
+
+    // The length, as the `#` operator.
+    pub fn len(&self) -> LuaResult<i64> {
+        let mut ls = self.anchor.state();
+        protected(&mut *ls, table_len, self)
+    }
+
+    // Whether the table has no pairs at all, even if it has a `__len`.
+    pub fn is_empty(&self) -> bool {
+        let mut ls = self.anchor.state();
+        self.anchor.push(&mut *ls);
+        ls.push_nil();
+        let empty = !ls.next(-2);
+        ls.pop(if empty { 1 } else { 3 });
+        empty
+    }
+
+    // Iterate over all the pairs with `next`, without the `__pairs`
+    // metamethod. The table must not get new keys meanwhile.
+    pub fn pairs<K: FromLua, V: FromLua>(&self) -> TablePairs<K, V> {
+        TablePairs { table: self.clone(), key: None, done: false, marker: PhantomData }
+    }
+}
+
+impl IntoLua for &LuaTable {
+    fn push_into(self, ls: &mut dyn LuaAPI) {
+        self.anchor.push(ls);
+    }
+
+    fn check_state(&self, ls: &dyn LuaAPI) -> LuaResult<()> {
+        self.anchor.check_state(ls)
+    }
+}
+
+impl IntoLua for LuaTable {
+    fn push_into(self, ls: &mut dyn LuaAPI) {
+        self.anchor.push(ls);
+    }
+
+    fn check_state(&self, ls: &dyn LuaAPI) -> LuaResult<()> {
+        self.anchor.check_state(ls)
+    }
+}
+
+impl FromLua for LuaTable {
+    fn from_lua(ls: &mut dyn LuaAPI, idx: isize) -> LuaResult<LuaTable> {
+        Anchor::new(ls, idx, LUA_TTABLE, "LuaTable").map(|anchor| LuaTable { anchor })
+    }
+}
+
+// Iterator of `LuaTable::pairs`, it ends after the first error of `next`.
+pub struct TablePairs<K, V> {
+    table: LuaTable,
+    key: Option<Anchor>, // the last key
+    done: bool,
+    marker: PhantomData<(K, V)>,
+}
+
+impl<K: FromLua, V: FromLua> Iterator for TablePairs<K, V> {
+    type Item = LuaResult<(K, V)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut ls = self.table.anchor.state();
+        let ls: &mut dyn LuaAPI = &mut *ls;
+        let top = ls.get_top();
+        ls.push_rust_fn(table_next);
+        self.table.anchor.push(ls);
+        match &self.key {
+            Some(key) => key.push(ls),
+            None => ls.push_nil(),
+        }
+        let status = ls.pcall(2, 2, 0);
+        if status != LUA_OK {
+            let message = ls.to_string(-1);
+            ls.set_top(top);
+            self.done = true;
+            return Some(Err(LuaError::Call { status, message }));
+        }
+        if ls.is_nil(-2) {
+            ls.set_top(top);
+            self.done = true;
+            return None;
+        }
+        match &mut self.key {
+            Some(key) => {
+                ls.unref(LUA_REGISTRY_INDEX, key.r);
+                ls.push_value(-2);
+                key.r = ls.ref_(LUA_REGISTRY_INDEX);
+            }
+            None => self.key = Some(Anchor::with_state(ls, -2, self.table.anchor.state.clone())),
+        }
+        let pair = K::from_lua(ls, -2).and_then(|k| V::from_lua(ls, -1).map(|v| (k, v)));
+        ls.set_top(top);
+        Some(pair)
+    }
+}
+
+// A function held by Rust.
+#[derive(Clone, Debug)]
+pub struct LuaFunction {
+    anchor: Anchor,
+}
+
+impl LuaFunction {
+    // Call the function in protected mode, see `Function::call`.
+    pub fn call<A: IntoLuaMulti, R: FromLuaMulti>(&self, args: A) -> LuaResult<R> {
+        let mut ls = self.anchor.state();
+        let ls: &mut dyn LuaAPI = &mut *ls;
+        self.anchor.push(ls);
+        call_top(ls, args)
+    }
+}
+
+impl IntoLua for &LuaFunction {
+    fn push_into(self, ls: &mut dyn LuaAPI) {
+        self.anchor.push(ls);
+    }
+
+    fn check_state(&self, ls: &dyn LuaAPI) -> LuaResult<()> {
+        self.anchor.check_state(ls)
+    }
+}
+
+impl IntoLua for LuaFunction {
+    fn push_into(self, ls: &mut dyn LuaAPI) {
+        self.anchor.push(ls);
+    }
+
+    fn check_state(&self, ls: &dyn LuaAPI) -> LuaResult<()> {
+        self.anchor.check_state(ls)
+    }
+}
+
+impl FromLua for LuaFunction {
+    fn from_lua(ls: &mut dyn LuaAPI, idx: isize) -> LuaResult<LuaFunction> {
+        Anchor::new(ls, idx, LUA_TFUNCTION, "LuaFunction").map(|anchor| LuaFunction { anchor })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lua;
+
+    #[test]
+    fn test_table_handle() {
+        let lua = Lua::new();
+        let g = lua.globals();
+        g.set("x", 5).unwrap();
+        assert_eq!(g.get::<_, i64>("x"), Ok(5));
+        assert_eq!(g.raw_get::<_, Option<i64>>("y"), Ok(None));
+
+        let t = lua.create_table();
+        assert!(t.is_empty());
+        t.set(1, "a").unwrap();
+        t.raw_set(2, "b").unwrap();
+        t.set("k", "v").unwrap();
+        assert_eq!(t.len(), Ok(2));
+        assert!(!t.is_empty());
+        let mut pairs: Vec<(String, String)> = t.pairs().collect::<LuaResult<_>>().unwrap();
+        pairs.sort();
+        let expected = [("1", "a"), ("2", "b"), ("k", "v")];
+        assert_eq!(pairs, expected.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect::<Vec<_>>());
+        let ints: Vec<LuaResult<(i64, String)>> = t.pairs().collect();
+        assert_eq!(ints.len(), 3);
+        assert_eq!(ints.iter().filter(|p| p.is_err()).count(), 1);
+        let err = t.raw_set(None::<i64>, 1).unwrap_err();
+        assert_eq!(err, LuaError::Call { status: LUA_ERRRUN, message: "table index is nil".to_string() });
+
+        // metamethods are used, and their errors returned
+        let mt = lua.create_table();
+        let string: LuaTable = g.get("string").unwrap();
+        mt.set("__index", string.get::<_, LuaFunction>("rep").unwrap()).unwrap();
+        let setmetatable: LuaFunction = g.get("setmetatable").unwrap();
+        setmetatable.call::<_, ()>((&t, &mt)).unwrap();
+        assert!(t.get::<_, String>("missing").is_err());
+        assert_eq!(t.raw_get::<_, Option<String>>("missing"), Ok(None));
+        mt.set("__index", string).unwrap();
+        assert_eq!(t.get::<_, LuaFunction>("upper").unwrap().call::<_, String>("x"), Ok("X".to_string()));
+
+        // handles outlive the stack and are usable from Lua
+        let type_fn: LuaFunction = g.get("type").unwrap();
+        assert_eq!(type_fn.call::<_, String>(&t), Ok("table".to_string()));
+        assert_eq!(lua.state().get_top(), 0);
+        assert!(LuaTable::from_lua(&mut *lua.state(), 1).is_err());
+    }
+
+    #[test]
+    fn test_handle_release() {
+        let lua = Lua::new_bare();
+        let t = lua.create_table();
+        let r = t.anchor.r;
+        let t2 = t.clone();
+        assert_ne!(t2.anchor.r, r);
+        drop(t);
+        // released references are reused
+        let t3 = lua.create_table();
+        assert_eq!(t3.anchor.r, r);
+
+        // handles dropped while the state is borrowed are released later
+        {
+            let ls = lua.state();
+            drop(t3);
+            assert_eq!(*ls.dropped_refs.borrow(), vec![r]);
+        }
+        assert_eq!(t2.len(), Ok(0));
+        assert!(lua.state().dropped_refs.borrow().is_empty());
+        assert_eq!(lua.create_table().anchor.r, r);
+        let mut ls = lua.state();
+        ls.raw_get_i(LUA_REGISTRY_INDEX, t2.anchor.r);
+        assert!(ls.is_table(-1));
+    }
+
+    #[test]
+    fn test_foreign_handle() {
+        let (lua1, lua2) = (Lua::new(), Lua::new());
+        let t = lua1.create_table();
+        let g2 = lua2.globals();
+        assert_eq!(g2.set("foreign", &t), Err(LuaError::ForeignHandle));
+        assert_eq!(g2.set("foreign", vec![Some(t.clone())]), Err(LuaError::ForeignHandle));
+        assert_eq!(g2.raw_get::<_, Option<i64>>(&t), Err(LuaError::ForeignHandle));
+        let print: LuaFunction = lua2.globals().get("print").unwrap();
+        assert_eq!(lua1.globals().set("p", print), Err(LuaError::ForeignHandle));
+        assert_eq!(lua2.state().get_top(), 0);
+        assert_eq!(g2.get::<_, Option<LuaTable>>("foreign").map(|t| t.is_none()), Ok(true));
+        g2.set("own", &g2).unwrap();
+    }
+}
@@ -0,0 +1,50 @@
+/*
+    A Lua 5.3 virtual machine to embed in Rust programs. It runs chunks
+    precompiled by `luac`, since there is no compiler.
+
+        let lua = Lua::new();
+        let mut ls = lua.state();
+        ls.load(chunk, "=script", "b", None);
+        ls.pcall(0, 0, 0);
+
+    The public surface is the `Lua` handle, the `LuaAPI` and `LuaAuxLib`
+    traits with their constants and types in `api`, the conversions of
+    Rust values (`IntoLua`, `FromLua`) used to call a `Function`, the
+    `LuaTable` and `LuaFunction` handles that Rust can keep, the
+    `UserData` trait to give Rust types to scripts as objects, the serde
+    conversions `to_lua` and `from_lua` (feature "serde"),
+    `binary::undump` with the prototypes it returns, the instruction
+    decoding (`Instruction`, `opcodes`) for disassemblers, and the
+    library openers in `stdlib`. How values are represented inside the
+    state is deliberately not public, they are only reached through the
+    stack of the API; `LuaState` is exported as the type implementing
+    it, with its limits set through `set_limits`.
+*/
+pub mod api;
+pub mod binary;
+mod convert;
+mod error;
+mod function;
+mod handle;
+mod lua;
+mod number;
+#[cfg(feature = "serde")]
+mod serialize;
+mod state;
+pub mod stdlib;
+mod userdata;
+mod vm;
+
+pub use crate::api::{LuaAPI, LuaAuxLib};
+pub use crate::binary::undump;
+pub use crate::convert::{FromLua, FromLuaMulti, IntoLua, IntoLuaMulti, Variadic};
+pub use crate::error::{LuaError, LuaResult};
+pub use crate::function::Function;
+pub use crate::handle::{LuaFunction, LuaTable, TablePairs};
+pub use crate::lua::Lua;
+#[cfg(feature = "serde")]
+pub use crate::serialize::{from_lua, from_lua_with, to_lua, EmptyTable, SerdeOptions};
+pub use crate::state::LuaState;
+pub use crate::userdata::{push_userdata, UserData, UserDataFields, UserDataMethods};
+pub use crate::vm::instruction::Instruction;
+pub use crate::vm::opcodes;
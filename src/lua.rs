@@ -0,0 +1,89 @@
+use crate::api::LuaAPI;
+use crate::convert::FromLua;
+use crate::handle::LuaTable;
+use crate::state::LuaState;
+use crate::stdlib::open_libs;
+use std::cell::{RefCell, RefMut};
+use std::rc::Rc;
+
+/*
+    Owner of a Lua state. The state keeps a weak reference to itself to
+    create the frames of calls, so it must live in an `Rc`, which this
+    handle sets up:
+        let lua = Lua::new();
+        lua.state().push_integer(1);
+*/
+pub struct Lua {
+    state: Rc<RefCell<LuaState>>,
+}
+
+impl Lua {
+    // A state with the libraries of `open_libs`, which cannot reach
+    // outside the interpreter.
+    pub fn new() -> Lua {
+        let lua = Lua::new_bare();
+        open_libs(&mut *lua.state());
+        lua
+    }
+
+    // A state without any library, not even the base functions.
+    pub fn new_bare() -> Lua {
+        let state = Rc::new(RefCell::new(LuaState::new()));
+        state.borrow_mut().stack_mut().state = Some(Rc::downgrade(&state));
+        Lua { state }
+    }
+
+    // Borrow the state to use the API. Only one borrow may be alive at a
+    // time, the Rust functions called by scripts get the state as their
+    // argument instead.
+    pub fn state(&self) -> RefMut<'_, LuaState> {
+        self.state.borrow_mut()
+    }
+
+    // The global table.
+    pub fn globals(&self) -> LuaTable {
+        let mut ls = self.state();
+        ls.push_global_table();
+        pop_table(&mut *ls)
+    }
+
+    pub fn create_table(&self) -> LuaTable {
+        let mut ls = self.state();
+        ls.new_table();
+        pop_table(&mut *ls)
+    }
+}
+
+fn pop_table(ls: &mut dyn LuaAPI) -> LuaTable {
+    let t = LuaTable::from_lua(ls, -1).unwrap(); // the state is held by an Rc
+    ls.pop(1);
+    t
+}
+
+impl Default for Lua {
+    fn default() -> Lua {
+        Lua::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::consts::*;
+    use crate::binary::reader::tests::LUA_FOR_LOOP;
+
+    #[test]
+    fn test_lua_handle() {
+        let lua = Lua::new();
+        let mut ls = lua.state();
+        assert_eq!(ls.get_global("string"), LUA_TTABLE);
+        assert_eq!(ls.get_global("io"), LUA_TNIL);
+        ls.pop(2);
+        ls.load(LUA_FOR_LOOP.to_vec(), "=loop", "b", None);
+        assert_eq!(ls.pcall(0, 0, 0), LUA_OK, "{}", ls.to_string(-1));
+        drop(ls);
+
+        let lua = Lua::new_bare();
+        assert_eq!(lua.state().get_global("print"), LUA_TNIL);
+    }
+}
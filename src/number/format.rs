@@ -0,0 +1,181 @@
+// Float to string conversion the way Lua does it: `LUAI_NUMFFORMAT` is
+// "%.14g", and ".0" is appended when the result looks like an integer.
+pub fn float_to_string(n: f64) -> String {
+    let s = fmt_g(n, 14, false, false);
+    if s.bytes().all(|b| b == b'-' || b.is_ascii_digit()) {
+        s + ".0"
+    } else {
+        s
+    }
+}
+
+// C `%.{prec}e`, `alt` is the `#` flag.
+pub fn fmt_e(n: f64, prec: usize, upper: bool, alt: bool) -> String {
+    if !n.is_finite() {
+        return fmt_special(n, upper);
+    }
+    let s = format!("{:.*e}", prec, n);
+    let (mantissa, exp) = s.split_at(s.find('e').unwrap());
+    let exp: i32 = exp[1..].parse().unwrap();
+    let mut s = mantissa.to_string();
+    if alt && prec == 0 {
+        s.push('.');
+    }
+    s.push(if upper { 'E' } else { 'e' });
+    s.push(if exp < 0 { '-' } else { '+' });
+    s.push_str(&format!("{:02}", exp.abs()));
+    s
+}
+
+// C `%.{prec}f`, `alt` is the `#` flag.
+pub fn fmt_f(n: f64, prec: usize, upper: bool, alt: bool) -> String {
+    if !n.is_finite() {
+        return fmt_special(n, upper);
+    }
+    let mut s = format!("{:.*}", prec, n);
+    if alt && prec == 0 {
+        s.push('.');
+    }
+    s
+}
+
+// C `%.{prec}g`, `alt` is the `#` flag.
+pub fn fmt_g(n: f64, prec: usize, upper: bool, alt: bool) -> String {
+    if !n.is_finite() {
+        return fmt_special(n, upper);
+    }
+    let p = prec.max(1);
+    // the exponent `n` would have in `%e` style decides the notation
+    let s = format!("{:.*e}", p - 1, n);
+    let x: i32 = s[s.find('e').unwrap() + 1..].parse().unwrap();
+    let s = if x < -4 || x >= p as i32 {
+        fmt_e(n, p - 1, upper, alt)
+    } else {
+        fmt_f(n, (p as i32 - 1 - x) as usize, upper, alt)
+    };
+    if alt {
+        s
+    } else {
+        strip_zeros(s)
+    }
+}
+
+// C `%a`, exact hexadecimal digits unless a precision is given.
+pub fn fmt_a(n: f64, prec: Option<usize>, upper: bool, alt: bool) -> String {
+    if !n.is_finite() {
+        return fmt_special(n, upper);
+    }
+    let bits = n.to_bits();
+    let biased = ((bits >> 52) & 0x7FF) as i32;
+    let mut mant = bits & ((1 << 52) - 1);
+    let (mut lead, exp) = match (biased, mant) {
+        (0, 0) => (0, 0),        // zero
+        (0, _) => (0, -1022),    // subnormal
+        _ => (1, biased - 1023), // normal
+    };
+    let digits = match prec {
+        None => {
+            let s = format!("{:013x}", mant);
+            s.trim_end_matches('0').to_string()
+        }
+        Some(p) if p < 13 => {
+            // round half to even on the dropped bits
+            let shift = (13 - p) * 4;
+            let rem = mant & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            mant >>= shift;
+            let odd = if p == 0 { lead & 1 == 1 } else { mant & 1 == 1 };
+            if rem > half || (rem == half && odd) {
+                mant += 1;
+                if mant >> (p * 4) != 0 {
+                    mant = 0;
+                    lead += 1;
+                }
+            }
+            if p == 0 {
+                String::new()
+            } else {
+                format!("{:0width$x}", mant, width = p)
+            }
+        }
+        Some(p) => format!("{:013x}{}", mant, "0".repeat(p - 13)),
+    };
+    let point = if digits.is_empty() && !alt { "" } else { "." };
+    let sign = if n.is_sign_negative() { "-" } else { "" };
+    let s = format!("{}0x{}{}{}p{:+}", sign, lead, point, digits, exp);
+    if upper {
+        s.to_uppercase()
+    } else {
+        s
+    }
+}
+
+// Remove trailing zeros of the fraction (and the point if nothing is left).
+fn strip_zeros(s: String) -> String {
+    let (num, exp) = match s.find(['e', 'E']) {
+        Some(i) => s.split_at(i),
+        None => (s.as_str(), ""),
+    };
+    if !num.contains('.') {
+        return s;
+    }
+    let num = num.trim_end_matches('0').trim_end_matches('.');
+    format!("{}{}", num, exp)
+}
+
+fn fmt_special(n: f64, upper: bool) -> String {
+    let s = if n.is_nan() {
+        if n.is_sign_negative() {
+            "-nan"
+        } else {
+            "nan"
+        }
+    } else if n > 0.0 {
+        "inf"
+    } else {
+        "-inf"
+    };
+    if upper {
+        s.to_uppercase()
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float2string() {
+        assert_eq!(float_to_string(3.0), "3.0");
+        assert_eq!(float_to_string(-0.5), "-0.5");
+        assert_eq!(float_to_string(0.1), "0.1");
+        assert_eq!(float_to_string(1e15), "1e+15");
+        assert_eq!(float_to_string(1e100), "1e+100");
+        assert_eq!(float_to_string(123456789012345.0), "1.2345678901234e+14");
+        assert_eq!(float_to_string(2f64.powi(53)), "9.007199254741e+15");
+        assert_eq!(float_to_string(1.0 / 3.0), "0.33333333333333");
+        assert_eq!(float_to_string(0.0001), "0.0001");
+        assert_eq!(float_to_string(0.00001), "1e-05");
+        assert_eq!(float_to_string(f64::INFINITY), "inf");
+        assert_eq!(float_to_string(f64::NEG_INFINITY), "-inf");
+    }
+
+    #[test]
+    fn printf_styles() {
+        assert_eq!(fmt_e(12345.678, 3, false, false), "1.235e+04");
+        assert_eq!(fmt_e(0.0, 0, true, true), "0.E+00");
+        assert_eq!(fmt_f(2.5, 0, false, false), "2");
+        assert_eq!(fmt_f(1.0 / 3.0, 4, false, false), "0.3333");
+        assert_eq!(fmt_g(100000.0, 6, false, false), "100000");
+        assert_eq!(fmt_g(1000000.0, 6, false, false), "1e+06");
+        assert_eq!(fmt_g(1.5, 6, false, true), "1.50000");
+        assert_eq!(fmt_g(0.0, 0, false, false), "0");
+        assert_eq!(fmt_a(1.0, None, false, false), "0x1p+0");
+        assert_eq!(fmt_a(-0.1, None, false, false), "-0x1.999999999999ap-4");
+        assert_eq!(fmt_a(0.0, Some(2), true, false), "0X0.00P+0");
+        assert_eq!(fmt_a(1.5, Some(0), false, false), "0x2p+0");
+        assert_eq!(fmt_a(f64::MIN_POSITIVE / 2.0, None, false, false), "0x0.8p-1022");
+    }
+}
@@ -1,12 +1,19 @@
-// Not of high quality, and the result is always even. more detail:
-// https://users.rust-lang.org/t/random-number-without-using-the-external-crate/17260/8
+use super::random::Xoshiro256;
+use std::cell::RefCell;
+
+thread_local! {
+    static RNG: RefCell<Xoshiro256> = RefCell::new(Xoshiro256::from_entropy());
+}
+
+// A random word from a per-thread xoshiro256** generator, for the names of os.tmpname.
 pub fn random() -> usize {
-    let ptr = Box::into_raw(Box::new(123));
-    ptr as usize
+    RNG.with(|rng| rng.borrow_mut().next_u64() as usize)
 }
 
 pub fn ifloor_div(a: i64, b: i64) -> i64 {
-    if a > 0 && b > 0 || a < 0 && b < 0 || a % b == 0 {
+    if b == -1 {
+        a.wrapping_neg() // avoid overflow with `i64::MIN // -1`
+    } else if a > 0 && b > 0 || a < 0 && b < 0 || a % b == 0 {
         a / b
     } else {
         a / b - 1
@@ -18,7 +25,11 @@ pub fn ffloor_div(a: f64, b: f64) -> f64 {
 }
 
 pub fn imod(a: i64, b: i64) -> i64 {
-    a - ifloor_div(a, b) * b
+    if b == -1 {
+        0 // avoid overflow with `i64::MIN % -1`
+    } else {
+        a - ifloor_div(a, b) * b
+    }
 }
 
 pub fn fmod(a: f64, b: f64) -> f64 {
@@ -60,6 +71,10 @@ pub fn shift_right(a: i64, n: i64) -> i64 {
 }
 
 pub fn float_to_integer(n: f64) -> Option<i64> {
+    // `as` saturates, so 2^63 must be rejected before the round trip check
+    if !(n >= i64::MIN as f64 && n < -(i64::MIN as f64)) {
+        return None;
+    }
     let i = n as i64;
     if i as f64 == n {
         Some(i)
@@ -78,6 +93,7 @@ mod tests {
         assert_eq!(imod(-5, 3), 1);
         assert_eq!(imod(-2, 3), 1);
         assert_eq!(imod(2, -3), -1);
+        assert_eq!(imod(i64::MIN, -1), 0);
         assert_eq!(fmod(5.0, -3.0), -1.0);
         assert_eq!(fmod(-5.0, -3.0), -2.0);
     }
@@ -86,6 +102,7 @@ mod tests {
     fn floor_div() {
         assert_eq!(ifloor_div(5, 3), 1);
         assert_eq!(ifloor_div(-5, 3), -2);
+        assert_eq!(ifloor_div(i64::MIN, -1), i64::MIN);
         assert_eq!(ffloor_div(5.0, -3.0), -2.0);
         assert_eq!(ffloor_div(-5.0, -3.0), 1.0);
     }
@@ -108,5 +125,8 @@ mod tests {
         assert_eq!(float_to_integer(99.9), None);
         assert_eq!(float_to_integer(-99.0), Some(-99));
         assert_eq!(float_to_integer(-99.9), None);
+        assert_eq!(float_to_integer(9223372036854775808.0), None);
+        assert_eq!(float_to_integer(-9223372036854775808.0), Some(i64::MIN));
+        assert_eq!(float_to_integer(f64::NAN), None);
     }
 }
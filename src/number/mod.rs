@@ -1 +1,4 @@
-pub mod math;
\ No newline at end of file
+pub mod format;
+pub mod math;
+pub mod parser;
+pub mod random;
@@ -0,0 +1,138 @@
+// Lua numerals: decimal or hexadecimal, with surrounding spaces allowed.
+
+// Integer numeral, hexadecimal ones wrap around, decimal ones that do not
+// fit in 64 bits are rejected (they are read as floats instead).
+pub fn parse_integer(s: &str) -> Option<i64> {
+    let s = trim(s);
+    let (neg, s) = split_sign(s);
+    if let Some(hex) = strip_hex_prefix(s) {
+        if hex.is_empty() {
+            return None;
+        }
+        let mut n: u64 = 0;
+        for c in hex.chars() {
+            n = n.wrapping_mul(16).wrapping_add(c.to_digit(16)? as u64);
+        }
+        let n = n as i64;
+        Some(if neg { n.wrapping_neg() } else { n })
+    } else {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let n: u64 = s.parse().ok()?;
+        let max = if neg { i64::MAX as u64 + 1 } else { i64::MAX as u64 };
+        if n > max {
+            None
+        } else if neg {
+            Some((n as i64).wrapping_neg())
+        } else {
+            Some(n as i64)
+        }
+    }
+}
+
+// Float numeral, including hexadecimal ones with a binary exponent.
+pub fn parse_float(s: &str) -> Option<f64> {
+    let s = trim(s);
+    let (neg, body) = split_sign(s);
+    let n = match strip_hex_prefix(body) {
+        Some(hex) => parse_hex_float(hex)?,
+        None => {
+            let valid = body.bytes().all(|b| b.is_ascii_digit() || b"+-.eE".contains(&b));
+            let starts_well = body.starts_with(|c: char| c.is_ascii_digit() || c == '.');
+            if !valid || !starts_well || !body.bytes().any(|b| b.is_ascii_digit()) {
+                return None; // rejects "inf", "nan", ...
+            }
+            body.parse::<f64>().ok()?
+        }
+    };
+    Some(if neg { -n } else { n })
+}
+
+// "A.Bp-C" without the "0x" prefix, see `lua_strx2number` in lobject.c
+fn parse_hex_float(s: &str) -> Option<f64> {
+    let (mantissa, exp) = match s.find(['p', 'P']) {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+    let (int_part, frac_part) = match mantissa.find('.') {
+        Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+        None => (mantissa, ""),
+    };
+    if int_part.is_empty() && frac_part.is_empty() {
+        return None; // invalid format (no digits)
+    }
+    let mut n = 0.0;
+    for c in int_part.chars() {
+        n = n * 16.0 + c.to_digit(16)? as f64;
+    }
+    let mut e: i32 = 0;
+    for c in frac_part.chars() {
+        n = n * 16.0 + c.to_digit(16)? as f64;
+        e -= 4; // each fractional digit is 4 bits
+    }
+    if let Some(exp) = exp {
+        let (neg, digits) = split_sign(exp);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let x: i32 = digits.parse().unwrap_or(i32::MAX);
+        e = e.saturating_add(if neg { -x } else { x });
+    }
+    Some(n * 2f64.powi(e))
+}
+
+fn trim(s: &str) -> &str {
+    s.trim_matches(|c| " \x0c\n\r\t\x0b".contains(c))
+}
+
+fn split_sign(s: &str) -> (bool, &str) {
+    if let Some(rest) = s.strip_prefix('-') {
+        (true, rest)
+    } else {
+        (false, s.strip_prefix('+').unwrap_or(s))
+    }
+}
+
+fn strip_hex_prefix(s: &str) -> Option<&str> {
+    s.strip_prefix("0x").or_else(|| s.strip_prefix("0X"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(parse_integer("10"), Some(10));
+        assert_eq!(parse_integer("  -7\n"), Some(-7));
+        assert_eq!(parse_integer("0xff"), Some(255));
+        assert_eq!(parse_integer("-0X10"), Some(-16));
+        assert_eq!(parse_integer("0xffffffffffffffff"), Some(-1));
+        assert_eq!(parse_integer("9223372036854775807"), Some(i64::MAX));
+        assert_eq!(parse_integer("-9223372036854775808"), Some(i64::MIN));
+        assert_eq!(parse_integer("9223372036854775808"), None);
+        assert_eq!(parse_integer("1.0"), None);
+        assert_eq!(parse_integer("0x"), None);
+        assert_eq!(parse_integer(""), None);
+        assert_eq!(parse_integer("1 2"), None);
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(parse_float("1.5"), Some(1.5));
+        assert_eq!(parse_float(" .5 "), Some(0.5));
+        assert_eq!(parse_float("5."), Some(5.0));
+        assert_eq!(parse_float("1e2"), Some(100.0));
+        assert_eq!(parse_float("-2E-1"), Some(-0.2));
+        assert_eq!(parse_float("0x1p4"), Some(16.0));
+        assert_eq!(parse_float("0xA.8"), Some(10.5));
+        assert_eq!(parse_float("0x.1P-2"), Some(0.015625));
+        assert_eq!(parse_float("inf"), None);
+        assert_eq!(parse_float("nan"), None);
+        assert_eq!(parse_float("1e"), None);
+        assert_eq!(parse_float("."), None);
+        assert_eq!(parse_float("0x"), None);
+        assert_eq!(parse_float("+-1"), None);
+    }
+}
@@ -0,0 +1,101 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/*
+    xoshiro256** pseudo-random number generator, the same algorithm
+    Lua 5.4 uses for `math.random`. more detail:
+    http://prng.di.unimi.it/xoshiro256starstar.c
+*/
+#[derive(Clone, Debug)]
+pub struct Xoshiro256 {
+    s: [u64; 4],
+}
+
+impl Xoshiro256 {
+    // Seed the generator the way `math.randomseed(n1, n2)` does in Lua 5.4,
+    // so the same pair of seeds always yields the same sequence.
+    pub fn with_seeds(n1: u64, n2: u64) -> Xoshiro256 {
+        let mut rng = Xoshiro256 {
+            s: [n1, 0xff, n2, 0], // avoid a zero state
+        };
+        for _ in 0..16 {
+            rng.next_u64(); // discard initial values to "spread" seed
+        }
+        rng
+    }
+
+    // Seed from the current time and an address, for states that were
+    // not given an explicit seed.
+    pub fn from_entropy() -> Xoshiro256 {
+        let time = match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(d) => d.as_secs() ^ (d.subsec_nanos() as u64) << 32,
+            Err(_) => 0,
+        };
+        let local = 0u8;
+        let addr = &local as *const u8 as u64;
+        Xoshiro256::with_seeds(time, addr)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    // A float uniformly distributed in [0, 1), built from the 53 higher bits.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (0.5 / (1u64 << 52) as f64)
+    }
+
+    // A random integer uniformly distributed in [0, n].
+    pub fn project(&mut self, n: u64) -> u64 {
+        let mut ran = self.next_u64();
+        if n & n.wrapping_add(1) == 0 {
+            // is 'n + 1' a power of 2?
+            return ran & n; // no bias
+        }
+        // compute the smallest (2^b - 1) not smaller than n
+        let lim = u64::MAX >> n.leading_zeros();
+        loop {
+            ran &= lim; // project 'ran' into [0, lim]
+            if ran <= n {
+                return ran;
+            }
+            ran = self.next_u64(); // not inside [0, n]? Try again
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xoshiro() {
+        // reference values from the C implementation with state {1, 2, 3, 4}
+        let mut rng = Xoshiro256 { s: [1, 2, 3, 4] };
+        assert_eq!(rng.next_u64(), 11520);
+        assert_eq!(rng.next_u64(), 0);
+        assert_eq!(rng.next_u64(), 1509978240);
+        assert_eq!(rng.next_u64(), 1215971899390074240);
+
+        let mut a = Xoshiro256::with_seeds(42, 0);
+        let mut b = Xoshiro256::with_seeds(42, 0);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+            let f = a.next_f64();
+            assert!((0.0..1.0).contains(&f));
+            assert_eq!(f, b.next_f64());
+            assert!(a.project(9) <= 9);
+            assert!(b.project(9) <= 9);
+        }
+        assert_ne!(Xoshiro256::with_seeds(1, 0).next_u64(), Xoshiro256::with_seeds(2, 0).next_u64());
+        assert_eq!(Xoshiro256::with_seeds(7, 0).project(0), 0);
+    }
+}
@@ -0,0 +1,907 @@
+use crate::api::consts::*;
+use crate::api::LuaAPI;
+use crate::error::{LuaError, LuaResult};
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::ser::{self, Serialize};
+use std::ffi::c_void;
+use std::fmt;
+
+/*
+    Conversions between serde and Lua values. Structs and maps are tables
+    with their fields as keys, sequences and tuples are tables indexed
+    from 1, `None` and `()` are nil, and enums are tagged: a unit variant
+    is its name, the others a table `{Variant = value}`.
+
+        to_lua(ls, &config)?;                 // pushes one value
+        let config: Config = from_lua(ls, -1)?;
+
+    Tables are read without metamethods. A table met again while it is
+    being read is a cycle, which is an error rather than an endless
+    recursion.
+*/
+
+// How an empty table is read when the Rust type does not tell, like a
+// `serde_json::Value`: `{}` cannot be told from `[]` in Lua.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EmptyTable {
+    Array,
+    Map,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SerdeOptions {
+    pub empty_table: EmptyTable,
+}
+
+impl Default for SerdeOptions {
+    fn default() -> SerdeOptions {
+        SerdeOptions { empty_table: EmptyTable::Map }
+    }
+}
+
+impl ser::Error for LuaError {
+    fn custom<T: fmt::Display>(msg: T) -> LuaError {
+        LuaError::Serde { message: msg.to_string() }
+    }
+}
+
+impl de::Error for LuaError {
+    fn custom<T: fmt::Display>(msg: T) -> LuaError {
+        LuaError::Serde { message: msg.to_string() }
+    }
+}
+
+fn serde_error<T>(message: String) -> LuaResult<T> {
+    Err(LuaError::Serde { message })
+}
+
+// Push `value` as one Lua value. Nothing is pushed on error.
+pub fn to_lua<T: Serialize + ?Sized>(ls: &mut dyn LuaAPI, value: &T) -> LuaResult<()> {
+    let top = ls.get_top();
+    let r = value.serialize(&mut Serializer { ls: &mut *ls });
+    if r.is_err() {
+        ls.set_top(top);
+    }
+    r
+}
+
+// Read the value at `idx`, which is left on the stack.
+pub fn from_lua<T: DeserializeOwned>(ls: &mut dyn LuaAPI, idx: isize) -> LuaResult<T> {
+    from_lua_with(ls, idx, SerdeOptions::default())
+}
+
+pub fn from_lua_with<T: DeserializeOwned>(ls: &mut dyn LuaAPI, idx: isize, options: SerdeOptions) -> LuaResult<T> {
+    let top = ls.get_top();
+    let idx = ls.abs_index(idx);
+    let r = T::deserialize(&mut Deserializer {
+        ls: &mut *ls,
+        idx,
+        options,
+        visiting: Vec::new(),
+    });
+    ls.set_top(top);
+    r
+}
+
+/* serializer */
+
+struct Serializer<'a> {
+    ls: &'a mut dyn LuaAPI,
+}
+
+impl<'a> Serializer<'a> {
+    // Room for a table, a key and a value.
+    fn reserve(&mut self) -> LuaResult<()> {
+        if self.ls.check_stack(3) {
+            Ok(())
+        } else {
+            serde_error("value too deeply nested".to_string())
+        }
+    }
+
+    // The table for a variant other than a unit one, `{variant = ...}`
+    // with the value to push still missing.
+    fn push_variant(&mut self, variant: &str) -> LuaResult<()> {
+        self.reserve()?;
+        self.ls.create_table(0, 1);
+        self.ls.push_string(variant.to_string());
+        Ok(())
+    }
+
+    // Set the variant of `push_variant` to the value on the top.
+    fn end_variant(&mut self) {
+        self.ls.raw_set(-3);
+    }
+}
+
+impl<'s, 'a> ser::Serializer for &'s mut Serializer<'a> {
+    type Ok = ();
+    type Error = LuaError;
+    type SerializeSeq = SerializeTable<'s, 'a>;
+    type SerializeTuple = SerializeTable<'s, 'a>;
+    type SerializeTupleStruct = SerializeTable<'s, 'a>;
+    type SerializeTupleVariant = SerializeTable<'s, 'a>;
+    type SerializeMap = SerializeTable<'s, 'a>;
+    type SerializeStruct = SerializeTable<'s, 'a>;
+    type SerializeStructVariant = SerializeTable<'s, 'a>;
+
+    fn serialize_bool(self, v: bool) -> LuaResult<()> {
+        self.ls.push_boolean(v);
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> LuaResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> LuaResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> LuaResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> LuaResult<()> {
+        self.ls.push_integer(v);
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> LuaResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u16(self, v: u16) -> LuaResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u32(self, v: u32) -> LuaResult<()> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u64(self, v: u64) -> LuaResult<()> {
+        if v > i64::MAX as u64 {
+            self.ls.push_number(v as f64); // too large for an integer
+        } else {
+            self.ls.push_integer(v as i64);
+        }
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> LuaResult<()> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> LuaResult<()> {
+        self.ls.push_number(v);
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> LuaResult<()> {
+        self.ls.push_string(v.to_string());
+        Ok(())
+    }
+
+    fn serialize_str(self, v: &str) -> LuaResult<()> {
+        self.ls.push_string(v.to_string());
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> LuaResult<()> {
+        self.ls.push_bytes(v.to_vec());
+        Ok(())
+    }
+
+    fn serialize_none(self) -> LuaResult<()> {
+        self.ls.push_nil();
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> LuaResult<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> LuaResult<()> {
+        self.ls.push_nil();
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> LuaResult<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> LuaResult<()> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> LuaResult<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> LuaResult<()> {
+        self.push_variant(variant)?;
+        value.serialize(&mut *self)?;
+        self.end_variant();
+        Ok(())
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> LuaResult<SerializeTable<'s, 'a>> {
+        self.reserve()?;
+        self.ls.create_table(len.unwrap_or(0), 0);
+        Ok(SerializeTable { ser: self, n: 0, variant: false })
+    }
+
+    fn serialize_tuple(self, len: usize) -> LuaResult<SerializeTable<'s, 'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> LuaResult<SerializeTable<'s, 'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> LuaResult<SerializeTable<'s, 'a>> {
+        self.push_variant(variant)?;
+        self.ls.create_table(len, 0);
+        Ok(SerializeTable { ser: self, n: 0, variant: true })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> LuaResult<SerializeTable<'s, 'a>> {
+        self.reserve()?;
+        self.ls.create_table(0, len.unwrap_or(0));
+        Ok(SerializeTable { ser: self, n: 0, variant: false })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> LuaResult<SerializeTable<'s, 'a>> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> LuaResult<SerializeTable<'s, 'a>> {
+        self.push_variant(variant)?;
+        self.ls.create_table(0, len);
+        Ok(SerializeTable { ser: self, n: 0, variant: true })
+    }
+}
+
+// A table being filled, on the top of the stack. Within a variant, the
+// table of `push_variant` and the variant name are below it.
+struct SerializeTable<'s, 'a> {
+    ser: &'s mut Serializer<'a>,
+    n: i64, // elements of a sequence so far
+    variant: bool,
+}
+
+impl<'s, 'a> SerializeTable<'s, 'a> {
+    fn push_element<T: Serialize + ?Sized>(&mut self, value: &T) -> LuaResult<()> {
+        value.serialize(&mut *self.ser)?;
+        self.n += 1;
+        self.ser.ls.raw_set_i(-2, self.n);
+        Ok(())
+    }
+
+    // Set the table to the pair on its top, unless the key is not one.
+    fn set_pair(&mut self) -> LuaResult<()> {
+        let ls = &mut *self.ser.ls;
+        if ls.is_nil(-2) {
+            return serde_error("map key is nil".to_string());
+        }
+        if ls.type_id(-2) == LUA_TNUMBER && !ls.is_integer(-2) && ls.to_number(-2).is_nan() {
+            return serde_error("map key is NaN".to_string());
+        }
+        ls.raw_set(-3);
+        Ok(())
+    }
+
+    fn end(self) -> LuaResult<()> {
+        if self.variant {
+            self.ser.end_variant();
+        }
+        Ok(())
+    }
+}
+
+impl<'s, 'a> ser::SerializeSeq for SerializeTable<'s, 'a> {
+    type Ok = ();
+    type Error = LuaError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> LuaResult<()> {
+        self.push_element(value)
+    }
+
+    fn end(self) -> LuaResult<()> {
+        SerializeTable::end(self)
+    }
+}
+
+impl<'s, 'a> ser::SerializeTuple for SerializeTable<'s, 'a> {
+    type Ok = ();
+    type Error = LuaError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> LuaResult<()> {
+        self.push_element(value)
+    }
+
+    fn end(self) -> LuaResult<()> {
+        SerializeTable::end(self)
+    }
+}
+
+impl<'s, 'a> ser::SerializeTupleStruct for SerializeTable<'s, 'a> {
+    type Ok = ();
+    type Error = LuaError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> LuaResult<()> {
+        self.push_element(value)
+    }
+
+    fn end(self) -> LuaResult<()> {
+        SerializeTable::end(self)
+    }
+}
+
+impl<'s, 'a> ser::SerializeTupleVariant for SerializeTable<'s, 'a> {
+    type Ok = ();
+    type Error = LuaError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> LuaResult<()> {
+        self.push_element(value)
+    }
+
+    fn end(self) -> LuaResult<()> {
+        SerializeTable::end(self)
+    }
+}
+
+impl<'s, 'a> ser::SerializeMap for SerializeTable<'s, 'a> {
+    type Ok = ();
+    type Error = LuaError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> LuaResult<()> {
+        key.serialize(&mut *self.ser)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> LuaResult<()> {
+        value.serialize(&mut *self.ser)?;
+        self.set_pair()
+    }
+
+    fn end(self) -> LuaResult<()> {
+        SerializeTable::end(self)
+    }
+}
+
+impl<'s, 'a> ser::SerializeStruct for SerializeTable<'s, 'a> {
+    type Ok = ();
+    type Error = LuaError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> LuaResult<()> {
+        self.ser.ls.push_string(key.to_string());
+        value.serialize(&mut *self.ser)?;
+        self.ser.ls.raw_set(-3);
+        Ok(())
+    }
+
+    fn end(self) -> LuaResult<()> {
+        SerializeTable::end(self)
+    }
+}
+
+impl<'s, 'a> ser::SerializeStructVariant for SerializeTable<'s, 'a> {
+    type Ok = ();
+    type Error = LuaError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> LuaResult<()> {
+        ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> LuaResult<()> {
+        SerializeTable::end(self)
+    }
+}
+
+/* deserializer */
+
+// Reads the value at `idx`. Elements of tables are pushed on the stack
+// to be read, `from_lua_with` restores the top.
+struct Deserializer<'a> {
+    ls: &'a mut dyn LuaAPI,
+    idx: isize, // absolute
+    options: SerdeOptions,
+    visiting: Vec<*const c_void>, // the tables being read
+}
+
+impl<'a> Deserializer<'a> {
+    // Read the value at `idx` with `seed`, leaving the stack as it was.
+    fn read<'de, T: DeserializeSeed<'de>>(&mut self, idx: isize, seed: T) -> LuaResult<T::Value> {
+        let (saved, top) = (self.idx, self.ls.get_top());
+        self.idx = self.ls.abs_index(idx);
+        let r = seed.deserialize(&mut *self);
+        self.idx = saved;
+        self.ls.set_top(top);
+        r
+    }
+
+    fn type_name(&self) -> String {
+        self.ls.type_name(self.ls.type_id(self.idx)).to_string()
+    }
+
+    fn unexpected<T>(&self, expected: &str) -> LuaResult<T> {
+        serde_error(format!("cannot deserialize a Lua {} as {}", self.type_name(), expected))
+    }
+
+    // Enter the table at `idx`, which must not be read already.
+    fn enter(&mut self) -> LuaResult<()> {
+        let p = self.ls.to_pointer(self.idx);
+        if self.visiting.contains(&p) {
+            return serde_error("cycle detected in table".to_string());
+        }
+        if !self.ls.check_stack(4) {
+            return serde_error("table too deeply nested".to_string());
+        }
+        self.visiting.push(p);
+        Ok(())
+    }
+
+    fn leave(&mut self) {
+        self.visiting.pop();
+    }
+
+    // Whether the table at `idx` is a sequence, its keys being 1..n.
+    fn is_array(&mut self) -> bool {
+        let (mut n, mut max) = (0, 0);
+        self.ls.push_nil();
+        while self.ls.next(self.idx) {
+            self.ls.pop(1);
+            if !self.ls.is_integer(-1) || self.ls.to_integer(-1) < 1 {
+                self.ls.pop(1);
+                return false;
+            }
+            n += 1;
+            max = max.max(self.ls.to_integer(-1));
+        }
+        match n {
+            0 => self.options.empty_table == EmptyTable::Array,
+            _ => max == n,
+        }
+    }
+
+    fn visit_seq<'de, V: Visitor<'de>>(&mut self, visitor: V) -> LuaResult<V::Value> {
+        self.enter()?;
+        let len = self.ls.raw_len(self.idx) as i64;
+        let table = self.idx;
+        let r = visitor.visit_seq(TableSeq { de: self, table, i: 0, len })?;
+        self.leave();
+        Ok(r)
+    }
+
+    fn visit_map<'de, V: Visitor<'de>>(&mut self, visitor: V) -> LuaResult<V::Value> {
+        self.enter()?;
+        let table = self.idx;
+        self.ls.push_nil();
+        let r = visitor.visit_map(TableMap { de: self, table, done: false })?;
+        self.leave();
+        Ok(r)
+    }
+}
+
+impl<'de, 'd, 'a> de::Deserializer<'de> for &'d mut Deserializer<'a> {
+    type Error = LuaError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        match self.ls.type_id(self.idx) {
+            LUA_TNONE | LUA_TNIL => visitor.visit_unit(),
+            LUA_TBOOLEAN => visitor.visit_bool(self.ls.to_boolean(self.idx)),
+            LUA_TNUMBER if self.ls.is_integer(self.idx) => visitor.visit_i64(self.ls.to_integer(self.idx)),
+            LUA_TNUMBER => visitor.visit_f64(self.ls.to_number(self.idx)),
+            LUA_TSTRING => match String::from_utf8(self.ls.to_bytes(self.idx).unwrap()) {
+                Ok(s) => visitor.visit_string(s),
+                Err(e) => visitor.visit_byte_buf(e.into_bytes()),
+            },
+            LUA_TTABLE if self.is_array() => self.visit_seq(visitor),
+            LUA_TTABLE => self.visit_map(visitor),
+            _ => self.unexpected("a serde value"),
+        }
+    }
+
+    // Floats with an exact integer value are integers, as in Lua.
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        if self.ls.type_id(self.idx) == LUA_TNUMBER {
+            if let Some(n) = self.ls.to_integerx(self.idx) {
+                return visitor.visit_i64(n);
+            }
+        }
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        if self.ls.is_none_or_nil(self.idx) {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> LuaResult<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        match self.ls.type_id(self.idx) {
+            LUA_TTABLE => self.visit_seq(visitor),
+            _ => self.unexpected("a sequence"),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> LuaResult<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> LuaResult<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        match self.ls.type_id(self.idx) {
+            LUA_TTABLE => self.visit_map(visitor),
+            _ => self.unexpected("a map"),
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> LuaResult<V::Value> {
+        match self.ls.type_id(self.idx) {
+            LUA_TTABLE => self.visit_map(visitor),
+            _ => self.unexpected(&format!("struct {}", name)),
+        }
+    }
+
+    // A variant name, or a table with a single pair `{Variant = value}`.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> LuaResult<V::Value> {
+        match self.ls.type_id(self.idx) {
+            LUA_TSTRING => {
+                let variant = self.ls.to_stringx(self.idx).unwrap();
+                visitor.visit_enum(variant.into_deserializer())
+            }
+            LUA_TTABLE => {
+                self.ls.push_nil();
+                if self.ls.next(self.idx) {
+                    let (key, value) = (self.ls.get_top() - 1, self.ls.get_top());
+                    self.ls.push_value(key);
+                    if !self.ls.next(self.idx) {
+                        self.enter()?;
+                        let r = visitor.visit_enum(TableEnum { de: &mut *self, key, value })?;
+                        self.leave();
+                        return Ok(r);
+                    }
+                }
+                serde_error(format!("enum {} expects a table with a single pair", name))
+            }
+            _ => self.unexpected(&format!("enum {}", name)),
+        }
+    }
+
+    // Integer keys of a table may name fields too.
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        match self.ls.to_integerx(self.idx) {
+            Some(n) if self.ls.is_integer(self.idx) && n >= 0 => visitor.visit_u64(n as u64),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    // Skipped values are not walked, so they may be anything.
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> LuaResult<V::Value> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool f32 f64 char str string bytes byte_buf unit unit_struct
+    }
+}
+
+struct TableSeq<'d, 'a> {
+    de: &'d mut Deserializer<'a>,
+    table: isize,
+    i: i64,
+    len: i64,
+}
+
+impl<'de, 'd, 'a> de::SeqAccess<'de> for TableSeq<'d, 'a> {
+    type Error = LuaError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> LuaResult<Option<T::Value>> {
+        if self.i >= self.len {
+            return Ok(None);
+        }
+        self.i += 1;
+        self.de.ls.raw_get_i(self.table, self.i);
+        let r = self.de.read(-1, seed)?;
+        self.de.ls.pop(1);
+        Ok(Some(r))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some((self.len - self.i) as usize)
+    }
+}
+
+// Walks the table with `next`, the current key being on the top.
+struct TableMap<'d, 'a> {
+    de: &'d mut Deserializer<'a>,
+    table: isize,
+    done: bool,
+}
+
+impl<'de, 'd, 'a> de::MapAccess<'de> for TableMap<'d, 'a> {
+    type Error = LuaError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> LuaResult<Option<K::Value>> {
+        if self.done || !self.de.ls.next(self.table) {
+            self.done = true;
+            return Ok(None);
+        }
+        self.de.read(-2, seed).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> LuaResult<V::Value> {
+        let r = self.de.read(-1, seed)?;
+        self.de.ls.pop(1); // keep the key for `next`
+        Ok(r)
+    }
+}
+
+struct TableEnum<'d, 'a> {
+    de: &'d mut Deserializer<'a>,
+    key: isize,
+    value: isize,
+}
+
+impl<'de, 'd, 'a> de::EnumAccess<'de> for TableEnum<'d, 'a> {
+    type Error = LuaError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> LuaResult<(V::Value, Self)> {
+        let variant = self.de.read(self.key, seed)?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de, 'd, 'a> de::VariantAccess<'de> for TableEnum<'d, 'a> {
+    type Error = LuaError;
+
+    fn unit_variant(self) -> LuaResult<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> LuaResult<T::Value> {
+        self.de.read(self.value, seed)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> LuaResult<V::Value> {
+        self.de.idx = self.value;
+        de::Deserializer::deserialize_seq(&mut *self.de, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> LuaResult<V::Value> {
+        self.de.idx = self.value;
+        de::Deserializer::deserialize_map(&mut *self.de, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lua;
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Rect(i32, i32),
+        Poly { points: Vec<(i32, i32)> },
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        name: String,
+        level: u8,
+        ratio: f32,
+        tags: Vec<String>,
+        limits: BTreeMap<String, i64>,
+        shapes: Vec<Shape>,
+        parent: Option<Box<Config>>,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(untagged)]
+    enum Any {
+        Seq(Vec<i64>),
+        Map(BTreeMap<String, i64>),
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Node {
+        #[allow(dead_code)]
+        next: Option<Box<Node>>,
+    }
+
+    #[test]
+    fn test_serde() {
+        let lua = Lua::new_bare();
+        let mut ls = lua.state();
+        let ls: &mut dyn LuaAPI = &mut *ls;
+
+        let mut limits = BTreeMap::new();
+        limits.insert("depth".to_string(), 8);
+        let config = Config {
+            name: "main".to_string(),
+            level: 3,
+            ratio: 0.5,
+            tags: vec!["a".to_string(), "b".to_string()],
+            limits,
+            shapes: vec![
+                Shape::Empty,
+                Shape::Circle(1.5),
+                Shape::Rect(2, 3),
+                Shape::Poly { points: vec![(0, 0), (1, 2)] },
+            ],
+            parent: None,
+        };
+        to_lua(ls, &config).unwrap();
+        assert_eq!(ls.get_top(), 1);
+        assert_eq!(from_lua::<Config>(ls, 1), Ok(config.clone()));
+
+        // the layout seen by scripts
+        assert_eq!(ls.get_field(1, "parent"), LUA_TNIL);
+        assert_eq!(ls.get_field(1, "tags"), LUA_TTABLE);
+        assert_eq!(ls.raw_len(-1), 2);
+        assert_eq!(ls.get_i(-1, 1), LUA_TSTRING);
+        assert_eq!(ls.to_string(-1), "a");
+        assert_eq!(ls.get_field(1, "shapes"), LUA_TTABLE);
+        ls.get_i(-1, 1);
+        assert_eq!(ls.to_string(-1), "Empty");
+        ls.get_i(-2, 3);
+        ls.get_field(-1, "Rect");
+        ls.get_i(-1, 2);
+        assert_eq!(ls.to_integer(-1), 3);
+        ls.set_top(1);
+
+        // floats with an integer value are integers, unknown fields are
+        // skipped and integer keys may name fields
+        ls.push_number(2.0);
+        ls.set_field(1, "level");
+        ls.push_boolean(true);
+        ls.set_field(1, "unknown");
+        assert_eq!(from_lua::<Config>(ls, 1).map(|c| c.level), Ok(2));
+        ls.push_number(2.5);
+        ls.set_field(1, "level");
+        assert!(from_lua::<Config>(ls, 1).is_err());
+        assert_eq!(from_lua::<Config>(ls, 1).unwrap_err().to_string(),
+                   "invalid type: floating point `2.5`, expected u8");
+        ls.set_top(0);
+
+        // enums from scripts, a name or a table with a single pair
+        ls.push_string("Circle".to_string());
+        assert!(from_lua::<Shape>(ls, 1).is_err());
+        to_lua(ls, &(Shape::Circle(2.0), Shape::Empty)).unwrap();
+        assert_eq!(from_lua(ls, 2), Ok((Shape::Circle(2.0), Shape::Empty)));
+        ls.push_integer(1);
+        ls.set_field(2, "extra");
+        assert!(from_lua::<(Shape, Shape)>(ls, 2).is_ok());
+        ls.get_i(2, 1);
+        ls.push_boolean(true);
+        ls.set_field(-2, "Empty");
+        assert_eq!(from_lua::<Shape>(ls, -1).unwrap_err().to_string(),
+                   "enum Shape expects a table with a single pair");
+        ls.set_top(0);
+
+        // None is nil, in sequences too
+        to_lua(ls, &vec![Some(1), None, Some(3)]).unwrap();
+        assert_eq!(ls.raw_get_i(1, 2), LUA_TNIL);
+        ls.set_top(0);
+        to_lua::<Option<i32>>(ls, &None).unwrap();
+        assert!(ls.is_nil(1));
+        ls.set_top(0);
+
+        // cycles are errors, even deep ones
+        ls.new_table();
+        ls.new_table();
+        ls.push_value(1);
+        ls.set_field(2, "next");
+        ls.set_field(1, "next");
+        let err = from_lua::<Node>(ls, 1).unwrap_err();
+        assert_eq!(err.to_string(), "cycle detected in table");
+        assert_eq!(ls.get_top(), 1);
+        ls.push_value(1);
+        ls.push_value(1);
+        ls.set_field(-2, "next"); // t.next = t
+        assert!(from_lua::<Node>(ls, -1).is_err());
+        ls.set_top(0);
+
+        // an empty table is an array or a map as told
+        ls.new_table();
+        let array = SerdeOptions { empty_table: EmptyTable::Array };
+        assert_eq!(from_lua_with::<Any>(ls, 1, array), Ok(Any::Seq(vec![])));
+        assert_eq!(from_lua::<Any>(ls, 1), Ok(Any::Map(BTreeMap::new())));
+        assert_eq!(from_lua::<Vec<i64>>(ls, 1), Ok(vec![]));
+        to_lua(ls, &vec![4, 5]).unwrap();
+        assert_eq!(from_lua::<Any>(ls, 2), Ok(Any::Seq(vec![4, 5])));
+        ls.push_integer(6);
+        ls.raw_set_i(2, 4); // a hole
+        assert!(from_lua::<Any>(ls, 2).is_err());
+        ls.set_top(0);
+
+        // keys must be table keys, nothing is left on failures
+        let mut map = BTreeMap::new();
+        map.insert(None::<i32>, 1);
+        assert_eq!(to_lua(ls, &map).unwrap_err().to_string(), "map key is nil");
+        assert_eq!(ls.get_top(), 0);
+        ls.push_rust_fn(|_| 0);
+        assert_eq!(from_lua::<i32>(ls, 1).unwrap_err().to_string(),
+                   "cannot deserialize a Lua function as a serde value");
+    }
+}
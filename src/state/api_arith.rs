@@ -1,21 +1,22 @@
-use crate::api::consts::ArithOp;
+use crate::api::consts::*;
 use crate::number::math;
 use super::lua_value::LuaValue;
 
+// Integer arithmetic wraps around, as in Lua
 fn iadd(a: i64, b: i64) -> i64 {
-    a + b
+    a.wrapping_add(b)
 }
 fn fadd(a: f64, b: f64) -> f64 {
     a + b
 }
 fn isub(a: i64, b: i64) -> i64 {
-    a - b
+    a.wrapping_sub(b)
 }
 fn fsub(a: f64, b: f64) -> f64 {
     a - b
 }
 fn imul(a: i64, b: i64) -> i64 {
-    a * b
+    a.wrapping_mul(b)
 }
 fn fmul(a: f64, b: f64) -> f64 {
     a * b
@@ -54,7 +55,7 @@ fn shr(a: i64, b: i64) -> i64 {
     math::shift_right(a, b)
 }
 fn iunm(a: i64, _: i64) -> i64 {
-    -a
+    a.wrapping_neg()
 }
 fn funm(a: f64, _: f64) -> f64 {
     -a
@@ -87,6 +88,16 @@ pub const OPS: &'static [(fn(i64, i64) -> i64, fn(f64, f64) -> f64)] = &[
     (bnot, fnone),
 ];
 
+// metamethod names, indexed by ArithOp
+pub const EVENTS: &[&str] = &[
+    "__add", "__sub", "__mul", "__mod", "__pow", "__div", "__idiv",
+    "__band", "__bor", "__bxor", "__shl", "__shr", "__unm", "__bnot",
+];
+
+pub fn is_bitwise(op: ArithOp) -> bool {
+    (LUA_OPBAND..=LUA_OPSHR).contains(&op) || op == LUA_OPBNOT
+}
+
 pub fn _arith(a: &LuaValue, b: &LuaValue, op: ArithOp) -> Option<LuaValue> {
     let int_func = OPS[op as usize].0;
     let float_func = OPS[op as usize].1;
@@ -42,9 +42,10 @@ fn _eq(a: &LuaValue, b: &LuaValue) -> bool {
             LuaValue::Boolean(y) => x == y,
             _ => false,
         },
-        _ => {
+        LuaValue::Integer(_) | LuaValue::Number(_) | LuaValue::Str(_) => {
             cmp!(a == b)
         },
+        _ => a == b, // reference types are equal only to themselves
     }
 }
 
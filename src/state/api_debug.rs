@@ -0,0 +1,351 @@
+use super::api_arith::EVENTS;
+use super::closure::Closure;
+use super::lua_stack::LuaStack;
+use super::lua_table::LuaTable;
+use super::lua_state::{chunk_id, LuaState};
+use super::lua_value::LuaValue;
+use crate::api::LuaDebug;
+use crate::binary::chunk::{Constant, Prototype};
+use crate::vm::instruction::Instruction;
+use crate::vm::opcodes::*;
+use core::cell::RefCell;
+use std::rc::Rc;
+
+// Where a local variable of a frame lives.
+enum LocalSlot {
+    Register(usize), // from 1
+    Vararg(usize),   // from 0
+}
+
+impl LuaState {
+    /*
+        Fill `ar` with the fields selected by `what` about the function
+        active in `ar.frame`, or about the function popped from the top of
+        the stack when `what` starts with '>'. Option 'f' pushes that
+        function and 'L' the table of its valid lines, in this order.
+    */
+    pub(crate) fn _get_info(&mut self, what: &str, ar: &mut LuaDebug) -> bool {
+        let (c, active) = match what.strip_prefix('>') {
+            Some(_) => match self.stack_mut().pop() {
+                LuaValue::Function(c) => (c, false),
+                _ => panic!("function expected"),
+            },
+            None => (self.frame(ar.frame).closure.clone(), true),
+        };
+        let what = what.trim_start_matches('>');
+        let mut status = true;
+        for option in what.chars() {
+            match option {
+                'S' => func_info(ar, &c),
+                'l' => {
+                    ar.current_line = if active { current_line(self.frame(ar.frame)) } else { -1 };
+                }
+                'u' => {
+                    ar.nups = c.upvals.len();
+                    if c.rust_fn.is_some() {
+                        ar.is_vararg = true;
+                        ar.nparams = 0;
+                    } else {
+                        ar.is_vararg = c.proto.is_vararg != 0;
+                        ar.nparams = c.proto.num_params as usize;
+                    }
+                }
+                't' => ar.is_tail_call = active && self.is_tail_call(ar.frame),
+                'n' => match if active { self.func_name(ar.frame) } else { None } {
+                    Some((name, namewhat)) => {
+                        ar.name = Some(name);
+                        ar.namewhat = namewhat;
+                    }
+                    None => {
+                        ar.name = None;
+                        ar.namewhat = "";
+                    }
+                },
+                'f' | 'L' => {}
+                _ => status = false,
+            }
+        }
+        if what.contains('f') {
+            self.stack_mut().push(LuaValue::Function(c.clone()));
+        }
+        if what.contains('L') {
+            self.stack_mut().push(active_lines(&c));
+        }
+        status
+    }
+
+    /*
+        Push the value of local `n` of the frame of `ar` and return its
+        name. Without `ar`, only return the name of parameter `n` of the
+        function on top of the stack.
+    */
+    pub(crate) fn _get_local(&mut self, ar: Option<&LuaDebug>, n: isize) -> Option<String> {
+        let ar = match ar {
+            Some(ar) => ar,
+            None => {
+                // information about non-active function
+                return match self.stack().get(-1) {
+                    LuaValue::Function(c) if c.rust_fn.is_none() && n > 0 => {
+                        local_name(&c.proto, n as usize, 0).map(str::to_string)
+                    }
+                    _ => None,
+                };
+            }
+        };
+        let (name, slot) = self.find_local(ar.frame, n)?;
+        let frame = self.frame(ar.frame);
+        let val = match slot {
+            LocalSlot::Register(reg) => frame.get(reg as isize),
+            LocalSlot::Vararg(i) => frame.varargs[i].clone(),
+        };
+        self.stack_mut().push(val);
+        Some(name)
+    }
+
+    // Pop a value into local `n` of the frame of `ar`, the value is left
+    // on the stack if there is no such local.
+    pub(crate) fn _set_local(&mut self, ar: &LuaDebug, n: isize) -> Option<String> {
+        let (name, slot) = self.find_local(ar.frame, n)?;
+        let val = self.stack_mut().pop();
+        let frame = self.frame_mut(ar.frame);
+        match slot {
+            LocalSlot::Register(reg) => frame.set(reg as isize, val),
+            LocalSlot::Vararg(i) => frame.varargs[i] = val,
+        }
+        Some(name)
+    }
+
+    // see `luaG_findlocal` in ldebug.c
+    fn find_local(&self, n_frame: usize, n: isize) -> Option<(String, LocalSlot)> {
+        let frame = self.frame(n_frame);
+        let mut name = None;
+        if is_lua(frame) {
+            if n < 0 {
+                // access to vararg values?
+                let i = (-n - 1) as usize;
+                return if i < frame.varargs.len() {
+                    Some(("(*vararg)".to_string(), LocalSlot::Vararg(i)))
+                } else {
+                    None
+                };
+            } else if n > 0 {
+                let pc = if frame.pc > 0 { frame.pc as usize - 1 } else { 0 };
+                name = local_name(&frame.closure.proto, n as usize, pc).map(str::to_string);
+            }
+        }
+        let name = match name {
+            Some(name) => name,
+            // 'n' is an active slot of the frame?
+            None if n > 0 && n <= frame.top() => "(*temporary)".to_string(),
+            None => return None,
+        };
+        Some((name, LocalSlot::Register(n as usize)))
+    }
+
+    /*
+        Whether the function of frame `n` was called by a tail call. Frames
+        are not reused, so the caller is still below it, it is only the
+        event reported to hooks and `get_info` that differs.
+    */
+    pub(crate) fn is_tail_call(&self, n: usize) -> bool {
+        if n < 1 || !is_lua(self.frame(n - 1)) {
+            return false;
+        }
+        let caller = self.frame(n - 1);
+        caller.pc > 0 && caller.closure.proto.code[caller.pc as usize - 1].opcode() == OP_TAILCALL
+    }
+
+    // Whether the running function is returning the results of a tail
+    // call, the return event of the callee then stands for both.
+    pub(crate) fn ends_tail_call(&self) -> bool {
+        let frame = self.stack();
+        is_lua(frame) && frame.pc >= 2 && frame.closure.proto.code[frame.pc as usize - 2].opcode() == OP_TAILCALL
+    }
+
+    // Name of the function running in frame `n`, deduced from the
+    // instruction of its caller, see `getfuncname` in ldebug.c
+    fn func_name(&self, n: usize) -> Option<(String, &'static str)> {
+        if n < 1 || !is_lua(self.frame(n - 1)) {
+            return None;
+        }
+        let caller = self.frame(n - 1);
+        let proto = &caller.closure.proto;
+        let pc = caller.pc as usize - 1; // calling instruction
+        let i = proto.code[pc];
+        let tm = match i.opcode() {
+            OP_CALL | OP_TAILCALL => return obj_name(proto, pc, i.abc().0 as usize),
+            OP_TFORCALL => return Some(("for iterator".to_string(), "for iterator")),
+            // other instructions can do calls through metamethods
+            OP_SELF | OP_GETTABUP | OP_GETTABLE => "__index",
+            OP_SETTABUP | OP_SETTABLE => "__newindex",
+            op @ OP_ADD..=OP_SHR => EVENTS[(op - OP_ADD) as usize],
+            OP_UNM => "__unm",
+            OP_BNOT => "__bnot",
+            OP_LEN => "__len",
+            OP_CONCAT => "__concat",
+            OP_EQ => "__eq",
+            OP_LT => "__lt",
+            OP_LE => "__le",
+            _ => return None,
+        };
+        Some((tm.to_string(), "metamethod"))
+    }
+}
+
+fn is_lua(frame: &LuaStack) -> bool {
+    frame.closure.rust_fn.is_none() && !frame.closure.proto.code.is_empty()
+}
+
+fn func_info(ar: &mut LuaDebug, c: &Closure) {
+    if c.rust_fn.is_some() {
+        ar.source = "=[C]".to_string();
+        ar.line_defined = -1;
+        ar.last_line_defined = -1;
+        ar.what = "C";
+    } else {
+        let p = &c.proto;
+        ar.source = p.source.clone().unwrap_or_else(|| "=?".to_string());
+        ar.line_defined = p.line_defined as isize;
+        ar.last_line_defined = p.last_line_defined as isize;
+        ar.what = if ar.line_defined == 0 { "main" } else { "Lua" };
+    }
+    ar.short_src = chunk_id(&ar.source);
+}
+
+// Table whose keys are the lines with code of a Lua function.
+fn active_lines(c: &Closure) -> LuaValue {
+    if c.rust_fn.is_some() {
+        return LuaValue::Nil;
+    }
+    let mut t = LuaTable::new(0, c.proto.line_info.len());
+    for line in c.proto.line_info.iter() {
+        t.put(LuaValue::Integer(*line as i64), LuaValue::Boolean(true)).unwrap(); // presized
+    }
+    LuaValue::Table(Rc::new(RefCell::new(t)))
+}
+
+fn current_line(frame: &LuaStack) -> isize {
+    if !is_lua(frame) {
+        return -1;
+    }
+    let pc = if frame.pc > 0 { frame.pc as usize - 1 } else { 0 };
+    match frame.closure.proto.line_info.get(pc) {
+        Some(line) => *line as isize,
+        None => -1,
+    }
+}
+
+// Name of the `n`-th active local variable (from 1) at `pc`.
+pub fn local_name(proto: &Prototype, mut n: usize, pc: usize) -> Option<&str> {
+    for var in proto.loc_vars.iter() {
+        if var.start_pc as usize > pc {
+            break;
+        }
+        if pc < var.end_pc as usize {
+            n -= 1;
+            if n == 0 {
+                return Some(&var.var_name);
+            }
+        }
+    }
+    None
+}
+
+fn upvalue_name(proto: &Prototype, n: usize) -> &str {
+    proto.upvalue_names.get(n).map_or("?", |s| s.as_str())
+}
+
+fn str_const(proto: &Prototype, idx: usize) -> Option<String> {
+    match &proto.constants[idx] {
+        Constant::Str(s) => Some(String::from_utf8_lossy(s).into_owned()),
+        _ => None,
+    }
+}
+
+// Symbolic execution to find what register `reg` holds at `lastpc`,
+// see `getobjname` in ldebug.c
+fn obj_name(proto: &Prototype, lastpc: usize, reg: usize) -> Option<(String, &'static str)> {
+    if let Some(name) = local_name(proto, reg + 1, lastpc) {
+        return Some((name.to_string(), "local"));
+    }
+    let pc = find_set_reg(proto, lastpc, reg)?;
+    let i = proto.code[pc];
+    match i.opcode() {
+        OP_MOVE => {
+            let (a, b, _) = i.abc();
+            if b < a {
+                return obj_name(proto, pc, b as usize); // get name for 'b'
+            }
+        }
+        op @ (OP_GETTABUP | OP_GETTABLE) => {
+            let (_, t, k) = i.abc();
+            let vn = if op == OP_GETTABLE {
+                local_name(proto, t as usize + 1, pc)
+            } else {
+                Some(upvalue_name(proto, t as usize))
+            };
+            let name = const_name(proto, pc, k);
+            return Some((name, if vn == Some("_ENV") { "global" } else { "field" }));
+        }
+        OP_GETUPVAL => {
+            let (_, b, _) = i.abc();
+            return Some((upvalue_name(proto, b as usize).to_string(), "upvalue"));
+        }
+        op @ (OP_LOADK | OP_LOADKX) => {
+            let b = if op == OP_LOADK { i.a_bx().1 } else { proto.code[pc + 1].ax() };
+            if let Some(s) = str_const(proto, b as usize) {
+                return Some((s, "constant"));
+            }
+        }
+        OP_SELF => {
+            let (_, _, k) = i.abc();
+            return Some((const_name(proto, pc, k), "method"));
+        }
+        _ => {}
+    }
+    None
+}
+
+// Name of the key RK(c), see `kname` in ldebug.c
+fn const_name(proto: &Prototype, pc: usize, c: isize) -> String {
+    if c > 0xFF {
+        if let Some(s) = str_const(proto, (c & 0xFF) as usize) {
+            return s;
+        }
+    } else if let Some((name, "constant")) = obj_name(proto, pc, c as usize) {
+        return name;
+    }
+    "?".to_string()
+}
+
+// The last instruction before `lastpc` that modified register `reg`.
+fn find_set_reg(proto: &Prototype, lastpc: usize, reg: usize) -> Option<usize> {
+    let mut setreg = None;
+    let mut jmptarget = 0; // any code before this address is conditional
+    for pc in 0..lastpc {
+        let i = proto.code[pc];
+        let a = i.abc().0 as usize;
+        let sets = match i.opcode() {
+            OP_LOADNIL => {
+                let b = i.abc().1 as usize;
+                a <= reg && reg <= a + b
+            }
+            OP_TFORCALL => reg >= a + 2,
+            OP_CALL | OP_TAILCALL => reg >= a,
+            OP_JMP => {
+                let dest = (pc as isize + 1 + i.a_sbx().1) as usize;
+                // jump is forward and do not skip 'lastpc'?
+                if pc < dest && dest <= lastpc && dest > jmptarget {
+                    jmptarget = dest;
+                }
+                false
+            }
+            op => OPCODES[op as usize].seta != 0 && reg == a,
+        };
+        if sets {
+            // code inside a jump cannot tell who sets the register
+            setreg = if pc < jmptarget { None } else { Some(pc) };
+        }
+    }
+    setreg
+}
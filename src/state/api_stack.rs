@@ -1,15 +1,22 @@
 use super::api_arith;
 use super::api_compare::compare;
-use super::closure::Closure;
+use super::closure::{Closure, Upvalue, UpvalueRef};
 use super::lua_stack::LuaStack;
-use super::lua_state::LuaState;
+use super::lua_state::{chunk_id, closure_size, native_address, ErrorUnwind, LuaState, TABLE_SIZE, VALUE_SIZE};
+use super::lua_table::MAXHSIZE;
 use super::lua_value::LuaValue;
 use crate::api::consts::*;
-use crate::api::{LuaAPI, LuaVM, RustFn};
+use crate::api::{Internal, LuaAPI, LuaDebug, LuaHook, LuaLimits, LuaVM, RustFn, StateRefs};
+use crate::binary::chunk::LUA_SIGNATURE;
+use crate::number::{format, parser};
 use crate::vm::instruction::Instruction;
-use std::rc::Rc;
+use std::cell::RefCell;
+use std::ffi::c_void;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::{Rc, Weak};
 
 const LUAVAL_RIDX_GLOBALS: LuaValue = LuaValue::Integer(LUA_RIDX_GLOBALS as i64);
+const MAXTAGLOOP: usize = 2000; // limit for chains of __index/__newindex
 
 impl LuaAPI for LuaState {
     /* =========================== Basic Methods =========================== */
@@ -22,8 +29,12 @@ impl LuaAPI for LuaState {
     }
 
     fn check_stack(&mut self, n: usize) -> bool {
+        let frame = self.stack();
+        if frame.base + frame.top() as usize + n > self.limits.stack_slots {
+            return false;
+        }
         self.stack_mut().check(n);
-        return true; // Never fails
+        true
     }
 
     /*
@@ -202,6 +213,15 @@ impl LuaAPI for LuaState {
         self.type_id(idx) == LUA_TFUNCTION
     }
 
+    fn is_userdata(&self, idx: isize) -> bool {
+        let t = self.type_id(idx);
+        t == LUA_TUSERDATA || t == LUA_TLIGHTUSERDATA
+    }
+
+    fn is_light_userdata(&self, idx: isize) -> bool {
+        self.type_id(idx) == LUA_TLIGHTUSERDATA
+    }
+
     fn to_boolean(&self, idx: isize) -> bool {
         self.stack().get(idx).to_boolean()
     }
@@ -227,14 +247,71 @@ impl LuaAPI for LuaState {
     }
 
     fn to_stringx(&self, idx: isize) -> std::option::Option<std::string::String> {
+        let s = self.to_bytes(idx)?;
+        // invalid UTF-8 sequences are replaced, use `to_bytes` for raw data
+        Some(std::string::String::from_utf8(s)
+            .unwrap_or_else(|e| std::string::String::from_utf8_lossy(e.as_bytes()).into_owned()))
+    }
+
+    fn to_bytes(&self, idx: isize) -> Option<Vec<u8>> {
+        self.to_shared_bytes(idx).map(|s| s.to_vec())
+    }
+
+    // The string itself rather than a copy; numbers are converted.
+    fn to_shared_bytes(&self, idx: isize) -> Option<Rc<Vec<u8>>> {
         match self.stack().get(idx) {
             LuaValue::Str(s) => Some(s),
-            LuaValue::Integer(i) => Some(i.to_string()),
-            LuaValue::Number(n) => Some(n.to_string()),
+            LuaValue::Integer(i) => Some(Rc::new(i.to_string().into_bytes())),
+            LuaValue::Number(n) => Some(Rc::new(format::float_to_string(n).into_bytes())),
+            _ => None,
+        }
+    }
+
+    fn to_light_userdata(&self, idx: isize) -> Option<*mut c_void> {
+        match self.stack().get(idx) {
+            LuaValue::LightUserData(p) => Some(p),
             _ => None,
         }
     }
 
+    fn to_userdata_any(&self, idx: isize) -> Option<Rc<dyn std::any::Any>> {
+        match self.stack().get(idx) {
+            LuaValue::UserData(u) => Some(u.borrow().data.clone()),
+            _ => None,
+        }
+    }
+
+    fn to_pointer(&self, idx: isize) -> *const c_void {
+        match self.stack().get(idx) {
+            LuaValue::Table(t) => Rc::as_ptr(&t) as *const c_void,
+            LuaValue::Function(c) => Rc::as_ptr(&c) as *const c_void,
+            LuaValue::UserData(u) => Rc::as_ptr(&u) as *const c_void,
+            LuaValue::LightUserData(p) => p,
+            _ => std::ptr::null(),
+        }
+    }
+
+    // Convert a numeral to a number and push it, see `lua_stringtonumber`
+    fn string_to_number(&mut self, s: &str) -> bool {
+        let val = if let Some(i) = parser::parse_integer(s) {
+            LuaValue::Integer(i)
+        } else if let Some(n) = parser::parse_float(s) {
+            LuaValue::Number(n)
+        } else {
+            return false;
+        };
+        self.stack_mut().push(val);
+        true
+    }
+
+    fn raw_len(&self, idx: isize) -> usize {
+        match self.stack().get(idx) {
+            LuaValue::Str(s) => s.len(),
+            LuaValue::Table(t) => t.borrow().len(),
+            _ => 0,
+        }
+    }
+
     /* =========================== Push Methods =========================== */
     fn push_nil(&mut self) {
         self.stack_mut().push(LuaValue::Nil);
@@ -249,7 +326,19 @@ impl LuaAPI for LuaState {
         self.stack_mut().push(LuaValue::Number(n));
     }
     fn push_string(&mut self, s: std::string::String) {
-        self.stack_mut().push(LuaValue::Str(s));
+        self.charge(s.len());
+        self.stack_mut().push(LuaValue::new_string(s));
+    }
+    fn push_bytes(&mut self, b: Vec<u8>) {
+        self.charge(b.len());
+        self.stack_mut().push(LuaValue::new_string(b));
+    }
+    fn push_light_userdata(&mut self, p: *mut c_void) {
+        self.stack_mut().push(LuaValue::LightUserData(p));
+    }
+    fn new_userdata_any(&mut self, data: Rc<dyn std::any::Any>) {
+        self.charge(std::mem::size_of_val(&*data));
+        self.stack_mut().push(LuaValue::new_userdata(data));
     }
 
     /* ================= comparison and arithmetic methods ================= */
@@ -277,33 +366,89 @@ impl LuaAPI for LuaState {
         +-------+       +-------+
     */
     fn arith(&mut self, op: ArithOp) {
-        if op != LUA_OPUNM && op != LUA_OPBNOT {
-            let b = self.stack_mut().pop();
-            let a = self.stack_mut().pop();
-            if let Some(ret) = api_arith::_arith(&a, &b, op) {
-                self.stack_mut().push(ret);
-                return;
+        let b = self.stack_mut().pop();
+        let a = if op != LUA_OPUNM && op != LUA_OPBNOT {
+            self.stack_mut().pop()
+        } else {
+            b.clone()
+        };
+        if op == LUA_OPIDIV || op == LUA_OPMOD {
+            if let (LuaValue::Integer(_), LuaValue::Integer(0)) = (&a, &b) {
+                let op_name = if op == LUA_OPIDIV { "n//0" } else { "n%0" };
+                self.runtime_error(&format!("attempt to perform '{}'", op_name));
             }
+        }
+        if let Some(ret) = api_arith::_arith(&a, &b, op) {
+            self.stack_mut().push(ret);
+            return;
+        }
+        if let Some(ret) = self.call_metamethod(&a, &b, api_arith::EVENTS[op as usize]) {
+            self.stack_mut().push(ret);
+            return;
+        }
+
+        let bad = if a.to_number().is_none() { &a } else { &b };
+        let msg = if !api_arith::is_bitwise(op) {
+            format!("attempt to perform arithmetic on a {} value", self.obj_type_name(bad))
+        } else if bad.to_number().is_some() {
+            "number has no integer representation".to_string()
         } else {
-            let a = self.stack_mut().pop();
-            if let Some(ret) = api_arith::_arith(&a, &a, op) {
-                self.stack_mut().push(ret);
-                return;
+            format!("attempt to perform bitwise operation on a {} value", self.obj_type_name(bad))
+        };
+        self.runtime_error(&msg)
+    }
+
+    fn compare(&mut self, idx1: isize, idx2: isize, op: CompareOp) -> bool {
+        if !self.stack().is_valid(idx1) || !self.stack().is_valid(idx2) {
+            return false;
+        }
+        let a = self.stack().get(idx1);
+        let b = self.stack().get(idx2);
+        match op {
+            LUA_OPEQ => {
+                if compare(&a, &b, op) {
+                    true
+                } else if let (LuaValue::Table(_), LuaValue::Table(_)) | (LuaValue::UserData(_), LuaValue::UserData(_)) =
+                    (&a, &b)
+                {
+                    match self.call_metamethod(&a, &b, "__eq") {
+                        Some(r) => r.to_boolean(),
+                        None => false,
+                    }
+                } else {
+                    false
+                }
             }
+            LUA_OPLT | LUA_OPLE => {
+                let numbers = a.type_id() == LUA_TNUMBER && b.type_id() == LUA_TNUMBER;
+                let strings = a.type_id() == LUA_TSTRING && b.type_id() == LUA_TSTRING;
+                if numbers || strings {
+                    return compare(&a, &b, op);
+                }
+                let event = if op == LUA_OPLT { "__lt" } else { "__le" };
+                if let Some(r) = self.call_metamethod(&a, &b, event) {
+                    r.to_boolean()
+                } else if let (LUA_OPLE, Some(r)) = (op, self.call_metamethod(&b, &a, "__lt")) {
+                    !r.to_boolean() // a <= b  <=>  not (b < a)
+                } else {
+                    let t1 = self.obj_type_name(&a);
+                    let t2 = self.obj_type_name(&b);
+                    if t1 == t2 {
+                        self.runtime_error(&format!("attempt to compare two {} values", t1))
+                    } else {
+                        self.runtime_error(&format!("attempt to compare {} with {}", t1, t2))
+                    }
+                }
+            }
+            _ => panic!("Invalid compare operation"),
         }
-        panic!("Arithmetic error!");
     }
 
-    fn compare(&self, idx1: isize, idx2: isize, op: CompareOp) -> bool {
+    fn raw_equal(&self, idx1: isize, idx2: isize) -> bool {
         if !self.stack().is_valid(idx1) || !self.stack().is_valid(idx2) {
             false
         } else {
-            let a = self.stack().get(idx1);
-            let b = self.stack().get(idx2);
-            match op {
-                LUA_OPEQ | LUA_OPLT | LUA_OPLE => compare(&a, &b, op),
-                _ => panic!("Invalid compare operation"),
-            }
+            compare(&self.stack().get(idx1), &self.stack().get(idx2), LUA_OPEQ)
         }
     }
 
@@ -322,12 +467,16 @@ impl LuaAPI for LuaState {
     */
     fn len(&mut self, idx: isize) {
         let val = self.stack().get(idx);
-        if let LuaValue::Str(s) = val {
+        if let LuaValue::Str(s) = &val {
             self.stack_mut().push(LuaValue::Integer(s.len() as i64));
-        } else if let LuaValue::Table(tbl) = val {
-            self.stack_mut().push(LuaValue::Integer(tbl.borrow().len() as i64));
+        } else if let Some(r) = self.call_metamethod(&val, &val, "__len") {
+            self.stack_mut().push(r);
+        } else if let LuaValue::Table(tbl) = &val {
+            let n = tbl.borrow().len() as i64;
+            self.stack_mut().push(LuaValue::Integer(n));
         } else {
-            panic!("TODO: need support more type!");
+            let msg = format!("attempt to get length of a {} value", self.obj_type_name(&val));
+            self.runtime_error(&msg);
         }
     }
 
@@ -345,17 +494,30 @@ impl LuaAPI for LuaState {
     */
     fn concat(&mut self, n: isize) {
         if n == 0 {
-            self.stack_mut().push(LuaValue::Str("".to_string()));
+            self.stack_mut().push(LuaValue::new_string(""));
         } else if n > 1 {
             for _ in 1..n {
                 if self.is_string(-1) && self.is_string(-2) {
-                    let s2 = self.to_string(-1);
-                    let s1 = self.to_string(-2);
+                    let s2 = self.to_shared_bytes(-1).unwrap();
+                    let s1 = self.to_shared_bytes(-2).unwrap();
+                    self.check_alloc(s1.len() + s2.len());
+                    let mut s = Vec::with_capacity(s1.len() + s2.len());
+                    s.extend_from_slice(&s1);
+                    s.extend_from_slice(&s2);
+                    self.charge(s.len());
                     self.stack_mut().pop();
                     self.stack_mut().pop();
-                    self.stack_mut().push(LuaValue::Str(s1 + &s2));
+                    self.stack_mut().push(LuaValue::new_string(s));
                 } else {
-                    panic!("Concatenation error!");
+                    let b = self.stack_mut().pop();
+                    let a = self.stack_mut().pop();
+                    if let Some(r) = self.call_metamethod(&a, &b, "__concat") {
+                        self.stack_mut().push(r);
+                        continue;
+                    }
+                    let bad = if let LuaValue::Str(_) | LuaValue::Integer(_) | LuaValue::Number(_) = a { &b } else { &a };
+                    let msg = format!("attempt to concatenate a {} value", self.obj_type_name(bad));
+                    self.runtime_error(&msg);
                 }
             }
         }
@@ -364,6 +526,10 @@ impl LuaAPI for LuaState {
 
     /* get functions (Lua -> stack) */
     fn create_table(&mut self, narr: usize, nrec: usize) {
+        if nrec > MAXHSIZE {
+            self.runtime_error("table overflow");
+        }
+        self.charge(TABLE_SIZE + (narr + 2 * nrec) * VALUE_SIZE);
         self.stack_mut().push(LuaValue::new_table(narr, nrec));
     }
 
@@ -386,7 +552,7 @@ impl LuaAPI for LuaState {
     fn get_table(&mut self, idx: isize) -> i8 {
         let t = self.stack().get(idx);
         let k = self.stack_mut().pop();
-        self._get_table(&t, &k)
+        self._get_table(&t, &k, false)
     }
 
     /*
@@ -403,8 +569,8 @@ impl LuaAPI for LuaState {
     */
     fn get_field(&mut self, idx: isize, k: &str) -> LuaType {
         let t = self.stack().get(idx);
-        let k = LuaValue::Str(k.to_string());
-        self._get_table(&t, &k)
+        let k = LuaValue::new_string(k);
+        self._get_table(&t, &k, false)
     }
 
     /*
@@ -422,7 +588,39 @@ impl LuaAPI for LuaState {
     fn get_i(&mut self, idx: isize, i: i64) -> i8 {
         let t = self.stack().get(idx);
         let k = LuaValue::Integer(i);
-        self._get_table(&t, &k)
+        self._get_table(&t, &k, false)
+    }
+
+    fn raw_get(&mut self, idx: isize) -> LuaType {
+        let t = self.stack().get(idx);
+        let k = self.stack_mut().pop();
+        self._get_table(&t, &k, true)
+    }
+
+    fn raw_get_i(&mut self, idx: isize, i: i64) -> LuaType {
+        let t = self.stack().get(idx);
+        self._get_table(&t, &LuaValue::Integer(i), true)
+    }
+
+    fn get_metatable(&mut self, idx: isize) -> bool {
+        let val = self.stack().get(idx);
+        if let Some(mt) = self._get_metatable(&val) {
+            self.stack_mut().push(LuaValue::Table(mt));
+            true
+        } else {
+            false
+        }
+    }
+
+    fn get_user_value(&mut self, idx: isize) -> LuaType {
+        if let LuaValue::UserData(u) = self.stack().get(idx) {
+            let uv = u.borrow().user_value.clone();
+            let type_id = uv.type_id();
+            self.stack_mut().push(uv);
+            type_id
+        } else {
+            panic!("Not full userdata!");
+        }
     }
 
     // set functions (stack -> Lua)
@@ -443,7 +641,7 @@ impl LuaAPI for LuaState {
         let t = self.stack().get(idx);
         let v = self.stack_mut().pop();
         let k = self.stack_mut().pop();
-        LuaState::_set_table(&t, k, v);
+        self._set_table(&t, k, v, false);
     }
 
     /*      set_field(2,"k")
@@ -460,9 +658,9 @@ impl LuaAPI for LuaState {
     */
     fn set_field(&mut self, idx: isize, k: &str) {
         let t = self.stack().get(idx);
-        let k = LuaValue::Str(k.to_string());
+        let k = LuaValue::new_string(k);
         let v = self.stack_mut().pop();
-        LuaState::_set_table(&t, k, v);
+        self._set_table(&t, k, v, false);
     }
 
     /*        set_field(2,3)
@@ -481,19 +679,104 @@ impl LuaAPI for LuaState {
         let t = self.stack().get(idx);
         let v = self.stack_mut().pop();
         let k = LuaValue::Integer(i);
-        LuaState::_set_table(&t, k, v);
+        self._set_table(&t, k, v, false);
+    }
+
+    fn raw_set(&mut self, idx: isize) {
+        let t = self.stack().get(idx);
+        let v = self.stack_mut().pop();
+        let k = self.stack_mut().pop();
+        self._set_table(&t, k, v, true);
+    }
+
+    fn raw_set_i(&mut self, idx: isize, i: i64) {
+        let t = self.stack().get(idx);
+        let v = self.stack_mut().pop();
+        self._set_table(&t, LuaValue::Integer(i), v, true);
+    }
+
+    /*
+            set_metatable(2)
+        +-------+        +-------+
+        |  mt   |---+    |       |
+        +-------+   |    +-------+
+        |   c   |   |    |   c   |
+        +-------+   |    +-------+
+        |   t   |<--+    |   t   |
+        +-------+        +-------+
+        |   a   |        |   a   |
+        +-------+        +-------+
+    */
+    fn set_metatable(&mut self, idx: isize) {
+        let val = self.stack().get(idx);
+        match self.stack_mut().pop() {
+            LuaValue::Table(mt) => self._set_metatable(&val, Some(mt)),
+            LuaValue::Nil => self._set_metatable(&val, None),
+            _ => panic!("Table expected!"),
+        }
     }
 
-    // Load chunk to top of stack
-    fn load(&mut self, chunk: Vec<u8>, /*chunk_name*/ _: &str, /*mode*/ _: &str) -> u8 {
-        let proto = crate::binary::undump(chunk);
-        let c = LuaValue::new_lua_closure(proto);
-        self.stack_mut().push(c);
-        0 // TODO:
+    fn set_user_value(&mut self, idx: isize) {
+        if let LuaValue::UserData(u) = self.stack().get(idx) {
+            u.borrow_mut().user_value = self.stack_mut().pop();
+        } else {
+            panic!("Not full userdata!");
+        }
     }
 
-    fn call(&mut self, nargs: usize, nresults: isize) {
-        let val = self.stack().get(-(nargs as isize + 1));
+    /*
+        Load a chunk and push it as a function whose first upvalue (`_ENV`)
+        is the value at index `env`, or the global table if it is None.
+        Only precompiled chunks can be loaded since there is no compiler,
+        on failure the error message is pushed.
+    */
+    fn load(&mut self, chunk: Vec<u8>, chunk_name: &str, mode: &str, env: Option<isize>) -> LuaStatus {
+        let is_binary = chunk.starts_with(LUA_SIGNATURE);
+        let kind = if is_binary { "binary" } else { "text" };
+        if !mode.contains(kind.as_bytes()[0] as char) {
+            self.push_string(format!("attempt to load a {} chunk (mode is '{}')", kind, mode));
+            return LUA_ERRSYNTAX;
+        }
+        if !is_binary {
+            self.push_string(format!("{}: cannot load text chunk (no compiler)", chunk_id(chunk_name)));
+            return LUA_ERRSYNTAX;
+        }
+        let proto = crate::binary::undump_named(chunk, chunk_name);
+        let c = Closure::new_lua_closure(proto);
+        self.charge(closure_size(&c));
+        if let Some(uv) = c.upvals.first() {
+            let env = match env {
+                Some(idx) => self.stack().get(idx),
+                None => self.registry_get(&LUAVAL_RIDX_GLOBALS),
+            };
+            *uv.borrow().borrow_mut() = Upvalue::Closed(env);
+        }
+        self.stack_mut().push(LuaValue::Function(Rc::new(c)));
+        LUA_OK
+    }
+
+    fn call(&mut self, mut nargs: usize, nresults: isize) {
+        if self.frame_count() == 1 {
+            self.reset_usage(); // each call from the host gets the whole budget
+            self.rust_calls = 0;
+            self.native_base = native_address();
+        }
+        let mut val = self.stack().get(-(nargs as isize + 1));
+        if let LuaValue::Function(_) = val {
+        } else {
+            // Calling a non-function goes through its __call metamethod,
+            // with the value itself as the first argument.
+            let mf = self._get_metafield(&val, "__call");
+            if let LuaValue::Function(_) = mf {
+                self.stack_mut().push(mf.clone());
+                self.insert(-(nargs as isize + 2));
+                nargs += 1;
+                val = mf;
+            } else {
+                let msg = format!("attempt to call a {} value", self.obj_type_name(&val));
+                self.runtime_error(&msg);
+            }
+        }
         if let LuaValue::Function(c) = val {
             // DEBUG info
             //println!(" Call {}<{}, {}>", c.proto.source.clone().unwrap(), c.proto.line_defined, c.proto.last_line_defined);
@@ -503,14 +786,60 @@ impl LuaAPI for LuaState {
             } else {
                 self.call_rust_closure(nargs, nresults, c);
             }
-        } else {
-            panic!("Not function!");
+        }
+    }
+
+    /*
+        Call in protected mode. On error the function and its arguments are
+        replaced by the error object, and the message handler at `msgh` (if
+        it is not 0) gets a chance to decorate the error first. Errors from
+        exceeding the `LuaLimits` are reported as LUA_ERRLIMIT.
+    */
+    fn pcall(&mut self, nargs: usize, nresults: isize, msgh: isize) -> LuaStatus {
+        let handler = if msgh == 0 { LuaValue::Nil } else { self.stack().get(msgh) };
+        let nframes = self.frame_count();
+        let base = self.get_top() - nargs as isize - 1;
+        let allow_hook = self.allow_hook;
+        let rust_calls = self.rust_calls;
+        self.handlers.push(handler);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| self.call(nargs, nresults)));
+        self.handlers.pop();
+        match result {
+            Ok(_) => LUA_OK,
+            Err(payload) => {
+                if !payload.is::<ErrorUnwind>() {
+                    panic::resume_unwind(payload); // not a Lua error
+                }
+                let err = self.error.take().unwrap_or(LuaValue::Nil);
+                let limit_hit = std::mem::replace(&mut self.limit_hit, false);
+                self.allow_hook = allow_hook; // an error may come from a hook
+                self.rust_calls = rust_calls;
+                self.truncate_frames(nframes);
+                self.set_top(base);
+                self.stack_mut().push(err);
+                if limit_hit {
+                    LUA_ERRLIMIT
+                } else {
+                    LUA_ERRRUN
+                }
+            }
         }
     }
 
     fn push_rust_fn(&mut self, f: RustFn) {
-        self.stack_mut()
-            .push(LuaValue::Function(Rc::new(Closure::new_rust_closure(f))));
+        self.push_rust_closure(f, 0);
+    }
+
+    // Push a Rust closure whose `n` upvalues are popped from the stack,
+    // they are then reachable through `upvalue_index(1..=n)`.
+    fn push_rust_closure(&mut self, f: RustFn, n: usize) {
+        let c = Closure::new_rust_closure(f, n);
+        self.charge(closure_size(&c));
+        for i in (0..n).rev() {
+            let val = self.stack_mut().pop();
+            *c.upvals[i].borrow().borrow_mut() = Upvalue::Closed(val);
+        }
+        self.stack_mut().push(LuaValue::Function(Rc::new(c)));
     }
 
     fn is_rust_fn(&self, idx: isize) -> bool {
@@ -549,8 +878,8 @@ impl LuaAPI for LuaState {
     fn get_global(&mut self, name: &str) -> LuaType {
         if let LuaValue::Table(t) = &self.registry {
             let global = t.borrow().get(&LUAVAL_RIDX_GLOBALS);
-            let k = LuaValue::Str(name.to_string());
-            self._get_table(&global, &k)
+            let k = LuaValue::new_string(name);
+            self._get_table(&global, &k, false)
         } else {
             LUA_TNONE
         }
@@ -572,8 +901,43 @@ impl LuaAPI for LuaState {
         if let LuaValue::Table(t) = &self.registry {
             let global = t.borrow().get(&LUAVAL_RIDX_GLOBALS);
             let v = self.stack_mut().pop();
-            let k = LuaValue::Str(name.to_string());
-            LuaState::_set_table(&global, k, v);
+            let k = LuaValue::new_string(name);
+            self._set_table(&global, k, v, false);
+        }
+    }
+
+    // Push upvalue `n` (from 1) of the function at `funcidx`, returning its name.
+    fn get_upvalue(&mut self, funcidx: isize, n: isize) -> Option<String> {
+        let (uv, name) = self.upvalue_at(funcidx, n)?;
+        let val = self.upvalue_get(&uv);
+        self.stack_mut().push(val);
+        Some(name)
+    }
+
+    // Pop a value into upvalue `n` (from 1) of the function at `funcidx`.
+    fn set_upvalue(&mut self, funcidx: isize, n: isize) -> Option<String> {
+        let (uv, name) = self.upvalue_at(funcidx, n)?;
+        let val = self.stack_mut().pop();
+        self.upvalue_set(&uv, val);
+        Some(name)
+    }
+
+    // Identity of upvalue `n` of the function at `funcidx`, shared by the
+    // closures that capture the same variable.
+    fn upvalue_id(&self, funcidx: isize, n: isize) -> Option<*mut c_void> {
+        let (uv, _) = self.upvalue_at(funcidx, n)?;
+        Some(Rc::as_ptr(&uv) as *mut c_void)
+    }
+
+    // Make upvalue `n1` of the Lua closure at `funcidx1` refer to upvalue
+    // `n2` of the Lua closure at `funcidx2`.
+    fn upvalue_join(&mut self, funcidx1: isize, n1: isize, funcidx2: isize, n2: isize) {
+        let (uv, _) = self.upvalue_at(funcidx2, n2).expect("invalid upvalue index");
+        match self.stack().get(funcidx1) {
+            LuaValue::Function(c) if c.rust_fn.is_none() && n1 >= 1 && n1 as usize <= c.upvals.len() => {
+                *c.upvals[n1 as usize - 1].borrow_mut() = uv;
+            }
+            _ => panic!("invalid upvalue index"),
         }
     }
 
@@ -581,32 +945,254 @@ impl LuaAPI for LuaState {
         self.push_rust_fn(f);
         self.set_global(name);
     }
-}
 
-impl LuaState {
-    fn _get_table(&mut self, t: &LuaValue, k: &LuaValue) -> i8 {
+    fn error(&mut self) -> ! {
+        let err = self.stack_mut().pop();
+        self.throw(err)
+    }
+
+    /*
+        Pop a key and push the next key-value pair of the table at `idx`,
+        or push nothing and return false when the traversal is over.
+    */
+    fn next(&mut self, idx: isize) -> bool {
+        let t = self.stack().get(idx);
+        let mut key = self.stack_mut().pop();
         if let LuaValue::Table(tbl) = t {
-            let v = tbl.borrow().get(k);
-            let type_id = v.type_id();
-            self.stack_mut().push(v);
-            type_id
+            loop {
+                let next = tbl.borrow().next_key(&key);
+                match next {
+                    Some(LuaValue::Nil) => return false,
+                    Some(k) => {
+                        let v = tbl.borrow().get(&k);
+                        if v.is_nil() {
+                            key = k; // cleared during the traversal
+                            continue;
+                        }
+                        self.stack_mut().push(k);
+                        self.stack_mut().push(v);
+                        return true;
+                    }
+                    None => self.runtime_error("invalid key to 'next'"),
+                }
+            }
         } else {
-            todo!()
+            panic!("Table expected!");
         }
     }
 
-    fn _set_table(t: &LuaValue, k: LuaValue, v: LuaValue) {
-        if let LuaValue::Table(tbl) = t {
-            tbl.borrow_mut().put(k, v);
+    // Level 0 is the running function, level n+1 is the one that called level n.
+    fn get_stack(&self, level: isize) -> Option<LuaDebug> {
+        let n = self.frame_count() as isize - 1 - level;
+        if level < 0 || n < 1 {
+            return None; // the bottom frame is not a function
+        }
+        Some(LuaDebug {
+            frame: n as usize,
+            ..LuaDebug::default()
+        })
+    }
+
+    fn get_info(&mut self, what: &str, ar: &mut LuaDebug) -> bool {
+        self._get_info(what, ar)
+    }
+
+    fn get_local(&mut self, ar: Option<&LuaDebug>, n: isize) -> Option<String> {
+        self._get_local(ar, n)
+    }
+
+    fn set_local(&mut self, ar: &LuaDebug, n: isize) -> Option<String> {
+        self._set_local(ar, n)
+    }
+
+    /*
+        Install `f` as the hook for the events in `mask`, LUA_MASKCOUNT
+        fires every `count` instructions. A None hook or an empty mask
+        turns hooks off.
+    */
+    fn set_hook(&mut self, f: Option<LuaHook>, mut mask: u8, count: usize) {
+        if count == 0 {
+            mask &= !LUA_MASKCOUNT;
+        }
+        if f.is_none() || mask == 0 {
+            mask = 0; // turn off hooks
+        }
+        self.hook = if mask == 0 { None } else { f };
+        self.hook_mask = mask;
+        self.base_hook_count = count;
+        self.hook_count = count;
+        // the instructions being executed are not reported as new lines
+        for n in 0..self.frame_count() {
+            let frame = self.frame_mut(n);
+            frame.oldpc = frame.pc;
+        }
+    }
+
+    fn get_hook(&self) -> Option<LuaHook> {
+        self.hook
+    }
+
+    fn get_hook_mask(&self) -> u8 {
+        self.hook_mask
+    }
+
+    fn get_hook_count(&self) -> usize {
+        self.base_hook_count
+    }
+
+    fn set_limits(&mut self, limits: LuaLimits) {
+        self.limits = limits;
+        self.reset_usage();
+    }
+
+    fn get_limits(&self) -> LuaLimits {
+        self.limits
+    }
+
+    fn instruction_count(&self) -> u64 {
+        self.instructions
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.allocated
+    }
+
+    fn check_alloc(&mut self, bytes: usize) {
+        if let Some(max) = self.limits.memory {
+            let used = if self.frame_count() > 1 { self.allocated } else { 0 };
+            if used.saturating_add(bytes) > max {
+                self.limit_error("memory limit exceeded");
+            }
+        }
+    }
+}
+
+impl StateRefs for LuaState {
+    fn owner(&self, _: Internal) -> Option<Rc<RefCell<LuaState>>> {
+        self.stack().state.as_ref().and_then(Weak::upgrade)
+    }
+
+    fn dropped_refs(&self, _: Internal) -> Rc<RefCell<Vec<i64>>> {
+        self.dropped_refs.clone()
+    }
+}
+
+impl LuaState {
+    // push t[k], falling back to the __index metamethod unless `raw` is set
+    fn _get_table(&mut self, t: &LuaValue, k: &LuaValue, raw: bool) -> i8 {
+        let mut t = t.clone();
+        for _ in 0..MAXTAGLOOP {
+            if let LuaValue::Table(tbl) = &t {
+                let v = tbl.borrow().get(k);
+                if raw || !v.is_nil() || self._get_metafield(&t, "__index").is_nil() {
+                    let type_id = v.type_id();
+                    self.stack_mut().push(v);
+                    return type_id;
+                }
+            } else if raw {
+                panic!("Table expected!");
+            }
+
+            let mf = self._get_metafield(&t, "__index");
+            match mf {
+                LuaValue::Nil => {
+                    let msg = format!("attempt to index a {} value", self.obj_type_name(&t));
+                    self.runtime_error(&msg);
+                }
+                LuaValue::Function(_) => {
+                    self.stack_mut().check(3);
+                    self.stack_mut().push(mf);
+                    self.stack_mut().push(t);
+                    self.stack_mut().push(k.clone());
+                    self.call(2, 1);
+                    return self.stack().get(-1).type_id();
+                }
+                _ => t = mf, // repeat with the __index table
+            }
+        }
+        self.runtime_error("'__index' chain too long; possible loop")
+    }
+
+    // t[k] = v, falling back to the __newindex metamethod unless `raw` is set
+    fn _set_table(&mut self, t: &LuaValue, k: LuaValue, v: LuaValue, raw: bool) {
+        let mut t = t.clone();
+        for _ in 0..MAXTAGLOOP {
+            if let LuaValue::Table(tbl) = &t {
+                let absent = tbl.borrow().get(&k).is_nil();
+                if raw || !absent || self._get_metafield(&t, "__newindex").is_nil() {
+                    match &k {
+                        LuaValue::Nil => self.runtime_error("table index is nil"),
+                        LuaValue::Number(n) if n.is_nan() => self.runtime_error("table index is NaN"),
+                        _ => {
+                            if absent && !v.is_nil() {
+                                self.charge(2 * VALUE_SIZE); // a new key and value
+                            }
+                            let res = tbl.borrow_mut().put(k, v);
+                            if res.is_err() {
+                                self.runtime_error("table overflow");
+                            }
+                        }
+                    }
+                    return;
+                }
+            } else if raw {
+                panic!("Table expected!");
+            }
+
+            let mf = self._get_metafield(&t, "__newindex");
+            match mf {
+                LuaValue::Nil => {
+                    let msg = format!("attempt to index a {} value", self.obj_type_name(&t));
+                    self.runtime_error(&msg);
+                }
+                LuaValue::Function(_) => {
+                    self.stack_mut().check(4);
+                    self.stack_mut().push(mf);
+                    self.stack_mut().push(t);
+                    self.stack_mut().push(k);
+                    self.stack_mut().push(v);
+                    self.call(3, 0);
+                    return;
+                }
+                _ => t = mf, // repeat with the __newindex table
+            }
+        }
+        self.runtime_error("'__newindex' chain too long; possible loop")
+    }
+
+    fn upvalue_at(&self, funcidx: isize, n: isize) -> Option<(UpvalueRef, String)> {
+        if let LuaValue::Function(c) = self.stack().get(funcidx) {
+            if n < 1 || n as usize > c.upvals.len() {
+                return None;
+            }
+            let uv = c.upvals[n as usize - 1].borrow().clone();
+            let name = if c.rust_fn.is_some() {
+                String::new()
+            } else {
+                let name = c.proto.upvalue_names.get(n as usize - 1);
+                name.cloned().unwrap_or_else(|| "(*no name)".to_string())
+            };
+            Some((uv, name))
         } else {
-            todo!();
+            None
+        }
+    }
+
+    // Type name used in error messages, honoring the `__name` metafield.
+    pub(crate) fn obj_type_name(&self, val: &LuaValue) -> String {
+        if let LuaValue::Table(_) | LuaValue::UserData(_) = val {
+            if let LuaValue::Str(name) = self._get_metafield(val, "__name") {
+                return String::from_utf8_lossy(&name).into_owned();
+            }
         }
+        self.type_name(val.type_id()).to_string()
     }
 
     fn call_lua_closure(&mut self, nargs: usize, nresults: isize, c: Rc<Closure>) {
         let nregs = c.proto.max_stack_size as usize;
         let nparams = c.proto.num_params as usize;
         let is_vararg = c.proto.is_vararg == 1;
+        self.check_call(nregs + LUA_MINSTACK);
 
         if let Some(state) = &self.stack().state {
             // create new lua stack
@@ -632,7 +1218,10 @@ impl LuaState {
 
             // run closure
             self.push_frame(new_stack);
+            self.hook_call();
             self.run_lua_closure();
+            self.hook_return();
+            self.close_upvalues(0);
             new_stack = self.pop_frame();
 
             // return results
@@ -650,6 +1239,10 @@ impl LuaState {
     fn run_lua_closure(&mut self) {
         loop {
             let inst = self.fetch();
+            self.count_instruction();
+            if self.hook_mask & (LUA_MASKLINE | LUA_MASKCOUNT) != 0 {
+                self.trace_exec();
+            }
             inst.execute(self);
 
             // DEBUG info
@@ -668,6 +1261,10 @@ impl LuaState {
 
     fn call_rust_closure(&mut self, nargs: usize, nresults: isize, c: Rc<Closure>) {
         let rust_fn = c.rust_fn.unwrap();
+        if self.rust_calls >= self.limits.call_depth {
+            self.limit_error("C stack overflow");
+        }
+        self.check_call(nargs + LUA_MINSTACK);
         if let Some(state) = &self.stack().state {
             // create new lua stack
             let mut new_stack = LuaStack::new(nargs + LUA_MINSTACK, c);
@@ -681,7 +1278,11 @@ impl LuaState {
 
             // run closure
             self.push_frame(new_stack);
+            self.hook_call();
+            self.rust_calls += 1;
             let r = rust_fn(self);
+            self.rust_calls -= 1;
+            self.hook_return();
             new_stack = self.pop_frame();
 
             if nresults != 0 {
@@ -740,8 +1341,10 @@ fn print_oprands(i: u32) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::{LuaAuxLib, LuaUserdata};
     use crate::binary::reader::tests::LUA_FOR_LOOP;
     use crate::state::new_lua_state;
+    use core::cell::RefCell;
 
     #[test]
     fn stack() {
@@ -767,9 +1370,13 @@ mod tests {
                 LuaValue::Boolean(true),
                 LuaValue::Integer(10),
                 LuaValue::Nil,
-                LuaValue::Str("hello".to_string())
+                LuaValue::new_string("hello")
             ]
         );
+        // strings are shared, numbers converted
+        let s = ls.borrow().to_shared_bytes(-1).unwrap();
+        assert!(Rc::ptr_eq(&s, &ls.borrow().to_shared_bytes(-1).unwrap()));
+        assert_eq!(*ls.borrow().to_shared_bytes(2).unwrap(), b"10".to_vec());
         ls.borrow_mut().push_value(-4);
         assert_eq!(
             *ls.borrow().stack()._raw_data(),
@@ -777,7 +1384,7 @@ mod tests {
                 LuaValue::Boolean(true),
                 LuaValue::Integer(10),
                 LuaValue::Nil,
-                LuaValue::Str("hello".to_string()),
+                LuaValue::new_string("hello"),
                 LuaValue::Boolean(true)
             ]
         );
@@ -788,7 +1395,7 @@ mod tests {
                 LuaValue::Boolean(true),
                 LuaValue::Integer(10),
                 LuaValue::Boolean(true),
-                LuaValue::Str("hello".to_string())
+                LuaValue::new_string("hello")
             ]
         );
         ls.borrow_mut().set_top(6);
@@ -798,7 +1405,7 @@ mod tests {
                 LuaValue::Boolean(true),
                 LuaValue::Integer(10),
                 LuaValue::Boolean(true),
-                LuaValue::Str("hello".to_string()),
+                LuaValue::new_string("hello"),
                 LuaValue::Nil,
                 LuaValue::Nil
             ]
@@ -827,15 +1434,15 @@ mod tests {
         ls.borrow_mut().push_string("2.0".to_string());
         assert_eq!(
             *ls.borrow().stack()._raw_data(),
-            vec![LuaValue::Integer(1), LuaValue::Str("2.0".to_string())]
+            vec![LuaValue::Integer(1), LuaValue::new_string("2.0")]
         );
         ls.borrow_mut().push_string("3.0".to_string());
         assert_eq!(
             *ls.borrow().stack()._raw_data(),
             vec![
                 LuaValue::Integer(1),
-                LuaValue::Str("2.0".to_string()),
-                LuaValue::Str("3.0".to_string())
+                LuaValue::new_string("2.0"),
+                LuaValue::new_string("3.0")
             ]
         );
         ls.borrow_mut().push_number(4.0);
@@ -843,8 +1450,8 @@ mod tests {
             *ls.borrow().stack()._raw_data(),
             vec![
                 LuaValue::Integer(1),
-                LuaValue::Str("2.0".to_string()),
-                LuaValue::Str("3.0".to_string()),
+                LuaValue::new_string("2.0"),
+                LuaValue::new_string("3.0"),
                 LuaValue::Number(4.0)
             ]
         );
@@ -854,7 +1461,7 @@ mod tests {
             *ls.borrow().stack()._raw_data(),
             vec![
                 LuaValue::Integer(1),
-                LuaValue::Str("2.0".to_string()),
+                LuaValue::new_string("2.0"),
                 LuaValue::Number(7.0)
             ]
         );
@@ -863,7 +1470,7 @@ mod tests {
             *ls.borrow().stack()._raw_data(),
             vec![
                 LuaValue::Integer(1),
-                LuaValue::Str("2.0".to_string()),
+                LuaValue::new_string("2.0"),
                 LuaValue::Integer(-8)
             ]
         );
@@ -872,7 +1479,7 @@ mod tests {
             *ls.borrow().stack()._raw_data(),
             vec![
                 LuaValue::Integer(1),
-                LuaValue::Str("2.0".to_string()),
+                LuaValue::new_string("2.0"),
                 LuaValue::Integer(-8),
                 LuaValue::Integer(3)
             ]
@@ -880,7 +1487,476 @@ mod tests {
         ls.borrow_mut().concat(3);
         assert_eq!(
             *ls.borrow().stack()._raw_data(),
-            vec![LuaValue::Integer(1), LuaValue::Str("2.0-83".to_string())]
+            vec![LuaValue::Integer(1), LuaValue::new_string("2.0-83")]
         );
     }
+
+    struct Point {
+        x: i64,
+        y: i64,
+    }
+
+    fn point_x(ls: &mut dyn LuaAPI) -> usize {
+        let p = ls.check_userdata::<Point>(1, "Point");
+        let x = p.borrow().x;
+        ls.push_integer(x);
+        1
+    }
+
+    #[test]
+    fn userdata() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+
+        ls.new_userdata(Point { x: 3, y: 4 });
+        assert_eq!(ls.type_id(1), LUA_TUSERDATA);
+        assert!(ls.to_userdata::<String>(1).is_none());
+        ls.to_userdata::<Point>(1).unwrap().borrow_mut().y = 40;
+        assert_eq!(ls.to_userdata::<Point>(1).unwrap().borrow().y, 40);
+
+        // user value
+        ls.push_string("tag".to_string());
+        ls.set_user_value(1);
+        assert_eq!(ls.get_user_value(1), LUA_TSTRING);
+        ls.pop(1);
+
+        // typed check through the metatable registered as registry["Point"]
+        ls.new_table();
+        ls.push_value(-1);
+        ls.set_field(LUA_REGISTRY_INDEX, "Point");
+        ls.set_metatable(1);
+        assert_eq!(ls.check_userdata::<Point>(1, "Point").borrow().x, 3);
+
+        ls.push_rust_fn(point_x);
+        ls.push_value(1);
+        assert_eq!(ls.pcall(1, 1, 0), LUA_OK);
+        assert_eq!(ls.to_integer(-1), 3);
+        ls.pop(1);
+
+        ls.push_rust_fn(point_x);
+        ls.new_table();
+        assert_eq!(ls.pcall(1, 1, 0), LUA_ERRRUN);
+        assert_eq!(ls.to_string(-1), "bad argument #1 to '?' (Point expected, got table)");
+        ls.pop(1);
+
+        // light userdata is compared by address
+        let mut n = 0;
+        let p = &mut n as *mut i32 as *mut std::ffi::c_void;
+        ls.push_light_userdata(p);
+        ls.push_light_userdata(p);
+        assert!(ls.raw_equal(-1, -2));
+        assert!(ls.is_light_userdata(-1));
+        assert_eq!(ls.to_light_userdata(-1), Some(p));
+        assert!(!ls.get_metatable(-1));
+    }
+
+    fn counter(ls: &mut dyn LuaAPI) -> usize {
+        let step = ls.opt_integer(1, 1);
+        let n = ls.to_integer(upvalue_index(1)) + step;
+        ls.push_integer(n);
+        ls.copy(-1, upvalue_index(1));
+        1
+    }
+
+    fn open_counter(ls: &mut dyn LuaAPI) -> usize {
+        ls.new_lib(&[]);
+        ls.push_integer(100);
+        ls.set_funcs(&[("next", counter)], 1);
+        1
+    }
+
+    #[test]
+    fn auxlib() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+
+        // functions of a library share the upvalues given to `set_funcs`
+        ls.requiref("counter", open_counter, true);
+        ls.get_field(-1, "next");
+        ls.call(0, 1);
+        ls.get_field(-2, "next");
+        ls.push_integer(10);
+        ls.call(1, 1);
+        assert_eq!((ls.to_integer(-2), ls.to_integer(-1)), (101, 111));
+        ls.set_top(0);
+
+        // errors name functions reachable from loaded modules
+        ls.get_global("counter");
+        ls.get_field(-1, "next");
+        ls.new_table();
+        assert_eq!(ls.pcall(1, 1, 0), LUA_ERRRUN);
+        assert_eq!(ls.to_string(-1), "bad argument #1 to 'counter.next' (number expected, got table)");
+        ls.set_top(0);
+
+        // references
+        ls.push_string("a".to_string());
+        let r1 = ls.ref_(LUA_REGISTRY_INDEX);
+        ls.push_string("b".to_string());
+        let r2 = ls.ref_(LUA_REGISTRY_INDEX);
+        assert_ne!(r1, r2);
+        ls.raw_get_i(LUA_REGISTRY_INDEX, r1);
+        assert_eq!(ls.to_string(-1), "a");
+        ls.pop(1);
+        ls.unref(LUA_REGISTRY_INDEX, r1);
+        ls.push_boolean(true);
+        assert_eq!(ls.ref_(LUA_REGISTRY_INDEX), r1); // reused
+        ls.push_nil();
+        assert_eq!(ls.ref_(LUA_REGISTRY_INDEX), LUA_REFNIL);
+        assert_eq!(ls.get_top(), 0);
+
+        // tostring and length honor metamethods
+        ls.push_number(3.0);
+        assert_eq!(ls.tolstring_l(-1), "3.0");
+        ls.new_table();
+        assert!(ls.tolstring_l(-1).starts_with("table: 0x"));
+        ls.pop(1);
+        ls.new_metatable("Obj");
+        ls.set_metatable(-2);
+        assert!(ls.tolstring_l(-1).starts_with("Obj: 0x"));
+        ls.pop(1);
+        ls.get_metatable_l("Obj");
+        ls.push_rust_fn(|ls| {
+            ls.push_string("obj".to_string());
+            1
+        });
+        ls.set_field(-2, "__tostring");
+        ls.push_rust_fn(|ls| {
+            ls.push_integer(42);
+            1
+        });
+        ls.set_field(-2, "__len");
+        ls.pop(1);
+        assert_eq!(ls.tolstring_l(-1), "obj");
+        assert_eq!(ls.len_l(-2), 42);
+    }
+
+    // Count the lines run into registry["lines"], and abort the script
+    // once registry["budget"] count events have fired.
+    fn budget_hook(ls: &mut dyn LuaAPI, ar: &mut LuaDebug) {
+        let key = if ar.event == LUA_HOOKLINE { "lines" } else { "budget" };
+        ls.get_field(LUA_REGISTRY_INDEX, key);
+        let n = ls.to_integer(-1) + if ar.event == LUA_HOOKLINE { 1 } else { -1 };
+        ls.push_integer(n);
+        ls.set_field(LUA_REGISTRY_INDEX, key);
+        if ar.event == LUA_HOOKCOUNT && n == 0 {
+            ls.get_info("Sl", ar);
+            ls.push_string(format!("budget exceeded at {}:{}", ar.short_src, ar.current_line));
+            ls.error();
+        }
+    }
+
+    #[test]
+    fn hooks() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+
+        ls.set_hook(Some(budget_hook), LUA_MASKCOUNT, 0);
+        assert!(ls.get_hook().is_none() && ls.get_hook_mask() == 0);
+
+        ls.set_hook(Some(budget_hook), LUA_MASKLINE | LUA_MASKCOUNT, 100);
+        assert_eq!((ls.get_hook_mask(), ls.get_hook_count()), (LUA_MASKLINE | LUA_MASKCOUNT, 100));
+        ls.push_integer(3);
+        ls.set_field(LUA_REGISTRY_INDEX, "budget");
+        ls.load(LUA_FOR_LOOP.to_vec(), "chunk", "b", None);
+        assert_eq!(ls.pcall(0, 0, 0), LUA_ERRRUN);
+        assert_eq!(ls.to_string(-1), "budget exceeded at ./test.lua:3");
+        ls.pop(1);
+        ls.get_field(LUA_REGISTRY_INDEX, "lines");
+        assert_eq!(ls.to_integer(-1), 185);
+        ls.pop(1);
+
+        // hooks are not called again until the running one returns,
+        // and are turned off by a None hook
+        assert!(ls.allow_hook);
+        ls.set_hook(None, LUA_MASKLINE, 0);
+        ls.load(LUA_FOR_LOOP.to_vec(), "chunk", "b", None);
+        assert_eq!(ls.pcall(0, 0, 0), LUA_OK);
+        ls.get_field(LUA_REGISTRY_INDEX, "lines");
+        assert_eq!(ls.to_integer(-1), 185);
+    }
+
+    #[test]
+    fn limits() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        crate::stdlib::open_libs(&mut *ls);
+        let run = |ls: &mut LuaState, mode: &str| {
+            ls.push_string(mode.to_string());
+            ls.set_global("MODE");
+            ls.load(LUA_LIMITS.to_vec(), "chunk", "b", None);
+            let status = ls.pcall(0, 1, 0);
+            let result = ls.to_string(-1);
+            ls.pop(1);
+            (status, result)
+        };
+
+        assert_eq!(ls.get_limits(), LuaLimits::default());
+        ls.set_limits(LuaLimits { instructions: Some(10000), ..LuaLimits::default() });
+        assert_eq!(run(&mut ls, "loop"), (LUA_ERRLIMIT, "chunk:3: instruction limit exceeded".to_string()));
+        assert_eq!(ls.instruction_count(), 10001);
+        // the budget is renewed for the next script
+        assert_eq!(run(&mut ls, "none"), (LUA_OK, "42".to_string()));
+        assert!(ls.instruction_count() < 100);
+
+        ls.set_limits(LuaLimits { memory: Some(100000), ..LuaLimits::default() });
+        assert_eq!(run(&mut ls, "alloc"), (LUA_ERRLIMIT, "chunk:8: memory limit exceeded".to_string()));
+        // the charge that would go past the limit is refused
+        let used = ls.allocated_bytes();
+        assert!(used > 99000 && used <= 100000, "{}", used);
+        assert_eq!(run(&mut ls, "none"), (LUA_OK, "42".to_string()));
+        // buffers are checked before they are allocated, this one is 1 GB
+        ls.get_global("string");
+        ls.get_field(-1, "rep");
+        ls.push_string("x".to_string());
+        ls.push_integer(1 << 30);
+        assert_eq!(ls.pcall(2, 1, 0), LUA_ERRLIMIT);
+        assert_eq!(ls.to_string(-1), "memory limit exceeded");
+        assert!(ls.allocated_bytes() < 100000);
+        ls.pop(1);
+        ls.get_field(-1, "gsub");
+        ls.push_string("x".repeat(1000));
+        ls.push_string("x".to_string());
+        ls.push_string("y".repeat(1000));
+        assert_eq!(ls.pcall(3, 1, 0), LUA_ERRLIMIT);
+        assert_eq!(ls.to_string(-1), "memory limit exceeded");
+        ls.pop(2);
+
+        // scripts can catch depth errors, which are not limit errors then
+        ls.set_limits(LuaLimits::default());
+        assert_eq!(run(&mut ls, "recurse"), (LUA_OK, "".to_string()));
+        ls.get_global("RESULT");
+        assert_eq!(ls.to_string(-1), "chunk:12: stack overflow");
+        ls.pop(1);
+        assert_eq!(ls.frame_count(), 1);
+
+        // only the nesting of Rust functions is bounded by `call_depth`
+        assert_eq!(run(&mut ls, "deep"), (LUA_OK, "300".to_string()));
+        assert_eq!(run(&mut ls, "nest"), (LUA_OK, "".to_string()));
+        ls.get_global("RESULT");
+        assert_eq!(ls.to_string(-1), "chunk:17: C stack overflow");
+        ls.pop(1);
+
+        // the Rust stack is protected whatever the host allows
+        ls.set_limits(LuaLimits { call_depth: usize::MAX, stack_slots: usize::MAX, ..LuaLimits::default() });
+        assert_eq!(run(&mut ls, "recurse"), (LUA_OK, "".to_string()));
+        ls.get_global("RESULT");
+        assert_eq!(ls.to_string(-1), "chunk:12: stack overflow");
+        ls.pop(1);
+        assert_eq!(run(&mut ls, "nest"), (LUA_OK, "".to_string()));
+        ls.get_global("RESULT");
+        let msg = ls.to_string(-1); // raised in `tostring` or in `nest`, by build
+        assert!(msg.ends_with("stack overflow") && !msg.contains("C stack"), "{}", msg);
+        ls.pop(1);
+
+        ls.set_limits(LuaLimits { stack_slots: 500, ..LuaLimits::default() });
+        assert_eq!(run(&mut ls, "recurse"), (LUA_OK, "".to_string()));
+        ls.get_global("RESULT");
+        assert_eq!(ls.to_string(-1), "chunk:12: stack overflow");
+        ls.pop(1);
+        assert!(!ls.check_stack(501));
+        assert!(ls.check_stack(100));
+        assert_eq!(run(&mut ls, "none"), (LUA_OK, "42".to_string()));
+
+        // a hash part beyond its maximum size is an error, not a panic
+        ls.set_limits(LuaLimits::default());
+        ls.push_rust_fn(huge_table);
+        assert_eq!(ls.pcall(0, 1, 0), LUA_ERRRUN);
+        assert_eq!(ls.to_string(-1), "table overflow");
+        ls.pop(1);
+    }
+
+    fn huge_table(ls: &mut dyn LuaAPI) -> usize {
+        ls.create_table(0, MAXHSIZE + 1);
+        1
+    }
+
+    /* Lua source code:
+        local function loop()
+          local n = 0
+          while true do n = n + 1 end
+        end
+
+        local function alloc()
+          local t = {}
+          for i = 1, 1000000 do t[i] = {i} end
+        end
+
+        local function recurse(n)
+          return 1 + recurse(n + 1)
+        end
+
+        -- every level runs a Rust function, tostring
+        local function nest()
+          return tostring(setmetatable({}, {__tostring = nest}))
+        end
+
+        local function depth(n)
+          if n == 0 then return 0 end
+          return 1 + depth(n - 1)
+        end
+
+        local function run(f)
+          local ok, err = pcall(f, 1)
+          assert(not ok)
+          return err
+        end
+
+        if MODE == "loop" then
+          loop()
+        elseif MODE == "alloc" then
+          alloc()
+        elseif MODE == "recurse" then
+          -- depth and stack limits can be caught by the script
+          RESULT = run(recurse)
+          assert(run(recurse) == RESULT)
+        elseif MODE == "nest" then
+          RESULT = run(nest)
+        elseif MODE == "deep" then
+          return depth(300)
+        else
+          return 42
+        end
+    */
+    const LUA_LIMITS: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x07, 0x40,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x09, 0x37, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00,
+        0x6c, 0x40, 0x00, 0x00, 0xac, 0x80, 0x00, 0x00, 0xec, 0xc0, 0x00, 0x00,
+        0x2c, 0x01, 0x01, 0x00, 0x6c, 0x41, 0x01, 0x00, 0x86, 0x01, 0x40, 0x00,
+        0x1f, 0x40, 0x40, 0x03, 0x1e, 0x80, 0x00, 0x80, 0x80, 0x01, 0x00, 0x00,
+        0xa4, 0x41, 0x80, 0x00, 0x1e, 0x40, 0x0a, 0x80, 0x86, 0x01, 0x40, 0x00,
+        0x1f, 0x80, 0x40, 0x03, 0x1e, 0x80, 0x00, 0x80, 0x80, 0x01, 0x80, 0x00,
+        0xa4, 0x41, 0x80, 0x00, 0x1e, 0xc0, 0x08, 0x80, 0x86, 0x01, 0x40, 0x00,
+        0x1f, 0xc0, 0x40, 0x03, 0x1e, 0x80, 0x03, 0x80, 0x80, 0x01, 0x80, 0x02,
+        0xc0, 0x01, 0x00, 0x01, 0xa4, 0x81, 0x00, 0x01, 0x08, 0x80, 0x01, 0x82,
+        0x86, 0x41, 0x41, 0x00, 0xc0, 0x01, 0x80, 0x02, 0x00, 0x02, 0x00, 0x01,
+        0xe4, 0x81, 0x00, 0x01, 0x06, 0x02, 0x41, 0x00, 0x5f, 0x00, 0x82, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00,
+        0xa4, 0x41, 0x00, 0x01, 0x1e, 0x40, 0x04, 0x80, 0x86, 0x01, 0x40, 0x00,
+        0x1f, 0x80, 0x41, 0x03, 0x1e, 0x00, 0x01, 0x80, 0x80, 0x01, 0x80, 0x02,
+        0xc0, 0x01, 0x80, 0x01, 0xa4, 0x81, 0x00, 0x01, 0x08, 0x80, 0x01, 0x82,
+        0x1e, 0x40, 0x02, 0x80, 0x86, 0x01, 0x40, 0x00, 0x1f, 0xc0, 0x41, 0x03,
+        0x1e, 0x00, 0x01, 0x80, 0x80, 0x01, 0x00, 0x02, 0xc1, 0x01, 0x02, 0x00,
+        0xa5, 0x01, 0x00, 0x01, 0xa6, 0x01, 0x00, 0x00, 0x1e, 0x40, 0x00, 0x80,
+        0x81, 0x41, 0x02, 0x00, 0xa6, 0x01, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x04, 0x05, 0x4d, 0x4f, 0x44, 0x45, 0x04, 0x05,
+        0x6c, 0x6f, 0x6f, 0x70, 0x04, 0x06, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x04,
+        0x08, 0x72, 0x65, 0x63, 0x75, 0x72, 0x73, 0x65, 0x04, 0x07, 0x52, 0x45,
+        0x53, 0x55, 0x4c, 0x54, 0x04, 0x07, 0x61, 0x73, 0x73, 0x65, 0x72, 0x74,
+        0x04, 0x05, 0x6e, 0x65, 0x73, 0x74, 0x04, 0x05, 0x64, 0x65, 0x65, 0x70,
+        0x13, 0x2c, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x2a, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x0d, 0x40, 0x40, 0x00, 0x1e, 0x40, 0xff, 0x7f, 0x26, 0x00, 0x80, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x6e, 0x01, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
+        0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x81, 0x40, 0x00, 0x00,
+        0xc1, 0x00, 0x00, 0x00, 0x68, 0xc0, 0x00, 0x80, 0x4b, 0x01, 0x80, 0x00,
+        0x80, 0x01, 0x00, 0x02, 0x6b, 0x41, 0x80, 0x00, 0x0a, 0x40, 0x01, 0x02,
+        0x67, 0x80, 0xfe, 0x7f, 0x26, 0x00, 0x80, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x40, 0x42,
+        0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x02, 0x74, 0x01, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x69, 0x6e,
+        0x64, 0x65, 0x78, 0x29, 0x04, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x29,
+        0x04, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0b, 0x28, 0x66, 0x6f,
+        0x72, 0x20, 0x73, 0x74, 0x65, 0x70, 0x29, 0x04, 0x00, 0x00, 0x00, 0x0a,
+        0x00, 0x00, 0x00, 0x02, 0x69, 0x05, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x03, 0x06, 0x00, 0x00, 0x00, 0x45, 0x00, 0x00,
+        0x00, 0x8d, 0x00, 0x40, 0x00, 0x64, 0x80, 0x00, 0x01, 0x4d, 0x40, 0x00,
+        0x80, 0x66, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x02, 0x6e, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x72, 0x65, 0x63, 0x75, 0x72,
+        0x73, 0x65, 0x00, 0x10, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x05, 0x0a, 0x00, 0x00, 0x00, 0x06, 0x00, 0x40, 0x00, 0x46, 0x40,
+        0x40, 0x00, 0x8b, 0x00, 0x00, 0x00, 0xcb, 0x40, 0x00, 0x00, 0x05, 0x01,
+        0x80, 0x00, 0xca, 0x00, 0x01, 0x81, 0x64, 0x00, 0x80, 0x01, 0x25, 0x00,
+        0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x80, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x04, 0x09, 0x74, 0x6f, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67,
+        0x04, 0x0d, 0x73, 0x65, 0x74, 0x6d, 0x65, 0x74, 0x61, 0x74, 0x61, 0x62,
+        0x6c, 0x65, 0x04, 0x0b, 0x5f, 0x5f, 0x74, 0x6f, 0x73, 0x74, 0x72, 0x69,
+        0x6e, 0x67, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x03, 0x00, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56, 0x05,
+        0x6e, 0x65, 0x73, 0x74, 0x00, 0x14, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x03, 0x0a, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x40, 0x00,
+        0x1e, 0x40, 0x00, 0x80, 0x41, 0x00, 0x00, 0x00, 0x66, 0x00, 0x00, 0x01,
+        0x45, 0x00, 0x00, 0x00, 0x8e, 0x40, 0x40, 0x00, 0x64, 0x80, 0x00, 0x01,
+        0x4d, 0x40, 0x80, 0x80, 0x66, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x04, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00,
+        0x15, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00,
+        0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00,
+        0x17, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x6e, 0x00, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x06, 0x64,
+        0x65, 0x70, 0x74, 0x68, 0x00, 0x19, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x05, 0x09, 0x00, 0x00, 0x00, 0x46, 0x00, 0x40, 0x00,
+        0x80, 0x00, 0x00, 0x00, 0xc1, 0x40, 0x00, 0x00, 0x64, 0xc0, 0x80, 0x01,
+        0xc6, 0x80, 0x40, 0x00, 0x1b, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01,
+        0xa6, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x04, 0x06, 0x70, 0x63, 0x61, 0x6c, 0x6c, 0x13, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x07, 0x61, 0x73, 0x73, 0x65, 0x72, 0x74,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00,
+        0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x02, 0x66, 0x00, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x00, 0x00, 0x03, 0x6f, 0x6b, 0x04, 0x00, 0x00, 0x00, 0x09,
+        0x00, 0x00, 0x00, 0x04, 0x65, 0x72, 0x72, 0x04, 0x00, 0x00, 0x00, 0x09,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+        0x37, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+        0x0d, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00,
+        0x1d, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00,
+        0x1f, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+        0x20, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00,
+        0x21, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00,
+        0x22, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00,
+        0x23, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00,
+        0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00,
+        0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00,
+        0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00,
+        0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00,
+        0x26, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00,
+        0x27, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00,
+        0x28, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00,
+        0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00,
+        0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00,
+        0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00,
+        0x2c, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x05, 0x6c, 0x6f, 0x6f, 0x70, 0x01, 0x00, 0x00, 0x00, 0x37, 0x00, 0x00,
+        0x00, 0x06, 0x61, 0x6c, 0x6c, 0x6f, 0x63, 0x02, 0x00, 0x00, 0x00, 0x37,
+        0x00, 0x00, 0x00, 0x08, 0x72, 0x65, 0x63, 0x75, 0x72, 0x73, 0x65, 0x03,
+        0x00, 0x00, 0x00, 0x37, 0x00, 0x00, 0x00, 0x05, 0x6e, 0x65, 0x73, 0x74,
+        0x04, 0x00, 0x00, 0x00, 0x37, 0x00, 0x00, 0x00, 0x06, 0x64, 0x65, 0x70,
+        0x74, 0x68, 0x05, 0x00, 0x00, 0x00, 0x37, 0x00, 0x00, 0x00, 0x04, 0x72,
+        0x75, 0x6e, 0x06, 0x00, 0x00, 0x00, 0x37, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
 }
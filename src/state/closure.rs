@@ -1,19 +1,22 @@
+use super::lua_value::LuaValue;
 use crate::binary::chunk::Prototype;
-use crate::number::math;
-use std::hash::Hash;
+use core::cell::RefCell;
 use std::rc::Rc;
 use crate::api::RustFn;
 
+// An upvalue is open while the variable it captures still lives in a
+// register of a running frame, and closed (owning the value) afterwards.
+pub enum Upvalue {
+    Open(usize, usize), // (frame index, register index)
+    Closed(LuaValue),
+}
+
+pub type UpvalueRef = Rc<RefCell<Upvalue>>;
+
 pub struct Closure {
     pub proto: Rc<Prototype>,   // lua closure
     pub rust_fn: Option<RustFn>,// rust closure
-    rdm: usize,
-}
-
-impl Hash for Closure {
-    fn hash<H>(&self, state: &mut H) where H: std::hash::Hasher {
-        self.rdm.hash(state);
-    }
+    pub upvals: Vec<RefCell<UpvalueRef>>,
 }
 
 impl Closure {
@@ -21,25 +24,40 @@ impl Closure {
         Closure {
             proto: new_dummy_prototype(),
             rust_fn: None,
-            rdm: math::random(),
+            upvals: Vec::new(),
         }
     }
 
     pub fn new_lua_closure(proto: Rc<Prototype>) -> Closure {
+        let n = proto.upvalues.len();
         Closure {
             proto,
             rust_fn: None,
-            rdm: math::random(),
+            upvals: new_upvals(n),
         }
     }
 
-    pub fn new_rust_closure(f: RustFn) -> Closure {
+    pub fn new_rust_closure(f: RustFn, n_upvals: usize) -> Closure {
         Closure {
             proto: new_dummy_prototype(),
             rust_fn: Some(f),
-            rdm: math::random(),
+            upvals: new_upvals(n_upvals),
         }
     }
+
+    // Value of upvalue `n` if it is closed; Rust closures only have closed ones.
+    pub fn get_upval(&self, n: usize) -> Option<LuaValue> {
+        match &*self.upvals.get(n)?.borrow().borrow() {
+            Upvalue::Closed(val) => Some(val.clone()),
+            Upvalue::Open(..) => None,
+        }
+    }
+}
+
+fn new_upvals(n: usize) -> Vec<RefCell<UpvalueRef>> {
+    (0..n)
+        .map(|_| RefCell::new(Rc::new(RefCell::new(Upvalue::Closed(LuaValue::Nil)))))
+        .collect()
 }
 
 fn new_dummy_prototype() -> Rc<Prototype> {
@@ -57,5 +75,6 @@ fn new_dummy_prototype() -> Rc<Prototype> {
         line_info: vec![],     // debug
         loc_vars: vec![],      // debug
         upvalue_names: vec![], // debug
+        field_caches: vec![],
     })
 }
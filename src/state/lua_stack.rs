@@ -2,15 +2,21 @@ use std::rc::Rc;
 use std::rc::Weak;
 use core::cell::RefCell;
 use super::lua_value::LuaValue;
-use super::closure::Closure;
+use super::closure::{Closure, Upvalue, UpvalueRef};
+use std::collections::HashMap;
 use super::lua_state::LuaState;
+use crate::api::consts::LUA_REGISTRY_INDEX;
 
 pub struct LuaStack {
     slots: Vec<LuaValue>,
     pub closure: Rc<Closure>,
     pub varargs: Vec<LuaValue>,
     pub pc: isize,
+    pub oldpc: isize, // last pc traced by line hooks
+    pub base: usize,  // slots used by the frames below
     pub state: Option<Weak<RefCell<LuaState>>>,
+    pub registry: LuaValue,
+    pub openuvs: HashMap<usize, UpvalueRef>, // open upvalues by register
 }
 
 impl LuaStack {
@@ -20,7 +26,11 @@ impl LuaStack {
             closure,
             varargs: Vec::new(),
             pc: 0,
+            oldpc: 0,
+            base: 0,
             state: None,
+            registry: LuaValue::Nil,
+            openuvs: HashMap::new(),
         }
     }
 
@@ -64,7 +74,7 @@ impl LuaStack {
     }
 
     pub fn abs_index(&self, idx: isize) -> isize {
-        if idx >= 0 {
+        if idx >= 0 || idx <= LUA_REGISTRY_INDEX {
             idx
         } else {
             idx + self.top() + 1
@@ -72,7 +82,12 @@ impl LuaStack {
     }
 
     pub fn is_valid(&self, idx: isize) -> bool {
-        self._is_valid(idx).0
+        if idx < LUA_REGISTRY_INDEX {
+            // upvalues
+            let uv_idx = (LUA_REGISTRY_INDEX - idx - 1) as usize;
+            return uv_idx < self.closure.upvals.len();
+        }
+        idx == LUA_REGISTRY_INDEX || self._is_valid(idx).0
     }
 
     fn _is_valid(&self, idx: isize) -> (bool, isize) {
@@ -81,6 +96,14 @@ impl LuaStack {
     }
 
     pub fn get(&self, idx: isize) -> LuaValue {
+        if idx < LUA_REGISTRY_INDEX {
+            // upvalues
+            let uv_idx = (LUA_REGISTRY_INDEX - idx - 1) as usize;
+            return self.closure.get_upval(uv_idx).unwrap_or(LuaValue::Nil);
+        }
+        if idx == LUA_REGISTRY_INDEX {
+            return self.registry.clone();
+        }
         let (valid, abs_idx) = self._is_valid(idx);
         if valid {
             self.slots[abs_idx as usize - 1].clone()
@@ -90,6 +113,18 @@ impl LuaStack {
     }
 
     pub fn set(&mut self, idx: isize, val: LuaValue) {
+        if idx < LUA_REGISTRY_INDEX {
+            // upvalues
+            let uv_idx = (LUA_REGISTRY_INDEX - idx - 1) as usize;
+            if let Some(uv) = self.closure.upvals.get(uv_idx) {
+                *uv.borrow().borrow_mut() = Upvalue::Closed(val);
+            }
+            return;
+        }
+        if idx == LUA_REGISTRY_INDEX {
+            self.registry = val;
+            return;
+        }
         let (valid, abs_idx) = self._is_valid(idx);
         if valid {
             self.slots[abs_idx as usize - 1] = val;
@@ -98,6 +133,16 @@ impl LuaStack {
         }
     }
 
+    // Registers are the slots from the bottom: R(r) is `slots[r]`, which
+    // exists for any register of the running function.
+    pub fn reg(&self, r: usize) -> Option<&LuaValue> {
+        self.slots.get(r)
+    }
+
+    pub fn set_reg(&mut self, r: usize, val: LuaValue) {
+        self.slots[r] = val;
+    }
+
     pub fn set_top(&mut self, idx: isize) {
         let new_top = self.abs_index(idx);
         if new_top < 0 {
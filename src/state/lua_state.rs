@@ -1,17 +1,38 @@
-use super::closure::Closure;
+use super::api_arith;
+use super::api_compare;
+use super::closure::{Closure, Upvalue, UpvalueRef};
 use super::lua_stack::LuaStack;
 use super::lua_table::LuaTable;
 use super::lua_value::LuaValue;
 use crate::api::consts::*;
-use crate::api::LuaAPI;
+use crate::api::{LuaAPI, LuaDebug, LuaHook, LuaLimits};
 use crate::api::LuaVM;
 use crate::binary::chunk::Constant;
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
+use std::panic;
 use std::rc::Rc;
 
+// Payload used to unwind the Rust stack when a Lua error is raised, the
+// error object itself is kept in `LuaState::error` until it is caught.
+pub struct ErrorUnwind;
+
 pub struct LuaState {
     frames: Vec<LuaStack>,
-    pub registry: LuaValue,
+    pub(crate) registry: LuaValue,
+    pub(crate) handlers: Vec<LuaValue>, // message handlers of active pcalls
+    pub(crate) error: Option<LuaValue>,
+    pub(crate) hook: Option<LuaHook>,
+    pub(crate) hook_mask: u8,
+    pub(crate) base_hook_count: usize,
+    pub(crate) hook_count: usize,
+    pub(crate) allow_hook: bool, // false while a hook runs
+    pub(crate) limits: LuaLimits,
+    pub(crate) instructions: u64, // executed since the usage was reset
+    pub(crate) allocated: usize,  // bytes allocated since the usage was reset
+    pub(crate) limit_hit: bool,   // the error being raised is a limit error
+    pub(crate) rust_calls: usize, // Rust functions running
+    pub(crate) native_base: usize, // Rust stack address of the call from the host
+    pub(crate) dropped_refs: Rc<RefCell<Vec<i64>>>, // released by handles while the state was borrowed
 }
 
 impl LuaState {
@@ -20,30 +41,361 @@ impl LuaState {
         tbl.borrow_mut().put(
             LuaValue::Integer(LUA_RIDX_GLOBALS as i64),
             LuaValue::Table(Rc::new(RefCell::new(LuaTable::new(0, 0)))),
-        ); // Global environment
+        ).unwrap(); // Global environment
+        let registry = LuaValue::Table(tbl);
         let dummy_closure = Rc::new(Closure::new_dummy_closure());
-        let dummy_frame = LuaStack::new(LUA_MINSTACK, dummy_closure);
+        let mut dummy_frame = LuaStack::new(LUA_MINSTACK, dummy_closure);
+        dummy_frame.registry = registry.clone();
         LuaState {
             frames: vec![dummy_frame],
-            registry: LuaValue::Table(tbl),
+            registry,
+            handlers: Vec::new(),
+            error: None,
+            hook: None,
+            hook_mask: 0,
+            base_hook_count: 0,
+            hook_count: 0,
+            allow_hook: true,
+            limits: LuaLimits::default(),
+            instructions: 0,
+            allocated: 0,
+            limit_hit: false,
+            rust_calls: 0,
+            native_base: 0,
+            dropped_refs: Rc::new(RefCell::new(Vec::new())),
         }
     }
 
-    pub fn stack_mut(&mut self) -> &mut LuaStack {
+    pub(crate) fn stack_mut(&mut self) -> &mut LuaStack {
         self.frames.last_mut().unwrap()
     }
 
-    pub fn stack(&self) -> &LuaStack {
+    pub(crate) fn stack(&self) -> &LuaStack {
         self.frames.last().unwrap()
     }
 
-    pub fn push_frame(&mut self, frame: LuaStack) {
+    pub(crate) fn push_frame(&mut self, mut frame: LuaStack) {
+        let below = self.stack();
+        frame.base = below.base + below.top() as usize;
+        frame.registry = self.registry.clone();
         self.frames.push(frame);
     }
 
-    pub fn pop_frame(&mut self) -> LuaStack {
+    pub(crate) fn pop_frame(&mut self) -> LuaStack {
         self.frames.pop().unwrap()
     }
+
+    pub(crate) fn frame(&self, n: usize) -> &LuaStack {
+        &self.frames[n]
+    }
+
+    pub(crate) fn frame_mut(&mut self, n: usize) -> &mut LuaStack {
+        &mut self.frames[n]
+    }
+
+    pub(crate) fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    // Drop the frames left behind by an error unwinding through them.
+    pub(crate) fn truncate_frames(&mut self, n: usize) {
+        while self.frames.len() > n {
+            self.close_upvalues(0);
+            self.frames.pop();
+        }
+    }
+}
+
+impl Default for LuaState {
+    fn default() -> LuaState {
+        LuaState::new()
+    }
+}
+
+/* upvalues */
+impl LuaState {
+    pub(crate) fn upvalue_get(&self, uv: &UpvalueRef) -> LuaValue {
+        match &*uv.borrow() {
+            Upvalue::Open(frame, reg) => self.frames[*frame].get(*reg as isize + 1),
+            Upvalue::Closed(val) => val.clone(),
+        }
+    }
+
+    pub(crate) fn upvalue_set(&mut self, uv: &UpvalueRef, val: LuaValue) {
+        match &mut *uv.borrow_mut() {
+            Upvalue::Open(frame, reg) => self.frames[*frame].set(*reg as isize + 1, val),
+            Upvalue::Closed(v) => *v = val,
+        }
+    }
+
+    // The open upvalue of the running frame for register `reg`, shared by
+    // every closure capturing that variable.
+    fn find_upvalue(&mut self, reg: usize) -> UpvalueRef {
+        let frame = self.frames.len() - 1;
+        let uv = self.stack_mut().openuvs.entry(reg);
+        uv.or_insert_with(|| Rc::new(RefCell::new(Upvalue::Open(frame, reg)))).clone()
+    }
+
+    // Close the upvalues of the running frame for registers >= `level`,
+    // they keep the current value of the variable from now on.
+    pub(crate) fn close_upvalues(&mut self, level: usize) {
+        let stack = self.stack_mut();
+        let regs: Vec<usize> = stack.openuvs.keys().filter(|r| **r >= level).cloned().collect();
+        for reg in regs {
+            let uv = stack.openuvs.remove(&reg).unwrap();
+            *uv.borrow_mut() = Upvalue::Closed(stack.get(reg as isize + 1));
+        }
+    }
+}
+
+/* metatables */
+impl LuaState {
+    pub(crate) fn _get_metatable(&self, val: &LuaValue) -> Option<Rc<RefCell<LuaTable>>> {
+        match val {
+            LuaValue::Table(t) => t.borrow().metatable.clone(),
+            LuaValue::UserData(u) => u.borrow().metatable.clone(),
+            _ => {
+                // Other types share one metatable per type, kept in the registry.
+                match self.registry_get(&type_mt_key(val)) {
+                    LuaValue::Table(mt) => Some(mt),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    pub(crate) fn _set_metatable(&mut self, val: &LuaValue, mt: Option<Rc<RefCell<LuaTable>>>) {
+        match val {
+            LuaValue::Table(t) => t.borrow_mut().metatable = mt,
+            LuaValue::UserData(u) => u.borrow_mut().metatable = mt,
+            _ => {
+                let mt = mt.map_or(LuaValue::Nil, LuaValue::Table);
+                let res = match &self.registry {
+                    LuaValue::Table(r) => r.borrow_mut().put(type_mt_key(val), mt),
+                    _ => Ok(()),
+                };
+                if res.is_err() {
+                    self.runtime_error("table overflow");
+                }
+            }
+        }
+    }
+
+    pub(crate) fn _get_metafield(&self, val: &LuaValue, event: &str) -> LuaValue {
+        match self._get_metatable(val) {
+            Some(mt) => mt.borrow().get(&LuaValue::new_string(event)),
+            None => LuaValue::Nil,
+        }
+    }
+
+    // Call the metamethod `event` of `a` (or of `b` if `a` has none) with
+    // both operands, returning its first result.
+    pub(crate) fn call_metamethod(&mut self, a: &LuaValue, b: &LuaValue, event: &str) -> Option<LuaValue> {
+        let mut mm = self._get_metafield(a, event);
+        if mm.is_nil() {
+            mm = self._get_metafield(b, event);
+            if mm.is_nil() {
+                return None;
+            }
+        }
+        self.stack_mut().check(4);
+        self.stack_mut().push(mm);
+        self.stack_mut().push(a.clone());
+        self.stack_mut().push(b.clone());
+        self.call(2, 1);
+        Some(self.stack_mut().pop())
+    }
+
+    pub(crate) fn registry_get(&self, key: &LuaValue) -> LuaValue {
+        match &self.registry {
+            LuaValue::Table(r) => r.borrow().get(key),
+            _ => LuaValue::Nil,
+        }
+    }
+}
+
+fn type_mt_key(val: &LuaValue) -> LuaValue {
+    LuaValue::new_string(format!("_MT{}", val.type_id()))
+}
+
+/* hooks */
+impl LuaState {
+    // Run the hook for `event` on the frame of the running function, the
+    // values it leaves on the stack are dropped. See `luaD_hook` in ldo.c
+    fn call_hook(&mut self, event: u8, line: isize) {
+        let hook = match self.hook {
+            Some(hook) if self.allow_hook => hook,
+            _ => return,
+        };
+        let mut ar = LuaDebug {
+            event,
+            current_line: line,
+            frame: self.frames.len() - 1,
+            ..LuaDebug::default()
+        };
+        let top = self.stack().top();
+        self.allow_hook = false; // cannot call hooks inside a hook
+        hook(self, &mut ar);
+        self.allow_hook = true;
+        self.stack_mut().set_top(top);
+    }
+
+    // Called once the frame of a function is pushed.
+    pub(crate) fn hook_call(&mut self) {
+        if self.hook_mask & LUA_MASKCALL != 0 {
+            let tail = self.is_tail_call(self.frames.len() - 1);
+            self.call_hook(if tail { LUA_HOOKTAILCALL } else { LUA_HOOKCALL }, -1);
+        }
+    }
+
+    // Called before the frame of a returning function is popped.
+    pub(crate) fn hook_return(&mut self) {
+        if self.hook_mask & LUA_MASKRET != 0 && !self.ends_tail_call() {
+            self.call_hook(LUA_HOOKRET, -1);
+        }
+    }
+
+    /*
+        Count and line hooks, run after fetching each instruction. A line
+        event is reported when entering a function, a new line, or jumping
+        back (for loops on a single line). See `luaG_traceexec` in ldebug.c
+    */
+    pub(crate) fn trace_exec(&mut self) {
+        let mask = self.hook_mask;
+        if mask & LUA_MASKCOUNT != 0 {
+            self.hook_count -= 1;
+            if self.hook_count == 0 {
+                self.hook_count = self.base_hook_count; // reset count
+                self.call_hook(LUA_HOOKCOUNT, -1);
+            }
+        }
+        let frame = self.stack();
+        let pc = frame.pc; // already points past the current instruction
+        if mask & LUA_MASKLINE != 0 {
+            let line_info = &frame.closure.proto.line_info;
+            let line_at = |pc: isize| match line_info.get((pc - 1) as usize) {
+                Some(line) if pc > 0 => *line as isize,
+                _ => -1,
+            };
+            let new_line = line_at(pc);
+            if pc == 1 || pc <= frame.oldpc || new_line != line_at(frame.oldpc) {
+                self.call_hook(LUA_HOOKLINE, new_line);
+            }
+        }
+        self.stack_mut().oldpc = pc;
+    }
+}
+
+/* limits */
+impl LuaState {
+    pub(crate) fn reset_usage(&mut self) {
+        self.instructions = 0;
+        self.allocated = 0;
+    }
+
+    // Raise an error that `pcall` reports as LUA_ERRLIMIT.
+    pub(crate) fn limit_error(&mut self, msg: &str) -> ! {
+        self.limit_hit = true;
+        self.runtime_error(msg)
+    }
+
+    // Account for `bytes` more allocated memory, for objects small enough
+    // to be checked once they exist. Large buffers use `check_alloc` first.
+    pub(crate) fn charge(&mut self, bytes: usize) {
+        self.check_alloc(bytes);
+        self.allocated += bytes;
+    }
+
+    // Account for one more executed instruction.
+    pub(crate) fn count_instruction(&mut self) {
+        self.instructions += 1;
+        if let Some(max) = self.limits.instructions {
+            if self.instructions > max {
+                self.limit_error("instruction limit exceeded");
+            }
+        }
+    }
+
+    // Check, before a call, that a frame of `size` slots can be pushed.
+    // Every call nests on the Rust stack, so besides the slots the depth
+    // is bounded by LUAI_MAXRSTACK, which limits cannot raise.
+    pub(crate) fn check_call(&mut self, size: usize) {
+        if self.native_base.abs_diff(native_address()) > LUAI_MAXRSTACK {
+            self.limit_error("stack overflow");
+        }
+        let frame = self.stack();
+        if frame.base + frame.top() as usize + size > self.limits.stack_slots {
+            self.limit_error("stack overflow");
+        }
+    }
+}
+
+// An address in the current frame of the Rust stack.
+#[inline(never)]
+pub fn native_address() -> usize {
+    let probe = 0u8;
+    std::hint::black_box(&probe) as *const u8 as usize
+}
+
+// Memory charged for the objects created by scripts.
+pub const VALUE_SIZE: usize = std::mem::size_of::<LuaValue>();
+pub const TABLE_SIZE: usize = std::mem::size_of::<LuaTable>();
+
+pub fn closure_size(c: &Closure) -> usize {
+    std::mem::size_of::<Closure>() + c.upvals.len() * std::mem::size_of::<UpvalueRef>()
+}
+
+/* errors */
+impl LuaState {
+    // Raise `err` as a Lua error. If the innermost pcall installed a message
+    // handler, it runs first, while the erroring frame is still alive.
+    pub(crate) fn throw(&mut self, mut err: LuaValue) -> ! {
+        if let Some(h) = self.handlers.last().cloned() {
+            if !h.is_nil() {
+                // An error inside the handler is not handled again.
+                *self.handlers.last_mut().unwrap() = LuaValue::Nil;
+                self.stack_mut().check(2);
+                self.stack_mut().push(h);
+                self.stack_mut().push(err);
+                self.call(1, 1);
+                err = self.stack_mut().pop();
+            }
+        }
+        self.error = Some(err);
+        panic::resume_unwind(Box::new(ErrorUnwind))
+    }
+
+    // Raise an error message prefixed with the position of the running Lua
+    // function, e.g. "test.lua:3: attempt to index a nil value".
+    pub(crate) fn runtime_error(&mut self, msg: &str) -> ! {
+        let msg = format!("{}{}", self.location(), msg);
+        self.throw(LuaValue::new_string(msg))
+    }
+
+    fn location(&self) -> String {
+        let stack = self.stack();
+        let proto = &stack.closure.proto;
+        if stack.closure.rust_fn.is_some() || proto.line_info.is_empty() {
+            return String::new();
+        }
+        let pc = if stack.pc > 0 { stack.pc as usize - 1 } else { 0 };
+        let line = proto.line_info.get(pc).cloned().unwrap_or(0);
+        format!("{}:{}: ", chunk_id(proto.source.as_deref().unwrap_or("?")), line)
+    }
+}
+
+// Human readable chunk name, see `luaO_chunkid` in lobject.c
+pub fn chunk_id(source: &str) -> String {
+    if let Some(name) = source.strip_prefix('=').or_else(|| source.strip_prefix('@')) {
+        name.to_string()
+    } else {
+        let line = source.lines().next().unwrap_or("");
+        if line.len() < source.len() {
+            format!("[string \"{}...\"]", line)
+        } else {
+            format!("[string \"{}\"]", line)
+        }
+    }
 }
 
 impl LuaVM for LuaState {
@@ -63,14 +415,7 @@ impl LuaVM for LuaState {
     }
 
     fn get_const(&mut self, idx: isize) {
-        let c = &self.stack().closure.proto.constants[idx as usize];
-        let val = match c {
-            Constant::Nil => LuaValue::Nil,
-            Constant::Boolean(b) => LuaValue::Boolean(*b),
-            Constant::Integer(i) => LuaValue::Integer(*i),
-            Constant::Number(n) => LuaValue::Number(*n),
-            Constant::Str(s) => LuaValue::Str((*s).clone()),
-        };
+        let val = self.constant(idx);
         self.stack_mut().push(val);
     }
 
@@ -99,7 +444,174 @@ impl LuaVM for LuaState {
 
     fn load_proto(&mut self, idx: usize) {
         let proto = self.stack().closure.proto.protos[idx].clone();
-        let closure = LuaValue::new_lua_closure(proto);
-        self.stack_mut().push(closure);
+        let closure = Closure::new_lua_closure(proto.clone());
+        for (i, uv_info) in proto.upvalues.iter().enumerate() {
+            let uv = if uv_info.instack == 1 {
+                // captures a local variable of the enclosing function
+                self.find_upvalue(uv_info.idx as usize)
+            } else {
+                // shares an upvalue of the enclosing function
+                self.stack().closure.upvals[uv_info.idx as usize].borrow().clone()
+            };
+            *closure.upvals[i].borrow_mut() = uv;
+        }
+        self.charge(closure_size(&closure));
+        self.stack_mut().push(LuaValue::Function(Rc::new(closure)));
+    }
+
+    fn get_upval(&mut self, idx: isize) {
+        let uv = self.stack().closure.upvals[idx as usize].borrow().clone();
+        let val = self.upvalue_get(&uv);
+        self.stack_mut().push(val);
+    }
+
+    fn set_upval(&mut self, idx: isize) {
+        let uv = self.stack().closure.upvals[idx as usize].borrow().clone();
+        let val = self.stack_mut().pop();
+        self.upvalue_set(&uv, val);
+    }
+
+    fn close_upvals(&mut self, a: isize) {
+        self.close_upvalues(a as usize);
+    }
+
+    fn load_const(&mut self, a: isize, idx: isize) {
+        let val = self.constant(idx);
+        self.stack_mut().set_reg(a as usize, val);
+    }
+
+    fn load_upval(&mut self, a: isize, idx: isize) {
+        let uv = self.stack().closure.upvals[idx as usize].borrow().clone();
+        let val = self.upvalue_get(&uv);
+        self.stack_mut().set_reg(a as usize, val);
+    }
+
+    fn reg_truth(&self, r: isize) -> bool {
+        self.stack().reg(r as usize).is_some_and(LuaValue::to_boolean)
+    }
+
+    fn move_reg(&mut self, a: isize, b: isize) {
+        let val = self.stack().reg(b as usize).cloned().unwrap_or(LuaValue::Nil);
+        self.stack_mut().set_reg(a as usize, val);
+    }
+
+    // Numbers are computed in place, anything that may need a metamethod
+    // or raise an error goes through the stack.
+    fn arith_rk(&mut self, op: ArithOp, a: isize, b: isize, c: isize) {
+        if let (Some(x), Some(y)) = (self.rk_number(b), self.rk_number(c)) {
+            let by_zero = matches!((&x, &y), (LuaValue::Integer(_), LuaValue::Integer(0)));
+            if !(by_zero && (op == LUA_OPIDIV || op == LUA_OPMOD)) {
+                if let Some(val) = api_arith::_arith(&x, &y, op) {
+                    self.stack_mut().set_reg(a as usize, val);
+                    return;
+                }
+            }
+        }
+        self.get_rk(b);
+        if op != LUA_OPUNM && op != LUA_OPBNOT {
+            self.get_rk(c);
+        }
+        self.arith(op);
+        self.replace(a + 1);
+    }
+
+    fn compare_rk(&mut self, op: CompareOp, b: isize, c: isize) -> bool {
+        if let (Some(x), Some(y)) = (self.rk_number(b), self.rk_number(c)) {
+            return api_compare::compare(&x, &y, op);
+        }
+        self.get_rk(b);
+        self.get_rk(c);
+        let r = self.compare(-2, -1, op);
+        self.pop(2);
+        r
+    }
+
+    // A hit needs a present field; absent ones may have an __index.
+    fn get_table_rk(&mut self, idx: isize, rk: isize) {
+        let idx = self.abs_index(idx);
+        if let Some((tbl, key)) = self.cached_field(idx, rk) {
+            let val = self.with_field_cache(|hint| tbl.borrow().get_hinted(&key, hint));
+            if !val.is_nil() {
+                self.stack_mut().push(val);
+                return;
+            }
+        }
+        self.get_rk(rk);
+        self.get_table(idx);
+    }
+
+    // A hit needs a present field; new ones may have a __newindex and
+    // are charged for.
+    fn set_table_rk(&mut self, idx: isize, b: isize, c: isize) {
+        let idx = self.abs_index(idx);
+        if let Some((tbl, key)) = self.cached_field(idx, b) {
+            self.get_rk(c);
+            let val = self.stack_mut().pop();
+            let hit = self.with_field_cache(|hint| tbl.borrow_mut().set_hinted(&key, val, hint));
+            if hit {
+                return;
+            }
+        }
+        self.get_rk(b);
+        self.get_rk(c);
+        self.set_table(idx);
+    }
+}
+
+impl LuaState {
+    fn constant(&self, idx: isize) -> LuaValue {
+        match &self.stack().closure.proto.constants[idx as usize] {
+            Constant::Nil => LuaValue::Nil,
+            Constant::Boolean(b) => LuaValue::Boolean(*b),
+            Constant::Integer(i) => LuaValue::Integer(*i),
+            Constant::Number(n) => LuaValue::Number(*n),
+            Constant::Str(s) => LuaValue::Str(s.clone()),
+        }
+    }
+
+    // The table at `idx` and the key RK(rk), if the key is a constant
+    // string that the field cache can be used for.
+    fn cached_field(&self, idx: isize, rk: isize) -> Option<(Rc<RefCell<LuaTable>>, LuaValue)> {
+        if rk <= 0xFF {
+            return None;
+        }
+        let key = match &self.stack().closure.proto.constants[(rk & 0xFF) as usize] {
+            Constant::Str(s) => LuaValue::Str(s.clone()),
+            _ => return None,
+        };
+        match self.stack().get(idx) {
+            LuaValue::Table(tbl) => Some((tbl, key)),
+            _ => None,
+        }
+    }
+
+    // Runs `f` on the field cache of the instruction being executed.
+    fn with_field_cache<T>(&self, f: impl FnOnce(&mut usize) -> T) -> T {
+        let caches = &self.stack().closure.proto.field_caches;
+        let cache = caches.get(self.stack().pc as usize - 1);
+        let mut hint = cache.map_or(usize::MAX, Cell::get);
+        let r = f(&mut hint);
+        if let Some(cache) = cache {
+            cache.set(hint);
+        }
+        r
+    }
+
+    // The value of RK(rk) if it is a number, read without touching the stack.
+    fn rk_number(&self, rk: isize) -> Option<LuaValue> {
+        let val = if rk > 0xFF {
+            match self.stack().closure.proto.constants[(rk & 0xFF) as usize] {
+                Constant::Integer(i) => LuaValue::Integer(i),
+                Constant::Number(n) => LuaValue::Number(n),
+                _ => return None,
+            }
+        } else {
+            match self.stack().reg(rk as usize)? {
+                LuaValue::Integer(i) => LuaValue::Integer(*i),
+                LuaValue::Number(n) => LuaValue::Number(*n),
+                _ => return None,
+            }
+        };
+        Some(val)
     }
 }
@@ -1,47 +1,122 @@
-use std::collections::HashMap;
-use std::hash::Hash;
+use core::cell::RefCell;
+use std::rc::Rc;
 use super::lua_value::LuaValue;
 use crate::number::math;
 
+const MAXABITS: usize = 31; // the array part has at most 2^MAXABITS slots
+const MAXASIZE: usize = 1 << MAXABITS;
+const MAXHBITS: usize = 30;
+pub const MAXHSIZE: usize = 1 << MAXHBITS; // the hash part has at most MAXHSIZE nodes
+
+// A new key needs a hash part larger than MAXHSIZE; the state raises it
+// as the Lua error "table overflow".
+#[derive(Debug)]
+pub struct TableOverflow;
+
+/*
+    A table in two parts, as in the reference implementation: an array
+    part for the integer keys 1..n and a hash part of 2^k nodes, where
+    colliding keys are chained through the nodes themselves (a chained
+    scatter table with Brent's variation: a key found out of its main
+    position is moved away when the owner of that position comes).
+    Nodes stay where they are until a new key finds no free node; then
+    `rehash` counts the integer keys to size both parts again.
+*/
 pub struct LuaTable {
     arr: Vec<LuaValue>,
-    map: HashMap<LuaValue, LuaValue>,
-    rdm: usize, // hash code
+    nodes: Vec<Node>, // empty or a power of two
+    last_free: usize, // the nodes from here on are taken
+    pub metatable: Option<Rc<RefCell<LuaTable>>>,
 }
 
-impl Hash for LuaTable {
-    fn hash<H>(&self, state: &mut H) where H: std::hash::Hasher {
-        self.rdm.hash(state)
-    }
+struct Node {
+    key: LuaValue, // Nil if free; a removed field keeps its key until a rehash
+    val: LuaValue,
+    next: Option<u32>, // next node of the chain
 }
 
 impl LuaTable {
+    // `nrec` is at most MAXHSIZE.
     pub fn new(narr: usize, nrec: usize) -> LuaTable {
+        let nodes = new_nodes(nrec);
         LuaTable {
-            arr: Vec::with_capacity(narr),
-            map: HashMap::with_capacity(nrec),
-            rdm: math::random(),
+            arr: vec![LuaValue::Nil; narr],
+            last_free: nodes.len(),
+            nodes,
+            metatable: None,
         }
     }
 
+    // A border of the table: a present key n with n + 1 absent, or 0.
     pub fn len(&self) -> usize {
-        self.arr.len()
+        let mut j = self.arr.len();
+        if j > 0 && self.arr[j - 1].is_nil() {
+            // there is a border in the array part
+            let mut i = 0;
+            while j - i > 1 {
+                let m = (i + j) / 2;
+                if self.arr[m - 1].is_nil() {
+                    j = m;
+                } else {
+                    i = m;
+                }
+            }
+            return i;
+        }
+        if self.nodes.is_empty() {
+            return j;
+        }
+
+        // look in the hash part for j present and j * 2 absent
+        let mut i = j;
+        j += 1;
+        while !self.get_int(j).is_nil() {
+            i = j;
+            if j > i64::MAX as usize / 2 {
+                // built to be slow: resort to a linear search
+                i = 1;
+                while !self.get_int(i).is_nil() {
+                    i += 1;
+                }
+                return i - 1;
+            }
+            j *= 2;
+        }
+        while j - i > 1 {
+            let m = (i + j) / 2;
+            if self.get_int(m).is_nil() {
+                j = m;
+            } else {
+                i = m;
+            }
+        }
+        i
     }
 
     pub fn get(&self, key: &LuaValue) -> LuaValue {
-        if let Some(idx) = to_index(key) {
-            if idx <= self.arr.len() {
-                return self.arr[idx - 1].clone();
-            }
+        if let Some(i) = float_key(key) {
+            return self.get(&LuaValue::Integer(i));
         }
-        if let Some(val) = self.map.get(key) {
-            val.clone()
-        } else {
-            LuaValue::Nil
+        if let Some(i) = self.array_slot(key) {
+            return self.arr[i].clone();
+        }
+        match self.find_node(key) {
+            Some(n) => self.nodes[n].val.clone(),
+            None => LuaValue::Nil,
+        }
+    }
+
+    fn get_int(&self, i: usize) -> LuaValue {
+        match self.arr.get(i.wrapping_sub(1)) {
+            Some(val) => val.clone(),
+            None => self.get(&LuaValue::Integer(i as i64)),
         }
     }
 
-    pub fn put(&mut self, key: LuaValue, val: LuaValue) {
+    pub fn put(&mut self, mut key: LuaValue, val: LuaValue) -> Result<(), TableOverflow> {
+        if let Some(i) = float_key(&key) {
+            key = LuaValue::Integer(i);
+        }
         if key.is_nil() {
             panic!("Table index is nil!");
         }
@@ -51,69 +126,451 @@ impl LuaTable {
             }
         }
 
-        if let Some(idx) = to_index(&key) {
-            let arr_len = self.arr.len();
-            if idx <= arr_len {
-                let val_is_nil = val.is_nil();
-                self.arr[idx - 1] = val;
-                if idx == arr_len && val_is_nil {
-                    self.shrink_array();
-                }
-                return ;
+        if let Some(i) = self.array_slot(&key) {
+            self.arr[i] = val;
+        } else if let Some(n) = self.find_node(&key) {
+            self.nodes[n].val = val;
+        } else if !val.is_nil() {
+            return self.new_key(key, val);
+        }
+        Ok(())
+    }
+
+    /*
+        Lookups for the field caches of the VM, which remember the node
+        where an instruction last found its key. The node is used when it
+        still holds that key, and otherwise the node of the key is written
+        back into `hint`. Only for keys of the hash part that are not
+        numbers; a rehash moves the nodes and a removal leaves Nil in
+        them, so a stale hint at worst costs the lookup it saved.
+    */
+    pub fn get_hinted(&self, key: &LuaValue, hint: &mut usize) -> LuaValue {
+        if let Some(node) = self.nodes.get(*hint) {
+            if node.key == *key {
+                return node.val.clone();
             }
-            if idx == arr_len + 1 {
-                self.map.remove(&key);
-                if !val.is_nil() {
-                    self.arr.push(val);
-                    self.expand_array();
+        }
+        match self.find_node(key) {
+            Some(n) => {
+                *hint = n;
+                self.nodes[n].val.clone()
+            }
+            None => LuaValue::Nil,
+        }
+    }
+
+    // Replaces the value of a present field, or returns false if there is
+    // none, in which case the field is new and may need __newindex.
+    pub fn set_hinted(&mut self, key: &LuaValue, val: LuaValue, hint: &mut usize) -> bool {
+        let n = match self.nodes.get(*hint) {
+            Some(node) if node.key == *key => *hint,
+            _ => match self.find_node(key) {
+                Some(n) => {
+                    *hint = n;
+                    n
                 }
-                return ;
+                None => return false,
+            },
+        };
+        if self.nodes[n].val.is_nil() {
+            return false;
+        }
+        self.nodes[n].val = val;
+        true
+    }
+
+    /*
+        The key that follows `key` in a traversal, Nil when `key` is the
+        last one and None when `key` is not in the table. The array part
+        comes first and then the nodes in order, so the order only depends
+        on how the table was built. Removed fields keep their nodes until
+        a new key is added, so fields may be cleared or updated (but not
+        added) while traversing.
+    */
+    pub fn next_key(&self, key: &LuaValue) -> Option<LuaValue> {
+        let from = match key {
+            LuaValue::Nil => 0,
+            _ => match float_key(key) {
+                Some(i) => self.find_index(&LuaValue::Integer(i))?,
+                None => self.find_index(key)?,
+            },
+        };
+        for i in from..self.arr.len() {
+            if !self.arr[i].is_nil() {
+                return Some(LuaValue::Integer(i as i64 + 1));
             }
         }
+        let from = from.saturating_sub(self.arr.len());
+        let node = self.nodes.iter().skip(from).find(|node| !node.val.is_nil());
+        Some(node.map_or(LuaValue::Nil, |node| node.key.clone()))
+    }
 
-        if !val.is_nil() {
-            self.map.insert(key, val);
-        } else {
-            self.map.remove(&key);
+    // Where a traversal goes on after `key`: array slots are numbered
+    // from 0 and then nodes after them.
+    fn find_index(&self, key: &LuaValue) -> Option<usize> {
+        if let Some(i) = self.array_slot(key) {
+            return Some(i + 1);
+        }
+        self.find_node(key).map(|n| self.arr.len() + n + 1)
+    }
+
+    fn array_slot(&self, key: &LuaValue) -> Option<usize> {
+        match key {
+            LuaValue::Integer(i) if *i >= 1 && (*i as u64) <= self.arr.len() as u64 => Some(*i as usize - 1),
+            _ => None,
+        }
+    }
+
+    fn find_node(&self, key: &LuaValue) -> Option<usize> {
+        if self.nodes.is_empty() || key.is_nil() {
+            return None;
+        }
+        let mut n = self.main_position(key);
+        loop {
+            if self.nodes[n].key == *key {
+                return Some(n);
+            }
+            n = self.nodes[n].next? as usize;
         }
     }
 
-    fn shrink_array(&mut self) {
-        while !self.arr.is_empty() {
-            if self.arr.last().unwrap().is_nil() {
-                self.arr.pop();
+    // The node a key hashes to. Integers and booleans are their own hash;
+    // pointers and floats are taken modulo an odd number, since their
+    // low bits vary little.
+    fn main_position(&self, key: &LuaValue) -> usize {
+        let mask = self.nodes.len() - 1;
+        let odd = mask | 1;
+        match key {
+            LuaValue::Integer(i) => *i as usize & mask,
+            LuaValue::Boolean(b) => *b as usize & mask,
+            LuaValue::Str(s) => hash_str(s) as usize & mask,
+            LuaValue::Number(n) => {
+                let bits = n.to_bits();
+                (bits ^ (bits >> 32)) as usize % odd
+            }
+            LuaValue::Table(t) => Rc::as_ptr(t) as usize % odd,
+            LuaValue::Function(f) => Rc::as_ptr(f) as usize % odd,
+            LuaValue::UserData(u) => Rc::as_ptr(u) as usize % odd,
+            LuaValue::LightUserData(p) => *p as usize % odd,
+            LuaValue::Nil => 0,
+        }
+    }
+
+    /*
+        Puts a key that is not in the table into its main position. If
+        another key is there, one of them goes to a free node: the other
+        one when it is out of its own main position, the new one
+        otherwise, chained after it.
+    */
+    fn new_key(&mut self, key: LuaValue, val: LuaValue) -> Result<(), TableOverflow> {
+        if self.nodes.is_empty() {
+            self.rehash(&key)?;
+            return self.put(key, val);
+        }
+        let mut mp = self.main_position(&key);
+        if !self.nodes[mp].val.is_nil() {
+            let f = match self.free_node() {
+                Some(f) => f,
+                None => {
+                    self.rehash(&key)?;
+                    return self.put(key, val);
+                }
+            };
+            let other = self.main_position(&self.nodes[mp].key);
+            if other != mp {
+                // move the colliding node to the free one
+                let mut prev = other;
+                while self.nodes[prev].next != Some(mp as u32) {
+                    prev = self.nodes[prev].next.unwrap() as usize;
+                }
+                self.nodes[prev].next = Some(f as u32);
+                self.nodes.swap(mp, f);
             } else {
+                // the new key goes to the free node
+                self.nodes[f].next = self.nodes[mp].next;
+                self.nodes[mp].next = Some(f as u32);
+                mp = f;
+            }
+        }
+        self.nodes[mp].key = key;
+        self.nodes[mp].val = val;
+        Ok(())
+    }
+
+    fn free_node(&mut self) -> Option<usize> {
+        while self.last_free > 0 {
+            self.last_free -= 1;
+            if self.nodes[self.last_free].key.is_nil() {
+                return Some(self.last_free);
+            }
+        }
+        None
+    }
+
+    /*
+        Sizes the parts to hold the present fields and `extra`. The array
+        part gets the largest n, a power of 2, such that more than half of
+        the slots 1..n would be in use.
+    */
+    fn rehash(&mut self, extra: &LuaValue) -> Result<(), TableOverflow> {
+        let mut nums = [0; MAXABITS + 1]; // nums[i]: keys k with 2^(i-1) < k <= 2^i
+        let mut na = self.count_array(&mut nums);
+        let mut total = na;
+        for node in self.nodes.iter().filter(|node| !node.val.is_nil()) {
+            na += count_int(&node.key, &mut nums);
+            total += 1;
+        }
+        na += count_int(extra, &mut nums);
+        total += 1;
+
+        let mut a = 0; // keys up to 2^i
+        let mut asize = 0;
+        let mut in_array = 0;
+        for (i, &n) in nums.iter().enumerate() {
+            let twotoi = 1usize << i;
+            if na <= twotoi / 2 {
                 break;
             }
+            a += n;
+            if n > 0 && a > twotoi / 2 {
+                asize = twotoi;
+                in_array = a;
+            }
         }
+        self.resize(asize, total - in_array)
     }
 
-    fn expand_array(&mut self) {
-        let mut idx = self.arr.len() + 1;
-        loop {
-            let key = LuaValue::Integer(idx as i64);
-            if self.map.contains_key(&key) {
-                let val = self.map.remove(&key).unwrap();
-                self.arr.push(val);
-                idx += 1;
-            } else {
+    fn count_array(&self, nums: &mut [usize]) -> usize {
+        let mut total = 0;
+        let mut i = 1;
+        for (lg, count) in nums.iter_mut().enumerate() {
+            let lim = (1usize << lg).min(self.arr.len());
+            if i > lim {
                 break;
             }
+            let n = self.arr[i - 1..lim].iter().filter(|v| !v.is_nil()).count();
+            *count += n;
+            total += n;
+            i = lim + 1;
+        }
+        total
+    }
+
+    fn resize(&mut self, asize: usize, hsize: usize) -> Result<(), TableOverflow> {
+        if hsize > MAXHSIZE {
+            return Err(TableOverflow);
+        }
+        let old = std::mem::replace(&mut self.nodes, new_nodes(hsize));
+        self.last_free = self.nodes.len();
+        if asize < self.arr.len() {
+            // the vanishing slice goes to the hash part
+            let vanishing = self.arr.split_off(asize);
+            for (i, val) in vanishing.into_iter().enumerate() {
+                if !val.is_nil() {
+                    self.put(LuaValue::Integer((asize + i + 1) as i64), val)?;
+                }
+            }
+        } else {
+            self.arr.resize(asize, LuaValue::Nil);
+        }
+        for node in old.into_iter().rev() {
+            if !node.val.is_nil() {
+                self.put(node.key, node.val)?;
+            }
         }
+        Ok(())
+    }
+}
+
+fn new_nodes(size: usize) -> Vec<Node> {
+    if size == 0 {
+        return Vec::new();
     }
+    (0..size.next_power_of_two())
+        .map(|_| Node {
+            key: LuaValue::Nil,
+            val: LuaValue::Nil,
+            next: None,
+        })
+        .collect()
 }
 
-fn to_index(key: &LuaValue) -> Option<usize> {
-    if let LuaValue::Integer(i) = key {
-        if *i > 0 {
-            return Some(*i as usize);
+// Counts `key` in its slice of `nums` if it could go to the array part.
+fn count_int(key: &LuaValue, nums: &mut [usize]) -> usize {
+    match key {
+        LuaValue::Integer(k) if *k > 0 && *k as u64 <= MAXASIZE as u64 => {
+            let lg = (usize::BITS - (*k as usize - 1).leading_zeros()) as usize;
+            nums[lg] += 1;
+            1
         }
-    } else if let LuaValue::Number(n) = key {
-        if let Some(i) = math::float_to_integer(*n) {
-            if i > 0 {
-                return Some(i as usize);
+        _ => 0,
+    }
+}
+
+// Lua's string hash, which samples at most 32 bytes of long strings.
+fn hash_str(s: &[u8]) -> u32 {
+    let mut h = 0x2545_f491 ^ s.len() as u32;
+    let step = (s.len() >> 5) + 1;
+    let mut l = s.len();
+    while l >= step {
+        h ^= (h << 5).wrapping_add(h >> 2).wrapping_add(s[l - 1] as u32);
+        l -= step;
+    }
+    h
+}
+
+// Floats with an exact integer value are stored as integer keys.
+fn float_key(key: &LuaValue) -> Option<i64> {
+    match key {
+        LuaValue::Number(n) => math::float_to_integer(*n),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::random::Xoshiro256;
+    use std::collections::BTreeMap;
+
+    fn int(i: i64) -> LuaValue {
+        LuaValue::Integer(i)
+    }
+
+    fn keys(t: &LuaTable) -> Vec<LuaValue> {
+        let mut keys = vec![];
+        let mut key = LuaValue::Nil;
+        loop {
+            key = t.next_key(&key).unwrap();
+            if key.is_nil() {
+                return keys;
+            }
+            keys.push(key.clone());
+        }
+    }
+
+    #[test]
+    fn random_ops() {
+        // against a model, with keys that collide a lot in small tables
+        let mut rng = Xoshiro256::with_seeds(7, 0);
+        let mut t = LuaTable::new(0, 0);
+        let mut model = BTreeMap::new();
+        let strs: Vec<LuaValue> = (0..40).map(|i| LuaValue::new_string(format!("k{}", i))).collect();
+        for _ in 0..20000 {
+            let k = rng.project(99) as i64;
+            let key = match k % 3 {
+                0 => int(k - 30),
+                1 => int(k * 64),
+                _ => strs[k as usize % 40].clone(),
+            };
+            let val = if rng.project(3) == 0 { LuaValue::Nil } else { int(k) };
+            let name = format!("{:?}", key);
+            if val.is_nil() {
+                model.remove(&name);
+            } else {
+                model.insert(name.clone(), k);
             }
+            t.put(key.clone(), val).unwrap();
+            assert_eq!(t.get(&key).to_integer(), model.get(&name).copied());
+        }
+        let mut seen: Vec<String> = keys(&t).iter().map(|k| format!("{:?}", k)).collect();
+        seen.sort();
+        assert_eq!(seen, model.keys().cloned().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn array_part() {
+        let mut t = LuaTable::new(0, 0);
+        for i in 1..=100 {
+            t.put(int(i), int(i)).unwrap();
+        }
+        assert_eq!(t.arr.len(), 128);
+        assert!(t.nodes.is_empty());
+        assert_eq!(t.len(), 100);
+
+        // sparse keys stay in the hash part
+        let mut t = LuaTable::new(0, 0);
+        for i in 0..10 {
+            t.put(int(1 << (i * 4)), int(i)).unwrap();
+        }
+        assert!(t.arr.len() <= 1);
+        assert_eq!(t.nodes.len(), 16);
+        assert_eq!(t.get(&LuaValue::Number(256.0)).to_integer(), Some(2));
+
+        // a rehash moves keys between the parts
+        let mut t = LuaTable::new(4, 0);
+        t.put(int(1), int(1)).unwrap();
+        t.put(LuaValue::new_string("x"), int(0)).unwrap();
+        t.put(int(1000), int(1000)).unwrap();
+        assert_eq!(t.arr.len(), 1);
+        assert_eq!(t.get(&int(1000)).to_integer(), Some(1000));
+    }
+
+    #[test]
+    fn borders() {
+        let mut t = LuaTable::new(8, 0);
+        assert_eq!(t.len(), 0);
+        for i in 1..=5 {
+            t.put(int(i), int(i)).unwrap();
+        }
+        assert_eq!(t.len(), 5);
+        t.put(int(5), LuaValue::Nil).unwrap();
+        assert_eq!(t.len(), 4);
+
+        let mut t = LuaTable::new(0, 0);
+        t.put(LuaValue::new_string("x"), int(0)).unwrap();
+        for i in (1..=20).rev() {
+            t.put(int(i), int(i)).unwrap();
+        }
+        assert_eq!(t.len(), 20);
+    }
+
+    #[test]
+    fn traversal() {
+        let build = || {
+            let mut t = LuaTable::new(0, 0);
+            for i in 0..50 {
+                t.put(LuaValue::new_string(format!("k{}", i)), int(i)).unwrap();
+                t.put(int(i), int(i)).unwrap();
+            }
+            t
+        };
+        let order = keys(&build());
+        assert_eq!(order.len(), 100);
+        assert_eq!(order, keys(&build()));
+        assert_eq!(order[0], int(1));
+
+        // clearing fields while traversing
+        let mut t = build();
+        let mut key = LuaValue::Nil;
+        let mut n = 0;
+        loop {
+            key = t.next_key(&key).unwrap();
+            if key.is_nil() {
+                break;
+            }
+            t.put(key.clone(), LuaValue::Nil).unwrap();
+            n += 1;
+        }
+        assert_eq!(n, 100);
+        assert!(keys(&t).is_empty());
+        assert_eq!(t.next_key(&LuaValue::new_string("none")), None);
+    }
+
+    #[test]
+    fn hints() {
+        let mut t = LuaTable::new(0, 0);
+        let x = LuaValue::new_string("x");
+        t.put(x.clone(), int(1)).unwrap();
+        let mut hint = usize::MAX;
+        assert_eq!(t.get_hinted(&x, &mut hint).to_integer(), Some(1));
+        for i in 0..100 {
+            t.put(LuaValue::new_string(format!("k{}", i)), int(i)).unwrap(); // rehashes
         }
+        assert!(t.set_hinted(&x, int(2), &mut hint));
+        assert_eq!(t.get(&x).to_integer(), Some(2));
+        t.put(x.clone(), LuaValue::Nil).unwrap();
+        assert!(!t.set_hinted(&x, int(3), &mut hint));
+        assert!(t.get_hinted(&x, &mut hint).is_nil());
     }
-    None
 }
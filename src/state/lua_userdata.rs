@@ -0,0 +1,21 @@
+use core::cell::RefCell;
+use std::any::Any;
+use std::rc::Rc;
+use super::lua_table::LuaTable;
+use super::lua_value::LuaValue;
+
+pub struct Userdata {
+    pub data: Rc<dyn Any>,
+    pub metatable: Option<Rc<RefCell<LuaTable>>>,
+    pub user_value: LuaValue,
+}
+
+impl Userdata {
+    pub fn new(data: Rc<dyn Any>) -> Userdata {
+        Userdata {
+            data,
+            metatable: None,
+            user_value: LuaValue::Nil,
+        }
+    }
+}
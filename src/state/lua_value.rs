@@ -1,12 +1,12 @@
 use core::cell::RefCell;
+use std::ffi::c_void;
 use std::rc::Rc;
 use std::fmt;
-use std::hash::Hash;
 use crate::number::math;
 use crate::api::consts::*;
-use crate::binary::chunk::Prototype;
 use super::lua_table::LuaTable;
 use super::closure::Closure;
+use super::lua_userdata::Userdata;
 
 #[derive(Clone)]  // Add PartialEq & Debug for unit test.
 pub enum LuaValue {
@@ -14,9 +14,11 @@ pub enum LuaValue {
     Boolean(bool),
     Number(f64),
     Integer(i64),
-    Str(String),
+    Str(Rc<Vec<u8>>),               // Lua strings are immutable byte strings, shared.
     Table(Rc<RefCell<LuaTable>>),   // mutability inside of something immutable.
     Function(Rc<Closure>),
+    LightUserData(*mut c_void),     // opaque handle, compared by address.
+    UserData(Rc<RefCell<Userdata>>),
 }
 
 impl fmt::Debug for LuaValue {
@@ -26,9 +28,11 @@ impl fmt::Debug for LuaValue {
             LuaValue::Boolean(b) => write!(f, "({})", b),
             LuaValue::Integer(i) => write!(f, "({})", i),
             LuaValue::Number(n) => write!(f, "({})", n),
-            LuaValue::Str(s) => write!(f, "(\"{}\")", s),
+            LuaValue::Str(s) => write!(f, "(\"{}\")", String::from_utf8_lossy(s)),
             LuaValue::Table(_) => write!(f, "(table)"),
             LuaValue::Function(_) => write!(f, "(function)"),
+            LuaValue::LightUserData(p) => write!(f, "(userdata: {:p})", p),
+            LuaValue::UserData(_) => write!(f, "(userdata)"),
         }
     }
 }
@@ -44,11 +48,15 @@ impl PartialEq for LuaValue {
         } else if let (LuaValue::Number(x), LuaValue::Number(y)) = (self, other) {
             x == y
         }  else if let (LuaValue::Str(x), LuaValue::Str(y)) = (self, other) {
-            x == y
+            Rc::ptr_eq(x, y) || x == y
         }  else if let (LuaValue::Table(x), LuaValue::Table(y)) = (self, other) {
             Rc::ptr_eq(x, y)
         }  else if let (LuaValue::Function(x), LuaValue::Function(y)) = (self, other) {
             Rc::ptr_eq(x, y)
+        }  else if let (LuaValue::LightUserData(x), LuaValue::LightUserData(y)) = (self, other) {
+            x == y
+        }  else if let (LuaValue::UserData(x), LuaValue::UserData(y)) = (self, other) {
+            Rc::ptr_eq(x, y)
         } else {
             false
         }
@@ -58,28 +66,17 @@ impl PartialEq for LuaValue {
 // the trait `std::cmp::Eq` is not implemented for `f64`
 impl Eq for LuaValue { }
 
-// the trait `std::hash::Hash` is not implemented for `f64`
-impl Hash for LuaValue {
-    fn hash<H>(&self, state: &mut H) where H: std::hash::Hasher {
-        match self {
-            LuaValue::Nil => 0.hash(state),
-            LuaValue::Boolean(b) => b.hash(state),
-            LuaValue::Integer(i) => i.hash(state),
-            LuaValue::Number(n) => n.to_bits().hash(state),
-            LuaValue::Str(s) => s.hash(state),
-            LuaValue::Table(t) => t.borrow().hash(state),
-            LuaValue::Function(f) => f.hash(state),
-        }
-    }
-}
-
 impl LuaValue {
     pub fn new_table(narr: usize, nrec: usize) -> LuaValue {
         LuaValue::Table(Rc::new(RefCell::new(LuaTable::new(narr, nrec))))
     }
 
-    pub fn new_lua_closure(proto: Rc<Prototype>) -> LuaValue {
-        LuaValue::Function(Rc::new(Closure::new_lua_closure(proto)))
+    pub fn new_string<S: Into<Vec<u8>>>(s: S) -> LuaValue {
+        LuaValue::Str(Rc::new(s.into()))
+    }
+
+    pub fn new_userdata(data: Rc<dyn std::any::Any>) -> LuaValue {
+        LuaValue::UserData(Rc::new(RefCell::new(Userdata::new(data))))
     }
 
     pub fn is_nil(&self) -> bool {
@@ -96,6 +93,8 @@ impl LuaValue {
             LuaValue::Str(_) => LUA_TSTRING,
             LuaValue::Table(_) => LUA_TTABLE,
             LuaValue::Function(_) => LUA_TFUNCTION,
+            LuaValue::LightUserData(_) => LUA_TLIGHTUSERDATA,
+            LuaValue::UserData(_) => LUA_TUSERDATA,
         }
     }
 
@@ -120,13 +119,14 @@ impl LuaValue {
         match self {
             LuaValue::Integer(i) => Some(*i as f64),
             LuaValue::Number(n) => Some(*n),
-            LuaValue::Str(s) => s.parse::<f64>().ok(),
+            LuaValue::Str(s) => std::str::from_utf8(s).ok()?.parse::<f64>().ok(),
             _ => None,
         }
     }
 }
 
-fn string_to_integer(s: &String) -> Option<i64> {
+fn string_to_integer(s: &[u8]) -> Option<i64> {
+    let s = std::str::from_utf8(s).ok()?;
     if let Ok(i) = s.parse::<i64>() {
         Some(i)
     } else if let Ok(n) = s.parse::<f64>() {
@@ -149,8 +149,8 @@ mod tests {
         assert_eq!(LuaValue::Integer(1).to_boolean(), true);
         assert_eq!(LuaValue::Number(0.0).to_boolean(), true);
         assert_eq!(LuaValue::Number(-1.1).to_boolean(), true);
-        assert_eq!(LuaValue::Str("".to_string()).to_boolean(), true);
-        assert_eq!(LuaValue::Str("false".to_string()).to_boolean(), true);
+        assert_eq!(LuaValue::new_string("").to_boolean(), true);
+        assert_eq!(LuaValue::new_string("false").to_boolean(), true);
     }
     
     #[test]
@@ -161,12 +161,12 @@ mod tests {
         assert_eq!(LuaValue::Number(99.0).to_integer(), Some(99));
         assert_eq!(LuaValue::Number(-0.99).to_integer(), None);
         assert_eq!(LuaValue::Number(-99.0).to_integer(), Some(-99));
-        assert_eq!(LuaValue::Str("4096".to_string()).to_integer(), Some(4096));
-        assert_eq!(LuaValue::Str("4096.00".to_string()).to_integer(), Some(4096));
-        assert_eq!(LuaValue::Str("0.4096".to_string()).to_integer(), None);
-        assert_eq!(LuaValue::Str("0xff".to_string()).to_integer(), None);
-        assert_eq!(LuaValue::Str("010".to_string()).to_integer(), Some(10));
-        assert_eq!(LuaValue::Str("0x10".to_string()).to_integer(), None);
+        assert_eq!(LuaValue::new_string("4096").to_integer(), Some(4096));
+        assert_eq!(LuaValue::new_string("4096.00").to_integer(), Some(4096));
+        assert_eq!(LuaValue::new_string("0.4096").to_integer(), None);
+        assert_eq!(LuaValue::new_string("0xff").to_integer(), None);
+        assert_eq!(LuaValue::new_string("010").to_integer(), Some(10));
+        assert_eq!(LuaValue::new_string("0x10").to_integer(), None);
         assert_eq!(LuaValue::Nil.to_integer(), None);
         assert_eq!(LuaValue::Boolean(true).to_integer(), None);
         assert_eq!(LuaValue::Boolean(false).to_integer(), None);
@@ -180,15 +180,25 @@ mod tests {
         assert_eq!(LuaValue::Number(99.0).to_number(), Some(99.0));
         assert_eq!(LuaValue::Number(-0.99).to_number(), Some(-0.99));
         assert_eq!(LuaValue::Number(-99.0).to_number(), Some(-99.0));
-        assert_eq!(LuaValue::Str("4096".to_string()).to_number(), Some(4096.0));
-        assert_eq!(LuaValue::Str("4096.00".to_string()).to_number(), Some(4096.0));
-        assert_eq!(LuaValue::Str("0.4096".to_string()).to_number(), Some(0.4096));
-        assert_eq!(LuaValue::Str("0xff".to_string()).to_number(), None);
-        assert_eq!(LuaValue::Str("010".to_string()).to_number(), Some(10.0));
-        assert_eq!(LuaValue::Str("0x10".to_string()).to_number(), None);
-        assert_eq!(LuaValue::Str(".01".to_string()).to_number(), Some(0.01));
+        assert_eq!(LuaValue::new_string("4096").to_number(), Some(4096.0));
+        assert_eq!(LuaValue::new_string("4096.00").to_number(), Some(4096.0));
+        assert_eq!(LuaValue::new_string("0.4096").to_number(), Some(0.4096));
+        assert_eq!(LuaValue::new_string("0xff").to_number(), None);
+        assert_eq!(LuaValue::new_string("010").to_number(), Some(10.0));
+        assert_eq!(LuaValue::new_string("0x10").to_number(), None);
+        assert_eq!(LuaValue::new_string(".01").to_number(), Some(0.01));
         assert_eq!(LuaValue::Nil.to_number(), None);
         assert_eq!(LuaValue::Boolean(true).to_number(), None);
         assert_eq!(LuaValue::Boolean(false).to_number(), None);
     }
+
+    #[test]
+    fn lua_value_size() {
+        // a tag and one word, copying a string only bumps a count
+        assert_eq!(std::mem::size_of::<LuaValue>(), 16);
+        let s = LuaValue::new_string("shared");
+        if let (LuaValue::Str(x), LuaValue::Str(y)) = (&s, &s.clone()) {
+            assert!(Rc::ptr_eq(x, y));
+        }
+    }
 }
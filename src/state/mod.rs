@@ -1,23 +1,29 @@
 mod api_arith;
 mod api_compare;
+mod api_debug;
 mod api_stack;
 mod closure;
 mod lua_stack;
 mod lua_state;
 mod lua_table;
+mod lua_userdata;
 mod lua_value;
 
 pub use self::lua_state::LuaState;
-use crate::binary::chunk::Prototype;
-use std::rc::Rc;
-use core::cell::RefCell;
+#[cfg(test)]
+use crate::{api::consts::LUA_RIDX_GLOBALS, binary::chunk::Prototype};
+#[cfg(test)]
+use std::{cell::RefCell, rc::Rc};
 
-pub fn new_lua_state(stack_size: usize, proto: Rc<Prototype>) -> Rc<RefCell<LuaState>> {
+#[cfg(test)]
+pub(crate) fn new_lua_state(stack_size: usize, proto: Rc<Prototype>) -> Rc<RefCell<LuaState>> {
     let ls = Rc::new(RefCell::new(LuaState::new()));
-    ls.borrow_mut().push_frame(self::lua_stack::LuaStack::new(
-            stack_size,
-            Rc::new(self::closure::Closure::new_lua_closure(proto))
-    ));
+    let closure = self::closure::Closure::new_lua_closure(proto);
+    if let Some(env) = closure.upvals.first() {
+        let globals = ls.borrow().registry_get(&lua_value::LuaValue::Integer(LUA_RIDX_GLOBALS as i64));
+        *env.borrow().borrow_mut() = self::closure::Upvalue::Closed(globals);
+    }
+    ls.borrow_mut().push_frame(self::lua_stack::LuaStack::new(stack_size, Rc::new(closure)));
     ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
     ls
 }
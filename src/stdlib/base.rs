@@ -0,0 +1,846 @@
+use crate::api::consts::*;
+use crate::api::{LuaAPI, LuaAuxLib, RustFn};
+use std::io::{self, Write};
+
+const BASE_FUNCS: &[(&str, RustFn)] = &[
+    ("assert", base_assert),
+    ("error", base_error),
+    ("getmetatable", base_getmetatable),
+    ("ipairs", base_ipairs),
+    ("load", base_load),
+    ("next", base_next),
+    ("pairs", base_pairs),
+    ("pcall", base_pcall),
+    ("print", base_print),
+    ("rawequal", base_rawequal),
+    ("rawget", base_rawget),
+    ("rawlen", base_rawlen),
+    ("rawset", base_rawset),
+    ("select", base_select),
+    ("setmetatable", base_setmetatable),
+    ("tonumber", base_tonumber),
+    ("tostring", base_tostring),
+    ("type", base_type),
+];
+
+// Base functions that read files, made global by `open_io` since the
+// base library is given to every script.
+pub const FILE_FUNCS: &[(&str, RustFn)] = &[("dofile", base_dofile)];
+
+// Install the base functions into the global table and push it. Open it
+// with `requiref("_G", open_base, true)` to also register it in `_LOADED`.
+pub fn open_base(ls: &mut dyn LuaAPI) -> usize {
+    /* open lib into global table */
+    ls.push_global_table();
+    ls.set_funcs(BASE_FUNCS, 0);
+    /* set global _G */
+    ls.push_value(-1);
+    ls.set_field(-2, "_G");
+    /* set global _VERSION */
+    ls.push_string(LUA_VERSION.to_string());
+    ls.set_field(-2, "_VERSION");
+    1
+}
+
+// print (···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-print
+fn base_print(ls: &mut dyn LuaAPI) -> usize {
+    let n = ls.get_top();
+    let mut line = Vec::new();
+    ls.get_global("tostring");
+    for i in 1..=n {
+        ls.push_value(-1); // function to be called
+        ls.push_value(i); // value to print
+        ls.call(1, 1);
+        match ls.to_bytes(-1) {
+            Some(s) => line.extend_from_slice(&s),
+            None => ls.error_l("'tostring' must return a string to 'print'"),
+        }
+        if i < n {
+            line.push(b'\t');
+        }
+        ls.pop(1); // pop result
+    }
+    line.push(b'\n');
+    let _ = io::stdout().write_all(&line);
+    0
+}
+
+// type (v)
+// http://www.lua.org/manual/5.3/manual.html#pdf-type
+fn base_type(ls: &mut dyn LuaAPI) -> usize {
+    let t = ls.type_id(1);
+    ls.arg_check(t != LUA_TNONE, 1, "value expected");
+    let name = ls.type_name(t).to_string();
+    ls.push_string(name);
+    1
+}
+
+// tostring (v)
+// http://www.lua.org/manual/5.3/manual.html#pdf-tostring
+fn base_tostring(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_any(1);
+    ls.tolstring_l(1);
+    1
+}
+
+// tonumber (e [, base])
+// http://www.lua.org/manual/5.3/manual.html#pdf-tonumber
+fn base_tonumber(ls: &mut dyn LuaAPI) -> usize {
+    if ls.is_none_or_nil(2) {
+        // standard conversion?
+        if ls.type_id(1) == LUA_TNUMBER {
+            ls.set_top(1); // yes; return it
+            return 1;
+        }
+        if ls.type_id(1) == LUA_TSTRING {
+            let s = ls.to_string(1);
+            if ls.string_to_number(&s) {
+                return 1; // successful conversion to number
+            }
+        }
+        ls.check_any(1); // (but there must be some parameter)
+    } else {
+        let base = ls.check_integer(2);
+        ls.check_type(1, LUA_TSTRING); // no numbers as strings
+        let s = ls.to_string(1);
+        ls.arg_check((2..=36).contains(&base), 2, "base out of range");
+        if let Some(n) = str_to_int(&s, base as u32) {
+            ls.push_integer(n);
+            return 1;
+        }
+    }
+    ls.push_nil(); // not a number
+    1
+}
+
+// Integer numeral in the given base, see `l_str2int` in lbaselib.c
+fn str_to_int(s: &str, base: u32) -> Option<i64> {
+    let s = s.trim_matches(|c| " \x0c\n\r\t\x0b".contains(c));
+    let (neg, digits) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    if digits.is_empty() {
+        return None; // no digit?
+    }
+    let mut n: i64 = 0;
+    for c in digits.chars() {
+        let digit = c.to_digit(base)?;
+        n = n.wrapping_mul(base as i64).wrapping_add(digit as i64);
+    }
+    Some(if neg { n.wrapping_neg() } else { n })
+}
+
+// pairs (t)
+// http://www.lua.org/manual/5.3/manual.html#pdf-pairs
+fn base_pairs(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_any(1);
+    if ls.get_metafield(1, "__pairs") == LUA_TNIL {
+        // no metamethod?
+        ls.push_rust_fn(base_next); // will return generator,
+        ls.push_value(1); // state,
+        ls.push_nil(); // and initial value
+    } else {
+        ls.push_value(1); // argument 'self' to metamethod
+        ls.call(1, 3); // get 3 values from metamethod
+    }
+    3
+}
+
+// ipairs (t)
+// http://www.lua.org/manual/5.3/manual.html#pdf-ipairs
+fn base_ipairs(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_any(1);
+    ls.push_rust_fn(ipairs_aux); // iteration function
+    ls.push_value(1); // state
+    ls.push_integer(0); // initial value
+    3
+}
+
+fn ipairs_aux(ls: &mut dyn LuaAPI) -> usize {
+    let i = ls.check_integer(2).wrapping_add(1);
+    ls.push_integer(i);
+    if ls.get_i(1, i) == LUA_TNIL {
+        1
+    } else {
+        2
+    }
+}
+
+// next (table [, index])
+// http://www.lua.org/manual/5.3/manual.html#pdf-next
+fn base_next(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_type(1, LUA_TTABLE);
+    ls.set_top(2); // create a 2nd argument if there isn't one
+    if ls.next(1) {
+        2
+    } else {
+        ls.push_nil();
+        1
+    }
+}
+
+// select (n, ···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-select
+fn base_select(ls: &mut dyn LuaAPI) -> usize {
+    let n = ls.get_top() as i64;
+    if ls.type_id(1) == LUA_TSTRING && ls.to_string(1).starts_with('#') {
+        ls.push_integer(n - 1);
+        1
+    } else {
+        let mut i = ls.check_integer(1);
+        if i < 0 {
+            i += n;
+        } else if i > n {
+            i = n;
+        }
+        ls.arg_check(1 <= i, 1, "index out of range");
+        (n - i) as usize
+    }
+}
+
+// rawequal (v1, v2)
+// http://www.lua.org/manual/5.3/manual.html#pdf-rawequal
+fn base_rawequal(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_any(1);
+    ls.check_any(2);
+    let eq = ls.raw_equal(1, 2);
+    ls.push_boolean(eq);
+    1
+}
+
+// rawlen (v)
+// http://www.lua.org/manual/5.3/manual.html#pdf-rawlen
+fn base_rawlen(ls: &mut dyn LuaAPI) -> usize {
+    let t = ls.type_id(1);
+    ls.arg_check(t == LUA_TTABLE || t == LUA_TSTRING, 1, "table or string expected");
+    let n = ls.raw_len(1);
+    ls.push_integer(n as i64);
+    1
+}
+
+// rawget (table, index)
+// http://www.lua.org/manual/5.3/manual.html#pdf-rawget
+fn base_rawget(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_type(1, LUA_TTABLE);
+    ls.check_any(2);
+    ls.set_top(2);
+    ls.raw_get(1);
+    1
+}
+
+// rawset (table, index, value)
+// http://www.lua.org/manual/5.3/manual.html#pdf-rawset
+fn base_rawset(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_type(1, LUA_TTABLE);
+    ls.check_any(2);
+    ls.check_any(3);
+    ls.set_top(3);
+    ls.raw_set(1);
+    1
+}
+
+// assert (v [, message])
+// http://www.lua.org/manual/5.3/manual.html#pdf-assert
+fn base_assert(ls: &mut dyn LuaAPI) -> usize {
+    if ls.to_boolean(1) {
+        // condition is true?
+        ls.get_top() as usize // return all arguments
+    } else {
+        ls.check_any(1); // there must be a condition
+        ls.remove(1); // remove it
+        ls.push_string("assertion failed!".to_string()); // default message
+        ls.set_top(1); // leave only message (default if no other one)
+        base_error(ls) // call 'error'
+    }
+}
+
+// error (message [, level])
+// http://www.lua.org/manual/5.3/manual.html#pdf-error
+fn base_error(ls: &mut dyn LuaAPI) -> usize {
+    let level = ls.opt_integer(2, 1);
+    ls.set_top(1);
+    if ls.type_id(1) == LUA_TSTRING && level > 0 {
+        let position = ls.where_l(level as isize); // add extra information
+        ls.push_string(position);
+        ls.push_value(1);
+        ls.concat(2);
+    }
+    ls.error()
+}
+
+// pcall (f [, arg1, ···])
+// http://www.lua.org/manual/5.3/manual.html#pdf-pcall
+fn base_pcall(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_any(1);
+    ls.push_boolean(true); // first result if no errors
+    ls.insert(1); // put it in place
+    let nargs = ls.get_top() as usize - 2;
+    if ls.pcall(nargs, LUA_MULTRET, 0) == LUA_OK {
+        ls.get_top() as usize // return all results
+    } else {
+        ls.push_boolean(false); // first result (false)
+        ls.push_value(-2); // error message
+        2 // return false, msg
+    }
+}
+
+// setmetatable (table, metatable)
+// http://www.lua.org/manual/5.3/manual.html#pdf-setmetatable
+fn base_setmetatable(ls: &mut dyn LuaAPI) -> usize {
+    let t = ls.type_id(2);
+    ls.check_type(1, LUA_TTABLE);
+    ls.arg_check(t == LUA_TNIL || t == LUA_TTABLE, 2, "nil or table expected");
+    if ls.get_metafield(1, "__metatable") != LUA_TNIL {
+        ls.error_l("cannot change a protected metatable");
+    }
+    ls.set_top(2);
+    ls.set_metatable(1);
+    1
+}
+
+// getmetatable (object)
+// http://www.lua.org/manual/5.3/manual.html#pdf-getmetatable
+fn base_getmetatable(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_any(1);
+    if !ls.get_metatable(1) {
+        ls.push_nil();
+        return 1; // no metatable
+    }
+    ls.get_metafield(1, "__metatable");
+    1 // returns either __metatable field (if present) or metatable
+}
+
+// load (chunk [, chunkname [, mode [, env]]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-load
+fn base_load(ls: &mut dyn LuaAPI) -> usize {
+    let mode = ls.opt_string(3, "bt");
+    let env = if ls.is_none(4) { None } else { Some(4) }; // 'env' index if given
+    let (chunk, chunk_name) = if let Some(s) = ls.to_bytes(1) {
+        // loading a string?
+        let chunk_name = ls.opt_string(2, &String::from_utf8_lossy(&s));
+        (s, chunk_name)
+    } else {
+        // loading from a reader function
+        let chunk_name = ls.opt_string(2, "=(load)");
+        ls.check_type(1, LUA_TFUNCTION);
+        (read_chunk(ls), chunk_name)
+    };
+    let status = ls.load(chunk, &chunk_name, &mode, env);
+    load_aux(ls, status)
+}
+
+// Concatenate the pieces returned by the reader function at index 1.
+fn read_chunk(ls: &mut dyn LuaAPI) -> Vec<u8> {
+    let mut chunk = Vec::new();
+    loop {
+        ls.push_value(1); // get function
+        ls.call(0, 1); // call it
+        if ls.is_nil(-1) {
+            ls.pop(1);
+            break;
+        } else if !ls.is_string(-1) {
+            ls.error_l("reader function must return a string");
+        }
+        let piece = ls.to_bytes(-1).unwrap();
+        ls.pop(1);
+        if piece.is_empty() {
+            break;
+        }
+        chunk.extend_from_slice(&piece);
+    }
+    chunk
+}
+
+fn load_aux(ls: &mut dyn LuaAPI, status: LuaStatus) -> usize {
+    if status == LUA_OK {
+        1
+    } else {
+        // error (message is on top of the stack)
+        ls.push_nil();
+        ls.insert(-2); // put before error message
+        2 // return nil plus error message
+    }
+}
+
+// dofile ([filename])
+// http://www.lua.org/manual/5.3/manual.html#pdf-dofile
+fn base_dofile(ls: &mut dyn LuaAPI) -> usize {
+    let filename = ls.opt_string(1, "");
+    ls.set_top(1);
+    let filename = if filename.is_empty() { None } else { Some(filename.as_str()) };
+    if ls.load_file(filename, "bt") != LUA_OK {
+        ls.error();
+    }
+    ls.call(0, LUA_MULTRET);
+    ls.get_top() as usize - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LuaState;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /* Lua source code:
+        assert(_G._G == _G and _VERSION == "Lua 5.3")
+        assert(type(nil) == "nil" and type(print) == "function" and type({}) == "table")
+        assert(tostring(1) == "1" and tostring(1.5) == "1.5" and tostring(true) == "true")
+        assert(tonumber("0x10") == 16 and tonumber(" 1e1 ") == 10.0 and tonumber("z") == nil)
+        assert(tonumber("ff", 16) == 255 and tonumber("-zz", 36) == -1295 and tonumber("8", 8) == nil)
+        assert(select("#", 1, nil, 3) == 3 and select(-1, 1, 2, 3) == 3 and select(2, "a", "b") == "b")
+        local t = setmetatable({}, {__index = function() return 1 end, __metatable = "locked"})
+        assert(getmetatable(t) == "locked" and t.x == 1 and rawget(t, "x") == nil)
+        assert(rawequal(t, t) and not rawequal(t, {}) and rawlen({1, 2}) == 2 and rawlen("abc") == 3)
+        assert(rawset(t, "x", 2) == t and t.x == 2)
+        local sum = 0
+        for i, v in ipairs({10, 20, 30, nil, 50}) do sum = sum + i * v end
+        local keys = 0
+        for k, v in pairs({a = 1, b = 2, 3}) do keys = keys + v end
+        assert(sum == 140 and keys == 6 and next({}) == nil)
+        local p = setmetatable({}, {__pairs = function(t) return function(_, k) if not k then return 1, "one" end end, t, nil end})
+        for k, v in pairs(p) do assert(k == 1 and v == "one") end
+        local ok, err = pcall(error, "boom")
+        assert(not ok and err == "boom")
+        ok, err = pcall(error, {code = 1})
+        assert(not ok and err.code == 1)
+        ok, err = pcall(function() error("here") end)
+        assert(err == "base.lua:22: here")
+        ok, err = pcall(assert, false)
+        assert(err == "assertion failed!")
+        ok, err = pcall(assert, nil, "custom")
+        assert(err == "custom")
+        ok, err = pcall(setmetatable, t, {})
+        assert(err == "cannot change a protected metatable")
+        ok, err = pcall(rawlen, 1)
+        assert(err == "bad argument #1 to 'rawlen' (table or string expected)")
+        ok, err = pcall(tonumber, "10", 99)
+        assert(err == "bad argument #2 to 'tonumber' (base out of range)")
+        ok, err = pcall(select, 0)
+        assert(err == "bad argument #1 to 'select' (index out of range)")
+        ok, err = pcall(tostring)
+        assert(err == "bad argument #1 to 'tostring' (value expected)")
+        local f, msg = load(function() return nil end, "=empty", "t")
+        return f, msg, select(2, load("\27Lua", "bin", "t"))
+    */
+    const LUA_BASE_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x0a, 0x40,
+        0x62, 0x61, 0x73, 0x65, 0x2e, 0x6c, 0x75, 0x61, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x10, 0x84, 0x01, 0x00, 0x00, 0x06,
+        0x00, 0x40, 0x00, 0x46, 0x40, 0x40, 0x00, 0x47, 0x40, 0xc0, 0x00, 0x86,
+        0x40, 0x40, 0x00, 0x1f, 0x80, 0x80, 0x00, 0x1e, 0x80, 0x00, 0x80, 0x46,
+        0x80, 0x40, 0x00, 0x5f, 0xc0, 0xc0, 0x00, 0x1e, 0x00, 0x00, 0x80, 0x43,
+        0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01, 0x06,
+        0x00, 0x40, 0x00, 0x46, 0x00, 0x41, 0x00, 0x84, 0x00, 0x00, 0x00, 0x64,
+        0x80, 0x00, 0x01, 0x1f, 0x40, 0xc1, 0x00, 0x1e, 0x40, 0x02, 0x80, 0x46,
+        0x00, 0x41, 0x00, 0x86, 0x80, 0x41, 0x00, 0x64, 0x80, 0x00, 0x01, 0x1f,
+        0xc0, 0xc1, 0x00, 0x1e, 0x00, 0x01, 0x80, 0x46, 0x00, 0x41, 0x00, 0x8b,
+        0x00, 0x00, 0x00, 0x64, 0x80, 0x00, 0x01, 0x5f, 0x00, 0xc2, 0x00, 0x1e,
+        0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00, 0x24,
+        0x40, 0x00, 0x01, 0x06, 0x00, 0x40, 0x00, 0x46, 0x40, 0x42, 0x00, 0x81,
+        0x80, 0x02, 0x00, 0x64, 0x80, 0x00, 0x01, 0x1f, 0xc0, 0xc2, 0x00, 0x1e,
+        0x40, 0x02, 0x80, 0x46, 0x40, 0x42, 0x00, 0x81, 0x00, 0x03, 0x00, 0x64,
+        0x80, 0x00, 0x01, 0x1f, 0x40, 0xc3, 0x00, 0x1e, 0x00, 0x01, 0x80, 0x46,
+        0x40, 0x42, 0x00, 0x83, 0x00, 0x80, 0x00, 0x64, 0x80, 0x00, 0x01, 0x5f,
+        0x80, 0xc3, 0x00, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00, 0x43,
+        0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40, 0x00, 0x46,
+        0xc0, 0x43, 0x00, 0x81, 0x00, 0x04, 0x00, 0x64, 0x80, 0x00, 0x01, 0x1f,
+        0x40, 0xc4, 0x00, 0x1e, 0x40, 0x02, 0x80, 0x46, 0xc0, 0x43, 0x00, 0x81,
+        0x80, 0x04, 0x00, 0x64, 0x80, 0x00, 0x01, 0x1f, 0xc0, 0xc4, 0x00, 0x1e,
+        0x00, 0x01, 0x80, 0x46, 0xc0, 0x43, 0x00, 0x81, 0x00, 0x05, 0x00, 0x64,
+        0x80, 0x00, 0x01, 0x5f, 0x40, 0xc5, 0x00, 0x1e, 0x00, 0x00, 0x80, 0x43,
+        0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01, 0x06,
+        0x00, 0x40, 0x00, 0x46, 0xc0, 0x43, 0x00, 0x81, 0x80, 0x05, 0x00, 0xc1,
+        0x40, 0x04, 0x00, 0x64, 0x80, 0x80, 0x01, 0x1f, 0xc0, 0xc5, 0x00, 0x1e,
+        0xc0, 0x02, 0x80, 0x46, 0xc0, 0x43, 0x00, 0x81, 0x00, 0x06, 0x00, 0xc1,
+        0x40, 0x06, 0x00, 0x64, 0x80, 0x80, 0x01, 0x1f, 0x80, 0xc6, 0x00, 0x1e,
+        0x40, 0x01, 0x80, 0x46, 0xc0, 0x43, 0x00, 0x81, 0xc0, 0x06, 0x00, 0xc1,
+        0x00, 0x07, 0x00, 0x64, 0x80, 0x80, 0x01, 0x5f, 0x40, 0xc5, 0x00, 0x1e,
+        0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00, 0x24,
+        0x40, 0x00, 0x01, 0x06, 0x00, 0x40, 0x00, 0x46, 0x40, 0x47, 0x00, 0x81,
+        0x80, 0x07, 0x00, 0xc1, 0x80, 0x02, 0x00, 0x04, 0x01, 0x00, 0x00, 0x41,
+        0xc1, 0x07, 0x00, 0x64, 0x80, 0x80, 0x02, 0x1f, 0xc0, 0xc7, 0x00, 0x1e,
+        0x80, 0x03, 0x80, 0x46, 0x40, 0x47, 0x00, 0x81, 0x00, 0x08, 0x00, 0xc1,
+        0x80, 0x02, 0x00, 0x01, 0x41, 0x08, 0x00, 0x41, 0xc1, 0x07, 0x00, 0x64,
+        0x80, 0x80, 0x02, 0x1f, 0xc0, 0xc7, 0x00, 0x1e, 0x80, 0x01, 0x80, 0x46,
+        0x40, 0x47, 0x00, 0x81, 0x40, 0x08, 0x00, 0xc1, 0x80, 0x08, 0x00, 0x01,
+        0xc1, 0x08, 0x00, 0x64, 0x80, 0x00, 0x02, 0x5f, 0xc0, 0xc8, 0x00, 0x1e,
+        0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00, 0x24,
+        0x40, 0x00, 0x01, 0x06, 0x00, 0x49, 0x00, 0x4b, 0x00, 0x00, 0x00, 0x8b,
+        0x80, 0x00, 0x00, 0xec, 0x00, 0x00, 0x00, 0x8a, 0xc0, 0x80, 0x92, 0x8a,
+        0xc0, 0x49, 0x93, 0x24, 0x80, 0x80, 0x01, 0x46, 0x00, 0x40, 0x00, 0x86,
+        0x00, 0x4a, 0x00, 0xc0, 0x00, 0x00, 0x00, 0xa4, 0x80, 0x00, 0x01, 0x1f,
+        0xc0, 0x49, 0x01, 0x1e, 0x00, 0x02, 0x80, 0x87, 0x40, 0x4a, 0x00, 0x1f,
+        0x80, 0x4a, 0x01, 0x1e, 0x40, 0x01, 0x80, 0x86, 0xc0, 0x4a, 0x00, 0xc0,
+        0x00, 0x00, 0x00, 0x01, 0x41, 0x0a, 0x00, 0xa4, 0x80, 0x80, 0x01, 0x5f,
+        0x40, 0x45, 0x01, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x40, 0x00, 0x00, 0x83,
+        0x00, 0x80, 0x00, 0x64, 0x40, 0x00, 0x01, 0x46, 0x00, 0x40, 0x00, 0x86,
+        0x00, 0x4b, 0x00, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0xa4,
+        0x80, 0x80, 0x01, 0xa2, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x05, 0x80, 0x86,
+        0x00, 0x4b, 0x00, 0xc0, 0x00, 0x00, 0x00, 0x0b, 0x01, 0x00, 0x00, 0xa4,
+        0x80, 0x80, 0x01, 0xa2, 0x40, 0x00, 0x00, 0x1e, 0x00, 0x03, 0x80, 0x86,
+        0x40, 0x4b, 0x00, 0xcb, 0x00, 0x00, 0x01, 0x01, 0x81, 0x0a, 0x00, 0x41,
+        0x41, 0x08, 0x00, 0xeb, 0x40, 0x00, 0x01, 0xa4, 0x80, 0x00, 0x01, 0x1f,
+        0x40, 0x48, 0x01, 0x1e, 0x00, 0x01, 0x80, 0x86, 0x40, 0x4b, 0x00, 0xc1,
+        0x80, 0x0b, 0x00, 0xa4, 0x80, 0x00, 0x01, 0x5f, 0xc0, 0x47, 0x01, 0x1e,
+        0x00, 0x00, 0x80, 0x83, 0x40, 0x00, 0x00, 0x83, 0x00, 0x80, 0x00, 0x64,
+        0x40, 0x00, 0x01, 0x46, 0x00, 0x40, 0x00, 0x86, 0xc0, 0x4b, 0x00, 0xc0,
+        0x00, 0x00, 0x00, 0x01, 0x41, 0x0a, 0x00, 0x41, 0x41, 0x08, 0x00, 0xa4,
+        0x80, 0x00, 0x02, 0x1f, 0x00, 0x00, 0x01, 0x1e, 0x80, 0x00, 0x80, 0x87,
+        0x40, 0x4a, 0x00, 0x5f, 0x40, 0x48, 0x01, 0x1e, 0x00, 0x00, 0x80, 0x83,
+        0x40, 0x00, 0x00, 0x83, 0x00, 0x80, 0x00, 0x64, 0x40, 0x00, 0x01, 0x41,
+        0x00, 0x0c, 0x00, 0x86, 0x40, 0x4c, 0x00, 0xcb, 0x00, 0x80, 0x02, 0x01,
+        0x81, 0x0c, 0x00, 0x41, 0xc1, 0x0c, 0x00, 0x81, 0x01, 0x0d, 0x00, 0xc4,
+        0x01, 0x00, 0x00, 0x01, 0x42, 0x0d, 0x00, 0xeb, 0x40, 0x80, 0x02, 0xa4,
+        0x00, 0x01, 0x01, 0x1e, 0x40, 0x00, 0x80, 0xcf, 0x81, 0x81, 0x02, 0x4d,
+        0xc0, 0x81, 0x00, 0xa9, 0x80, 0x00, 0x00, 0x2a, 0xc1, 0xfe, 0x7f, 0x81,
+        0x00, 0x0c, 0x00, 0xc6, 0x80, 0x4d, 0x00, 0x0b, 0x81, 0x80, 0x00, 0x0a,
+        0x81, 0x4a, 0x91, 0x0a, 0x41, 0xc8, 0x91, 0x41, 0xc1, 0x07, 0x00, 0x2b,
+        0x41, 0x80, 0x00, 0xe4, 0x00, 0x01, 0x01, 0x1e, 0x00, 0x00, 0x80, 0x8d,
+        0xc0, 0x01, 0x01, 0xe9, 0x80, 0x00, 0x00, 0x6a, 0x01, 0xff, 0x7f, 0xc6,
+        0x00, 0x40, 0x00, 0x1f, 0xc0, 0xcd, 0x00, 0x1e, 0x80, 0x01, 0x80, 0x1f,
+        0x00, 0x4e, 0x01, 0x1e, 0x00, 0x01, 0x80, 0x06, 0x41, 0x4e, 0x00, 0x4b,
+        0x01, 0x00, 0x00, 0x24, 0x81, 0x00, 0x01, 0x5f, 0x40, 0x45, 0x02, 0x1e,
+        0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00, 0xe4,
+        0x40, 0x00, 0x01, 0xc6, 0x00, 0x49, 0x00, 0x0b, 0x01, 0x00, 0x00, 0x4b,
+        0x41, 0x00, 0x00, 0xac, 0x41, 0x00, 0x00, 0x4a, 0x81, 0x01, 0x9d, 0xe4,
+        0x80, 0x80, 0x01, 0x06, 0x81, 0x4d, 0x00, 0x40, 0x01, 0x80, 0x01, 0x24,
+        0x01, 0x01, 0x01, 0x1e, 0xc0, 0x01, 0x80, 0x46, 0x02, 0x40, 0x00, 0x1f,
+        0xc0, 0xce, 0x03, 0x1e, 0x40, 0x00, 0x80, 0x5f, 0x00, 0x4f, 0x04, 0x1e,
+        0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00, 0x64,
+        0x42, 0x00, 0x01, 0x29, 0x81, 0x00, 0x00, 0xaa, 0x41, 0xfd, 0x7f, 0x06,
+        0x41, 0x4f, 0x00, 0x46, 0x81, 0x4f, 0x00, 0x81, 0xc1, 0x0f, 0x00, 0x24,
+        0xc1, 0x80, 0x01, 0x86, 0x01, 0x40, 0x00, 0x22, 0x41, 0x00, 0x00, 0x1e,
+        0x40, 0x00, 0x80, 0x5f, 0xc0, 0xcf, 0x02, 0x1e, 0x00, 0x00, 0x80, 0xc3,
+        0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86,
+        0x41, 0x4f, 0x00, 0xc6, 0x81, 0x4f, 0x00, 0x0b, 0x42, 0x00, 0x00, 0x0a,
+        0xc2, 0x4e, 0xa0, 0xa4, 0xc1, 0x80, 0x01, 0x40, 0x01, 0x80, 0x03, 0x00,
+        0x01, 0x00, 0x03, 0x86, 0x01, 0x40, 0x00, 0x22, 0x41, 0x00, 0x00, 0x1e,
+        0x80, 0x00, 0x80, 0xc7, 0x01, 0xd0, 0x02, 0x5f, 0xc0, 0xce, 0x03, 0x1e,
+        0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00, 0xa4,
+        0x41, 0x00, 0x01, 0x86, 0x41, 0x4f, 0x00, 0xec, 0x81, 0x00, 0x00, 0xa4,
+        0xc1, 0x00, 0x01, 0x40, 0x01, 0x80, 0x03, 0x00, 0x01, 0x00, 0x03, 0x86,
+        0x01, 0x40, 0x00, 0x5f, 0x40, 0xd0, 0x02, 0x1e, 0x00, 0x00, 0x80, 0xc3,
+        0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86,
+        0x41, 0x4f, 0x00, 0xc6, 0x01, 0x40, 0x00, 0x03, 0x02, 0x00, 0x00, 0xa4,
+        0xc1, 0x80, 0x01, 0x40, 0x01, 0x80, 0x03, 0x00, 0x01, 0x00, 0x03, 0x86,
+        0x01, 0x40, 0x00, 0x5f, 0x80, 0xd0, 0x02, 0x1e, 0x00, 0x00, 0x80, 0xc3,
+        0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86,
+        0x41, 0x4f, 0x00, 0xc6, 0x01, 0x40, 0x00, 0x04, 0x02, 0x00, 0x00, 0x41,
+        0xc2, 0x10, 0x00, 0xa4, 0xc1, 0x00, 0x02, 0x40, 0x01, 0x80, 0x03, 0x00,
+        0x01, 0x00, 0x03, 0x86, 0x01, 0x40, 0x00, 0x5f, 0xc0, 0xd0, 0x02, 0x1e,
+        0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00, 0xa4,
+        0x41, 0x00, 0x01, 0x86, 0x41, 0x4f, 0x00, 0xc6, 0x01, 0x49, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x4b, 0x02, 0x00, 0x00, 0xa4, 0xc1, 0x00, 0x02, 0x40,
+        0x01, 0x80, 0x03, 0x00, 0x01, 0x00, 0x03, 0x86, 0x01, 0x40, 0x00, 0x5f,
+        0x00, 0xd1, 0x02, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00, 0xc3,
+        0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86, 0x41, 0x4f, 0x00, 0xc6,
+        0x41, 0x4b, 0x00, 0x01, 0xc2, 0x0e, 0x00, 0xa4, 0xc1, 0x80, 0x01, 0x40,
+        0x01, 0x80, 0x03, 0x00, 0x01, 0x00, 0x03, 0x86, 0x01, 0x40, 0x00, 0x5f,
+        0x40, 0xd1, 0x02, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00, 0xc3,
+        0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86, 0x41, 0x4f, 0x00, 0xc6,
+        0xc1, 0x43, 0x00, 0x01, 0x82, 0x11, 0x00, 0x41, 0xc2, 0x11, 0x00, 0xa4,
+        0xc1, 0x00, 0x02, 0x40, 0x01, 0x80, 0x03, 0x00, 0x01, 0x00, 0x03, 0x86,
+        0x01, 0x40, 0x00, 0x5f, 0x00, 0xd2, 0x02, 0x1e, 0x00, 0x00, 0x80, 0xc3,
+        0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86,
+        0x41, 0x4f, 0x00, 0xc6, 0x41, 0x47, 0x00, 0x01, 0x02, 0x0c, 0x00, 0xa4,
+        0xc1, 0x80, 0x01, 0x40, 0x01, 0x80, 0x03, 0x00, 0x01, 0x00, 0x03, 0x86,
+        0x01, 0x40, 0x00, 0x5f, 0x40, 0xd2, 0x02, 0x1e, 0x00, 0x00, 0x80, 0xc3,
+        0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86,
+        0x41, 0x4f, 0x00, 0xc6, 0x41, 0x42, 0x00, 0xa4, 0xc1, 0x00, 0x01, 0x40,
+        0x01, 0x80, 0x03, 0x00, 0x01, 0x00, 0x03, 0x86, 0x01, 0x40, 0x00, 0x5f,
+        0x80, 0xd2, 0x02, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00, 0xc3,
+        0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86, 0xc1, 0x52, 0x00, 0xec,
+        0xc1, 0x00, 0x00, 0x01, 0x02, 0x13, 0x00, 0x41, 0x42, 0x13, 0x00, 0xa4,
+        0xc1, 0x00, 0x02, 0x00, 0x02, 0x00, 0x03, 0x40, 0x02, 0x80, 0x03, 0x86,
+        0x42, 0x47, 0x00, 0xc1, 0x42, 0x08, 0x00, 0x06, 0xc3, 0x52, 0x00, 0x41,
+        0x83, 0x13, 0x00, 0x81, 0xc3, 0x13, 0x00, 0xc1, 0x43, 0x13, 0x00, 0x24,
+        0x03, 0x00, 0x02, 0xa4, 0x02, 0x00, 0x00, 0x26, 0x02, 0x00, 0x00, 0x26,
+        0x00, 0x80, 0x00, 0x50, 0x00, 0x00, 0x00, 0x04, 0x07, 0x61, 0x73, 0x73,
+        0x65, 0x72, 0x74, 0x04, 0x03, 0x5f, 0x47, 0x04, 0x09, 0x5f, 0x56, 0x45,
+        0x52, 0x53, 0x49, 0x4f, 0x4e, 0x04, 0x08, 0x4c, 0x75, 0x61, 0x20, 0x35,
+        0x2e, 0x33, 0x04, 0x05, 0x74, 0x79, 0x70, 0x65, 0x04, 0x04, 0x6e, 0x69,
+        0x6c, 0x04, 0x06, 0x70, 0x72, 0x69, 0x6e, 0x74, 0x04, 0x09, 0x66, 0x75,
+        0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x04, 0x06, 0x74, 0x61, 0x62, 0x6c,
+        0x65, 0x04, 0x09, 0x74, 0x6f, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x13,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x31, 0x03,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x3f, 0x04, 0x04, 0x31, 0x2e,
+        0x35, 0x04, 0x05, 0x74, 0x72, 0x75, 0x65, 0x04, 0x09, 0x74, 0x6f, 0x6e,
+        0x75, 0x6d, 0x62, 0x65, 0x72, 0x04, 0x05, 0x30, 0x78, 0x31, 0x30, 0x13,
+        0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x06, 0x20, 0x31,
+        0x65, 0x31, 0x20, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x40,
+        0x04, 0x02, 0x7a, 0x00, 0x04, 0x03, 0x66, 0x66, 0x13, 0xff, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x04, 0x2d, 0x7a, 0x7a, 0x13, 0x24,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0xf1, 0xfa, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0x04, 0x02, 0x38, 0x13, 0x08, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x07, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74,
+        0x04, 0x02, 0x23, 0x13, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x13, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x13, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x61, 0x04, 0x02, 0x62,
+        0x04, 0x0d, 0x73, 0x65, 0x74, 0x6d, 0x65, 0x74, 0x61, 0x74, 0x61, 0x62,
+        0x6c, 0x65, 0x04, 0x08, 0x5f, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x04,
+        0x0c, 0x5f, 0x5f, 0x6d, 0x65, 0x74, 0x61, 0x74, 0x61, 0x62, 0x6c, 0x65,
+        0x04, 0x07, 0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x04, 0x0d, 0x67, 0x65,
+        0x74, 0x6d, 0x65, 0x74, 0x61, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x04, 0x02,
+        0x78, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x07,
+        0x72, 0x61, 0x77, 0x67, 0x65, 0x74, 0x04, 0x09, 0x72, 0x61, 0x77, 0x65,
+        0x71, 0x75, 0x61, 0x6c, 0x04, 0x07, 0x72, 0x61, 0x77, 0x6c, 0x65, 0x6e,
+        0x04, 0x04, 0x61, 0x62, 0x63, 0x04, 0x07, 0x72, 0x61, 0x77, 0x73, 0x65,
+        0x74, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x07,
+        0x69, 0x70, 0x61, 0x69, 0x72, 0x73, 0x13, 0x0a, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x13, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x13, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x32, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x06, 0x70, 0x61, 0x69, 0x72,
+        0x73, 0x13, 0x8c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x06,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x05, 0x6e, 0x65, 0x78,
+        0x74, 0x04, 0x08, 0x5f, 0x5f, 0x70, 0x61, 0x69, 0x72, 0x73, 0x13, 0x01,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x04, 0x6f, 0x6e, 0x65,
+        0x04, 0x06, 0x70, 0x63, 0x61, 0x6c, 0x6c, 0x04, 0x06, 0x65, 0x72, 0x72,
+        0x6f, 0x72, 0x04, 0x05, 0x62, 0x6f, 0x6f, 0x6d, 0x04, 0x05, 0x63, 0x6f,
+        0x64, 0x65, 0x04, 0x12, 0x62, 0x61, 0x73, 0x65, 0x2e, 0x6c, 0x75, 0x61,
+        0x3a, 0x32, 0x32, 0x3a, 0x20, 0x68, 0x65, 0x72, 0x65, 0x04, 0x12, 0x61,
+        0x73, 0x73, 0x65, 0x72, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x66, 0x61, 0x69,
+        0x6c, 0x65, 0x64, 0x21, 0x04, 0x07, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d,
+        0x04, 0x24, 0x63, 0x61, 0x6e, 0x6e, 0x6f, 0x74, 0x20, 0x63, 0x68, 0x61,
+        0x6e, 0x67, 0x65, 0x20, 0x61, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x65, 0x63,
+        0x74, 0x65, 0x64, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x74, 0x61, 0x62, 0x6c,
+        0x65, 0x14, 0x37, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d,
+        0x65, 0x6e, 0x74, 0x20, 0x23, 0x31, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x72,
+        0x61, 0x77, 0x6c, 0x65, 0x6e, 0x27, 0x20, 0x28, 0x74, 0x61, 0x62, 0x6c,
+        0x65, 0x20, 0x6f, 0x72, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20,
+        0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x29, 0x04, 0x03, 0x31,
+        0x30, 0x13, 0x63, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x32,
+        0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74,
+        0x20, 0x23, 0x32, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x74, 0x6f, 0x6e, 0x75,
+        0x6d, 0x62, 0x65, 0x72, 0x27, 0x20, 0x28, 0x62, 0x61, 0x73, 0x65, 0x20,
+        0x6f, 0x75, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65,
+        0x29, 0x14, 0x31, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d,
+        0x65, 0x6e, 0x74, 0x20, 0x23, 0x31, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x73,
+        0x65, 0x6c, 0x65, 0x63, 0x74, 0x27, 0x20, 0x28, 0x69, 0x6e, 0x64, 0x65,
+        0x78, 0x20, 0x6f, 0x75, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x72, 0x61, 0x6e,
+        0x67, 0x65, 0x29, 0x14, 0x2f, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67,
+        0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x31, 0x20, 0x74, 0x6f, 0x20,
+        0x27, 0x74, 0x6f, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x27, 0x20, 0x28,
+        0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74,
+        0x65, 0x64, 0x29, 0x04, 0x05, 0x6c, 0x6f, 0x61, 0x64, 0x04, 0x07, 0x3d,
+        0x65, 0x6d, 0x70, 0x74, 0x79, 0x04, 0x02, 0x74, 0x04, 0x05, 0x1b, 0x4c,
+        0x75, 0x61, 0x04, 0x04, 0x62, 0x69, 0x6e, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x26, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x05, 0x00, 0x00, 0x00, 0x6c, 0x00,
+        0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0xc4, 0x00, 0x00, 0x00, 0x66, 0x00,
+        0x00, 0x02, 0x26, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x04, 0x06, 0x00, 0x00, 0x00, 0x62, 0x40,
+        0x00, 0x00, 0x1e, 0x80, 0x00, 0x80, 0x81, 0x00, 0x00, 0x00, 0xc1, 0x40,
+        0x00, 0x00, 0xa6, 0x00, 0x80, 0x01, 0x26, 0x00, 0x80, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x04, 0x6f, 0x6e, 0x65, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0x10, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x5f, 0x00, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x02, 0x6b, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x02, 0x74, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x02, 0x04, 0x00, 0x00, 0x00, 0x06, 0x00, 0x40, 0x00, 0x41, 0x40,
+        0x00, 0x00, 0x24, 0x40, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x04, 0x06, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x04, 0x05, 0x68,
+        0x65, 0x72, 0x65, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00,
+        0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56, 0x00, 0x26,
+        0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x01, 0x26, 0x00,
+        0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00,
+        0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x84, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00,
+        0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00,
+        0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1b, 0x00,
+        0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00,
+        0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00,
+        0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00,
+        0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00,
+        0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00,
+        0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00,
+        0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00,
+        0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00,
+        0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00,
+        0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00,
+        0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00,
+        0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00,
+        0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00,
+        0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x27, 0x00,
+        0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00,
+        0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00,
+        0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00,
+        0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x02, 0x74, 0x7d, 0x00, 0x00, 0x00, 0x84, 0x01, 0x00, 0x00,
+        0x04, 0x73, 0x75, 0x6d, 0xbb, 0x00, 0x00, 0x00, 0x84, 0x01, 0x00, 0x00,
+        0x10, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61,
+        0x74, 0x6f, 0x72, 0x29, 0xc4, 0x00, 0x00, 0x00, 0xc9, 0x00, 0x00, 0x00,
+        0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x29,
+        0xc4, 0x00, 0x00, 0x00, 0xc9, 0x00, 0x00, 0x00, 0x0e, 0x28, 0x66, 0x6f,
+        0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x29, 0xc4, 0x00,
+        0x00, 0x00, 0xc9, 0x00, 0x00, 0x00, 0x02, 0x69, 0xc5, 0x00, 0x00, 0x00,
+        0xc7, 0x00, 0x00, 0x00, 0x02, 0x76, 0xc5, 0x00, 0x00, 0x00, 0xc7, 0x00,
+        0x00, 0x00, 0x05, 0x6b, 0x65, 0x79, 0x73, 0xca, 0x00, 0x00, 0x00, 0x84,
+        0x01, 0x00, 0x00, 0x10, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e,
+        0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x29, 0xd1, 0x00, 0x00, 0x00, 0xd5,
+        0x00, 0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74, 0x61,
+        0x74, 0x65, 0x29, 0xd1, 0x00, 0x00, 0x00, 0xd5, 0x00, 0x00, 0x00, 0x0e,
+        0x28, 0x66, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c,
+        0x29, 0xd1, 0x00, 0x00, 0x00, 0xd5, 0x00, 0x00, 0x00, 0x02, 0x6b, 0xd2,
+        0x00, 0x00, 0x00, 0xd3, 0x00, 0x00, 0x00, 0x02, 0x76, 0xd2, 0x00, 0x00,
+        0x00, 0xd3, 0x00, 0x00, 0x00, 0x02, 0x70, 0xe8, 0x00, 0x00, 0x00, 0x84,
+        0x01, 0x00, 0x00, 0x10, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e,
+        0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x29, 0xeb, 0x00, 0x00, 0x00, 0xf6,
+        0x00, 0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74, 0x61,
+        0x74, 0x65, 0x29, 0xeb, 0x00, 0x00, 0x00, 0xf6, 0x00, 0x00, 0x00, 0x0e,
+        0x28, 0x66, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c,
+        0x29, 0xeb, 0x00, 0x00, 0x00, 0xf6, 0x00, 0x00, 0x00, 0x02, 0x6b, 0xec,
+        0x00, 0x00, 0x00, 0xf4, 0x00, 0x00, 0x00, 0x02, 0x76, 0xec, 0x00, 0x00,
+        0x00, 0xf4, 0x00, 0x00, 0x00, 0x03, 0x6f, 0x6b, 0xfa, 0x00, 0x00, 0x00,
+        0x84, 0x01, 0x00, 0x00, 0x04, 0x65, 0x72, 0x72, 0xfa, 0x00, 0x00, 0x00,
+        0x84, 0x01, 0x00, 0x00, 0x02, 0x66, 0x78, 0x01, 0x00, 0x00, 0x84, 0x01,
+        0x00, 0x00, 0x04, 0x6d, 0x73, 0x67, 0x78, 0x01, 0x00, 0x00, 0x84, 0x01,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
+
+    #[test]
+    fn test_base_lib() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        ls.requiref("_G", open_base, true);
+        ls.pop(1);
+
+        ls.load(LUA_BASE_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 3, 0);
+        assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+        assert!(ls.is_nil(1));
+        assert_eq!(ls.to_string(2), "empty: cannot load text chunk (no compiler)");
+        assert_eq!(ls.to_string(3), "attempt to load a binary chunk (mode is 't')");
+    }
+}
@@ -0,0 +1,1368 @@
+use crate::api::consts::*;
+use crate::api::{LuaAPI, LuaAuxLib, LuaDebug, LuaHook, RustFn};
+
+// key, in the registry, of the Lua function set by `debug.sethook`
+const HOOKKEY: &str = "_HOOKKEY";
+
+/*
+    There are no coroutines, so the functions that take an optional thread
+    in the reference library always act on the running state: their first
+    argument is at index 1 (`arg` is 0 below, as in ldblib).
+*/
+
+const DB_FUNCS: &[(&str, RustFn)] = &[
+    ("getuservalue", db_getuservalue),
+    ("gethook", db_gethook),
+    ("getinfo", db_getinfo),
+    ("getlocal", db_getlocal),
+    ("getregistry", db_getregistry),
+    ("getmetatable", db_getmetatable),
+    ("getupvalue", db_getupvalue),
+    ("upvaluejoin", db_upvaluejoin),
+    ("upvalueid", db_upvalueid),
+    ("setuservalue", db_setuservalue),
+    ("sethook", db_sethook),
+    ("setlocal", db_setlocal),
+    ("setmetatable", db_setmetatable),
+    ("setupvalue", db_setupvalue),
+    ("traceback", db_traceback),
+];
+
+/*
+    Scripts can reach any local, upvalue and metatable through `debug`,
+    which defeats any sandbox, so it is opt-in like `io` and `os`.
+*/
+pub fn open_debug(ls: &mut dyn LuaAPI) -> usize {
+    ls.new_lib(DB_FUNCS);
+    1
+}
+
+// debug.getregistry ()
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.getregistry
+fn db_getregistry(ls: &mut dyn LuaAPI) -> usize {
+    ls.push_value(LUA_REGISTRY_INDEX);
+    1
+}
+
+// debug.getmetatable (value)
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.getmetatable
+fn db_getmetatable(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_any(1);
+    if !ls.get_metatable(1) {
+        ls.push_nil(); // no metatable
+    }
+    1
+}
+
+// debug.setmetatable (value, table)
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.setmetatable
+fn db_setmetatable(ls: &mut dyn LuaAPI) -> usize {
+    let t = ls.type_id(2);
+    ls.arg_check(t == LUA_TNIL || t == LUA_TTABLE, 2, "nil or table expected");
+    ls.set_top(2);
+    ls.set_metatable(1);
+    1 // return 1st argument
+}
+
+// debug.getuservalue (u)
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.getuservalue
+fn db_getuservalue(ls: &mut dyn LuaAPI) -> usize {
+    if ls.type_id(1) != LUA_TUSERDATA {
+        ls.push_nil();
+    } else {
+        ls.get_user_value(1);
+    }
+    1
+}
+
+// debug.setuservalue (udata, value)
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.setuservalue
+fn db_setuservalue(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_type(1, LUA_TUSERDATA);
+    ls.check_any(2);
+    ls.set_top(2);
+    ls.set_user_value(1);
+    1
+}
+
+// Stack level given as argument `arg`, or an argument error.
+fn check_level(ls: &mut dyn LuaAPI, arg: isize) -> LuaDebug {
+    let level = ls.check_integer(arg);
+    match ls.get_stack(level as isize) {
+        Some(ar) => ar,
+        None => ls.arg_error(arg, "level out of range"), // out of range?
+    }
+}
+
+// debug.getinfo ([thread,] f [, what])
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.getinfo
+fn db_getinfo(ls: &mut dyn LuaAPI) -> usize {
+    let arg = 0;
+    let mut options = ls.opt_string(arg + 2, "flnStu");
+    let mut ar = if ls.is_function(arg + 1) {
+        // info about a function?
+        options = format!(">{}", options); // add '>' to 'options'
+        ls.push_value(arg + 1); // push function
+        LuaDebug::default()
+    } else {
+        // stack level
+        let level = ls.check_integer(arg + 1);
+        match ls.get_stack(level as isize) {
+            Some(ar) => ar,
+            None => {
+                ls.push_nil(); // level out of range
+                return 1;
+            }
+        }
+    };
+    if !ls.get_info(&options, &mut ar) {
+        ls.arg_error(arg + 2, "invalid option");
+    }
+    ls.new_table(); // table to collect results
+    if options.contains('S') {
+        ls.push_string(ar.source.clone());
+        ls.set_field(-2, "source");
+        ls.push_string(ar.short_src.clone());
+        ls.set_field(-2, "short_src");
+        ls.push_integer(ar.line_defined as i64);
+        ls.set_field(-2, "linedefined");
+        ls.push_integer(ar.last_line_defined as i64);
+        ls.set_field(-2, "lastlinedefined");
+        ls.push_string(ar.what.to_string());
+        ls.set_field(-2, "what");
+    }
+    if options.contains('l') {
+        ls.push_integer(ar.current_line as i64);
+        ls.set_field(-2, "currentline");
+    }
+    if options.contains('u') {
+        ls.push_integer(ar.nups as i64);
+        ls.set_field(-2, "nups");
+        ls.push_integer(ar.nparams as i64);
+        ls.set_field(-2, "nparams");
+        ls.push_boolean(ar.is_vararg);
+        ls.set_field(-2, "isvararg");
+    }
+    if options.contains('n') {
+        if let Some(name) = ar.name.clone() {
+            ls.push_string(name);
+            ls.set_field(-2, "name");
+        }
+        ls.push_string(ar.namewhat.to_string());
+        ls.set_field(-2, "namewhat");
+    }
+    if options.contains('t') {
+        ls.push_boolean(ar.is_tail_call);
+        ls.set_field(-2, "istailcall");
+    }
+    // 'get_info' pushed the function and then the active lines
+    if options.contains('L') {
+        ls.rotate(-2, 1); // exchange object and table
+        ls.set_field(-2, "activelines");
+    }
+    if options.contains('f') {
+        ls.rotate(-2, 1);
+        ls.set_field(-2, "func");
+    }
+    1 // return table
+}
+
+// debug.getlocal ([thread,] f, local)
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.getlocal
+fn db_getlocal(ls: &mut dyn LuaAPI) -> usize {
+    let arg = 0;
+    let nvar = ls.check_integer(arg + 2) as isize; // local-variable index
+    if ls.is_function(arg + 1) {
+        // function argument?
+        ls.push_value(arg + 1); // push function
+        match ls.get_local(None, nvar) {
+            Some(name) => ls.push_string(name), // push local name
+            None => ls.push_nil(),
+        }
+        return 1; // return only name (there is no value)
+    }
+    // stack-level argument
+    let ar = check_level(ls, arg + 1);
+    match ls.get_local(Some(&ar), nvar) {
+        Some(name) => {
+            ls.push_string(name); // push name
+            ls.rotate(-2, 1); // re-order
+            2
+        }
+        None => {
+            ls.push_nil(); // no name (nor value)
+            1
+        }
+    }
+}
+
+// debug.setlocal ([thread,] level, local, value)
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.setlocal
+fn db_setlocal(ls: &mut dyn LuaAPI) -> usize {
+    let arg = 0;
+    let ar = check_level(ls, arg + 1);
+    let nvar = ls.check_integer(arg + 2) as isize;
+    ls.check_any(arg + 3);
+    ls.set_top(arg + 3);
+    match ls.set_local(&ar, nvar) {
+        Some(name) => ls.push_string(name),
+        None => {
+            ls.pop(1); // pop value (if not popped by 'set_local')
+            ls.push_nil();
+        }
+    }
+    1
+}
+
+// get (if `get` is true) or set an upvalue from a closure
+fn aux_upvalue(ls: &mut dyn LuaAPI, get: bool) -> usize {
+    let n = ls.check_integer(2) as isize; // upvalue index
+    ls.check_type(1, LUA_TFUNCTION); // closure
+    let name = if get { ls.get_upvalue(1, n) } else { ls.set_upvalue(1, n) };
+    match name {
+        Some(name) => {
+            ls.push_string(name);
+            ls.insert(-(get as isize + 1));
+            get as usize + 1
+        }
+        None => 0,
+    }
+}
+
+// debug.getupvalue (f, up)
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.getupvalue
+fn db_getupvalue(ls: &mut dyn LuaAPI) -> usize {
+    aux_upvalue(ls, true)
+}
+
+// debug.setupvalue (f, up, value)
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.setupvalue
+fn db_setupvalue(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_any(3);
+    aux_upvalue(ls, false)
+}
+
+// Check whether a given upvalue from a given closure exists and
+// returns its index
+fn check_upval(ls: &mut dyn LuaAPI, argf: isize, argnup: isize) -> isize {
+    let nup = ls.check_integer(argnup) as isize; // upvalue index
+    ls.check_type(argf, LUA_TFUNCTION); // closure
+    let exists = ls.get_upvalue(argf, nup).is_some();
+    ls.arg_check(exists, argnup, "invalid upvalue index");
+    nup
+}
+
+// debug.upvalueid (f, n)
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.upvalueid
+fn db_upvalueid(ls: &mut dyn LuaAPI) -> usize {
+    let n = check_upval(ls, 1, 2);
+    let id = ls.upvalue_id(1, n).unwrap();
+    ls.push_light_userdata(id);
+    1
+}
+
+// debug.upvaluejoin (f1, n1, f2, n2)
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.upvaluejoin
+fn db_upvaluejoin(ls: &mut dyn LuaAPI) -> usize {
+    let n1 = check_upval(ls, 1, 2);
+    let n2 = check_upval(ls, 3, 4);
+    let is_lua1 = !ls.is_rust_fn(1);
+    ls.arg_check(is_lua1, 1, "Lua function expected");
+    let is_lua2 = !ls.is_rust_fn(3);
+    ls.arg_check(is_lua2, 3, "Lua function expected");
+    ls.upvalue_join(1, n1, 3, n2);
+    0
+}
+
+const HOOK_NAMES: &[&str] = &["call", "return", "line", "count", "tail call"];
+
+// Call the hook function registered by `debug.sethook` with the event
+// name and the current line.
+fn hookf(ls: &mut dyn LuaAPI, ar: &mut LuaDebug) {
+    if ls.get_field(LUA_REGISTRY_INDEX, HOOKKEY) == LUA_TFUNCTION {
+        // is there a hook function?
+        ls.push_string(HOOK_NAMES[ar.event as usize].to_string()); // push event name
+        if ar.current_line >= 0 {
+            ls.push_integer(ar.current_line as i64); // push current line
+        } else {
+            ls.push_nil();
+        }
+        ls.get_info("lS", ar);
+        ls.call(2, 0); // call hook function
+    }
+}
+
+// Convert a string mask (for 'sethook') into a bit mask
+fn make_mask(smask: &str, count: usize) -> u8 {
+    let mut mask = 0;
+    if smask.contains('c') {
+        mask |= LUA_MASKCALL;
+    }
+    if smask.contains('r') {
+        mask |= LUA_MASKRET;
+    }
+    if smask.contains('l') {
+        mask |= LUA_MASKLINE;
+    }
+    if count > 0 {
+        mask |= LUA_MASKCOUNT;
+    }
+    mask
+}
+
+// Convert a bit mask (for 'gethook') into a string mask
+fn unmake_mask(mask: u8) -> String {
+    let mut smask = String::new();
+    if mask & LUA_MASKCALL != 0 {
+        smask.push('c');
+    }
+    if mask & LUA_MASKRET != 0 {
+        smask.push('r');
+    }
+    if mask & LUA_MASKLINE != 0 {
+        smask.push('l');
+    }
+    smask
+}
+
+// debug.sethook ([thread,] hook, mask [, count])
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.sethook
+fn db_sethook(ls: &mut dyn LuaAPI) -> usize {
+    let arg = 0;
+    let (func, mask, count): (Option<LuaHook>, u8, usize) = if ls.is_none_or_nil(arg + 1) {
+        // no hook?
+        ls.set_top(arg + 1);
+        (None, 0, 0) // turn off hooks
+    } else {
+        let smask = ls.check_string(arg + 2);
+        ls.check_type(arg + 1, LUA_TFUNCTION);
+        let count = ls.opt_integer(arg + 3, 0).max(0) as usize;
+        (Some(hookf), make_mask(&smask, count), count)
+    };
+    ls.push_value(arg + 1);
+    ls.set_field(LUA_REGISTRY_INDEX, HOOKKEY); // registry[HOOKKEY] = new Lua hook
+    ls.set_hook(func, mask, count);
+    0
+}
+
+// debug.gethook ([thread])
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.gethook
+fn db_gethook(ls: &mut dyn LuaAPI) -> usize {
+    let mask = ls.get_hook_mask();
+    match ls.get_hook() {
+        None => ls.push_nil(), // no hook?
+        Some(hook) if !std::ptr::fn_addr_eq(hook, hookf as LuaHook) => {
+            // external hook?
+            ls.push_string("external hook".to_string());
+        }
+        Some(_) => {
+            ls.get_field(LUA_REGISTRY_INDEX, HOOKKEY); // 1st result = hook function
+        }
+    }
+    ls.push_string(unmake_mask(mask)); // 2nd result = mask
+    ls.push_integer(ls.get_hook_count() as i64); // 3rd result = count
+    3
+}
+
+// debug.traceback ([thread,] [message [, level]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-debug.traceback
+fn db_traceback(ls: &mut dyn LuaAPI) -> usize {
+    let arg = 0;
+    let msg = ls.to_stringx(arg + 1);
+    if msg.is_none() && !ls.is_none_or_nil(arg + 1) {
+        // non-string 'msg'?
+        ls.push_value(arg + 1); // return it untouched
+    } else {
+        let level = ls.opt_integer(arg + 2, 1) as isize;
+        ls.traceback(msg.as_deref(), level);
+    }
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LuaState;
+    use crate::stdlib::open_libs;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /* Lua source code:
+        local function f(a, b, ...)
+          local c = a + b
+          local info = debug.getinfo(1, "Slnu")
+          assert(info.currentline == 3 and info.what == "Lua" and info.linedefined == 1)
+          assert(info.nparams == 2 and info.isvararg and info.nups == 1)
+          assert(info.short_src == "chunk" and info.source == "@chunk")
+          local names = {}
+          for i = 1, math.huge do
+            local name, val = debug.getlocal(1, i)
+            if not name then break end
+            names[#names + 1] = name .. "=" .. tostring(val)
+          end
+          assert(names[1] == "a=1" and names[2] == "b=2" and names[3] == "c=3")
+          assert(debug.getlocal(1, -1) == "(*vararg)" and select(2, debug.getlocal(1, -2)) == "y")
+          assert(debug.getlocal(1, -3) == nil)
+          assert(debug.setlocal(1, 3, 30) == "c" and c == 30)
+          assert(debug.setlocal(1, -1, "X") == "(*vararg)" and ... == "X")
+          assert(debug.setlocal(1, 100, 0) == nil)
+          local r = debug.getinfo(2, "nl")
+          return r
+        end
+        local caller = f(1, 2, "x", "y")
+        assert(caller.currentline == 22)
+
+        local function named() local r = debug.getinfo(1, "n") return r end
+        local n = named()
+        assert(n.name == "named" and n.namewhat == "local")
+        assert(debug.getinfo(print).what == "C" and debug.getinfo(print).short_src == "[C]")
+        assert(debug.getinfo(print, "S").source == "=[C]")
+        assert(debug.getinfo(100) == nil)
+        assert(not pcall(debug.getinfo, 1, "X"))
+        assert(select(2, pcall(debug.getinfo, 1, "X")):find("invalid option"))
+        local fi = debug.getinfo(f, "SLfu")
+        assert(fi.func == f and fi.linedefined == 1 and fi.lastlinedefined == 21 and fi.nparams == 2)
+        assert(fi.activelines[2] and fi.activelines[20] and not fi.activelines[1])
+        assert(debug.getinfo(f, "l").currentline == -1)
+        assert(debug.getlocal(f, 1) == "a" and debug.getlocal(f, 2) == "b" and debug.getlocal(f, 3) == nil)
+        assert(select(2, pcall(debug.getlocal, 50, 1)):find("level out of range"))
+
+        -- upvalues
+        local up1, up2 = 10, 20
+        local function g() return up1 end
+        local function h() return up2 end
+        assert(debug.getupvalue(g, 1) == "up1" and select(2, debug.getupvalue(g, 1)) == 10)
+        assert(debug.getupvalue(g, 2) == nil)
+        assert(debug.setupvalue(g, 1, 11) == "up1" and up1 == 11 and g() == 11)
+        assert(debug.upvalueid(g, 1) ~= debug.upvalueid(h, 1))
+        assert(type(debug.upvalueid(g, 1)) == "userdata")
+        assert(select(2, pcall(debug.upvalueid, g, 5)):find("invalid upvalue index"))
+        debug.upvaluejoin(g, 1, h, 1)
+        assert(g() == 20 and debug.upvalueid(g, 1) == debug.upvalueid(h, 1))
+        up2 = 21
+        assert(g() == 21)
+        assert(select(2, pcall(debug.upvaluejoin, print, 1, h, 1)):find("invalid upvalue index"))
+
+        -- metatables and registry
+        assert(type(debug.getregistry()) == "table")
+        local t = setmetatable({}, {__metatable = "locked"})
+        assert(getmetatable(t) == "locked" and type(debug.getmetatable(t)) == "table")
+        assert(debug.setmetatable(t, nil) == t and getmetatable(t) == nil)
+        assert(debug.getmetatable(1) == nil)
+        assert(debug.getuservalue(1) == nil)
+
+        -- tracebacks
+        local function lvl3() local s = debug.traceback("msg", 1) return s end
+        local function lvl2() return lvl3() .. "" end
+        local tb = lvl2()
+        assert(tb:find("^msg\nstack traceback:\n\tchunk:65: in upvalue 'lvl3'\n\tchunk:66: in local 'lvl2'\n\tchunk:67: in main chunk"), tb)
+        assert(debug.traceback(t) == t)
+        assert(debug.traceback(nil, 1):find("^stack traceback:\n\tchunk:70: in main chunk"))
+        local function deep(n) if n == 0 then return debug.traceback() end return (deep(n - 1)) end
+        tb = deep(30)
+        assert(tb:find("\n\t...\n", 1, true) and select(2, tb:gsub("\n\t", "")) == 22, tb)
+        assert(debug.traceback("x", 1):find("^x\nstack traceback:\n\t"))
+        local ok, err = pcall(error)
+        assert(debug.traceback(12):find("^12\n"))
+    */
+    const LUA_DEBUG_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x07, 0x40,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x15, 0xd8, 0x01, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00,
+        0x40, 0x00, 0x00, 0x00, 0x81, 0x00, 0x00, 0x00, 0xc1, 0x40, 0x00, 0x00,
+        0x01, 0x81, 0x00, 0x00, 0x41, 0xc1, 0x00, 0x00, 0x64, 0x80, 0x80, 0x02,
+        0x86, 0x00, 0x41, 0x00, 0xc7, 0x40, 0xc1, 0x00, 0x5f, 0x80, 0xc1, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0xac, 0x40, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x01,
+        0xe4, 0x80, 0x80, 0x00, 0x06, 0x01, 0x41, 0x00, 0x47, 0xc1, 0xc1, 0x01,
+        0x1f, 0x00, 0xc2, 0x02, 0x1e, 0x80, 0x00, 0x80, 0x47, 0x41, 0xc2, 0x01,
+        0x5f, 0x80, 0xc2, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x41, 0x00, 0x00,
+        0x43, 0x01, 0x80, 0x00, 0x24, 0x41, 0x00, 0x01, 0x06, 0x01, 0x41, 0x00,
+        0x46, 0xc1, 0x42, 0x00, 0x47, 0x01, 0xc3, 0x02, 0x86, 0x41, 0x43, 0x00,
+        0x64, 0x81, 0x00, 0x01, 0x47, 0x81, 0xc3, 0x02, 0x1f, 0xc0, 0xc3, 0x02,
+        0x1e, 0x80, 0x01, 0x80, 0x46, 0xc1, 0x42, 0x00, 0x47, 0x01, 0xc3, 0x02,
+        0x86, 0x41, 0x43, 0x00, 0x64, 0x81, 0x00, 0x01, 0x47, 0x01, 0xc4, 0x02,
+        0x5f, 0x40, 0xc4, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x41, 0x00, 0x00,
+        0x43, 0x01, 0x80, 0x00, 0x24, 0x41, 0x00, 0x01, 0x06, 0x01, 0x41, 0x00,
+        0x46, 0xc1, 0x42, 0x00, 0x47, 0x01, 0xc3, 0x02, 0x86, 0x41, 0x43, 0x00,
+        0xc1, 0x81, 0x04, 0x00, 0x64, 0x81, 0x80, 0x01, 0x47, 0xc1, 0xc4, 0x02,
+        0x5f, 0x00, 0xc5, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x41, 0x00, 0x00,
+        0x43, 0x01, 0x80, 0x00, 0x24, 0x41, 0x00, 0x01, 0x06, 0x01, 0x41, 0x00,
+        0x46, 0xc1, 0x42, 0x00, 0x47, 0x01, 0xc3, 0x02, 0x81, 0x41, 0x05, 0x00,
+        0x64, 0x81, 0x00, 0x01, 0x5f, 0x80, 0xc5, 0x02, 0x1e, 0x00, 0x00, 0x80,
+        0x43, 0x41, 0x00, 0x00, 0x43, 0x01, 0x80, 0x00, 0x24, 0x41, 0x00, 0x01,
+        0x06, 0x01, 0x41, 0x00, 0x46, 0xc1, 0x45, 0x00, 0x86, 0xc1, 0x42, 0x00,
+        0x87, 0x01, 0x43, 0x03, 0xc1, 0x01, 0x06, 0x00, 0x01, 0x42, 0x06, 0x00,
+        0x64, 0x81, 0x00, 0x02, 0x5b, 0x01, 0x80, 0x02, 0x24, 0x41, 0x00, 0x01,
+        0x06, 0x01, 0x41, 0x00, 0x46, 0x81, 0x46, 0x00, 0x81, 0x41, 0x00, 0x00,
+        0xc6, 0xc1, 0x45, 0x00, 0x06, 0xc2, 0x42, 0x00, 0x07, 0x02, 0x43, 0x04,
+        0x41, 0x02, 0x06, 0x00, 0x81, 0x42, 0x06, 0x00, 0xe4, 0x01, 0x00, 0x02,
+        0x64, 0x81, 0x00, 0x00, 0x4c, 0xc1, 0xc6, 0x02, 0xc1, 0x01, 0x07, 0x00,
+        0x64, 0x01, 0x80, 0x01, 0x24, 0x41, 0x00, 0x00, 0x06, 0xc1, 0x42, 0x00,
+        0x07, 0x01, 0x43, 0x02, 0x40, 0x01, 0x00, 0x00, 0x81, 0x41, 0x07, 0x00,
+        0x24, 0x81, 0x80, 0x01, 0x46, 0x01, 0x41, 0x00, 0x87, 0x81, 0x47, 0x02,
+        0x1f, 0x00, 0x00, 0x03, 0x1e, 0x00, 0x02, 0x80, 0x87, 0xc1, 0x47, 0x02,
+        0x1f, 0x00, 0x46, 0x03, 0x1e, 0x40, 0x01, 0x80, 0x87, 0x01, 0x48, 0x02,
+        0x1f, 0x40, 0x48, 0x03, 0x1e, 0x80, 0x00, 0x80, 0x87, 0x81, 0x48, 0x02,
+        0x5f, 0x40, 0x40, 0x03, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x41, 0x00, 0x00,
+        0x83, 0x01, 0x80, 0x00, 0x64, 0x41, 0x00, 0x01, 0x46, 0x01, 0x41, 0x00,
+        0x87, 0xc1, 0x48, 0x02, 0x87, 0x41, 0x40, 0x03, 0xa2, 0x01, 0x00, 0x00,
+        0x1e, 0x80, 0x01, 0x80, 0x87, 0xc1, 0x48, 0x02, 0x87, 0x01, 0x49, 0x03,
+        0xa2, 0x01, 0x00, 0x00, 0x1e, 0x80, 0x00, 0x80, 0x87, 0xc1, 0x48, 0x02,
+        0x87, 0x01, 0x46, 0x03, 0x9b, 0x01, 0x00, 0x03, 0x64, 0x41, 0x00, 0x01,
+        0x46, 0x01, 0x41, 0x00, 0x86, 0xc1, 0x42, 0x00, 0x87, 0x01, 0x43, 0x03,
+        0xc0, 0x01, 0x00, 0x00, 0x01, 0x42, 0x09, 0x00, 0xa4, 0x81, 0x80, 0x01,
+        0x87, 0x41, 0x41, 0x03, 0x5f, 0x80, 0x49, 0x03, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80, 0x00, 0x64, 0x41, 0x00, 0x01,
+        0x46, 0x01, 0x41, 0x00, 0x86, 0xc1, 0x42, 0x00, 0x87, 0xc1, 0x49, 0x03,
+        0xc0, 0x01, 0x00, 0x00, 0x01, 0x02, 0x06, 0x00, 0xa4, 0x81, 0x80, 0x01,
+        0x1f, 0x00, 0x4a, 0x03, 0x1e, 0x40, 0x03, 0x80, 0x86, 0xc1, 0x42, 0x00,
+        0x87, 0xc1, 0x49, 0x03, 0xc0, 0x01, 0x00, 0x00, 0x01, 0x42, 0x00, 0x00,
+        0xa4, 0x81, 0x80, 0x01, 0x1f, 0x40, 0x4a, 0x03, 0x1e, 0x80, 0x01, 0x80,
+        0x86, 0xc1, 0x42, 0x00, 0x87, 0xc1, 0x49, 0x03, 0xc0, 0x01, 0x00, 0x00,
+        0x01, 0x82, 0x0a, 0x00, 0xa4, 0x81, 0x80, 0x01, 0x5f, 0x80, 0x45, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80, 0x00,
+        0x64, 0x41, 0x00, 0x01, 0x46, 0x01, 0x41, 0x00, 0x86, 0x81, 0x46, 0x00,
+        0xc1, 0x41, 0x00, 0x00, 0x06, 0xc2, 0x45, 0x00, 0x46, 0xc2, 0x42, 0x00,
+        0x47, 0xc2, 0xc9, 0x04, 0x81, 0xc2, 0x0a, 0x00, 0xc1, 0x02, 0x06, 0x00,
+        0x24, 0x02, 0x00, 0x02, 0xa4, 0x81, 0x00, 0x00, 0x8c, 0xc1, 0x46, 0x03,
+        0x01, 0x02, 0x0b, 0x00, 0xa4, 0x01, 0x80, 0x01, 0x64, 0x41, 0x00, 0x00,
+        0x41, 0x41, 0x0b, 0x00, 0x81, 0x01, 0x09, 0x00, 0xec, 0x81, 0x00, 0x00,
+        0x2c, 0xc2, 0x00, 0x00, 0x46, 0x02, 0x41, 0x00, 0x86, 0xc2, 0x42, 0x00,
+        0x87, 0x82, 0x4b, 0x05, 0xc0, 0x02, 0x80, 0x03, 0x01, 0x03, 0x06, 0x00,
+        0xa4, 0x82, 0x80, 0x01, 0x1f, 0xc0, 0x4b, 0x05, 0x1e, 0x40, 0x02, 0x80,
+        0x86, 0x82, 0x46, 0x00, 0xc1, 0x42, 0x00, 0x00, 0x06, 0xc3, 0x42, 0x00,
+        0x07, 0x83, 0x4b, 0x06, 0x40, 0x03, 0x80, 0x03, 0x81, 0x03, 0x06, 0x00,
+        0x24, 0x03, 0x80, 0x01, 0xa4, 0x82, 0x00, 0x00, 0x5f, 0x40, 0x4b, 0x05,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x41, 0x00, 0x86, 0xc2, 0x42, 0x00,
+        0x87, 0x82, 0x4b, 0x05, 0xc0, 0x02, 0x80, 0x03, 0x01, 0x43, 0x00, 0x00,
+        0xa4, 0x82, 0x80, 0x01, 0x5f, 0x80, 0x45, 0x05, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01,
+        0x46, 0x02, 0x41, 0x00, 0x86, 0xc2, 0x42, 0x00, 0x87, 0x02, 0x4c, 0x05,
+        0xc0, 0x02, 0x80, 0x03, 0x01, 0x03, 0x06, 0x00, 0x41, 0x43, 0x0c, 0x00,
+        0xa4, 0x82, 0x00, 0x02, 0x1f, 0xc0, 0x4b, 0x05, 0x1e, 0x40, 0x01, 0x80,
+        0x1f, 0x40, 0xcc, 0x02, 0x1e, 0xc0, 0x00, 0x80, 0x80, 0x02, 0x80, 0x03,
+        0xa4, 0x82, 0x80, 0x00, 0x5f, 0x40, 0x4c, 0x05, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01,
+        0x46, 0x02, 0x41, 0x00, 0x86, 0xc2, 0x42, 0x00, 0x87, 0x82, 0x4c, 0x05,
+        0xc0, 0x02, 0x80, 0x03, 0x01, 0x03, 0x06, 0x00, 0xa4, 0x82, 0x80, 0x01,
+        0xc6, 0xc2, 0x42, 0x00, 0xc7, 0x82, 0xcc, 0x05, 0x00, 0x03, 0x00, 0x04,
+        0x41, 0x03, 0x06, 0x00, 0xe4, 0x82, 0x80, 0x01, 0x1f, 0xc0, 0x02, 0x05,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x41, 0x00, 0x86, 0xc2, 0x4c, 0x00,
+        0xc6, 0xc2, 0x42, 0x00, 0xc7, 0x82, 0xcc, 0x05, 0x00, 0x03, 0x80, 0x03,
+        0x41, 0x03, 0x06, 0x00, 0xe4, 0x02, 0x80, 0x01, 0xa4, 0x82, 0x00, 0x00,
+        0x5f, 0x00, 0x4d, 0x05, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00,
+        0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x41, 0x00,
+        0x86, 0x82, 0x46, 0x00, 0xc1, 0x42, 0x00, 0x00, 0x06, 0xc3, 0x45, 0x00,
+        0x46, 0xc3, 0x42, 0x00, 0x47, 0x83, 0xcc, 0x06, 0x80, 0x03, 0x80, 0x03,
+        0xc1, 0x43, 0x0d, 0x00, 0x24, 0x03, 0x00, 0x02, 0xa4, 0x82, 0x00, 0x00,
+        0x8c, 0xc2, 0x46, 0x05, 0x01, 0x83, 0x0d, 0x00, 0xa4, 0x02, 0x80, 0x01,
+        0x64, 0x42, 0x00, 0x00, 0x46, 0xc2, 0x42, 0x00, 0x47, 0xc2, 0xcd, 0x04,
+        0x80, 0x02, 0x80, 0x03, 0xc1, 0x02, 0x06, 0x00, 0x00, 0x03, 0x00, 0x04,
+        0x41, 0x03, 0x06, 0x00, 0x64, 0x42, 0x80, 0x02, 0x46, 0x02, 0x41, 0x00,
+        0x80, 0x02, 0x80, 0x03, 0xa4, 0x82, 0x80, 0x00, 0x1f, 0x00, 0x49, 0x05,
+        0x1e, 0xc0, 0x02, 0x80, 0x86, 0xc2, 0x42, 0x00, 0x87, 0x82, 0x4c, 0x05,
+        0xc0, 0x02, 0x80, 0x03, 0x01, 0x03, 0x06, 0x00, 0xa4, 0x82, 0x80, 0x01,
+        0xc6, 0xc2, 0x42, 0x00, 0xc7, 0x82, 0xcc, 0x05, 0x00, 0x03, 0x00, 0x04,
+        0x41, 0x03, 0x06, 0x00, 0xe4, 0x82, 0x80, 0x01, 0x5f, 0xc0, 0x02, 0x05,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0x64, 0x42, 0x00, 0x01, 0x81, 0x41, 0x08, 0x00, 0x46, 0x02, 0x41, 0x00,
+        0x80, 0x02, 0x80, 0x03, 0xa4, 0x82, 0x80, 0x00, 0x5f, 0x40, 0x48, 0x05,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x41, 0x00, 0x86, 0x82, 0x46, 0x00,
+        0xc1, 0x42, 0x00, 0x00, 0x06, 0xc3, 0x45, 0x00, 0x46, 0xc3, 0x42, 0x00,
+        0x47, 0xc3, 0xcd, 0x06, 0x86, 0x43, 0x43, 0x00, 0xc1, 0x03, 0x06, 0x00,
+        0x00, 0x04, 0x00, 0x04, 0x41, 0x04, 0x06, 0x00, 0x24, 0x03, 0x00, 0x03,
+        0xa4, 0x82, 0x00, 0x00, 0x8c, 0xc2, 0x46, 0x05, 0x01, 0x83, 0x0d, 0x00,
+        0xa4, 0x02, 0x80, 0x01, 0x64, 0x42, 0x00, 0x00, 0x46, 0x02, 0x41, 0x00,
+        0x86, 0xc2, 0x4c, 0x00, 0xc6, 0xc2, 0x42, 0x00, 0xc7, 0x02, 0xce, 0x05,
+        0xe4, 0x02, 0x80, 0x00, 0xa4, 0x82, 0x00, 0x00, 0x5f, 0x40, 0x4e, 0x05,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0x64, 0x42, 0x00, 0x01, 0x46, 0x82, 0x4e, 0x00, 0x8b, 0x02, 0x00, 0x00,
+        0xcb, 0x42, 0x00, 0x00, 0xca, 0x02, 0xcf, 0x9d, 0x64, 0x82, 0x80, 0x01,
+        0x86, 0x02, 0x41, 0x00, 0xc6, 0x42, 0x4f, 0x00, 0x00, 0x03, 0x80, 0x04,
+        0xe4, 0x82, 0x00, 0x01, 0x1f, 0x00, 0xcf, 0x05, 0x1e, 0xc0, 0x01, 0x80,
+        0xc6, 0xc2, 0x4c, 0x00, 0x06, 0xc3, 0x42, 0x00, 0x07, 0x43, 0x4f, 0x06,
+        0x40, 0x03, 0x80, 0x04, 0x24, 0x03, 0x00, 0x01, 0xe4, 0x82, 0x00, 0x00,
+        0x5f, 0x40, 0xce, 0x05, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x42, 0x00, 0x00,
+        0xc3, 0x02, 0x80, 0x00, 0xa4, 0x42, 0x00, 0x01, 0x86, 0x02, 0x41, 0x00,
+        0xc6, 0xc2, 0x42, 0x00, 0xc7, 0x82, 0xce, 0x05, 0x00, 0x03, 0x80, 0x04,
+        0x44, 0x03, 0x00, 0x00, 0xe4, 0x82, 0x80, 0x01, 0x1f, 0x40, 0x82, 0x05,
+        0x1e, 0x00, 0x01, 0x80, 0xc6, 0x42, 0x4f, 0x00, 0x00, 0x03, 0x80, 0x04,
+        0xe4, 0x82, 0x00, 0x01, 0x5f, 0x80, 0xc5, 0x05, 0x1e, 0x00, 0x00, 0x80,
+        0xc3, 0x42, 0x00, 0x00, 0xc3, 0x02, 0x80, 0x00, 0xa4, 0x42, 0x00, 0x01,
+        0x86, 0x02, 0x41, 0x00, 0xc6, 0xc2, 0x42, 0x00, 0xc7, 0x42, 0xcf, 0x05,
+        0x01, 0x03, 0x06, 0x00, 0xe4, 0x82, 0x00, 0x01, 0x5f, 0x80, 0xc5, 0x05,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x42, 0x00, 0x00, 0xc3, 0x02, 0x80, 0x00,
+        0xa4, 0x42, 0x00, 0x01, 0x86, 0x02, 0x41, 0x00, 0xc6, 0xc2, 0x42, 0x00,
+        0xc7, 0x82, 0xcf, 0x05, 0x01, 0x03, 0x06, 0x00, 0xe4, 0x82, 0x00, 0x01,
+        0x5f, 0x80, 0xc5, 0x05, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x42, 0x00, 0x00,
+        0xc3, 0x02, 0x80, 0x00, 0xa4, 0x42, 0x00, 0x01, 0xac, 0x02, 0x01, 0x00,
+        0xec, 0x42, 0x01, 0x00, 0x00, 0x03, 0x80, 0x05, 0x24, 0x83, 0x80, 0x00,
+        0x46, 0x03, 0x41, 0x00, 0x8c, 0xc3, 0x46, 0x06, 0x01, 0xc4, 0x0f, 0x00,
+        0xa4, 0x83, 0x80, 0x01, 0xc0, 0x03, 0x00, 0x06, 0x64, 0x43, 0x80, 0x01,
+        0x46, 0x03, 0x41, 0x00, 0x86, 0x03, 0x50, 0x00, 0x87, 0x43, 0x50, 0x07,
+        0xc0, 0x03, 0x80, 0x04, 0xa4, 0x83, 0x00, 0x01, 0x5f, 0x40, 0x02, 0x07,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x43, 0x00, 0x00, 0x83, 0x03, 0x80, 0x00,
+        0x64, 0x43, 0x00, 0x01, 0x46, 0x03, 0x41, 0x00, 0x86, 0x03, 0x50, 0x00,
+        0x87, 0x43, 0x50, 0x07, 0xc4, 0x03, 0x00, 0x00, 0x01, 0x84, 0x10, 0x00,
+        0xa4, 0x83, 0x80, 0x01, 0x8c, 0xc3, 0x46, 0x07, 0x01, 0xc4, 0x10, 0x00,
+        0xa4, 0x03, 0x80, 0x01, 0x64, 0x43, 0x00, 0x00, 0x6c, 0x83, 0x01, 0x00,
+        0x80, 0x03, 0x80, 0x06, 0xc1, 0x03, 0x11, 0x00, 0xa4, 0x83, 0x00, 0x01,
+        0x00, 0x03, 0x00, 0x07, 0x86, 0x03, 0x41, 0x00, 0xcc, 0xc3, 0x46, 0x06,
+        0x41, 0x44, 0x11, 0x00, 0x81, 0x84, 0x11, 0x00, 0xc3, 0x04, 0x80, 0x00,
+        0xe4, 0x83, 0x80, 0x02, 0xe2, 0x03, 0x00, 0x00, 0x1e, 0x80, 0x02, 0x80,
+        0xc6, 0x83, 0x46, 0x00, 0x01, 0x44, 0x00, 0x00, 0x4c, 0xc4, 0x51, 0x06,
+        0xc1, 0x04, 0x12, 0x00, 0x01, 0x45, 0x12, 0x00, 0x64, 0x04, 0x00, 0x02,
+        0xe4, 0x83, 0x00, 0x00, 0x5f, 0x80, 0xc1, 0x07, 0x1e, 0x00, 0x00, 0x80,
+        0xc3, 0x43, 0x00, 0x00, 0xc3, 0x03, 0x80, 0x00, 0x00, 0x04, 0x00, 0x06,
+        0xa4, 0x43, 0x80, 0x01, 0x86, 0x03, 0x41, 0x00, 0xc6, 0x83, 0x52, 0x00,
+        0xc7, 0xc3, 0xd2, 0x07, 0x01, 0x84, 0x00, 0x00, 0x41, 0x84, 0x11, 0x00,
+        0xe4, 0x83, 0x80, 0x01, 0xcc, 0xc3, 0xc6, 0x07, 0x41, 0x04, 0x13, 0x00,
+        0xe4, 0x03, 0x80, 0x01, 0xa4, 0x43, 0x00, 0x00, 0x86, 0xc3, 0x45, 0x00,
+        0xc6, 0x43, 0x53, 0x00, 0xa4, 0xc3, 0x00, 0x01, 0x06, 0x04, 0x41, 0x00,
+        0x46, 0x84, 0x52, 0x00, 0x47, 0xc4, 0xd2, 0x08, 0x81, 0x84, 0x13, 0x00,
+        0x64, 0x84, 0x00, 0x01, 0x4c, 0xc4, 0xc6, 0x08, 0xc1, 0xc4, 0x13, 0x00,
+        0x64, 0x04, 0x80, 0x01, 0x24, 0x44, 0x00, 0x00, 0x26, 0x00, 0x80, 0x00,
+        0x50, 0x00, 0x00, 0x00, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x13, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02,
+        0x78, 0x04, 0x02, 0x79, 0x04, 0x07, 0x61, 0x73, 0x73, 0x65, 0x72, 0x74,
+        0x04, 0x0c, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x6c, 0x69, 0x6e,
+        0x65, 0x13, 0x16, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x05,
+        0x6e, 0x61, 0x6d, 0x65, 0x04, 0x06, 0x6e, 0x61, 0x6d, 0x65, 0x64, 0x04,
+        0x09, 0x6e, 0x61, 0x6d, 0x65, 0x77, 0x68, 0x61, 0x74, 0x04, 0x06, 0x6c,
+        0x6f, 0x63, 0x61, 0x6c, 0x04, 0x06, 0x64, 0x65, 0x62, 0x75, 0x67, 0x04,
+        0x08, 0x67, 0x65, 0x74, 0x69, 0x6e, 0x66, 0x6f, 0x04, 0x06, 0x70, 0x72,
+        0x69, 0x6e, 0x74, 0x04, 0x05, 0x77, 0x68, 0x61, 0x74, 0x04, 0x02, 0x43,
+        0x04, 0x0a, 0x73, 0x68, 0x6f, 0x72, 0x74, 0x5f, 0x73, 0x72, 0x63, 0x04,
+        0x04, 0x5b, 0x43, 0x5d, 0x04, 0x02, 0x53, 0x04, 0x07, 0x73, 0x6f, 0x75,
+        0x72, 0x63, 0x65, 0x04, 0x05, 0x3d, 0x5b, 0x43, 0x5d, 0x13, 0x64, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x06, 0x70, 0x63, 0x61,
+        0x6c, 0x6c, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x02, 0x58, 0x04, 0x07, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x04, 0x05,
+        0x66, 0x69, 0x6e, 0x64, 0x04, 0x0f, 0x69, 0x6e, 0x76, 0x61, 0x6c, 0x69,
+        0x64, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x04, 0x05, 0x53, 0x4c,
+        0x66, 0x75, 0x04, 0x05, 0x66, 0x75, 0x6e, 0x63, 0x04, 0x0c, 0x6c, 0x69,
+        0x6e, 0x65, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x65, 0x64, 0x04, 0x10, 0x6c,
+        0x61, 0x73, 0x74, 0x6c, 0x69, 0x6e, 0x65, 0x64, 0x65, 0x66, 0x69, 0x6e,
+        0x65, 0x64, 0x13, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x08, 0x6e, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x04, 0x0c, 0x61, 0x63,
+        0x74, 0x69, 0x76, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x73, 0x13, 0x14, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x6c, 0x13, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x04, 0x09, 0x67, 0x65, 0x74, 0x6c,
+        0x6f, 0x63, 0x61, 0x6c, 0x04, 0x02, 0x61, 0x04, 0x02, 0x62, 0x13, 0x03,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x32, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x13, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x20,
+        0x6f, 0x75, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65,
+        0x13, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x0b, 0x67,
+        0x65, 0x74, 0x75, 0x70, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x04, 0x04, 0x75,
+        0x70, 0x31, 0x04, 0x0b, 0x73, 0x65, 0x74, 0x75, 0x70, 0x76, 0x61, 0x6c,
+        0x75, 0x65, 0x13, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x0a, 0x75, 0x70, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x69, 0x64, 0x04, 0x05,
+        0x74, 0x79, 0x70, 0x65, 0x04, 0x09, 0x75, 0x73, 0x65, 0x72, 0x64, 0x61,
+        0x74, 0x61, 0x13, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x16, 0x69, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x75, 0x70, 0x76,
+        0x61, 0x6c, 0x75, 0x65, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x04, 0x0c,
+        0x75, 0x70, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x6a, 0x6f, 0x69, 0x6e, 0x04,
+        0x0c, 0x67, 0x65, 0x74, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79,
+        0x04, 0x06, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x04, 0x0d, 0x73, 0x65, 0x74,
+        0x6d, 0x65, 0x74, 0x61, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x04, 0x0c, 0x5f,
+        0x5f, 0x6d, 0x65, 0x74, 0x61, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x04, 0x07,
+        0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x04, 0x0d, 0x67, 0x65, 0x74, 0x6d,
+        0x65, 0x74, 0x61, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x04, 0x0d, 0x67, 0x65,
+        0x74, 0x75, 0x73, 0x65, 0x72, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x14, 0x67,
+        0x5e, 0x6d, 0x73, 0x67, 0x0a, 0x73, 0x74, 0x61, 0x63, 0x6b, 0x20, 0x74,
+        0x72, 0x61, 0x63, 0x65, 0x62, 0x61, 0x63, 0x6b, 0x3a, 0x0a, 0x09, 0x63,
+        0x68, 0x75, 0x6e, 0x6b, 0x3a, 0x36, 0x35, 0x3a, 0x20, 0x69, 0x6e, 0x20,
+        0x75, 0x70, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x27, 0x6c, 0x76, 0x6c,
+        0x33, 0x27, 0x0a, 0x09, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x3a, 0x36, 0x36,
+        0x3a, 0x20, 0x69, 0x6e, 0x20, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x20, 0x27,
+        0x6c, 0x76, 0x6c, 0x32, 0x27, 0x0a, 0x09, 0x63, 0x68, 0x75, 0x6e, 0x6b,
+        0x3a, 0x36, 0x37, 0x3a, 0x20, 0x69, 0x6e, 0x20, 0x6d, 0x61, 0x69, 0x6e,
+        0x20, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x04, 0x06, 0x64, 0x65, 0x62, 0x75,
+        0x67, 0x04, 0x0a, 0x74, 0x72, 0x61, 0x63, 0x65, 0x62, 0x61, 0x63, 0x6b,
+        0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x2b, 0x5e,
+        0x73, 0x74, 0x61, 0x63, 0x6b, 0x20, 0x74, 0x72, 0x61, 0x63, 0x65, 0x62,
+        0x61, 0x63, 0x6b, 0x3a, 0x0a, 0x09, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x3a,
+        0x37, 0x30, 0x3a, 0x20, 0x69, 0x6e, 0x20, 0x6d, 0x61, 0x69, 0x6e, 0x20,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x13, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x04, 0x07, 0x0a, 0x09, 0x2e, 0x2e, 0x2e, 0x0a, 0x13, 0x01,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x05, 0x67, 0x73, 0x75,
+        0x62, 0x04, 0x03, 0x0a, 0x09, 0x04, 0x01, 0x04, 0x06, 0x64, 0x65, 0x62,
+        0x75, 0x67, 0x04, 0x0a, 0x74, 0x72, 0x61, 0x63, 0x65, 0x62, 0x61, 0x63,
+        0x6b, 0x04, 0x16, 0x5e, 0x78, 0x0a, 0x73, 0x74, 0x61, 0x63, 0x6b, 0x20,
+        0x74, 0x72, 0x61, 0x63, 0x65, 0x62, 0x61, 0x63, 0x6b, 0x3a, 0x0a, 0x09,
+        0x04, 0x06, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x13, 0x0c, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x05, 0x5e, 0x31, 0x32, 0x0a, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x15, 0x00, 0x00, 0x00, 0x02, 0x01, 0x10, 0x9e, 0x00, 0x00, 0x00,
+        0x8d, 0x40, 0x00, 0x00, 0xc6, 0x00, 0x40, 0x00, 0xc7, 0x40, 0xc0, 0x01,
+        0x01, 0x81, 0x00, 0x00, 0x41, 0xc1, 0x00, 0x00, 0xe4, 0x80, 0x80, 0x01,
+        0x06, 0x01, 0x41, 0x00, 0x47, 0x41, 0xc1, 0x01, 0x1f, 0x80, 0xc1, 0x02,
+        0x1e, 0x40, 0x01, 0x80, 0x47, 0xc1, 0xc1, 0x01, 0x1f, 0x00, 0xc2, 0x02,
+        0x1e, 0x80, 0x00, 0x80, 0x47, 0x41, 0xc2, 0x01, 0x5f, 0x80, 0xc0, 0x02,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x41, 0x00, 0x00, 0x43, 0x01, 0x80, 0x00,
+        0x24, 0x41, 0x00, 0x01, 0x06, 0x01, 0x41, 0x00, 0x47, 0x81, 0xc2, 0x01,
+        0x1f, 0xc0, 0xc2, 0x02, 0x1e, 0x40, 0x01, 0x80, 0x47, 0x01, 0xc3, 0x01,
+        0x62, 0x01, 0x00, 0x00, 0x1e, 0x00, 0x01, 0x80, 0x47, 0x41, 0xc3, 0x01,
+        0x5f, 0x80, 0xc0, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x41, 0x00, 0x00,
+        0x43, 0x01, 0x80, 0x00, 0x24, 0x41, 0x00, 0x01, 0x06, 0x01, 0x41, 0x00,
+        0x47, 0x81, 0xc3, 0x01, 0x1f, 0xc0, 0xc3, 0x02, 0x1e, 0x80, 0x00, 0x80,
+        0x47, 0x01, 0xc4, 0x01, 0x5f, 0x40, 0xc4, 0x02, 0x1e, 0x00, 0x00, 0x80,
+        0x43, 0x41, 0x00, 0x00, 0x43, 0x01, 0x80, 0x00, 0x24, 0x41, 0x00, 0x01,
+        0x0b, 0x01, 0x00, 0x00, 0x41, 0x81, 0x00, 0x00, 0x86, 0x81, 0x44, 0x00,
+        0x87, 0xc1, 0x44, 0x03, 0xc1, 0x81, 0x00, 0x00, 0x68, 0xc1, 0x03, 0x80,
+        0x46, 0x02, 0x40, 0x00, 0x47, 0x02, 0xc5, 0x04, 0x81, 0x82, 0x00, 0x00,
+        0xc0, 0x02, 0x00, 0x04, 0x64, 0xc2, 0x80, 0x01, 0x62, 0x02, 0x00, 0x00,
+        0x1e, 0x40, 0x02, 0x80, 0xdc, 0x02, 0x00, 0x02, 0xcd, 0x82, 0xc0, 0x05,
+        0x00, 0x03, 0x80, 0x04, 0x41, 0x43, 0x05, 0x00, 0x86, 0x83, 0x45, 0x00,
+        0xc0, 0x03, 0x00, 0x05, 0xa4, 0x83, 0x00, 0x01, 0x1d, 0x83, 0x03, 0x06,
+        0x0a, 0x01, 0x83, 0x05, 0x67, 0x81, 0xfb, 0x7f, 0x46, 0x01, 0x41, 0x00,
+        0x87, 0x81, 0x40, 0x02, 0x1f, 0xc0, 0x45, 0x03, 0x1e, 0x40, 0x01, 0x80,
+        0x87, 0xc1, 0x42, 0x02, 0x1f, 0x00, 0x46, 0x03, 0x1e, 0x80, 0x00, 0x80,
+        0x87, 0x81, 0x41, 0x02, 0x5f, 0x40, 0x46, 0x03, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80, 0x00, 0x64, 0x41, 0x00, 0x01,
+        0x46, 0x01, 0x41, 0x00, 0x86, 0x01, 0x40, 0x00, 0x87, 0x01, 0x45, 0x03,
+        0xc1, 0x81, 0x00, 0x00, 0x01, 0x82, 0x06, 0x00, 0xa4, 0x81, 0x80, 0x01,
+        0x1f, 0xc0, 0x46, 0x03, 0x1e, 0x40, 0x02, 0x80, 0x86, 0x01, 0x47, 0x00,
+        0xc1, 0xc1, 0x02, 0x00, 0x06, 0x02, 0x40, 0x00, 0x07, 0x02, 0x45, 0x04,
+        0x41, 0x82, 0x00, 0x00, 0x81, 0x42, 0x07, 0x00, 0x24, 0x02, 0x80, 0x01,
+        0xa4, 0x81, 0x00, 0x00, 0x5f, 0x80, 0x47, 0x03, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80, 0x00, 0x64, 0x41, 0x00, 0x01,
+        0x46, 0x01, 0x41, 0x00, 0x86, 0x01, 0x40, 0x00, 0x87, 0x01, 0x45, 0x03,
+        0xc1, 0x81, 0x00, 0x00, 0x01, 0xc2, 0x07, 0x00, 0xa4, 0x81, 0x80, 0x01,
+        0x5f, 0x00, 0x48, 0x03, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x41, 0x00, 0x00,
+        0x83, 0x01, 0x80, 0x00, 0x64, 0x41, 0x00, 0x01, 0x46, 0x01, 0x41, 0x00,
+        0x86, 0x01, 0x40, 0x00, 0x87, 0x41, 0x48, 0x03, 0xc1, 0x81, 0x00, 0x00,
+        0x01, 0x82, 0x01, 0x00, 0x41, 0x82, 0x08, 0x00, 0xa4, 0x81, 0x00, 0x02,
+        0x1f, 0xc0, 0x48, 0x03, 0x1e, 0x40, 0x00, 0x80, 0x5f, 0x80, 0x48, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80, 0x00,
+        0x64, 0x41, 0x00, 0x01, 0x46, 0x01, 0x41, 0x00, 0x86, 0x01, 0x40, 0x00,
+        0x87, 0x41, 0x48, 0x03, 0xc1, 0x81, 0x00, 0x00, 0x01, 0x82, 0x06, 0x00,
+        0x41, 0x02, 0x09, 0x00, 0xa4, 0x81, 0x00, 0x02, 0x1f, 0xc0, 0x46, 0x03,
+        0x1e, 0x80, 0x00, 0x80, 0xad, 0x01, 0x00, 0x01, 0x5f, 0x00, 0x49, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80, 0x00,
+        0x64, 0x41, 0x00, 0x01, 0x46, 0x01, 0x41, 0x00, 0x86, 0x01, 0x40, 0x00,
+        0x87, 0x41, 0x48, 0x03, 0xc1, 0x81, 0x00, 0x00, 0x01, 0x42, 0x09, 0x00,
+        0x41, 0x82, 0x09, 0x00, 0xa4, 0x81, 0x00, 0x02, 0x5f, 0x00, 0x48, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80, 0x00,
+        0x64, 0x41, 0x00, 0x01, 0x46, 0x01, 0x40, 0x00, 0x47, 0x41, 0xc0, 0x02,
+        0x81, 0xc1, 0x02, 0x00, 0xc1, 0xc1, 0x09, 0x00, 0x64, 0x81, 0x80, 0x01,
+        0x66, 0x01, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x28, 0x00, 0x00, 0x00,
+        0x04, 0x06, 0x64, 0x65, 0x62, 0x75, 0x67, 0x04, 0x08, 0x67, 0x65, 0x74,
+        0x69, 0x6e, 0x66, 0x6f, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x05, 0x53, 0x6c, 0x6e, 0x75, 0x04, 0x07, 0x61, 0x73, 0x73,
+        0x65, 0x72, 0x74, 0x04, 0x0c, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74,
+        0x6c, 0x69, 0x6e, 0x65, 0x13, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x05, 0x77, 0x68, 0x61, 0x74, 0x04, 0x04, 0x4c, 0x75, 0x61,
+        0x04, 0x0c, 0x6c, 0x69, 0x6e, 0x65, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x65,
+        0x64, 0x04, 0x08, 0x6e, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x13, 0x02,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x09, 0x69, 0x73, 0x76,
+        0x61, 0x72, 0x61, 0x72, 0x67, 0x04, 0x05, 0x6e, 0x75, 0x70, 0x73, 0x04,
+        0x0a, 0x73, 0x68, 0x6f, 0x72, 0x74, 0x5f, 0x73, 0x72, 0x63, 0x04, 0x06,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x04, 0x07, 0x73, 0x6f, 0x75, 0x72, 0x63,
+        0x65, 0x04, 0x07, 0x40, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x04, 0x05, 0x6d,
+        0x61, 0x74, 0x68, 0x04, 0x05, 0x68, 0x75, 0x67, 0x65, 0x04, 0x09, 0x67,
+        0x65, 0x74, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x04, 0x02, 0x3d, 0x04, 0x09,
+        0x74, 0x6f, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x04, 0x04, 0x61, 0x3d,
+        0x31, 0x04, 0x04, 0x62, 0x3d, 0x32, 0x04, 0x04, 0x63, 0x3d, 0x33, 0x13,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x04, 0x0a, 0x28, 0x2a,
+        0x76, 0x61, 0x72, 0x61, 0x72, 0x67, 0x29, 0x04, 0x07, 0x73, 0x65, 0x6c,
+        0x65, 0x63, 0x74, 0x13, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x04, 0x02, 0x79, 0x13, 0xfd, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x00, 0x04, 0x09, 0x73, 0x65, 0x74, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x13,
+        0x1e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x63, 0x04,
+        0x02, 0x58, 0x13, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x03, 0x6e, 0x6c,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x9e, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x02, 0x61, 0x00, 0x00, 0x00, 0x00, 0x9e, 0x00, 0x00, 0x00,
+        0x02, 0x62, 0x00, 0x00, 0x00, 0x00, 0x9e, 0x00, 0x00, 0x00, 0x02, 0x63,
+        0x01, 0x00, 0x00, 0x00, 0x9e, 0x00, 0x00, 0x00, 0x05, 0x69, 0x6e, 0x66,
+        0x6f, 0x06, 0x00, 0x00, 0x00, 0x9e, 0x00, 0x00, 0x00, 0x06, 0x6e, 0x61,
+        0x6d, 0x65, 0x73, 0x2b, 0x00, 0x00, 0x00, 0x9e, 0x00, 0x00, 0x00, 0x0c,
+        0x28, 0x66, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x29, 0x2f,
+        0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72,
+        0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x29, 0x2f, 0x00, 0x00, 0x00, 0x41,
+        0x00, 0x00, 0x00, 0x0b, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74, 0x65,
+        0x70, 0x29, 0x2f, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x02, 0x69,
+        0x30, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x05, 0x6e, 0x61, 0x6d,
+        0x65, 0x35, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x04, 0x76, 0x61,
+        0x6c, 0x35, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x02, 0x72, 0x9c,
+        0x00, 0x00, 0x00, 0x9e, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05,
+        0x5f, 0x45, 0x4e, 0x56, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x07, 0x00, 0x00, 0x00, 0x06, 0x00, 0x40, 0x00,
+        0x07, 0x40, 0x40, 0x00, 0x41, 0x80, 0x00, 0x00, 0x81, 0xc0, 0x00, 0x00,
+        0x24, 0x80, 0x80, 0x01, 0x26, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x04, 0x06, 0x64, 0x65, 0x62, 0x75, 0x67, 0x04,
+        0x08, 0x67, 0x65, 0x74, 0x69, 0x6e, 0x66, 0x6f, 0x13, 0x01, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x6e, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x19, 0x00,
+        0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00,
+        0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x72, 0x05, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e,
+        0x56, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0x03, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00,
+        0x01, 0x26, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x01, 0x05, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x2a,
+        0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x75, 0x70, 0x31, 0x00,
+        0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x01, 0x26,
+        0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x06, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00,
+        0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x75, 0x70, 0x32, 0x00, 0x41, 0x00,
+        0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x07, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x40, 0x00, 0x07, 0x40, 0x40, 0x00, 0x41, 0x80, 0x00,
+        0x00, 0x81, 0xc0, 0x00, 0x00, 0x24, 0x80, 0x80, 0x01, 0x26, 0x00, 0x00,
+        0x01, 0x26, 0x00, 0x80, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x06, 0x64,
+        0x65, 0x62, 0x75, 0x67, 0x04, 0x0a, 0x74, 0x72, 0x61, 0x63, 0x65, 0x62,
+        0x61, 0x63, 0x6b, 0x04, 0x04, 0x6d, 0x73, 0x67, 0x13, 0x01, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x41,
+        0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x41,
+        0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x02, 0x73, 0x05, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56, 0x00, 0x42,
+        0x00, 0x00, 0x00, 0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x06, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x24, 0x80, 0x80, 0x00, 0x41, 0x00,
+        0x00, 0x00, 0x1d, 0x40, 0x00, 0x00, 0x26, 0x00, 0x00, 0x01, 0x26, 0x00,
+        0x80, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x01, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x42, 0x00,
+        0x00, 0x00, 0x42, 0x00, 0x00, 0x00, 0x42, 0x00, 0x00, 0x00, 0x42, 0x00,
+        0x00, 0x00, 0x42, 0x00, 0x00, 0x00, 0x42, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x6c, 0x76, 0x6c, 0x33, 0x00,
+        0x47, 0x00, 0x00, 0x00, 0x47, 0x00, 0x00, 0x00, 0x01, 0x00, 0x03, 0x0b,
+        0x00, 0x00, 0x00, 0x1f, 0x00, 0x40, 0x00, 0x1e, 0xc0, 0x00, 0x80, 0x46,
+        0x40, 0x40, 0x00, 0x47, 0x80, 0xc0, 0x00, 0x65, 0x00, 0x80, 0x00, 0x66,
+        0x00, 0x00, 0x00, 0x45, 0x00, 0x80, 0x00, 0x8e, 0xc0, 0x40, 0x00, 0x64,
+        0x80, 0x00, 0x01, 0x66, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x04, 0x06, 0x64, 0x65, 0x62, 0x75, 0x67, 0x04, 0x0a, 0x74, 0x72, 0x61,
+        0x63, 0x65, 0x62, 0x61, 0x63, 0x6b, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x0d, 0x00,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x47, 0x00, 0x00, 0x00, 0x47,
+        0x00, 0x00, 0x00, 0x47, 0x00, 0x00, 0x00, 0x47, 0x00, 0x00, 0x00, 0x47,
+        0x00, 0x00, 0x00, 0x47, 0x00, 0x00, 0x00, 0x47, 0x00, 0x00, 0x00, 0x47,
+        0x00, 0x00, 0x00, 0x47, 0x00, 0x00, 0x00, 0x47, 0x00, 0x00, 0x00, 0x47,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x6e, 0x00, 0x00, 0x00,
+        0x00, 0x0b, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45,
+        0x4e, 0x56, 0x05, 0x64, 0x65, 0x65, 0x70, 0xd8, 0x01, 0x00, 0x00, 0x15,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17,
+        0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17,
+        0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x1a,
+        0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b,
+        0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b,
+        0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b,
+        0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1e,
+        0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e,
+        0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e,
+        0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e,
+        0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26,
+        0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26,
+        0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26,
+        0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26,
+        0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26,
+        0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x2a,
+        0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30,
+        0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30,
+        0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30,
+        0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30,
+        0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x32, 0x00, 0x00, 0x00, 0x32,
+        0x00, 0x00, 0x00, 0x32, 0x00, 0x00, 0x00, 0x32, 0x00, 0x00, 0x00, 0x32,
+        0x00, 0x00, 0x00, 0x32, 0x00, 0x00, 0x00, 0x32, 0x00, 0x00, 0x00, 0x33,
+        0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33,
+        0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33,
+        0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33,
+        0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33,
+        0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33,
+        0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33,
+        0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x35,
+        0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35,
+        0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35,
+        0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x36,
+        0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x36,
+        0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x36,
+        0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x36,
+        0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x36,
+        0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x39,
+        0x00, 0x00, 0x00, 0x39, 0x00, 0x00, 0x00, 0x39, 0x00, 0x00, 0x00, 0x39,
+        0x00, 0x00, 0x00, 0x39, 0x00, 0x00, 0x00, 0x39, 0x00, 0x00, 0x00, 0x39,
+        0x00, 0x00, 0x00, 0x39, 0x00, 0x00, 0x00, 0x39, 0x00, 0x00, 0x00, 0x39,
+        0x00, 0x00, 0x00, 0x39, 0x00, 0x00, 0x00, 0x3a, 0x00, 0x00, 0x00, 0x3a,
+        0x00, 0x00, 0x00, 0x3a, 0x00, 0x00, 0x00, 0x3a, 0x00, 0x00, 0x00, 0x3a,
+        0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b,
+        0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b,
+        0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b,
+        0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b,
+        0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b,
+        0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3c,
+        0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x3c,
+        0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x3c,
+        0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x3c,
+        0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x3c,
+        0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x3c,
+        0x00, 0x00, 0x00, 0x3d, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x00, 0x00, 0x3d,
+        0x00, 0x00, 0x00, 0x3d, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x00, 0x00, 0x3d,
+        0x00, 0x00, 0x00, 0x3d, 0x00, 0x00, 0x00, 0x3d, 0x00, 0x00, 0x00, 0x3d,
+        0x00, 0x00, 0x00, 0x3d, 0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e,
+        0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e,
+        0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e,
+        0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x41,
+        0x00, 0x00, 0x00, 0x42, 0x00, 0x00, 0x00, 0x43, 0x00, 0x00, 0x00, 0x43,
+        0x00, 0x00, 0x00, 0x44, 0x00, 0x00, 0x00, 0x44, 0x00, 0x00, 0x00, 0x44,
+        0x00, 0x00, 0x00, 0x44, 0x00, 0x00, 0x00, 0x44, 0x00, 0x00, 0x00, 0x44,
+        0x00, 0x00, 0x00, 0x45, 0x00, 0x00, 0x00, 0x45, 0x00, 0x00, 0x00, 0x45,
+        0x00, 0x00, 0x00, 0x45, 0x00, 0x00, 0x00, 0x45, 0x00, 0x00, 0x00, 0x45,
+        0x00, 0x00, 0x00, 0x45, 0x00, 0x00, 0x00, 0x45, 0x00, 0x00, 0x00, 0x45,
+        0x00, 0x00, 0x00, 0x45, 0x00, 0x00, 0x00, 0x46, 0x00, 0x00, 0x00, 0x46,
+        0x00, 0x00, 0x00, 0x46, 0x00, 0x00, 0x00, 0x46, 0x00, 0x00, 0x00, 0x46,
+        0x00, 0x00, 0x00, 0x46, 0x00, 0x00, 0x00, 0x46, 0x00, 0x00, 0x00, 0x46,
+        0x00, 0x00, 0x00, 0x46, 0x00, 0x00, 0x00, 0x46, 0x00, 0x00, 0x00, 0x47,
+        0x00, 0x00, 0x00, 0x48, 0x00, 0x00, 0x00, 0x48, 0x00, 0x00, 0x00, 0x48,
+        0x00, 0x00, 0x00, 0x48, 0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x49,
+        0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x49,
+        0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x49,
+        0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x49,
+        0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x49,
+        0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x49,
+        0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x49,
+        0x00, 0x00, 0x00, 0x49, 0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x4a,
+        0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x4a,
+        0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x4a,
+        0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x4b,
+        0x00, 0x00, 0x00, 0x4b, 0x00, 0x00, 0x00, 0x4b, 0x00, 0x00, 0x00, 0x4c,
+        0x00, 0x00, 0x00, 0x4c, 0x00, 0x00, 0x00, 0x4c, 0x00, 0x00, 0x00, 0x4c,
+        0x00, 0x00, 0x00, 0x4c, 0x00, 0x00, 0x00, 0x4c, 0x00, 0x00, 0x00, 0x4c,
+        0x00, 0x00, 0x00, 0x4c, 0x00, 0x00, 0x00, 0x4c, 0x00, 0x00, 0x00, 0x4c,
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x02, 0x66, 0x01, 0x00, 0x00,
+        0x00, 0xd8, 0x01, 0x00, 0x00, 0x07, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72,
+        0x07, 0x00, 0x00, 0x00, 0xd8, 0x01, 0x00, 0x00, 0x06, 0x6e, 0x61, 0x6d,
+        0x65, 0x64, 0x0f, 0x00, 0x00, 0x00, 0xd8, 0x01, 0x00, 0x00, 0x02, 0x6e,
+        0x11, 0x00, 0x00, 0x00, 0xd8, 0x01, 0x00, 0x00, 0x03, 0x66, 0x69, 0x5f,
+        0x00, 0x00, 0x00, 0xd8, 0x01, 0x00, 0x00, 0x04, 0x75, 0x70, 0x31, 0xb1,
+        0x00, 0x00, 0x00, 0xd8, 0x01, 0x00, 0x00, 0x04, 0x75, 0x70, 0x32, 0xb1,
+        0x00, 0x00, 0x00, 0xd8, 0x01, 0x00, 0x00, 0x02, 0x67, 0xb2, 0x00, 0x00,
+        0x00, 0xd8, 0x01, 0x00, 0x00, 0x02, 0x68, 0xb3, 0x00, 0x00, 0x00, 0xd8,
+        0x01, 0x00, 0x00, 0x02, 0x74, 0x54, 0x01, 0x00, 0x00, 0xd8, 0x01, 0x00,
+        0x00, 0x05, 0x6c, 0x76, 0x6c, 0x33, 0x8a, 0x01, 0x00, 0x00, 0xd8, 0x01,
+        0x00, 0x00, 0x05, 0x6c, 0x76, 0x6c, 0x32, 0x8b, 0x01, 0x00, 0x00, 0xd8,
+        0x01, 0x00, 0x00, 0x03, 0x74, 0x62, 0x8d, 0x01, 0x00, 0x00, 0xd8, 0x01,
+        0x00, 0x00, 0x05, 0x64, 0x65, 0x65, 0x70, 0xa8, 0x01, 0x00, 0x00, 0xd8,
+        0x01, 0x00, 0x00, 0x03, 0x6f, 0x6b, 0xce, 0x01, 0x00, 0x00, 0xd8, 0x01,
+        0x00, 0x00, 0x04, 0x65, 0x72, 0x72, 0xce, 0x01, 0x00, 0x00, 0xd8, 0x01,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
+
+    /* Lua source code:
+        local events = {}
+        local function rec(ev, line)
+          events[#events + 1] = line and (ev .. ":" .. line) or ev
+        end
+        local function add(a, b)
+          local c = a + b
+          return c
+        end
+        local function tail(a)
+          return add(a, 1)
+        end
+
+        debug.sethook(rec, "crl")
+        local x = add(1, 2)
+        for i = 1, 3 do x = x + i end
+        debug.sethook()
+        assert(table.concat(events, " ") == "return line:14 call line:6 line:7 return " ..
+          "line:15 line:15 line:15 line:15 line:16 call", table.concat(events, " "))
+
+        events = {}
+        debug.sethook(rec, "cr")
+        tail(1)
+        debug.sethook()
+        assert(table.concat(events, " ") == "return call tail call return call", table.concat(events, " "))
+
+        local f, m, c = debug.gethook()
+        assert(f == nil and m == "" and c == 0)
+        debug.sethook(rec, "lc", 7)
+        f, m, c = debug.gethook()
+        debug.sethook()
+        assert(f == rec and m == "cl" and c == 7)
+
+        local n = 0
+        debug.sethook(function(ev) n = n + 1 end, "", 1)
+        add(1, 2)
+        debug.sethook()
+        assert(n == 9, n)
+
+        local ok, err = pcall(function()
+          debug.sethook(function() error("out of budget") end, "", 1000)
+          while true do end
+        end)
+        debug.sethook()
+        assert(not ok and err == "chunk:40: out of budget", err)
+    */
+    const LUA_HOOKS_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x07, 0x40,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x0e, 0x91, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x6c, 0x00, 0x00, 0x00, 0xac, 0x40, 0x00, 0x00, 0xec, 0x80, 0x00, 0x00,
+        0x06, 0x01, 0x40, 0x00, 0x07, 0x41, 0x40, 0x02, 0x40, 0x01, 0x80, 0x00,
+        0x81, 0x81, 0x00, 0x00, 0x24, 0x41, 0x80, 0x01, 0x00, 0x01, 0x00, 0x01,
+        0x41, 0xc1, 0x00, 0x00, 0x81, 0x01, 0x01, 0x00, 0x24, 0x81, 0x80, 0x01,
+        0x41, 0xc1, 0x00, 0x00, 0x81, 0x41, 0x01, 0x00, 0xc1, 0xc1, 0x00, 0x00,
+        0x68, 0x01, 0x00, 0x80, 0x0d, 0x01, 0x02, 0x02, 0x67, 0x41, 0xff, 0x7f,
+        0x46, 0x01, 0x40, 0x00, 0x47, 0x41, 0xc0, 0x02, 0x64, 0x41, 0x80, 0x00,
+        0x46, 0x81, 0x41, 0x00, 0x86, 0xc1, 0x41, 0x00, 0x87, 0x01, 0x42, 0x03,
+        0xc0, 0x01, 0x00, 0x00, 0x01, 0x42, 0x02, 0x00, 0xa4, 0x81, 0x80, 0x01,
+        0xc1, 0x81, 0x02, 0x00, 0x01, 0xc2, 0x02, 0x00, 0xdd, 0x01, 0x82, 0x03,
+        0x5f, 0xc0, 0x01, 0x03, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x41, 0x00, 0x00,
+        0x83, 0x01, 0x80, 0x00, 0xc6, 0xc1, 0x41, 0x00, 0xc7, 0x01, 0xc2, 0x03,
+        0x00, 0x02, 0x00, 0x00, 0x41, 0x42, 0x02, 0x00, 0xe4, 0x01, 0x80, 0x01,
+        0x64, 0x41, 0x00, 0x00, 0x4b, 0x01, 0x00, 0x00, 0x00, 0x00, 0x80, 0x02,
+        0x46, 0x01, 0x40, 0x00, 0x47, 0x41, 0xc0, 0x02, 0x80, 0x01, 0x80, 0x00,
+        0xc1, 0x01, 0x03, 0x00, 0x64, 0x41, 0x80, 0x01, 0x40, 0x01, 0x80, 0x01,
+        0x81, 0xc1, 0x00, 0x00, 0x64, 0x41, 0x00, 0x01, 0x46, 0x01, 0x40, 0x00,
+        0x47, 0x41, 0xc0, 0x02, 0x64, 0x41, 0x80, 0x00, 0x46, 0x81, 0x41, 0x00,
+        0x86, 0xc1, 0x41, 0x00, 0x87, 0x01, 0x42, 0x03, 0xc0, 0x01, 0x00, 0x00,
+        0x01, 0x42, 0x02, 0x00, 0xa4, 0x81, 0x80, 0x01, 0x5f, 0x40, 0x43, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80, 0x00,
+        0xc6, 0xc1, 0x41, 0x00, 0xc7, 0x01, 0xc2, 0x03, 0x00, 0x02, 0x00, 0x00,
+        0x41, 0x42, 0x02, 0x00, 0xe4, 0x01, 0x80, 0x01, 0x64, 0x41, 0x00, 0x00,
+        0x46, 0x01, 0x40, 0x00, 0x47, 0x81, 0xc3, 0x02, 0x64, 0x01, 0x81, 0x00,
+        0x06, 0x82, 0x41, 0x00, 0x1f, 0xc0, 0xc3, 0x02, 0x1e, 0xc0, 0x00, 0x80,
+        0x1f, 0x00, 0x44, 0x03, 0x1e, 0x40, 0x00, 0x80, 0x5f, 0x40, 0xc4, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x42, 0x00, 0x00, 0x43, 0x02, 0x80, 0x00,
+        0x24, 0x42, 0x00, 0x01, 0x06, 0x02, 0x40, 0x00, 0x07, 0x42, 0x40, 0x04,
+        0x40, 0x02, 0x80, 0x00, 0x81, 0x82, 0x04, 0x00, 0xc1, 0xc2, 0x04, 0x00,
+        0x24, 0x42, 0x00, 0x02, 0x06, 0x02, 0x40, 0x00, 0x07, 0x82, 0x43, 0x04,
+        0x24, 0x02, 0x81, 0x00, 0xc0, 0x01, 0x00, 0x05, 0x80, 0x01, 0x80, 0x04,
+        0x40, 0x01, 0x00, 0x04, 0x06, 0x02, 0x40, 0x00, 0x07, 0x42, 0x40, 0x04,
+        0x24, 0x42, 0x80, 0x00, 0x06, 0x82, 0x41, 0x00, 0x1f, 0x40, 0x80, 0x02,
+        0x1e, 0xc0, 0x00, 0x80, 0x1f, 0x00, 0x45, 0x03, 0x1e, 0x40, 0x00, 0x80,
+        0x5f, 0xc0, 0xc4, 0x03, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x42, 0x00, 0x00,
+        0x43, 0x02, 0x80, 0x00, 0x24, 0x42, 0x00, 0x01, 0x01, 0x42, 0x04, 0x00,
+        0x46, 0x02, 0x40, 0x00, 0x47, 0x42, 0xc0, 0x04, 0xac, 0xc2, 0x00, 0x00,
+        0xc1, 0x02, 0x04, 0x00, 0x01, 0x43, 0x05, 0x00, 0x64, 0x42, 0x00, 0x02,
+        0x40, 0x02, 0x00, 0x01, 0x81, 0x42, 0x05, 0x00, 0xc1, 0x02, 0x01, 0x00,
+        0x64, 0x42, 0x80, 0x01, 0x46, 0x02, 0x40, 0x00, 0x47, 0x42, 0xc0, 0x04,
+        0x64, 0x42, 0x80, 0x00, 0x46, 0x82, 0x41, 0x00, 0x5f, 0x80, 0x45, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0xc0, 0x02, 0x00, 0x04, 0x64, 0x42, 0x80, 0x01, 0x46, 0xc2, 0x45, 0x00,
+        0xac, 0x02, 0x01, 0x00, 0x64, 0xc2, 0x00, 0x01, 0xc6, 0x02, 0x40, 0x00,
+        0xc7, 0x42, 0xc0, 0x05, 0xe4, 0x42, 0x80, 0x00, 0xc6, 0x82, 0x41, 0x00,
+        0x62, 0x42, 0x00, 0x00, 0x1e, 0x40, 0x00, 0x80, 0x5f, 0x00, 0x46, 0x05,
+        0x1e, 0x00, 0x00, 0x80, 0x03, 0x43, 0x00, 0x00, 0x03, 0x03, 0x80, 0x00,
+        0x40, 0x03, 0x00, 0x05, 0xe4, 0x42, 0x80, 0x01, 0x26, 0x00, 0x80, 0x00,
+        0x19, 0x00, 0x00, 0x00, 0x04, 0x06, 0x64, 0x65, 0x62, 0x75, 0x67, 0x04,
+        0x08, 0x73, 0x65, 0x74, 0x68, 0x6f, 0x6f, 0x6b, 0x04, 0x04, 0x63, 0x72,
+        0x6c, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x02,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x03, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x07, 0x61, 0x73, 0x73, 0x65, 0x72, 0x74,
+        0x04, 0x06, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x04, 0x07, 0x63, 0x6f, 0x6e,
+        0x63, 0x61, 0x74, 0x04, 0x02, 0x20, 0x14, 0x2a, 0x72, 0x65, 0x74, 0x75,
+        0x72, 0x6e, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x3a, 0x31, 0x34, 0x20, 0x63,
+        0x61, 0x6c, 0x6c, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x3a, 0x36, 0x20, 0x6c,
+        0x69, 0x6e, 0x65, 0x3a, 0x37, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e,
+        0x20, 0x14, 0x2d, 0x6c, 0x69, 0x6e, 0x65, 0x3a, 0x31, 0x35, 0x20, 0x6c,
+        0x69, 0x6e, 0x65, 0x3a, 0x31, 0x35, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x3a,
+        0x31, 0x35, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x3a, 0x31, 0x35, 0x20, 0x6c,
+        0x69, 0x6e, 0x65, 0x3a, 0x31, 0x36, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x04,
+        0x03, 0x63, 0x72, 0x04, 0x22, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x20,
+        0x63, 0x61, 0x6c, 0x6c, 0x20, 0x74, 0x61, 0x69, 0x6c, 0x20, 0x63, 0x61,
+        0x6c, 0x6c, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x20, 0x63, 0x61,
+        0x6c, 0x6c, 0x04, 0x08, 0x67, 0x65, 0x74, 0x68, 0x6f, 0x6f, 0x6b, 0x00,
+        0x04, 0x01, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x03, 0x6c, 0x63, 0x13, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x04, 0x03, 0x63, 0x6c, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x13, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x06,
+        0x70, 0x63, 0x61, 0x6c, 0x6c, 0x04, 0x18, 0x63, 0x68, 0x75, 0x6e, 0x6b,
+        0x3a, 0x34, 0x30, 0x3a, 0x20, 0x6f, 0x75, 0x74, 0x20, 0x6f, 0x66, 0x20,
+        0x62, 0x75, 0x64, 0x67, 0x65, 0x74, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x06, 0x0e, 0x00, 0x00, 0x00, 0x85, 0x00, 0x00, 0x00,
+        0x9c, 0x00, 0x00, 0x01, 0x8d, 0x00, 0x40, 0x01, 0x62, 0x00, 0x00, 0x00,
+        0x1e, 0x40, 0x01, 0x80, 0xc0, 0x00, 0x00, 0x00, 0x01, 0x41, 0x00, 0x00,
+        0x40, 0x01, 0x80, 0x00, 0xdd, 0x40, 0x81, 0x01, 0xe2, 0x40, 0x00, 0x00,
+        0x1e, 0x00, 0x00, 0x80, 0xc0, 0x00, 0x00, 0x00, 0x08, 0xc0, 0x00, 0x01,
+        0x26, 0x00, 0x80, 0x00, 0x02, 0x00, 0x00, 0x00, 0x13, 0x01, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x3a, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x65,
+        0x76, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x05, 0x6c, 0x69,
+        0x6e, 0x65, 0x00, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x07, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x02, 0x00, 0x03, 0x03, 0x00, 0x00,
+        0x00, 0x8d, 0x40, 0x00, 0x00, 0xa6, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00,
+        0x00, 0x08, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x02, 0x61, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x02, 0x62, 0x00, 0x00, 0x00,
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x02, 0x63, 0x01, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x06, 0x00, 0x00, 0x00, 0x45,
+        0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0xc1, 0x00, 0x00, 0x00, 0x65,
+        0x00, 0x80, 0x01, 0x66, 0x00, 0x00, 0x00, 0x26, 0x00, 0x80, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x61, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x61, 0x64, 0x64,
+        0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02,
+        0x04, 0x00, 0x00, 0x00, 0x45, 0x00, 0x00, 0x00, 0x4d, 0x00, 0xc0, 0x00,
+        0x49, 0x00, 0x00, 0x00, 0x26, 0x00, 0x80, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x01, 0x08, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03, 0x65, 0x76, 0x00, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x6e,
+        0x00, 0x27, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x08, 0x00, 0x00, 0x00, 0x06, 0x00, 0x40, 0x00, 0x07, 0x40, 0x40, 0x00,
+        0x6c, 0x00, 0x00, 0x00, 0x81, 0x80, 0x00, 0x00, 0xc1, 0xc0, 0x00, 0x00,
+        0x24, 0x40, 0x00, 0x02, 0x1e, 0x80, 0xff, 0x7f, 0x26, 0x00, 0x80, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x04, 0x06, 0x64, 0x65, 0x62, 0x75, 0x67, 0x04,
+        0x08, 0x73, 0x65, 0x74, 0x68, 0x6f, 0x6f, 0x6b, 0x04, 0x01, 0x13, 0xe8,
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x04, 0x00, 0x00, 0x00, 0x06, 0x00, 0x40,
+        0x00, 0x41, 0x40, 0x00, 0x00, 0x24, 0x40, 0x00, 0x01, 0x26, 0x00, 0x80,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0x06, 0x65, 0x72, 0x72, 0x6f, 0x72,
+        0x04, 0x0e, 0x6f, 0x75, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x62, 0x75, 0x64,
+        0x67, 0x65, 0x74, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00,
+        0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56, 0x08, 0x00,
+        0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00,
+        0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00,
+        0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56, 0x91,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x12,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12,
+        0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12,
+        0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12,
+        0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15,
+        0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17,
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a,
+        0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b,
+        0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b,
+        0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b,
+        0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1e,
+        0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x25,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25,
+        0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x27,
+        0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x07,
+        0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x01, 0x00, 0x00, 0x00, 0x91, 0x00,
+        0x00, 0x00, 0x04, 0x72, 0x65, 0x63, 0x02, 0x00, 0x00, 0x00, 0x91, 0x00,
+        0x00, 0x00, 0x04, 0x61, 0x64, 0x64, 0x03, 0x00, 0x00, 0x00, 0x91, 0x00,
+        0x00, 0x00, 0x05, 0x74, 0x61, 0x69, 0x6c, 0x04, 0x00, 0x00, 0x00, 0x91,
+        0x00, 0x00, 0x00, 0x02, 0x78, 0x0d, 0x00, 0x00, 0x00, 0x91, 0x00, 0x00,
+        0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78,
+        0x29, 0x10, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x0c, 0x28, 0x66,
+        0x6f, 0x72, 0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x29, 0x10, 0x00, 0x00,
+        0x00, 0x13, 0x00, 0x00, 0x00, 0x0b, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73,
+        0x74, 0x65, 0x70, 0x29, 0x10, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00,
+        0x02, 0x69, 0x11, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x02, 0x66,
+        0x49, 0x00, 0x00, 0x00, 0x91, 0x00, 0x00, 0x00, 0x02, 0x6d, 0x49, 0x00,
+        0x00, 0x00, 0x91, 0x00, 0x00, 0x00, 0x02, 0x63, 0x49, 0x00, 0x00, 0x00,
+        0x91, 0x00, 0x00, 0x00, 0x02, 0x6e, 0x6d, 0x00, 0x00, 0x00, 0x91, 0x00,
+        0x00, 0x00, 0x03, 0x6f, 0x6b, 0x84, 0x00, 0x00, 0x00, 0x91, 0x00, 0x00,
+        0x00, 0x04, 0x65, 0x72, 0x72, 0x84, 0x00, 0x00, 0x00, 0x91, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
+
+    #[test]
+    fn test_debug_lib() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        open_libs(&mut *ls);
+        ls.requiref("debug", open_debug, true);
+        ls.pop(1);
+
+        ls.load(LUA_DEBUG_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 0, 0);
+        assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+    }
+
+    #[test]
+    fn test_debug_hooks() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        open_libs(&mut *ls);
+        ls.requiref("debug", open_debug, true);
+        ls.pop(1);
+
+        ls.load(LUA_HOOKS_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 0, 0);
+        assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+        assert!(ls.get_hook().is_none());
+    }
+}
@@ -0,0 +1,1320 @@
+use super::base::FILE_FUNCS;
+use crate::api::consts::*;
+use crate::api::{strerror, LuaAPI, LuaAuxLib, LuaUserdata, RustFn};
+use std::cell::RefCell;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::rc::Rc;
+
+const LUA_FILEHANDLE: &str = "FILE*";
+
+// registry keys of the default input and output files
+const IO_INPUT: &str = "_IO_input";
+const IO_OUTPUT: &str = "_IO_output";
+
+const BUFSIZ: usize = 8192;
+
+// maximum length of a numeral read by `read("n")`
+const L_MAXLENNUM: usize = 200;
+
+// maximum number of arguments to `f:lines`/`io.lines`
+const MAXARGLINE: isize = 250;
+
+const IO_FUNCS: &[(&str, RustFn)] = &[
+    ("close", io_close),
+    ("flush", io_flush),
+    ("input", io_input),
+    ("lines", io_lines),
+    ("open", io_open),
+    ("output", io_output),
+    ("read", io_read),
+    ("type", io_type),
+    ("write", io_write),
+];
+
+// methods for file handles
+const FILE_METHODS: &[(&str, RustFn)] = &[
+    ("close", io_close),
+    ("flush", f_flush),
+    ("lines", f_lines),
+    ("read", f_read),
+    ("seek", f_seek),
+    ("setvbuf", f_setvbuf),
+    ("write", f_write),
+    ("__tostring", f_tostring),
+];
+
+/*
+    The `io` library gives scripts access to the filesystem and to the
+    standard streams, so it is never opened implicitly: only states that
+    should have that capability call `requiref("io", open_io, true)`.
+    It also brings the global `dofile`, which the base library leaves out.
+*/
+pub fn open_io(ls: &mut dyn LuaAPI) -> usize {
+    ls.push_global_table();
+    ls.set_funcs(FILE_FUNCS, 0);
+    ls.pop(1);
+    ls.new_lib(IO_FUNCS); // new module
+    create_meta(ls);
+    // create (and set) default files
+    create_std_file(ls, Handle::Stdin(io::stdin()), Some(IO_INPUT), "stdin");
+    create_std_file(ls, Handle::Stdout(io::stdout()), Some(IO_OUTPUT), "stdout");
+    create_std_file(ls, Handle::Stderr(io::stderr()), None, "stderr");
+    1
+}
+
+fn create_meta(ls: &mut dyn LuaAPI) {
+    ls.new_metatable(LUA_FILEHANDLE); // create metatable for file handles
+    ls.push_value(-1); // push metatable
+    ls.set_field(-2, "__index"); // metatable.__index = metatable
+    ls.set_funcs(FILE_METHODS, 0); // add file methods to new metatable
+    ls.pop(1); // pop new metatable
+}
+
+// Standard files are not buffered here: Rust already buffers stdout,
+// and output then interleaves correctly with `print`.
+fn create_std_file(ls: &mut dyn LuaAPI, h: Handle, k: Option<&str>, fname: &str) {
+    new_file(ls, LStream::new(h, true, BufMode::No));
+    if let Some(k) = k {
+        ls.push_value(-1);
+        ls.set_field(LUA_REGISTRY_INDEX, k); // add file to registry
+    }
+    ls.set_field(-2, fname); // add file to module
+}
+
+fn new_file(ls: &mut dyn LuaAPI, p: LStream) {
+    ls.new_userdata(p);
+    ls.set_metatable_l(LUA_FILEHANDLE);
+}
+
+/*
+    Stream behind a Lua file handle. Reads and writes go through our own
+    buffers, so that `read("n")` can look one byte ahead and `setvbuf`
+    can control when output reaches the file.
+*/
+pub struct LStream {
+    handle: Option<Handle>, // None when the file is closed
+    std: bool,              // standard files cannot be closed
+    rbuf: Vec<u8>,          // read-ahead buffer
+    rpos: usize,            // next unread byte in `rbuf`
+    wbuf: Vec<u8>,          // pending output
+    vbuf: BufMode,
+    bufsize: usize,
+    err: Option<io::Error>, // last read error
+}
+
+enum Handle {
+    Stdin(io::Stdin),
+    Stdout(io::Stdout),
+    Stderr(io::Stderr),
+    File(File),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum BufMode {
+    No,
+    Full,
+    Line,
+}
+
+impl Handle {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Handle::Stdin(h) => h.read(buf),
+            Handle::File(f) => f.read(buf),
+            _ => Err(io::Error::from_raw_os_error(9)), // EBADF
+        }
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        match self {
+            Handle::Stdout(h) => h.write_all(buf),
+            Handle::Stderr(h) => h.write_all(buf),
+            Handle::File(f) => f.write_all(buf),
+            Handle::Stdin(_) => Err(io::Error::from_raw_os_error(9)), // EBADF
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Handle::Stdout(h) => h.flush(),
+            Handle::Stderr(h) => h.flush(),
+            Handle::File(f) => f.flush(),
+            Handle::Stdin(_) => Ok(()),
+        }
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match self {
+            Handle::File(f) => f.seek(pos),
+            _ => Err(io::Error::from_raw_os_error(29)), // ESPIPE
+        }
+    }
+}
+
+impl LStream {
+    fn new(h: Handle, std: bool, vbuf: BufMode) -> LStream {
+        LStream {
+            handle: Some(h),
+            std,
+            rbuf: Vec::new(),
+            rpos: 0,
+            wbuf: Vec::new(),
+            vbuf,
+            bufsize: BUFSIZ,
+            err: None,
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        self.handle.is_none()
+    }
+
+    fn handle(&mut self) -> &mut Handle {
+        self.handle.as_mut().unwrap()
+    }
+
+    // Make sure there is an unread byte in `rbuf`, false on end of file.
+    fn fill(&mut self) -> bool {
+        if self.rpos < self.rbuf.len() {
+            return true;
+        }
+        if let Err(e) = self.flush_wbuf() {
+            self.err = Some(e);
+            return false;
+        }
+        self.rbuf.resize(BUFSIZ, 0);
+        self.rpos = 0;
+        let h = self.handle.as_mut().unwrap();
+        let res = loop {
+            match h.read(&mut self.rbuf) {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                res => break res,
+            }
+        };
+        match res {
+            Ok(n) => self.rbuf.truncate(n),
+            Err(e) => {
+                self.rbuf.clear();
+                self.err = Some(e);
+            }
+        }
+        !self.rbuf.is_empty()
+    }
+
+    fn getc(&mut self) -> Option<u8> {
+        if !self.fill() {
+            return None;
+        }
+        self.rpos += 1;
+        Some(self.rbuf[self.rpos - 1])
+    }
+
+    // Push back the byte returned by the last `getc`.
+    fn ungetc(&mut self, c: Option<u8>) {
+        if c.is_some() {
+            self.rpos -= 1;
+        }
+    }
+
+    fn read_line(&mut self, chop: bool) -> (Vec<u8>, bool) {
+        let mut line = Vec::new();
+        while self.fill() {
+            let avail = &self.rbuf[self.rpos..];
+            if let Some(i) = avail.iter().position(|&c| c == b'\n') {
+                line.extend_from_slice(&avail[..i]);
+                if !chop {
+                    line.push(b'\n'); // keep newline
+                }
+                self.rpos += i + 1;
+                return (line, true);
+            }
+            line.extend_from_slice(avail);
+            self.rpos = self.rbuf.len();
+        }
+        (line, false)
+    }
+
+    fn read_chars(&mut self, n: usize) -> Vec<u8> {
+        let mut b = Vec::new();
+        while b.len() < n && self.fill() {
+            let k = (n - b.len()).min(self.rbuf.len() - self.rpos);
+            b.extend_from_slice(&self.rbuf[self.rpos..self.rpos + k]);
+            self.rpos += k;
+        }
+        b
+    }
+
+    fn read_all(&mut self) -> Vec<u8> {
+        let mut b = Vec::new();
+        while self.fill() {
+            b.extend_from_slice(&self.rbuf[self.rpos..]);
+            self.rpos = self.rbuf.len();
+        }
+        b
+    }
+
+    // Give back bytes read ahead but not consumed, so that the file
+    // position matches what the script has seen.
+    fn sync_read(&mut self) -> io::Result<()> {
+        let unread = (self.rbuf.len() - self.rpos) as i64;
+        self.rbuf.clear();
+        self.rpos = 0;
+        if unread > 0 {
+            if let Handle::File(f) = self.handle() {
+                f.seek(SeekFrom::Current(-unread))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn flush_wbuf(&mut self) -> io::Result<()> {
+        if !self.wbuf.is_empty() {
+            let wbuf = std::mem::take(&mut self.wbuf);
+            self.handle().write_all(&wbuf)?;
+        }
+        self.handle().flush()
+    }
+
+    fn write(&mut self, data: &[u8]) -> io::Result<()> {
+        self.sync_read()?;
+        match self.vbuf {
+            BufMode::No => {
+                self.handle().write_all(data)?;
+                self.handle().flush()
+            }
+            BufMode::Full => {
+                self.wbuf.extend_from_slice(data);
+                if self.wbuf.len() >= self.bufsize {
+                    self.flush_wbuf()?;
+                }
+                Ok(())
+            }
+            BufMode::Line => {
+                self.wbuf.extend_from_slice(data);
+                if data.contains(&b'\n') || self.wbuf.len() >= self.bufsize {
+                    self.flush_wbuf()?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.sync_read()?;
+        self.flush_wbuf()
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.flush_wbuf()?;
+        let unread = (self.rbuf.len() - self.rpos) as i64;
+        self.rbuf.clear();
+        self.rpos = 0;
+        let pos = match pos {
+            SeekFrom::Current(off) => SeekFrom::Current(off - unread),
+            pos => pos,
+        };
+        self.handle().seek(pos)
+    }
+
+    fn setvbuf(&mut self, mode: BufMode, size: usize) -> io::Result<()> {
+        self.flush()?;
+        self.vbuf = mode;
+        self.bufsize = size;
+        Ok(())
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        let res = self.flush_wbuf();
+        self.handle = None;
+        res
+    }
+}
+
+impl Drop for LStream {
+    fn drop(&mut self) {
+        if !self.is_closed() {
+            let _ = self.flush_wbuf(); // nowhere to report errors here
+        }
+    }
+}
+
+fn to_lstream(ls: &mut dyn LuaAPI) -> Rc<RefCell<LStream>> {
+    ls.check_userdata::<LStream>(1, LUA_FILEHANDLE)
+}
+
+// Check that the argument is an open file handle.
+fn to_file(ls: &mut dyn LuaAPI) -> Rc<RefCell<LStream>> {
+    let p = to_lstream(ls);
+    if p.borrow().is_closed() {
+        ls.error_l("attempt to use a closed file");
+    }
+    p
+}
+
+// Push the default input or output file and return it.
+fn get_io_file(ls: &mut dyn LuaAPI, findex: &str) -> Rc<RefCell<LStream>> {
+    ls.get_field(LUA_REGISTRY_INDEX, findex);
+    let p = ls.to_userdata::<LStream>(-1).unwrap();
+    if p.borrow().is_closed() {
+        let name = &findex["_IO_".len()..];
+        ls.error_l(&format!("standard {} file is closed", name));
+    }
+    p
+}
+
+// Check that `mode` matches '[rwa]%+?b*'.
+fn check_mode(mode: &str) -> bool {
+    let mode = mode.as_bytes();
+    match mode.first() {
+        Some(b'r') | Some(b'w') | Some(b'a') => {
+            let rest = &mode[1..];
+            let rest = rest.strip_prefix(b"+").unwrap_or(rest);
+            rest.iter().all(|&c| c == b'b')
+        }
+        _ => false,
+    }
+}
+
+fn open_file(fname: &str, mode: &str) -> io::Result<File> {
+    let plus = mode.contains('+');
+    let mut opts = OpenOptions::new();
+    match mode.as_bytes()[0] {
+        b'r' => opts.read(true).write(plus),
+        b'w' => opts.write(true).create(true).truncate(true).read(plus),
+        _ => opts.append(true).create(true).read(plus),
+    };
+    opts.open(fname)
+}
+
+// Open `fname`, raising an error on failure, and push its handle.
+fn open_check_file(ls: &mut dyn LuaAPI, fname: &str, mode: &str) {
+    match open_file(fname, mode) {
+        Ok(f) => new_file(ls, LStream::new(Handle::File(f), false, BufMode::Full)),
+        Err(e) => ls.error_l(&format!("cannot open file '{}' ({})", fname, strerror(&e))),
+    }
+}
+
+// io.open (filename [, mode])
+// http://www.lua.org/manual/5.3/manual.html#pdf-io.open
+fn io_open(ls: &mut dyn LuaAPI) -> usize {
+    let filename = ls.check_string(1);
+    let mode = ls.opt_string(2, "r");
+    ls.arg_check(check_mode(&mode), 2, "invalid mode");
+    match open_file(&filename, &mode) {
+        Ok(f) => {
+            new_file(ls, LStream::new(Handle::File(f), false, BufMode::Full));
+            1
+        }
+        Err(e) => ls.file_result(Err(e), Some(&filename)),
+    }
+}
+
+fn aux_close(ls: &mut dyn LuaAPI) -> usize {
+    let p = ls.to_userdata::<LStream>(1).unwrap();
+    let mut p = p.borrow_mut();
+    if p.std {
+        // keep standard files opened
+        ls.push_nil();
+        ls.push_string("cannot close standard file".to_string());
+        return 2;
+    }
+    let res = p.close();
+    drop(p);
+    ls.file_result(res, None)
+}
+
+// io.close ([file]), file:close ()
+// http://www.lua.org/manual/5.3/manual.html#pdf-io.close
+fn io_close(ls: &mut dyn LuaAPI) -> usize {
+    if ls.is_none(1) {
+        // no argument? use default output file
+        ls.get_field(LUA_REGISTRY_INDEX, IO_OUTPUT);
+    }
+    to_file(ls); // make sure argument is an open stream
+    aux_close(ls)
+}
+
+fn g_iofile(ls: &mut dyn LuaAPI, f: &str, mode: &str) -> usize {
+    if !ls.is_none_or_nil(1) {
+        if ls.type_id(1) == LUA_TSTRING || ls.type_id(1) == LUA_TNUMBER {
+            let filename = ls.to_string(1);
+            open_check_file(ls, &filename, mode);
+        } else {
+            to_file(ls); // check that it's a valid file handle
+            ls.push_value(1);
+        }
+        ls.set_field(LUA_REGISTRY_INDEX, f);
+    }
+    // return current value
+    ls.get_field(LUA_REGISTRY_INDEX, f);
+    1
+}
+
+// io.input ([file])
+// http://www.lua.org/manual/5.3/manual.html#pdf-io.input
+fn io_input(ls: &mut dyn LuaAPI) -> usize {
+    g_iofile(ls, IO_INPUT, "r")
+}
+
+// io.output ([file])
+// http://www.lua.org/manual/5.3/manual.html#pdf-io.output
+fn io_output(ls: &mut dyn LuaAPI) -> usize {
+    g_iofile(ls, IO_OUTPUT, "w")
+}
+
+// io.type (obj)
+// http://www.lua.org/manual/5.3/manual.html#pdf-io.type
+fn io_type(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_any(1);
+    match ls.test_udata(1, LUA_FILEHANDLE) {
+        None => ls.push_nil(), // not a file
+        Some(_) => {
+            let p = ls.to_userdata::<LStream>(1).unwrap();
+            let s = if p.borrow().is_closed() { "closed file" } else { "file" };
+            ls.push_string(s.to_string());
+        }
+    }
+    1
+}
+
+fn f_tostring(ls: &mut dyn LuaAPI) -> usize {
+    let p = to_lstream(ls);
+    let s = if p.borrow().is_closed() {
+        "file (closed)".to_string()
+    } else {
+        format!("file ({:p})", Rc::as_ptr(&p))
+    };
+    ls.push_string(s);
+    1
+}
+
+/*
+    Return an iteration function for `io.lines`. If file has to be
+    closed, sets upvalue 3 to true. Upvalues are the file, the number
+    of arguments to read, the close flag and the read formats.
+*/
+fn aux_lines(ls: &mut dyn LuaAPI, toclose: bool) {
+    let n = ls.get_top() - 1; // number of arguments to read
+    ls.arg_check(n <= MAXARGLINE, MAXARGLINE + 2, "too many arguments");
+    ls.push_integer(n as i64); // number of arguments to read
+    ls.push_boolean(toclose); // close/not close file when finished
+    ls.rotate(2, 2); // move 'n' and 'toclose' to their positions
+    ls.push_rust_closure(io_readline, 3 + n as usize);
+}
+
+// file:lines (···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-file:lines
+fn f_lines(ls: &mut dyn LuaAPI) -> usize {
+    to_file(ls); // check that it's a valid file handle
+    aux_lines(ls, false);
+    1
+}
+
+// io.lines ([filename, ···])
+// http://www.lua.org/manual/5.3/manual.html#pdf-io.lines
+fn io_lines(ls: &mut dyn LuaAPI) -> usize {
+    if ls.is_none(1) {
+        ls.push_nil(); // at least one argument
+    }
+    let toclose = if ls.is_nil(1) {
+        // no file name?
+        ls.get_field(LUA_REGISTRY_INDEX, IO_INPUT); // get default input
+        ls.replace(1); // put it at index 1
+        to_file(ls); // check that it's a valid file handle
+        false // do not close it after iteration
+    } else {
+        // open a new file
+        let filename = ls.check_string(1);
+        open_check_file(ls, &filename, "r");
+        ls.replace(1); // put file at index 1
+        true // close it after iteration
+    };
+    aux_lines(ls, toclose);
+    1
+}
+
+/*
+    READ
+*/
+
+// Accumulates the longest prefix of a numeral found in the stream.
+struct RN<'a> {
+    f: &'a mut LStream,
+    c: Option<u8>, // current character (look ahead)
+    buff: Vec<u8>, // up to L_MAXLENNUM characters
+}
+
+impl RN<'_> {
+    // Add current char to buffer (if not out of space) and read next one
+    fn nextc(&mut self) -> bool {
+        if self.buff.len() >= L_MAXLENNUM {
+            // buffer overflow?
+            self.buff.clear(); // invalidate result
+            false // fail
+        } else {
+            self.buff.push(self.c.unwrap_or(0)); // save current char
+            self.c = self.f.getc(); // read next one
+            true
+        }
+    }
+
+    // Accept current char if it is in `set` (of size 2)
+    fn test2(&mut self, set: &[u8; 2]) -> bool {
+        match self.c {
+            Some(c) if c == set[0] || c == set[1] => self.nextc(),
+            _ => false,
+        }
+    }
+
+    // Read a sequence of (hex)digits
+    fn read_digits(&mut self, hex: bool) -> usize {
+        let mut count = 0;
+        while let Some(c) = self.c {
+            let ok = if hex { c.is_ascii_hexdigit() } else { c.is_ascii_digit() };
+            if !(ok && self.nextc()) {
+                break;
+            }
+            count += 1;
+        }
+        count
+    }
+}
+
+// Read a number: first reads a valid prefix of a numeral into a buffer.
+// Then it calls `string_to_number` to check whether the format is correct
+// and to convert it to a Lua number.
+fn read_number(ls: &mut dyn LuaAPI, f: &mut LStream) -> bool {
+    let mut rn = RN {
+        f,
+        c: None,
+        buff: Vec::new(),
+    };
+    let mut count = 0;
+    let mut hex = false;
+    loop {
+        rn.c = rn.f.getc();
+        if !matches!(rn.c, Some(c) if c.is_ascii_whitespace() || c == 0x0b) {
+            break; // skip spaces
+        }
+    }
+    rn.test2(b"-+"); // optional signal
+    if rn.test2(b"00") {
+        if rn.test2(b"xX") {
+            hex = true; // numeral is hexadecimal
+        } else {
+            count = 1; // count initial '0' as a valid digit
+        }
+    }
+    count += rn.read_digits(hex); // integral part
+    if rn.test2(b"..") {
+        // decimal point?
+        count += rn.read_digits(hex); // fractional part
+    }
+    if count > 0 && rn.test2(if hex { b"pP" } else { b"eE" }) {
+        // exponent mark?
+        rn.test2(b"-+"); // exponent signal
+        rn.read_digits(false); // exponent digits
+    }
+    let c = rn.c;
+    rn.f.ungetc(c); // unread look-ahead char
+    let s = String::from_utf8_lossy(&rn.buff).into_owned();
+    if ls.string_to_number(&s) {
+        true // ok
+    } else {
+        // invalid format
+        ls.push_nil(); // "result" to be removed
+        false // read fails
+    }
+}
+
+fn test_eof(ls: &mut dyn LuaAPI, f: &mut LStream) -> bool {
+    let c = f.getc();
+    f.ungetc(c); // no-op when c is EOF
+    ls.push_string(String::new());
+    c.is_some()
+}
+
+fn read_line(ls: &mut dyn LuaAPI, f: &mut LStream, chop: bool) -> bool {
+    let (line, nl) = f.read_line(chop);
+    let ok = nl || !line.is_empty();
+    ls.push_bytes(line);
+    // return ok if read something (either a newline or something else)
+    ok
+}
+
+fn read_chars(ls: &mut dyn LuaAPI, f: &mut LStream, n: usize) -> bool {
+    let b = f.read_chars(n);
+    let ok = !b.is_empty();
+    ls.push_bytes(b);
+    ok // true iff read something
+}
+
+fn g_read(ls: &mut dyn LuaAPI, p: &Rc<RefCell<LStream>>, first: isize) -> usize {
+    let mut f = p.borrow_mut();
+    let mut nargs = ls.get_top() - 1;
+    f.err = None;
+    let mut n = first;
+    let success = if nargs == 0 {
+        // no arguments?
+        n = first + 1; // to return 1 result
+        read_line(ls, &mut f, true)
+    } else {
+        if !ls.check_stack(nargs as usize + LUA_MINSTACK) {
+            ls.error_l("stack overflow (too many arguments)");
+        }
+        let mut success = true;
+        while nargs > 0 && success {
+            nargs -= 1;
+            if ls.type_id(n) == LUA_TNUMBER {
+                let l = ls.check_integer(n) as usize;
+                success = if l == 0 { test_eof(ls, &mut f) } else { read_chars(ls, &mut f, l) };
+            } else {
+                let p = ls.check_string(n);
+                let p = p.strip_prefix('*').unwrap_or(&p); // skip optional '*' (for compatibility)
+                success = match p.as_bytes().first() {
+                    Some(b'n') => read_number(ls, &mut f), // number
+                    Some(b'l') => read_line(ls, &mut f, true), // line
+                    Some(b'L') => read_line(ls, &mut f, false), // line with end-of-line
+                    Some(b'a') => {
+                        // read entire file
+                        let b = f.read_all();
+                        ls.push_bytes(b);
+                        true // always success
+                    }
+                    _ => ls.arg_error(n, "invalid format"),
+                };
+            }
+            n += 1;
+        }
+        success
+    };
+    if let Some(e) = f.err.take() {
+        return ls.file_result(Err(e), None);
+    }
+    if !success {
+        ls.pop(1); // remove last result
+        ls.push_nil(); // push nil instead
+    }
+    (n - first) as usize
+}
+
+// io.read (···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-io.read
+fn io_read(ls: &mut dyn LuaAPI) -> usize {
+    let p = get_io_file(ls, IO_INPUT); // file stays on top, after the formats
+    g_read(ls, &p, 1)
+}
+
+// file:read (···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-file:read
+fn f_read(ls: &mut dyn LuaAPI) -> usize {
+    let p = to_file(ls);
+    g_read(ls, &p, 2)
+}
+
+fn io_readline(ls: &mut dyn LuaAPI) -> usize {
+    let p = ls.to_userdata::<LStream>(upvalue_index(1)).unwrap();
+    let n = ls.to_integer(upvalue_index(2));
+    if p.borrow().is_closed() {
+        // file is already closed?
+        ls.error_l("file is already closed");
+    }
+    ls.set_top(1);
+    if !ls.check_stack(n as usize) {
+        ls.error_l("stack overflow (too many arguments)");
+    }
+    for i in 1..=n {
+        // push arguments to 'g_read'
+        ls.push_value(upvalue_index(3 + i as isize));
+    }
+    let n = g_read(ls, &p, 2) as isize; // 'n' is number of results
+    if ls.to_boolean(-n) {
+        // read at least one value?
+        return n as usize; // return them
+    }
+    // first result is nil: EOF or error
+    if n > 1 {
+        // is there error information?
+        // 2nd result is error message
+        let msg = ls.to_string(-n + 1);
+        ls.error_l(&msg);
+    }
+    if ls.to_boolean(upvalue_index(3)) {
+        // generate error?
+        ls.set_top(0);
+        ls.push_value(upvalue_index(1));
+        aux_close(ls); // close it
+    }
+    0
+}
+
+/*
+    WRITE
+*/
+
+fn g_write(ls: &mut dyn LuaAPI, p: &Rc<RefCell<LStream>>, mut arg: isize) -> usize {
+    let nargs = ls.get_top() - arg;
+    let mut res = Ok(());
+    for _ in 0..nargs {
+        let b = if ls.type_id(arg) == LUA_TNUMBER {
+            ls.to_shared_bytes(arg).unwrap() // optimization: could be done exactly as for strings
+        } else {
+            ls.check_shared_bytes(arg)
+        };
+        if res.is_ok() {
+            res = p.borrow_mut().write(&b);
+        }
+        arg += 1;
+    }
+    match res {
+        Ok(()) => 1, // file handle already on stack top
+        Err(e) => ls.file_result(Err(e), None),
+    }
+}
+
+// io.write (···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-io.write
+fn io_write(ls: &mut dyn LuaAPI) -> usize {
+    let p = get_io_file(ls, IO_OUTPUT);
+    g_write(ls, &p, 1)
+}
+
+// file:write (···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-file:write
+fn f_write(ls: &mut dyn LuaAPI) -> usize {
+    let p = to_file(ls);
+    ls.push_value(1); // push file at the stack top (to be returned)
+    g_write(ls, &p, 2)
+}
+
+// file:seek ([whence [, offset]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-file:seek
+fn f_seek(ls: &mut dyn LuaAPI) -> usize {
+    let p = to_file(ls);
+    let op = ls.check_option(2, Some("cur"), &["set", "cur", "end"]);
+    let offset = ls.opt_integer(3, 0);
+    let pos = match op {
+        0 => {
+            ls.arg_check(offset >= 0, 3, "not an integer in proper range");
+            SeekFrom::Start(offset as u64)
+        }
+        1 => SeekFrom::Current(offset),
+        _ => SeekFrom::End(offset),
+    };
+    let res = p.borrow_mut().seek(pos);
+    match res {
+        Ok(pos) => {
+            ls.push_integer(pos as i64);
+            1
+        }
+        Err(e) => ls.file_result(Err(e), None), // error
+    }
+}
+
+// file:setvbuf (mode [, size])
+// http://www.lua.org/manual/5.3/manual.html#pdf-file:setvbuf
+fn f_setvbuf(ls: &mut dyn LuaAPI) -> usize {
+    const MODES: [BufMode; 3] = [BufMode::No, BufMode::Full, BufMode::Line];
+    let p = to_file(ls);
+    let op = ls.check_option(2, None, &["no", "full", "line"]);
+    let sz = ls.opt_integer(3, BUFSIZ as i64).max(1) as usize;
+    let res = p.borrow_mut().setvbuf(MODES[op], sz);
+    ls.file_result(res, None)
+}
+
+// io.flush ()
+// http://www.lua.org/manual/5.3/manual.html#pdf-io.flush
+fn io_flush(ls: &mut dyn LuaAPI) -> usize {
+    let p = get_io_file(ls, IO_OUTPUT);
+    let res = p.borrow_mut().flush();
+    ls.file_result(res, None)
+}
+
+// file:flush ()
+// http://www.lua.org/manual/5.3/manual.html#pdf-file:flush
+fn f_flush(ls: &mut dyn LuaAPI) -> usize {
+    let p = to_file(ls);
+    let res = p.borrow_mut().flush();
+    ls.file_result(res, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LuaState;
+    use crate::stdlib::open_libs;
+    use crate::stdlib::os::open_os;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /* Lua source code:
+        local name = os.tmpname()
+        assert(type(name) == "string" and io.open(name, "r")):close()
+        local f = assert(io.open(name, "w"))
+        assert(io.type(f) == "file" and io.type(42) == nil and tostring(f):match("^file %("))
+        assert(f:write("line one\n", 42, " ", 1.5, "\n", "0x1F -7 3e2 nan\n", "last") == f)
+        assert(f:setvbuf("no") and f:close() and io.type(f) == "closed file" and tostring(f) == "file (closed)")
+        assert(select(2, pcall(f.write, f, "x")) == "attempt to use a closed file")
+        f = assert(io.open(name))
+        assert(f:read() == "line one" and f:read("L") == "42 1.5\n")
+        local a, b, c, d = f:read("n", "n", "*n", "n")
+        assert(a == 31 and b == -7 and c == 300.0 and d == nil)
+        assert(f:read("l") == "nan" and f:read(2) == "la" and f:read(0) == "" and f:read("a") == "st")
+        assert(f:read("a") == "" and f:read() == nil and f:read(0) == nil)
+        assert(f:seek("set", 5) == 5 and f:read(3) == "one" and f:seek() == 8 and f:seek("end") == 36)
+        assert(select(2, pcall(io.read, "x")) == "bad argument #1 to 'io.read' (invalid format)")
+        f:close()
+        local t = {}
+        for l in io.lines(name) do t[#t + 1] = l end
+        assert(#t == 4 and t[2] == "42 1.5" and t[4] == "last")
+        t = {}
+        for n1, n2 in io.lines(name, 4, "l") do t[#t + 1] = n1 .. "|" .. tostring(n2) end
+        assert(#t == 4 and t[1] == "line| one" and t[2] == "42 1|.5" and t[4] == "last|nil")
+        f = assert(io.open(name, "a+"))
+        f:write("\nappended")
+        f:seek("set")
+        assert(f:read("a") == "line one\n42 1.5\n0x1F -7 3e2 nan\nlast\nappended")
+        f:close()
+        assert(io.input(name) and io.read() == "line one" and io.read("n") == 42)
+        io.input():close()
+        assert(select(2, pcall(io.read)) == "standard input file is closed")
+        local n, msg = io.open("/nonexistent/dir/file")
+        assert(n == nil and msg == "/nonexistent/dir/file: No such file or directory")
+        assert(select(2, pcall(io.lines, "/nonexistent")) == "cannot open file '/nonexistent' (No such file or directory)")
+        assert(select(2, pcall(io.open, name, "rw")) == "bad argument #2 to 'io.open' (invalid mode)")
+        local _, m2 = io.stdout:close()
+        assert(m2 == "cannot close standard file" and io.type(io.stderr) == "file")
+        assert(os.rename(name, name .. ".x") and not io.open(name) and os.remove(name .. ".x"))
+        local r, rmsg = os.remove(name)
+        assert(r == nil and rmsg == name .. ": No such file or directory")
+    */
+    const LUA_IO_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x07, 0x40,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x11, 0xbf, 0x01, 0x00, 0x00, 0x06, 0x00, 0x40, 0x00,
+        0x07, 0x40, 0x40, 0x00, 0x24, 0x80, 0x80, 0x00, 0x46, 0x80, 0x40, 0x00,
+        0x86, 0xc0, 0x40, 0x00, 0xc0, 0x00, 0x00, 0x00, 0xa4, 0x80, 0x00, 0x01,
+        0x1f, 0x00, 0x41, 0x01, 0x1e, 0x40, 0x01, 0x80, 0x86, 0x40, 0x41, 0x00,
+        0x87, 0x80, 0x41, 0x01, 0xc0, 0x00, 0x00, 0x00, 0x01, 0xc1, 0x01, 0x00,
+        0xa4, 0x80, 0x80, 0x01, 0x1e, 0x40, 0x00, 0x80, 0x83, 0x40, 0x00, 0x00,
+        0x83, 0x00, 0x80, 0x00, 0x64, 0x80, 0x00, 0x01, 0x4c, 0x00, 0xc2, 0x00,
+        0x64, 0x40, 0x00, 0x01, 0x46, 0x80, 0x40, 0x00, 0x86, 0x40, 0x41, 0x00,
+        0x87, 0x80, 0x41, 0x01, 0xc0, 0x00, 0x00, 0x00, 0x01, 0x41, 0x02, 0x00,
+        0xa4, 0x00, 0x80, 0x01, 0x64, 0x80, 0x00, 0x00, 0x86, 0x80, 0x40, 0x00,
+        0xc6, 0x40, 0x41, 0x00, 0xc7, 0xc0, 0xc0, 0x01, 0x00, 0x01, 0x80, 0x00,
+        0xe4, 0x80, 0x00, 0x01, 0x1f, 0x80, 0xc2, 0x01, 0x1e, 0x00, 0x03, 0x80,
+        0xc6, 0x40, 0x41, 0x00, 0xc7, 0xc0, 0xc0, 0x01, 0x01, 0xc1, 0x02, 0x00,
+        0xe4, 0x80, 0x00, 0x01, 0x1f, 0x00, 0xc3, 0x01, 0x1e, 0x80, 0x01, 0x80,
+        0xc6, 0x40, 0x43, 0x00, 0x00, 0x01, 0x80, 0x00, 0xe4, 0x80, 0x00, 0x01,
+        0xcc, 0x80, 0xc3, 0x01, 0x41, 0xc1, 0x03, 0x00, 0xe4, 0x80, 0x80, 0x01,
+        0x1e, 0x40, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0x80, 0x40, 0x00, 0xcc, 0x00, 0xc4, 0x00,
+        0x41, 0x41, 0x04, 0x00, 0x81, 0xc1, 0x02, 0x00, 0xc1, 0x81, 0x04, 0x00,
+        0x01, 0xc2, 0x04, 0x00, 0x41, 0x02, 0x05, 0x00, 0x81, 0x42, 0x05, 0x00,
+        0xc1, 0x82, 0x05, 0x00, 0xe4, 0x80, 0x80, 0x04, 0x5f, 0x40, 0x80, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0x80, 0x40, 0x00, 0xcc, 0xc0, 0xc5, 0x00,
+        0x41, 0x01, 0x06, 0x00, 0xe4, 0x80, 0x80, 0x01, 0xe2, 0x00, 0x00, 0x00,
+        0x1e, 0x00, 0x04, 0x80, 0xcc, 0x00, 0xc2, 0x00, 0xe4, 0x80, 0x00, 0x01,
+        0xe2, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x03, 0x80, 0xc6, 0x40, 0x41, 0x00,
+        0xc7, 0xc0, 0xc0, 0x01, 0x00, 0x01, 0x80, 0x00, 0xe4, 0x80, 0x00, 0x01,
+        0x1f, 0x40, 0xc6, 0x01, 0x1e, 0x00, 0x01, 0x80, 0xc6, 0x40, 0x43, 0x00,
+        0x00, 0x01, 0x80, 0x00, 0xe4, 0x80, 0x00, 0x01, 0x5f, 0x80, 0xc6, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0x80, 0x40, 0x00, 0xc6, 0xc0, 0x46, 0x00,
+        0x01, 0x01, 0x07, 0x00, 0x46, 0x41, 0x47, 0x00, 0x87, 0x01, 0xc4, 0x00,
+        0xc0, 0x01, 0x80, 0x00, 0x01, 0x82, 0x07, 0x00, 0x64, 0x01, 0x00, 0x02,
+        0xe4, 0x80, 0x00, 0x00, 0x5f, 0xc0, 0xc7, 0x01, 0x1e, 0x00, 0x00, 0x80,
+        0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00, 0xa4, 0x40, 0x00, 0x01,
+        0x86, 0x80, 0x40, 0x00, 0xc6, 0x40, 0x41, 0x00, 0xc7, 0x80, 0xc1, 0x01,
+        0x00, 0x01, 0x00, 0x00, 0xe4, 0x00, 0x00, 0x01, 0xa4, 0x80, 0x00, 0x00,
+        0x40, 0x00, 0x00, 0x01, 0x86, 0x80, 0x40, 0x00, 0xcc, 0x00, 0xc8, 0x00,
+        0xe4, 0x80, 0x00, 0x01, 0x1f, 0x40, 0xc8, 0x01, 0x1e, 0x00, 0x01, 0x80,
+        0xcc, 0x00, 0xc8, 0x00, 0x41, 0x81, 0x08, 0x00, 0xe4, 0x80, 0x80, 0x01,
+        0x5f, 0xc0, 0xc8, 0x01, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00,
+        0xc3, 0x00, 0x80, 0x00, 0xa4, 0x40, 0x00, 0x01, 0x8c, 0x00, 0xc8, 0x00,
+        0x01, 0x01, 0x09, 0x00, 0x41, 0x01, 0x09, 0x00, 0x81, 0x41, 0x09, 0x00,
+        0xc1, 0x01, 0x09, 0x00, 0xa4, 0x40, 0x01, 0x03, 0x86, 0x81, 0x40, 0x00,
+        0x1f, 0x80, 0x49, 0x01, 0x1e, 0x40, 0x01, 0x80, 0x1f, 0xc0, 0xc9, 0x01,
+        0x1e, 0xc0, 0x00, 0x80, 0x1f, 0x00, 0x4a, 0x02, 0x1e, 0x40, 0x00, 0x80,
+        0x5f, 0x00, 0xc3, 0x02, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00,
+        0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86, 0x81, 0x40, 0x00,
+        0xcc, 0x01, 0xc8, 0x00, 0x41, 0x42, 0x0a, 0x00, 0xe4, 0x81, 0x80, 0x01,
+        0x1f, 0x80, 0xca, 0x03, 0x1e, 0x80, 0x03, 0x80, 0xcc, 0x01, 0xc8, 0x00,
+        0x41, 0x02, 0x07, 0x00, 0xe4, 0x81, 0x80, 0x01, 0x1f, 0xc0, 0xca, 0x03,
+        0x1e, 0x40, 0x02, 0x80, 0xcc, 0x01, 0xc8, 0x00, 0x41, 0x02, 0x0b, 0x00,
+        0xe4, 0x81, 0x80, 0x01, 0x1f, 0x40, 0xcb, 0x03, 0x1e, 0x00, 0x01, 0x80,
+        0xcc, 0x01, 0xc8, 0x00, 0x41, 0x82, 0x0b, 0x00, 0xe4, 0x81, 0x80, 0x01,
+        0x5f, 0xc0, 0xcb, 0x03, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00,
+        0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86, 0x81, 0x40, 0x00,
+        0xcc, 0x01, 0xc8, 0x00, 0x41, 0x82, 0x0b, 0x00, 0xe4, 0x81, 0x80, 0x01,
+        0x1f, 0x40, 0xcb, 0x03, 0x1e, 0x00, 0x02, 0x80, 0xcc, 0x01, 0xc8, 0x00,
+        0xe4, 0x81, 0x00, 0x01, 0x1f, 0x00, 0xc3, 0x03, 0x1e, 0x00, 0x01, 0x80,
+        0xcc, 0x01, 0xc8, 0x00, 0x41, 0x02, 0x0b, 0x00, 0xe4, 0x81, 0x80, 0x01,
+        0x5f, 0x00, 0xc3, 0x03, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00,
+        0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86, 0x81, 0x40, 0x00,
+        0xcc, 0x01, 0xcc, 0x00, 0x41, 0x42, 0x0c, 0x00, 0x81, 0x82, 0x0c, 0x00,
+        0xe4, 0x81, 0x00, 0x02, 0x1f, 0x80, 0xcc, 0x03, 0x1e, 0x40, 0x03, 0x80,
+        0xcc, 0x01, 0xc8, 0x00, 0x41, 0xc2, 0x0c, 0x00, 0xe4, 0x81, 0x80, 0x01,
+        0x1f, 0x00, 0xcd, 0x03, 0x1e, 0x00, 0x02, 0x80, 0xcc, 0x01, 0xcc, 0x00,
+        0xe4, 0x81, 0x00, 0x01, 0x1f, 0x40, 0xcd, 0x03, 0x1e, 0x00, 0x01, 0x80,
+        0xcc, 0x01, 0xcc, 0x00, 0x41, 0x82, 0x0d, 0x00, 0xe4, 0x81, 0x80, 0x01,
+        0x5f, 0xc0, 0xcd, 0x03, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00,
+        0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86, 0x81, 0x40, 0x00,
+        0xc6, 0xc1, 0x46, 0x00, 0x01, 0x02, 0x07, 0x00, 0x46, 0x42, 0x47, 0x00,
+        0x86, 0x42, 0x41, 0x00, 0x87, 0x02, 0x48, 0x05, 0xc1, 0x82, 0x07, 0x00,
+        0x64, 0x02, 0x80, 0x01, 0xe4, 0x81, 0x00, 0x00, 0x5f, 0x00, 0xce, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00,
+        0xa4, 0x41, 0x00, 0x01, 0x8c, 0x01, 0xc2, 0x00, 0xa4, 0x41, 0x00, 0x01,
+        0x8b, 0x01, 0x00, 0x00, 0xc6, 0x41, 0x41, 0x00, 0xc7, 0x41, 0xce, 0x03,
+        0x00, 0x02, 0x00, 0x00, 0xe4, 0x01, 0x01, 0x01, 0x1e, 0x80, 0x00, 0x80,
+        0xdc, 0x02, 0x00, 0x03, 0xcd, 0x82, 0xce, 0x05, 0x8a, 0x81, 0x82, 0x05,
+        0xe9, 0x41, 0x00, 0x00, 0x6a, 0x82, 0xfe, 0x7f, 0xc6, 0x81, 0x40, 0x00,
+        0x1c, 0x02, 0x00, 0x03, 0x1f, 0xc0, 0x4e, 0x04, 0x1e, 0x40, 0x01, 0x80,
+        0x07, 0x02, 0x47, 0x03, 0x1f, 0x00, 0x4f, 0x04, 0x1e, 0x80, 0x00, 0x80,
+        0x07, 0xc2, 0x4e, 0x03, 0x5f, 0x80, 0x45, 0x04, 0x1e, 0x00, 0x00, 0x80,
+        0x03, 0x42, 0x00, 0x00, 0x03, 0x02, 0x80, 0x00, 0xe4, 0x41, 0x00, 0x01,
+        0xcb, 0x01, 0x00, 0x00, 0x80, 0x01, 0x80, 0x03, 0xc6, 0x41, 0x41, 0x00,
+        0xc7, 0x41, 0xce, 0x03, 0x00, 0x02, 0x00, 0x00, 0x41, 0xc2, 0x0e, 0x00,
+        0x81, 0x42, 0x0a, 0x00, 0xe4, 0x01, 0x01, 0x02, 0x1e, 0x00, 0x02, 0x80,
+        0x1c, 0x03, 0x00, 0x03, 0x0d, 0x83, 0x4e, 0x06, 0x40, 0x03, 0x00, 0x05,
+        0x81, 0x43, 0x0f, 0x00, 0xc6, 0x43, 0x43, 0x00, 0x00, 0x04, 0x80, 0x05,
+        0xe4, 0x83, 0x00, 0x01, 0x5d, 0xc3, 0x83, 0x06, 0x8a, 0x41, 0x03, 0x06,
+        0xe9, 0x81, 0x00, 0x00, 0x6a, 0x02, 0xfd, 0x7f, 0xc6, 0x81, 0x40, 0x00,
+        0x1c, 0x02, 0x00, 0x03, 0x1f, 0xc0, 0x4e, 0x04, 0x1e, 0x00, 0x02, 0x80,
+        0x07, 0x82, 0x4e, 0x03, 0x1f, 0x80, 0x4f, 0x04, 0x1e, 0x40, 0x01, 0x80,
+        0x07, 0x02, 0x47, 0x03, 0x1f, 0xc0, 0x4f, 0x04, 0x1e, 0x80, 0x00, 0x80,
+        0x07, 0xc2, 0x4e, 0x03, 0x5f, 0x00, 0x50, 0x04, 0x1e, 0x00, 0x00, 0x80,
+        0x03, 0x42, 0x00, 0x00, 0x03, 0x02, 0x80, 0x00, 0xe4, 0x41, 0x00, 0x01,
+        0xc6, 0x81, 0x40, 0x00, 0x06, 0x42, 0x41, 0x00, 0x07, 0x82, 0x41, 0x04,
+        0x40, 0x02, 0x00, 0x00, 0x81, 0x42, 0x10, 0x00, 0x24, 0x02, 0x80, 0x01,
+        0xe4, 0x81, 0x00, 0x00, 0x40, 0x00, 0x80, 0x03, 0xcc, 0x01, 0xc4, 0x00,
+        0x41, 0x82, 0x10, 0x00, 0xe4, 0x41, 0x80, 0x01, 0xcc, 0x01, 0xcc, 0x00,
+        0x41, 0x42, 0x0c, 0x00, 0xe4, 0x41, 0x80, 0x01, 0xc6, 0x81, 0x40, 0x00,
+        0x0c, 0x02, 0xc8, 0x00, 0x81, 0x82, 0x0b, 0x00, 0x24, 0x82, 0x80, 0x01,
+        0x5f, 0xc0, 0x50, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x42, 0x00, 0x00,
+        0x03, 0x02, 0x80, 0x00, 0xe4, 0x41, 0x00, 0x01, 0xcc, 0x01, 0xc2, 0x00,
+        0xe4, 0x41, 0x00, 0x01, 0xc6, 0x81, 0x40, 0x00, 0x06, 0x42, 0x41, 0x00,
+        0x07, 0x02, 0x51, 0x04, 0x40, 0x02, 0x00, 0x00, 0x24, 0x82, 0x00, 0x01,
+        0x22, 0x02, 0x00, 0x00, 0x1e, 0x00, 0x03, 0x80, 0x06, 0x42, 0x41, 0x00,
+        0x07, 0x02, 0x48, 0x04, 0x24, 0x82, 0x80, 0x00, 0x1f, 0x40, 0x48, 0x04,
+        0x1e, 0x40, 0x01, 0x80, 0x06, 0x42, 0x41, 0x00, 0x07, 0x02, 0x48, 0x04,
+        0x41, 0x02, 0x09, 0x00, 0x24, 0x82, 0x00, 0x01, 0x5f, 0xc0, 0x42, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x03, 0x42, 0x00, 0x00, 0x03, 0x02, 0x80, 0x00,
+        0xe4, 0x41, 0x00, 0x01, 0xc6, 0x41, 0x41, 0x00, 0xc7, 0x01, 0xd1, 0x03,
+        0xe4, 0x81, 0x80, 0x00, 0xcc, 0x01, 0xc2, 0x03, 0xe4, 0x41, 0x00, 0x01,
+        0xc6, 0x81, 0x40, 0x00, 0x06, 0xc2, 0x46, 0x00, 0x41, 0x02, 0x07, 0x00,
+        0x86, 0x42, 0x47, 0x00, 0xc6, 0x42, 0x41, 0x00, 0xc7, 0x02, 0xc8, 0x05,
+        0xa4, 0x02, 0x00, 0x01, 0x24, 0x82, 0x00, 0x00, 0x5f, 0x40, 0x51, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x03, 0x42, 0x00, 0x00, 0x03, 0x02, 0x80, 0x00,
+        0xe4, 0x41, 0x00, 0x01, 0xc6, 0x41, 0x41, 0x00, 0xc7, 0x81, 0xc1, 0x03,
+        0x01, 0x82, 0x11, 0x00, 0xe4, 0xc1, 0x00, 0x01, 0x46, 0x82, 0x40, 0x00,
+        0x1f, 0x00, 0xc3, 0x03, 0x1e, 0x40, 0x00, 0x80, 0x5f, 0xc0, 0x51, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0x64, 0x42, 0x00, 0x01, 0x46, 0x82, 0x40, 0x00, 0x86, 0xc2, 0x46, 0x00,
+        0xc1, 0x02, 0x07, 0x00, 0x06, 0x43, 0x47, 0x00, 0x46, 0x43, 0x41, 0x00,
+        0x47, 0x43, 0xce, 0x06, 0x81, 0x03, 0x12, 0x00, 0x24, 0x03, 0x80, 0x01,
+        0xa4, 0x82, 0x00, 0x00, 0x5f, 0x40, 0x52, 0x05, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01,
+        0x46, 0x82, 0x40, 0x00, 0x86, 0xc2, 0x46, 0x00, 0xc1, 0x02, 0x07, 0x00,
+        0x06, 0x43, 0x47, 0x00, 0x46, 0x43, 0x41, 0x00, 0x47, 0x83, 0xc1, 0x06,
+        0x80, 0x03, 0x00, 0x00, 0xc1, 0x83, 0x12, 0x00, 0x24, 0x03, 0x00, 0x02,
+        0xa4, 0x82, 0x00, 0x00, 0x5f, 0xc0, 0x52, 0x05, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01,
+        0x46, 0x42, 0x41, 0x00, 0x47, 0x02, 0xd3, 0x04, 0x4c, 0x02, 0xc2, 0x04,
+        0x64, 0xc2, 0x00, 0x01, 0xc6, 0x82, 0x40, 0x00, 0x1f, 0x40, 0x53, 0x05,
+        0x1e, 0x80, 0x01, 0x80, 0x06, 0x43, 0x41, 0x00, 0x07, 0xc3, 0x40, 0x06,
+        0x46, 0x43, 0x41, 0x00, 0x47, 0x83, 0xd3, 0x06, 0x24, 0x83, 0x00, 0x01,
+        0x5f, 0x80, 0x42, 0x06, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x43, 0x00, 0x00,
+        0x03, 0x03, 0x80, 0x00, 0xe4, 0x42, 0x00, 0x01, 0xc6, 0x82, 0x40, 0x00,
+        0x06, 0x03, 0x40, 0x00, 0x07, 0xc3, 0x53, 0x06, 0x40, 0x03, 0x00, 0x00,
+        0x80, 0x03, 0x00, 0x00, 0xc1, 0x03, 0x14, 0x00, 0x9d, 0xc3, 0x03, 0x07,
+        0x24, 0x83, 0x80, 0x01, 0x22, 0x03, 0x00, 0x00, 0x1e, 0x80, 0x03, 0x80,
+        0x06, 0x43, 0x41, 0x00, 0x07, 0x83, 0x41, 0x06, 0x40, 0x03, 0x00, 0x00,
+        0x24, 0x83, 0x00, 0x01, 0x22, 0x43, 0x00, 0x00, 0x1e, 0x80, 0x01, 0x80,
+        0x06, 0x03, 0x40, 0x00, 0x07, 0x43, 0x54, 0x06, 0x40, 0x03, 0x00, 0x00,
+        0x81, 0x03, 0x14, 0x00, 0x5d, 0x83, 0x83, 0x06, 0x24, 0x83, 0x00, 0x01,
+        0x1e, 0x40, 0x00, 0x80, 0x03, 0x43, 0x00, 0x00, 0x03, 0x03, 0x80, 0x00,
+        0xe4, 0x42, 0x00, 0x01, 0xc6, 0x02, 0x40, 0x00, 0xc7, 0x42, 0xd4, 0x05,
+        0x00, 0x03, 0x00, 0x00, 0xe4, 0xc2, 0x00, 0x01, 0x46, 0x83, 0x40, 0x00,
+        0x1f, 0x00, 0xc3, 0x05, 0x1e, 0x00, 0x01, 0x80, 0x80, 0x03, 0x00, 0x00,
+        0xc1, 0x83, 0x14, 0x00, 0x9d, 0xc3, 0x03, 0x07, 0x5f, 0x80, 0x03, 0x06,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x43, 0x00, 0x00, 0x83, 0x03, 0x80, 0x00,
+        0x64, 0x43, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x53, 0x00, 0x00, 0x00,
+        0x04, 0x03, 0x6f, 0x73, 0x04, 0x08, 0x74, 0x6d, 0x70, 0x6e, 0x61, 0x6d,
+        0x65, 0x04, 0x07, 0x61, 0x73, 0x73, 0x65, 0x72, 0x74, 0x04, 0x05, 0x74,
+        0x79, 0x70, 0x65, 0x04, 0x07, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x04,
+        0x03, 0x69, 0x6f, 0x04, 0x05, 0x6f, 0x70, 0x65, 0x6e, 0x04, 0x02, 0x72,
+        0x04, 0x06, 0x63, 0x6c, 0x6f, 0x73, 0x65, 0x04, 0x02, 0x77, 0x04, 0x05,
+        0x66, 0x69, 0x6c, 0x65, 0x13, 0x2a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x04, 0x09, 0x74, 0x6f, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67,
+        0x04, 0x06, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x04, 0x09, 0x5e, 0x66, 0x69,
+        0x6c, 0x65, 0x20, 0x25, 0x28, 0x04, 0x06, 0x77, 0x72, 0x69, 0x74, 0x65,
+        0x04, 0x0a, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x0a, 0x04,
+        0x02, 0x20, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x3f, 0x04,
+        0x02, 0x0a, 0x04, 0x11, 0x30, 0x78, 0x31, 0x46, 0x20, 0x2d, 0x37, 0x20,
+        0x33, 0x65, 0x32, 0x20, 0x6e, 0x61, 0x6e, 0x0a, 0x04, 0x05, 0x6c, 0x61,
+        0x73, 0x74, 0x04, 0x08, 0x73, 0x65, 0x74, 0x76, 0x62, 0x75, 0x66, 0x04,
+        0x03, 0x6e, 0x6f, 0x04, 0x0c, 0x63, 0x6c, 0x6f, 0x73, 0x65, 0x64, 0x20,
+        0x66, 0x69, 0x6c, 0x65, 0x04, 0x0e, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x28,
+        0x63, 0x6c, 0x6f, 0x73, 0x65, 0x64, 0x29, 0x04, 0x07, 0x73, 0x65, 0x6c,
+        0x65, 0x63, 0x74, 0x13, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x04, 0x06, 0x70, 0x63, 0x61, 0x6c, 0x6c, 0x04, 0x02, 0x78, 0x04, 0x1d,
+        0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x75,
+        0x73, 0x65, 0x20, 0x61, 0x20, 0x63, 0x6c, 0x6f, 0x73, 0x65, 0x64, 0x20,
+        0x66, 0x69, 0x6c, 0x65, 0x04, 0x05, 0x72, 0x65, 0x61, 0x64, 0x04, 0x09,
+        0x6c, 0x69, 0x6e, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x04, 0x02, 0x4c, 0x04,
+        0x08, 0x34, 0x32, 0x20, 0x31, 0x2e, 0x35, 0x0a, 0x04, 0x02, 0x6e, 0x04,
+        0x03, 0x2a, 0x6e, 0x13, 0x1f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x13, 0xf9, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x03, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0xc0, 0x72, 0x40, 0x04, 0x02, 0x6c, 0x04, 0x04, 0x6e,
+        0x61, 0x6e, 0x04, 0x03, 0x6c, 0x61, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x01, 0x04, 0x02, 0x61, 0x04, 0x03, 0x73, 0x74,
+        0x04, 0x05, 0x73, 0x65, 0x65, 0x6b, 0x04, 0x04, 0x73, 0x65, 0x74, 0x13,
+        0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x03, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x04, 0x6f, 0x6e, 0x65, 0x13, 0x08,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x04, 0x65, 0x6e, 0x64,
+        0x13, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x2e, 0x62,
+        0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20,
+        0x23, 0x31, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x69, 0x6f, 0x2e, 0x72, 0x65,
+        0x61, 0x64, 0x27, 0x20, 0x28, 0x69, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64,
+        0x20, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x29, 0x04, 0x06, 0x6c, 0x69,
+        0x6e, 0x65, 0x73, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x13, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x07, 0x34,
+        0x32, 0x20, 0x31, 0x2e, 0x35, 0x04, 0x02, 0x7c, 0x04, 0x0a, 0x6c, 0x69,
+        0x6e, 0x65, 0x7c, 0x20, 0x6f, 0x6e, 0x65, 0x04, 0x08, 0x34, 0x32, 0x20,
+        0x31, 0x7c, 0x2e, 0x35, 0x04, 0x09, 0x6c, 0x61, 0x73, 0x74, 0x7c, 0x6e,
+        0x69, 0x6c, 0x04, 0x03, 0x61, 0x2b, 0x04, 0x0a, 0x0a, 0x61, 0x70, 0x70,
+        0x65, 0x6e, 0x64, 0x65, 0x64, 0x14, 0x2e, 0x6c, 0x69, 0x6e, 0x65, 0x20,
+        0x6f, 0x6e, 0x65, 0x0a, 0x34, 0x32, 0x20, 0x31, 0x2e, 0x35, 0x0a, 0x30,
+        0x78, 0x31, 0x46, 0x20, 0x2d, 0x37, 0x20, 0x33, 0x65, 0x32, 0x20, 0x6e,
+        0x61, 0x6e, 0x0a, 0x6c, 0x61, 0x73, 0x74, 0x0a, 0x61, 0x70, 0x70, 0x65,
+        0x6e, 0x64, 0x65, 0x64, 0x04, 0x06, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x04,
+        0x1e, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x69, 0x6e,
+        0x70, 0x75, 0x74, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x69, 0x73, 0x20,
+        0x63, 0x6c, 0x6f, 0x73, 0x65, 0x64, 0x04, 0x16, 0x2f, 0x6e, 0x6f, 0x6e,
+        0x65, 0x78, 0x69, 0x73, 0x74, 0x65, 0x6e, 0x74, 0x2f, 0x64, 0x69, 0x72,
+        0x2f, 0x66, 0x69, 0x6c, 0x65, 0x14, 0x31, 0x2f, 0x6e, 0x6f, 0x6e, 0x65,
+        0x78, 0x69, 0x73, 0x74, 0x65, 0x6e, 0x74, 0x2f, 0x64, 0x69, 0x72, 0x2f,
+        0x66, 0x69, 0x6c, 0x65, 0x3a, 0x20, 0x4e, 0x6f, 0x20, 0x73, 0x75, 0x63,
+        0x68, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x64, 0x69,
+        0x72, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x79, 0x04, 0x0d, 0x2f, 0x6e, 0x6f,
+        0x6e, 0x65, 0x78, 0x69, 0x73, 0x74, 0x65, 0x6e, 0x74, 0x14, 0x3c, 0x63,
+        0x61, 0x6e, 0x6e, 0x6f, 0x74, 0x20, 0x6f, 0x70, 0x65, 0x6e, 0x20, 0x66,
+        0x69, 0x6c, 0x65, 0x20, 0x27, 0x2f, 0x6e, 0x6f, 0x6e, 0x65, 0x78, 0x69,
+        0x73, 0x74, 0x65, 0x6e, 0x74, 0x27, 0x20, 0x28, 0x4e, 0x6f, 0x20, 0x73,
+        0x75, 0x63, 0x68, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x6f, 0x72, 0x20,
+        0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x79, 0x29, 0x04, 0x03,
+        0x72, 0x77, 0x14, 0x2c, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75,
+        0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x32, 0x20, 0x74, 0x6f, 0x20, 0x27,
+        0x69, 0x6f, 0x2e, 0x6f, 0x70, 0x65, 0x6e, 0x27, 0x20, 0x28, 0x69, 0x6e,
+        0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x6d, 0x6f, 0x64, 0x65, 0x29, 0x04,
+        0x07, 0x73, 0x74, 0x64, 0x6f, 0x75, 0x74, 0x04, 0x1b, 0x63, 0x61, 0x6e,
+        0x6e, 0x6f, 0x74, 0x20, 0x63, 0x6c, 0x6f, 0x73, 0x65, 0x20, 0x73, 0x74,
+        0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x04,
+        0x07, 0x73, 0x74, 0x64, 0x65, 0x72, 0x72, 0x04, 0x07, 0x72, 0x65, 0x6e,
+        0x61, 0x6d, 0x65, 0x04, 0x03, 0x2e, 0x78, 0x04, 0x07, 0x72, 0x65, 0x6d,
+        0x6f, 0x76, 0x65, 0x04, 0x1c, 0x3a, 0x20, 0x4e, 0x6f, 0x20, 0x73, 0x75,
+        0x63, 0x68, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x6f, 0x72, 0x20, 0x64,
+        0x69, 0x72, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x79, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xbf, 0x01, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x18, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x19, 0x00,
+        0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1b, 0x00,
+        0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00,
+        0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x20, 0x00,
+        0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00,
+        0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00,
+        0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00,
+        0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00,
+        0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00,
+        0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00,
+        0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00,
+        0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00,
+        0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00,
+        0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00,
+        0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00,
+        0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00,
+        0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00,
+        0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00,
+        0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00,
+        0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00,
+        0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00,
+        0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00,
+        0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x27, 0x00,
+        0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00,
+        0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00,
+        0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00,
+        0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x05, 0x6e, 0x61, 0x6d, 0x65, 0x03, 0x00, 0x00, 0x00, 0xbf,
+        0x01, 0x00, 0x00, 0x02, 0x66, 0x1b, 0x00, 0x00, 0x00, 0xbf, 0x01, 0x00,
+        0x00, 0x02, 0x61, 0x81, 0x00, 0x00, 0x00, 0xbf, 0x01, 0x00, 0x00, 0x02,
+        0x62, 0x81, 0x00, 0x00, 0x00, 0xbf, 0x01, 0x00, 0x00, 0x02, 0x63, 0x81,
+        0x00, 0x00, 0x00, 0xbf, 0x01, 0x00, 0x00, 0x02, 0x64, 0x81, 0x00, 0x00,
+        0x00, 0xbf, 0x01, 0x00, 0x00, 0x02, 0x74, 0xe0, 0x00, 0x00, 0x00, 0xbf,
+        0x01, 0x00, 0x00, 0x10, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e,
+        0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x29, 0xe4, 0x00, 0x00, 0x00, 0xea,
+        0x00, 0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74, 0x61,
+        0x74, 0x65, 0x29, 0xe4, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x0e,
+        0x28, 0x66, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c,
+        0x29, 0xe4, 0x00, 0x00, 0x00, 0xea, 0x00, 0x00, 0x00, 0x02, 0x6c, 0xe5,
+        0x00, 0x00, 0x00, 0xe8, 0x00, 0x00, 0x00, 0x10, 0x28, 0x66, 0x6f, 0x72,
+        0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x29, 0xff,
+        0x00, 0x00, 0x00, 0x0b, 0x01, 0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72,
+        0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x29, 0xff, 0x00, 0x00, 0x00, 0x0b,
+        0x01, 0x00, 0x00, 0x0e, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e,
+        0x74, 0x72, 0x6f, 0x6c, 0x29, 0xff, 0x00, 0x00, 0x00, 0x0b, 0x01, 0x00,
+        0x00, 0x03, 0x6e, 0x31, 0x00, 0x01, 0x00, 0x00, 0x09, 0x01, 0x00, 0x00,
+        0x03, 0x6e, 0x32, 0x00, 0x01, 0x00, 0x00, 0x09, 0x01, 0x00, 0x00, 0x02,
+        0x6e, 0x5f, 0x01, 0x00, 0x00, 0xbf, 0x01, 0x00, 0x00, 0x04, 0x6d, 0x73,
+        0x67, 0x5f, 0x01, 0x00, 0x00, 0xbf, 0x01, 0x00, 0x00, 0x02, 0x5f, 0x88,
+        0x01, 0x00, 0x00, 0xbf, 0x01, 0x00, 0x00, 0x03, 0x6d, 0x32, 0x88, 0x01,
+        0x00, 0x00, 0xbf, 0x01, 0x00, 0x00, 0x02, 0x72, 0xb3, 0x01, 0x00, 0x00,
+        0xbf, 0x01, 0x00, 0x00, 0x05, 0x72, 0x6d, 0x73, 0x67, 0xb3, 0x01, 0x00,
+        0x00, 0xbf, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45,
+        0x4e, 0x56,
+    ];
+
+    #[test]
+    fn test_io_lib() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        open_libs(&mut *ls);
+        assert_eq!(ls.get_global("dofile"), LUA_TNIL);
+        ls.pop(1);
+        ls.requiref("io", open_io, true);
+        ls.requiref("os", open_os, true);
+        ls.pop(2);
+        assert_eq!(ls.get_global("dofile"), LUA_TFUNCTION);
+        ls.pop(1);
+
+        ls.load(LUA_IO_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 0, 0);
+        assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+    }
+}
@@ -0,0 +1,930 @@
+use crate::api::consts::*;
+use crate::api::{LuaAPI, LuaAuxLib, RustFn};
+use std::fmt::Write;
+
+// Nesting allowed by default, and at most, in values to encode or decode.
+const MAX_DEPTH: i64 = 128;
+const MAX_DEPTH_LIMIT: i64 = 1000;
+
+const JSON_FUNCS: &[(&str, RustFn)] = &[("encode", json_encode), ("decode", json_decode)];
+
+/*
+    JSON for scripts:
+        json.encode(value [, maxdepth]) -> string
+        json.decode(s [, maxdepth]) -> value
+    A table is an array when its keys are exactly 1..#t, and an object
+    otherwise (so `{}` is "{}"), with its number keys written as strings.
+    Integers and floats stay apart both ways: 1 is "1" and 1.0 is "1.0".
+    `json.null` stands for null, which nil cannot be inside a table.
+*/
+pub fn open_json(ls: &mut dyn LuaAPI) -> usize {
+    ls.new_lib(JSON_FUNCS);
+    push_null(ls);
+    ls.set_field(-2, "null");
+    1
+}
+
+fn push_null(ls: &mut dyn LuaAPI) {
+    ls.push_light_userdata(std::ptr::null_mut());
+}
+
+fn opt_depth(ls: &mut dyn LuaAPI, arg: isize) -> usize {
+    let depth = ls.opt_integer(arg, MAX_DEPTH);
+    ls.arg_check(0 < depth && depth <= MAX_DEPTH_LIMIT, arg, "depth out of range");
+    depth as usize
+}
+
+/* encode */
+
+// json.encode (value [, maxdepth])
+fn json_encode(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_any(1);
+    let mut enc = Encoder {
+        max_depth: opt_depth(ls, 2),
+        path: Vec::new(),
+        out: String::new(),
+    };
+    ls.set_top(1);
+    match enc.encode(ls, 1) {
+        Ok(()) => ls.push_string(enc.out),
+        Err(msg) => ls.error_l(&msg),
+    }
+    1
+}
+
+struct Encoder {
+    max_depth: usize,
+    path: Vec<String>, // keys down to the value being encoded
+    out: String,
+}
+
+impl Encoder {
+    // `msg` with the path of the value, like "cannot encode a function at 'a.b[2]'".
+    fn error(&self, msg: &str) -> String {
+        if self.path.is_empty() {
+            msg.to_string()
+        } else {
+            format!("{} at '{}'", msg, self.path.concat())
+        }
+    }
+
+    fn encode(&mut self, ls: &mut dyn LuaAPI, idx: isize) -> Result<(), String> {
+        match ls.type_id(idx) {
+            LUA_TNIL => self.out.push_str("null"),
+            LUA_TBOOLEAN => self.out.push_str(if ls.to_boolean(idx) { "true" } else { "false" }),
+            LUA_TNUMBER if ls.is_integer(idx) => write!(self.out, "{}", ls.to_integer(idx)).unwrap(),
+            LUA_TNUMBER => {
+                let n = ls.to_number(idx);
+                if !n.is_finite() {
+                    return Err(self.error(&format!("cannot encode the number {}", ls.to_string(idx))));
+                }
+                write!(self.out, "{:?}", n).unwrap(); // shortest exact form, keeps ".0"
+            }
+            LUA_TSTRING => {
+                let s = ls.to_shared_bytes(idx).unwrap();
+                match std::str::from_utf8(&s) {
+                    Ok(s) => encode_string(&mut self.out, s),
+                    Err(_) => return Err(self.error("cannot encode a string that is not UTF-8")),
+                }
+            }
+            LUA_TLIGHTUSERDATA if ls.to_light_userdata(idx) == Some(std::ptr::null_mut()) => {
+                self.out.push_str("null"); // json.null
+            }
+            LUA_TTABLE => self.encode_table(ls, idx)?,
+            tt => return Err(self.error(&format!("cannot encode a {}", ls.type_name(tt)))),
+        }
+        Ok(())
+    }
+
+    fn encode_table(&mut self, ls: &mut dyn LuaAPI, idx: isize) -> Result<(), String> {
+        let idx = ls.abs_index(idx);
+        if self.path.len() >= self.max_depth {
+            return Err(self.error(&format!("nesting deeper than {}", self.max_depth)));
+        }
+        if !ls.check_stack(3) {
+            return Err(self.error("stack overflow"));
+        }
+        let n = ls.raw_len(idx);
+        if n > 0 && count_pairs(ls, idx) == n {
+            self.out.push('[');
+            for i in 1..=n {
+                if i > 1 {
+                    self.out.push(',');
+                }
+                ls.raw_get_i(idx, i as i64);
+                self.path.push(format!("[{}]", i));
+                self.encode(ls, -1)?;
+                self.path.pop();
+                ls.pop(1);
+            }
+            self.out.push(']');
+            return Ok(());
+        }
+        self.out.push('{');
+        let mut first = true;
+        ls.push_nil();
+        while ls.next(idx) {
+            let key = match ls.type_id(-2) {
+                LUA_TSTRING | LUA_TNUMBER => String::from_utf8(ls.to_bytes(-2).unwrap()),
+                tt => return Err(self.error(&format!("cannot encode a {} key", ls.type_name(tt)))),
+            };
+            let key = key.map_err(|_| self.error("cannot encode a key that is not UTF-8"))?;
+            if !first {
+                self.out.push(',');
+            }
+            first = false;
+            encode_string(&mut self.out, &key);
+            self.out.push(':');
+            self.path.push(path_segment(&key, self.path.is_empty()));
+            self.encode(ls, -1)?;
+            self.path.pop();
+            ls.pop(1); // keep the key for `next`
+        }
+        self.out.push('}');
+        Ok(())
+    }
+}
+
+fn count_pairs(ls: &mut dyn LuaAPI, idx: isize) -> usize {
+    let mut n = 0;
+    ls.push_nil();
+    while ls.next(idx) {
+        ls.pop(1);
+        n += 1;
+    }
+    n
+}
+
+// A key in a path, `.name` for identifiers and `["key"]` otherwise.
+fn path_segment(key: &str, first: bool) -> String {
+    let mut chars = key.chars();
+    let is_name = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    match (is_name, first) {
+        (true, true) => key.to_string(),
+        (true, false) => format!(".{}", key),
+        _ => format!("[{:?}]", key),
+    }
+}
+
+fn encode_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/* decode */
+
+// json.decode (s [, maxdepth])
+fn json_decode(ls: &mut dyn LuaAPI) -> usize {
+    let s = ls.check_shared_bytes(1);
+    let mut dec = Decoder {
+        s: &s,
+        pos: 0,
+        depth: 0,
+        max_depth: opt_depth(ls, 2),
+    };
+    let top = ls.get_top();
+    let r = dec.decode(ls).and_then(|()| {
+        dec.skip_space();
+        match dec.peek() {
+            None => Ok(()),
+            Some(_) => Err(dec.error("unexpected character")),
+        }
+    });
+    if let Err(msg) = r {
+        ls.set_top(top);
+        ls.error_l(&msg);
+    }
+    1
+}
+
+struct Decoder<'a> {
+    s: &'a [u8],
+    pos: usize,
+    depth: usize,
+    max_depth: usize,
+}
+
+impl<'a> Decoder<'a> {
+    // `msg` with the byte position, 1-based as string indices are.
+    fn error(&self, msg: &str) -> String {
+        match self.s.get(self.pos) {
+            None => format!("unexpected end of JSON at byte {}", self.pos + 1),
+            Some(&c) if msg == "unexpected character" && c.is_ascii_graphic() => {
+                format!("unexpected character '{}' at byte {}", c as char, self.pos + 1)
+            }
+            Some(_) => format!("{} at byte {}", msg, self.pos + 1),
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+
+    fn skip_space(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), String> {
+        self.skip_space();
+        if self.peek() != Some(c) {
+            return Err(self.error("unexpected character"));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn literal(&mut self, word: &str) -> Result<(), String> {
+        if !self.s[self.pos..].starts_with(word.as_bytes()) {
+            return Err(self.error("unexpected character"));
+        }
+        self.pos += word.len();
+        Ok(())
+    }
+
+    // Push the value starting at `pos`.
+    fn decode(&mut self, ls: &mut dyn LuaAPI) -> Result<(), String> {
+        self.skip_space();
+        match self.peek() {
+            Some(b'{') | Some(b'[') => return self.decode_container(ls),
+            Some(b'"') => {
+                let s = self.decode_string()?;
+                ls.push_string(s);
+            }
+            Some(b't') => {
+                self.literal("true")?;
+                ls.push_boolean(true);
+            }
+            Some(b'f') => {
+                self.literal("false")?;
+                ls.push_boolean(false);
+            }
+            Some(b'n') => {
+                self.literal("null")?;
+                push_null(ls);
+            }
+            Some(b'-') | Some(b'0'..=b'9') => self.decode_number(ls)?,
+            _ => return Err(self.error("unexpected character")),
+        }
+        Ok(())
+    }
+
+    fn decode_container(&mut self, ls: &mut dyn LuaAPI) -> Result<(), String> {
+        if self.depth >= self.max_depth {
+            return Err(self.error(&format!("nesting deeper than {}", self.max_depth)));
+        }
+        if !ls.check_stack(3) {
+            return Err(self.error("stack overflow"));
+        }
+        self.depth += 1;
+        let array = self.peek() == Some(b'[');
+        let close = if array { b']' } else { b'}' };
+        self.pos += 1;
+        ls.new_table();
+        self.skip_space();
+        if self.peek() == Some(close) {
+            self.pos += 1;
+        } else {
+            let mut n = 0;
+            loop {
+                if array {
+                    self.decode(ls)?;
+                    n += 1;
+                    ls.raw_set_i(-2, n);
+                } else {
+                    self.skip_space();
+                    if self.peek() != Some(b'"') {
+                        return Err(self.error("unexpected character"));
+                    }
+                    let key = self.decode_string()?;
+                    ls.push_string(key);
+                    self.expect(b':')?;
+                    self.decode(ls)?;
+                    ls.raw_set(-3);
+                }
+                self.skip_space();
+                match self.peek() {
+                    Some(b',') => self.pos += 1,
+                    Some(c) if c == close => {
+                        self.pos += 1;
+                        break;
+                    }
+                    _ => return Err(self.error("unexpected character")),
+                }
+            }
+        }
+        self.depth -= 1;
+        Ok(())
+    }
+
+    fn decode_number(&mut self, ls: &mut dyn LuaAPI) -> Result<(), String> {
+        let start = self.pos;
+        let digits = |d: &mut Decoder| {
+            let from = d.pos;
+            while let Some(b'0'..=b'9') = d.peek() {
+                d.pos += 1;
+            }
+            d.pos > from
+        };
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        let int_start = self.pos;
+        if !digits(self) {
+            return Err(self.error("unexpected character"));
+        }
+        if self.s[int_start] == b'0' && self.pos - int_start > 1 {
+            self.pos = int_start + 1;
+            return Err(self.error("unexpected character")); // leading zero
+        }
+        let mut float = false;
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            float = true;
+            if !digits(self) {
+                return Err(self.error("unexpected character"));
+            }
+        }
+        if let Some(b'e') | Some(b'E') = self.peek() {
+            self.pos += 1;
+            float = true;
+            if let Some(b'+') | Some(b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if !digits(self) {
+                return Err(self.error("unexpected character"));
+            }
+        }
+        let text = std::str::from_utf8(&self.s[start..self.pos]).unwrap();
+        match text.parse::<i64>() {
+            Ok(i) if !float => ls.push_integer(i),
+            _ => ls.push_number(text.parse::<f64>().unwrap()), // floats and too large integers
+        }
+        Ok(())
+    }
+
+    fn decode_string(&mut self) -> Result<String, String> {
+        self.pos += 1; // skip '"'
+        let mut buf = Vec::new();
+        loop {
+            match self.peek() {
+                None => return Err(self.error("unfinished string")),
+                Some(b'"') => break,
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let c = match self.peek() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            let c = self.decode_escape()?;
+                            let mut utf8 = [0; 4];
+                            buf.extend_from_slice(c.encode_utf8(&mut utf8).as_bytes());
+                            continue;
+                        }
+                        _ => return Err(self.error("invalid escape sequence")),
+                    };
+                    buf.push(c as u8);
+                    self.pos += 1;
+                }
+                Some(c) if c < 0x20 => return Err(self.error("control character in string")),
+                Some(c) => {
+                    buf.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+        let start = self.pos;
+        self.pos += 1;
+        String::from_utf8(buf).map_err(|_| {
+            let pos = self.pos;
+            self.pos = start;
+            let msg = self.error("invalid UTF-8 in string");
+            self.pos = pos;
+            msg
+        })
+    }
+
+    // The character of `\uXXXX`, or of a surrogate pair `\uD83D\uDE00`.
+    // `pos` is on the 'u' and is left after the escape.
+    fn decode_escape(&mut self) -> Result<char, String> {
+        let start = self.pos - 1;
+        let hi = self.hex4()?;
+        let code = if (0xD800..0xDC00).contains(&hi) && self.s[self.pos..].starts_with(b"\\u") {
+            self.pos += 1;
+            let lo = self.hex4()?;
+            if !(0xDC00..0xE000).contains(&lo) {
+                self.pos = start;
+                return Err(self.error("invalid surrogate pair"));
+            }
+            0x10000 + ((hi - 0xD800) << 10) + (lo - 0xDC00)
+        } else {
+            hi
+        };
+        std::char::from_u32(code).ok_or_else(|| {
+            self.pos = start;
+            self.error("invalid unicode escape")
+        })
+    }
+
+    // The 4 hex digits after the 'u' at `pos`.
+    fn hex4(&mut self) -> Result<u32, String> {
+        self.pos += 1;
+        let digits = self.s.get(self.pos..self.pos + 4).and_then(|h| std::str::from_utf8(h).ok());
+        match digits.and_then(|h| u32::from_str_radix(h, 16).ok()) {
+            Some(code) if digits.unwrap().bytes().all(|c| c.is_ascii_hexdigit()) => {
+                self.pos += 4;
+                Ok(code)
+            }
+            _ => Err(self.error("invalid unicode escape")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::consts::*;
+    use crate::api::LuaAPI;
+    use crate::Lua;
+
+    #[test]
+    fn test_json() {
+        let lua = Lua::new();
+        let mut ls = lua.state();
+        ls.load(LUA_JSON_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 1, 0);
+        assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+        assert!(ls.to_boolean(-1));
+    }
+
+    /* Lua source code:
+        -- encoding: arrays by the border, integers apart from floats
+        assert(json.encode({1, 2.0, "x", true}) == '[1,2.0,"x",true]')
+        assert(json.encode({}) == "{}")
+        assert(json.encode({a = json.null}) == '{"a":null}')
+        assert(json.encode({[1] = 1, [3] = 3}) == '{"1":1,"3":3}' or json.encode({[1] = 1, [3] = 3}) == '{"3":3,"1":1}')
+        assert(json.encode("a\"b\\\n\1é") == '"a\\"b\\\\\\n\\u0001é"')
+        assert(json.encode(0.1) == "0.1" and json.encode(-0.0) == "-0.0" and json.encode(1e300) == "1e300")
+        assert(json.encode(math.mininteger) == "-9223372036854775808")
+
+        -- decoding
+        local v = json.decode(' {"a": [1, 2.5, -3e2, null, "\\u00e9\\ud83d\\ude00"], "b": {"c": false}} ')
+        assert(math.type(v.a[1]) == "integer" and math.type(v.a[2]) == "float" and v.a[3] == -300.0)
+        assert(v.a[4] == json.null and #v.a == 5 and v.a[5] == "é😀" and v.b.c == false)
+        assert(math.type(json.decode("1.0")) == "float" and json.decode("123456789012345678901") == 1.2345678901234568e20)
+        assert(json.decode('"\\t"') == "\t" and json.decode("[]")[1] == nil)
+
+        -- round trip
+        local t = {name = "n", list = {1, 2, {x = 1.5}}, flag = true}
+        local t2 = json.decode(json.encode(t))
+        assert(t2.name == "n" and t2.list[3].x == 1.5 and t2.flag == true)
+
+        -- errors come with byte positions or key paths
+        local function err(f, ...)
+          local ok, msg = pcall(f, ...)
+          assert(not ok)
+          return msg
+        end
+        assert(err(json.decode, '[1, 2,]'):find("unexpected character ']' at byte 7", 1, true))
+        assert(err(json.decode, '{"a": tru}'):find("unexpected character 't' at byte 7", 1, true))
+        assert(err(json.decode, '[1'):find("unexpected end of JSON at byte 3", 1, true))
+        assert(err(json.decode, '"\\x"'):find("invalid escape sequence at byte 3", 1, true))
+        assert(err(json.decode, '01'):find("at byte 2", 1, true))
+        assert(err(json.decode, '1 2'):find("unexpected character '2' at byte 3", 1, true))
+        assert(err(json.encode, {a = {b = {1, print}}}):find("cannot encode a function at 'a.b[2]'", 1, true))
+        assert(err(json.encode, {["a b"] = 0/0}):find("cannot encode the number", 1, true))
+        assert(err(json.encode, {[true] = 1}):find("cannot encode a boolean key", 1, true))
+
+        -- nesting limits, cycles run into them
+        local deep = {}
+        deep[1] = deep
+        assert(err(json.encode, deep):find("nesting deeper than 128 at '[1][1]", 1, true))
+        assert(err(json.encode, {{{}}}, 2):find("nesting deeper than 2 at '[1][1]'", 1, true))
+        assert(json.encode({{{}}}, 3) == "[[{}]]")
+        assert(err(json.decode, "[[[]]]", 2):find("nesting deeper than 2 at byte 3", 1, true))
+        assert(err(json.decode, "[]", 0):find("depth out of range", 1, true))
+        assert(#json.decode(string.rep("[", 128) .. string.rep("]", 128)) == 1)
+        return true
+    */
+    const LUA_JSON_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x14, 0x40,
+        0x2f, 0x74, 0x6d, 0x70, 0x2f, 0x66, 0x78, 0x2f, 0x6a, 0x73, 0x6f, 0x6e,
+        0x2f, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x0c, 0xb1, 0x01, 0x00, 0x00, 0x06, 0x00, 0x40,
+        0x00, 0x46, 0x40, 0x40, 0x00, 0x47, 0x80, 0xc0, 0x00, 0x8b, 0x00, 0x00,
+        0x02, 0xc1, 0xc0, 0x00, 0x00, 0x01, 0x01, 0x01, 0x00, 0x41, 0x41, 0x01,
+        0x00, 0x83, 0x01, 0x80, 0x00, 0xab, 0x40, 0x00, 0x02, 0x64, 0x80, 0x00,
+        0x01, 0x5f, 0x80, 0xc1, 0x00, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00,
+        0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40,
+        0x00, 0x46, 0x40, 0x40, 0x00, 0x47, 0x80, 0xc0, 0x00, 0x8b, 0x00, 0x00,
+        0x00, 0x64, 0x80, 0x00, 0x01, 0x5f, 0xc0, 0xc1, 0x00, 0x1e, 0x00, 0x00,
+        0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00,
+        0x01, 0x06, 0x00, 0x40, 0x00, 0x46, 0x40, 0x40, 0x00, 0x47, 0x80, 0xc0,
+        0x00, 0x8b, 0x40, 0x00, 0x00, 0xc6, 0x40, 0x40, 0x00, 0xc7, 0x40, 0xc2,
+        0x01, 0x8a, 0xc0, 0x00, 0x84, 0x64, 0x80, 0x00, 0x01, 0x5f, 0x80, 0xc2,
+        0x00, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80,
+        0x00, 0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40, 0x00, 0x46, 0x40, 0x40,
+        0x00, 0x47, 0x80, 0xc0, 0x00, 0x8b, 0x80, 0x00, 0x00, 0x8a, 0xc0, 0xc0,
+        0x81, 0x8a, 0xc0, 0xc2, 0x85, 0x64, 0x80, 0x00, 0x01, 0x5f, 0x00, 0xc3,
+        0x00, 0x1e, 0x00, 0x02, 0x80, 0x46, 0x40, 0x40, 0x00, 0x47, 0x80, 0xc0,
+        0x00, 0x8b, 0x80, 0x00, 0x00, 0x8a, 0xc0, 0xc0, 0x81, 0x8a, 0xc0, 0xc2,
+        0x85, 0x64, 0x80, 0x00, 0x01, 0x5f, 0x40, 0xc3, 0x00, 0x1e, 0x00, 0x00,
+        0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00,
+        0x01, 0x06, 0x00, 0x40, 0x00, 0x46, 0x40, 0x40, 0x00, 0x47, 0x80, 0xc0,
+        0x00, 0x81, 0x80, 0x03, 0x00, 0x64, 0x80, 0x00, 0x01, 0x5f, 0xc0, 0xc3,
+        0x00, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80,
+        0x00, 0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40, 0x00, 0x46, 0x40, 0x40,
+        0x00, 0x47, 0x80, 0xc0, 0x00, 0x81, 0x00, 0x04, 0x00, 0x64, 0x80, 0x00,
+        0x01, 0x1f, 0x40, 0xc4, 0x00, 0x1e, 0x00, 0x03, 0x80, 0x46, 0x40, 0x40,
+        0x00, 0x47, 0x80, 0xc0, 0x00, 0x81, 0x80, 0x04, 0x00, 0x99, 0x00, 0x00,
+        0x01, 0x64, 0x80, 0x00, 0x01, 0x1f, 0xc0, 0xc4, 0x00, 0x1e, 0x40, 0x01,
+        0x80, 0x46, 0x40, 0x40, 0x00, 0x47, 0x80, 0xc0, 0x00, 0x81, 0x00, 0x05,
+        0x00, 0x64, 0x80, 0x00, 0x01, 0x5f, 0x40, 0xc5, 0x00, 0x1e, 0x00, 0x00,
+        0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00,
+        0x01, 0x06, 0x00, 0x40, 0x00, 0x46, 0x40, 0x40, 0x00, 0x47, 0x80, 0xc0,
+        0x00, 0x86, 0x80, 0x45, 0x00, 0x87, 0xc0, 0x45, 0x01, 0x64, 0x80, 0x00,
+        0x01, 0x5f, 0x00, 0xc6, 0x00, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00,
+        0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01, 0x06, 0x40, 0x40,
+        0x00, 0x07, 0x40, 0x46, 0x00, 0x41, 0x80, 0x06, 0x00, 0x24, 0x80, 0x00,
+        0x01, 0x46, 0x00, 0x40, 0x00, 0x86, 0x80, 0x45, 0x00, 0x87, 0xc0, 0x46,
+        0x01, 0xc7, 0x00, 0x42, 0x00, 0xc7, 0xc0, 0xc0, 0x01, 0xa4, 0x80, 0x00,
+        0x01, 0x1f, 0x00, 0x47, 0x01, 0x1e, 0x80, 0x02, 0x80, 0x86, 0x80, 0x45,
+        0x00, 0x87, 0xc0, 0x46, 0x01, 0xc7, 0x00, 0x42, 0x00, 0xc7, 0x40, 0xc7,
+        0x01, 0xa4, 0x80, 0x00, 0x01, 0x1f, 0x80, 0x47, 0x01, 0x1e, 0xc0, 0x00,
+        0x80, 0x87, 0x00, 0x42, 0x00, 0x87, 0xc0, 0x42, 0x01, 0x5f, 0xc0, 0x47,
+        0x01, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x40, 0x00, 0x00, 0x83, 0x00, 0x80,
+        0x00, 0x64, 0x40, 0x00, 0x01, 0x46, 0x00, 0x40, 0x00, 0x87, 0x00, 0x42,
+        0x00, 0x87, 0x00, 0x48, 0x01, 0xc6, 0x40, 0x40, 0x00, 0xc7, 0x40, 0xc2,
+        0x01, 0x1f, 0xc0, 0x00, 0x01, 0x1e, 0xc0, 0x02, 0x80, 0x87, 0x00, 0x42,
+        0x00, 0x9c, 0x00, 0x00, 0x01, 0x1f, 0x40, 0x48, 0x01, 0x1e, 0xc0, 0x01,
+        0x80, 0x87, 0x00, 0x42, 0x00, 0x87, 0x40, 0x48, 0x01, 0x1f, 0x80, 0x48,
+        0x01, 0x1e, 0xc0, 0x00, 0x80, 0x87, 0xc0, 0x48, 0x00, 0x87, 0x00, 0x49,
+        0x01, 0x5f, 0x40, 0x49, 0x01, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x40, 0x00,
+        0x00, 0x83, 0x00, 0x80, 0x00, 0x64, 0x40, 0x00, 0x01, 0x46, 0x00, 0x40,
+        0x00, 0x86, 0x80, 0x45, 0x00, 0x87, 0xc0, 0x46, 0x01, 0xc6, 0x40, 0x40,
+        0x00, 0xc7, 0x40, 0xc6, 0x01, 0x01, 0x81, 0x09, 0x00, 0xe4, 0x00, 0x00,
+        0x01, 0xa4, 0x80, 0x00, 0x00, 0x1f, 0x80, 0x47, 0x01, 0x1e, 0x40, 0x01,
+        0x80, 0x86, 0x40, 0x40, 0x00, 0x87, 0x40, 0x46, 0x01, 0xc1, 0xc0, 0x09,
+        0x00, 0xa4, 0x80, 0x00, 0x01, 0x5f, 0x00, 0x4a, 0x01, 0x1e, 0x00, 0x00,
+        0x80, 0x83, 0x40, 0x00, 0x00, 0x83, 0x00, 0x80, 0x00, 0x64, 0x40, 0x00,
+        0x01, 0x46, 0x00, 0x40, 0x00, 0x86, 0x40, 0x40, 0x00, 0x87, 0x40, 0x46,
+        0x01, 0xc1, 0x40, 0x0a, 0x00, 0xa4, 0x80, 0x00, 0x01, 0x1f, 0x80, 0x4a,
+        0x01, 0x1e, 0x80, 0x01, 0x80, 0x86, 0x40, 0x40, 0x00, 0x87, 0x40, 0x46,
+        0x01, 0xc1, 0xc0, 0x0a, 0x00, 0xa4, 0x80, 0x00, 0x01, 0x87, 0xc0, 0x40,
+        0x01, 0x5f, 0x00, 0x4b, 0x01, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x40, 0x00,
+        0x00, 0x83, 0x00, 0x80, 0x00, 0x64, 0x40, 0x00, 0x01, 0x4b, 0xc0, 0x00,
+        0x00, 0x4a, 0x80, 0xcb, 0x96, 0x8b, 0x00, 0x80, 0x01, 0xc1, 0xc0, 0x00,
+        0x00, 0x01, 0x41, 0x07, 0x00, 0x4b, 0x41, 0x00, 0x00, 0x4a, 0x01, 0xcc,
+        0x82, 0xab, 0x40, 0x80, 0x01, 0x4a, 0x80, 0x80, 0x97, 0x4a, 0x80, 0xcc,
+        0x98, 0x86, 0x40, 0x40, 0x00, 0x87, 0x40, 0x46, 0x01, 0xc6, 0x40, 0x40,
+        0x00, 0xc7, 0x80, 0xc0, 0x01, 0x00, 0x01, 0x80, 0x00, 0xe4, 0x00, 0x00,
+        0x01, 0xa4, 0x80, 0x00, 0x00, 0xc6, 0x00, 0x40, 0x00, 0x07, 0x41, 0x4b,
+        0x01, 0x1f, 0x80, 0x4b, 0x02, 0x1e, 0xc0, 0x01, 0x80, 0x07, 0xc1, 0x4b,
+        0x01, 0x07, 0xc1, 0x42, 0x02, 0x07, 0x41, 0x41, 0x02, 0x1f, 0x00, 0x4c,
+        0x02, 0x1e, 0x80, 0x00, 0x80, 0x07, 0x41, 0x4c, 0x01, 0x5f, 0x80, 0x4c,
+        0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80,
+        0x00, 0xe4, 0x40, 0x00, 0x01, 0xec, 0x00, 0x00, 0x00, 0x06, 0xc1, 0x4c,
+        0x00, 0x40, 0x01, 0x80, 0x01, 0x86, 0x41, 0x40, 0x00, 0x87, 0x41, 0x46,
+        0x03, 0xc1, 0x01, 0x0d, 0x00, 0x64, 0x81, 0x80, 0x01, 0x4c, 0x41, 0xcd,
+        0x02, 0xc1, 0x81, 0x0d, 0x00, 0x01, 0xc2, 0x00, 0x00, 0x43, 0x02, 0x80,
+        0x00, 0x64, 0x01, 0x80, 0x02, 0x24, 0x41, 0x00, 0x00, 0x06, 0xc1, 0x4c,
+        0x00, 0x40, 0x01, 0x80, 0x01, 0x86, 0x41, 0x40, 0x00, 0x87, 0x41, 0x46,
+        0x03, 0xc1, 0xc1, 0x0d, 0x00, 0x64, 0x81, 0x80, 0x01, 0x4c, 0x41, 0xcd,
+        0x02, 0xc1, 0x01, 0x0e, 0x00, 0x01, 0xc2, 0x00, 0x00, 0x43, 0x02, 0x80,
+        0x00, 0x64, 0x01, 0x80, 0x02, 0x24, 0x41, 0x00, 0x00, 0x06, 0xc1, 0x4c,
+        0x00, 0x40, 0x01, 0x80, 0x01, 0x86, 0x41, 0x40, 0x00, 0x87, 0x41, 0x46,
+        0x03, 0xc1, 0x41, 0x0e, 0x00, 0x64, 0x81, 0x80, 0x01, 0x4c, 0x41, 0xcd,
+        0x02, 0xc1, 0x81, 0x0e, 0x00, 0x01, 0xc2, 0x00, 0x00, 0x43, 0x02, 0x80,
+        0x00, 0x64, 0x01, 0x80, 0x02, 0x24, 0x41, 0x00, 0x00, 0x06, 0xc1, 0x4c,
+        0x00, 0x40, 0x01, 0x80, 0x01, 0x86, 0x41, 0x40, 0x00, 0x87, 0x41, 0x46,
+        0x03, 0xc1, 0xc1, 0x0e, 0x00, 0x64, 0x81, 0x80, 0x01, 0x4c, 0x41, 0xcd,
+        0x02, 0xc1, 0x01, 0x0f, 0x00, 0x01, 0xc2, 0x00, 0x00, 0x43, 0x02, 0x80,
+        0x00, 0x64, 0x01, 0x80, 0x02, 0x24, 0x41, 0x00, 0x00, 0x06, 0xc1, 0x4c,
+        0x00, 0x40, 0x01, 0x80, 0x01, 0x86, 0x41, 0x40, 0x00, 0x87, 0x41, 0x46,
+        0x03, 0xc1, 0x41, 0x0f, 0x00, 0x64, 0x81, 0x80, 0x01, 0x4c, 0x41, 0xcd,
+        0x02, 0xc1, 0x81, 0x0f, 0x00, 0x01, 0xc2, 0x00, 0x00, 0x43, 0x02, 0x80,
+        0x00, 0x64, 0x01, 0x80, 0x02, 0x24, 0x41, 0x00, 0x00, 0x06, 0xc1, 0x4c,
+        0x00, 0x40, 0x01, 0x80, 0x01, 0x86, 0x41, 0x40, 0x00, 0x87, 0x41, 0x46,
+        0x03, 0xc1, 0xc1, 0x0f, 0x00, 0x64, 0x81, 0x80, 0x01, 0x4c, 0x41, 0xcd,
+        0x02, 0xc1, 0x01, 0x10, 0x00, 0x01, 0xc2, 0x00, 0x00, 0x43, 0x02, 0x80,
+        0x00, 0x64, 0x01, 0x80, 0x02, 0x24, 0x41, 0x00, 0x00, 0x06, 0xc1, 0x4c,
+        0x00, 0x40, 0x01, 0x80, 0x01, 0x86, 0x41, 0x40, 0x00, 0x87, 0x81, 0x40,
+        0x03, 0xcb, 0x41, 0x00, 0x00, 0x0b, 0x42, 0x00, 0x00, 0x4b, 0x02, 0x00,
+        0x01, 0x81, 0xc2, 0x00, 0x00, 0xc6, 0x42, 0x50, 0x00, 0x6b, 0x42, 0x00,
+        0x01, 0x0a, 0x42, 0x82, 0x91, 0xca, 0x01, 0x02, 0x84, 0x64, 0x81, 0x80,
+        0x01, 0x4c, 0x41, 0xcd, 0x02, 0xc1, 0x81, 0x10, 0x00, 0x01, 0xc2, 0x00,
+        0x00, 0x43, 0x02, 0x80, 0x00, 0x64, 0x01, 0x80, 0x02, 0x24, 0x41, 0x00,
+        0x00, 0x06, 0xc1, 0x4c, 0x00, 0x40, 0x01, 0x80, 0x01, 0x86, 0x41, 0x40,
+        0x00, 0x87, 0x81, 0x40, 0x03, 0xcb, 0x41, 0x00, 0x00, 0x12, 0x02, 0x51,
+        0xa2, 0xca, 0x01, 0x82, 0xa1, 0x64, 0x81, 0x80, 0x01, 0x4c, 0x41, 0xcd,
+        0x02, 0xc1, 0x41, 0x11, 0x00, 0x01, 0xc2, 0x00, 0x00, 0x43, 0x02, 0x80,
+        0x00, 0x64, 0x01, 0x80, 0x02, 0x24, 0x41, 0x00, 0x00, 0x06, 0xc1, 0x4c,
+        0x00, 0x40, 0x01, 0x80, 0x01, 0x86, 0x41, 0x40, 0x00, 0x87, 0x81, 0x40,
+        0x03, 0xcb, 0x41, 0x00, 0x00, 0xca, 0xc1, 0x40, 0x99, 0x64, 0x81, 0x80,
+        0x01, 0x4c, 0x41, 0xcd, 0x02, 0xc1, 0x81, 0x11, 0x00, 0x01, 0xc2, 0x00,
+        0x00, 0x43, 0x02, 0x80, 0x00, 0x64, 0x01, 0x80, 0x02, 0x24, 0x41, 0x00,
+        0x00, 0x0b, 0x01, 0x00, 0x00, 0x0a, 0x01, 0x81, 0x81, 0x46, 0xc1, 0x4c,
+        0x00, 0x80, 0x01, 0x80, 0x01, 0xc6, 0x41, 0x40, 0x00, 0xc7, 0x81, 0xc0,
+        0x03, 0x00, 0x02, 0x00, 0x02, 0xa4, 0x81, 0x80, 0x01, 0x8c, 0x41, 0x4d,
+        0x03, 0x01, 0xc2, 0x11, 0x00, 0x41, 0xc2, 0x00, 0x00, 0x83, 0x02, 0x80,
+        0x00, 0xa4, 0x01, 0x80, 0x02, 0x64, 0x41, 0x00, 0x00, 0x46, 0xc1, 0x4c,
+        0x00, 0x80, 0x01, 0x80, 0x01, 0xc6, 0x41, 0x40, 0x00, 0xc7, 0x81, 0xc0,
+        0x03, 0x0b, 0x02, 0x80, 0x00, 0x4b, 0x02, 0x80, 0x00, 0x8b, 0x02, 0x00,
+        0x00, 0x6b, 0x42, 0x80, 0x00, 0x2b, 0x42, 0x80, 0x00, 0x41, 0x42, 0x07,
+        0x00, 0xa4, 0x81, 0x00, 0x02, 0x8c, 0x41, 0x4d, 0x03, 0x01, 0x02, 0x12,
+        0x00, 0x41, 0xc2, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00, 0xa4, 0x01, 0x80,
+        0x02, 0x64, 0x41, 0x00, 0x00, 0x46, 0xc1, 0x4c, 0x00, 0x86, 0x41, 0x40,
+        0x00, 0x87, 0x81, 0x40, 0x03, 0xcb, 0x01, 0x80, 0x00, 0x0b, 0x02, 0x80,
+        0x00, 0x4b, 0x02, 0x00, 0x00, 0x2b, 0x42, 0x80, 0x00, 0xeb, 0x41, 0x80,
+        0x00, 0x01, 0xc2, 0x02, 0x00, 0xa4, 0x81, 0x80, 0x01, 0x5f, 0x40, 0x52,
+        0x03, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80,
+        0x00, 0x64, 0x41, 0x00, 0x01, 0x46, 0xc1, 0x4c, 0x00, 0x80, 0x01, 0x80,
+        0x01, 0xc6, 0x41, 0x40, 0x00, 0xc7, 0x41, 0xc6, 0x03, 0x01, 0x82, 0x12,
+        0x00, 0x41, 0x42, 0x07, 0x00, 0xa4, 0x81, 0x00, 0x02, 0x8c, 0x41, 0x4d,
+        0x03, 0x01, 0xc2, 0x12, 0x00, 0x41, 0xc2, 0x00, 0x00, 0x83, 0x02, 0x80,
+        0x00, 0xa4, 0x01, 0x80, 0x02, 0x64, 0x41, 0x00, 0x00, 0x46, 0xc1, 0x4c,
+        0x00, 0x80, 0x01, 0x80, 0x01, 0xc6, 0x41, 0x40, 0x00, 0xc7, 0x41, 0xc6,
+        0x03, 0x01, 0xc2, 0x0a, 0x00, 0x41, 0x02, 0x11, 0x00, 0xa4, 0x81, 0x00,
+        0x02, 0x8c, 0x41, 0x4d, 0x03, 0x01, 0x02, 0x13, 0x00, 0x41, 0xc2, 0x00,
+        0x00, 0x83, 0x02, 0x80, 0x00, 0xa4, 0x01, 0x80, 0x02, 0x64, 0x41, 0x00,
+        0x00, 0x46, 0xc1, 0x4c, 0x00, 0x86, 0x41, 0x40, 0x00, 0x87, 0x41, 0x46,
+        0x03, 0xc6, 0x41, 0x53, 0x00, 0xc7, 0x81, 0xd3, 0x03, 0x01, 0xc2, 0x13,
+        0x00, 0x41, 0x02, 0x14, 0x00, 0xe4, 0x81, 0x80, 0x01, 0x06, 0x42, 0x53,
+        0x00, 0x07, 0x82, 0x53, 0x04, 0x41, 0x42, 0x14, 0x00, 0x81, 0x02, 0x14,
+        0x00, 0x24, 0x82, 0x80, 0x01, 0xdd, 0x01, 0x82, 0x03, 0xa4, 0x81, 0x00,
+        0x01, 0x9c, 0x01, 0x00, 0x03, 0x5f, 0xc0, 0x40, 0x03, 0x1e, 0x00, 0x00,
+        0x80, 0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80, 0x00, 0x64, 0x41, 0x00,
+        0x01, 0x43, 0x01, 0x80, 0x00, 0x66, 0x01, 0x00, 0x01, 0x26, 0x00, 0x80,
+        0x00, 0x52, 0x00, 0x00, 0x00, 0x04, 0x07, 0x61, 0x73, 0x73, 0x65, 0x72,
+        0x74, 0x04, 0x05, 0x6a, 0x73, 0x6f, 0x6e, 0x04, 0x07, 0x65, 0x6e, 0x63,
+        0x6f, 0x64, 0x65, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x04, 0x02, 0x78,
+        0x04, 0x11, 0x5b, 0x31, 0x2c, 0x32, 0x2e, 0x30, 0x2c, 0x22, 0x78, 0x22,
+        0x2c, 0x74, 0x72, 0x75, 0x65, 0x5d, 0x04, 0x03, 0x7b, 0x7d, 0x04, 0x02,
+        0x61, 0x04, 0x05, 0x6e, 0x75, 0x6c, 0x6c, 0x04, 0x0b, 0x7b, 0x22, 0x61,
+        0x22, 0x3a, 0x6e, 0x75, 0x6c, 0x6c, 0x7d, 0x13, 0x03, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x0e, 0x7b, 0x22, 0x31, 0x22, 0x3a, 0x31,
+        0x2c, 0x22, 0x33, 0x22, 0x3a, 0x33, 0x7d, 0x04, 0x0e, 0x7b, 0x22, 0x33,
+        0x22, 0x3a, 0x33, 0x2c, 0x22, 0x31, 0x22, 0x3a, 0x31, 0x7d, 0x04, 0x09,
+        0x61, 0x22, 0x62, 0x5c, 0x0a, 0x01, 0xc3, 0xa9, 0x04, 0x13, 0x22, 0x61,
+        0x5c, 0x22, 0x62, 0x5c, 0x5c, 0x5c, 0x6e, 0x5c, 0x75, 0x30, 0x30, 0x30,
+        0x31, 0xc3, 0xa9, 0x22, 0x03, 0x9a, 0x99, 0x99, 0x99, 0x99, 0x99, 0xb9,
+        0x3f, 0x04, 0x04, 0x30, 0x2e, 0x31, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x05, 0x2d, 0x30, 0x2e, 0x30, 0x03, 0x9c, 0x75,
+        0x00, 0x88, 0x3c, 0xe4, 0x37, 0x7e, 0x04, 0x06, 0x31, 0x65, 0x33, 0x30,
+        0x30, 0x04, 0x05, 0x6d, 0x61, 0x74, 0x68, 0x04, 0x0b, 0x6d, 0x69, 0x6e,
+        0x69, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x04, 0x15, 0x2d, 0x39, 0x32,
+        0x32, 0x33, 0x33, 0x37, 0x32, 0x30, 0x33, 0x36, 0x38, 0x35, 0x34, 0x37,
+        0x37, 0x35, 0x38, 0x30, 0x38, 0x04, 0x07, 0x64, 0x65, 0x63, 0x6f, 0x64,
+        0x65, 0x14, 0x47, 0x20, 0x7b, 0x22, 0x61, 0x22, 0x3a, 0x20, 0x5b, 0x31,
+        0x2c, 0x20, 0x32, 0x2e, 0x35, 0x2c, 0x20, 0x2d, 0x33, 0x65, 0x32, 0x2c,
+        0x20, 0x6e, 0x75, 0x6c, 0x6c, 0x2c, 0x20, 0x22, 0x5c, 0x75, 0x30, 0x30,
+        0x65, 0x39, 0x5c, 0x75, 0x64, 0x38, 0x33, 0x64, 0x5c, 0x75, 0x64, 0x65,
+        0x30, 0x30, 0x22, 0x5d, 0x2c, 0x20, 0x22, 0x62, 0x22, 0x3a, 0x20, 0x7b,
+        0x22, 0x63, 0x22, 0x3a, 0x20, 0x66, 0x61, 0x6c, 0x73, 0x65, 0x7d, 0x7d,
+        0x20, 0x04, 0x05, 0x74, 0x79, 0x70, 0x65, 0x04, 0x08, 0x69, 0x6e, 0x74,
+        0x65, 0x67, 0x65, 0x72, 0x13, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x06, 0x66, 0x6c, 0x6f, 0x61, 0x74, 0x03, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xc0, 0x72, 0xc0, 0x13, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x13, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x07, 0xc3, 0xa9, 0xf0, 0x9f, 0x98, 0x80, 0x04, 0x02, 0x62, 0x04, 0x02,
+        0x63, 0x01, 0x00, 0x04, 0x04, 0x31, 0x2e, 0x30, 0x04, 0x16, 0x31, 0x32,
+        0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x30, 0x31, 0x32, 0x33, 0x34,
+        0x35, 0x36, 0x37, 0x38, 0x39, 0x30, 0x31, 0x03, 0xda, 0xbc, 0x04, 0x7e,
+        0x3a, 0xc5, 0x1a, 0x44, 0x04, 0x05, 0x22, 0x5c, 0x74, 0x22, 0x04, 0x02,
+        0x09, 0x04, 0x03, 0x5b, 0x5d, 0x00, 0x04, 0x05, 0x6e, 0x61, 0x6d, 0x65,
+        0x04, 0x02, 0x6e, 0x04, 0x05, 0x6c, 0x69, 0x73, 0x74, 0x03, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xf8, 0x3f, 0x04, 0x05, 0x66, 0x6c, 0x61, 0x67,
+        0x01, 0x01, 0x04, 0x07, 0x61, 0x73, 0x73, 0x65, 0x72, 0x74, 0x04, 0x08,
+        0x5b, 0x31, 0x2c, 0x20, 0x32, 0x2c, 0x5d, 0x04, 0x05, 0x66, 0x69, 0x6e,
+        0x64, 0x04, 0x23, 0x75, 0x6e, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65,
+        0x64, 0x20, 0x63, 0x68, 0x61, 0x72, 0x61, 0x63, 0x74, 0x65, 0x72, 0x20,
+        0x27, 0x5d, 0x27, 0x20, 0x61, 0x74, 0x20, 0x62, 0x79, 0x74, 0x65, 0x20,
+        0x37, 0x04, 0x0b, 0x7b, 0x22, 0x61, 0x22, 0x3a, 0x20, 0x74, 0x72, 0x75,
+        0x7d, 0x04, 0x23, 0x75, 0x6e, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65,
+        0x64, 0x20, 0x63, 0x68, 0x61, 0x72, 0x61, 0x63, 0x74, 0x65, 0x72, 0x20,
+        0x27, 0x74, 0x27, 0x20, 0x61, 0x74, 0x20, 0x62, 0x79, 0x74, 0x65, 0x20,
+        0x37, 0x04, 0x03, 0x5b, 0x31, 0x04, 0x21, 0x75, 0x6e, 0x65, 0x78, 0x70,
+        0x65, 0x63, 0x74, 0x65, 0x64, 0x20, 0x65, 0x6e, 0x64, 0x20, 0x6f, 0x66,
+        0x20, 0x4a, 0x53, 0x4f, 0x4e, 0x20, 0x61, 0x74, 0x20, 0x62, 0x79, 0x74,
+        0x65, 0x20, 0x33, 0x04, 0x05, 0x22, 0x5c, 0x78, 0x22, 0x04, 0x22, 0x69,
+        0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x65, 0x73, 0x63, 0x61, 0x70,
+        0x65, 0x20, 0x73, 0x65, 0x71, 0x75, 0x65, 0x6e, 0x63, 0x65, 0x20, 0x61,
+        0x74, 0x20, 0x62, 0x79, 0x74, 0x65, 0x20, 0x33, 0x04, 0x03, 0x30, 0x31,
+        0x04, 0x0a, 0x61, 0x74, 0x20, 0x62, 0x79, 0x74, 0x65, 0x20, 0x32, 0x04,
+        0x04, 0x31, 0x20, 0x32, 0x04, 0x23, 0x75, 0x6e, 0x65, 0x78, 0x70, 0x65,
+        0x63, 0x74, 0x65, 0x64, 0x20, 0x63, 0x68, 0x61, 0x72, 0x61, 0x63, 0x74,
+        0x65, 0x72, 0x20, 0x27, 0x32, 0x27, 0x20, 0x61, 0x74, 0x20, 0x62, 0x79,
+        0x74, 0x65, 0x20, 0x33, 0x04, 0x06, 0x70, 0x72, 0x69, 0x6e, 0x74, 0x04,
+        0x25, 0x63, 0x61, 0x6e, 0x6e, 0x6f, 0x74, 0x20, 0x65, 0x6e, 0x63, 0x6f,
+        0x64, 0x65, 0x20, 0x61, 0x20, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f,
+        0x6e, 0x20, 0x61, 0x74, 0x20, 0x27, 0x61, 0x2e, 0x62, 0x5b, 0x32, 0x5d,
+        0x27, 0x04, 0x04, 0x61, 0x20, 0x62, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x19, 0x63, 0x61, 0x6e, 0x6e, 0x6f, 0x74, 0x20,
+        0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e,
+        0x75, 0x6d, 0x62, 0x65, 0x72, 0x04, 0x1c, 0x63, 0x61, 0x6e, 0x6e, 0x6f,
+        0x74, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x20, 0x61, 0x20, 0x62,
+        0x6f, 0x6f, 0x6c, 0x65, 0x61, 0x6e, 0x20, 0x6b, 0x65, 0x79, 0x04, 0x23,
+        0x6e, 0x65, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x64, 0x65, 0x65, 0x70,
+        0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x31, 0x32, 0x38, 0x20,
+        0x61, 0x74, 0x20, 0x27, 0x5b, 0x31, 0x5d, 0x5b, 0x31, 0x5d, 0x04, 0x22,
+        0x6e, 0x65, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x64, 0x65, 0x65, 0x70,
+        0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x32, 0x20, 0x61, 0x74,
+        0x20, 0x27, 0x5b, 0x31, 0x5d, 0x5b, 0x31, 0x5d, 0x27, 0x04, 0x07, 0x5b,
+        0x5b, 0x7b, 0x7d, 0x5d, 0x5d, 0x04, 0x07, 0x5b, 0x5b, 0x5b, 0x5d, 0x5d,
+        0x5d, 0x04, 0x20, 0x6e, 0x65, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x64,
+        0x65, 0x65, 0x70, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x32,
+        0x20, 0x61, 0x74, 0x20, 0x62, 0x79, 0x74, 0x65, 0x20, 0x33, 0x04, 0x13,
+        0x64, 0x65, 0x70, 0x74, 0x68, 0x20, 0x6f, 0x75, 0x74, 0x20, 0x6f, 0x66,
+        0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x04, 0x07, 0x73, 0x74, 0x72, 0x69,
+        0x6e, 0x67, 0x04, 0x04, 0x72, 0x65, 0x70, 0x04, 0x02, 0x5b, 0x13, 0x80,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x5d, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00,
+        0x00, 0x1b, 0x00, 0x00, 0x00, 0x01, 0x01, 0x05, 0x09, 0x00, 0x00, 0x00,
+        0x46, 0x00, 0x40, 0x00, 0x80, 0x00, 0x00, 0x00, 0xed, 0x00, 0x00, 0x00,
+        0x64, 0xc0, 0x00, 0x00, 0xc6, 0x40, 0x40, 0x00, 0x1b, 0x01, 0x80, 0x00,
+        0xe4, 0x40, 0x00, 0x01, 0xa6, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x04, 0x06, 0x70, 0x63, 0x61, 0x6c, 0x6c, 0x04,
+        0x07, 0x61, 0x73, 0x73, 0x65, 0x72, 0x74, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00,
+        0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00,
+        0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00,
+        0x00, 0x1a, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x02, 0x66, 0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x03,
+        0x6f, 0x6b, 0x04, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x04, 0x6d,
+        0x73, 0x67, 0x04, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56, 0xb1, 0x01, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x0b,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x12,
+        0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12,
+        0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12,
+        0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12,
+        0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13,
+        0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13,
+        0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1e,
+        0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e,
+        0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e,
+        0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e,
+        0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x29,
+        0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29,
+        0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29,
+        0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29,
+        0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x2a,
+        0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a,
+        0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a,
+        0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a,
+        0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a,
+        0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a,
+        0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b,
+        0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b,
+        0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b,
+        0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b,
+        0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b,
+        0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x02, 0x76, 0x6a, 0x00, 0x00,
+        0x00, 0xb1, 0x01, 0x00, 0x00, 0x02, 0x74, 0xc4, 0x00, 0x00, 0x00, 0xb1,
+        0x01, 0x00, 0x00, 0x03, 0x74, 0x32, 0xcb, 0x00, 0x00, 0x00, 0xb1, 0x01,
+        0x00, 0x00, 0x04, 0x65, 0x72, 0x72, 0xdb, 0x00, 0x00, 0x00, 0xb1, 0x01,
+        0x00, 0x00, 0x05, 0x64, 0x65, 0x65, 0x70, 0x52, 0x01, 0x00, 0x00, 0xb1,
+        0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
+}
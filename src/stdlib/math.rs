@@ -0,0 +1,862 @@
+use crate::api::consts::*;
+use crate::api::{LuaAPI, LuaAuxLib, LuaUserdata, RustFn};
+use crate::number::math::float_to_integer;
+use crate::number::random::Xoshiro256;
+use std::f64::consts::PI;
+
+const MATH_FUNCS: &[(&str, RustFn)] = &[
+    ("abs", math_abs),
+    ("ceil", math_ceil),
+    ("cos", math_cos),
+    ("deg", math_deg),
+    ("exp", math_exp),
+    ("tointeger", math_toint),
+    ("floor", math_floor),
+    ("fmod", math_fmod),
+    ("ult", math_ult),
+    ("log", math_log),
+    ("max", math_max),
+    ("min", math_min),
+    ("modf", math_modf),
+    ("rad", math_rad),
+    ("sin", math_sin),
+    ("sqrt", math_sqrt),
+    ("tan", math_tan),
+    ("type", math_type),
+    ("acos", math_acos),
+    ("asin", math_asin),
+    ("atan", math_atan),
+];
+
+// Functions sharing the generator state as their upvalue.
+const RAND_FUNCS: &[(&str, RustFn)] = &[("random", math_random), ("randomseed", math_randomseed)];
+
+pub fn open_math(ls: &mut dyn LuaAPI) -> usize {
+    ls.new_lib(MATH_FUNCS);
+    ls.push_number(PI);
+    ls.set_field(-2, "pi");
+    ls.push_number(f64::INFINITY);
+    ls.set_field(-2, "huge");
+    ls.push_integer(i64::MAX);
+    ls.set_field(-2, "maxinteger");
+    ls.push_integer(i64::MIN);
+    ls.set_field(-2, "mininteger");
+    ls.new_userdata(Xoshiro256::from_entropy());
+    ls.set_funcs(RAND_FUNCS, 1);
+    1
+}
+
+// Push a float with an integral value as an integer when it fits.
+fn push_numint(ls: &mut dyn LuaAPI, d: f64) {
+    match float_to_integer(d) {
+        Some(n) => ls.push_integer(n), // does 'd' fit in an integer?
+        None => ls.push_number(d),     // result is float
+    }
+}
+
+// math.abs (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.abs
+fn math_abs(ls: &mut dyn LuaAPI) -> usize {
+    if ls.is_integer(1) {
+        let n = ls.to_integer(1);
+        ls.push_integer(n.wrapping_abs());
+    } else {
+        let x = ls.check_number(1);
+        ls.push_number(x.abs());
+    }
+    1
+}
+
+// math.sin (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.sin
+fn math_sin(ls: &mut dyn LuaAPI) -> usize {
+    let x = ls.check_number(1);
+    ls.push_number(x.sin());
+    1
+}
+
+// math.cos (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.cos
+fn math_cos(ls: &mut dyn LuaAPI) -> usize {
+    let x = ls.check_number(1);
+    ls.push_number(x.cos());
+    1
+}
+
+// math.tan (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.tan
+fn math_tan(ls: &mut dyn LuaAPI) -> usize {
+    let x = ls.check_number(1);
+    ls.push_number(x.tan());
+    1
+}
+
+// math.asin (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.asin
+fn math_asin(ls: &mut dyn LuaAPI) -> usize {
+    let x = ls.check_number(1);
+    ls.push_number(x.asin());
+    1
+}
+
+// math.acos (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.acos
+fn math_acos(ls: &mut dyn LuaAPI) -> usize {
+    let x = ls.check_number(1);
+    ls.push_number(x.acos());
+    1
+}
+
+// math.atan (y [, x])
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.atan
+fn math_atan(ls: &mut dyn LuaAPI) -> usize {
+    let y = ls.check_number(1);
+    let x = ls.opt_number(2, 1.0);
+    ls.push_number(y.atan2(x));
+    1
+}
+
+// math.tointeger (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.tointeger
+fn math_toint(ls: &mut dyn LuaAPI) -> usize {
+    match ls.to_integerx(1) {
+        Some(n) => ls.push_integer(n),
+        None => {
+            ls.check_any(1);
+            ls.push_nil(); // value is not convertible to integer
+        }
+    }
+    1
+}
+
+// math.floor (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.floor
+fn math_floor(ls: &mut dyn LuaAPI) -> usize {
+    if ls.is_integer(1) {
+        ls.set_top(1); // integer is its own floor
+    } else {
+        let d = ls.check_number(1).floor();
+        push_numint(ls, d);
+    }
+    1
+}
+
+// math.ceil (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.ceil
+fn math_ceil(ls: &mut dyn LuaAPI) -> usize {
+    if ls.is_integer(1) {
+        ls.set_top(1); // integer is its own ceil
+    } else {
+        let d = ls.check_number(1).ceil();
+        push_numint(ls, d);
+    }
+    1
+}
+
+// math.fmod (x, y)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.fmod
+fn math_fmod(ls: &mut dyn LuaAPI) -> usize {
+    if ls.is_integer(1) && ls.is_integer(2) {
+        let d = ls.to_integer(2);
+        if (d as u64).wrapping_add(1) <= 1 {
+            // special cases: -1 or 0
+            ls.arg_check(d != 0, 2, "zero");
+            ls.push_integer(0); // avoid overflow with 0x80000... / -1
+        } else {
+            let m = ls.to_integer(1);
+            ls.push_integer(m % d);
+        }
+    } else {
+        let a = ls.check_number(1);
+        let b = ls.check_number(2);
+        ls.push_number(a % b); // `%` on floats truncates, like C fmod
+    }
+    1
+}
+
+// math.modf (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.modf
+// next function does not use 'modf', avoiding problems with 'double*'
+// (which is not compatible with 'float*') when lua_Number is not 'double'.
+fn math_modf(ls: &mut dyn LuaAPI) -> usize {
+    if ls.is_integer(1) {
+        ls.set_top(1); // number is its own integer part
+        ls.push_number(0.0); // no fractional part
+    } else {
+        let n = ls.check_number(1);
+        // integer part (rounds toward zero)
+        let ip = if n < 0.0 { n.ceil() } else { n.floor() };
+        push_numint(ls, ip);
+        // fractional part (test needed for inf/-inf)
+        ls.push_number(if n == ip { 0.0 } else { n - ip });
+    }
+    2
+}
+
+// math.sqrt (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.sqrt
+fn math_sqrt(ls: &mut dyn LuaAPI) -> usize {
+    let x = ls.check_number(1);
+    ls.push_number(x.sqrt());
+    1
+}
+
+// math.ult (m, n)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.ult
+fn math_ult(ls: &mut dyn LuaAPI) -> usize {
+    let a = ls.check_integer(1);
+    let b = ls.check_integer(2);
+    ls.push_boolean((a as u64) < (b as u64));
+    1
+}
+
+// math.log (x [, base])
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.log
+fn math_log(ls: &mut dyn LuaAPI) -> usize {
+    let x = ls.check_number(1);
+    let res = if ls.is_none_or_nil(2) {
+        x.ln()
+    } else {
+        let base = ls.check_number(2);
+        if base == 2.0 {
+            x.log2()
+        } else if base == 10.0 {
+            x.log10()
+        } else {
+            x.ln() / base.ln()
+        }
+    };
+    ls.push_number(res);
+    1
+}
+
+// math.exp (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.exp
+fn math_exp(ls: &mut dyn LuaAPI) -> usize {
+    let x = ls.check_number(1);
+    ls.push_number(x.exp());
+    1
+}
+
+// math.deg (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.deg
+fn math_deg(ls: &mut dyn LuaAPI) -> usize {
+    let x = ls.check_number(1);
+    ls.push_number(x * (180.0 / PI));
+    1
+}
+
+// math.rad (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.rad
+fn math_rad(ls: &mut dyn LuaAPI) -> usize {
+    let x = ls.check_number(1);
+    ls.push_number(x * (PI / 180.0));
+    1
+}
+
+// math.min (x, ···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.min
+fn math_min(ls: &mut dyn LuaAPI) -> usize {
+    let n = ls.get_top(); // number of arguments
+    let mut imin = 1; // index of current minimum value
+    ls.arg_check(n >= 1, 1, "value expected");
+    for i in 2..=n {
+        if ls.compare(i, imin, LUA_OPLT) {
+            imin = i;
+        }
+    }
+    ls.push_value(imin);
+    1
+}
+
+// math.max (x, ···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.max
+fn math_max(ls: &mut dyn LuaAPI) -> usize {
+    let n = ls.get_top(); // number of arguments
+    let mut imax = 1; // index of current maximum value
+    ls.arg_check(n >= 1, 1, "value expected");
+    for i in 2..=n {
+        if ls.compare(imax, i, LUA_OPLT) {
+            imax = i;
+        }
+    }
+    ls.push_value(imax);
+    1
+}
+
+// math.type (x)
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.type
+fn math_type(ls: &mut dyn LuaAPI) -> usize {
+    if ls.type_id(1) == LUA_TNUMBER {
+        let name = if ls.is_integer(1) { "integer" } else { "float" };
+        ls.push_string(name.to_string());
+    } else {
+        ls.check_any(1);
+        ls.push_nil();
+    }
+    1
+}
+
+/*
+    Pseudo-Random Number Generator based on 'xoshiro256**', kept in a
+    userdata shared as upvalue by `random` and `randomseed`, so every
+    state has its own reproducible sequence.
+*/
+
+// math.random ([m [, n]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.random
+fn math_random(ls: &mut dyn LuaAPI) -> usize {
+    let rng = ls.to_userdata::<Xoshiro256>(upvalue_index(1)).unwrap();
+    let (low, up) = match ls.get_top() {
+        // check number of arguments
+        0 => {
+            // no arguments
+            let f = rng.borrow_mut().next_f64();
+            ls.push_number(f); // Number between 0 and 1
+            return 1;
+        }
+        1 => (1, ls.check_integer(1)), // only upper limit
+        2 => (ls.check_integer(1), ls.check_integer(2)), // lower and upper limits
+        _ => ls.error_l("wrong number of arguments"),
+    };
+    // random integer in the interval [low, up]
+    ls.arg_check(low <= up, 1, "interval is empty");
+    // project random integer into the interval [0, up - low]
+    let p = rng.borrow_mut().project((up as u64).wrapping_sub(low as u64));
+    ls.push_integer(p.wrapping_add(low as u64) as i64);
+    1
+}
+
+// math.randomseed ([x [, y]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-math.randomseed
+fn math_randomseed(ls: &mut dyn LuaAPI) -> usize {
+    let rng = ls.to_userdata::<Xoshiro256>(upvalue_index(1)).unwrap();
+    if ls.is_none(1) {
+        *rng.borrow_mut() = Xoshiro256::from_entropy();
+    } else {
+        let n1 = seed_arg(ls, 1);
+        let n2 = if ls.is_none_or_nil(2) { 0 } else { seed_arg(ls, 2) };
+        *rng.borrow_mut() = Xoshiro256::with_seeds(n1, n2);
+    }
+    0
+}
+
+// Seeds may be floats (e.g. `os.clock()`): integral values seed like the
+// equal integer, any other float seeds with its bit pattern.
+fn seed_arg(ls: &mut dyn LuaAPI, arg: isize) -> u64 {
+    let n = ls.check_number(arg);
+    match ls.to_integerx(arg) {
+        Some(i) => i as u64,
+        None => n.to_bits(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LuaState;
+    use crate::stdlib::base::open_base;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /* Lua source code:
+        assert(math.floor(3.7) == 3 and math.type(math.floor(-3.2)) == "integer" and math.floor(-3.2) == -4)
+        assert(math.type(math.floor(2^63)) == "float" and math.ceil(3.2) == 4 and math.ceil(5) == 5)
+        assert(math.fmod(5, -1) == 0 and math.fmod(-6, 4) == -2 and math.fmod(5.5, 2) == 1.5)
+        assert(math.fmod(math.mininteger, -1) == 0 and select(2, pcall(math.fmod, 5, 0)) == "bad argument #2 to 'math.fmod' (zero)")
+        local ip, fp = math.modf(-3.5)
+        assert(ip == -3 and math.type(ip) == "integer" and fp == -0.5 and select(2, math.modf(1/0)) == 0.0)
+        assert(math.tointeger(3.0) == 3 and math.tointeger(3.5) == nil and math.tointeger({}) == nil)
+        assert(math.type(1) == "integer" and math.type(1.0) == "float" and math.type("1") == nil)
+        assert(select(2, pcall(math.type)) == "bad argument #1 to 'math.type' (value expected)")
+        assert(math.ult(1, -1) and not math.ult(-1, 1) and math.abs(math.mininteger) == math.mininteger and math.abs(-2.5) == 2.5)
+        assert(math.max(1, 2.5, -1) == 2.5 and math.min(3, 1, 2) == 1 and math.max("a", "b") == "b")
+        assert(select(2, pcall(math.max)) == "bad argument #1 to 'math.max' (value expected)")
+        assert(math.log(8, 2) == 3.0 and math.log(100, 10) == 2.0 and math.log(1) == 0.0 and math.exp(0) == 1.0 and math.sqrt(16) == 4.0)
+        assert(math.atan(1, 1) == math.pi / 4 and math.deg(math.pi) == 180.0 and math.rad(180) == math.pi)
+        assert(math.huge > 1e308 and -math.huge < -1e308 and math.maxinteger + 1 == math.mininteger)
+        assert(math.mininteger * -1 == math.mininteger and math.mininteger // -1 == math.mininteger and math.mininteger % -1 == 0)
+        assert(select(2, pcall(math.random, 0)) == "bad argument #1 to 'math.random' (interval is empty)")
+        assert(select(2, pcall(math.random, 3, 1)) == "bad argument #1 to 'math.random' (interval is empty)")
+        assert(select(2, pcall(math.random, 1, 2, 3)) == "wrong number of arguments")
+        for _ = 1, 100 do
+          local f = math.random()
+          assert(math.type(f) == "float" and f >= 0 and f < 1)
+          local a, b = math.random(6), math.random(-3, 3)
+          assert(a >= 1 and a <= 6 and b >= -3 and b <= 3)
+          local c = math.random(math.mininteger // 2, math.maxinteger // 2)
+          assert(math.type(c) == "integer")
+        end
+        local function seq()
+          local s = ""
+          for _ = 1, 10 do s = s .. math.random(1000) .. "," end
+          return s
+        end
+        math.randomseed(42)
+        local s1 = seq()
+        math.randomseed(42)
+        assert(seq() == s1)
+        math.randomseed(42.0)
+        assert(seq() == s1)
+    */
+    const LUA_MATH_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x07, 0x40,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x0d, 0xf6, 0x01, 0x00, 0x00, 0x06, 0x00, 0x40, 0x00,
+        0x46, 0x40, 0x40, 0x00, 0x47, 0x80, 0xc0, 0x00, 0x81, 0xc0, 0x00, 0x00,
+        0x64, 0x80, 0x00, 0x01, 0x1f, 0x00, 0xc1, 0x00, 0x1e, 0x80, 0x03, 0x80,
+        0x46, 0x40, 0x40, 0x00, 0x47, 0x40, 0xc1, 0x00, 0x86, 0x40, 0x40, 0x00,
+        0x87, 0x80, 0x40, 0x01, 0xc1, 0x80, 0x01, 0x00, 0xa4, 0x00, 0x00, 0x01,
+        0x64, 0x80, 0x00, 0x00, 0x1f, 0xc0, 0xc1, 0x00, 0x1e, 0x40, 0x01, 0x80,
+        0x46, 0x40, 0x40, 0x00, 0x47, 0x80, 0xc0, 0x00, 0x81, 0x80, 0x01, 0x00,
+        0x64, 0x80, 0x00, 0x01, 0x5f, 0x00, 0xc2, 0x00, 0x1e, 0x00, 0x00, 0x80,
+        0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01,
+        0x06, 0x00, 0x40, 0x00, 0x46, 0x40, 0x40, 0x00, 0x47, 0x40, 0xc1, 0x00,
+        0x86, 0x40, 0x40, 0x00, 0x87, 0x80, 0x40, 0x01, 0xc1, 0x40, 0x02, 0x00,
+        0xa4, 0x00, 0x00, 0x01, 0x64, 0x80, 0x00, 0x00, 0x1f, 0x80, 0xc2, 0x00,
+        0x1e, 0xc0, 0x02, 0x80, 0x46, 0x40, 0x40, 0x00, 0x47, 0xc0, 0xc2, 0x00,
+        0x81, 0x00, 0x03, 0x00, 0x64, 0x80, 0x00, 0x01, 0x1f, 0x40, 0xc3, 0x00,
+        0x1e, 0x40, 0x01, 0x80, 0x46, 0x40, 0x40, 0x00, 0x47, 0xc0, 0xc2, 0x00,
+        0x81, 0x80, 0x03, 0x00, 0x64, 0x80, 0x00, 0x01, 0x5f, 0x80, 0xc3, 0x00,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00,
+        0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40, 0x00, 0x46, 0x40, 0x40, 0x00,
+        0x47, 0xc0, 0xc3, 0x00, 0x81, 0x80, 0x03, 0x00, 0xc1, 0x00, 0x04, 0x00,
+        0x64, 0x80, 0x80, 0x01, 0x1f, 0x40, 0xc4, 0x00, 0x1e, 0x40, 0x03, 0x80,
+        0x46, 0x40, 0x40, 0x00, 0x47, 0xc0, 0xc3, 0x00, 0x81, 0x80, 0x04, 0x00,
+        0xc1, 0x40, 0x03, 0x00, 0x64, 0x80, 0x80, 0x01, 0x1f, 0xc0, 0xc4, 0x00,
+        0x1e, 0x80, 0x01, 0x80, 0x46, 0x40, 0x40, 0x00, 0x47, 0xc0, 0xc3, 0x00,
+        0x81, 0x00, 0x05, 0x00, 0xc1, 0x40, 0x05, 0x00, 0x64, 0x80, 0x80, 0x01,
+        0x5f, 0x80, 0xc5, 0x00, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00,
+        0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40, 0x00,
+        0x46, 0x40, 0x40, 0x00, 0x47, 0xc0, 0xc3, 0x00, 0x86, 0x40, 0x40, 0x00,
+        0x87, 0xc0, 0x45, 0x01, 0xc1, 0x00, 0x04, 0x00, 0x64, 0x80, 0x80, 0x01,
+        0x1f, 0x40, 0xc4, 0x00, 0x1e, 0x80, 0x02, 0x80, 0x46, 0x00, 0x46, 0x00,
+        0x81, 0x40, 0x05, 0x00, 0xc6, 0x40, 0x46, 0x00, 0x06, 0x41, 0x40, 0x00,
+        0x07, 0xc1, 0x43, 0x02, 0x41, 0x81, 0x03, 0x00, 0x81, 0x41, 0x04, 0x00,
+        0xe4, 0x00, 0x00, 0x02, 0x64, 0x80, 0x00, 0x00, 0x5f, 0x80, 0xc6, 0x00,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00,
+        0x24, 0x40, 0x00, 0x01, 0x06, 0x40, 0x40, 0x00, 0x07, 0xc0, 0x46, 0x00,
+        0x41, 0x00, 0x07, 0x00, 0x24, 0xc0, 0x00, 0x01, 0x86, 0x00, 0x40, 0x00,
+        0x1f, 0x40, 0x47, 0x00, 0x1e, 0x00, 0x04, 0x80, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0x40, 0xc1, 0x01, 0x00, 0x01, 0x00, 0x00, 0xe4, 0x80, 0x00, 0x01,
+        0x1f, 0xc0, 0xc1, 0x01, 0x1e, 0x80, 0x02, 0x80, 0x1f, 0x80, 0xc7, 0x00,
+        0x1e, 0x00, 0x02, 0x80, 0xc6, 0x00, 0x46, 0x00, 0x01, 0x41, 0x05, 0x00,
+        0x46, 0x41, 0x40, 0x00, 0x47, 0xc1, 0xc6, 0x02, 0x92, 0x41, 0xc4, 0x8f,
+        0x64, 0x01, 0x00, 0x01, 0xe4, 0x80, 0x00, 0x00, 0x5f, 0x00, 0xc8, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0x00, 0x40, 0x00, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0x40, 0xc8, 0x01, 0x01, 0x81, 0x08, 0x00, 0xe4, 0x80, 0x00, 0x01,
+        0x1f, 0x00, 0xc1, 0x01, 0x1e, 0xc0, 0x02, 0x80, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0x40, 0xc8, 0x01, 0x01, 0xc1, 0x08, 0x00, 0xe4, 0x80, 0x00, 0x01,
+        0x1f, 0x00, 0xc9, 0x01, 0x1e, 0x40, 0x01, 0x80, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0x40, 0xc8, 0x01, 0x0b, 0x01, 0x00, 0x00, 0xe4, 0x80, 0x00, 0x01,
+        0x5f, 0x00, 0xc9, 0x01, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00,
+        0xc3, 0x00, 0x80, 0x00, 0xa4, 0x40, 0x00, 0x01, 0x86, 0x00, 0x40, 0x00,
+        0xc6, 0x40, 0x40, 0x00, 0xc7, 0x40, 0xc1, 0x01, 0x01, 0xc1, 0x07, 0x00,
+        0xe4, 0x80, 0x00, 0x01, 0x1f, 0xc0, 0xc1, 0x01, 0x1e, 0xc0, 0x02, 0x80,
+        0xc6, 0x40, 0x40, 0x00, 0xc7, 0x40, 0xc1, 0x01, 0x01, 0x41, 0x09, 0x00,
+        0xe4, 0x80, 0x00, 0x01, 0x1f, 0x80, 0xc2, 0x01, 0x1e, 0x40, 0x01, 0x80,
+        0xc6, 0x40, 0x40, 0x00, 0xc7, 0x40, 0xc1, 0x01, 0x01, 0x81, 0x09, 0x00,
+        0xe4, 0x80, 0x00, 0x01, 0x5f, 0x00, 0xc9, 0x01, 0x1e, 0x00, 0x00, 0x80,
+        0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00, 0xa4, 0x40, 0x00, 0x01,
+        0x86, 0x00, 0x40, 0x00, 0xc6, 0x00, 0x46, 0x00, 0x01, 0x41, 0x05, 0x00,
+        0x46, 0x41, 0x46, 0x00, 0x86, 0x41, 0x40, 0x00, 0x87, 0x41, 0x41, 0x03,
+        0x64, 0x01, 0x00, 0x01, 0xe4, 0x80, 0x00, 0x00, 0x5f, 0xc0, 0xc9, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0x00, 0x40, 0x00, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0x00, 0xca, 0x01, 0x01, 0xc1, 0x07, 0x00, 0x41, 0x01, 0x04, 0x00,
+        0xe4, 0x80, 0x80, 0x01, 0xe2, 0x00, 0x00, 0x00, 0x1e, 0xc0, 0x05, 0x80,
+        0xc6, 0x40, 0x40, 0x00, 0xc7, 0x00, 0xca, 0x01, 0x01, 0x01, 0x04, 0x00,
+        0x41, 0xc1, 0x07, 0x00, 0xe4, 0x80, 0x80, 0x01, 0xe2, 0x40, 0x00, 0x00,
+        0x1e, 0x80, 0x03, 0x80, 0xc6, 0x40, 0x40, 0x00, 0xc7, 0x40, 0xca, 0x01,
+        0x06, 0x41, 0x40, 0x00, 0x07, 0xc1, 0x45, 0x02, 0xe4, 0x80, 0x00, 0x01,
+        0x06, 0x41, 0x40, 0x00, 0x07, 0xc1, 0x45, 0x02, 0x1f, 0x00, 0x81, 0x01,
+        0x1e, 0x40, 0x01, 0x80, 0xc6, 0x40, 0x40, 0x00, 0xc7, 0x40, 0xca, 0x01,
+        0x01, 0x81, 0x0a, 0x00, 0xe4, 0x80, 0x00, 0x01, 0x5f, 0xc0, 0xca, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0x00, 0x40, 0x00, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0x00, 0xcb, 0x01, 0x01, 0xc1, 0x07, 0x00, 0x41, 0xc1, 0x0a, 0x00,
+        0x81, 0x01, 0x04, 0x00, 0xe4, 0x80, 0x00, 0x02, 0x1f, 0xc0, 0xca, 0x01,
+        0x1e, 0x80, 0x03, 0x80, 0xc6, 0x40, 0x40, 0x00, 0xc7, 0x40, 0xcb, 0x01,
+        0x01, 0x01, 0x01, 0x00, 0x41, 0xc1, 0x07, 0x00, 0x81, 0x41, 0x05, 0x00,
+        0xe4, 0x80, 0x00, 0x02, 0x1f, 0xc0, 0xc7, 0x01, 0x1e, 0x80, 0x01, 0x80,
+        0xc6, 0x40, 0x40, 0x00, 0xc7, 0x00, 0xcb, 0x01, 0x01, 0x81, 0x0b, 0x00,
+        0x41, 0xc1, 0x0b, 0x00, 0xe4, 0x80, 0x80, 0x01, 0x5f, 0xc0, 0xcb, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0x00, 0x40, 0x00, 0xc6, 0x00, 0x46, 0x00,
+        0x01, 0x41, 0x05, 0x00, 0x46, 0x41, 0x46, 0x00, 0x86, 0x41, 0x40, 0x00,
+        0x87, 0x01, 0x4b, 0x03, 0x64, 0x01, 0x00, 0x01, 0xe4, 0x80, 0x00, 0x00,
+        0x5f, 0x00, 0xcc, 0x01, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00,
+        0xc3, 0x00, 0x80, 0x00, 0xa4, 0x40, 0x00, 0x01, 0x86, 0x00, 0x40, 0x00,
+        0xc6, 0x40, 0x40, 0x00, 0xc7, 0x40, 0xcc, 0x01, 0x01, 0x81, 0x0c, 0x00,
+        0x41, 0x41, 0x05, 0x00, 0xe4, 0x80, 0x80, 0x01, 0x1f, 0x80, 0xc8, 0x01,
+        0x1e, 0x00, 0x06, 0x80, 0xc6, 0x40, 0x40, 0x00, 0xc7, 0x40, 0xcc, 0x01,
+        0x01, 0xc1, 0x0c, 0x00, 0x41, 0x01, 0x0d, 0x00, 0xe4, 0x80, 0x80, 0x01,
+        0x1f, 0x40, 0xcd, 0x01, 0x1e, 0x40, 0x04, 0x80, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0x40, 0xcc, 0x01, 0x01, 0xc1, 0x07, 0x00, 0xe4, 0x80, 0x00, 0x01,
+        0x1f, 0x00, 0xc8, 0x01, 0x1e, 0xc0, 0x02, 0x80, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0x80, 0xcd, 0x01, 0x01, 0x41, 0x04, 0x00, 0xe4, 0x80, 0x00, 0x01,
+        0x1f, 0x40, 0xc9, 0x01, 0x1e, 0x40, 0x01, 0x80, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0xc0, 0xcd, 0x01, 0x01, 0x01, 0x0e, 0x00, 0xe4, 0x80, 0x00, 0x01,
+        0x5f, 0x40, 0xce, 0x01, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00,
+        0xc3, 0x00, 0x80, 0x00, 0xa4, 0x40, 0x00, 0x01, 0x86, 0x00, 0x40, 0x00,
+        0xc6, 0x40, 0x40, 0x00, 0xc7, 0x80, 0xce, 0x01, 0x01, 0xc1, 0x07, 0x00,
+        0x41, 0xc1, 0x07, 0x00, 0xe4, 0x80, 0x80, 0x01, 0x06, 0x41, 0x40, 0x00,
+        0x07, 0xc1, 0x4e, 0x02, 0x12, 0x41, 0x43, 0x02, 0x1f, 0x00, 0x81, 0x01,
+        0x1e, 0x80, 0x03, 0x80, 0xc6, 0x40, 0x40, 0x00, 0xc7, 0x00, 0xcf, 0x01,
+        0x06, 0x41, 0x40, 0x00, 0x07, 0xc1, 0x4e, 0x02, 0xe4, 0x80, 0x00, 0x01,
+        0x1f, 0x40, 0xcf, 0x01, 0x1e, 0xc0, 0x01, 0x80, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0x80, 0xcf, 0x01, 0x01, 0xc1, 0x0f, 0x00, 0xe4, 0x80, 0x00, 0x01,
+        0x06, 0x41, 0x40, 0x00, 0x07, 0xc1, 0x4e, 0x02, 0x5f, 0x00, 0x81, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0x00, 0x40, 0x00, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0x00, 0xd0, 0x01, 0x20, 0xc0, 0x80, 0xa0, 0x1e, 0xc0, 0x02, 0x80,
+        0xc6, 0x40, 0x40, 0x00, 0xc7, 0x00, 0xd0, 0x01, 0xd9, 0x00, 0x80, 0x01,
+        0x20, 0x80, 0xd0, 0x01, 0x1e, 0x80, 0x01, 0x80, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0xc0, 0xd0, 0x01, 0xcd, 0xc0, 0xc7, 0x01, 0x06, 0x41, 0x40, 0x00,
+        0x07, 0xc1, 0x45, 0x02, 0x5f, 0x00, 0x81, 0x01, 0x1e, 0x00, 0x00, 0x80,
+        0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00, 0xa4, 0x40, 0x00, 0x01,
+        0x86, 0x00, 0x40, 0x00, 0xc6, 0x40, 0x40, 0x00, 0xc7, 0xc0, 0xc5, 0x01,
+        0xcf, 0x00, 0xc4, 0x01, 0x06, 0x41, 0x40, 0x00, 0x07, 0xc1, 0x45, 0x02,
+        0x1f, 0x00, 0x81, 0x01, 0x1e, 0xc0, 0x02, 0x80, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0xc0, 0xc5, 0x01, 0xd3, 0x00, 0xc4, 0x01, 0x06, 0x41, 0x40, 0x00,
+        0x07, 0xc1, 0x45, 0x02, 0x1f, 0x00, 0x81, 0x01, 0x1e, 0x00, 0x01, 0x80,
+        0xc6, 0x40, 0x40, 0x00, 0xc7, 0xc0, 0xc5, 0x01, 0xd0, 0x00, 0xc4, 0x01,
+        0x5f, 0x40, 0xc4, 0x01, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00,
+        0xc3, 0x00, 0x80, 0x00, 0xa4, 0x40, 0x00, 0x01, 0x86, 0x00, 0x40, 0x00,
+        0xc6, 0x00, 0x46, 0x00, 0x01, 0x41, 0x05, 0x00, 0x46, 0x41, 0x46, 0x00,
+        0x86, 0x41, 0x40, 0x00, 0x87, 0x01, 0x51, 0x03, 0xc1, 0x41, 0x04, 0x00,
+        0x64, 0x01, 0x80, 0x01, 0xe4, 0x80, 0x00, 0x00, 0x5f, 0x40, 0xd1, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0x00, 0x40, 0x00, 0xc6, 0x00, 0x46, 0x00,
+        0x01, 0x41, 0x05, 0x00, 0x46, 0x41, 0x46, 0x00, 0x86, 0x41, 0x40, 0x00,
+        0x87, 0x01, 0x51, 0x03, 0xc1, 0x01, 0x01, 0x00, 0x01, 0xc2, 0x07, 0x00,
+        0x64, 0x01, 0x00, 0x02, 0xe4, 0x80, 0x00, 0x00, 0x5f, 0x40, 0xd1, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0x00, 0x40, 0x00, 0xc6, 0x00, 0x46, 0x00,
+        0x01, 0x41, 0x05, 0x00, 0x46, 0x41, 0x46, 0x00, 0x86, 0x41, 0x40, 0x00,
+        0x87, 0x01, 0x51, 0x03, 0xc1, 0xc1, 0x07, 0x00, 0x01, 0x42, 0x05, 0x00,
+        0x41, 0x02, 0x01, 0x00, 0x64, 0x01, 0x80, 0x02, 0xe4, 0x80, 0x00, 0x00,
+        0x5f, 0x80, 0xd1, 0x01, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00,
+        0xc3, 0x00, 0x80, 0x00, 0xa4, 0x40, 0x00, 0x01, 0x81, 0xc0, 0x07, 0x00,
+        0xc1, 0xc0, 0x0c, 0x00, 0x01, 0xc1, 0x07, 0x00, 0xa8, 0x00, 0x0e, 0x80,
+        0x86, 0x41, 0x40, 0x00, 0x87, 0x01, 0x51, 0x03, 0xa4, 0x81, 0x80, 0x00,
+        0xc6, 0x01, 0x40, 0x00, 0x06, 0x42, 0x40, 0x00, 0x07, 0x42, 0x41, 0x04,
+        0x40, 0x02, 0x00, 0x03, 0x24, 0x82, 0x00, 0x01, 0x1f, 0x80, 0x42, 0x04,
+        0x1e, 0xc0, 0x00, 0x80, 0x21, 0x80, 0x81, 0x88, 0x1e, 0x40, 0x00, 0x80,
+        0x60, 0xc0, 0x47, 0x03, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x42, 0x00, 0x00,
+        0x03, 0x02, 0x80, 0x00, 0xe4, 0x41, 0x00, 0x01, 0xc6, 0x41, 0x40, 0x00,
+        0xc7, 0x01, 0xd1, 0x03, 0x01, 0xc2, 0x11, 0x00, 0xe4, 0x81, 0x00, 0x01,
+        0x06, 0x42, 0x40, 0x00, 0x07, 0x02, 0x51, 0x04, 0x41, 0x42, 0x07, 0x00,
+        0x81, 0x02, 0x01, 0x00, 0x24, 0x82, 0x80, 0x01, 0x46, 0x02, 0x40, 0x00,
+        0x21, 0xc0, 0x81, 0x8f, 0x1e, 0x40, 0x01, 0x80, 0x21, 0xc0, 0xd1, 0x03,
+        0x1e, 0xc0, 0x00, 0x80, 0x21, 0x00, 0x82, 0x8e, 0x1e, 0x40, 0x00, 0x80,
+        0x61, 0x00, 0x41, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00,
+        0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01, 0x46, 0x42, 0x40, 0x00,
+        0x47, 0x02, 0xd1, 0x04, 0x86, 0x42, 0x40, 0x00, 0x87, 0xc2, 0x45, 0x05,
+        0x93, 0x42, 0x45, 0x05, 0xc6, 0x42, 0x40, 0x00, 0xc7, 0xc2, 0xd0, 0x05,
+        0xd3, 0x42, 0xc5, 0x05, 0x64, 0x82, 0x80, 0x01, 0x86, 0x02, 0x40, 0x00,
+        0xc6, 0x42, 0x40, 0x00, 0xc7, 0x42, 0xc1, 0x05, 0x00, 0x03, 0x80, 0x04,
+        0xe4, 0x82, 0x00, 0x01, 0x5f, 0xc0, 0xc1, 0x05, 0x1e, 0x00, 0x00, 0x80,
+        0xc3, 0x42, 0x00, 0x00, 0xc3, 0x02, 0x80, 0x00, 0xa4, 0x42, 0x00, 0x01,
+        0xa7, 0x40, 0xf1, 0x7f, 0xac, 0x00, 0x00, 0x00, 0xc6, 0x00, 0x52, 0x00,
+        0xc7, 0x40, 0xd2, 0x01, 0x01, 0x81, 0x12, 0x00, 0xe4, 0x40, 0x00, 0x01,
+        0xc0, 0x00, 0x00, 0x01, 0xe4, 0x80, 0x80, 0x00, 0x06, 0x01, 0x52, 0x00,
+        0x07, 0x41, 0x52, 0x02, 0x41, 0x81, 0x12, 0x00, 0x24, 0x41, 0x00, 0x01,
+        0x06, 0x01, 0x40, 0x00, 0x40, 0x01, 0x00, 0x01, 0x64, 0x81, 0x80, 0x00,
+        0x5f, 0xc0, 0x80, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x41, 0x00, 0x00,
+        0x43, 0x01, 0x80, 0x00, 0x24, 0x41, 0x00, 0x01, 0x06, 0x01, 0x52, 0x00,
+        0x07, 0x41, 0x52, 0x02, 0x41, 0xc1, 0x12, 0x00, 0x24, 0x41, 0x00, 0x01,
+        0x06, 0x01, 0x40, 0x00, 0x40, 0x01, 0x00, 0x01, 0x64, 0x81, 0x80, 0x00,
+        0x5f, 0xc0, 0x80, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x41, 0x00, 0x00,
+        0x43, 0x01, 0x80, 0x00, 0x24, 0x41, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00,
+        0x4c, 0x00, 0x00, 0x00, 0x04, 0x07, 0x61, 0x73, 0x73, 0x65, 0x72, 0x74,
+        0x04, 0x05, 0x6d, 0x61, 0x74, 0x68, 0x04, 0x06, 0x66, 0x6c, 0x6f, 0x6f,
+        0x72, 0x03, 0x9a, 0x99, 0x99, 0x99, 0x99, 0x99, 0x0d, 0x40, 0x13, 0x03,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x05, 0x74, 0x79, 0x70,
+        0x65, 0x03, 0x9a, 0x99, 0x99, 0x99, 0x99, 0x99, 0x09, 0xc0, 0x04, 0x08,
+        0x69, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x13, 0xfc, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe0,
+        0x43, 0x04, 0x06, 0x66, 0x6c, 0x6f, 0x61, 0x74, 0x04, 0x05, 0x63, 0x65,
+        0x69, 0x6c, 0x03, 0x9a, 0x99, 0x99, 0x99, 0x99, 0x99, 0x09, 0x40, 0x13,
+        0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x05, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x05, 0x66, 0x6d, 0x6f, 0x64, 0x13,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x13, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0xfa, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0x13, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x03,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x16, 0x40, 0x13, 0x02, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xf8, 0x3f, 0x04, 0x0b, 0x6d, 0x69, 0x6e, 0x69, 0x6e, 0x74, 0x65, 0x67,
+        0x65, 0x72, 0x04, 0x07, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x04, 0x06,
+        0x70, 0x63, 0x61, 0x6c, 0x6c, 0x04, 0x26, 0x62, 0x61, 0x64, 0x20, 0x61,
+        0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x32, 0x20, 0x74,
+        0x6f, 0x20, 0x27, 0x6d, 0x61, 0x74, 0x68, 0x2e, 0x66, 0x6d, 0x6f, 0x64,
+        0x27, 0x20, 0x28, 0x7a, 0x65, 0x72, 0x6f, 0x29, 0x04, 0x05, 0x6d, 0x6f,
+        0x64, 0x66, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0xc0, 0x13,
+        0xfd, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x03, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0xe0, 0xbf, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x0a, 0x74, 0x6f, 0x69, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x40, 0x03, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x0c, 0x40, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xf0, 0x3f, 0x04, 0x02, 0x31, 0x14, 0x30, 0x62, 0x61, 0x64, 0x20, 0x61,
+        0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x31, 0x20, 0x74,
+        0x6f, 0x20, 0x27, 0x6d, 0x61, 0x74, 0x68, 0x2e, 0x74, 0x79, 0x70, 0x65,
+        0x27, 0x20, 0x28, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x65, 0x78, 0x70,
+        0x65, 0x63, 0x74, 0x65, 0x64, 0x29, 0x04, 0x04, 0x75, 0x6c, 0x74, 0x04,
+        0x04, 0x61, 0x62, 0x73, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0xc0, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x40, 0x04, 0x04,
+        0x6d, 0x61, 0x78, 0x04, 0x04, 0x6d, 0x69, 0x6e, 0x04, 0x02, 0x61, 0x04,
+        0x02, 0x62, 0x14, 0x2f, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75,
+        0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x31, 0x20, 0x74, 0x6f, 0x20, 0x27,
+        0x6d, 0x61, 0x74, 0x68, 0x2e, 0x6d, 0x61, 0x78, 0x27, 0x20, 0x28, 0x76,
+        0x61, 0x6c, 0x75, 0x65, 0x20, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65,
+        0x64, 0x29, 0x04, 0x04, 0x6c, 0x6f, 0x67, 0x13, 0x08, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x13, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x13, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x04, 0x04, 0x65, 0x78, 0x70,
+        0x04, 0x05, 0x73, 0x71, 0x72, 0x74, 0x13, 0x10, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x40,
+        0x04, 0x05, 0x61, 0x74, 0x61, 0x6e, 0x04, 0x03, 0x70, 0x69, 0x04, 0x04,
+        0x64, 0x65, 0x67, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x66, 0x40,
+        0x04, 0x04, 0x72, 0x61, 0x64, 0x13, 0xb4, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x04, 0x05, 0x68, 0x75, 0x67, 0x65, 0x03, 0xa0, 0xc8, 0xeb,
+        0x85, 0xf3, 0xcc, 0xe1, 0x7f, 0x03, 0xa0, 0xc8, 0xeb, 0x85, 0xf3, 0xcc,
+        0xe1, 0xff, 0x04, 0x0b, 0x6d, 0x61, 0x78, 0x69, 0x6e, 0x74, 0x65, 0x67,
+        0x65, 0x72, 0x04, 0x07, 0x72, 0x61, 0x6e, 0x64, 0x6f, 0x6d, 0x14, 0x35,
+        0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74,
+        0x20, 0x23, 0x31, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x6d, 0x61, 0x74, 0x68,
+        0x2e, 0x72, 0x61, 0x6e, 0x64, 0x6f, 0x6d, 0x27, 0x20, 0x28, 0x69, 0x6e,
+        0x74, 0x65, 0x72, 0x76, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x65, 0x6d,
+        0x70, 0x74, 0x79, 0x29, 0x04, 0x1a, 0x77, 0x72, 0x6f, 0x6e, 0x67, 0x20,
+        0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x72,
+        0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x13, 0x06, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x05, 0x6d, 0x61, 0x74, 0x68, 0x04, 0x0b,
+        0x72, 0x61, 0x6e, 0x64, 0x6f, 0x6d, 0x73, 0x65, 0x65, 0x64, 0x13, 0x2a,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x45, 0x40, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x08, 0x0f, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x41, 0x40,
+        0x00, 0x00, 0x81, 0x80, 0x00, 0x00, 0xc1, 0x40, 0x00, 0x00, 0x68, 0x80,
+        0x01, 0x80, 0x40, 0x01, 0x00, 0x00, 0x86, 0xc1, 0x40, 0x00, 0x87, 0x01,
+        0x41, 0x03, 0xc1, 0x41, 0x01, 0x00, 0xa4, 0x81, 0x00, 0x01, 0xc1, 0x81,
+        0x01, 0x00, 0x1d, 0xc0, 0x81, 0x02, 0x67, 0xc0, 0xfd, 0x7f, 0x26, 0x00,
+        0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x07, 0x00, 0x00, 0x00, 0x04, 0x01,
+        0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x0a, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x05, 0x6d, 0x61, 0x74, 0x68,
+        0x04, 0x07, 0x72, 0x61, 0x6e, 0x64, 0x6f, 0x6d, 0x13, 0xe8, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x2c, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x02, 0x73, 0x01, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00,
+        0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x29,
+        0x04, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f,
+        0x72, 0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x29, 0x04, 0x00, 0x00, 0x00,
+        0x0d, 0x00, 0x00, 0x00, 0x0b, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74,
+        0x65, 0x70, 0x29, 0x04, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x02,
+        0x5f, 0x05, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56, 0xf6, 0x01, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x18, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x19, 0x00,
+        0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00,
+        0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00,
+        0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x21, 0x00,
+        0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00,
+        0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x23, 0x00,
+        0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00,
+        0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00,
+        0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00,
+        0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00,
+        0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00,
+        0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x03, 0x69, 0x70, 0x66, 0x00, 0x00,
+        0x00, 0xf6, 0x01, 0x00, 0x00, 0x03, 0x66, 0x70, 0x66, 0x00, 0x00, 0x00,
+        0xf6, 0x01, 0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x69, 0x6e,
+        0x64, 0x65, 0x78, 0x29, 0x9b, 0x01, 0x00, 0x00, 0xd6, 0x01, 0x00, 0x00,
+        0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x29,
+        0x9b, 0x01, 0x00, 0x00, 0xd6, 0x01, 0x00, 0x00, 0x0b, 0x28, 0x66, 0x6f,
+        0x72, 0x20, 0x73, 0x74, 0x65, 0x70, 0x29, 0x9b, 0x01, 0x00, 0x00, 0xd6,
+        0x01, 0x00, 0x00, 0x02, 0x5f, 0x9c, 0x01, 0x00, 0x00, 0xd5, 0x01, 0x00,
+        0x00, 0x02, 0x66, 0x9f, 0x01, 0x00, 0x00, 0xd5, 0x01, 0x00, 0x00, 0x02,
+        0x61, 0xb6, 0x01, 0x00, 0x00, 0xd5, 0x01, 0x00, 0x00, 0x02, 0x62, 0xb6,
+        0x01, 0x00, 0x00, 0xd5, 0x01, 0x00, 0x00, 0x02, 0x63, 0xcb, 0x01, 0x00,
+        0x00, 0xd5, 0x01, 0x00, 0x00, 0x04, 0x73, 0x65, 0x71, 0xd7, 0x01, 0x00,
+        0x00, 0xf6, 0x01, 0x00, 0x00, 0x03, 0x73, 0x31, 0xdd, 0x01, 0x00, 0x00,
+        0xf6, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e,
+        0x56,
+    ];
+
+    #[test]
+    fn test_math_lib() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        ls.requiref("_G", open_base, true);
+        ls.requiref("math", open_math, true);
+        ls.pop(2);
+
+        ls.load(LUA_MATH_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 0, 0);
+        assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+    }
+}
@@ -0,0 +1,41 @@
+pub mod base;
+pub mod debug;
+pub mod io;
+pub mod json;
+pub mod math;
+pub mod os;
+mod pack;
+pub mod package;
+pub mod sandbox;
+mod pattern;
+pub mod string;
+pub mod table;
+pub mod utf8;
+
+use crate::api::{LuaAPI, LuaAuxLib, RustFn};
+
+// Libraries that only compute, and so are safe to give to any script.
+const LOADED_LIBS: &[(&str, RustFn)] = &[
+    ("_G", base::open_base),
+    ("table", table::open_table),
+    ("string", string::open_string),
+    ("math", math::open_math),
+    ("utf8", utf8::open_utf8),
+    ("json", json::open_json),
+];
+
+/*
+    Open the standard libraries that cannot reach outside the interpreter.
+    `io`, `os` and `package` give access to files, the environment and the
+    process, and `debug` to the internals of any function, so each state
+    opts in to them explicitly (`dofile` comes with `io`):
+        ls.requiref("io", open_io, true);
+    Scripts that must not even share globals get a table from
+    `sandbox::new_safe_env` as their environment instead.
+*/
+pub fn open_libs(ls: &mut dyn LuaAPI) {
+    for (name, openf) in LOADED_LIBS {
+        ls.requiref(name, *openf, true);
+        ls.pop(1); // remove lib
+    }
+}
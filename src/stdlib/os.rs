@@ -0,0 +1,601 @@
+use crate::api::consts::*;
+use crate::api::{LuaAPI, LuaAuxLib, RustFn};
+use crate::number::math;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SYS_FUNCS: &[(&str, RustFn)] = &[
+    ("clock", os_clock),
+    ("date", os_date),
+    ("difftime", os_difftime),
+    ("exit", os_exit),
+    ("getenv", os_getenv),
+    ("remove", os_remove),
+    ("rename", os_rename),
+    ("time", os_time),
+    ("tmpname", os_tmpname),
+];
+
+/*
+    Like `io`, the `os` library reaches outside the interpreter (files,
+    environment, process exit), so a state only gets it when the host
+    opens it with `requiref("os", open_os, true)`.
+*/
+pub fn open_os(ls: &mut dyn LuaAPI) -> usize {
+    ls.new_lib(SYS_FUNCS);
+    1
+}
+
+// os.getenv (varname)
+// http://www.lua.org/manual/5.3/manual.html#pdf-os.getenv
+fn os_getenv(ls: &mut dyn LuaAPI) -> usize {
+    let name = ls.check_string(1);
+    match env::var_os(&name) {
+        Some(v) => ls.push_string(v.to_string_lossy().into_owned()),
+        None => ls.push_nil(), // if NULL push nil
+    }
+    1
+}
+
+// os.remove (filename)
+// http://www.lua.org/manual/5.3/manual.html#pdf-os.remove
+fn os_remove(ls: &mut dyn LuaAPI) -> usize {
+    let filename = ls.check_string(1);
+    // like C `remove`, also removes empty directories
+    let res = match fs::symlink_metadata(&filename) {
+        Ok(md) if md.is_dir() => fs::remove_dir(&filename),
+        _ => fs::remove_file(&filename),
+    };
+    ls.file_result(res, Some(&filename))
+}
+
+// os.rename (oldname, newname)
+// http://www.lua.org/manual/5.3/manual.html#pdf-os.rename
+fn os_rename(ls: &mut dyn LuaAPI) -> usize {
+    let fromname = ls.check_string(1);
+    let toname = ls.check_string(2);
+    let res = fs::rename(&fromname, &toname);
+    ls.file_result(res, Some(&fromname))
+}
+
+// os.tmpname ()
+// http://www.lua.org/manual/5.3/manual.html#pdf-os.tmpname
+fn os_tmpname(ls: &mut dyn LuaAPI) -> usize {
+    const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+    for _ in 0..100 {
+        // create the file, as `mkstemp` does, so the name is really taken
+        let suffix: String = (0..6)
+            .map(|_| CHARS[math::random() % CHARS.len()] as char)
+            .collect();
+        let path = env::temp_dir().join(format!("lua_{}", suffix));
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => {
+                ls.push_string(path.to_string_lossy().into_owned());
+                return 1;
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(_) => break,
+        }
+    }
+    ls.error_l("unable to generate a unique filename")
+}
+
+// os.clock ()
+// http://www.lua.org/manual/5.3/manual.html#pdf-os.clock
+fn os_clock(ls: &mut dyn LuaAPI) -> usize {
+    ls.push_number(ctime::clock());
+    1
+}
+
+/*
+    Time/Date operations
+    { year=%Y, month=%m, day=%d, hour=%H, min=%M, sec=%S,
+      wday=%w+1, yday=%j, isdst=? }
+*/
+
+fn set_field(ls: &mut dyn LuaAPI, key: &str, value: i64, delta: i64) {
+    ls.push_integer(value + delta);
+    ls.set_field(-2, key);
+}
+
+fn set_bool_field(ls: &mut dyn LuaAPI, key: &str, value: i32) {
+    if value < 0 {
+        // undefined?
+        return; // does not set field
+    }
+    ls.push_boolean(value != 0);
+    ls.set_field(-2, key);
+}
+
+// Set all fields from structure 'tm' in the table on top of the stack
+fn set_all_fields(ls: &mut dyn LuaAPI, stm: &ctime::Tm) {
+    set_field(ls, "year", stm.tm_year as i64, 1900);
+    set_field(ls, "month", stm.tm_mon as i64, 1);
+    set_field(ls, "day", stm.tm_mday as i64, 0);
+    set_field(ls, "hour", stm.tm_hour as i64, 0);
+    set_field(ls, "min", stm.tm_min as i64, 0);
+    set_field(ls, "sec", stm.tm_sec as i64, 0);
+    set_field(ls, "yday", stm.tm_yday as i64, 1);
+    set_field(ls, "wday", stm.tm_wday as i64, 1);
+    set_bool_field(ls, "isdst", stm.tm_isdst);
+}
+
+fn get_bool_field(ls: &mut dyn LuaAPI, key: &str) -> i32 {
+    let res = if ls.get_field(-1, key) == LUA_TNIL {
+        -1
+    } else {
+        ls.to_boolean(-1) as i32
+    };
+    ls.pop(1);
+    res
+}
+
+// maximum value for date fields (to avoid arithmetic overflows with 'int')
+const L_MAXDATEFIELD: i64 = i32::MAX as i64 / 2;
+
+fn get_field(ls: &mut dyn LuaAPI, key: &str, d: i64, delta: i64) -> i32 {
+    let t = ls.get_field(-1, key); // get field and its type
+    let res = match ls.to_integerx(-1) {
+        Some(res) => {
+            if !(-L_MAXDATEFIELD..=L_MAXDATEFIELD).contains(&res) {
+                ls.error_l(&format!("field '{}' is out-of-bound", key));
+            }
+            res - delta
+        }
+        None => {
+            // field is not an integer?
+            if t != LUA_TNIL {
+                // some other value?
+                ls.error_l(&format!("field '{}' is not an integer", key));
+            } else if d < 0 {
+                // absent field; no default?
+                ls.error_l(&format!("field '{}' missing in date table", key));
+            }
+            d
+        }
+    };
+    ls.pop(1);
+    res as i32
+}
+
+// Conversion specifiers accepted by `os.date`, as in C99 `strftime`
+const STRFTIME_OPTIONS: &[&str] = &[
+    "a", "A", "b", "B", "c", "C", "d", "D", "e", "F", "g", "G", "h", "H", "I", "j", "m", "M", "n",
+    "p", "r", "R", "S", "t", "T", "u", "U", "V", "w", "W", "x", "X", "y", "Y", "z", "Z", "%",
+    "Ec", "EC", "Ex", "EX", "Ey", "EY", "Od", "Oe", "OH", "OI", "Om", "OM", "OS", "Ou", "OU",
+    "OV", "Ow", "OW", "Oy",
+];
+
+// Return the valid conversion at the start of `conv`.
+fn check_option<'a>(ls: &mut dyn LuaAPI, conv: &'a [u8]) -> &'a [u8] {
+    for option in STRFTIME_OPTIONS {
+        if conv.starts_with(option.as_bytes()) {
+            return &conv[..option.len()];
+        }
+    }
+    let msg = format!(
+        "invalid conversion specifier '%{}'",
+        String::from_utf8_lossy(conv)
+    );
+    ls.arg_error(1, &msg)
+}
+
+fn check_time(ls: &mut dyn LuaAPI, arg: isize) -> i64 {
+    ls.check_integer(arg)
+}
+
+// os.date ([format [, time]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-os.date
+fn os_date(ls: &mut dyn LuaAPI) -> usize {
+    let s = if ls.is_none_or_nil(1) {
+        b"%c".to_vec()
+    } else {
+        ls.check_bytes(1)
+    };
+    let t = if ls.is_none_or_nil(2) {
+        now()
+    } else {
+        check_time(ls, 2)
+    };
+    let (utc, s) = match s.strip_prefix(b"!") {
+        Some(s) => (true, s), // UTC?
+        None => (false, &s[..]),
+    };
+    let stm = match ctime::to_tm(t, utc) {
+        Some(stm) => stm,
+        None => ls.error_l("time result cannot be represented in this installation"),
+    };
+    if s == b"*t" {
+        ls.create_table(0, 9); // 9 = number of fields
+        set_all_fields(ls, &stm);
+    } else {
+        let mut b = Vec::new();
+        let mut i = 0;
+        while i < s.len() {
+            if s[i] != b'%' {
+                // not a conversion specifier?
+                b.push(s[i]);
+                i += 1;
+            } else {
+                i += 1;
+                let conv = check_option(ls, &s[i..]);
+                i += conv.len();
+                b.extend(ctime::strftime(conv, &stm));
+            }
+        }
+        ls.push_bytes(b);
+    }
+    1
+}
+
+fn now() -> i64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs() as i64),
+    }
+}
+
+// os.time ([table])
+// http://www.lua.org/manual/5.3/manual.html#pdf-os.time
+fn os_time(ls: &mut dyn LuaAPI) -> usize {
+    let t = if ls.is_none_or_nil(1) {
+        // called without args?
+        Some(now()) // get current time
+    } else {
+        ls.check_type(1, LUA_TTABLE);
+        ls.set_top(1); // make sure table is at the top
+        let mut ts = ctime::Tm::default();
+        ts.tm_sec = get_field(ls, "sec", 0, 0);
+        ts.tm_min = get_field(ls, "min", 0, 0);
+        ts.tm_hour = get_field(ls, "hour", 12, 0);
+        ts.tm_mday = get_field(ls, "day", -1, 0);
+        ts.tm_mon = get_field(ls, "month", -1, 1);
+        ts.tm_year = get_field(ls, "year", -1, 1900);
+        ts.tm_isdst = get_bool_field(ls, "isdst");
+        let t = ctime::mktime(&mut ts);
+        set_all_fields(ls, &ts); // update fields with normalized values
+        t
+    };
+    match t {
+        Some(t) => ls.push_integer(t),
+        None => ls.error_l("time result cannot be represented in this installation"),
+    }
+    1
+}
+
+// os.difftime (t2, t1)
+// http://www.lua.org/manual/5.3/manual.html#pdf-os.difftime
+fn os_difftime(ls: &mut dyn LuaAPI) -> usize {
+    let t1 = check_time(ls, 1);
+    let t2 = if ls.is_none_or_nil(2) { 0 } else { check_time(ls, 2) };
+    ls.push_number(t1 as f64 - t2 as f64);
+    1
+}
+
+// os.exit ([code [, close]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-os.exit
+fn os_exit(ls: &mut dyn LuaAPI) -> usize {
+    let status = if ls.is_boolean(1) {
+        if ls.to_boolean(1) {
+            0 // EXIT_SUCCESS
+        } else {
+            1 // EXIT_FAILURE
+        }
+    } else {
+        ls.opt_integer(1, 0) as i32
+    };
+    let _ = io::stdout().flush();
+    process::exit(status)
+}
+
+/*
+    The C library does the calendar work: Rust's std has no access to the
+    local time zone, and `strftime` output must match C exactly.
+*/
+#[allow(non_camel_case_types)]
+mod ctime {
+    use std::os::raw::{c_char, c_int, c_long};
+
+    type time_t = i64;
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct Tm {
+        pub tm_sec: c_int,
+        pub tm_min: c_int,
+        pub tm_hour: c_int,
+        pub tm_mday: c_int,
+        pub tm_mon: c_int,
+        pub tm_year: c_int,
+        pub tm_wday: c_int,
+        pub tm_yday: c_int,
+        pub tm_isdst: c_int,
+        tm_gmtoff: c_long,
+        tm_zone: *const c_char,
+    }
+
+    impl Default for Tm {
+        fn default() -> Tm {
+            Tm {
+                tm_sec: 0,
+                tm_min: 0,
+                tm_hour: 0,
+                tm_mday: 0,
+                tm_mon: 0,
+                tm_year: 0,
+                tm_wday: 0,
+                tm_yday: 0,
+                tm_isdst: 0,
+                tm_gmtoff: 0,
+                tm_zone: std::ptr::null(),
+            }
+        }
+    }
+
+    extern "C" {
+        #[link_name = "clock"]
+        fn c_clock() -> c_long;
+        fn gmtime_r(t: *const time_t, tm: *mut Tm) -> *mut Tm;
+        fn localtime_r(t: *const time_t, tm: *mut Tm) -> *mut Tm;
+        #[link_name = "mktime"]
+        fn c_mktime(tm: *mut Tm) -> time_t;
+        #[link_name = "strftime"]
+        fn c_strftime(s: *mut c_char, max: usize, format: *const c_char, tm: *const Tm) -> usize;
+    }
+
+    const CLOCKS_PER_SEC: f64 = 1_000_000.0; // required by POSIX
+
+    // Processor time used by the program, in seconds.
+    pub fn clock() -> f64 {
+        unsafe { c_clock() as f64 / CLOCKS_PER_SEC }
+    }
+
+    // Broken-down time of `t`, in UTC or in the local time zone.
+    pub fn to_tm(t: i64, utc: bool) -> Option<Tm> {
+        let mut tm = Tm::default();
+        let res = unsafe {
+            if utc {
+                gmtime_r(&t, &mut tm)
+            } else {
+                localtime_r(&t, &mut tm)
+            }
+        };
+        if res.is_null() {
+            None
+        } else {
+            Some(tm)
+        }
+    }
+
+    // Local time of `tm`, normalizing its fields in place.
+    pub fn mktime(tm: &mut Tm) -> Option<i64> {
+        let t = unsafe { c_mktime(tm) };
+        if t == -1 {
+            None
+        } else {
+            Some(t)
+        }
+    }
+
+    // Format one conversion (without the leading '%').
+    pub fn strftime(conv: &[u8], tm: &Tm) -> Vec<u8> {
+        let mut fmt = vec![b'%'];
+        fmt.extend_from_slice(conv);
+        fmt.push(0);
+        let mut buff = vec![0u8; 250]; // SIZETIMEFMT
+        let n = unsafe {
+            c_strftime(
+                buff.as_mut_ptr() as *mut c_char,
+                buff.len(),
+                fmt.as_ptr() as *const c_char,
+                tm,
+            )
+        };
+        buff.truncate(n);
+        buff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LuaState;
+    use crate::stdlib::open_libs;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /* Lua source code:
+        assert(os.getenv("PATH") ~= nil and os.getenv("NO_SUCH_VARIABLE_HOPEFULLY") == nil)
+        assert(math.type(os.time()) == "integer" and type(os.clock()) == "number")
+        assert(os.date("!%Y-%m-%d %H:%M:%S", 86400 * 365) == "1971-01-01 00:00:00")
+        local d = os.date("!*t", 1000000000)
+        assert(d.year == 2001 and d.month == 9 and d.day == 9 and d.hour == 1 and d.min == 46 and d.wday == 1 and d.yday == 252)
+        local t0 = os.time({year = 2020, month = 2, day = 30, hour = 0})
+        local d2 = os.date("*t", t0)
+        assert(d2.month == 3 and d2.day == 1 and os.date("%d/%m", t0) == "01/03")
+        assert(os.difftime(t0 + 60, t0) == 60.0)
+        assert(select(2, pcall(os.time, {year = 2020})) == "field 'day' missing in date table")
+        assert(select(2, pcall(os.time, {year = 2020, month = 1, day = "x"})) == "field 'day' is not an integer")
+        assert(select(2, pcall(os.date, "%Q")) == "bad argument #1 to 'os.date' (invalid conversion specifier '%Q')")
+    */
+    const LUA_OS_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x07, 0x40,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x09, 0xa4, 0x00, 0x00, 0x00, 0x06, 0x00, 0x40, 0x00,
+        0x46, 0x40, 0x40, 0x00, 0x47, 0x80, 0xc0, 0x00, 0x81, 0xc0, 0x00, 0x00,
+        0x64, 0x80, 0x00, 0x01, 0x5f, 0x00, 0xc1, 0x00, 0x1e, 0x40, 0x01, 0x80,
+        0x46, 0x40, 0x40, 0x00, 0x47, 0x80, 0xc0, 0x00, 0x81, 0x40, 0x01, 0x00,
+        0x64, 0x80, 0x00, 0x01, 0x5f, 0x00, 0xc1, 0x00, 0x1e, 0x00, 0x00, 0x80,
+        0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01,
+        0x06, 0x00, 0x40, 0x00, 0x46, 0x80, 0x41, 0x00, 0x47, 0xc0, 0xc1, 0x00,
+        0x86, 0x40, 0x40, 0x00, 0x87, 0x00, 0x42, 0x01, 0xa4, 0x00, 0x80, 0x00,
+        0x64, 0x80, 0x00, 0x00, 0x1f, 0x40, 0xc2, 0x00, 0x1e, 0x80, 0x01, 0x80,
+        0x46, 0xc0, 0x41, 0x00, 0x86, 0x40, 0x40, 0x00, 0x87, 0x80, 0x42, 0x01,
+        0xa4, 0x00, 0x80, 0x00, 0x64, 0x80, 0x00, 0x00, 0x5f, 0xc0, 0xc2, 0x00,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00,
+        0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40, 0x00, 0x46, 0x40, 0x40, 0x00,
+        0x47, 0x00, 0xc3, 0x00, 0x81, 0x40, 0x03, 0x00, 0xc1, 0x80, 0x03, 0x00,
+        0x64, 0x80, 0x80, 0x01, 0x5f, 0xc0, 0xc3, 0x00, 0x1e, 0x00, 0x00, 0x80,
+        0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01,
+        0x06, 0x40, 0x40, 0x00, 0x07, 0x00, 0x43, 0x00, 0x41, 0x00, 0x04, 0x00,
+        0x81, 0x40, 0x04, 0x00, 0x24, 0x80, 0x80, 0x01, 0x46, 0x00, 0x40, 0x00,
+        0x87, 0x80, 0x44, 0x00, 0x1f, 0xc0, 0x44, 0x01, 0x1e, 0x40, 0x04, 0x80,
+        0x87, 0x00, 0x45, 0x00, 0x1f, 0x40, 0x45, 0x01, 0x1e, 0x80, 0x03, 0x80,
+        0x87, 0x80, 0x45, 0x00, 0x1f, 0x40, 0x45, 0x01, 0x1e, 0xc0, 0x02, 0x80,
+        0x87, 0xc0, 0x45, 0x00, 0x1f, 0x00, 0x46, 0x01, 0x1e, 0x00, 0x02, 0x80,
+        0x87, 0x40, 0x46, 0x00, 0x1f, 0x80, 0x46, 0x01, 0x1e, 0x40, 0x01, 0x80,
+        0x87, 0xc0, 0x46, 0x00, 0x1f, 0x00, 0x46, 0x01, 0x1e, 0x80, 0x00, 0x80,
+        0x87, 0x00, 0x47, 0x00, 0x5f, 0x40, 0x47, 0x01, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x40, 0x00, 0x00, 0x83, 0x00, 0x80, 0x00, 0x64, 0x40, 0x00, 0x01,
+        0x46, 0x40, 0x40, 0x00, 0x47, 0x00, 0xc2, 0x00, 0x8b, 0x00, 0x01, 0x00,
+        0x8a, 0x80, 0x47, 0x89, 0x8a, 0xc0, 0x47, 0x8a, 0x8a, 0x00, 0x48, 0x8b,
+        0x8a, 0x40, 0xc8, 0x8b, 0x64, 0x80, 0x00, 0x01, 0x86, 0x40, 0x40, 0x00,
+        0x87, 0x00, 0x43, 0x01, 0xc1, 0x80, 0x08, 0x00, 0x00, 0x01, 0x80, 0x00,
+        0xa4, 0x80, 0x80, 0x01, 0xc6, 0x00, 0x40, 0x00, 0x07, 0x01, 0x45, 0x01,
+        0x1f, 0xc0, 0x48, 0x02, 0x1e, 0x40, 0x02, 0x80, 0x07, 0x81, 0x45, 0x01,
+        0x1f, 0x00, 0x46, 0x02, 0x1e, 0x80, 0x01, 0x80, 0x06, 0x41, 0x40, 0x00,
+        0x07, 0x01, 0x43, 0x02, 0x41, 0x01, 0x09, 0x00, 0x80, 0x01, 0x80, 0x00,
+        0x24, 0x81, 0x80, 0x01, 0x5f, 0x40, 0x49, 0x02, 0x1e, 0x00, 0x00, 0x80,
+        0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01,
+        0xc6, 0x00, 0x40, 0x00, 0x06, 0x41, 0x40, 0x00, 0x07, 0x81, 0x49, 0x02,
+        0x4d, 0xc1, 0xc9, 0x00, 0x80, 0x01, 0x80, 0x00, 0x24, 0x81, 0x80, 0x01,
+        0x5f, 0x00, 0x4a, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00,
+        0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x40, 0x00,
+        0x06, 0x41, 0x4a, 0x00, 0x41, 0xc1, 0x07, 0x00, 0x86, 0x81, 0x4a, 0x00,
+        0xc6, 0x41, 0x40, 0x00, 0xc7, 0x01, 0xc2, 0x03, 0x0b, 0x42, 0x00, 0x00,
+        0x0a, 0x82, 0x47, 0x89, 0xa4, 0x01, 0x80, 0x01, 0x24, 0x81, 0x00, 0x00,
+        0x5f, 0xc0, 0x4a, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00,
+        0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x40, 0x00,
+        0x06, 0x41, 0x4a, 0x00, 0x41, 0xc1, 0x07, 0x00, 0x86, 0x81, 0x4a, 0x00,
+        0xc6, 0x41, 0x40, 0x00, 0xc7, 0x01, 0xc2, 0x03, 0x0b, 0xc2, 0x00, 0x00,
+        0x0a, 0x82, 0x47, 0x89, 0x0a, 0x02, 0x46, 0x8a, 0x0a, 0x02, 0x4b, 0x8b,
+        0xa4, 0x01, 0x80, 0x01, 0x24, 0x81, 0x00, 0x00, 0x5f, 0x40, 0x4b, 0x02,
+        0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00,
+        0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x40, 0x00, 0x06, 0x41, 0x4a, 0x00,
+        0x41, 0xc1, 0x07, 0x00, 0x86, 0x81, 0x4a, 0x00, 0xc6, 0x41, 0x40, 0x00,
+        0xc7, 0x01, 0xc3, 0x03, 0x01, 0x82, 0x0b, 0x00, 0xa4, 0x01, 0x80, 0x01,
+        0x24, 0x81, 0x00, 0x00, 0x5f, 0xc0, 0x4b, 0x02, 0x1e, 0x00, 0x00, 0x80,
+        0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01,
+        0x26, 0x00, 0x80, 0x00, 0x30, 0x00, 0x00, 0x00, 0x04, 0x07, 0x61, 0x73,
+        0x73, 0x65, 0x72, 0x74, 0x04, 0x03, 0x6f, 0x73, 0x04, 0x07, 0x67, 0x65,
+        0x74, 0x65, 0x6e, 0x76, 0x04, 0x05, 0x50, 0x41, 0x54, 0x48, 0x00, 0x04,
+        0x1b, 0x4e, 0x4f, 0x5f, 0x53, 0x55, 0x43, 0x48, 0x5f, 0x56, 0x41, 0x52,
+        0x49, 0x41, 0x42, 0x4c, 0x45, 0x5f, 0x48, 0x4f, 0x50, 0x45, 0x46, 0x55,
+        0x4c, 0x4c, 0x59, 0x04, 0x05, 0x6d, 0x61, 0x74, 0x68, 0x04, 0x05, 0x74,
+        0x79, 0x70, 0x65, 0x04, 0x05, 0x74, 0x69, 0x6d, 0x65, 0x04, 0x08, 0x69,
+        0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x04, 0x06, 0x63, 0x6c, 0x6f, 0x63,
+        0x6b, 0x04, 0x07, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x04, 0x05, 0x64,
+        0x61, 0x74, 0x65, 0x04, 0x13, 0x21, 0x25, 0x59, 0x2d, 0x25, 0x6d, 0x2d,
+        0x25, 0x64, 0x20, 0x25, 0x48, 0x3a, 0x25, 0x4d, 0x3a, 0x25, 0x53, 0x13,
+        0x80, 0x33, 0xe1, 0x01, 0x00, 0x00, 0x00, 0x00, 0x04, 0x14, 0x31, 0x39,
+        0x37, 0x31, 0x2d, 0x30, 0x31, 0x2d, 0x30, 0x31, 0x20, 0x30, 0x30, 0x3a,
+        0x30, 0x30, 0x3a, 0x30, 0x30, 0x04, 0x04, 0x21, 0x2a, 0x74, 0x13, 0x00,
+        0xca, 0x9a, 0x3b, 0x00, 0x00, 0x00, 0x00, 0x04, 0x05, 0x79, 0x65, 0x61,
+        0x72, 0x13, 0xd1, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x06,
+        0x6d, 0x6f, 0x6e, 0x74, 0x68, 0x13, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x04, 0x04, 0x64, 0x61, 0x79, 0x04, 0x05, 0x68, 0x6f, 0x75,
+        0x72, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x04,
+        0x6d, 0x69, 0x6e, 0x13, 0x2e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x04, 0x05, 0x77, 0x64, 0x61, 0x79, 0x04, 0x05, 0x79, 0x64, 0x61, 0x79,
+        0x13, 0xfc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0xe4, 0x07,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x02, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x13, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x03, 0x2a,
+        0x74, 0x13, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x06,
+        0x25, 0x64, 0x2f, 0x25, 0x6d, 0x04, 0x06, 0x30, 0x31, 0x2f, 0x30, 0x33,
+        0x04, 0x09, 0x64, 0x69, 0x66, 0x66, 0x74, 0x69, 0x6d, 0x65, 0x13, 0x3c,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x4e, 0x40, 0x04, 0x07, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74,
+        0x04, 0x06, 0x70, 0x63, 0x61, 0x6c, 0x6c, 0x04, 0x22, 0x66, 0x69, 0x65,
+        0x6c, 0x64, 0x20, 0x27, 0x64, 0x61, 0x79, 0x27, 0x20, 0x6d, 0x69, 0x73,
+        0x73, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x20, 0x64, 0x61, 0x74, 0x65,
+        0x20, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x04, 0x02, 0x78, 0x04, 0x1e, 0x66,
+        0x69, 0x65, 0x6c, 0x64, 0x20, 0x27, 0x64, 0x61, 0x79, 0x27, 0x20, 0x69,
+        0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x61, 0x6e, 0x20, 0x69, 0x6e, 0x74,
+        0x65, 0x67, 0x65, 0x72, 0x04, 0x03, 0x25, 0x51, 0x14, 0x41, 0x62, 0x61,
+        0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23,
+        0x31, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x6f, 0x73, 0x2e, 0x64, 0x61, 0x74,
+        0x65, 0x27, 0x20, 0x28, 0x69, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x20,
+        0x63, 0x6f, 0x6e, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x73,
+        0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x27, 0x25, 0x51,
+        0x27, 0x29, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xa4, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x02, 0x64, 0x33, 0x00, 0x00, 0x00, 0xa4, 0x00,
+        0x00, 0x00, 0x03, 0x74, 0x30, 0x54, 0x00, 0x00, 0x00, 0xa4, 0x00, 0x00,
+        0x00, 0x03, 0x64, 0x32, 0x59, 0x00, 0x00, 0x00, 0xa4, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
+
+    #[test]
+    fn test_os_lib() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        open_libs(&mut *ls);
+        ls.requiref("os", open_os, true);
+        ls.pop(1);
+
+        ls.load(LUA_OS_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 0, 0);
+        assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+    }
+}
@@ -0,0 +1,890 @@
+// string.pack, string.unpack and string.packsize, see lstrlib.c
+use crate::api::LuaAPI;
+use crate::api::LuaAuxLib;
+use crate::binary::reader::Reader;
+
+use super::string::{posrelat, MAXSIZE};
+
+const MAXINTSIZE: usize = 16; // maximum size for the binary representation of an integer
+const NB: usize = 8; // number of bits in a character
+const SZINT: usize = 8; // size of a lua_Integer
+const MAXALIGN: usize = 8; // alignment used by option '!' without a size
+const PACKPADBYTE: u8 = 0x00; // value used for padding
+
+// Options for pack/unpack.
+#[derive(Clone, Copy, PartialEq)]
+enum KOption {
+    Int,       // signed integers
+    Uint,      // unsigned integers
+    Float,     // floating-point numbers
+    Char,      // fixed-length strings
+    String,    // strings with prefixed length
+    Zstr,      // zero-terminated strings
+    Padding,   // padding
+    PaddAlign, // padding for alignment
+    Nop,       // no-op (configuration or spaces)
+}
+
+// Reading state of a format string.
+struct Header<'a> {
+    fmt: &'a [u8],
+    pos: usize,
+    little: bool,
+    maxalign: usize,
+}
+
+impl<'a> Header<'a> {
+    fn new(fmt: &'a [u8]) -> Header<'a> {
+        Header {
+            fmt,
+            pos: 0,
+            little: cfg!(target_endian = "little"),
+            maxalign: 1,
+        }
+    }
+
+    fn more(&self) -> bool {
+        self.pos < self.fmt.len()
+    }
+
+    fn is_digit(&self) -> bool {
+        self.fmt.get(self.pos).is_some_and(u8::is_ascii_digit)
+    }
+
+    // Read an integer numeral from the format, `df` if there is none.
+    fn get_num(&mut self, df: usize) -> usize {
+        if !self.is_digit() {
+            return df;
+        }
+        let mut a = 0;
+        loop {
+            a = a * 10 + (self.fmt[self.pos] - b'0') as usize;
+            self.pos += 1;
+            if !(self.is_digit() && a <= (MAXSIZE - 9) / 10) {
+                return a;
+            }
+        }
+    }
+
+    // Read an integer numeral and raise an error if it is larger than the
+    // maximum size for integers.
+    fn get_num_limit(&mut self, ls: &mut dyn LuaAPI, df: usize) -> usize {
+        let sz = self.get_num(df);
+        if sz > MAXINTSIZE || sz == 0 {
+            let msg = format!("integral size ({}) out of limits [1,{}]", sz, MAXINTSIZE);
+            ls.error_l(&msg);
+        }
+        sz
+    }
+
+    // Read and classify the next option, returning it with its size.
+    fn get_option(&mut self, ls: &mut dyn LuaAPI) -> (KOption, usize) {
+        let opt = self.fmt[self.pos];
+        self.pos += 1;
+        match opt {
+            b'b' => (KOption::Int, 1),
+            b'B' => (KOption::Uint, 1),
+            b'h' => (KOption::Int, 2),
+            b'H' => (KOption::Uint, 2),
+            b'l' | b'j' => (KOption::Int, 8),
+            b'L' | b'J' | b'T' => (KOption::Uint, 8),
+            b'f' => (KOption::Float, 4),
+            b'd' | b'n' => (KOption::Float, 8),
+            b'i' => (KOption::Int, self.get_num_limit(ls, 4)),
+            b'I' => (KOption::Uint, self.get_num_limit(ls, 4)),
+            b's' => (KOption::String, self.get_num_limit(ls, 8)),
+            b'c' => {
+                if !self.is_digit() {
+                    ls.error_l("missing size for format option 'c'");
+                }
+                (KOption::Char, self.get_num(0))
+            }
+            b'z' => (KOption::Zstr, 0),
+            b'x' => (KOption::Padding, 1),
+            b'X' => (KOption::PaddAlign, 0),
+            b' ' => (KOption::Nop, 0),
+            b'<' => {
+                self.little = true;
+                (KOption::Nop, 0)
+            }
+            b'>' => {
+                self.little = false;
+                (KOption::Nop, 0)
+            }
+            b'=' => {
+                self.little = cfg!(target_endian = "little");
+                (KOption::Nop, 0)
+            }
+            b'!' => {
+                self.maxalign = self.get_num_limit(ls, MAXALIGN);
+                (KOption::Nop, 0)
+            }
+            _ => ls.error_l(&format!("invalid format option '{}'", opt as char)),
+        }
+    }
+
+    // Read the next option along with its size and the padding needed to
+    // align it at `totalsize`, see `getdetails` in lstrlib.c
+    fn get_details(&mut self, ls: &mut dyn LuaAPI, totalsize: usize) -> (KOption, usize, usize) {
+        let (opt, size) = self.get_option(ls);
+        let mut align = size; // usually, alignment follows size
+        if opt == KOption::PaddAlign {
+            // 'X' gets alignment from following option
+            if !self.more() {
+                ls.arg_error(1, "invalid next option for option 'X'");
+            }
+            let (next, next_size) = self.get_option(ls);
+            align = next_size;
+            if next == KOption::Char || align == 0 {
+                ls.arg_error(1, "invalid next option for option 'X'");
+            }
+        }
+        let ntoalign = if align <= 1 || opt == KOption::Char {
+            0 // need no alignment
+        } else {
+            let align = align.min(self.maxalign); // enforce maximum alignment
+            if align & (align - 1) != 0 {
+                // is 'align' not a power of 2?
+                ls.arg_error(1, "format asks for alignment not power of 2");
+            }
+            (align - (totalsize & (align - 1))) & (align - 1)
+        };
+        (opt, size, ntoalign)
+    }
+}
+
+// Append integer `n` with `size` bytes, negative values extend their sign
+// beyond the 8 bytes of a Lua integer.
+fn pack_int(b: &mut Vec<u8>, n: u64, little: bool, size: usize, neg: bool) {
+    let mut buff = vec![if neg { 0xFF } else { 0 }; size];
+    for (i, byte) in buff.iter_mut().take(SZINT).enumerate() {
+        *byte = (n >> (i * NB)) as u8;
+    }
+    if !little {
+        buff.reverse();
+    }
+    b.extend_from_slice(&buff);
+}
+
+fn unpack_int(ls: &mut dyn LuaAPI, r: &mut Reader, little: bool, size: usize, signed: bool) -> i64 {
+    let limit = size.min(SZINT);
+    let (res, extra) = if little {
+        let res = r.read_uint(limit, true);
+        (res, r.read_bytes(size - limit))
+    } else {
+        let extra = r.read_bytes(size - limit);
+        (r.read_uint(limit, false), extra)
+    };
+    if size < SZINT {
+        // real size smaller than lua_Integer?
+        if signed {
+            // needs sign extension?
+            let mask = 1u64 << (size * NB - 1);
+            return (res ^ mask).wrapping_sub(mask) as i64; // do sign extension
+        }
+    } else if size > SZINT {
+        // must check unread bytes
+        let mask = if !signed || (res as i64) >= 0 { 0 } else { 0xFF };
+        if extra.iter().any(|&c| c != mask) {
+            ls.error_l(&format!("{}-byte integer does not fit into Lua Integer", size));
+        }
+    }
+    res as i64
+}
+
+// string.pack (fmt, v1, v2, ···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.pack
+pub fn str_pack(ls: &mut dyn LuaAPI) -> usize {
+    let fmt = ls.check_shared_bytes(1);
+    let mut h = Header::new(&fmt);
+    let mut b = Vec::new();
+    let mut arg = 1; // current argument to pack
+    while h.more() {
+        let (opt, size, ntoalign) = h.get_details(ls, b.len());
+        b.resize(b.len() + ntoalign, PACKPADBYTE); // fill alignment
+        arg += 1;
+        match opt {
+            KOption::Int => {
+                // signed integers
+                let n = ls.check_integer(arg);
+                if size < SZINT {
+                    // need overflow check?
+                    let lim = 1i64 << (size * NB - 1);
+                    ls.arg_check(-lim <= n && n < lim, arg, "integer overflow");
+                }
+                pack_int(&mut b, n as u64, h.little, size, n < 0);
+            }
+            KOption::Uint => {
+                // unsigned integers
+                let n = ls.check_integer(arg);
+                if size < SZINT {
+                    // need overflow check?
+                    ls.arg_check((n as u64) < (1u64 << (size * NB)), arg, "unsigned overflow");
+                }
+                pack_int(&mut b, n as u64, h.little, size, false);
+            }
+            KOption::Float => {
+                // floating-point options
+                let n = ls.check_number(arg);
+                let bits = if size == 4 { (n as f32).to_bits() as u64 } else { n.to_bits() };
+                pack_int(&mut b, bits, h.little, size, false);
+            }
+            KOption::Char => {
+                // fixed-size string
+                let s = ls.check_shared_bytes(arg);
+                ls.arg_check(s.len() <= size, arg, "string longer than given size");
+                b.extend_from_slice(&s);
+                b.resize(b.len() + size - s.len(), PACKPADBYTE); // pad extra space
+            }
+            KOption::String => {
+                // strings with length count
+                let s = ls.check_shared_bytes(arg);
+                ls.arg_check(
+                    size >= SZINT || (s.len() as u64) < (1u64 << (size * NB)),
+                    arg,
+                    "string length does not fit in given size",
+                );
+                pack_int(&mut b, s.len() as u64, h.little, size, false); // pack length
+                b.extend_from_slice(&s);
+            }
+            KOption::Zstr => {
+                // zero-terminated string
+                let s = ls.check_shared_bytes(arg);
+                ls.arg_check(!s.contains(&0), arg, "string contains zeros");
+                b.extend_from_slice(&s);
+                b.push(0); // add zero at the end
+            }
+            KOption::Padding => {
+                b.push(PACKPADBYTE);
+                arg -= 1; // undo increment
+            }
+            KOption::PaddAlign | KOption::Nop => arg -= 1, // undo increment
+        }
+    }
+    ls.push_bytes(b);
+    1
+}
+
+// string.packsize (fmt)
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.packsize
+pub fn str_packsize(ls: &mut dyn LuaAPI) -> usize {
+    let fmt = ls.check_shared_bytes(1);
+    let mut h = Header::new(&fmt);
+    let mut totalsize = 0; // accumulate total size of result
+    while h.more() {
+        let (opt, size, ntoalign) = h.get_details(ls, totalsize);
+        let size = size + ntoalign; // total space used by option
+        ls.arg_check(totalsize <= MAXSIZE - size, 1, "format result too large");
+        totalsize += size;
+        if let KOption::String | KOption::Zstr = opt {
+            ls.arg_error(1, "variable-length format");
+        }
+    }
+    ls.push_integer(totalsize as i64);
+    1
+}
+
+// string.unpack (fmt, s [, pos])
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.unpack
+pub fn str_unpack(ls: &mut dyn LuaAPI) -> usize {
+    let fmt = ls.check_shared_bytes(1);
+    let data = ls.check_bytes(2);
+    let ld = data.len();
+    let pos = posrelat(ls.opt_integer(3, 1), ld) - 1;
+    ls.arg_check(0 <= pos && pos as usize <= ld, 3, "initial position out of string");
+    let mut h = Header::new(&fmt);
+    let mut r = Reader::new(data);
+    r.skip(pos as usize);
+    let mut n = 0; // number of results
+    while h.more() {
+        let (opt, size, ntoalign) = h.get_details(ls, r.position());
+        if r.position() + ntoalign + size > ld {
+            ls.arg_error(2, "data string too short");
+        }
+        r.skip(ntoalign); // skip alignment
+        if !ls.check_stack(2) {
+            ls.error_l("stack overflow (too many results)");
+        }
+        n += 1;
+        match opt {
+            KOption::Int | KOption::Uint => {
+                let res = unpack_int(ls, &mut r, h.little, size, opt == KOption::Int);
+                ls.push_integer(res);
+            }
+            KOption::Float => {
+                let bits = r.read_uint(size, h.little);
+                let f = if size == 4 { f32::from_bits(bits as u32) as f64 } else { f64::from_bits(bits) };
+                ls.push_number(f);
+            }
+            KOption::Char => {
+                let s = r.read_bytes(size);
+                ls.push_bytes(s);
+            }
+            KOption::String => {
+                let len = unpack_int(ls, &mut r, h.little, size, false) as u64;
+                ls.arg_check(len <= (ld - r.position()) as u64, 2, "data string too short");
+                let s = r.read_bytes(len as usize);
+                ls.push_bytes(s);
+            }
+            KOption::Zstr => {
+                let len = r.remaining().iter().position(|&c| c == 0);
+                ls.arg_check(len.is_some(), 2, "unfinished string for format 'z'");
+                let s = r.read_bytes(len.unwrap());
+                r.skip(1); // skip final zero
+                ls.push_bytes(s);
+            }
+            KOption::Padding => {
+                r.skip(size);
+                n -= 1; // undo increment
+            }
+            KOption::PaddAlign | KOption::Nop => n -= 1, // undo increment
+        }
+    }
+    ls.push_integer(r.position() as i64 + 1); // next position
+    n + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::consts::*;
+    use crate::state::LuaState;
+    use crate::stdlib::base::open_base;
+    use crate::stdlib::string::open_string;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /* Lua source code:
+        local pack, unpack, packsize = string.pack, string.unpack, string.packsize
+        assert(pack("<i4", 1) == "\1\0\0\0" and pack(">i4", 1) == "\0\0\0\1" and pack("b", -1) == "\255")
+        assert(pack("<h>H", 258, 258) == "\2\1\1\2" and pack("<i3", -2) == "\254\255\255")
+        assert(pack("<i16", -1) == ("\255"):rep(16) and pack(">I9", 1) == ("\0"):rep(8) .. "\1")
+        assert(unpack("<i4", "\255\255\255\255") == -1 and unpack("<I4", "\255\255\255\255") == 4294967295)
+        assert(unpack(">j", pack(">j", 9223372036854775807)) == 9223372036854775807)
+        assert(unpack("<i16", pack("<i16", -3)) == -3 and unpack(">I12", pack(">I12", 77)) == 77)
+        assert(unpack("<d", pack("<d", 0.1)) == 0.1 and unpack(">f", pack(">f", 0.5)) == 0.5 and unpack("n", pack("n", -2.5)) == -2.5)
+        assert(pack("z", "hi") == "hi\0" and pack("s1", "abc") == "\3abc" and pack(">s2", "x") == "\0\1x")
+        assert(pack("c5", "ab") == "ab\0\0\0" and unpack("c2", "abc") == "ab")
+        local a, b, c, nxt = unpack("<z s1 B", "hey\0\2yo\7")
+        assert(a == "hey" and b == "yo" and c == 7 and nxt == 9)
+        assert(packsize("i4") == 4 and packsize("!8 b d") == 16 and packsize("<!4 b i8") == 12 and packsize("!b Xi4 i4") == 8)
+        assert(pack("!4 b i4", 1, 2) == "\1\0\0\0\2\0\0\0" and pack("bxh", 1, 2) == "\1\0\2\0")
+        assert(select("#", unpack("b b", "\1\2")) == 3 and select(2, unpack("b", "\1\2", 2)) == 3)
+        assert(unpack("b", "\1\2\3", -1) == 3)
+        local ok, err = pcall(pack, "i17", 1)
+        assert(err == "integral size (17) out of limits [1,16]")
+        ok, err = pcall(pack, "b", 128)
+        assert(err == "bad argument #2 to 'string.pack' (integer overflow)")
+        ok, err = pcall(pack, "B", -1)
+        assert(err == "bad argument #2 to 'string.pack' (unsigned overflow)")
+        ok, err = pcall(pack, "y")
+        assert(err == "invalid format option 'y'")
+        ok, err = pcall(pack, "c")
+        assert(err == "missing size for format option 'c'")
+        ok, err = pcall(pack, "c1", "ab")
+        assert(err == "bad argument #2 to 'string.pack' (string longer than given size)")
+        ok, err = pcall(pack, "s1", ("x"):rep(256))
+        assert(err == "bad argument #2 to 'string.pack' (string length does not fit in given size)")
+        ok, err = pcall(pack, "z", "a\0b")
+        assert(err == "bad argument #2 to 'string.pack' (string contains zeros)")
+        ok, err = pcall(pack, "!3 i4", 1)
+        assert(err == "bad argument #1 to 'string.pack' (format asks for alignment not power of 2)")
+        ok, err = pcall(pack, "X")
+        assert(err == "bad argument #1 to 'string.pack' (invalid next option for option 'X')")
+        ok, err = pcall(packsize, "s")
+        assert(err == "bad argument #1 to 'string.packsize' (variable-length format)")
+        ok, err = pcall(unpack, "i4", "abc")
+        assert(err == "bad argument #2 to 'string.unpack' (data string too short)")
+        ok, err = pcall(unpack, "z", "abc")
+        assert(err == "bad argument #2 to 'string.unpack' (unfinished string for format 'z')")
+        ok, err = pcall(unpack, "<i9", ("\255"):rep(8) .. "\1")
+        assert(err == "9-byte integer does not fit into Lua Integer")
+        ok, err = pcall(unpack, "b", "a", 3)
+        assert(err == "bad argument #3 to 'string.unpack' (initial position out of string)")
+        return pack(">I2 i8 d", 513, -2, 1.5)
+    */
+    const LUA_PACK_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x07, 0x40,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x0f, 0xe9, 0x01, 0x00, 0x00, 0x06, 0x00, 0x40, 0x00,
+        0x07, 0x40, 0x40, 0x00, 0x46, 0x00, 0x40, 0x00, 0x47, 0x80, 0xc0, 0x00,
+        0x86, 0x00, 0x40, 0x00, 0x87, 0xc0, 0x40, 0x01, 0xc6, 0x00, 0x41, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x41, 0x41, 0x01, 0x00, 0x81, 0x81, 0x01, 0x00,
+        0x24, 0x81, 0x80, 0x01, 0x1f, 0xc0, 0x41, 0x02, 0x1e, 0xc0, 0x02, 0x80,
+        0x00, 0x01, 0x00, 0x00, 0x41, 0x01, 0x02, 0x00, 0x81, 0x81, 0x01, 0x00,
+        0x24, 0x81, 0x80, 0x01, 0x1f, 0x40, 0x42, 0x02, 0x1e, 0x40, 0x01, 0x80,
+        0x00, 0x01, 0x00, 0x00, 0x41, 0x81, 0x02, 0x00, 0x81, 0xc1, 0x02, 0x00,
+        0x24, 0x81, 0x80, 0x01, 0x5f, 0x00, 0x43, 0x02, 0x1e, 0x00, 0x00, 0x80,
+        0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01,
+        0xc6, 0x00, 0x41, 0x00, 0x00, 0x01, 0x00, 0x00, 0x41, 0x41, 0x03, 0x00,
+        0x81, 0x81, 0x03, 0x00, 0xc1, 0x81, 0x03, 0x00, 0x24, 0x81, 0x00, 0x02,
+        0x1f, 0xc0, 0x43, 0x02, 0x1e, 0x40, 0x01, 0x80, 0x00, 0x01, 0x00, 0x00,
+        0x41, 0x01, 0x04, 0x00, 0x81, 0x41, 0x04, 0x00, 0x24, 0x81, 0x80, 0x01,
+        0x5f, 0x80, 0x44, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00,
+        0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x41, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x41, 0xc1, 0x04, 0x00, 0x81, 0xc1, 0x02, 0x00,
+        0x24, 0x81, 0x80, 0x01, 0x41, 0x01, 0x03, 0x00, 0x4c, 0x01, 0xc5, 0x02,
+        0xc1, 0x41, 0x05, 0x00, 0x64, 0x81, 0x80, 0x01, 0x1f, 0x40, 0x01, 0x02,
+        0x1e, 0xc0, 0x02, 0x80, 0x00, 0x01, 0x00, 0x00, 0x41, 0x81, 0x05, 0x00,
+        0x81, 0x81, 0x01, 0x00, 0x24, 0x81, 0x80, 0x01, 0x41, 0xc1, 0x05, 0x00,
+        0x4c, 0x01, 0xc5, 0x02, 0xc1, 0x01, 0x06, 0x00, 0x64, 0x81, 0x80, 0x01,
+        0x81, 0x41, 0x06, 0x00, 0x5d, 0x81, 0x81, 0x02, 0x5f, 0x40, 0x01, 0x02,
+        0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00,
+        0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x41, 0x00, 0x00, 0x01, 0x80, 0x00,
+        0x41, 0x41, 0x01, 0x00, 0x81, 0x81, 0x06, 0x00, 0x24, 0x81, 0x80, 0x01,
+        0x1f, 0xc0, 0x42, 0x02, 0x1e, 0x40, 0x01, 0x80, 0x00, 0x01, 0x80, 0x00,
+        0x41, 0xc1, 0x06, 0x00, 0x81, 0x81, 0x06, 0x00, 0x24, 0x81, 0x80, 0x01,
+        0x5f, 0x00, 0x47, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00,
+        0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x41, 0x00,
+        0x00, 0x01, 0x80, 0x00, 0x41, 0x41, 0x07, 0x00, 0x80, 0x01, 0x00, 0x00,
+        0xc1, 0x41, 0x07, 0x00, 0x01, 0x82, 0x07, 0x00, 0xa4, 0x01, 0x80, 0x01,
+        0x24, 0x81, 0x00, 0x00, 0x5f, 0x80, 0x47, 0x02, 0x1e, 0x00, 0x00, 0x80,
+        0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01,
+        0xc6, 0x00, 0x41, 0x00, 0x00, 0x01, 0x80, 0x00, 0x41, 0xc1, 0x04, 0x00,
+        0x80, 0x01, 0x00, 0x00, 0xc1, 0xc1, 0x04, 0x00, 0x01, 0xc2, 0x07, 0x00,
+        0xa4, 0x01, 0x80, 0x01, 0x24, 0x81, 0x00, 0x00, 0x1f, 0xc0, 0x47, 0x02,
+        0x1e, 0x00, 0x02, 0x80, 0x00, 0x01, 0x80, 0x00, 0x41, 0x01, 0x08, 0x00,
+        0x80, 0x01, 0x00, 0x00, 0xc1, 0x01, 0x08, 0x00, 0x01, 0x42, 0x08, 0x00,
+        0xa4, 0x01, 0x80, 0x01, 0x24, 0x81, 0x00, 0x00, 0x5f, 0x40, 0x48, 0x02,
+        0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00,
+        0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x41, 0x00, 0x00, 0x01, 0x80, 0x00,
+        0x41, 0x81, 0x08, 0x00, 0x80, 0x01, 0x00, 0x00, 0xc1, 0x81, 0x08, 0x00,
+        0x01, 0xc2, 0x08, 0x00, 0xa4, 0x01, 0x80, 0x01, 0x24, 0x81, 0x00, 0x00,
+        0x1f, 0xc0, 0x48, 0x02, 0x1e, 0x40, 0x04, 0x80, 0x00, 0x01, 0x80, 0x00,
+        0x41, 0x01, 0x09, 0x00, 0x80, 0x01, 0x00, 0x00, 0xc1, 0x01, 0x09, 0x00,
+        0x01, 0x42, 0x09, 0x00, 0xa4, 0x01, 0x80, 0x01, 0x24, 0x81, 0x00, 0x00,
+        0x1f, 0x40, 0x49, 0x02, 0x1e, 0x00, 0x02, 0x80, 0x00, 0x01, 0x80, 0x00,
+        0x41, 0x81, 0x09, 0x00, 0x80, 0x01, 0x00, 0x00, 0xc1, 0x81, 0x09, 0x00,
+        0x01, 0xc2, 0x09, 0x00, 0xa4, 0x01, 0x80, 0x01, 0x24, 0x81, 0x00, 0x00,
+        0x5f, 0xc0, 0x49, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00,
+        0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x41, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x41, 0x01, 0x0a, 0x00, 0x81, 0x41, 0x0a, 0x00,
+        0x24, 0x81, 0x80, 0x01, 0x1f, 0x80, 0x4a, 0x02, 0x1e, 0xc0, 0x02, 0x80,
+        0x00, 0x01, 0x00, 0x00, 0x41, 0xc1, 0x0a, 0x00, 0x81, 0x01, 0x0b, 0x00,
+        0x24, 0x81, 0x80, 0x01, 0x1f, 0x40, 0x4b, 0x02, 0x1e, 0x40, 0x01, 0x80,
+        0x00, 0x01, 0x00, 0x00, 0x41, 0x81, 0x0b, 0x00, 0x81, 0xc1, 0x0b, 0x00,
+        0x24, 0x81, 0x80, 0x01, 0x5f, 0x00, 0x4c, 0x02, 0x1e, 0x00, 0x00, 0x80,
+        0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01,
+        0xc6, 0x00, 0x41, 0x00, 0x00, 0x01, 0x00, 0x00, 0x41, 0x41, 0x0c, 0x00,
+        0x81, 0x81, 0x0c, 0x00, 0x24, 0x81, 0x80, 0x01, 0x1f, 0xc0, 0x4c, 0x02,
+        0x1e, 0x40, 0x01, 0x80, 0x00, 0x01, 0x80, 0x00, 0x41, 0x01, 0x0d, 0x00,
+        0x81, 0x01, 0x0b, 0x00, 0x24, 0x81, 0x80, 0x01, 0x5f, 0x80, 0x4c, 0x02,
+        0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00,
+        0xe4, 0x40, 0x00, 0x01, 0xc0, 0x00, 0x80, 0x00, 0x01, 0x41, 0x0d, 0x00,
+        0x41, 0x81, 0x0d, 0x00, 0xe4, 0x40, 0x81, 0x01, 0xc6, 0x01, 0x41, 0x00,
+        0x1f, 0xc0, 0xcd, 0x01, 0x1e, 0x40, 0x01, 0x80, 0x1f, 0x00, 0x4e, 0x02,
+        0x1e, 0xc0, 0x00, 0x80, 0x1f, 0x40, 0xce, 0x02, 0x1e, 0x40, 0x00, 0x80,
+        0x5f, 0x80, 0x4e, 0x03, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x42, 0x00, 0x00,
+        0x03, 0x02, 0x80, 0x00, 0xe4, 0x41, 0x00, 0x01, 0xc6, 0x01, 0x41, 0x00,
+        0x00, 0x02, 0x00, 0x01, 0x41, 0xc2, 0x0e, 0x00, 0x24, 0x82, 0x00, 0x01,
+        0x1f, 0x00, 0x4f, 0x04, 0x1e, 0x80, 0x03, 0x80, 0x00, 0x02, 0x00, 0x01,
+        0x41, 0x42, 0x0f, 0x00, 0x24, 0x82, 0x00, 0x01, 0x1f, 0x40, 0x45, 0x04,
+        0x1e, 0x40, 0x02, 0x80, 0x00, 0x02, 0x00, 0x01, 0x41, 0x82, 0x0f, 0x00,
+        0x24, 0x82, 0x00, 0x01, 0x1f, 0xc0, 0x4f, 0x04, 0x1e, 0x00, 0x01, 0x80,
+        0x00, 0x02, 0x00, 0x01, 0x41, 0x02, 0x10, 0x00, 0x24, 0x82, 0x00, 0x01,
+        0x5f, 0x00, 0x46, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x42, 0x00, 0x00,
+        0x03, 0x02, 0x80, 0x00, 0xe4, 0x41, 0x00, 0x01, 0xc6, 0x01, 0x41, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x41, 0x42, 0x10, 0x00, 0x81, 0x82, 0x01, 0x00,
+        0xc1, 0x82, 0x10, 0x00, 0x24, 0x82, 0x00, 0x02, 0x1f, 0xc0, 0x50, 0x04,
+        0x1e, 0x80, 0x01, 0x80, 0x00, 0x02, 0x00, 0x00, 0x41, 0x02, 0x11, 0x00,
+        0x81, 0x82, 0x01, 0x00, 0xc1, 0x82, 0x10, 0x00, 0x24, 0x82, 0x00, 0x02,
+        0x5f, 0x40, 0x51, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x42, 0x00, 0x00,
+        0x03, 0x02, 0x80, 0x00, 0xe4, 0x41, 0x00, 0x01, 0xc6, 0x01, 0x41, 0x00,
+        0x06, 0x82, 0x51, 0x00, 0x41, 0xc2, 0x11, 0x00, 0x80, 0x02, 0x80, 0x00,
+        0xc1, 0x02, 0x12, 0x00, 0x01, 0x43, 0x12, 0x00, 0xa4, 0x02, 0x80, 0x01,
+        0x24, 0x82, 0x00, 0x00, 0x1f, 0x80, 0x52, 0x04, 0x1e, 0x40, 0x02, 0x80,
+        0x06, 0x82, 0x51, 0x00, 0x41, 0x82, 0x10, 0x00, 0x80, 0x02, 0x80, 0x00,
+        0xc1, 0x82, 0x02, 0x00, 0x01, 0x43, 0x12, 0x00, 0x41, 0x83, 0x10, 0x00,
+        0xa4, 0x02, 0x00, 0x02, 0x24, 0x82, 0x00, 0x00, 0x5f, 0x80, 0x52, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x03, 0x42, 0x00, 0x00, 0x03, 0x02, 0x80, 0x00,
+        0xe4, 0x41, 0x00, 0x01, 0xc6, 0x01, 0x41, 0x00, 0x00, 0x02, 0x80, 0x00,
+        0x41, 0x82, 0x02, 0x00, 0x81, 0xc2, 0x12, 0x00, 0xc1, 0xc2, 0x02, 0x00,
+        0x24, 0x82, 0x00, 0x02, 0x5f, 0x80, 0x52, 0x04, 0x1e, 0x00, 0x00, 0x80,
+        0x03, 0x42, 0x00, 0x00, 0x03, 0x02, 0x80, 0x00, 0xe4, 0x41, 0x00, 0x01,
+        0xc6, 0x01, 0x53, 0x00, 0x00, 0x02, 0x00, 0x00, 0x41, 0x42, 0x13, 0x00,
+        0x81, 0x82, 0x01, 0x00, 0xe4, 0xc1, 0x00, 0x02, 0x46, 0x02, 0x41, 0x00,
+        0x5f, 0x80, 0x53, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00,
+        0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x53, 0x00,
+        0x80, 0x02, 0x00, 0x00, 0xc1, 0x82, 0x02, 0x00, 0x01, 0xc3, 0x13, 0x00,
+        0x64, 0xc2, 0x00, 0x02, 0x00, 0x02, 0x00, 0x05, 0xc0, 0x01, 0x80, 0x04,
+        0x46, 0x02, 0x41, 0x00, 0x5f, 0x00, 0x54, 0x04, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01,
+        0x46, 0x02, 0x53, 0x00, 0x80, 0x02, 0x00, 0x00, 0xc1, 0x42, 0x14, 0x00,
+        0x01, 0xc3, 0x02, 0x00, 0x64, 0xc2, 0x00, 0x02, 0x00, 0x02, 0x00, 0x05,
+        0xc0, 0x01, 0x80, 0x04, 0x46, 0x02, 0x41, 0x00, 0x5f, 0x80, 0x54, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x53, 0x00, 0x80, 0x02, 0x00, 0x00,
+        0xc1, 0xc2, 0x14, 0x00, 0x64, 0xc2, 0x80, 0x01, 0x00, 0x02, 0x00, 0x05,
+        0xc0, 0x01, 0x80, 0x04, 0x46, 0x02, 0x41, 0x00, 0x5f, 0x00, 0x55, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x53, 0x00, 0x80, 0x02, 0x00, 0x00,
+        0xc1, 0x42, 0x15, 0x00, 0x64, 0xc2, 0x80, 0x01, 0x00, 0x02, 0x00, 0x05,
+        0xc0, 0x01, 0x80, 0x04, 0x46, 0x02, 0x41, 0x00, 0x5f, 0x80, 0x55, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x53, 0x00, 0x80, 0x02, 0x00, 0x00,
+        0xc1, 0xc2, 0x15, 0x00, 0x01, 0x83, 0x0c, 0x00, 0x64, 0xc2, 0x00, 0x02,
+        0x00, 0x02, 0x00, 0x05, 0xc0, 0x01, 0x80, 0x04, 0x46, 0x02, 0x41, 0x00,
+        0x5f, 0x00, 0x56, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00,
+        0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x53, 0x00,
+        0x80, 0x02, 0x00, 0x00, 0xc1, 0xc2, 0x0a, 0x00, 0x01, 0xc3, 0x0b, 0x00,
+        0x0c, 0x03, 0x45, 0x06, 0x81, 0x43, 0x16, 0x00, 0x24, 0x03, 0x80, 0x01,
+        0x64, 0xc2, 0x00, 0x00, 0x00, 0x02, 0x00, 0x05, 0xc0, 0x01, 0x80, 0x04,
+        0x46, 0x02, 0x41, 0x00, 0x5f, 0x80, 0x56, 0x04, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01,
+        0x46, 0x02, 0x53, 0x00, 0x80, 0x02, 0x00, 0x00, 0xc1, 0x02, 0x0a, 0x00,
+        0x01, 0xc3, 0x16, 0x00, 0x64, 0xc2, 0x00, 0x02, 0x00, 0x02, 0x00, 0x05,
+        0xc0, 0x01, 0x80, 0x04, 0x46, 0x02, 0x41, 0x00, 0x5f, 0x00, 0x57, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x53, 0x00, 0x80, 0x02, 0x00, 0x00,
+        0xc1, 0x42, 0x17, 0x00, 0x01, 0x83, 0x01, 0x00, 0x64, 0xc2, 0x00, 0x02,
+        0x00, 0x02, 0x00, 0x05, 0xc0, 0x01, 0x80, 0x04, 0x46, 0x02, 0x41, 0x00,
+        0x5f, 0x80, 0x57, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00,
+        0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x53, 0x00,
+        0x80, 0x02, 0x00, 0x00, 0xc1, 0xc2, 0x17, 0x00, 0x64, 0xc2, 0x80, 0x01,
+        0x00, 0x02, 0x00, 0x05, 0xc0, 0x01, 0x80, 0x04, 0x46, 0x02, 0x41, 0x00,
+        0x5f, 0x00, 0x58, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00,
+        0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x53, 0x00,
+        0x80, 0x02, 0x00, 0x01, 0xc1, 0x42, 0x18, 0x00, 0x64, 0xc2, 0x80, 0x01,
+        0x00, 0x02, 0x00, 0x05, 0xc0, 0x01, 0x80, 0x04, 0x46, 0x02, 0x41, 0x00,
+        0x5f, 0x80, 0x58, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00,
+        0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x53, 0x00,
+        0x80, 0x02, 0x80, 0x00, 0xc1, 0xc2, 0x0e, 0x00, 0x01, 0x03, 0x0b, 0x00,
+        0x64, 0xc2, 0x00, 0x02, 0x00, 0x02, 0x00, 0x05, 0xc0, 0x01, 0x80, 0x04,
+        0x46, 0x02, 0x41, 0x00, 0x5f, 0xc0, 0x58, 0x04, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01,
+        0x46, 0x02, 0x53, 0x00, 0x80, 0x02, 0x80, 0x00, 0xc1, 0x02, 0x0a, 0x00,
+        0x01, 0x03, 0x0b, 0x00, 0x64, 0xc2, 0x00, 0x02, 0x00, 0x02, 0x00, 0x05,
+        0xc0, 0x01, 0x80, 0x04, 0x46, 0x02, 0x41, 0x00, 0x5f, 0x00, 0x59, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x53, 0x00, 0x80, 0x02, 0x80, 0x00,
+        0xc1, 0x42, 0x19, 0x00, 0x01, 0x03, 0x03, 0x00, 0x0c, 0x03, 0x45, 0x06,
+        0x81, 0x03, 0x06, 0x00, 0x24, 0x83, 0x80, 0x01, 0x41, 0x43, 0x06, 0x00,
+        0x1d, 0x43, 0x03, 0x06, 0x64, 0xc2, 0x00, 0x02, 0x00, 0x02, 0x00, 0x05,
+        0xc0, 0x01, 0x80, 0x04, 0x46, 0x02, 0x41, 0x00, 0x5f, 0x80, 0x59, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x53, 0x00, 0x80, 0x02, 0x80, 0x00,
+        0xc1, 0x82, 0x02, 0x00, 0x01, 0xc3, 0x19, 0x00, 0x41, 0x83, 0x12, 0x00,
+        0x64, 0xc2, 0x80, 0x02, 0x00, 0x02, 0x00, 0x05, 0xc0, 0x01, 0x80, 0x04,
+        0x46, 0x02, 0x41, 0x00, 0x5f, 0x00, 0x5a, 0x04, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01,
+        0x40, 0x02, 0x00, 0x00, 0x81, 0x42, 0x1a, 0x00, 0xc1, 0x82, 0x1a, 0x00,
+        0x01, 0x43, 0x04, 0x00, 0x41, 0xc3, 0x1a, 0x00, 0x65, 0x02, 0x80, 0x02,
+        0x66, 0x02, 0x00, 0x00, 0x26, 0x00, 0x80, 0x00, 0x6c, 0x00, 0x00, 0x00,
+        0x04, 0x07, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x04, 0x05, 0x70, 0x61,
+        0x63, 0x6b, 0x04, 0x07, 0x75, 0x6e, 0x70, 0x61, 0x63, 0x6b, 0x04, 0x09,
+        0x70, 0x61, 0x63, 0x6b, 0x73, 0x69, 0x7a, 0x65, 0x04, 0x07, 0x61, 0x73,
+        0x73, 0x65, 0x72, 0x74, 0x04, 0x04, 0x3c, 0x69, 0x34, 0x13, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x05, 0x01, 0x00, 0x00, 0x00,
+        0x04, 0x04, 0x3e, 0x69, 0x34, 0x04, 0x05, 0x00, 0x00, 0x00, 0x01, 0x04,
+        0x02, 0x62, 0x13, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x04,
+        0x02, 0xff, 0x04, 0x05, 0x3c, 0x68, 0x3e, 0x48, 0x13, 0x02, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x05, 0x02, 0x01, 0x01, 0x02, 0x04,
+        0x04, 0x3c, 0x69, 0x33, 0x13, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x04, 0x04, 0xfe, 0xff, 0xff, 0x04, 0x05, 0x3c, 0x69, 0x31, 0x36,
+        0x04, 0x04, 0x72, 0x65, 0x70, 0x13, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x04, 0x04, 0x3e, 0x49, 0x39, 0x04, 0x02, 0x00, 0x13, 0x08,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x01, 0x04, 0x05,
+        0xff, 0xff, 0xff, 0xff, 0x04, 0x04, 0x3c, 0x49, 0x34, 0x13, 0xff, 0xff,
+        0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x04, 0x03, 0x3e, 0x6a, 0x13, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f, 0x13, 0xfd, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0x04, 0x05, 0x3e, 0x49, 0x31, 0x32, 0x13, 0x4d,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x03, 0x3c, 0x64, 0x03,
+        0x9a, 0x99, 0x99, 0x99, 0x99, 0x99, 0xb9, 0x3f, 0x04, 0x03, 0x3e, 0x66,
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe0, 0x3f, 0x04, 0x02, 0x6e,
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0xc0, 0x04, 0x02, 0x7a,
+        0x04, 0x03, 0x68, 0x69, 0x04, 0x04, 0x68, 0x69, 0x00, 0x04, 0x03, 0x73,
+        0x31, 0x04, 0x04, 0x61, 0x62, 0x63, 0x04, 0x05, 0x03, 0x61, 0x62, 0x63,
+        0x04, 0x04, 0x3e, 0x73, 0x32, 0x04, 0x02, 0x78, 0x04, 0x04, 0x00, 0x01,
+        0x78, 0x04, 0x03, 0x63, 0x35, 0x04, 0x03, 0x61, 0x62, 0x04, 0x06, 0x61,
+        0x62, 0x00, 0x00, 0x00, 0x04, 0x03, 0x63, 0x32, 0x04, 0x08, 0x3c, 0x7a,
+        0x20, 0x73, 0x31, 0x20, 0x42, 0x04, 0x09, 0x68, 0x65, 0x79, 0x00, 0x02,
+        0x79, 0x6f, 0x07, 0x04, 0x04, 0x68, 0x65, 0x79, 0x04, 0x03, 0x79, 0x6f,
+        0x13, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x09, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x03, 0x69, 0x34, 0x13, 0x04,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x07, 0x21, 0x38, 0x20,
+        0x62, 0x20, 0x64, 0x04, 0x09, 0x3c, 0x21, 0x34, 0x20, 0x62, 0x20, 0x69,
+        0x38, 0x13, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x0a,
+        0x21, 0x62, 0x20, 0x58, 0x69, 0x34, 0x20, 0x69, 0x34, 0x04, 0x08, 0x21,
+        0x34, 0x20, 0x62, 0x20, 0x69, 0x34, 0x13, 0x02, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x09, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x04, 0x04, 0x62, 0x78, 0x68, 0x04, 0x05, 0x01, 0x00, 0x02, 0x00,
+        0x04, 0x07, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x04, 0x02, 0x23, 0x04,
+        0x04, 0x62, 0x20, 0x62, 0x04, 0x03, 0x01, 0x02, 0x13, 0x03, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x04, 0x01, 0x02, 0x03, 0x04, 0x06,
+        0x70, 0x63, 0x61, 0x6c, 0x6c, 0x04, 0x04, 0x69, 0x31, 0x37, 0x04, 0x28,
+        0x69, 0x6e, 0x74, 0x65, 0x67, 0x72, 0x61, 0x6c, 0x20, 0x73, 0x69, 0x7a,
+        0x65, 0x20, 0x28, 0x31, 0x37, 0x29, 0x20, 0x6f, 0x75, 0x74, 0x20, 0x6f,
+        0x66, 0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x73, 0x20, 0x5b, 0x31, 0x2c,
+        0x31, 0x36, 0x5d, 0x13, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x34, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65,
+        0x6e, 0x74, 0x20, 0x23, 0x32, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x73, 0x74,
+        0x72, 0x69, 0x6e, 0x67, 0x2e, 0x70, 0x61, 0x63, 0x6b, 0x27, 0x20, 0x28,
+        0x69, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x20, 0x6f, 0x76, 0x65, 0x72,
+        0x66, 0x6c, 0x6f, 0x77, 0x29, 0x04, 0x02, 0x42, 0x14, 0x35, 0x62, 0x61,
+        0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23,
+        0x32, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67,
+        0x2e, 0x70, 0x61, 0x63, 0x6b, 0x27, 0x20, 0x28, 0x75, 0x6e, 0x73, 0x69,
+        0x67, 0x6e, 0x65, 0x64, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x66, 0x6c, 0x6f,
+        0x77, 0x29, 0x04, 0x02, 0x79, 0x04, 0x1a, 0x69, 0x6e, 0x76, 0x61, 0x6c,
+        0x69, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x20, 0x6f, 0x70,
+        0x74, 0x69, 0x6f, 0x6e, 0x20, 0x27, 0x79, 0x27, 0x04, 0x02, 0x63, 0x04,
+        0x23, 0x6d, 0x69, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x73, 0x69, 0x7a,
+        0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74,
+        0x20, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x27, 0x63, 0x27, 0x04,
+        0x03, 0x63, 0x31, 0x14, 0x41, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67,
+        0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x32, 0x20, 0x74, 0x6f, 0x20,
+        0x27, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x70, 0x61, 0x63, 0x6b,
+        0x27, 0x20, 0x28, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x6c, 0x6f,
+        0x6e, 0x67, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x67, 0x69,
+        0x76, 0x65, 0x6e, 0x20, 0x73, 0x69, 0x7a, 0x65, 0x29, 0x13, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x4c, 0x62, 0x61, 0x64, 0x20,
+        0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x32, 0x20,
+        0x74, 0x6f, 0x20, 0x27, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x70,
+        0x61, 0x63, 0x6b, 0x27, 0x20, 0x28, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67,
+        0x20, 0x6c, 0x65, 0x6e, 0x67, 0x74, 0x68, 0x20, 0x64, 0x6f, 0x65, 0x73,
+        0x20, 0x6e, 0x6f, 0x74, 0x20, 0x66, 0x69, 0x74, 0x20, 0x69, 0x6e, 0x20,
+        0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x73, 0x69, 0x7a, 0x65, 0x29, 0x04,
+        0x04, 0x61, 0x00, 0x62, 0x14, 0x39, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72,
+        0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x32, 0x20, 0x74, 0x6f,
+        0x20, 0x27, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x70, 0x61, 0x63,
+        0x6b, 0x27, 0x20, 0x28, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x63,
+        0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x20, 0x7a, 0x65, 0x72, 0x6f,
+        0x73, 0x29, 0x04, 0x06, 0x21, 0x33, 0x20, 0x69, 0x34, 0x14, 0x4c, 0x62,
+        0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20,
+        0x23, 0x31, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x73, 0x74, 0x72, 0x69, 0x6e,
+        0x67, 0x2e, 0x70, 0x61, 0x63, 0x6b, 0x27, 0x20, 0x28, 0x66, 0x6f, 0x72,
+        0x6d, 0x61, 0x74, 0x20, 0x61, 0x73, 0x6b, 0x73, 0x20, 0x66, 0x6f, 0x72,
+        0x20, 0x61, 0x6c, 0x69, 0x67, 0x6e, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x6e,
+        0x6f, 0x74, 0x20, 0x70, 0x6f, 0x77, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20,
+        0x32, 0x29, 0x04, 0x02, 0x58, 0x14, 0x46, 0x62, 0x61, 0x64, 0x20, 0x61,
+        0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x31, 0x20, 0x74,
+        0x6f, 0x20, 0x27, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x70, 0x61,
+        0x63, 0x6b, 0x27, 0x20, 0x28, 0x69, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64,
+        0x20, 0x6e, 0x65, 0x78, 0x74, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e,
+        0x20, 0x66, 0x6f, 0x72, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+        0x27, 0x58, 0x27, 0x29, 0x04, 0x02, 0x73, 0x14, 0x3e, 0x62, 0x61, 0x64,
+        0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x31,
+        0x20, 0x74, 0x6f, 0x20, 0x27, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e,
+        0x70, 0x61, 0x63, 0x6b, 0x73, 0x69, 0x7a, 0x65, 0x27, 0x20, 0x28, 0x76,
+        0x61, 0x72, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x2d, 0x6c, 0x65, 0x6e, 0x67,
+        0x74, 0x68, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x29, 0x14, 0x3b,
+        0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74,
+        0x20, 0x23, 0x32, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x73, 0x74, 0x72, 0x69,
+        0x6e, 0x67, 0x2e, 0x75, 0x6e, 0x70, 0x61, 0x63, 0x6b, 0x27, 0x20, 0x28,
+        0x64, 0x61, 0x74, 0x61, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20,
+        0x74, 0x6f, 0x6f, 0x20, 0x73, 0x68, 0x6f, 0x72, 0x74, 0x29, 0x14, 0x46,
+        0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74,
+        0x20, 0x23, 0x32, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x73, 0x74, 0x72, 0x69,
+        0x6e, 0x67, 0x2e, 0x75, 0x6e, 0x70, 0x61, 0x63, 0x6b, 0x27, 0x20, 0x28,
+        0x75, 0x6e, 0x66, 0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x20, 0x73,
+        0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x66, 0x6f,
+        0x72, 0x6d, 0x61, 0x74, 0x20, 0x27, 0x7a, 0x27, 0x29, 0x04, 0x04, 0x3c,
+        0x69, 0x39, 0x14, 0x2d, 0x39, 0x2d, 0x62, 0x79, 0x74, 0x65, 0x20, 0x69,
+        0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x20, 0x64, 0x6f, 0x65, 0x73, 0x20,
+        0x6e, 0x6f, 0x74, 0x20, 0x66, 0x69, 0x74, 0x20, 0x69, 0x6e, 0x74, 0x6f,
+        0x20, 0x4c, 0x75, 0x61, 0x20, 0x49, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72,
+        0x04, 0x02, 0x61, 0x14, 0x44, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67,
+        0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x33, 0x20, 0x74, 0x6f, 0x20,
+        0x27, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x75, 0x6e, 0x70, 0x61,
+        0x63, 0x6b, 0x27, 0x20, 0x28, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c,
+        0x20, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x75,
+        0x74, 0x20, 0x6f, 0x66, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x29,
+        0x04, 0x09, 0x3e, 0x49, 0x32, 0x20, 0x69, 0x38, 0x20, 0x64, 0x13, 0x01,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xf8, 0x3f, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xe9, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00,
+        0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00,
+        0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00,
+        0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00,
+        0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1f, 0x00,
+        0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00,
+        0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00,
+        0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00,
+        0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00,
+        0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00,
+        0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00,
+        0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00,
+        0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00,
+        0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00,
+        0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00,
+        0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00,
+        0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00,
+        0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00,
+        0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00,
+        0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00,
+        0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00,
+        0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00,
+        0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x28, 0x00,
+        0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00,
+        0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x29, 0x00,
+        0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00,
+        0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00,
+        0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00,
+        0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00,
+        0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00,
+        0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00,
+        0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00,
+        0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00,
+        0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00,
+        0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00,
+        0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00,
+        0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00,
+        0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2e, 0x00,
+        0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00,
+        0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2f, 0x00,
+        0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00,
+        0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00,
+        0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x05, 0x70,
+        0x61, 0x63, 0x6b, 0x06, 0x00, 0x00, 0x00, 0xe9, 0x01, 0x00, 0x00, 0x07,
+        0x75, 0x6e, 0x70, 0x61, 0x63, 0x6b, 0x06, 0x00, 0x00, 0x00, 0xe9, 0x01,
+        0x00, 0x00, 0x09, 0x70, 0x61, 0x63, 0x6b, 0x73, 0x69, 0x7a, 0x65, 0x06,
+        0x00, 0x00, 0x00, 0xe9, 0x01, 0x00, 0x00, 0x02, 0x61, 0xc3, 0x00, 0x00,
+        0x00, 0xe9, 0x01, 0x00, 0x00, 0x02, 0x62, 0xc3, 0x00, 0x00, 0x00, 0xe9,
+        0x01, 0x00, 0x00, 0x02, 0x63, 0xc3, 0x00, 0x00, 0x00, 0xe9, 0x01, 0x00,
+        0x00, 0x04, 0x6e, 0x78, 0x74, 0xc3, 0x00, 0x00, 0x00, 0xe9, 0x01, 0x00,
+        0x00, 0x03, 0x6f, 0x6b, 0x20, 0x01, 0x00, 0x00, 0xe9, 0x01, 0x00, 0x00,
+        0x04, 0x65, 0x72, 0x72, 0x20, 0x01, 0x00, 0x00, 0xe9, 0x01, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
+
+    #[test]
+    fn test_pack() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        ls.requiref("_G", open_base, true);
+        ls.requiref("string", open_string, true);
+        ls.pop(2);
+
+        ls.load(LUA_PACK_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 1, 0);
+        assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+        assert_eq!(
+            ls.to_bytes(1).unwrap(),
+            vec![2, 1, 255, 255, 255, 255, 255, 255, 255, 254, 63, 248, 0, 0, 0, 0, 0, 0]
+        );
+    }
+}
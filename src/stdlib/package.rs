@@ -0,0 +1,905 @@
+use crate::api::consts::*;
+use crate::api::{LuaAPI, LuaAuxLib, LuaUserdata, RustFn};
+use std::env;
+use std::fs::File;
+
+// environment variables that may hold the path for Lua modules
+const LUA_PATH_VAR: &str = "LUA_PATH_5_3";
+const LUA_PATH_VAR_FALLBACK: &str = "LUA_PATH";
+
+const LUA_PATH_DEFAULT: &str = "/usr/local/share/lua/5.3/?.lua;/usr/local/share/lua/5.3/?/init.lua;\
+                                /usr/local/lib/lua/5.3/?.lua;/usr/local/lib/lua/5.3/?/init.lua;\
+                                ./?.lua;./?/init.lua";
+
+const LUA_DIRSEP: &str = "/"; // directory separator
+const LUA_PATH_SEP: &str = ";"; // separates templates in a path
+const LUA_PATH_MARK: &str = "?"; // marks the substitution points in a template
+const LUA_EXEC_DIR: &str = "!"; // replaced by the executable's directory (Windows only)
+const LUA_IGMARK: &str = "-"; // ignore everything before it when building `luaopen_` names
+
+// mark used in a path to denote the default path
+const AUXMARK: &str = "\u{1}";
+
+// key, in the registry, for table of preloaded loaders
+const LUA_PRELOAD_TABLE: &str = "_PRELOAD";
+
+const PK_FUNCS: &[(&str, RustFn)] = &[("loadlib", ll_loadlib), ("searchpath", ll_searchpath)];
+
+const LL_FUNCS: &[(&str, RustFn)] = &[("require", ll_require)];
+
+const SEARCHERS: &[RustFn] = &[searcher_preload, searcher_lua];
+
+/*
+    Searcher supplied by the host: given a module name, it returns the
+    chunk to load and its chunk name (e.g. "@db/mod.lua"), or None when it
+    does not know the module.
+*/
+pub type HostSearcher = dyn Fn(&str) -> Option<(Vec<u8>, String)>;
+
+/*
+    The `package` library reads module files from the filesystem (and
+    `LUA_PATH` from the environment), so like `io` it must be opened
+    explicitly. States without it can still `require` in-memory modules
+    through `open_require`, which `add_searcher` opens for them.
+*/
+pub fn open_package(ls: &mut dyn LuaAPI) -> usize {
+    ls.new_lib(PK_FUNCS); // create 'package' table
+    create_searchers_table(ls, SEARCHERS);
+    set_path(ls, "path", LUA_PATH_VAR, LUA_PATH_VAR_FALLBACK, LUA_PATH_DEFAULT);
+    // store config information
+    let config = [LUA_DIRSEP, LUA_PATH_SEP, LUA_PATH_MARK, LUA_EXEC_DIR, LUA_IGMARK];
+    ls.push_string(config.iter().map(|s| format!("{}\n", s)).collect());
+    ls.set_field(-2, "config");
+    set_loaded(ls);
+    1 // return 'package' table
+}
+
+/*
+    A `package` library without filesystem access: `require` with only
+    the preload searcher, and `loaded`, `preload` and `searchers`, but no
+    `path`, `searchpath` or `loadlib`. Host searchers are added to it
+    with `add_searcher`.
+*/
+pub fn open_require(ls: &mut dyn LuaAPI) -> usize {
+    ls.create_table(0, 3); // create 'package' table
+    create_searchers_table(ls, &[searcher_preload]);
+    set_loaded(ls);
+    1 // return 'package' table
+}
+
+// Set the fields 'loaded' and 'preload' of the 'package' table on top
+// and the global 'require'.
+fn set_loaded(ls: &mut dyn LuaAPI) {
+    // set field 'loaded'
+    ls.get_subtable(LUA_REGISTRY_INDEX, "_LOADED");
+    ls.set_field(-2, "loaded");
+    // set field 'preload'
+    ls.get_subtable(LUA_REGISTRY_INDEX, LUA_PRELOAD_TABLE);
+    ls.set_field(-2, "preload");
+    ls.push_global_table();
+    ls.push_value(-2); // set 'package' as upvalue for next lib
+    ls.set_funcs(LL_FUNCS, 1); // open lib into global table
+    ls.pop(1); // pop global table
+}
+
+fn create_searchers_table(ls: &mut dyn LuaAPI, searchers: &[RustFn]) {
+    // create 'searchers' table
+    ls.create_table(searchers.len(), 0);
+    // fill it with predefined searchers
+    for (i, searcher) in searchers.iter().enumerate() {
+        ls.push_value(-2); // set 'package' as upvalue for all searchers
+        ls.push_rust_closure(*searcher, 1);
+        ls.raw_set_i(-2, i as i64 + 1);
+    }
+    ls.set_field(-2, "searchers"); // put it in field 'searchers'
+}
+
+/*
+    Append `searcher` to `package.searchers`, after the preload and Lua
+    file searchers, so `require` falls back to it. States without the
+    package library get `open_require` first. Returns false if
+    `package.searchers` is not a table.
+*/
+pub fn add_searcher<F>(ls: &mut dyn LuaAPI, searcher: F) -> bool
+where
+    F: Fn(&str) -> Option<(Vec<u8>, String)> + 'static,
+{
+    ls.get_field(LUA_REGISTRY_INDEX, "_LOADED");
+    if ls.get_field(-1, "package") != LUA_TTABLE {
+        ls.pop(1);
+        ls.requiref("package", open_require, true);
+    }
+    if ls.get_field(-1, "searchers") != LUA_TTABLE {
+        ls.pop(3);
+        return false;
+    }
+    let searcher: Box<HostSearcher> = Box::new(searcher);
+    ls.new_userdata(searcher);
+    ls.push_rust_closure(searcher_host, 1);
+    let n = ls.raw_len(-2) as i64;
+    ls.raw_set_i(-2, n + 1); // searchers[#searchers + 1] = searcher
+    ls.pop(3); // pop 'searchers', 'package' and '_LOADED'
+    true
+}
+
+// Set `package[fieldname]` from the environment, where ";;" stands for
+// the default path.
+fn set_path(ls: &mut dyn LuaAPI, fieldname: &str, envname1: &str, envname2: &str, def: &str) {
+    let path = env::var(envname1).or_else(|_| env::var(envname2));
+    let path = match path {
+        Err(_) => def.to_string(), // no environment variable
+        Ok(path) => {
+            // replace ";;" by ";AUXMARK;" and then AUXMARK by default path
+            let sep2 = format!("{}{}", LUA_PATH_SEP, LUA_PATH_SEP);
+            let aux = format!("{}{}{}", LUA_PATH_SEP, AUXMARK, LUA_PATH_SEP);
+            path.replace(&sep2, &aux).replace(AUXMARK, def)
+        }
+    };
+    ls.push_string(path);
+    ls.set_field(-2, fieldname);
+}
+
+/*
+    LOADLIB: dynamic libraries are not supported, native modules have
+    to be registered by the host (in `package.preload` or with a searcher).
+*/
+
+// package.loadlib (libname, funcname)
+// http://www.lua.org/manual/5.3/manual.html#pdf-package.loadlib
+fn ll_loadlib(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_string(1);
+    ls.check_string(2);
+    ls.push_nil();
+    ls.push_string("dynamic libraries not enabled; check your Lua installation".to_string());
+    ls.push_string("absent".to_string());
+    3 // return nil, error message, and where
+}
+
+/*
+    'require' function
+*/
+
+// Does the file exist and is readable?
+fn readable(filename: &str) -> bool {
+    File::open(filename).is_ok()
+}
+
+// Search `name` through the templates of `path`, returning the first
+// readable file, or the message listing every file tried.
+fn search_path(name: &str, path: &str, sep: &str, dirsep: &str) -> Result<String, String> {
+    let name = if sep.is_empty() {
+        name.to_string()
+    } else {
+        name.replace(sep, dirsep) // replace it by 'dirsep'
+    };
+    let mut msg = String::new(); // to build error message
+    for template in path.split(LUA_PATH_SEP).filter(|t| !t.is_empty()) {
+        let filename = template.replace(LUA_PATH_MARK, &name);
+        if readable(&filename) {
+            // does file exist and is readable?
+            return Ok(filename); // return that file name
+        }
+        msg.push_str(&format!("\n\tno file '{}'", filename));
+    }
+    Err(msg) // not found
+}
+
+// package.searchpath (name, path [, sep [, rep]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-package.searchpath
+fn ll_searchpath(ls: &mut dyn LuaAPI) -> usize {
+    let name = ls.check_string(1);
+    let path = ls.check_string(2);
+    let sep = ls.opt_string(3, ".");
+    let dirsep = ls.opt_string(4, LUA_DIRSEP);
+    match search_path(&name, &path, &sep, &dirsep) {
+        Ok(filename) => {
+            ls.push_string(filename);
+            1
+        }
+        Err(msg) => {
+            // error message is on top of the stack
+            ls.push_nil();
+            ls.push_string(msg);
+            2 // return nil + error message
+        }
+    }
+}
+
+fn find_file(ls: &mut dyn LuaAPI, name: &str, pname: &str, dirsep: &str) -> Result<String, String> {
+    ls.get_field(upvalue_index(1), pname);
+    let path = match ls.to_stringx(-1) {
+        Some(path) => path,
+        None => ls.error_l(&format!("'package.{}' must be a string", pname)),
+    };
+    ls.pop(1);
+    search_path(name, &path, ".", dirsep)
+}
+
+fn check_load(ls: &mut dyn LuaAPI, stat: bool, filename: &str) -> usize {
+    if stat {
+        // module loaded successfully?
+        ls.push_string(filename.to_string()); // will be 2nd argument to module
+        2 // return open function and file name
+    } else {
+        let modname = ls.to_string(1);
+        let err = ls.to_string(-1);
+        ls.error_l(&format!(
+            "error loading module '{}' from file '{}':\n\t{}",
+            modname, filename, err
+        ))
+    }
+}
+
+fn searcher_lua(ls: &mut dyn LuaAPI) -> usize {
+    let name = ls.check_string(1);
+    match find_file(ls, &name, "path", LUA_DIRSEP) {
+        Ok(filename) => {
+            let stat = ls.load_file(Some(&filename), "bt") == LUA_OK;
+            check_load(ls, stat, &filename)
+        }
+        Err(msg) => {
+            ls.push_string(msg);
+            1 // module not found in this path
+        }
+    }
+}
+
+fn searcher_preload(ls: &mut dyn LuaAPI) -> usize {
+    let name = ls.check_string(1);
+    ls.get_field(LUA_REGISTRY_INDEX, LUA_PRELOAD_TABLE);
+    if ls.get_field(-1, &name) == LUA_TNIL {
+        // not found?
+        ls.push_string(format!("\n\tno field package.preload['{}']", name));
+    }
+    1
+}
+
+fn searcher_host(ls: &mut dyn LuaAPI) -> usize {
+    let name = ls.check_string(1);
+    let searcher = ls.to_userdata::<Box<HostSearcher>>(upvalue_index(1)).unwrap();
+    let found = (searcher.borrow())(&name);
+    match found {
+        Some((chunk, chunk_name)) => {
+            let stat = ls.load(chunk, &chunk_name, "bt", None) == LUA_OK;
+            // name to report, without the '@' or '=' of the chunk name
+            let filename = match chunk_name.chars().next() {
+                Some('@') | Some('=') => chunk_name[1..].to_string(),
+                _ => chunk_name,
+            };
+            check_load(ls, stat, &filename)
+        }
+        None => {
+            ls.push_string(format!("\n\tno module '{}' in host searcher", name));
+            1
+        }
+    }
+}
+
+fn find_loader(ls: &mut dyn LuaAPI, name: &str) {
+    let mut msg = Vec::new(); // to build error message
+    // push 'package.searchers' to index 3 in the stack
+    if ls.get_field(upvalue_index(1), "searchers") != LUA_TTABLE {
+        ls.error_l("'package.searchers' must be a table");
+    }
+    // iterate over available searchers to find a loader
+    for i in 1.. {
+        if ls.raw_get_i(3, i) == LUA_TNIL {
+            // no more searchers?
+            ls.pop(1); // remove nil
+            let msg = String::from_utf8_lossy(&msg).into_owned();
+            ls.error_l(&format!("module '{}' not found:{}", name, msg));
+        }
+        ls.push_string(name.to_string());
+        ls.call(1, 2); // call it
+        if ls.is_function(-2) {
+            // did it find a loader?
+            return; // module loader found
+        } else if ls.is_string(-2) {
+            // searcher returned error message?
+            ls.pop(1); // remove extra return
+            msg.extend(ls.to_bytes(-1).unwrap()); // concatenate error message
+            ls.pop(1);
+        } else {
+            ls.pop(2); // remove both returns
+        }
+    }
+}
+
+// require (modname)
+// http://www.lua.org/manual/5.3/manual.html#pdf-require
+fn ll_require(ls: &mut dyn LuaAPI) -> usize {
+    let name = ls.check_string(1);
+    ls.set_top(1); // LOADED table will be at index 2
+    ls.get_field(LUA_REGISTRY_INDEX, "_LOADED");
+    ls.get_field(2, &name); // LOADED[name]
+    if ls.to_boolean(-1) {
+        // is it there?
+        return 1; // package is already loaded
+    }
+    // else must load package
+    ls.pop(1); // remove 'getfield' result
+    find_loader(ls, &name);
+    ls.push_string(name.clone()); // pass name as argument to module loader
+    ls.insert(-2); // name is 1st argument (before search data)
+    ls.call(2, 1); // run loader to load module
+    if !ls.is_nil(-1) {
+        // non-nil return?
+        ls.set_field(2, &name); // LOADED[name] = returned value
+    }
+    if ls.get_field(2, &name) == LUA_TNIL {
+        // module set no value?
+        ls.push_boolean(true); // use true as result
+        ls.push_value(-1); // extra copy to be returned
+        ls.set_field(2, &name); // LOADED[name] = true
+    }
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LuaState;
+    use crate::stdlib::open_libs;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::fs;
+    use std::rc::Rc;
+
+    /* Lua source code:
+        local dir = MODDIR
+        package.path = dir .. "/?.luac;" .. dir .. "/?/init.luac"
+
+        local m = require("m1")
+        assert(m.answer == 42 and m.name == "m1" and m.path == dir .. "/m1.luac")
+        assert(require("m1") == m and package.loaded.m1 == m)
+
+        -- modules that return nothing are recorded as true
+        assert(require("quiet") == true and QUIET == 1)
+        assert(require("quiet") == true and QUIET == 1)
+        assert(require("sub") == true and QUIET == 2)
+
+        package.preload.pre = function(name, extra)
+          return {name = name, extra = extra}
+        end
+        local p = require("pre")
+        assert(p.name == "pre" and p.extra == nil)
+
+        -- runtime errors point at the module file
+        local ok, err = pcall(require, "bad")
+        assert(not ok and err == "bad.lua:1: boom", err)
+        assert(package.loaded.bad == nil)
+
+        ok, err = pcall(require, "nope.sub")
+        assert(not ok)
+        assert(err:find("module 'nope.sub' not found:\n" ..
+          "\tno field package.preload['nope.sub']\n" ..
+          "\tno file '" .. dir .. "/nope/sub.luac'\n" ..
+          "\tno file '" .. dir .. "/nope/sub/init.luac'", 1, true) == 1, err)
+
+        -- modules served by the host
+        if HOSTED then
+          assert(err:sub(-#"\n\tno module 'nope.sub' in host searcher") ==
+                 "\n\tno module 'nope.sub' in host searcher", err)
+          local h = require("hosted")
+          assert(h.name == "hosted" and h.src == "db/hosted.lua")
+          -- stripped chunks are named after where they were loaded from
+          assert(h.where == "db/hosted.lua", h.where)
+          ok, err = pcall(require, "txt")
+          assert(not ok and err:find("error loading module 'txt' from file 'db/txt.lua':\n\t" ..
+            "db/txt.lua: cannot load text chunk (no compiler)", 1, true), err)
+        end
+
+        assert(package.searchpath("m1", package.path) == dir .. "/m1.luac")
+        assert(package.searchpath("sub", package.path) == dir .. "/sub/init.luac")
+        local f, e = package.searchpath("x.y", "a/?.z;b/?.w")
+        assert(f == nil and e == "\n\tno file 'a/x/y.z'\n\tno file 'b/x/y.w'")
+        assert(package.searchpath("x.y", "?", "", "") == nil)
+        assert(select(2, package.searchpath("x.y", "a/?", "")) == "\n\tno file 'a/x.y'")
+
+        package.path = {}
+        ok, err = pcall(require, "nothere")
+        assert(not ok and err:find("'package.path' must be a string", 1, true))
+        package.path = dir .. "/?.luac"
+
+        local searchers = package.searchers
+        package.searchers = nil
+        ok, err = pcall(require, "nothere")
+        assert(not ok and err:find("'package.searchers' must be a table", 1, true))
+        package.searchers = searchers
+
+        assert(type(package.config) == "string" and package.config:sub(1, 2) == "/\n")
+        assert(package.loaded._G == _G and package.loaded.package == package)
+    */
+    const LUA_PACKAGE_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x07, 0x40,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x10, 0x66, 0x01, 0x00, 0x00, 0x06, 0x00, 0x40, 0x00,
+        0x46, 0x40, 0x40, 0x00, 0x80, 0x00, 0x00, 0x00, 0xc1, 0xc0, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x41, 0x01, 0x01, 0x00, 0x9d, 0x40, 0x01, 0x01,
+        0x4a, 0x80, 0x00, 0x81, 0x46, 0x40, 0x41, 0x00, 0x81, 0x80, 0x01, 0x00,
+        0x64, 0x80, 0x00, 0x01, 0x86, 0xc0, 0x41, 0x00, 0xc7, 0x00, 0xc2, 0x00,
+        0x1f, 0x40, 0xc2, 0x01, 0x1e, 0x00, 0x02, 0x80, 0xc7, 0x80, 0xc2, 0x00,
+        0x1f, 0x80, 0xc1, 0x01, 0x1e, 0x40, 0x01, 0x80, 0xc7, 0x80, 0xc0, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x41, 0xc1, 0x02, 0x00, 0x1d, 0x41, 0x01, 0x02,
+        0x5f, 0x00, 0x81, 0x01, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00,
+        0xc3, 0x00, 0x80, 0x00, 0xa4, 0x40, 0x00, 0x01, 0x86, 0xc0, 0x41, 0x00,
+        0xc6, 0x40, 0x41, 0x00, 0x01, 0x81, 0x01, 0x00, 0xe4, 0x80, 0x00, 0x01,
+        0x1f, 0x40, 0x80, 0x01, 0x1e, 0x00, 0x01, 0x80, 0xc6, 0x40, 0x40, 0x00,
+        0xc7, 0x00, 0xc3, 0x01, 0xc7, 0x80, 0xc1, 0x01, 0x5f, 0x40, 0x80, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0xc0, 0x41, 0x00, 0xc6, 0x40, 0x41, 0x00,
+        0x01, 0x41, 0x03, 0x00, 0xe4, 0x80, 0x00, 0x01, 0x1f, 0x80, 0xc3, 0x01,
+        0x1e, 0x80, 0x00, 0x80, 0xc6, 0xc0, 0x43, 0x00, 0x5f, 0x00, 0xc4, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0xc0, 0x41, 0x00, 0xc6, 0x40, 0x41, 0x00,
+        0x01, 0x41, 0x03, 0x00, 0xe4, 0x80, 0x00, 0x01, 0x1f, 0x80, 0xc3, 0x01,
+        0x1e, 0x80, 0x00, 0x80, 0xc6, 0xc0, 0x43, 0x00, 0x5f, 0x00, 0xc4, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0xc0, 0x41, 0x00, 0xc6, 0x40, 0x41, 0x00,
+        0x01, 0x41, 0x04, 0x00, 0xe4, 0x80, 0x00, 0x01, 0x1f, 0x80, 0xc3, 0x01,
+        0x1e, 0x80, 0x00, 0x80, 0xc6, 0xc0, 0x43, 0x00, 0x5f, 0x80, 0xc4, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0x40, 0x40, 0x00, 0x87, 0xc0, 0x44, 0x01,
+        0xec, 0x00, 0x00, 0x00, 0x8a, 0xc0, 0x00, 0x8a, 0x86, 0x40, 0x41, 0x00,
+        0xc1, 0x00, 0x05, 0x00, 0xa4, 0x80, 0x00, 0x01, 0xc6, 0xc0, 0x41, 0x00,
+        0x07, 0x41, 0x45, 0x01, 0x1f, 0x00, 0x45, 0x02, 0x1e, 0x80, 0x00, 0x80,
+        0x07, 0x81, 0x45, 0x01, 0x5f, 0xc0, 0x45, 0x02, 0x1e, 0x00, 0x00, 0x80,
+        0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01,
+        0xc6, 0x00, 0x46, 0x00, 0x06, 0x41, 0x41, 0x00, 0x41, 0x41, 0x06, 0x00,
+        0xe4, 0xc0, 0x80, 0x01, 0x46, 0xc1, 0x41, 0x00, 0xe2, 0x40, 0x00, 0x00,
+        0x1e, 0x40, 0x00, 0x80, 0x5f, 0x80, 0x46, 0x02, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80, 0x00, 0xc0, 0x01, 0x00, 0x02,
+        0x64, 0x41, 0x80, 0x01, 0x46, 0xc1, 0x41, 0x00, 0x86, 0x41, 0x40, 0x00,
+        0x87, 0x01, 0x43, 0x03, 0x87, 0x41, 0x46, 0x03, 0x5f, 0xc0, 0x45, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80, 0x00,
+        0x64, 0x41, 0x00, 0x01, 0x46, 0x01, 0x46, 0x00, 0x86, 0x41, 0x41, 0x00,
+        0xc1, 0xc1, 0x06, 0x00, 0x64, 0xc1, 0x80, 0x01, 0x00, 0x01, 0x00, 0x03,
+        0xc0, 0x00, 0x80, 0x02, 0x46, 0xc1, 0x41, 0x00, 0x9b, 0x01, 0x80, 0x01,
+        0x64, 0x41, 0x00, 0x01, 0x46, 0xc1, 0x41, 0x00, 0x8c, 0x01, 0x47, 0x02,
+        0x01, 0x42, 0x07, 0x00, 0x41, 0x82, 0x07, 0x00, 0x81, 0xc2, 0x07, 0x00,
+        0xc0, 0x02, 0x00, 0x00, 0x01, 0x03, 0x08, 0x00, 0x41, 0xc3, 0x07, 0x00,
+        0x80, 0x03, 0x00, 0x00, 0xc1, 0x43, 0x08, 0x00, 0x1d, 0xc2, 0x03, 0x04,
+        0x41, 0x02, 0x04, 0x00, 0x83, 0x02, 0x80, 0x00, 0xa4, 0x81, 0x80, 0x02,
+        0x5f, 0x00, 0x44, 0x03, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x41, 0x00, 0x00,
+        0x83, 0x01, 0x80, 0x00, 0xc0, 0x01, 0x00, 0x02, 0x64, 0x41, 0x80, 0x01,
+        0x46, 0x81, 0x48, 0x00, 0x62, 0x01, 0x00, 0x00, 0x1e, 0x40, 0x0d, 0x80,
+        0x46, 0xc1, 0x41, 0x00, 0x8c, 0x41, 0x44, 0x02, 0x01, 0xc2, 0x08, 0x00,
+        0x1c, 0x02, 0x00, 0x04, 0x19, 0x02, 0x00, 0x04, 0xa4, 0x81, 0x80, 0x01,
+        0x5f, 0xc0, 0x48, 0x03, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x41, 0x00, 0x00,
+        0x83, 0x01, 0x80, 0x00, 0xc0, 0x01, 0x00, 0x02, 0x64, 0x41, 0x80, 0x01,
+        0x46, 0x41, 0x41, 0x00, 0x81, 0x01, 0x09, 0x00, 0x64, 0x81, 0x00, 0x01,
+        0x86, 0xc1, 0x41, 0x00, 0xc7, 0x41, 0xc5, 0x02, 0x1f, 0x00, 0xc9, 0x03,
+        0x1e, 0x80, 0x00, 0x80, 0xc7, 0x41, 0xc9, 0x02, 0x5f, 0x80, 0xc9, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00,
+        0xa4, 0x41, 0x00, 0x01, 0x86, 0xc1, 0x41, 0x00, 0xc7, 0xc1, 0xc9, 0x02,
+        0x5f, 0x80, 0xc9, 0x03, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00,
+        0xc3, 0x01, 0x80, 0x00, 0x07, 0xc2, 0xc9, 0x02, 0xa4, 0x41, 0x80, 0x01,
+        0x86, 0x01, 0x46, 0x00, 0xc6, 0x41, 0x41, 0x00, 0x01, 0x02, 0x0a, 0x00,
+        0xa4, 0xc1, 0x80, 0x01, 0x00, 0x01, 0x80, 0x03, 0xc0, 0x00, 0x00, 0x03,
+        0x86, 0xc1, 0x41, 0x00, 0xe2, 0x40, 0x00, 0x00, 0x1e, 0xc0, 0x01, 0x80,
+        0xcc, 0x01, 0x47, 0x02, 0x41, 0x42, 0x0a, 0x00, 0x81, 0x82, 0x0a, 0x00,
+        0x5d, 0x82, 0x82, 0x04, 0x81, 0x02, 0x04, 0x00, 0xc3, 0x02, 0x80, 0x00,
+        0xe4, 0x81, 0x80, 0x02, 0x1e, 0x40, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00,
+        0xc3, 0x01, 0x80, 0x00, 0x00, 0x02, 0x00, 0x02, 0xa4, 0x41, 0x80, 0x01,
+        0x46, 0xc1, 0x41, 0x00, 0x86, 0x41, 0x40, 0x00, 0x87, 0xc1, 0x4a, 0x03,
+        0xc1, 0x81, 0x01, 0x00, 0x06, 0x42, 0x40, 0x00, 0x07, 0x82, 0x40, 0x04,
+        0xa4, 0x81, 0x80, 0x01, 0xc0, 0x01, 0x00, 0x00, 0x01, 0xc2, 0x02, 0x00,
+        0xdd, 0x01, 0x82, 0x03, 0x5f, 0xc0, 0x01, 0x03, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80, 0x00, 0x64, 0x41, 0x00, 0x01,
+        0x46, 0xc1, 0x41, 0x00, 0x86, 0x41, 0x40, 0x00, 0x87, 0xc1, 0x4a, 0x03,
+        0xc1, 0x41, 0x04, 0x00, 0x06, 0x42, 0x40, 0x00, 0x07, 0x82, 0x40, 0x04,
+        0xa4, 0x81, 0x80, 0x01, 0xc0, 0x01, 0x00, 0x00, 0x01, 0x02, 0x0b, 0x00,
+        0xdd, 0x01, 0x82, 0x03, 0x5f, 0xc0, 0x01, 0x03, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x41, 0x00, 0x00, 0x83, 0x01, 0x80, 0x00, 0x64, 0x41, 0x00, 0x01,
+        0x46, 0x41, 0x40, 0x00, 0x47, 0xc1, 0xca, 0x02, 0x81, 0x41, 0x0b, 0x00,
+        0xc1, 0x81, 0x0b, 0x00, 0x64, 0xc1, 0x80, 0x01, 0xc6, 0xc1, 0x41, 0x00,
+        0x1f, 0xc0, 0xc5, 0x02, 0x1e, 0x40, 0x00, 0x80, 0x5f, 0xc0, 0x4b, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0x03, 0x42, 0x00, 0x00, 0x03, 0x02, 0x80, 0x00,
+        0xe4, 0x41, 0x00, 0x01, 0xc6, 0xc1, 0x41, 0x00, 0x06, 0x42, 0x40, 0x00,
+        0x07, 0xc2, 0x4a, 0x04, 0x41, 0x42, 0x0b, 0x00, 0x81, 0x02, 0x0c, 0x00,
+        0xc1, 0x42, 0x0c, 0x00, 0x01, 0x43, 0x0c, 0x00, 0x24, 0x82, 0x80, 0x02,
+        0x5f, 0xc0, 0x45, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x42, 0x00, 0x00,
+        0x03, 0x02, 0x80, 0x00, 0xe4, 0x41, 0x00, 0x01, 0xc6, 0xc1, 0x41, 0x00,
+        0x06, 0x82, 0x4c, 0x00, 0x41, 0x82, 0x04, 0x00, 0x86, 0x42, 0x40, 0x00,
+        0x87, 0xc2, 0x4a, 0x05, 0xc1, 0x42, 0x0b, 0x00, 0x01, 0xc3, 0x0c, 0x00,
+        0x41, 0x43, 0x0c, 0x00, 0xa4, 0x02, 0x00, 0x02, 0x24, 0x82, 0x00, 0x00,
+        0x5f, 0x00, 0x4d, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x42, 0x00, 0x00,
+        0x03, 0x02, 0x80, 0x00, 0xe4, 0x41, 0x00, 0x01, 0xc6, 0x41, 0x40, 0x00,
+        0x0b, 0x02, 0x00, 0x00, 0xca, 0x01, 0x02, 0x81, 0xc6, 0x01, 0x46, 0x00,
+        0x06, 0x42, 0x41, 0x00, 0x41, 0x42, 0x0d, 0x00, 0xe4, 0xc1, 0x80, 0x01,
+        0x00, 0x01, 0x00, 0x04, 0xc0, 0x00, 0x80, 0x03, 0xc6, 0xc1, 0x41, 0x00,
+        0xe2, 0x40, 0x00, 0x00, 0x1e, 0x40, 0x01, 0x80, 0x0c, 0x02, 0x47, 0x02,
+        0x81, 0x82, 0x0d, 0x00, 0xc1, 0x02, 0x04, 0x00, 0x03, 0x03, 0x80, 0x00,
+        0x24, 0x82, 0x80, 0x02, 0x1e, 0x40, 0x00, 0x80, 0x03, 0x42, 0x00, 0x00,
+        0x03, 0x02, 0x80, 0x00, 0xe4, 0x41, 0x00, 0x01, 0xc6, 0x41, 0x40, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x41, 0xc2, 0x0d, 0x00, 0x1d, 0x42, 0x02, 0x04,
+        0xca, 0x01, 0x02, 0x81, 0xc6, 0x41, 0x40, 0x00, 0xc7, 0x01, 0xce, 0x03,
+        0x06, 0x42, 0x40, 0x00, 0x0a, 0xc2, 0x45, 0x9c, 0x06, 0x02, 0x46, 0x00,
+        0x46, 0x42, 0x41, 0x00, 0x81, 0x42, 0x0d, 0x00, 0x24, 0xc2, 0x80, 0x01,
+        0x00, 0x01, 0x80, 0x04, 0xc0, 0x00, 0x00, 0x04, 0x06, 0xc2, 0x41, 0x00,
+        0xe2, 0x40, 0x00, 0x00, 0x1e, 0x40, 0x01, 0x80, 0x4c, 0x02, 0x47, 0x02,
+        0xc1, 0x42, 0x0e, 0x00, 0x01, 0x03, 0x04, 0x00, 0x43, 0x03, 0x80, 0x00,
+        0x64, 0x82, 0x80, 0x02, 0x1e, 0x40, 0x00, 0x80, 0x43, 0x42, 0x00, 0x00,
+        0x43, 0x02, 0x80, 0x00, 0x24, 0x42, 0x00, 0x01, 0x06, 0x42, 0x40, 0x00,
+        0x0a, 0xc2, 0x01, 0x9c, 0x06, 0xc2, 0x41, 0x00, 0x46, 0x82, 0x4e, 0x00,
+        0x86, 0x42, 0x40, 0x00, 0x87, 0xc2, 0x4e, 0x05, 0x64, 0x82, 0x00, 0x01,
+        0x1f, 0x00, 0xcf, 0x04, 0x1e, 0xc0, 0x01, 0x80, 0x46, 0x42, 0x40, 0x00,
+        0x47, 0xc2, 0xce, 0x04, 0x4c, 0x42, 0xc4, 0x04, 0xc1, 0x02, 0x04, 0x00,
+        0x01, 0x83, 0x04, 0x00, 0x64, 0x82, 0x00, 0x02, 0x5f, 0x40, 0xcf, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x42, 0x00, 0x00, 0x43, 0x02, 0x80, 0x00,
+        0x24, 0x42, 0x00, 0x01, 0x06, 0xc2, 0x41, 0x00, 0x46, 0x42, 0x40, 0x00,
+        0x47, 0x02, 0xc3, 0x04, 0x47, 0x82, 0xcf, 0x04, 0x86, 0x82, 0x4f, 0x00,
+        0x1f, 0x80, 0x82, 0x04, 0x1e, 0x40, 0x01, 0x80, 0x46, 0x42, 0x40, 0x00,
+        0x47, 0x02, 0xc3, 0x04, 0x47, 0x42, 0xc0, 0x04, 0x86, 0x42, 0x40, 0x00,
+        0x5f, 0x80, 0x82, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x42, 0x00, 0x00,
+        0x43, 0x02, 0x80, 0x00, 0x24, 0x42, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00,
+        0x3f, 0x00, 0x00, 0x00, 0x04, 0x07, 0x4d, 0x4f, 0x44, 0x44, 0x49, 0x52,
+        0x04, 0x08, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x04, 0x05, 0x70,
+        0x61, 0x74, 0x68, 0x04, 0x09, 0x2f, 0x3f, 0x2e, 0x6c, 0x75, 0x61, 0x63,
+        0x3b, 0x04, 0x0d, 0x2f, 0x3f, 0x2f, 0x69, 0x6e, 0x69, 0x74, 0x2e, 0x6c,
+        0x75, 0x61, 0x63, 0x04, 0x08, 0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65,
+        0x04, 0x03, 0x6d, 0x31, 0x04, 0x07, 0x61, 0x73, 0x73, 0x65, 0x72, 0x74,
+        0x04, 0x07, 0x61, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x13, 0x2a, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x05, 0x6e, 0x61, 0x6d, 0x65, 0x04,
+        0x09, 0x2f, 0x6d, 0x31, 0x2e, 0x6c, 0x75, 0x61, 0x63, 0x04, 0x07, 0x6c,
+        0x6f, 0x61, 0x64, 0x65, 0x64, 0x04, 0x06, 0x71, 0x75, 0x69, 0x65, 0x74,
+        0x01, 0x01, 0x04, 0x06, 0x51, 0x55, 0x49, 0x45, 0x54, 0x13, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x04, 0x73, 0x75, 0x62, 0x13,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x08, 0x70, 0x72,
+        0x65, 0x6c, 0x6f, 0x61, 0x64, 0x04, 0x04, 0x70, 0x72, 0x65, 0x04, 0x05,
+        0x6e, 0x61, 0x6d, 0x65, 0x04, 0x06, 0x65, 0x78, 0x74, 0x72, 0x61, 0x00,
+        0x04, 0x06, 0x70, 0x63, 0x61, 0x6c, 0x6c, 0x04, 0x04, 0x62, 0x61, 0x64,
+        0x04, 0x10, 0x62, 0x61, 0x64, 0x2e, 0x6c, 0x75, 0x61, 0x3a, 0x31, 0x3a,
+        0x20, 0x62, 0x6f, 0x6f, 0x6d, 0x04, 0x09, 0x6e, 0x6f, 0x70, 0x65, 0x2e,
+        0x73, 0x75, 0x62, 0x04, 0x05, 0x66, 0x69, 0x6e, 0x64, 0x04, 0x1e, 0x6d,
+        0x6f, 0x64, 0x75, 0x6c, 0x65, 0x20, 0x27, 0x6e, 0x6f, 0x70, 0x65, 0x2e,
+        0x73, 0x75, 0x62, 0x27, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x66, 0x6f, 0x75,
+        0x6e, 0x64, 0x3a, 0x0a, 0x04, 0x27, 0x09, 0x6e, 0x6f, 0x20, 0x66, 0x69,
+        0x65, 0x6c, 0x64, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x2e,
+        0x70, 0x72, 0x65, 0x6c, 0x6f, 0x61, 0x64, 0x5b, 0x27, 0x6e, 0x6f, 0x70,
+        0x65, 0x2e, 0x73, 0x75, 0x62, 0x27, 0x5d, 0x0a, 0x04, 0x0b, 0x09, 0x6e,
+        0x6f, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x27, 0x04, 0x11, 0x2f, 0x6e,
+        0x6f, 0x70, 0x65, 0x2f, 0x73, 0x75, 0x62, 0x2e, 0x6c, 0x75, 0x61, 0x63,
+        0x27, 0x0a, 0x04, 0x15, 0x2f, 0x6e, 0x6f, 0x70, 0x65, 0x2f, 0x73, 0x75,
+        0x62, 0x2f, 0x69, 0x6e, 0x69, 0x74, 0x2e, 0x6c, 0x75, 0x61, 0x63, 0x27,
+        0x04, 0x07, 0x48, 0x4f, 0x53, 0x54, 0x45, 0x44, 0x04, 0x28, 0x0a, 0x09,
+        0x6e, 0x6f, 0x20, 0x6d, 0x6f, 0x64, 0x75, 0x6c, 0x65, 0x20, 0x27, 0x6e,
+        0x6f, 0x70, 0x65, 0x2e, 0x73, 0x75, 0x62, 0x27, 0x20, 0x69, 0x6e, 0x20,
+        0x68, 0x6f, 0x73, 0x74, 0x20, 0x73, 0x65, 0x61, 0x72, 0x63, 0x68, 0x65,
+        0x72, 0x04, 0x07, 0x68, 0x6f, 0x73, 0x74, 0x65, 0x64, 0x04, 0x04, 0x73,
+        0x72, 0x63, 0x04, 0x0e, 0x64, 0x62, 0x2f, 0x68, 0x6f, 0x73, 0x74, 0x65,
+        0x64, 0x2e, 0x6c, 0x75, 0x61, 0x04, 0x06, 0x77, 0x68, 0x65, 0x72, 0x65,
+        0x04, 0x04, 0x74, 0x78, 0x74, 0x14, 0x35, 0x65, 0x72, 0x72, 0x6f, 0x72,
+        0x20, 0x6c, 0x6f, 0x61, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x6d, 0x6f, 0x64,
+        0x75, 0x6c, 0x65, 0x20, 0x27, 0x74, 0x78, 0x74, 0x27, 0x20, 0x66, 0x72,
+        0x6f, 0x6d, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x27, 0x64, 0x62, 0x2f,
+        0x74, 0x78, 0x74, 0x2e, 0x6c, 0x75, 0x61, 0x27, 0x3a, 0x0a, 0x09, 0x14,
+        0x31, 0x64, 0x62, 0x2f, 0x74, 0x78, 0x74, 0x2e, 0x6c, 0x75, 0x61, 0x3a,
+        0x20, 0x63, 0x61, 0x6e, 0x6e, 0x6f, 0x74, 0x20, 0x6c, 0x6f, 0x61, 0x64,
+        0x20, 0x74, 0x65, 0x78, 0x74, 0x20, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x20,
+        0x28, 0x6e, 0x6f, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x72,
+        0x29, 0x04, 0x0b, 0x73, 0x65, 0x61, 0x72, 0x63, 0x68, 0x70, 0x61, 0x74,
+        0x68, 0x04, 0x0f, 0x2f, 0x73, 0x75, 0x62, 0x2f, 0x69, 0x6e, 0x69, 0x74,
+        0x2e, 0x6c, 0x75, 0x61, 0x63, 0x04, 0x04, 0x78, 0x2e, 0x79, 0x04, 0x0c,
+        0x61, 0x2f, 0x3f, 0x2e, 0x7a, 0x3b, 0x62, 0x2f, 0x3f, 0x2e, 0x77, 0x04,
+        0x27, 0x0a, 0x09, 0x6e, 0x6f, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x27,
+        0x61, 0x2f, 0x78, 0x2f, 0x79, 0x2e, 0x7a, 0x27, 0x0a, 0x09, 0x6e, 0x6f,
+        0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x27, 0x62, 0x2f, 0x78, 0x2f, 0x79,
+        0x2e, 0x77, 0x27, 0x04, 0x02, 0x3f, 0x04, 0x01, 0x04, 0x07, 0x73, 0x65,
+        0x6c, 0x65, 0x63, 0x74, 0x04, 0x04, 0x61, 0x2f, 0x3f, 0x04, 0x12, 0x0a,
+        0x09, 0x6e, 0x6f, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x27, 0x61, 0x2f,
+        0x78, 0x2e, 0x79, 0x27, 0x04, 0x08, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72,
+        0x65, 0x04, 0x20, 0x27, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x2e,
+        0x70, 0x61, 0x74, 0x68, 0x27, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x62,
+        0x65, 0x20, 0x61, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x04, 0x08,
+        0x2f, 0x3f, 0x2e, 0x6c, 0x75, 0x61, 0x63, 0x04, 0x0a, 0x73, 0x65, 0x61,
+        0x72, 0x63, 0x68, 0x65, 0x72, 0x73, 0x04, 0x24, 0x27, 0x70, 0x61, 0x63,
+        0x6b, 0x61, 0x67, 0x65, 0x2e, 0x73, 0x65, 0x61, 0x72, 0x63, 0x68, 0x65,
+        0x72, 0x73, 0x27, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x62, 0x65, 0x20,
+        0x61, 0x20, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x04, 0x05, 0x74, 0x79, 0x70,
+        0x65, 0x04, 0x07, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x04, 0x07, 0x73,
+        0x74, 0x72, 0x69, 0x6e, 0x67, 0x04, 0x03, 0x2f, 0x0a, 0x04, 0x03, 0x5f,
+        0x47, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x0d, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x02, 0x00, 0x03, 0x05,
+        0x00, 0x00, 0x00, 0x8b, 0x80, 0x00, 0x00, 0x8a, 0x00, 0x00, 0x80, 0x8a,
+        0x40, 0x80, 0x80, 0xa6, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x04, 0x05, 0x6e, 0x61, 0x6d, 0x65, 0x04, 0x06, 0x65,
+        0x78, 0x74, 0x72, 0x61, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00,
+        0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x05, 0x6e, 0x61, 0x6d, 0x65, 0x00, 0x00, 0x00,
+        0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x65, 0x78, 0x74, 0x72, 0x61, 0x00,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x66,
+        0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x09,
+        0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09,
+        0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09,
+        0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09,
+        0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x0a,
+        0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a,
+        0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a,
+        0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a,
+        0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0b,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x15,
+        0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15,
+        0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15,
+        0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x19,
+        0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x1a,
+        0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1b,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d,
+        0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x26,
+        0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26,
+        0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26,
+        0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27,
+        0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27,
+        0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28,
+        0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28,
+        0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x29,
+        0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x29,
+        0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29,
+        0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x30,
+        0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30,
+        0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30,
+        0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30,
+        0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33,
+        0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x34,
+        0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x34,
+        0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35,
+        0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35,
+        0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35,
+        0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35,
+        0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x36,
+        0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x36, 0x00, 0x00, 0x00, 0x38,
+        0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00, 0x39, 0x00, 0x00, 0x00, 0x39,
+        0x00, 0x00, 0x00, 0x3a, 0x00, 0x00, 0x00, 0x3a, 0x00, 0x00, 0x00, 0x3a,
+        0x00, 0x00, 0x00, 0x3a, 0x00, 0x00, 0x00, 0x3a, 0x00, 0x00, 0x00, 0x3a,
+        0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b,
+        0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b,
+        0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b,
+        0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x3b,
+        0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x3c, 0x00, 0x00, 0x00, 0x3e,
+        0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e,
+        0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e,
+        0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e,
+        0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e,
+        0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e,
+        0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x3f,
+        0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x3f,
+        0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x3f,
+        0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x3f,
+        0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x3f,
+        0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x3f,
+        0x00, 0x00, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x04,
+        0x64, 0x69, 0x72, 0x01, 0x00, 0x00, 0x00, 0x66, 0x01, 0x00, 0x00, 0x02,
+        0x6d, 0x0b, 0x00, 0x00, 0x00, 0x66, 0x01, 0x00, 0x00, 0x02, 0x70, 0x54,
+        0x00, 0x00, 0x00, 0x66, 0x01, 0x00, 0x00, 0x03, 0x6f, 0x6b, 0x62, 0x00,
+        0x00, 0x00, 0x66, 0x01, 0x00, 0x00, 0x04, 0x65, 0x72, 0x72, 0x62, 0x00,
+        0x00, 0x00, 0x66, 0x01, 0x00, 0x00, 0x02, 0x68, 0xa3, 0x00, 0x00, 0x00,
+        0xca, 0x00, 0x00, 0x00, 0x02, 0x66, 0xed, 0x00, 0x00, 0x00, 0x66, 0x01,
+        0x00, 0x00, 0x02, 0x65, 0xed, 0x00, 0x00, 0x00, 0x66, 0x01, 0x00, 0x00,
+        0x0a, 0x73, 0x65, 0x61, 0x72, 0x63, 0x68, 0x65, 0x72, 0x73, 0x2d, 0x01,
+        0x00, 0x00, 0x66, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f,
+        0x45, 0x4e, 0x56,
+    ];
+
+    /* Lua source code:
+        local name, path = ...
+        return {name = name, path = path, answer = 42}
+    */
+    const MOD_M1: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x08, 0x40,
+        0x6d, 0x31, 0x2e, 0x6c, 0x75, 0x61, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x03, 0x07, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x80,
+        0x01, 0x8b, 0xc0, 0x00, 0x00, 0x8a, 0x00, 0x00, 0x80, 0x8a, 0x40, 0x80,
+        0x80, 0x8a, 0xc0, 0x40, 0x81, 0xa6, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80,
+        0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x05, 0x6e, 0x61, 0x6d, 0x65, 0x04,
+        0x05, 0x70, 0x61, 0x74, 0x68, 0x04, 0x07, 0x61, 0x6e, 0x73, 0x77, 0x65,
+        0x72, 0x13, 0x2a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, 0x6e, 0x61, 0x6d,
+        0x65, 0x01, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x05, 0x70, 0x61,
+        0x74, 0x68, 0x01, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
+
+    /* Lua source code:
+        error("boom")
+    */
+    const MOD_BAD: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x09, 0x40,
+        0x62, 0x61, 0x64, 0x2e, 0x6c, 0x75, 0x61, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x04, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x40, 0x00, 0x41, 0x40, 0x00, 0x00, 0x24, 0x40, 0x00, 0x01, 0x26, 0x00,
+        0x80, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0x06, 0x65, 0x72, 0x72, 0x6f,
+        0x72, 0x04, 0x05, 0x62, 0x6f, 0x6f, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45,
+        0x4e, 0x56,
+    ];
+
+    /* Lua source code:
+        QUIET = (QUIET or 0) + 1
+    */
+    const MOD_QUIET: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x0b, 0x40,
+        0x71, 0x75, 0x69, 0x65, 0x74, 0x2e, 0x6c, 0x75, 0x61, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x07, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x40, 0x00, 0x22, 0x40, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x80,
+        0x01, 0x40, 0x00, 0x00, 0x0d, 0x80, 0x40, 0x00, 0x08, 0x00, 0x00, 0x80,
+        0x26, 0x00, 0x80, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04, 0x06, 0x51, 0x55,
+        0x49, 0x45, 0x54, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05,
+        0x5f, 0x45, 0x4e, 0x56,
+    ];
+
+    /* Lua source code (stripped):
+        local name, src = ...
+        return {name = name, src = src, where = caller_src()}
+    */
+    const MOD_HOSTED: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x04, 0x09, 0x00,
+        0x00, 0x00, 0x2d, 0x00, 0x80, 0x01, 0x8b, 0xc0, 0x00, 0x00, 0x8a, 0x00,
+        0x00, 0x80, 0x8a, 0x40, 0x80, 0x80, 0xc6, 0xc0, 0x40, 0x00, 0xe4, 0x80,
+        0x80, 0x00, 0x8a, 0xc0, 0x00, 0x81, 0xa6, 0x00, 0x00, 0x01, 0x26, 0x00,
+        0x80, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x05, 0x6e, 0x61, 0x6d, 0x65,
+        0x04, 0x04, 0x73, 0x72, 0x63, 0x04, 0x06, 0x77, 0x68, 0x65, 0x72, 0x65,
+        0x04, 0x0b, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x5f, 0x73, 0x72, 0x63,
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    // Short source of the calling function.
+    fn caller_src(ls: &mut dyn LuaAPI) -> usize {
+        let mut ar = ls.get_stack(1).unwrap();
+        ls.get_info("S", &mut ar);
+        ls.push_string(ar.short_src);
+        1
+    }
+
+    #[test]
+    fn test_package_lib() {
+        let dir = std::env::temp_dir().join(format!("rslua-package-{}", std::process::id()));
+        fs::create_dir_all(dir.join("sub")).unwrap();
+        fs::write(dir.join("m1.luac"), MOD_M1).unwrap();
+        fs::write(dir.join("bad.luac"), MOD_BAD).unwrap();
+        fs::write(dir.join("quiet.luac"), MOD_QUIET).unwrap();
+        fs::write(dir.join("sub/init.luac"), MOD_QUIET).unwrap();
+
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        open_libs(&mut *ls);
+        ls.requiref("package", open_package, true);
+        ls.pop(1);
+
+        let mut modules = HashMap::new();
+        modules.insert("hosted", (MOD_HOSTED.to_vec(), "@db/hosted.lua".to_string()));
+        modules.insert("txt", (b"return 1".to_vec(), "@db/txt.lua".to_string()));
+        assert!(add_searcher(&mut *ls, move |name| modules.get(name).cloned()));
+
+        ls.push_string(dir.to_str().unwrap().to_string());
+        ls.set_global("MODDIR");
+        ls.push_boolean(true);
+        ls.set_global("HOSTED");
+        ls.register("caller_src", caller_src);
+        ls.load(LUA_PACKAGE_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 0, 0);
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+    }
+
+    #[test]
+    fn test_add_searcher_without_package() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        open_libs(&mut *ls);
+        ls.register("caller_src", caller_src);
+        assert!(add_searcher(&mut *ls, |name| match name {
+            "hosted" => Some((MOD_HOSTED.to_vec(), "@db/hosted.lua".to_string())),
+            _ => None,
+        }));
+        assert_eq!(ls.get_top(), 0);
+
+        // require works on the host modules, without any file access
+        ls.get_global("require");
+        ls.push_string("hosted".to_string());
+        assert_eq!(ls.pcall(1, 1, 0), LUA_OK, "{}", ls.to_string(-1));
+        ls.get_field(-1, "src");
+        assert_eq!(ls.to_string(-1), "db/hosted.lua");
+        ls.pop(2);
+        ls.get_global("package");
+        for name in &["path", "searchpath", "loadlib"] {
+            assert_eq!(ls.get_field(-1, name), LUA_TNIL);
+            ls.pop(1);
+        }
+        ls.get_field(-1, "searchers");
+        assert_eq!(ls.raw_len(-1), 2); // preload and the host searcher
+        ls.pop(2);
+        ls.get_global("require");
+        ls.push_string("nope".to_string());
+        assert_eq!(ls.pcall(1, 1, 0), LUA_ERRRUN);
+        assert_eq!(
+            ls.to_string(-1),
+            "module 'nope' not found:\n\tno field package.preload['nope']\n\tno module 'nope' in host searcher"
+        );
+    }
+}
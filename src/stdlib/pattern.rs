@@ -0,0 +1,394 @@
+// Lua patterns, a port of the pattern matching part of lstrlib.c
+// Positions are 0-based offsets into the subject and the pattern, errors are
+// the messages to raise with `error_l`.
+
+pub const SPECIALS: &[u8] = b"^$*+?.([%-";
+const MAXCCALLS: usize = 200; // maximum recursion depth for 'do_match'
+const LUA_MAXCAPTURES: usize = 32;
+const CAP_UNFINISHED: isize = -1;
+const CAP_POSITION: isize = -2;
+const L_ESC: u8 = b'%';
+
+pub type MatchResult = Result<Option<usize>, String>;
+
+pub enum Capture<'a> {
+    Str(&'a [u8]),
+    Position(usize), // 1-based, like string.find
+}
+
+pub struct MatchState<'a> {
+    pub src: &'a [u8],
+    pat: &'a [u8],
+    matchdepth: usize, // control for recursive depth (to avoid stack overflow)
+    pub level: usize,  // total number of captures (finished or unfinished)
+    capture: [(usize, isize); LUA_MAXCAPTURES], // (init, len)
+}
+
+impl<'a> MatchState<'a> {
+    pub fn new(src: &'a [u8], pat: &'a [u8]) -> MatchState<'a> {
+        MatchState {
+            src,
+            pat,
+            matchdepth: MAXCCALLS,
+            level: 0,
+            capture: [(0, 0); LUA_MAXCAPTURES],
+        }
+    }
+
+    // Reset the state before a new match attempt.
+    pub fn reprep(&mut self) {
+        self.level = 0;
+        self.matchdepth = MAXCCALLS;
+    }
+
+    // Match the subject from `s` against the pattern from `p`, returning the
+    // end of the match.
+    pub fn do_match(&mut self, mut s: usize, mut p: usize) -> MatchResult {
+        if self.matchdepth == 0 {
+            return Err("pattern too complex".to_string());
+        }
+        self.matchdepth -= 1;
+        let pat = self.pat;
+        let r = loop {
+            if p == pat.len() {
+                break Some(s); // end of pattern
+            }
+            match pat[p] {
+                b'(' => {
+                    // start capture
+                    break if pat.get(p + 1) == Some(&b')') {
+                        self.start_capture(s, p + 2, CAP_POSITION)?
+                    } else {
+                        self.start_capture(s, p + 1, CAP_UNFINISHED)?
+                    };
+                }
+                b')' => break self.end_capture(s, p + 1)?, // end capture
+                b'$' if p + 1 == pat.len() => {
+                    // is the '$' the last char in pattern?
+                    break if s == self.src.len() { Some(s) } else { None };
+                }
+                L_ESC if pat.get(p + 1) == Some(&b'b') => {
+                    // balanced string?
+                    match self.match_balance(s, p + 2)? {
+                        Some(e) => {
+                            s = e;
+                            p += 4;
+                        }
+                        None => break None,
+                    }
+                }
+                L_ESC if pat.get(p + 1) == Some(&b'f') => {
+                    // frontier?
+                    p += 2;
+                    if pat.get(p) != Some(&b'[') {
+                        return Err("missing '[' after '%f' in pattern".to_string());
+                    }
+                    let ep = self.class_end(p)?; // points to what is next
+                    let previous = if s == 0 { 0 } else { self.src[s - 1] };
+                    let current = self.src.get(s).copied().unwrap_or(0);
+                    if !self.match_bracket_class(previous, p, ep - 1)
+                        && self.match_bracket_class(current, p, ep - 1)
+                    {
+                        p = ep;
+                    } else {
+                        break None; // match failed
+                    }
+                }
+                L_ESC if pat.get(p + 1).is_some_and(u8::is_ascii_digit) => {
+                    // capture results (%0-%9)?
+                    match self.match_capture(s, pat[p + 1])? {
+                        Some(e) => {
+                            s = e;
+                            p += 2;
+                        }
+                        None => break None,
+                    }
+                }
+                _ => {
+                    let ep = self.class_end(p)?; // points to optional suffix
+                    let epc = pat.get(ep).copied();
+                    if !self.single_match(s, p, ep) {
+                        // does not match at least once?
+                        if let Some(b'*') | Some(b'?') | Some(b'-') = epc {
+                            // accept empty?
+                            p = ep + 1;
+                            continue;
+                        }
+                        break None; // '+' or no suffix
+                    }
+                    // matched once
+                    match epc {
+                        Some(b'?') => {
+                            if let Some(r) = self.do_match(s + 1, ep + 1)? {
+                                break Some(r);
+                            }
+                            p = ep + 1;
+                        }
+                        Some(b'+') => break self.max_expand(s + 1, p, ep)?, // 1 match already done
+                        Some(b'*') => break self.max_expand(s, p, ep)?, // 0 or more repetitions
+                        Some(b'-') => break self.min_expand(s, p, ep)?, // 0 or more repetitions (minimum)
+                        _ => {
+                            // no suffix
+                            s += 1;
+                            p = ep;
+                        }
+                    }
+                }
+            }
+        };
+        self.matchdepth += 1;
+        Ok(r)
+    }
+
+    // Capture `i` of a match of `s..e`, the whole match when there are no
+    // explicit captures.
+    pub fn get_capture(&self, i: usize, s: usize, e: usize) -> Result<Capture<'a>, String> {
+        if i >= self.level {
+            if i == 0 {
+                Ok(Capture::Str(&self.src[s..e])) // add whole match
+            } else {
+                Err(format!("invalid capture index %{}", i + 1))
+            }
+        } else {
+            let (init, l) = self.capture[i];
+            match l {
+                CAP_UNFINISHED => Err("unfinished capture".to_string()),
+                CAP_POSITION => Ok(Capture::Position(init + 1)),
+                l => Ok(Capture::Str(&self.src[init..init + l as usize])),
+            }
+        }
+    }
+
+    fn class_end(&self, mut p: usize) -> Result<usize, String> {
+        let pat = self.pat;
+        let c = pat[p];
+        p += 1;
+        if c == L_ESC {
+            if p >= pat.len() {
+                return Err("malformed pattern (ends with '%')".to_string());
+            }
+            Ok(p + 1)
+        } else if c == b'[' {
+            if pat.get(p) == Some(&b'^') {
+                p += 1;
+            }
+            loop {
+                // look for a ']'
+                if p >= pat.len() {
+                    return Err("malformed pattern (missing ']')".to_string());
+                }
+                let cc = pat[p];
+                p += 1;
+                if cc == L_ESC && p < pat.len() {
+                    p += 1; // skip escapes (e.g. '%]')
+                }
+                if pat.get(p) == Some(&b']') {
+                    break;
+                }
+            }
+            Ok(p + 1)
+        } else {
+            Ok(p)
+        }
+    }
+
+    // `p` is the '[' of the class and `ec` its closing ']'.
+    fn match_bracket_class(&self, c: u8, mut p: usize, ec: usize) -> bool {
+        let pat = self.pat;
+        let mut sig = true;
+        if pat[p + 1] == b'^' {
+            sig = false;
+            p += 1; // skip the '^'
+        }
+        loop {
+            p += 1;
+            if p >= ec {
+                break;
+            }
+            if pat[p] == L_ESC {
+                p += 1;
+                if match_class(c, pat[p]) {
+                    return sig;
+                }
+            } else if pat[p + 1] == b'-' && p + 2 < ec {
+                p += 2;
+                if pat[p - 2] <= c && c <= pat[p] {
+                    return sig;
+                }
+            } else if pat[p] == c {
+                return sig;
+            }
+        }
+        !sig
+    }
+
+    fn single_match(&self, s: usize, p: usize, ep: usize) -> bool {
+        match self.src.get(s) {
+            None => false,
+            Some(&c) => match self.pat[p] {
+                b'.' => true, // matches any char
+                L_ESC => match_class(c, self.pat[p + 1]),
+                b'[' => self.match_bracket_class(c, p, ep - 1),
+                pc => pc == c,
+            },
+        }
+    }
+
+    fn match_balance(&self, s: usize, p: usize) -> MatchResult {
+        if p + 1 >= self.pat.len() {
+            return Err("malformed pattern (missing arguments to '%b')".to_string());
+        }
+        if self.src.get(s) != Some(&self.pat[p]) {
+            return Ok(None);
+        }
+        let (b, e) = (self.pat[p], self.pat[p + 1]);
+        let mut cont = 1;
+        for (i, &c) in self.src.iter().enumerate().skip(s + 1) {
+            if c == e {
+                cont -= 1;
+                if cont == 0 {
+                    return Ok(Some(i + 1));
+                }
+            } else if c == b {
+                cont += 1;
+            }
+        }
+        Ok(None) // string ends out of balance
+    }
+
+    fn max_expand(&mut self, s: usize, p: usize, ep: usize) -> MatchResult {
+        let mut i = 0; // counts maximum expand for item
+        while self.single_match(s + i, p, ep) {
+            i += 1;
+        }
+        // keeps trying to match with the maximum repetitions
+        loop {
+            if let Some(r) = self.do_match(s + i, ep + 1)? {
+                return Ok(Some(r));
+            }
+            if i == 0 {
+                return Ok(None);
+            }
+            i -= 1; // else didn't match; reduce 1 repetition to try again
+        }
+    }
+
+    fn min_expand(&mut self, mut s: usize, p: usize, ep: usize) -> MatchResult {
+        loop {
+            if let Some(r) = self.do_match(s, ep + 1)? {
+                return Ok(Some(r));
+            } else if self.single_match(s, p, ep) {
+                s += 1; // try with one more repetition
+            } else {
+                return Ok(None);
+            }
+        }
+    }
+
+    fn start_capture(&mut self, s: usize, p: usize, what: isize) -> MatchResult {
+        if self.level >= LUA_MAXCAPTURES {
+            return Err("too many captures".to_string());
+        }
+        self.capture[self.level] = (s, what);
+        self.level += 1;
+        let r = self.do_match(s, p)?;
+        if r.is_none() {
+            self.level -= 1; // undo capture
+        }
+        Ok(r)
+    }
+
+    fn end_capture(&mut self, s: usize, p: usize) -> MatchResult {
+        let l = self.capture_to_close()?;
+        self.capture[l].1 = (s - self.capture[l].0) as isize; // close capture
+        let r = self.do_match(s, p)?;
+        if r.is_none() {
+            self.capture[l].1 = CAP_UNFINISHED; // undo capture
+        }
+        Ok(r)
+    }
+
+    fn capture_to_close(&self) -> Result<usize, String> {
+        (0..self.level)
+            .rev()
+            .find(|&l| self.capture[l].1 == CAP_UNFINISHED)
+            .ok_or_else(|| "invalid pattern capture".to_string())
+    }
+
+    fn match_capture(&self, s: usize, l: u8) -> MatchResult {
+        let l = self.check_capture(l)?;
+        let (init, len) = (self.capture[l].0, self.capture[l].1 as usize);
+        if self.src.len() - s >= len && self.src[init..init + len] == self.src[s..s + len] {
+            Ok(Some(s + len))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn check_capture(&self, l: u8) -> Result<usize, String> {
+        let l = l as isize - b'1' as isize;
+        if l < 0 || l as usize >= self.level || self.capture[l as usize].1 == CAP_UNFINISHED {
+            return Err(format!("invalid capture index %{}", l + 1));
+        }
+        Ok(l as usize)
+    }
+}
+
+fn match_class(c: u8, cl: u8) -> bool {
+    let res = match cl.to_ascii_lowercase() {
+        b'a' => c.is_ascii_alphabetic(),
+        b'c' => c.is_ascii_control(),
+        b'd' => c.is_ascii_digit(),
+        b'g' => c.is_ascii_graphic(),
+        b'l' => c.is_ascii_lowercase(),
+        b'p' => c.is_ascii_punctuation(),
+        b's' => c == b' ' || (b'\t'..=b'\r').contains(&c),
+        b'u' => c.is_ascii_uppercase(),
+        b'w' => c.is_ascii_alphanumeric(),
+        b'x' => c.is_ascii_hexdigit(),
+        _ => return cl == c,
+    };
+    if cl.is_ascii_uppercase() {
+        !res
+    } else {
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(s: &str, p: &str) -> Result<Option<(usize, usize)>, String> {
+        let mut ms = MatchState::new(s.as_bytes(), p.as_bytes());
+        for init in 0..=s.len() {
+            ms.reprep();
+            if let Some(e) = ms.do_match(init, 0)? {
+                return Ok(Some((init, e)));
+            }
+        }
+        Ok(None)
+    }
+
+    #[test]
+    fn patterns() {
+        assert_eq!(find("hello world", "o w"), Ok(Some((4, 7))));
+        assert_eq!(find("hello", "l+"), Ok(Some((2, 4))));
+        assert_eq!(find("hello", "x*"), Ok(Some((0, 0))));
+        assert_eq!(find("<a><b>", "<.->"), Ok(Some((0, 3))));
+        assert_eq!(find("<a><b>", "<.*>"), Ok(Some((0, 6))));
+        assert_eq!(find("f(a(b)c)d", "%b()"), Ok(Some((1, 8))));
+        assert_eq!(find("THE (quick) fox", "%f[%a]%a+%f[%A]"), Ok(Some((0, 3))));
+        assert_eq!(find("x = 12", "[%d]+$"), Ok(Some((4, 6))));
+        assert_eq!(find("a]b", "[]]"), Ok(Some((1, 2))));
+        assert_eq!(find("abc-1", "[a-c%-]+"), Ok(Some((0, 4))));
+        assert_eq!(find("abc", "[^a]"), Ok(Some((1, 2))));
+        assert_eq!(find("xyyx", "(y)%1"), Ok(Some((1, 3))));
+        assert_eq!(find("a", "%"), Err("malformed pattern (ends with '%')".to_string()));
+        assert_eq!(find("a", "[a"), Err("malformed pattern (missing ']')".to_string()));
+        assert_eq!(find("a", "(a"), Ok(Some((0, 1))));
+        assert_eq!(find("a", "a)"), Err("invalid pattern capture".to_string()));
+        assert_eq!(find("a", "%1"), Err("invalid capture index %1".to_string()));
+        assert_eq!(find("a", "%f"), Err("missing '[' after '%f' in pattern".to_string()));
+    }
+}
@@ -0,0 +1,372 @@
+use crate::api::consts::*;
+use crate::api::{LuaAPI, LuaAuxLib, RustFn};
+
+// Functions copied as they are into a safe environment.
+const SAFE_BASE: &[&str] = &[
+    "assert", "error", "ipairs", "next", "pairs", "pcall", "rawequal", "rawget", "rawlen", "select",
+    "setmetatable", "tonumber", "tostring", "type",
+];
+const SAFE_STRING: &[&str] = &[
+    "byte", "char", "find", "format", "gmatch", "gsub", "len", "lower", "match", "pack", "packsize",
+    "rep", "reverse", "sub", "unpack", "upper",
+];
+const SAFE_TABLE: &[&str] = &["concat", "insert", "move", "pack", "remove", "sort", "unpack"];
+// `randomseed` is left out since the generator is shared by all tenants.
+const SAFE_MATH: &[&str] = &[
+    "abs", "acos", "asin", "atan", "ceil", "cos", "deg", "exp", "floor", "fmod", "huge", "log",
+    "max", "maxinteger", "min", "mininteger", "modf", "pi", "rad", "random", "sin", "sqrt", "tan",
+    "tointeger", "type", "ult",
+];
+const SAFE_JSON: &[&str] = &["decode", "encode", "null"];
+
+// Base functions replaced by checked versions, which get the environment
+// and the original function as upvalues.
+const GUARDED_BASE: &[(&str, RustFn)] = &[
+    ("getmetatable", safe_getmetatable),
+    ("load", safe_load),
+    ("rawset", safe_rawset),
+];
+
+const SAFE_LIBS: &[(&str, RustFn, &[&str])] = &[
+    ("string", super::string::open_string, SAFE_STRING),
+    ("table", super::table::open_table, SAFE_TABLE),
+    ("math", super::math::open_math, SAFE_MATH),
+    ("json", super::json::open_json, SAFE_JSON),
+];
+
+/*
+    Push a new environment for untrusted chunks, to be given to `load`:
+        new_safe_env(ls);
+        ls.load(chunk, "tenant", "b", Some(-1));
+    It only has whitelisted functions of the base, string, table, math and
+    json libraries, in tables of its own, so tenants sharing a state cannot
+    see or change each other's globals. There is no way out to the real global
+    table, the files or the internals of functions: `load` refuses binary
+    chunks and loads into this environment, `rawset` cannot change the
+    environment itself, and `getmetatable` only works on tables so the
+    shared string metatable stays out of reach. Libraries that are not
+    open yet are opened, without making them global.
+*/
+pub fn new_safe_env(ls: &mut dyn LuaAPI) {
+    ls.requiref("_G", super::base::open_base, false);
+    let globals = ls.abs_index(-1);
+    ls.create_table(0, SAFE_BASE.len() + GUARDED_BASE.len() + SAFE_LIBS.len() + 2);
+    let env = ls.abs_index(-1);
+    copy_fields(ls, globals, env, SAFE_BASE);
+    for (name, f) in GUARDED_BASE {
+        ls.push_value(env);
+        ls.get_field(globals, name);
+        ls.push_rust_closure(*f, 2);
+        ls.set_field(env, name);
+    }
+    for (name, openf, funcs) in SAFE_LIBS {
+        ls.requiref(name, *openf, false);
+        ls.create_table(0, funcs.len());
+        copy_fields(ls, -2, -1, funcs);
+        ls.set_field(env, name);
+        ls.pop(1); // remove lib
+    }
+    ls.push_value(env);
+    ls.set_field(env, "_G");
+    ls.push_string(LUA_VERSION.to_string());
+    ls.set_field(env, "_VERSION");
+    ls.remove(globals);
+}
+
+// Copy `names` from the table at `from` to the table at `to`.
+fn copy_fields(ls: &mut dyn LuaAPI, from: isize, to: isize, names: &[&str]) {
+    let (from, to) = (ls.abs_index(from), ls.abs_index(to));
+    for name in names {
+        ls.get_field(from, name);
+        ls.set_field(to, name);
+    }
+}
+
+// getmetatable (object), for tables only
+fn safe_getmetatable(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_any(1);
+    if !ls.is_table(1) {
+        ls.push_nil();
+        return 1;
+    }
+    ls.push_value(upvalue_index(2));
+    ls.insert(1);
+    let nargs = ls.get_top() as usize - 1;
+    ls.call(nargs, 1);
+    1
+}
+
+// load (chunk [, chunkname [, mode [, env]]]), of text chunks into the
+// safe environment unless `env` is given
+fn safe_load(ls: &mut dyn LuaAPI) -> usize {
+    let mode = ls.opt_string(3, "bt").replace('b', "");
+    let has_env = !ls.is_none(4);
+    ls.set_top(4);
+    ls.push_string(mode);
+    ls.replace(3);
+    if !has_env {
+        ls.push_value(upvalue_index(1));
+        ls.replace(4);
+    }
+    ls.push_value(upvalue_index(2));
+    ls.insert(1);
+    ls.call(4, LUA_MULTRET);
+    ls.get_top() as usize
+}
+
+// rawset (table, index, value), on any table but the environment
+fn safe_rawset(ls: &mut dyn LuaAPI) -> usize {
+    ls.push_global_table();
+    let globals = ls.raw_equal(1, -1);
+    ls.pop(1);
+    if globals || ls.raw_equal(1, upvalue_index(1)) {
+        ls.arg_error(1, "cannot rawset the environment");
+    }
+    ls.push_value(upvalue_index(2));
+    ls.insert(1);
+    let nargs = ls.get_top() as usize - 1;
+    ls.call(nargs, 1);
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LuaState;
+    use crate::stdlib::open_libs;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_safe_env() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        open_libs(&mut *ls);
+        ls.requiref("debug", crate::stdlib::debug::open_debug, true);
+        ls.pop(1);
+
+        for tenant in &["a", "b"] {
+            new_safe_env(&mut *ls);
+            ls.push_string(tenant.to_string());
+            ls.set_field(-2, "TENANT");
+        }
+        // run tenant "a" twice and "b" once, each keeps its own globals
+        for (env, count, owner) in &[(-2, 1, "a"), (-1, 1, "b"), (-2, 2, "a")] {
+            ls.load(LUA_SANDBOX_CHUNK.to_vec(), "chunk", "b", Some(*env));
+            let status = ls.pcall(0, 2, 0);
+            assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+            assert_eq!((ls.to_integer(-2), ls.to_string(-1)), (*count, owner.to_string()));
+            ls.pop(2);
+        }
+        assert_eq!(ls.get_top(), 2);
+        assert_eq!(ls.get_global("COUNT"), LUA_TNIL);
+        ls.get_global("string");
+        assert_eq!(ls.get_field(-1, "owner"), LUA_TNIL);
+    }
+
+    /* Lua source code:
+        -- only the whitelisted libraries are reachable
+        assert(_G == _ENV and _VERSION == "Lua 5.3")
+        assert(print == nil and dofile == nil and require == nil)
+        assert(io == nil and os == nil and debug == nil and package == nil)
+        assert(math.randomseed == nil and math.random(3) <= 3)
+        assert(("x"):rep(3) == "xxx" and table.concat({1, 2}, ",") == "1,2")
+        assert(getmetatable("") == nil)
+        assert(getmetatable(setmetatable({}, {__metatable = 1})) == 1)
+
+        -- no binary chunks, and loaded code stays in the sandbox
+        local f, err = load("\27Lua", "=bin")
+        assert(f == nil and err == "attempt to load a binary chunk (mode is 't')", err)
+        f, err = load("\27Lua", "=bin", "b")
+        assert(f == nil and err == "attempt to load a binary chunk (mode is '')", err)
+
+        -- rawset works on any table but the environment
+        local t = {}
+        rawset(t, 1, 2)
+        assert(t[1] == 2)
+        local ok
+        ok, err = pcall(rawset, _G, "x", 1)
+        assert(not ok and err:find("cannot rawset the environment", 1, true), err)
+        assert(rawget(_G, "x") == nil)
+
+        -- globals and library tables belong to this tenant
+        string.owner = string.owner or TENANT
+        COUNT = (COUNT or 0) + 1
+        return COUNT, string.owner
+    */
+    const LUA_SANDBOX_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x07, 0x40,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x0a, 0xbc, 0x00, 0x00, 0x00, 0x06, 0x00, 0x40, 0x00,
+        0x46, 0x40, 0x40, 0x00, 0x85, 0x00, 0x00, 0x00, 0x1f, 0x80, 0x80, 0x00,
+        0x1e, 0x80, 0x00, 0x80, 0x46, 0x80, 0x40, 0x00, 0x5f, 0xc0, 0xc0, 0x00,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00,
+        0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40, 0x00, 0x46, 0x00, 0x41, 0x00,
+        0x1f, 0x40, 0xc1, 0x00, 0x1e, 0x40, 0x01, 0x80, 0x46, 0x80, 0x41, 0x00,
+        0x1f, 0x40, 0xc1, 0x00, 0x1e, 0x80, 0x00, 0x80, 0x46, 0xc0, 0x41, 0x00,
+        0x5f, 0x40, 0xc1, 0x00, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00,
+        0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40, 0x00,
+        0x46, 0x00, 0x42, 0x00, 0x1f, 0x40, 0xc1, 0x00, 0x1e, 0x00, 0x02, 0x80,
+        0x46, 0x40, 0x42, 0x00, 0x1f, 0x40, 0xc1, 0x00, 0x1e, 0x40, 0x01, 0x80,
+        0x46, 0x80, 0x42, 0x00, 0x1f, 0x40, 0xc1, 0x00, 0x1e, 0x80, 0x00, 0x80,
+        0x46, 0xc0, 0x42, 0x00, 0x5f, 0x40, 0xc1, 0x00, 0x1e, 0x00, 0x00, 0x80,
+        0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01,
+        0x06, 0x00, 0x40, 0x00, 0x46, 0x00, 0x43, 0x00, 0x47, 0x40, 0xc3, 0x00,
+        0x1f, 0x40, 0xc1, 0x00, 0x1e, 0x40, 0x01, 0x80, 0x46, 0x00, 0x43, 0x00,
+        0x47, 0x80, 0xc3, 0x00, 0x81, 0xc0, 0x03, 0x00, 0x64, 0x80, 0x00, 0x01,
+        0x61, 0xc0, 0xc3, 0x00, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00,
+        0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40, 0x00,
+        0x41, 0x00, 0x04, 0x00, 0x4c, 0x40, 0xc4, 0x00, 0xc1, 0xc0, 0x03, 0x00,
+        0x64, 0x80, 0x80, 0x01, 0x1f, 0x80, 0xc4, 0x00, 0x1e, 0x40, 0x02, 0x80,
+        0x46, 0xc0, 0x44, 0x00, 0x47, 0x00, 0xc5, 0x00, 0x8b, 0x00, 0x00, 0x01,
+        0xc1, 0x40, 0x05, 0x00, 0x01, 0x81, 0x05, 0x00, 0xab, 0x40, 0x00, 0x01,
+        0xc1, 0xc0, 0x05, 0x00, 0x64, 0x80, 0x80, 0x01, 0x5f, 0x00, 0xc6, 0x00,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00,
+        0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40, 0x00, 0x46, 0x40, 0x46, 0x00,
+        0x81, 0x80, 0x06, 0x00, 0x64, 0x80, 0x00, 0x01, 0x5f, 0x40, 0xc1, 0x00,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00,
+        0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40, 0x00, 0x46, 0x40, 0x46, 0x00,
+        0x86, 0xc0, 0x46, 0x00, 0xcb, 0x00, 0x00, 0x00, 0x0b, 0x41, 0x00, 0x00,
+        0x0a, 0x41, 0x45, 0x8e, 0xa4, 0x00, 0x80, 0x01, 0x64, 0x80, 0x00, 0x00,
+        0x5f, 0x40, 0xc5, 0x00, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00,
+        0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01, 0x06, 0x40, 0x47, 0x00,
+        0x41, 0x80, 0x07, 0x00, 0x81, 0xc0, 0x07, 0x00, 0x24, 0xc0, 0x80, 0x01,
+        0x86, 0x00, 0x40, 0x00, 0x1f, 0x40, 0x41, 0x00, 0x1e, 0x40, 0x00, 0x80,
+        0x5f, 0x00, 0xc8, 0x00, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00,
+        0xc3, 0x00, 0x80, 0x00, 0x00, 0x01, 0x80, 0x00, 0xa4, 0x40, 0x80, 0x01,
+        0x86, 0x40, 0x47, 0x00, 0xc1, 0x80, 0x07, 0x00, 0x01, 0xc1, 0x07, 0x00,
+        0x41, 0x41, 0x08, 0x00, 0xa4, 0xc0, 0x00, 0x02, 0x40, 0x00, 0x80, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x86, 0x00, 0x40, 0x00, 0x1f, 0x40, 0x41, 0x00,
+        0x1e, 0x40, 0x00, 0x80, 0x5f, 0x80, 0xc8, 0x00, 0x1e, 0x00, 0x00, 0x80,
+        0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00, 0x00, 0x01, 0x80, 0x00,
+        0xa4, 0x40, 0x80, 0x01, 0x8b, 0x00, 0x00, 0x00, 0xc6, 0xc0, 0x48, 0x00,
+        0x00, 0x01, 0x00, 0x01, 0x41, 0x41, 0x05, 0x00, 0x81, 0x81, 0x05, 0x00,
+        0xe4, 0x40, 0x00, 0x02, 0xc6, 0x00, 0x40, 0x00, 0x07, 0x41, 0x45, 0x01,
+        0x5f, 0x80, 0x45, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00,
+        0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01, 0xc4, 0x00, 0x00, 0x00,
+        0x06, 0x01, 0x49, 0x00, 0x46, 0xc1, 0x48, 0x00, 0x86, 0x41, 0x40, 0x00,
+        0xc1, 0x01, 0x04, 0x00, 0x01, 0x42, 0x05, 0x00, 0x24, 0xc1, 0x80, 0x02,
+        0x40, 0x00, 0x80, 0x02, 0xc0, 0x00, 0x00, 0x02, 0x06, 0x01, 0x40, 0x00,
+        0xe2, 0x40, 0x00, 0x00, 0x1e, 0x40, 0x01, 0x80, 0x4c, 0x41, 0xc9, 0x00,
+        0xc1, 0x81, 0x09, 0x00, 0x01, 0x42, 0x05, 0x00, 0x43, 0x02, 0x80, 0x00,
+        0x64, 0x81, 0x80, 0x02, 0x1e, 0x40, 0x00, 0x80, 0x43, 0x41, 0x00, 0x00,
+        0x43, 0x01, 0x80, 0x00, 0x80, 0x01, 0x80, 0x00, 0x24, 0x41, 0x80, 0x01,
+        0x06, 0x01, 0x40, 0x00, 0x46, 0xc1, 0x49, 0x00, 0x86, 0x41, 0x40, 0x00,
+        0xc1, 0x01, 0x04, 0x00, 0x64, 0x81, 0x80, 0x01, 0x5f, 0x40, 0xc1, 0x02,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x41, 0x00, 0x00, 0x43, 0x01, 0x80, 0x00,
+        0x24, 0x41, 0x00, 0x01, 0x06, 0x01, 0x4a, 0x00, 0x46, 0x01, 0x4a, 0x00,
+        0x47, 0x41, 0xca, 0x02, 0x62, 0x41, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x80,
+        0x46, 0x81, 0x4a, 0x00, 0x0a, 0x41, 0x81, 0x94, 0x06, 0xc1, 0x4a, 0x00,
+        0x22, 0x41, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x80, 0x01, 0x01, 0x0b, 0x00,
+        0x0d, 0x41, 0x45, 0x02, 0x08, 0x00, 0x81, 0x95, 0x06, 0xc1, 0x4a, 0x00,
+        0x46, 0x01, 0x4a, 0x00, 0x47, 0x41, 0xca, 0x02, 0x26, 0x01, 0x80, 0x01,
+        0x26, 0x00, 0x80, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x04, 0x07, 0x61, 0x73,
+        0x73, 0x65, 0x72, 0x74, 0x04, 0x03, 0x5f, 0x47, 0x04, 0x09, 0x5f, 0x56,
+        0x45, 0x52, 0x53, 0x49, 0x4f, 0x4e, 0x04, 0x08, 0x4c, 0x75, 0x61, 0x20,
+        0x35, 0x2e, 0x33, 0x04, 0x06, 0x70, 0x72, 0x69, 0x6e, 0x74, 0x00, 0x04,
+        0x07, 0x64, 0x6f, 0x66, 0x69, 0x6c, 0x65, 0x04, 0x08, 0x72, 0x65, 0x71,
+        0x75, 0x69, 0x72, 0x65, 0x04, 0x03, 0x69, 0x6f, 0x04, 0x03, 0x6f, 0x73,
+        0x04, 0x06, 0x64, 0x65, 0x62, 0x75, 0x67, 0x04, 0x08, 0x70, 0x61, 0x63,
+        0x6b, 0x61, 0x67, 0x65, 0x04, 0x05, 0x6d, 0x61, 0x74, 0x68, 0x04, 0x0b,
+        0x72, 0x61, 0x6e, 0x64, 0x6f, 0x6d, 0x73, 0x65, 0x65, 0x64, 0x04, 0x07,
+        0x72, 0x61, 0x6e, 0x64, 0x6f, 0x6d, 0x13, 0x03, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x02, 0x78, 0x04, 0x04, 0x72, 0x65, 0x70, 0x04,
+        0x04, 0x78, 0x78, 0x78, 0x04, 0x06, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x04,
+        0x07, 0x63, 0x6f, 0x6e, 0x63, 0x61, 0x74, 0x13, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x13, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x02, 0x2c, 0x04, 0x04, 0x31, 0x2c, 0x32, 0x04, 0x0d, 0x67,
+        0x65, 0x74, 0x6d, 0x65, 0x74, 0x61, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x04,
+        0x01, 0x04, 0x0d, 0x73, 0x65, 0x74, 0x6d, 0x65, 0x74, 0x61, 0x74, 0x61,
+        0x62, 0x6c, 0x65, 0x04, 0x0c, 0x5f, 0x5f, 0x6d, 0x65, 0x74, 0x61, 0x74,
+        0x61, 0x62, 0x6c, 0x65, 0x04, 0x05, 0x6c, 0x6f, 0x61, 0x64, 0x04, 0x05,
+        0x1b, 0x4c, 0x75, 0x61, 0x04, 0x05, 0x3d, 0x62, 0x69, 0x6e, 0x14, 0x2d,
+        0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x6c,
+        0x6f, 0x61, 0x64, 0x20, 0x61, 0x20, 0x62, 0x69, 0x6e, 0x61, 0x72, 0x79,
+        0x20, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x20, 0x28, 0x6d, 0x6f, 0x64, 0x65,
+        0x20, 0x69, 0x73, 0x20, 0x27, 0x74, 0x27, 0x29, 0x04, 0x02, 0x62, 0x14,
+        0x2c, 0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x20, 0x74, 0x6f, 0x20,
+        0x6c, 0x6f, 0x61, 0x64, 0x20, 0x61, 0x20, 0x62, 0x69, 0x6e, 0x61, 0x72,
+        0x79, 0x20, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x20, 0x28, 0x6d, 0x6f, 0x64,
+        0x65, 0x20, 0x69, 0x73, 0x20, 0x27, 0x27, 0x29, 0x04, 0x07, 0x72, 0x61,
+        0x77, 0x73, 0x65, 0x74, 0x04, 0x06, 0x70, 0x63, 0x61, 0x6c, 0x6c, 0x04,
+        0x05, 0x66, 0x69, 0x6e, 0x64, 0x04, 0x1e, 0x63, 0x61, 0x6e, 0x6e, 0x6f,
+        0x74, 0x20, 0x72, 0x61, 0x77, 0x73, 0x65, 0x74, 0x20, 0x74, 0x68, 0x65,
+        0x20, 0x65, 0x6e, 0x76, 0x69, 0x72, 0x6f, 0x6e, 0x6d, 0x65, 0x6e, 0x74,
+        0x04, 0x07, 0x72, 0x61, 0x77, 0x67, 0x65, 0x74, 0x04, 0x07, 0x73, 0x74,
+        0x72, 0x69, 0x6e, 0x67, 0x04, 0x06, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x04,
+        0x07, 0x54, 0x45, 0x4e, 0x41, 0x4e, 0x54, 0x04, 0x06, 0x43, 0x4f, 0x55,
+        0x4e, 0x54, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xbc, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+        0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+        0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+        0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+        0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00,
+        0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00,
+        0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00,
+        0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00,
+        0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00,
+        0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00,
+        0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00,
+        0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00,
+        0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00,
+        0x00, 0x0b, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00,
+        0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00,
+        0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00,
+        0x00, 0x0c, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00,
+        0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00,
+        0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00,
+        0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00,
+        0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00,
+        0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00,
+        0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00,
+        0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00,
+        0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00,
+        0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00,
+        0x00, 0x14, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00,
+        0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00,
+        0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00,
+        0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00,
+        0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00,
+        0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00,
+        0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00,
+        0x00, 0x16, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00,
+        0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00,
+        0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00,
+        0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00,
+        0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00,
+        0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00,
+        0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00,
+        0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00,
+        0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00,
+        0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x00, 0x02, 0x66, 0x64, 0x00, 0x00, 0x00, 0xbc, 0x00, 0x00, 0x00, 0x04,
+        0x65, 0x72, 0x72, 0x64, 0x00, 0x00, 0x00, 0xbc, 0x00, 0x00, 0x00, 0x02,
+        0x74, 0x7e, 0x00, 0x00, 0x00, 0xbc, 0x00, 0x00, 0x00, 0x03, 0x6f, 0x6b,
+        0x8b, 0x00, 0x00, 0x00, 0xbc, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
+}
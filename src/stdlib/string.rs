@@ -0,0 +1,1331 @@
+use super::pack::{str_pack, str_packsize, str_unpack};
+use super::pattern::{Capture, MatchState, SPECIALS};
+use crate::api::consts::*;
+use crate::api::{LuaAPI, LuaAuxLib, RustFn};
+use crate::number::format;
+use std::rc::Rc;
+
+const L_ESC: u8 = b'%';
+// Some sizes are better limited to fit in 'int', like lstrlib.c does.
+pub const MAXSIZE: usize = i32::MAX as usize;
+
+const STR_FUNCS: &[(&str, RustFn)] = &[
+    ("byte", str_byte),
+    ("char", str_char),
+    ("find", str_find),
+    ("format", str_format),
+    ("gmatch", str_gmatch),
+    ("gsub", str_gsub),
+    ("len", str_len),
+    ("lower", str_lower),
+    ("match", str_match),
+    ("pack", str_pack),
+    ("packsize", str_packsize),
+    ("rep", str_rep),
+    ("reverse", str_reverse),
+    ("sub", str_sub),
+    ("unpack", str_unpack),
+    ("upper", str_upper),
+];
+
+// Create the `string` table and make it the `__index` of the string
+// metatable, so that `("x"):rep(3)` works.
+pub fn open_string(ls: &mut dyn LuaAPI) -> usize {
+    ls.new_lib(STR_FUNCS);
+    create_metatable(ls);
+    1
+}
+
+fn create_metatable(ls: &mut dyn LuaAPI) {
+    ls.create_table(0, 1); // table to be metatable for strings
+    ls.push_string(String::new()); // dummy string
+    ls.push_value(-2); // copy table
+    ls.set_metatable(-2); // set table as metatable for strings
+    ls.pop(1); // pop dummy string
+    ls.push_value(-2); // get string library
+    ls.set_field(-2, "__index"); // metatable.__index = string
+    ls.pop(1); // pop metatable
+}
+
+// Translate a relative string position: negative means back from end.
+pub fn posrelat(pos: i64, len: usize) -> i64 {
+    if pos >= 0 {
+        pos
+    } else if pos.unsigned_abs() > len as u64 {
+        0
+    } else {
+        len as i64 + pos + 1
+    }
+}
+
+// string.len (s)
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.len
+fn str_len(ls: &mut dyn LuaAPI) -> usize {
+    let s = ls.check_shared_bytes(1);
+    ls.push_integer(s.len() as i64);
+    1
+}
+
+// string.sub (s, i [, j])
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.sub
+fn str_sub(ls: &mut dyn LuaAPI) -> usize {
+    let s = ls.check_shared_bytes(1);
+    let l = s.len();
+    let start = posrelat(ls.check_integer(2), l).max(1);
+    let end = posrelat(ls.opt_integer(3, -1), l).min(l as i64);
+    if start <= end {
+        ls.push_bytes(s[start as usize - 1..end as usize].to_vec());
+    } else {
+        ls.push_string(String::new());
+    }
+    1
+}
+
+// string.reverse (s)
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.reverse
+fn str_reverse(ls: &mut dyn LuaAPI) -> usize {
+    let mut s = ls.check_bytes(1);
+    s.reverse();
+    ls.push_bytes(s);
+    1
+}
+
+// string.lower (s)
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.lower
+fn str_lower(ls: &mut dyn LuaAPI) -> usize {
+    let s = ls.check_shared_bytes(1);
+    ls.push_bytes(s.to_ascii_lowercase());
+    1
+}
+
+// string.upper (s)
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.upper
+fn str_upper(ls: &mut dyn LuaAPI) -> usize {
+    let s = ls.check_shared_bytes(1);
+    ls.push_bytes(s.to_ascii_uppercase());
+    1
+}
+
+// string.rep (s, n [, sep])
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.rep
+fn str_rep(ls: &mut dyn LuaAPI) -> usize {
+    let s = ls.check_shared_bytes(1);
+    let n = ls.check_integer(2);
+    let sep = if ls.is_none_or_nil(3) { Rc::default() } else { ls.check_shared_bytes(3) };
+    if n <= 0 {
+        ls.push_string(String::new());
+    } else if (s.len() + sep.len()) > MAXSIZE / n as usize {
+        ls.error_l("resulting string too large");
+    } else {
+        let n = n as usize;
+        let total = n * s.len() + (n - 1) * sep.len();
+        ls.check_alloc(total);
+        let mut b = Vec::with_capacity(total);
+        for i in 0..n {
+            if i > 0 {
+                b.extend_from_slice(&sep);
+            }
+            b.extend_from_slice(&s);
+        }
+        ls.push_bytes(b);
+    }
+    1
+}
+
+// string.byte (s [, i [, j]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.byte
+fn str_byte(ls: &mut dyn LuaAPI) -> usize {
+    let s = ls.check_shared_bytes(1);
+    let l = s.len();
+    let posi = posrelat(ls.opt_integer(2, 1), l);
+    let pose = posrelat(ls.opt_integer(3, posi), l);
+    let posi = posi.max(1);
+    let pose = pose.min(l as i64);
+    if posi > pose {
+        return 0; // empty interval; return no values
+    }
+    let n = (pose - posi) as usize + 1;
+    if n >= i32::MAX as usize || !ls.check_stack(n) {
+        ls.error_l("string slice too long");
+    }
+    for &c in &s[posi as usize - 1..pose as usize] {
+        ls.push_integer(c as i64);
+    }
+    n
+}
+
+// string.char (···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.char
+fn str_char(ls: &mut dyn LuaAPI) -> usize {
+    let n = ls.get_top();
+    let mut b = Vec::with_capacity(n as usize);
+    for i in 1..=n {
+        let c = ls.check_integer(i) as u64;
+        ls.arg_check(c <= 255, i, "value out of range");
+        b.push(c as u8);
+    }
+    ls.push_bytes(b);
+    1
+}
+
+/* pattern matching */
+
+// string.find (s, pattern [, init [, plain]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.find
+fn str_find(ls: &mut dyn LuaAPI) -> usize {
+    str_find_aux(ls, true)
+}
+
+// string.match (s, pattern [, init])
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.match
+fn str_match(ls: &mut dyn LuaAPI) -> usize {
+    str_find_aux(ls, false)
+}
+
+fn str_find_aux(ls: &mut dyn LuaAPI, find: bool) -> usize {
+    let s = ls.check_shared_bytes(1);
+    let p = ls.check_shared_bytes(2);
+    let init = posrelat(ls.opt_integer(3, 1), s.len()).max(1) as usize;
+    if init > s.len() + 1 {
+        // start after string's end?
+        ls.push_nil(); // cannot find anything
+        return 1;
+    }
+    // explicit request or no special characters?
+    if find && (ls.to_boolean(4) || no_specials(&p)) {
+        // do a plain search
+        if let Some(i) = find_bytes(&s[init - 1..], &p) {
+            let start = init + i;
+            ls.push_integer(start as i64);
+            ls.push_integer((start + p.len() - 1) as i64);
+            return 2;
+        }
+    } else {
+        let anchor = p.first() == Some(&b'^');
+        let mut ms = MatchState::new(&s, &p);
+        let mut s1 = init - 1;
+        loop {
+            ms.reprep();
+            if let Some(e) = check(ls, ms.do_match(s1, anchor as usize)) {
+                return if find {
+                    ls.push_integer(s1 as i64 + 1); // start
+                    ls.push_integer(e as i64); // end
+                    push_captures(ls, &ms, None, 0) + 2
+                } else {
+                    push_captures(ls, &ms, Some(s1), e)
+                };
+            }
+            s1 += 1;
+            if anchor || s1 > s.len() {
+                break;
+            }
+        }
+    }
+    ls.push_nil(); // not found
+    1
+}
+
+fn no_specials(p: &[u8]) -> bool {
+    !p.iter().any(|c| SPECIALS.contains(c))
+}
+
+fn find_bytes(s: &[u8], p: &[u8]) -> Option<usize> {
+    if p.is_empty() {
+        Some(0) // empty strings are everywhere
+    } else {
+        s.windows(p.len()).position(|w| w == p)
+    }
+}
+
+// Raise the error of a failed match.
+fn check<T>(ls: &mut dyn LuaAPI, r: Result<T, String>) -> T {
+    match r {
+        Ok(v) => v,
+        Err(msg) => ls.error_l(&msg),
+    }
+}
+
+fn push_onecapture(ls: &mut dyn LuaAPI, ms: &MatchState, i: usize, s: usize, e: usize) {
+    match check(ls, ms.get_capture(i, s, e)) {
+        Capture::Str(b) => ls.push_bytes(b.to_vec()),
+        Capture::Position(n) => ls.push_integer(n as i64),
+    }
+}
+
+// Push the captures of a match of `s..e` (the whole match if the pattern
+// has none and `s` is given), returning how many were pushed.
+fn push_captures(ls: &mut dyn LuaAPI, ms: &MatchState, s: Option<usize>, e: usize) -> usize {
+    let nlevels = if ms.level == 0 && s.is_some() { 1 } else { ms.level };
+    if !ls.check_stack(nlevels) {
+        ls.error_l("too many captures");
+    }
+    for i in 0..nlevels {
+        push_onecapture(ls, ms, i, s.unwrap_or(0), e);
+    }
+    nlevels // number of strings pushed
+}
+
+// string.gmatch (s, pattern)
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.gmatch
+fn str_gmatch(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_shared_bytes(1);
+    ls.check_shared_bytes(2);
+    ls.set_top(2);
+    ls.push_integer(0); // current position
+    ls.push_nil(); // end of last match
+    ls.push_rust_closure(gmatch_aux, 4);
+    1
+}
+
+fn gmatch_aux(ls: &mut dyn LuaAPI) -> usize {
+    let s = ls.to_shared_bytes(upvalue_index(1)).unwrap();
+    let p = ls.to_shared_bytes(upvalue_index(2)).unwrap();
+    let mut src = ls.to_integer(upvalue_index(3)) as usize;
+    let lastmatch = ls.to_integerx(upvalue_index(4));
+    let mut ms = MatchState::new(&s, &p);
+    while src <= s.len() {
+        ms.reprep();
+        match check(ls, ms.do_match(src, 0)) {
+            Some(e) if Some(e as i64) != lastmatch => {
+                // start next match after this one
+                ls.push_integer(e as i64);
+                ls.replace(upvalue_index(3));
+                ls.push_integer(e as i64);
+                ls.replace(upvalue_index(4));
+                return push_captures(ls, &ms, Some(src), e);
+            }
+            _ => src += 1,
+        }
+    }
+    0 // not found
+}
+
+// string.gsub (s, pattern, repl [, n])
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.gsub
+fn str_gsub(ls: &mut dyn LuaAPI) -> usize {
+    let src = ls.check_shared_bytes(1);
+    let p = ls.check_shared_bytes(2);
+    let tr = ls.type_id(3); // replacement type
+    let max_s = ls.opt_integer(4, src.len() as i64 + 1); // max replacements
+    ls.arg_check(
+        tr == LUA_TNUMBER || tr == LUA_TSTRING || tr == LUA_TFUNCTION || tr == LUA_TTABLE,
+        3,
+        "string/function/table expected",
+    );
+    let anchor = p.first() == Some(&b'^');
+    let mut ms = MatchState::new(&src, &p);
+    let mut b = Vec::new();
+    let mut s = 0;
+    let mut lastmatch = None;
+    let mut n = 0;
+    while n < max_s {
+        ms.reprep();
+        match check(ls, ms.do_match(s, anchor as usize)) {
+            Some(e) if Some(e) != lastmatch => {
+                // match?
+                n += 1;
+                add_value(ls, &ms, &mut b, s, e, tr); // add replacement to buffer
+                s = e;
+                lastmatch = Some(e);
+            }
+            _ if s < src.len() => {
+                // otherwise, skip one character
+                b.push(src[s]);
+                s += 1;
+            }
+            _ => break, // end of subject
+        }
+        ls.check_alloc(b.len()); // the buffer grows by pieces of existing strings
+        if anchor {
+            break;
+        }
+    }
+    b.extend_from_slice(&src[s..]);
+    ls.push_bytes(b);
+    ls.push_integer(n); // number of substitutions
+    2
+}
+
+fn add_value(ls: &mut dyn LuaAPI, ms: &MatchState, b: &mut Vec<u8>, s: usize, e: usize, tr: LuaType) {
+    match tr {
+        LUA_TFUNCTION => {
+            ls.push_value(3);
+            let n = push_captures(ls, ms, Some(s), e);
+            ls.call(n, 1); // call it
+        }
+        LUA_TTABLE => {
+            push_onecapture(ls, ms, 0, s, e);
+            ls.get_table(3);
+        }
+        _ => {
+            // LUA_TNUMBER or LUA_TSTRING
+            add_s(ls, ms, b, s, e);
+            return;
+        }
+    }
+    if !ls.to_boolean(-1) {
+        // nil or false?
+        ls.pop(1);
+        b.extend_from_slice(&ms.src[s..e]); // keep original text
+    } else if !ls.is_string(-1) {
+        let tname = ls.type_name(ls.type_id(-1)).to_string();
+        ls.error_l(&format!("invalid replacement value (a {})", tname));
+    } else {
+        b.extend_from_slice(&ls.to_shared_bytes(-1).unwrap()); // add result to accumulator
+        ls.pop(1);
+    }
+}
+
+fn add_s(ls: &mut dyn LuaAPI, ms: &MatchState, b: &mut Vec<u8>, s: usize, e: usize) {
+    let news = ls.to_shared_bytes(3).unwrap();
+    let mut i = 0;
+    while i < news.len() {
+        if news[i] != L_ESC {
+            b.push(news[i]);
+        } else {
+            i += 1; // skip ESC
+            match news.get(i) {
+                Some(&L_ESC) => b.push(L_ESC), // %%
+                Some(b'0') => b.extend_from_slice(&ms.src[s..e]), // %0
+                Some(&d) if d.is_ascii_digit() => {
+                    // %n
+                    push_onecapture(ls, ms, (d - b'1') as usize, s, e);
+                    ls.tolstring_l(-1); // if number, convert it to string
+                    b.extend_from_slice(&ls.to_shared_bytes(-1).unwrap());
+                    ls.pop(2); // remove original value and its string
+                }
+                _ => ls.error_l("invalid use of '%' in replacement string"),
+            }
+        }
+        i += 1;
+    }
+}
+
+/* string.format */
+
+const L_FMTFLAGS: &[u8] = b"-+ #0"; // valid flags in a format specification
+
+// A conversion specification like "%-5.2f".
+struct FormatSpec {
+    flags: Vec<u8>,
+    width: usize,
+    precision: Option<usize>,
+    conv: u8,
+}
+
+impl FormatSpec {
+    fn has(&self, flag: u8) -> bool {
+        self.flags.contains(&flag)
+    }
+
+    // Sign of a signed conversion.
+    fn sign(&self, negative: bool) -> &'static str {
+        if negative {
+            "-"
+        } else if self.has(b'+') {
+            "+"
+        } else if self.has(b' ') {
+            " "
+        } else {
+            ""
+        }
+    }
+
+    // Justify `prefix` and `body` in the field width, zeros go in between.
+    fn pad(&self, prefix: &str, body: &[u8], zero_ok: bool) -> Vec<u8> {
+        let len = prefix.len() + body.len();
+        let fill = self.width.saturating_sub(len);
+        let mut b = Vec::with_capacity(len + fill);
+        if self.has(b'-') {
+            b.extend_from_slice(prefix.as_bytes());
+            b.extend_from_slice(body);
+            b.resize(len + fill, b' ');
+        } else if self.has(b'0') && zero_ok {
+            b.extend_from_slice(prefix.as_bytes());
+            b.resize(prefix.len() + fill, b'0');
+            b.extend_from_slice(body);
+        } else {
+            b.resize(fill, b' ');
+            b.extend_from_slice(prefix.as_bytes());
+            b.extend_from_slice(body);
+        }
+        b
+    }
+}
+
+fn scan_format(ls: &mut dyn LuaAPI, strfrmt: &[u8], i: &mut usize) -> FormatSpec {
+    let digit = |i: usize| strfrmt.get(i).is_some_and(u8::is_ascii_digit);
+    let start = *i;
+    while strfrmt.get(*i).is_some_and(|c| L_FMTFLAGS.contains(c)) {
+        *i += 1; // skip flags
+    }
+    if *i - start > L_FMTFLAGS.len() {
+        ls.error_l("invalid format (repeated flags)");
+    }
+    let flags = strfrmt[start..*i].to_vec();
+    let mut width = 0;
+    for _ in 0..2 {
+        // (2 digits at most)
+        if digit(*i) {
+            width = width * 10 + (strfrmt[*i] - b'0') as usize;
+            *i += 1;
+        }
+    }
+    let mut precision = None;
+    if strfrmt.get(*i) == Some(&b'.') {
+        *i += 1;
+        let mut p = 0;
+        for _ in 0..2 {
+            // (2 digits at most)
+            if digit(*i) {
+                p = p * 10 + (strfrmt[*i] - b'0') as usize;
+                *i += 1;
+            }
+        }
+        precision = Some(p);
+    }
+    if digit(*i) {
+        ls.error_l("invalid format (width or precision too long)");
+    }
+    let conv = strfrmt.get(*i).copied().unwrap_or(0);
+    *i += 1;
+    FormatSpec { flags, width, precision, conv }
+}
+
+// string.format (formatstring, ···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-string.format
+fn str_format(ls: &mut dyn LuaAPI) -> usize {
+    let top = ls.get_top();
+    let strfrmt = ls.check_shared_bytes(1);
+    let mut arg = 1;
+    let mut b = Vec::with_capacity(strfrmt.len());
+    let mut i = 0;
+    while i < strfrmt.len() {
+        let c = strfrmt[i];
+        i += 1;
+        if c != L_ESC {
+            b.push(c);
+            continue;
+        } else if strfrmt.get(i) == Some(&L_ESC) {
+            b.push(L_ESC); // %%
+            i += 1;
+            continue;
+        }
+        // format item
+        arg += 1;
+        if arg > top {
+            ls.arg_error(arg, "no value");
+        }
+        let spec = scan_format(ls, &strfrmt, &mut i);
+        match spec.conv {
+            b'c' => {
+                let c = ls.check_integer(arg) as u8;
+                b.extend(spec.pad("", &[c], false));
+            }
+            b'd' | b'i' => {
+                let n = ls.check_integer(arg);
+                let digits = int_digits(n.unsigned_abs(), 10, false, spec.precision);
+                b.extend(spec.pad(spec.sign(n < 0), digits.as_bytes(), spec.precision.is_none()));
+            }
+            b'u' | b'o' | b'x' | b'X' => {
+                let n = ls.check_integer(arg) as u64;
+                let (radix, upper) = match spec.conv {
+                    b'o' => (8, false),
+                    b'x' => (16, false),
+                    b'X' => (16, true),
+                    _ => (10, false),
+                };
+                let mut digits = int_digits(n, radix, upper, spec.precision);
+                let mut prefix = "";
+                if spec.has(b'#') {
+                    if spec.conv == b'o' && !digits.starts_with('0') {
+                        digits.insert(0, '0');
+                    } else if radix == 16 && n != 0 {
+                        prefix = if upper { "0X" } else { "0x" };
+                    }
+                }
+                b.extend(spec.pad(prefix, digits.as_bytes(), spec.precision.is_none()));
+            }
+            b'a' | b'A' | b'e' | b'E' | b'f' | b'g' | b'G' => {
+                let n = ls.check_number(arg);
+                let (upper, alt) = (spec.conv.is_ascii_uppercase(), spec.has(b'#'));
+                let prec = spec.precision.unwrap_or(6);
+                let body = match spec.conv.to_ascii_lowercase() {
+                    b'a' => format::fmt_a(n.abs(), spec.precision, upper, alt),
+                    b'e' => format::fmt_e(n.abs(), prec, upper, alt),
+                    b'f' => format::fmt_f(n.abs(), prec, upper, alt),
+                    _ => format::fmt_g(n.abs(), prec, upper, alt),
+                };
+                let sign = spec.sign(n.is_sign_negative());
+                b.extend(spec.pad(sign, body.as_bytes(), n.is_finite()));
+            }
+            b'q' => add_literal(ls, &mut b, arg),
+            b's' => {
+                ls.tolstring_l(arg);
+                let s = ls.to_shared_bytes(-1).unwrap();
+                ls.pop(1);
+                if spec.precision.is_none() && s.len() >= 100 {
+                    // no precision and string is too long to be formatted
+                    b.extend_from_slice(&s); // keep entire string
+                } else {
+                    ls.arg_check(!s.contains(&0), arg, "string contains zeros");
+                    let l = spec.precision.map_or(s.len(), |p| p.min(s.len()));
+                    b.extend(spec.pad("", &s[..l], false));
+                }
+            }
+            c => {
+                // also treat cases 'pnLlh'
+                let msg = format!("invalid option '%{}' to 'format'", c as char);
+                ls.error_l(&msg);
+            }
+        }
+    }
+    ls.push_bytes(b);
+    1
+}
+
+// Digits of `n`, with at least `precision` of them (none for a zero with
+// precision 0, like C does).
+fn int_digits(n: u64, radix: u32, upper: bool, precision: Option<usize>) -> String {
+    let digits = match (radix, upper) {
+        (8, _) => format!("{:o}", n),
+        (16, false) => format!("{:x}", n),
+        (16, true) => format!("{:X}", n),
+        _ => n.to_string(),
+    };
+    match precision {
+        Some(0) if n == 0 => String::new(),
+        Some(p) if p > digits.len() => format!("{}{}", "0".repeat(p - digits.len()), digits),
+        _ => digits,
+    }
+}
+
+// `%q`: a literal that reads back as the same value.
+fn add_literal(ls: &mut dyn LuaAPI, b: &mut Vec<u8>, arg: isize) {
+    match ls.type_id(arg) {
+        LUA_TSTRING => add_quoted(b, &ls.to_shared_bytes(arg).unwrap()),
+        LUA_TNUMBER => {
+            let s = if ls.is_integer(arg) {
+                let n = ls.to_integer(arg);
+                if n == i64::MIN {
+                    format!("0x{:x}", n) // corner case: no literal for it
+                } else {
+                    n.to_string()
+                }
+            } else {
+                // write as hexa ('%a') to keep all bits
+                format::fmt_a(ls.to_number(arg), None, false, false)
+            };
+            b.extend_from_slice(s.as_bytes());
+        }
+        LUA_TNIL | LUA_TBOOLEAN => {
+            let s = ls.tolstring_l(arg);
+            ls.pop(1);
+            b.extend_from_slice(s.as_bytes());
+        }
+        _ => ls.arg_error(arg, "value has no literal form"),
+    }
+}
+
+fn add_quoted(b: &mut Vec<u8>, s: &[u8]) {
+    b.push(b'"');
+    for (i, &c) in s.iter().enumerate() {
+        if c == b'"' || c == b'\\' || c == b'\n' {
+            b.push(b'\\');
+            b.push(c);
+        } else if c.is_ascii_control() {
+            let next_is_digit = s.get(i + 1).is_some_and(u8::is_ascii_digit);
+            let esc = if next_is_digit { format!("\\{:03}", c) } else { format!("\\{}", c) };
+            b.extend_from_slice(esc.as_bytes());
+        } else {
+            b.push(c);
+        }
+    }
+    b.push(b'"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LuaState;
+    use crate::stdlib::base::open_base;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /* Lua source code:
+        assert(("x"):rep(3) == "xxx" and ("ab"):rep(3, ",") == "ab,ab,ab" and ("x"):rep(0) == "")
+        assert(#"hello" == 5 and string.len("\0a") == 2 and ("hello"):upper() == "HELLO")
+        assert(("hello"):sub(2, -2) == "ell" and ("hello"):sub(-3) == "llo" and ("hello"):sub(10) == "")
+        assert(("abc"):reverse() == "cba" and ("MiXeD"):lower() == "mixed")
+        local a, b, c = ("ABC"):byte(1, -1)
+        assert(a == 65 and b == 66 and c == 67 and ("A"):byte(10) == nil)
+        assert(string.char(72, 105) == "Hi" and #string.char(0, 255) == 2)
+        assert(("%d|%5d|%-5d|%05d|%+d|%.3d"):format(42, 42, 42, 42, 42, 7) == "42|   42|42   |00042|+42|007")
+        assert(("%x|%X|%#x|%o|%#o|%u"):format(255, 255, 255, 8, 8, -1) == "ff|FF|0xff|10|010|18446744073709551615")
+        assert(("%5.2f|%e|%.3E|%g|%g|%G"):format(3.14159, 12345.678, 0.001, 1e20, 0.1, 1e-10)
+               == " 3.14|1.234568e+04|1.000E-03|1e+20|0.1|1E-10")
+        assert(("%a|%A|%c%c|%%|%5s|%-5s|%.2s"):format(1, 0.5, 76, 117, "ab", "ab", "xyz") == "0x1p+0|0X1P-1|Lu|%|   ab|ab   |xy")
+        assert(("%q"):format('a "q"\n\0\0011\r') == '"a \\"q\\"\\\n\\0\\0011\\13"')
+        assert(("%q|%q|%q"):format(1/0, -9223372036854775807 - 1, 0.5) == "inf|0x8000000000000000|0x1p-1")
+        assert(("%s %s"):format(nil, true) == "nil true" and ("%i"):format(3.0) == "3")
+        assert(string.find("hello world", "o w") == 5 and select(2, string.find("hello", "l+")) == 4)
+        assert(string.find("a.b", ".", 1, true) == 2 and string.find("abc", "b", -1) == nil and string.find("", "") == 1)
+        local s, e, cap = string.find("key = value", "(%w+)%s*=")
+        assert(s == 1 and e == 5 and cap == "key")
+        assert(string.match("  trim  ", "^%s*(.-)%s*$") == "trim" and string.match("abc", "()b()") == 2)
+        assert(string.match("f(a(b)c)d", "%b()") == "(a(b)c)" and string.match("THE (quick) fox", "%f[%a]%a+", 5) == "quick")
+        assert(string.match("2024-01-15", "(%d+)-(%d+)-(%d+)") == "2024" and select(3, string.match("2024-01-15", "(%d+)-(%d+)-(%d+)")) == "15")
+        local words = {}
+        for w in string.gmatch("one two  three", "%a+") do words[#words + 1] = w end
+        assert(#words == 3 and words[3] == "three")
+        local kv = {}
+        for k, v in ("a=1, b=2"):gmatch("(%w+)=(%w+)") do kv[k] = v end
+        assert(kv.a == "1" and kv.b == "2")
+        local n = 0
+        for _ in ("abc"):gmatch("") do n = n + 1 end
+        assert(n == 4)
+        assert(string.gsub("hello world", "o", "0") == "hell0 w0rld" and select(2, string.gsub("abc", "", "-")) == 4)
+        assert(string.gsub("hello world", "(%w+)", "<%1>") == "<hello> <world>" and string.gsub("abc", "%w", "%0%0", 2) == "aabbc")
+        assert(string.gsub("$name is $age", "%$(%w+)", {name = "Bob", age = 42}) == "Bob is 42")
+        assert(string.gsub("1 2 3", "%d", function(d) return d .. d end) == "11 22 33")
+        assert(string.gsub("keep", "%w+", function() return nil end) == "keep" and string.gsub("^x", "^%^", "") == "x")
+        local ok, err = pcall(string.rep)
+        assert(err == "bad argument #1 to 'string.rep' (string expected, got no value)")
+        ok, err = pcall(string.format, "%y", 1)
+        assert(err == "invalid option '%y' to 'format'")
+        ok, err = pcall(string.format, "%d", 1.5)
+        assert(err == "bad argument #2 to 'string.format' (number has no integer representation)")
+        ok, err = pcall(string.find, "a", "[a")
+        assert(err == "malformed pattern (missing ']')")
+        ok, err = pcall(string.gsub, "a", "a", "%2")
+        assert(err == "invalid capture index %2")
+        ok, err = pcall(string.gsub, "a", "a", "%x")
+        assert(err == "invalid use of '%' in replacement string")
+        ok, err = pcall(string.gsub, "a", "a", {a = {}})
+        assert(err == "invalid replacement value (a table)")
+        ok, err = pcall(string.char, 256)
+        assert(err == "bad argument #1 to 'string.char' (value out of range)")
+        return ("%5.1f"):format(-2.25), ("%q"):format(0.1), #("x"):rep(100, "yz")
+    */
+    const LUA_STRING_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x0c, 0x40,
+        0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x6c, 0x75, 0x61, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x11, 0x67, 0x02, 0x00,
+        0x00, 0x06, 0x00, 0x40, 0x00, 0x41, 0x40, 0x00, 0x00, 0x4c, 0x80, 0xc0,
+        0x00, 0xc1, 0xc0, 0x00, 0x00, 0x64, 0x80, 0x80, 0x01, 0x1f, 0x00, 0xc1,
+        0x00, 0x1e, 0x00, 0x03, 0x80, 0x41, 0x40, 0x01, 0x00, 0x4c, 0x80, 0xc0,
+        0x00, 0xc1, 0xc0, 0x00, 0x00, 0x01, 0x81, 0x01, 0x00, 0x64, 0x80, 0x00,
+        0x02, 0x1f, 0xc0, 0xc1, 0x00, 0x1e, 0x40, 0x01, 0x80, 0x41, 0x40, 0x00,
+        0x00, 0x4c, 0x80, 0xc0, 0x00, 0xc1, 0x00, 0x02, 0x00, 0x64, 0x80, 0x80,
+        0x01, 0x5f, 0x40, 0xc2, 0x00, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00,
+        0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40,
+        0x00, 0x41, 0x80, 0x02, 0x00, 0x5c, 0x00, 0x80, 0x00, 0x1f, 0xc0, 0xc2,
+        0x00, 0x1e, 0x80, 0x02, 0x80, 0x46, 0x00, 0x43, 0x00, 0x47, 0x40, 0xc3,
+        0x00, 0x81, 0x80, 0x03, 0x00, 0x64, 0x80, 0x00, 0x01, 0x1f, 0xc0, 0xc3,
+        0x00, 0x1e, 0x00, 0x01, 0x80, 0x41, 0x80, 0x02, 0x00, 0x4c, 0x00, 0xc4,
+        0x00, 0x64, 0x80, 0x00, 0x01, 0x5f, 0x40, 0xc4, 0x00, 0x1e, 0x00, 0x00,
+        0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00,
+        0x01, 0x06, 0x00, 0x40, 0x00, 0x41, 0x80, 0x02, 0x00, 0x4c, 0x80, 0xc4,
+        0x00, 0xc1, 0xc0, 0x03, 0x00, 0x01, 0xc1, 0x04, 0x00, 0x64, 0x80, 0x00,
+        0x02, 0x1f, 0x00, 0xc5, 0x00, 0x1e, 0xc0, 0x02, 0x80, 0x41, 0x80, 0x02,
+        0x00, 0x4c, 0x80, 0xc4, 0x00, 0xc1, 0x40, 0x05, 0x00, 0x64, 0x80, 0x80,
+        0x01, 0x1f, 0x80, 0xc5, 0x00, 0x1e, 0x40, 0x01, 0x80, 0x41, 0x80, 0x02,
+        0x00, 0x4c, 0x80, 0xc4, 0x00, 0xc1, 0xc0, 0x05, 0x00, 0x64, 0x80, 0x80,
+        0x01, 0x5f, 0x40, 0xc2, 0x00, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00,
+        0x00, 0x43, 0x00, 0x80, 0x00, 0x24, 0x40, 0x00, 0x01, 0x06, 0x00, 0x40,
+        0x00, 0x41, 0x00, 0x06, 0x00, 0x4c, 0x40, 0xc6, 0x00, 0x64, 0x80, 0x00,
+        0x01, 0x1f, 0x80, 0xc6, 0x00, 0x1e, 0x00, 0x01, 0x80, 0x41, 0xc0, 0x06,
+        0x00, 0x4c, 0x00, 0xc7, 0x00, 0x64, 0x80, 0x00, 0x01, 0x5f, 0x40, 0xc7,
+        0x00, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x40, 0x00, 0x00, 0x43, 0x00, 0x80,
+        0x00, 0x24, 0x40, 0x00, 0x01, 0x01, 0x80, 0x07, 0x00, 0x0c, 0xc0, 0x47,
+        0x00, 0x81, 0x00, 0x08, 0x00, 0xc1, 0x40, 0x08, 0x00, 0x24, 0x00, 0x01,
+        0x02, 0xc6, 0x00, 0x40, 0x00, 0x1f, 0x80, 0x48, 0x00, 0x1e, 0x40, 0x02,
+        0x80, 0x1f, 0xc0, 0xc8, 0x00, 0x1e, 0xc0, 0x01, 0x80, 0x1f, 0x00, 0x49,
+        0x01, 0x1e, 0x40, 0x01, 0x80, 0x01, 0x41, 0x09, 0x00, 0x0c, 0xc1, 0x47,
+        0x02, 0x81, 0xc1, 0x05, 0x00, 0x24, 0x81, 0x80, 0x01, 0x5f, 0x80, 0x49,
+        0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80,
+        0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x40, 0x00, 0x06, 0x01, 0x43,
+        0x00, 0x07, 0xc1, 0x49, 0x02, 0x41, 0x01, 0x0a, 0x00, 0x81, 0x41, 0x0a,
+        0x00, 0x24, 0x81, 0x80, 0x01, 0x1f, 0x80, 0x4a, 0x02, 0x1e, 0xc0, 0x01,
+        0x80, 0x06, 0x01, 0x43, 0x00, 0x07, 0xc1, 0x49, 0x02, 0x41, 0x01, 0x02,
+        0x00, 0x81, 0xc1, 0x0a, 0x00, 0x24, 0x81, 0x80, 0x01, 0x1c, 0x01, 0x00,
+        0x02, 0x5f, 0xc0, 0x43, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00,
+        0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x40,
+        0x00, 0x01, 0x01, 0x0b, 0x00, 0x0c, 0x41, 0x4b, 0x02, 0x81, 0x81, 0x0b,
+        0x00, 0xc1, 0x81, 0x0b, 0x00, 0x01, 0x82, 0x0b, 0x00, 0x41, 0x82, 0x0b,
+        0x00, 0x81, 0x82, 0x0b, 0x00, 0xc1, 0xc2, 0x0b, 0x00, 0x24, 0x81, 0x00,
+        0x04, 0x5f, 0x00, 0x4c, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00,
+        0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x40,
+        0x00, 0x01, 0x41, 0x0c, 0x00, 0x0c, 0x41, 0x4b, 0x02, 0x81, 0xc1, 0x0a,
+        0x00, 0xc1, 0xc1, 0x0a, 0x00, 0x01, 0xc2, 0x0a, 0x00, 0x41, 0x82, 0x0c,
+        0x00, 0x81, 0x82, 0x0c, 0x00, 0xc1, 0x42, 0x08, 0x00, 0x24, 0x81, 0x00,
+        0x04, 0x5f, 0xc0, 0x4c, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00,
+        0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x40,
+        0x00, 0x01, 0x01, 0x0d, 0x00, 0x0c, 0x41, 0x4b, 0x02, 0x81, 0x41, 0x0d,
+        0x00, 0xc1, 0x81, 0x0d, 0x00, 0x01, 0xc2, 0x0d, 0x00, 0x41, 0x02, 0x0e,
+        0x00, 0x81, 0x42, 0x0e, 0x00, 0xc1, 0x82, 0x0e, 0x00, 0x24, 0x81, 0x00,
+        0x04, 0x5f, 0xc0, 0x4e, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00,
+        0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x40,
+        0x00, 0x01, 0x01, 0x0f, 0x00, 0x0c, 0x41, 0x4b, 0x02, 0x81, 0x01, 0x08,
+        0x00, 0xc1, 0x41, 0x0f, 0x00, 0x01, 0x82, 0x0f, 0x00, 0x41, 0xc2, 0x0f,
+        0x00, 0x81, 0x42, 0x01, 0x00, 0xc1, 0x42, 0x01, 0x00, 0x01, 0x03, 0x10,
+        0x00, 0x24, 0x81, 0x80, 0x04, 0x5f, 0x40, 0x50, 0x02, 0x1e, 0x00, 0x00,
+        0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00,
+        0x01, 0xc6, 0x00, 0x40, 0x00, 0x01, 0x81, 0x10, 0x00, 0x0c, 0x41, 0x4b,
+        0x02, 0x81, 0xc1, 0x10, 0x00, 0x24, 0x81, 0x80, 0x01, 0x5f, 0x00, 0x51,
+        0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80,
+        0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x40, 0x00, 0x01, 0x41, 0x11,
+        0x00, 0x0c, 0x41, 0x4b, 0x02, 0x92, 0x01, 0x42, 0x90, 0xc1, 0x81, 0x11,
+        0x00, 0x01, 0x42, 0x0f, 0x00, 0x24, 0x81, 0x80, 0x02, 0x5f, 0xc0, 0x51,
+        0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80,
+        0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x40, 0x00, 0x01, 0x01, 0x12,
+        0x00, 0x0c, 0x41, 0x4b, 0x02, 0x84, 0x01, 0x00, 0x00, 0xc3, 0x01, 0x80,
+        0x00, 0x24, 0x81, 0x00, 0x02, 0x1f, 0x40, 0x52, 0x02, 0x1e, 0x40, 0x01,
+        0x80, 0x01, 0x81, 0x12, 0x00, 0x0c, 0x41, 0x4b, 0x02, 0x81, 0xc1, 0x12,
+        0x00, 0x24, 0x81, 0x80, 0x01, 0x5f, 0x00, 0x53, 0x02, 0x1e, 0x00, 0x00,
+        0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00,
+        0x01, 0xc6, 0x00, 0x40, 0x00, 0x06, 0x01, 0x43, 0x00, 0x07, 0x41, 0x53,
+        0x02, 0x41, 0x81, 0x13, 0x00, 0x81, 0xc1, 0x13, 0x00, 0x24, 0x81, 0x80,
+        0x01, 0x1f, 0xc0, 0x42, 0x02, 0x1e, 0x40, 0x02, 0x80, 0x06, 0x01, 0x54,
+        0x00, 0x41, 0xc1, 0x03, 0x00, 0x86, 0x01, 0x43, 0x00, 0x87, 0x41, 0x53,
+        0x03, 0xc1, 0x81, 0x02, 0x00, 0x01, 0x42, 0x14, 0x00, 0xa4, 0x01, 0x80,
+        0x01, 0x24, 0x81, 0x00, 0x00, 0x5f, 0x80, 0x54, 0x02, 0x1e, 0x00, 0x00,
+        0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00,
+        0x01, 0xc6, 0x00, 0x40, 0x00, 0x06, 0x01, 0x43, 0x00, 0x07, 0x41, 0x53,
+        0x02, 0x41, 0xc1, 0x14, 0x00, 0x81, 0x01, 0x15, 0x00, 0xc1, 0x01, 0x08,
+        0x00, 0x03, 0x02, 0x80, 0x00, 0x24, 0x81, 0x80, 0x02, 0x1f, 0xc0, 0x43,
+        0x02, 0x1e, 0x80, 0x03, 0x80, 0x06, 0x01, 0x43, 0x00, 0x07, 0x41, 0x53,
+        0x02, 0x41, 0x01, 0x06, 0x00, 0x81, 0x41, 0x15, 0x00, 0xc1, 0x41, 0x08,
+        0x00, 0x24, 0x81, 0x00, 0x02, 0x1f, 0x80, 0x49, 0x02, 0x1e, 0x80, 0x01,
+        0x80, 0x06, 0x01, 0x43, 0x00, 0x07, 0x41, 0x53, 0x02, 0x41, 0x41, 0x02,
+        0x00, 0x81, 0x41, 0x02, 0x00, 0x24, 0x81, 0x80, 0x01, 0x5f, 0x00, 0x48,
+        0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80,
+        0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x00, 0x43, 0x00, 0xc7, 0x40, 0xd3,
+        0x01, 0x01, 0x81, 0x15, 0x00, 0x41, 0xc1, 0x15, 0x00, 0xe4, 0x00, 0x81,
+        0x01, 0x86, 0x01, 0x40, 0x00, 0x1f, 0x00, 0xc8, 0x01, 0x1e, 0xc0, 0x00,
+        0x80, 0x1f, 0xc0, 0x42, 0x02, 0x1e, 0x40, 0x00, 0x80, 0x5f, 0x00, 0xd6,
+        0x02, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00, 0xc3, 0x01, 0x80,
+        0x00, 0xa4, 0x41, 0x00, 0x01, 0x86, 0x01, 0x40, 0x00, 0xc6, 0x01, 0x43,
+        0x00, 0xc7, 0x41, 0xd6, 0x03, 0x01, 0x82, 0x16, 0x00, 0x41, 0xc2, 0x16,
+        0x00, 0xe4, 0x81, 0x80, 0x01, 0x1f, 0x00, 0xd7, 0x03, 0x1e, 0x80, 0x01,
+        0x80, 0xc6, 0x01, 0x43, 0x00, 0xc7, 0x41, 0xd6, 0x03, 0x01, 0x02, 0x06,
+        0x00, 0x41, 0x42, 0x17, 0x00, 0xe4, 0x81, 0x80, 0x01, 0x5f, 0xc0, 0xc3,
+        0x03, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00, 0xc3, 0x01, 0x80,
+        0x00, 0xa4, 0x41, 0x00, 0x01, 0x86, 0x01, 0x40, 0x00, 0xc6, 0x01, 0x43,
+        0x00, 0xc7, 0x41, 0xd6, 0x03, 0x01, 0x82, 0x17, 0x00, 0x41, 0xc2, 0x17,
+        0x00, 0xe4, 0x81, 0x80, 0x01, 0x1f, 0x00, 0xd8, 0x03, 0x1e, 0xc0, 0x01,
+        0x80, 0xc6, 0x01, 0x43, 0x00, 0xc7, 0x41, 0xd6, 0x03, 0x01, 0x42, 0x18,
+        0x00, 0x41, 0x82, 0x18, 0x00, 0x81, 0xc2, 0x02, 0x00, 0xe4, 0x81, 0x00,
+        0x02, 0x5f, 0xc0, 0xd8, 0x03, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00,
+        0x00, 0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86, 0x01, 0x40,
+        0x00, 0xc6, 0x01, 0x43, 0x00, 0xc7, 0x41, 0xd6, 0x03, 0x01, 0x02, 0x19,
+        0x00, 0x41, 0x42, 0x19, 0x00, 0xe4, 0x81, 0x80, 0x01, 0x1f, 0x80, 0xd9,
+        0x03, 0x1e, 0x40, 0x02, 0x80, 0xc6, 0x01, 0x54, 0x00, 0x01, 0xc2, 0x00,
+        0x00, 0x46, 0x02, 0x43, 0x00, 0x47, 0x42, 0xd6, 0x04, 0x81, 0x02, 0x19,
+        0x00, 0xc1, 0x42, 0x19, 0x00, 0x64, 0x02, 0x80, 0x01, 0xe4, 0x81, 0x00,
+        0x00, 0x5f, 0xc0, 0xd9, 0x03, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00,
+        0x00, 0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x8b, 0x01, 0x00,
+        0x00, 0xc6, 0x01, 0x43, 0x00, 0xc7, 0x01, 0xda, 0x03, 0x01, 0x42, 0x1a,
+        0x00, 0x41, 0x82, 0x1a, 0x00, 0xe4, 0x01, 0x81, 0x01, 0x1e, 0x80, 0x00,
+        0x80, 0xdc, 0x02, 0x00, 0x03, 0xcd, 0x02, 0xc8, 0x05, 0x8a, 0x81, 0x82,
+        0x05, 0xe9, 0x41, 0x00, 0x00, 0x6a, 0x82, 0xfe, 0x7f, 0xc6, 0x01, 0x40,
+        0x00, 0x1c, 0x02, 0x00, 0x03, 0x1f, 0xc0, 0x40, 0x04, 0x1e, 0x80, 0x00,
+        0x80, 0x07, 0xc2, 0x40, 0x03, 0x5f, 0xc0, 0x5a, 0x04, 0x1e, 0x00, 0x00,
+        0x80, 0x03, 0x42, 0x00, 0x00, 0x03, 0x02, 0x80, 0x00, 0xe4, 0x41, 0x00,
+        0x01, 0xcb, 0x01, 0x00, 0x00, 0x01, 0x02, 0x1b, 0x00, 0x0c, 0x02, 0x5a,
+        0x04, 0x81, 0x42, 0x1b, 0x00, 0x24, 0x02, 0x81, 0x01, 0x1e, 0x00, 0x00,
+        0x80, 0xca, 0x01, 0x83, 0x05, 0x29, 0x82, 0x00, 0x00, 0xaa, 0x02, 0xff,
+        0x7f, 0x06, 0x02, 0x40, 0x00, 0x47, 0x82, 0xdb, 0x03, 0x1f, 0xc0, 0xdb,
+        0x04, 0x1e, 0x80, 0x00, 0x80, 0x47, 0x42, 0xd5, 0x03, 0x5f, 0x00, 0xdc,
+        0x04, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x42, 0x00, 0x00, 0x43, 0x02, 0x80,
+        0x00, 0x24, 0x42, 0x00, 0x01, 0x01, 0x02, 0x02, 0x00, 0x41, 0x02, 0x06,
+        0x00, 0x4c, 0x02, 0xda, 0x04, 0xc1, 0x42, 0x02, 0x00, 0x64, 0x02, 0x81,
+        0x01, 0x1e, 0x00, 0x00, 0x80, 0x0d, 0x02, 0x48, 0x04, 0x69, 0x42, 0x00,
+        0x00, 0xea, 0x02, 0xff, 0x7f, 0x46, 0x02, 0x40, 0x00, 0x5f, 0x80, 0x54,
+        0x04, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80,
+        0x00, 0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x40, 0x00, 0x86, 0x02, 0x43,
+        0x00, 0x87, 0x42, 0x5c, 0x05, 0xc1, 0x82, 0x13, 0x00, 0x01, 0x83, 0x1c,
+        0x00, 0x41, 0xc3, 0x1c, 0x00, 0xa4, 0x82, 0x00, 0x02, 0x1f, 0x00, 0x5d,
+        0x05, 0x1e, 0x80, 0x02, 0x80, 0x86, 0x02, 0x54, 0x00, 0xc1, 0xc2, 0x03,
+        0x00, 0x06, 0x03, 0x43, 0x00, 0x07, 0x43, 0x5c, 0x06, 0x41, 0x03, 0x06,
+        0x00, 0x81, 0x43, 0x02, 0x00, 0xc1, 0x43, 0x1d, 0x00, 0x24, 0x03, 0x00,
+        0x02, 0xa4, 0x82, 0x00, 0x00, 0x5f, 0x80, 0x54, 0x05, 0x1e, 0x00, 0x00,
+        0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00,
+        0x01, 0x46, 0x02, 0x40, 0x00, 0x86, 0x02, 0x43, 0x00, 0x87, 0x42, 0x5c,
+        0x05, 0xc1, 0x82, 0x13, 0x00, 0x01, 0x83, 0x1d, 0x00, 0x41, 0xc3, 0x1d,
+        0x00, 0xa4, 0x82, 0x00, 0x02, 0x1f, 0x00, 0x5e, 0x05, 0x1e, 0x00, 0x02,
+        0x80, 0x86, 0x02, 0x43, 0x00, 0x87, 0x42, 0x5c, 0x05, 0xc1, 0x02, 0x06,
+        0x00, 0x01, 0x43, 0x1e, 0x00, 0x41, 0x83, 0x1e, 0x00, 0x81, 0xc3, 0x03,
+        0x00, 0xa4, 0x82, 0x80, 0x02, 0x5f, 0xc0, 0x5e, 0x05, 0x1e, 0x00, 0x00,
+        0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00,
+        0x01, 0x46, 0x02, 0x40, 0x00, 0x86, 0x02, 0x43, 0x00, 0x87, 0x42, 0x5c,
+        0x05, 0xc1, 0x02, 0x1f, 0x00, 0x01, 0x43, 0x1f, 0x00, 0x4b, 0x83, 0x00,
+        0x00, 0x4a, 0xc3, 0x5f, 0xbf, 0x4a, 0x83, 0x4b, 0xc0, 0xa4, 0x82, 0x00,
+        0x02, 0x5f, 0x40, 0x60, 0x05, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00,
+        0x00, 0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x40,
+        0x00, 0x86, 0x02, 0x43, 0x00, 0x87, 0x42, 0x5c, 0x05, 0xc1, 0x82, 0x20,
+        0x00, 0x01, 0xc3, 0x20, 0x00, 0x6c, 0x03, 0x00, 0x00, 0xa4, 0x82, 0x00,
+        0x02, 0x5f, 0x00, 0x61, 0x05, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00,
+        0x00, 0x83, 0x02, 0x80, 0x00, 0x64, 0x42, 0x00, 0x01, 0x46, 0x02, 0x40,
+        0x00, 0x86, 0x02, 0x43, 0x00, 0x87, 0x42, 0x5c, 0x05, 0xc1, 0x42, 0x21,
+        0x00, 0x01, 0x83, 0x21, 0x00, 0x6c, 0x43, 0x00, 0x00, 0xa4, 0x82, 0x00,
+        0x02, 0x1f, 0x40, 0x61, 0x05, 0x1e, 0xc0, 0x01, 0x80, 0x86, 0x02, 0x43,
+        0x00, 0x87, 0x42, 0x5c, 0x05, 0xc1, 0xc2, 0x21, 0x00, 0x01, 0x03, 0x22,
+        0x00, 0x41, 0x43, 0x02, 0x00, 0xa4, 0x82, 0x00, 0x02, 0x5f, 0x40, 0x40,
+        0x05, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80,
+        0x00, 0x64, 0x42, 0x00, 0x01, 0x46, 0x42, 0x62, 0x00, 0x86, 0x02, 0x43,
+        0x00, 0x87, 0x82, 0x40, 0x05, 0x64, 0xc2, 0x00, 0x01, 0xc6, 0x02, 0x40,
+        0x00, 0x5f, 0x80, 0x62, 0x05, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x43, 0x00,
+        0x00, 0x03, 0x03, 0x80, 0x00, 0xe4, 0x42, 0x00, 0x01, 0xc6, 0x42, 0x62,
+        0x00, 0x06, 0x03, 0x43, 0x00, 0x07, 0x43, 0x4b, 0x06, 0x41, 0xc3, 0x22,
+        0x00, 0x81, 0x03, 0x08, 0x00, 0xe4, 0xc2, 0x00, 0x02, 0x80, 0x02, 0x00,
+        0x06, 0x40, 0x02, 0x80, 0x05, 0xc6, 0x02, 0x40, 0x00, 0x5f, 0x00, 0x63,
+        0x05, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x43, 0x00, 0x00, 0x03, 0x03, 0x80,
+        0x00, 0xe4, 0x42, 0x00, 0x01, 0xc6, 0x42, 0x62, 0x00, 0x06, 0x03, 0x43,
+        0x00, 0x07, 0x43, 0x4b, 0x06, 0x41, 0xc3, 0x20, 0x00, 0x81, 0x43, 0x23,
+        0x00, 0xe4, 0xc2, 0x00, 0x02, 0x80, 0x02, 0x00, 0x06, 0x40, 0x02, 0x80,
+        0x05, 0xc6, 0x02, 0x40, 0x00, 0x5f, 0x80, 0x63, 0x05, 0x1e, 0x00, 0x00,
+        0x80, 0x03, 0x43, 0x00, 0x00, 0x03, 0x03, 0x80, 0x00, 0xe4, 0x42, 0x00,
+        0x01, 0xc6, 0x42, 0x62, 0x00, 0x06, 0x03, 0x43, 0x00, 0x07, 0x43, 0x53,
+        0x06, 0x41, 0x83, 0x1b, 0x00, 0x81, 0xc3, 0x23, 0x00, 0xe4, 0xc2, 0x00,
+        0x02, 0x80, 0x02, 0x00, 0x06, 0x40, 0x02, 0x80, 0x05, 0xc6, 0x02, 0x40,
+        0x00, 0x5f, 0x00, 0x64, 0x05, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x43, 0x00,
+        0x00, 0x03, 0x03, 0x80, 0x00, 0xe4, 0x42, 0x00, 0x01, 0xc6, 0x42, 0x62,
+        0x00, 0x06, 0x03, 0x43, 0x00, 0x07, 0x43, 0x5c, 0x06, 0x41, 0x83, 0x1b,
+        0x00, 0x81, 0x83, 0x1b, 0x00, 0xc1, 0x43, 0x24, 0x00, 0xe4, 0xc2, 0x80,
+        0x02, 0x80, 0x02, 0x00, 0x06, 0x40, 0x02, 0x80, 0x05, 0xc6, 0x02, 0x40,
+        0x00, 0x5f, 0x80, 0x64, 0x05, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x43, 0x00,
+        0x00, 0x03, 0x03, 0x80, 0x00, 0xe4, 0x42, 0x00, 0x01, 0xc6, 0x42, 0x62,
+        0x00, 0x06, 0x03, 0x43, 0x00, 0x07, 0x43, 0x5c, 0x06, 0x41, 0x83, 0x1b,
+        0x00, 0x81, 0x83, 0x1b, 0x00, 0xc1, 0xc3, 0x24, 0x00, 0xe4, 0xc2, 0x80,
+        0x02, 0x80, 0x02, 0x00, 0x06, 0x40, 0x02, 0x80, 0x05, 0xc6, 0x02, 0x40,
+        0x00, 0x5f, 0x00, 0x65, 0x05, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x43, 0x00,
+        0x00, 0x03, 0x03, 0x80, 0x00, 0xe4, 0x42, 0x00, 0x01, 0xc6, 0x42, 0x62,
+        0x00, 0x06, 0x03, 0x43, 0x00, 0x07, 0x43, 0x5c, 0x06, 0x41, 0x83, 0x1b,
+        0x00, 0x81, 0x83, 0x1b, 0x00, 0xcb, 0x43, 0x00, 0x00, 0x0b, 0x04, 0x00,
+        0x00, 0xca, 0x03, 0x04, 0xb7, 0xe4, 0xc2, 0x80, 0x02, 0x80, 0x02, 0x00,
+        0x06, 0x40, 0x02, 0x80, 0x05, 0xc6, 0x02, 0x40, 0x00, 0x5f, 0x40, 0x65,
+        0x05, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x43, 0x00, 0x00, 0x03, 0x03, 0x80,
+        0x00, 0xe4, 0x42, 0x00, 0x01, 0xc6, 0x42, 0x62, 0x00, 0x06, 0x03, 0x43,
+        0x00, 0x07, 0xc3, 0x49, 0x06, 0x41, 0x83, 0x25, 0x00, 0xe4, 0xc2, 0x80,
+        0x01, 0x80, 0x02, 0x00, 0x06, 0x40, 0x02, 0x80, 0x05, 0xc6, 0x02, 0x40,
+        0x00, 0x5f, 0xc0, 0x65, 0x05, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x43, 0x00,
+        0x00, 0x03, 0x03, 0x80, 0x00, 0xe4, 0x42, 0x00, 0x01, 0xc1, 0x02, 0x26,
+        0x00, 0xcc, 0x42, 0xcb, 0x05, 0x41, 0x43, 0x26, 0x00, 0xe4, 0x82, 0x80,
+        0x01, 0x01, 0x83, 0x10, 0x00, 0x0c, 0x43, 0x4b, 0x06, 0x81, 0x43, 0x0e,
+        0x00, 0x24, 0x83, 0x80, 0x01, 0x41, 0x43, 0x00, 0x00, 0x4c, 0x83, 0xc0,
+        0x06, 0xc1, 0x83, 0x26, 0x00, 0x01, 0xc4, 0x26, 0x00, 0x64, 0x83, 0x00,
+        0x02, 0x5c, 0x03, 0x80, 0x06, 0xe6, 0x02, 0x00, 0x02, 0x26, 0x00, 0x80,
+        0x00, 0x9c, 0x00, 0x00, 0x00, 0x04, 0x07, 0x61, 0x73, 0x73, 0x65, 0x72,
+        0x74, 0x04, 0x02, 0x78, 0x04, 0x04, 0x72, 0x65, 0x70, 0x13, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x04, 0x78, 0x78, 0x78, 0x04,
+        0x03, 0x61, 0x62, 0x04, 0x02, 0x2c, 0x04, 0x09, 0x61, 0x62, 0x2c, 0x61,
+        0x62, 0x2c, 0x61, 0x62, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x01, 0x04, 0x06, 0x68, 0x65, 0x6c, 0x6c, 0x6f, 0x13, 0x05,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x07, 0x73, 0x74, 0x72,
+        0x69, 0x6e, 0x67, 0x04, 0x04, 0x6c, 0x65, 0x6e, 0x04, 0x03, 0x00, 0x61,
+        0x13, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x06, 0x75,
+        0x70, 0x70, 0x65, 0x72, 0x04, 0x06, 0x48, 0x45, 0x4c, 0x4c, 0x4f, 0x04,
+        0x04, 0x73, 0x75, 0x62, 0x13, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x04, 0x04, 0x65, 0x6c, 0x6c, 0x13, 0xfd, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0x04, 0x04, 0x6c, 0x6c, 0x6f, 0x13, 0x0a, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x04, 0x61, 0x62, 0x63, 0x04, 0x08,
+        0x72, 0x65, 0x76, 0x65, 0x72, 0x73, 0x65, 0x04, 0x04, 0x63, 0x62, 0x61,
+        0x04, 0x06, 0x4d, 0x69, 0x58, 0x65, 0x44, 0x04, 0x06, 0x6c, 0x6f, 0x77,
+        0x65, 0x72, 0x04, 0x06, 0x6d, 0x69, 0x78, 0x65, 0x64, 0x04, 0x04, 0x41,
+        0x42, 0x43, 0x04, 0x05, 0x62, 0x79, 0x74, 0x65, 0x13, 0x01, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0x13, 0x41, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13,
+        0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x43, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x41, 0x00, 0x04, 0x05, 0x63,
+        0x68, 0x61, 0x72, 0x13, 0x48, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x13, 0x69, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x03, 0x48,
+        0x69, 0x13, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x1a,
+        0x25, 0x64, 0x7c, 0x25, 0x35, 0x64, 0x7c, 0x25, 0x2d, 0x35, 0x64, 0x7c,
+        0x25, 0x30, 0x35, 0x64, 0x7c, 0x25, 0x2b, 0x64, 0x7c, 0x25, 0x2e, 0x33,
+        0x64, 0x04, 0x07, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x13, 0x2a, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x07, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x1d, 0x34, 0x32, 0x7c, 0x20, 0x20, 0x20, 0x34,
+        0x32, 0x7c, 0x34, 0x32, 0x20, 0x20, 0x20, 0x7c, 0x30, 0x30, 0x30, 0x34,
+        0x32, 0x7c, 0x2b, 0x34, 0x32, 0x7c, 0x30, 0x30, 0x37, 0x04, 0x14, 0x25,
+        0x78, 0x7c, 0x25, 0x58, 0x7c, 0x25, 0x23, 0x78, 0x7c, 0x25, 0x6f, 0x7c,
+        0x25, 0x23, 0x6f, 0x7c, 0x25, 0x75, 0x13, 0x08, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x27, 0x66, 0x66, 0x7c, 0x46, 0x46, 0x7c, 0x30,
+        0x78, 0x66, 0x66, 0x7c, 0x31, 0x30, 0x7c, 0x30, 0x31, 0x30, 0x7c, 0x31,
+        0x38, 0x34, 0x34, 0x36, 0x37, 0x34, 0x34, 0x30, 0x37, 0x33, 0x37, 0x30,
+        0x39, 0x35, 0x35, 0x31, 0x36, 0x31, 0x35, 0x04, 0x17, 0x25, 0x35, 0x2e,
+        0x32, 0x66, 0x7c, 0x25, 0x65, 0x7c, 0x25, 0x2e, 0x33, 0x45, 0x7c, 0x25,
+        0x67, 0x7c, 0x25, 0x67, 0x7c, 0x25, 0x47, 0x03, 0x6e, 0x86, 0x1b, 0xf0,
+        0xf9, 0x21, 0x09, 0x40, 0x03, 0x58, 0x39, 0xb4, 0xc8, 0xd6, 0x1c, 0xc8,
+        0x40, 0x03, 0xfc, 0xa9, 0xf1, 0xd2, 0x4d, 0x62, 0x50, 0x3f, 0x03, 0x40,
+        0x8c, 0xb5, 0x78, 0x1d, 0xaf, 0x15, 0x44, 0x03, 0x9a, 0x99, 0x99, 0x99,
+        0x99, 0x99, 0xb9, 0x3f, 0x03, 0xbb, 0xbd, 0xd7, 0xd9, 0xdf, 0x7c, 0xdb,
+        0x3d, 0x14, 0x2d, 0x20, 0x33, 0x2e, 0x31, 0x34, 0x7c, 0x31, 0x2e, 0x32,
+        0x33, 0x34, 0x35, 0x36, 0x38, 0x65, 0x2b, 0x30, 0x34, 0x7c, 0x31, 0x2e,
+        0x30, 0x30, 0x30, 0x45, 0x2d, 0x30, 0x33, 0x7c, 0x31, 0x65, 0x2b, 0x32,
+        0x30, 0x7c, 0x30, 0x2e, 0x31, 0x7c, 0x31, 0x45, 0x2d, 0x31, 0x30, 0x04,
+        0x1c, 0x25, 0x61, 0x7c, 0x25, 0x41, 0x7c, 0x25, 0x63, 0x25, 0x63, 0x7c,
+        0x25, 0x25, 0x7c, 0x25, 0x35, 0x73, 0x7c, 0x25, 0x2d, 0x35, 0x73, 0x7c,
+        0x25, 0x2e, 0x32, 0x73, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe0,
+        0x3f, 0x13, 0x4c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x75,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x04, 0x78, 0x79, 0x7a,
+        0x04, 0x22, 0x30, 0x78, 0x31, 0x70, 0x2b, 0x30, 0x7c, 0x30, 0x58, 0x31,
+        0x50, 0x2d, 0x31, 0x7c, 0x4c, 0x75, 0x7c, 0x25, 0x7c, 0x20, 0x20, 0x20,
+        0x61, 0x62, 0x7c, 0x61, 0x62, 0x20, 0x20, 0x20, 0x7c, 0x78, 0x79, 0x04,
+        0x03, 0x25, 0x71, 0x04, 0x0b, 0x61, 0x20, 0x22, 0x71, 0x22, 0x0a, 0x00,
+        0x01, 0x31, 0x0d, 0x04, 0x16, 0x22, 0x61, 0x20, 0x5c, 0x22, 0x71, 0x5c,
+        0x22, 0x5c, 0x0a, 0x5c, 0x30, 0x5c, 0x30, 0x30, 0x31, 0x31, 0x5c, 0x31,
+        0x33, 0x22, 0x04, 0x09, 0x25, 0x71, 0x7c, 0x25, 0x71, 0x7c, 0x25, 0x71,
+        0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x04, 0x1e, 0x69,
+        0x6e, 0x66, 0x7c, 0x30, 0x78, 0x38, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30,
+        0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x30, 0x7c, 0x30, 0x78,
+        0x31, 0x70, 0x2d, 0x31, 0x04, 0x06, 0x25, 0x73, 0x20, 0x25, 0x73, 0x04,
+        0x09, 0x6e, 0x69, 0x6c, 0x20, 0x74, 0x72, 0x75, 0x65, 0x04, 0x03, 0x25,
+        0x69, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x40, 0x04, 0x02,
+        0x33, 0x04, 0x05, 0x66, 0x69, 0x6e, 0x64, 0x04, 0x0c, 0x68, 0x65, 0x6c,
+        0x6c, 0x6f, 0x20, 0x77, 0x6f, 0x72, 0x6c, 0x64, 0x04, 0x04, 0x6f, 0x20,
+        0x77, 0x04, 0x07, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x04, 0x03, 0x6c,
+        0x2b, 0x13, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x04,
+        0x61, 0x2e, 0x62, 0x04, 0x02, 0x2e, 0x04, 0x02, 0x62, 0x04, 0x0c, 0x6b,
+        0x65, 0x79, 0x20, 0x3d, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x04, 0x0a,
+        0x28, 0x25, 0x77, 0x2b, 0x29, 0x25, 0x73, 0x2a, 0x3d, 0x04, 0x04, 0x6b,
+        0x65, 0x79, 0x04, 0x06, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x04, 0x09, 0x20,
+        0x20, 0x74, 0x72, 0x69, 0x6d, 0x20, 0x20, 0x04, 0x0d, 0x5e, 0x25, 0x73,
+        0x2a, 0x28, 0x2e, 0x2d, 0x29, 0x25, 0x73, 0x2a, 0x24, 0x04, 0x05, 0x74,
+        0x72, 0x69, 0x6d, 0x04, 0x06, 0x28, 0x29, 0x62, 0x28, 0x29, 0x04, 0x0a,
+        0x66, 0x28, 0x61, 0x28, 0x62, 0x29, 0x63, 0x29, 0x64, 0x04, 0x05, 0x25,
+        0x62, 0x28, 0x29, 0x04, 0x08, 0x28, 0x61, 0x28, 0x62, 0x29, 0x63, 0x29,
+        0x04, 0x10, 0x54, 0x48, 0x45, 0x20, 0x28, 0x71, 0x75, 0x69, 0x63, 0x6b,
+        0x29, 0x20, 0x66, 0x6f, 0x78, 0x04, 0x0a, 0x25, 0x66, 0x5b, 0x25, 0x61,
+        0x5d, 0x25, 0x61, 0x2b, 0x04, 0x06, 0x71, 0x75, 0x69, 0x63, 0x6b, 0x04,
+        0x0b, 0x32, 0x30, 0x32, 0x34, 0x2d, 0x30, 0x31, 0x2d, 0x31, 0x35, 0x04,
+        0x12, 0x28, 0x25, 0x64, 0x2b, 0x29, 0x2d, 0x28, 0x25, 0x64, 0x2b, 0x29,
+        0x2d, 0x28, 0x25, 0x64, 0x2b, 0x29, 0x04, 0x05, 0x32, 0x30, 0x32, 0x34,
+        0x04, 0x03, 0x31, 0x35, 0x04, 0x07, 0x67, 0x6d, 0x61, 0x74, 0x63, 0x68,
+        0x04, 0x0f, 0x6f, 0x6e, 0x65, 0x20, 0x74, 0x77, 0x6f, 0x20, 0x20, 0x74,
+        0x68, 0x72, 0x65, 0x65, 0x04, 0x04, 0x25, 0x61, 0x2b, 0x04, 0x06, 0x74,
+        0x68, 0x72, 0x65, 0x65, 0x04, 0x09, 0x61, 0x3d, 0x31, 0x2c, 0x20, 0x62,
+        0x3d, 0x32, 0x04, 0x0c, 0x28, 0x25, 0x77, 0x2b, 0x29, 0x3d, 0x28, 0x25,
+        0x77, 0x2b, 0x29, 0x04, 0x02, 0x61, 0x04, 0x02, 0x31, 0x04, 0x02, 0x32,
+        0x04, 0x05, 0x67, 0x73, 0x75, 0x62, 0x04, 0x02, 0x6f, 0x04, 0x02, 0x30,
+        0x04, 0x0c, 0x68, 0x65, 0x6c, 0x6c, 0x30, 0x20, 0x77, 0x30, 0x72, 0x6c,
+        0x64, 0x04, 0x02, 0x2d, 0x04, 0x06, 0x28, 0x25, 0x77, 0x2b, 0x29, 0x04,
+        0x05, 0x3c, 0x25, 0x31, 0x3e, 0x04, 0x10, 0x3c, 0x68, 0x65, 0x6c, 0x6c,
+        0x6f, 0x3e, 0x20, 0x3c, 0x77, 0x6f, 0x72, 0x6c, 0x64, 0x3e, 0x04, 0x03,
+        0x25, 0x77, 0x04, 0x05, 0x25, 0x30, 0x25, 0x30, 0x04, 0x06, 0x61, 0x61,
+        0x62, 0x62, 0x63, 0x04, 0x0e, 0x24, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x69,
+        0x73, 0x20, 0x24, 0x61, 0x67, 0x65, 0x04, 0x08, 0x25, 0x24, 0x28, 0x25,
+        0x77, 0x2b, 0x29, 0x04, 0x05, 0x6e, 0x61, 0x6d, 0x65, 0x04, 0x04, 0x42,
+        0x6f, 0x62, 0x04, 0x04, 0x61, 0x67, 0x65, 0x04, 0x0a, 0x42, 0x6f, 0x62,
+        0x20, 0x69, 0x73, 0x20, 0x34, 0x32, 0x04, 0x06, 0x31, 0x20, 0x32, 0x20,
+        0x33, 0x04, 0x03, 0x25, 0x64, 0x04, 0x09, 0x31, 0x31, 0x20, 0x32, 0x32,
+        0x20, 0x33, 0x33, 0x04, 0x05, 0x6b, 0x65, 0x65, 0x70, 0x04, 0x04, 0x25,
+        0x77, 0x2b, 0x04, 0x03, 0x5e, 0x78, 0x04, 0x04, 0x5e, 0x25, 0x5e, 0x04,
+        0x06, 0x70, 0x63, 0x61, 0x6c, 0x6c, 0x14, 0x40, 0x62, 0x61, 0x64, 0x20,
+        0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x31, 0x20,
+        0x74, 0x6f, 0x20, 0x27, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x72,
+        0x65, 0x70, 0x27, 0x20, 0x28, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20,
+        0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x2c, 0x20, 0x67, 0x6f,
+        0x74, 0x20, 0x6e, 0x6f, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x29, 0x04,
+        0x03, 0x25, 0x79, 0x04, 0x20, 0x69, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64,
+        0x20, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x27, 0x25, 0x79, 0x27,
+        0x20, 0x74, 0x6f, 0x20, 0x27, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x27,
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x3f, 0x14, 0x4a, 0x62,
+        0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20,
+        0x23, 0x32, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x73, 0x74, 0x72, 0x69, 0x6e,
+        0x67, 0x2e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x27, 0x20, 0x28, 0x6e,
+        0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x68, 0x61, 0x73, 0x20, 0x6e, 0x6f,
+        0x20, 0x69, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x20, 0x72, 0x65, 0x70,
+        0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x29,
+        0x04, 0x03, 0x5b, 0x61, 0x04, 0x20, 0x6d, 0x61, 0x6c, 0x66, 0x6f, 0x72,
+        0x6d, 0x65, 0x64, 0x20, 0x70, 0x61, 0x74, 0x74, 0x65, 0x72, 0x6e, 0x20,
+        0x28, 0x6d, 0x69, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x27, 0x5d, 0x27,
+        0x29, 0x04, 0x03, 0x25, 0x32, 0x04, 0x19, 0x69, 0x6e, 0x76, 0x61, 0x6c,
+        0x69, 0x64, 0x20, 0x63, 0x61, 0x70, 0x74, 0x75, 0x72, 0x65, 0x20, 0x69,
+        0x6e, 0x64, 0x65, 0x78, 0x20, 0x25, 0x32, 0x04, 0x03, 0x25, 0x78, 0x04,
+        0x29, 0x69, 0x6e, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x75, 0x73, 0x65,
+        0x20, 0x6f, 0x66, 0x20, 0x27, 0x25, 0x27, 0x20, 0x69, 0x6e, 0x20, 0x72,
+        0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x73,
+        0x74, 0x72, 0x69, 0x6e, 0x67, 0x04, 0x24, 0x69, 0x6e, 0x76, 0x61, 0x6c,
+        0x69, 0x64, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x6d, 0x65,
+        0x6e, 0x74, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x28, 0x61, 0x20,
+        0x74, 0x61, 0x62, 0x6c, 0x65, 0x29, 0x13, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x14, 0x36, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67,
+        0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x31, 0x20, 0x74, 0x6f, 0x20,
+        0x27, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x63, 0x68, 0x61, 0x72,
+        0x27, 0x20, 0x28, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x6f, 0x75, 0x74,
+        0x20, 0x6f, 0x66, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x29, 0x04, 0x06,
+        0x25, 0x35, 0x2e, 0x31, 0x66, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0xc0, 0x13, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x03, 0x79, 0x7a, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x03, 0x05, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00,
+        0x00, 0x5d, 0x80, 0x80, 0x00, 0x66, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x05, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00,
+        0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x64, 0x00, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00,
+        0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x67,
+        0x02, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x09,
+        0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09,
+        0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09,
+        0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09,
+        0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09,
+        0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x0a,
+        0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a,
+        0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a,
+        0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12,
+        0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12,
+        0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13,
+        0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13,
+        0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13,
+        0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15,
+        0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15,
+        0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15,
+        0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15,
+        0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15,
+        0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15,
+        0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x17,
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x19,
+        0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19,
+        0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19,
+        0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19,
+        0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b,
+        0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b,
+        0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c,
+        0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1e,
+        0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e,
+        0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e,
+        0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f,
+        0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23,
+        0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x24,
+        0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x26,
+        0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26,
+        0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x27,
+        0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27,
+        0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x27,
+        0x00, 0x00, 0x00, 0x27, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28,
+        0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x28,
+        0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29,
+        0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29,
+        0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29,
+        0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a,
+        0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a,
+        0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b,
+        0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b,
+        0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c,
+        0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d,
+        0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e,
+        0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x2f,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f,
+        0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x30,
+        0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30,
+        0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x31,
+        0x00, 0x00, 0x00, 0x31, 0x00, 0x00, 0x00, 0x32, 0x00, 0x00, 0x00, 0x32,
+        0x00, 0x00, 0x00, 0x32, 0x00, 0x00, 0x00, 0x32, 0x00, 0x00, 0x00, 0x32,
+        0x00, 0x00, 0x00, 0x32, 0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33,
+        0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33,
+        0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x33, 0x00, 0x00, 0x00, 0x34,
+        0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x34,
+        0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x35,
+        0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35,
+        0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35,
+        0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35,
+        0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35,
+        0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35, 0x00, 0x00, 0x00, 0x35,
+        0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x02, 0x61, 0x54, 0x00, 0x00,
+        0x00, 0x67, 0x02, 0x00, 0x00, 0x02, 0x62, 0x54, 0x00, 0x00, 0x00, 0x67,
+        0x02, 0x00, 0x00, 0x02, 0x63, 0x54, 0x00, 0x00, 0x00, 0x67, 0x02, 0x00,
+        0x00, 0x02, 0x73, 0x11, 0x01, 0x00, 0x00, 0x67, 0x02, 0x00, 0x00, 0x02,
+        0x65, 0x11, 0x01, 0x00, 0x00, 0x67, 0x02, 0x00, 0x00, 0x04, 0x63, 0x61,
+        0x70, 0x11, 0x01, 0x00, 0x00, 0x67, 0x02, 0x00, 0x00, 0x06, 0x77, 0x6f,
+        0x72, 0x64, 0x73, 0x56, 0x01, 0x00, 0x00, 0x67, 0x02, 0x00, 0x00, 0x10,
+        0x28, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
+        0x6f, 0x72, 0x29, 0x5b, 0x01, 0x00, 0x00, 0x61, 0x01, 0x00, 0x00, 0x0c,
+        0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x29, 0x5b,
+        0x01, 0x00, 0x00, 0x61, 0x01, 0x00, 0x00, 0x0e, 0x28, 0x66, 0x6f, 0x72,
+        0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x29, 0x5b, 0x01, 0x00,
+        0x00, 0x61, 0x01, 0x00, 0x00, 0x02, 0x77, 0x5c, 0x01, 0x00, 0x00, 0x5f,
+        0x01, 0x00, 0x00, 0x03, 0x6b, 0x76, 0x6c, 0x01, 0x00, 0x00, 0x67, 0x02,
+        0x00, 0x00, 0x10, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65,
+        0x72, 0x61, 0x74, 0x6f, 0x72, 0x29, 0x70, 0x01, 0x00, 0x00, 0x74, 0x01,
+        0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74, 0x61, 0x74,
+        0x65, 0x29, 0x70, 0x01, 0x00, 0x00, 0x74, 0x01, 0x00, 0x00, 0x0e, 0x28,
+        0x66, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x29,
+        0x70, 0x01, 0x00, 0x00, 0x74, 0x01, 0x00, 0x00, 0x02, 0x6b, 0x71, 0x01,
+        0x00, 0x00, 0x72, 0x01, 0x00, 0x00, 0x02, 0x76, 0x71, 0x01, 0x00, 0x00,
+        0x72, 0x01, 0x00, 0x00, 0x02, 0x6e, 0x7f, 0x01, 0x00, 0x00, 0x67, 0x02,
+        0x00, 0x00, 0x10, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65,
+        0x72, 0x61, 0x74, 0x6f, 0x72, 0x29, 0x83, 0x01, 0x00, 0x00, 0x87, 0x01,
+        0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74, 0x61, 0x74,
+        0x65, 0x29, 0x83, 0x01, 0x00, 0x00, 0x87, 0x01, 0x00, 0x00, 0x0e, 0x28,
+        0x66, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x29,
+        0x83, 0x01, 0x00, 0x00, 0x87, 0x01, 0x00, 0x00, 0x02, 0x5f, 0x84, 0x01,
+        0x00, 0x00, 0x85, 0x01, 0x00, 0x00, 0x03, 0x6f, 0x6b, 0xeb, 0x01, 0x00,
+        0x00, 0x67, 0x02, 0x00, 0x00, 0x04, 0x65, 0x72, 0x72, 0xeb, 0x01, 0x00,
+        0x00, 0x67, 0x02, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45,
+        0x4e, 0x56,
+    ];
+
+    #[test]
+    fn test_string_lib() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        ls.requiref("_G", open_base, true);
+        ls.requiref("string", open_string, true);
+        ls.pop(2);
+
+        ls.load(LUA_STRING_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 3, 0);
+        assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+        assert_eq!(ls.to_string(1), " -2.2");
+        assert_eq!(ls.to_string(2), "0x1.999999999999ap-4");
+        assert_eq!(ls.to_integer(3), 298);
+    }
+}
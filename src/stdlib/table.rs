@@ -0,0 +1,690 @@
+use crate::api::consts::*;
+use crate::api::{LuaAPI, LuaAuxLib, RustFn};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/*
+    Operations that an object must define to mimic a table
+    (some functions only need some of them)
+*/
+const TAB_R: u8 = 1; // read
+const TAB_W: u8 = 2; // write
+const TAB_L: u8 = 4; // length
+const TAB_RW: u8 = TAB_R | TAB_W; // read/write
+
+const TAB_FUNCS: &[(&str, RustFn)] = &[
+    ("concat", tconcat),
+    ("insert", tinsert),
+    ("move", tmove),
+    ("pack", tpack),
+    ("remove", tremove),
+    ("sort", sort),
+    ("unpack", tunpack),
+];
+
+pub fn open_table(ls: &mut dyn LuaAPI) -> usize {
+    ls.new_lib(TAB_FUNCS);
+    1
+}
+
+// Check that `arg` either is a table or can behave like one (that is,
+// has a metatable with the required metamethods).
+fn check_tab(ls: &mut dyn LuaAPI, arg: isize, what: u8) {
+    if ls.type_id(arg) != LUA_TTABLE {
+        // is it not a table?
+        let mut n = 1; // number of elements to pop
+        if ls.get_metatable(arg) // must have metatable
+            && (what & TAB_R == 0 || check_field(ls, "__index", &mut n))
+            && (what & TAB_W == 0 || check_field(ls, "__newindex", &mut n))
+            && (what & TAB_L == 0 || check_field(ls, "__len", &mut n))
+        {
+            ls.pop(n); // pop metatable and tested metamethods
+        } else {
+            ls.check_type(arg, LUA_TTABLE); // force an error
+        }
+    }
+}
+
+fn check_field(ls: &mut dyn LuaAPI, key: &str, n: &mut usize) -> bool {
+    *n += 1;
+    ls.push_string(key.to_string());
+    ls.raw_get(-(*n as isize)) != LUA_TNIL
+}
+
+fn aux_getn(ls: &mut dyn LuaAPI, n: isize, w: u8) -> i64 {
+    check_tab(ls, n, w | TAB_L);
+    ls.len_l(n)
+}
+
+// table.insert (list, [pos,] value)
+// http://www.lua.org/manual/5.3/manual.html#pdf-table.insert
+fn tinsert(ls: &mut dyn LuaAPI) -> usize {
+    let e = aux_getn(ls, 1, TAB_RW).wrapping_add(1); // first empty element
+    let pos = match ls.get_top() {
+        2 => e, // called with only 2 arguments: insert new element at the end
+        3 => {
+            let pos = ls.check_integer(2); // 2nd argument is the position
+            // check whether 'pos' is in [1, e]
+            ls.arg_check((pos as u64).wrapping_sub(1) < e as u64, 2, "position out of bounds");
+            let mut i = e;
+            while i > pos {
+                // move up elements
+                ls.get_i(1, i - 1);
+                ls.set_i(1, i); // t[i] = t[i - 1]
+                i -= 1;
+            }
+            pos
+        }
+        _ => ls.error_l("wrong number of arguments to 'insert'"),
+    };
+    ls.set_i(1, pos); // t[pos] = v
+    0
+}
+
+// table.remove (list [, pos])
+// http://www.lua.org/manual/5.3/manual.html#pdf-table.remove
+fn tremove(ls: &mut dyn LuaAPI) -> usize {
+    let size = aux_getn(ls, 1, TAB_RW);
+    let mut pos = ls.opt_integer(2, size);
+    if pos != size {
+        // validate 'pos' if given
+        // check whether 'pos' is in [1, size + 1]
+        ls.arg_check((pos as u64).wrapping_sub(1) <= size as u64, 1, "position out of bounds");
+    }
+    ls.get_i(1, pos); // result = t[pos]
+    while pos < size {
+        ls.get_i(1, pos + 1);
+        ls.set_i(1, pos); // t[pos] = t[pos + 1]
+        pos += 1;
+    }
+    ls.push_nil();
+    ls.set_i(1, pos); // remove entry t[pos]
+    1
+}
+
+// table.move (a1, f, e, t [,a2])
+// http://www.lua.org/manual/5.3/manual.html#pdf-table.move
+fn tmove(ls: &mut dyn LuaAPI) -> usize {
+    let f = ls.check_integer(2);
+    let e = ls.check_integer(3);
+    let t = ls.check_integer(4);
+    let tt = if ls.is_none_or_nil(5) { 1 } else { 5 }; // destination table
+    check_tab(ls, 1, TAB_R);
+    check_tab(ls, tt, TAB_W);
+    if e >= f {
+        // otherwise, nothing to move
+        ls.arg_check(f > 0 || e < i64::MAX + f, 3, "too many elements to move");
+        let n = e - f + 1; // number of elements to move
+        ls.arg_check(t <= i64::MAX - n + 1, 4, "destination wrap around");
+        if t > e || t <= f || (tt != 1 && !ls.compare(1, tt, LUA_OPEQ)) {
+            for i in 0..n {
+                ls.get_i(1, f + i);
+                ls.set_i(tt, t + i);
+            }
+        } else {
+            for i in (0..n).rev() {
+                ls.get_i(1, f + i);
+                ls.set_i(tt, t + i);
+            }
+        }
+    }
+    ls.push_value(tt); // return destination table
+    1
+}
+
+fn add_field(ls: &mut dyn LuaAPI, b: &mut Vec<u8>, i: i64) {
+    ls.get_i(1, i);
+    if !ls.is_string(-1) {
+        let tname = ls.type_name(ls.type_id(-1)).to_string();
+        ls.error_l(&format!("invalid value ({}) at index {} in table for 'concat'", tname, i));
+    }
+    b.extend_from_slice(&ls.to_bytes(-1).unwrap());
+    ls.pop(1);
+}
+
+// table.concat (list [, sep [, i [, j]]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-table.concat
+fn tconcat(ls: &mut dyn LuaAPI) -> usize {
+    let last = aux_getn(ls, 1, TAB_R);
+    let sep = if ls.is_none_or_nil(2) { Vec::new() } else { ls.check_bytes(2) };
+    let mut i = ls.opt_integer(3, 1);
+    let last = ls.opt_integer(4, last);
+    let mut b = Vec::new();
+    while i < last {
+        add_field(ls, &mut b, i);
+        b.extend_from_slice(&sep);
+        ls.check_alloc(b.len());
+        i += 1;
+    }
+    if i == last {
+        // add last value (if interval was not empty)
+        add_field(ls, &mut b, i);
+    }
+    ls.push_bytes(b);
+    1
+}
+
+// table.pack (···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-table.pack
+fn tpack(ls: &mut dyn LuaAPI) -> usize {
+    let n = ls.get_top(); // number of elements to pack
+    ls.create_table(n as usize, 1); // create result table
+    ls.insert(1); // put it at index 1
+    for i in (1..=n).rev() {
+        // assign elements
+        ls.set_i(1, i as i64);
+    }
+    ls.push_integer(n as i64);
+    ls.set_field(1, "n"); // t.n = number of elements
+    1 // return table
+}
+
+// table.unpack (list [, i [, j]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-table.unpack
+fn tunpack(ls: &mut dyn LuaAPI) -> usize {
+    let mut i = ls.opt_integer(2, 1);
+    let e = if ls.is_none_or_nil(3) { ls.len_l(1) } else { ls.check_integer(3) };
+    if i > e {
+        return 0; // empty range
+    }
+    let n = (e as u64).wrapping_sub(i as u64); // number of elements minus 1 (avoid overflows)
+    if n >= i32::MAX as u64 || !ls.check_stack(n as usize + 1) {
+        ls.error_l("too many results to unpack");
+    }
+    while i < e {
+        // push arg[i..e - 1] (to avoid overflows)
+        ls.get_i(1, i);
+        i += 1;
+    }
+    ls.get_i(1, e); // push last element
+    n as usize + 1
+}
+
+/*
+    Quicksort (based on 'Algorithms in MODULA-3', Robert Sedgewick;
+    Addison-Wesley, 1993.), as done by ltablib.c
+*/
+
+// Partitions smaller than this use the middle element as pivot.
+const RANLIMIT: u64 = 100;
+
+// table.sort (list [, comp])
+// http://www.lua.org/manual/5.3/manual.html#pdf-table.sort
+fn sort(ls: &mut dyn LuaAPI) -> usize {
+    let n = aux_getn(ls, 1, TAB_RW);
+    if n > 1 {
+        // non-trivial interval?
+        ls.arg_check(n < i32::MAX as i64, 1, "array too big");
+        if !ls.is_none_or_nil(2) {
+            // is there a 2nd argument?
+            ls.check_type(2, LUA_TFUNCTION); // must be a function
+        }
+        ls.set_top(2); // make sure there are two arguments
+        aux_sort(ls, 1, n as u64, 0);
+    }
+    0
+}
+
+// Produce a "random" value to choose pivots in large partitions.
+fn randomize_pivot() -> u64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_secs() ^ d.subsec_nanos() as u64,
+        Err(_) => 0,
+    }
+}
+
+fn set2(ls: &mut dyn LuaAPI, i: u64, j: u64) {
+    ls.set_i(1, i as i64);
+    ls.set_i(1, j as i64);
+}
+
+// Return true iff value at stack index `a` is less than the value at
+// index `b` (according to the order of the sort).
+fn sort_comp(ls: &mut dyn LuaAPI, a: isize, b: isize) -> bool {
+    if ls.is_nil(2) {
+        // no function?
+        ls.compare(a, b, LUA_OPLT) // a < b
+    } else {
+        // function
+        ls.push_value(2); // push function
+        ls.push_value(a - 1); // -1 to compensate function
+        ls.push_value(b - 2); // -2 to compensate function and 'a'
+        ls.call(2, 1); // call function
+        let res = ls.to_boolean(-1); // get result
+        ls.pop(1); // pop result
+        res
+    }
+}
+
+/*
+    Does the partition: Pivot P is at the top of the stack.
+    precondition: a[lo] <= P == a[up-1] <= a[up],
+    so it only needs to do the partition from lo + 1 to up - 2.
+    Pos-condition: a[lo .. i - 1] <= a[i] == P <= a[i + 1 .. up]
+    returns 'i'.
+*/
+fn partition(ls: &mut dyn LuaAPI, lo: u64, up: u64) -> u64 {
+    let mut i = lo; // will be incremented before first use
+    let mut j = up - 1; // will be decremented before first use
+    // loop invariant: a[lo .. i] <= P <= a[j .. up], a[up - 1] == P
+    loop {
+        // next loop: repeat ++i while a[i] < P
+        loop {
+            i += 1;
+            ls.get_i(1, i as i64);
+            if !sort_comp(ls, -1, -2) {
+                break;
+            }
+            if i == up - 1 {
+                // a[i] < P  but a[up - 1] == P  ??
+                ls.error_l("invalid order function for sorting");
+            }
+            ls.pop(1); // remove a[i]
+        }
+        // after the loop, a[i] >= P and a[lo .. i - 1] < P
+        // next loop: repeat --j while P < a[j]
+        loop {
+            j = j.wrapping_sub(1);
+            ls.get_i(1, j as i64);
+            if !sort_comp(ls, -3, -1) {
+                break;
+            }
+            if j < i {
+                // j < i  but  a[j] > P ??
+                ls.error_l("invalid order function for sorting");
+            }
+            ls.pop(1); // remove a[j]
+        }
+        // after the loop, a[j] <= P and a[j + 1 .. up] >= P
+        if j < i {
+            // no elements to be exchanged?
+            ls.pop(1); // pop a[j]
+            // swap pivot (a[up - 1]) with a[i] to satisfy pos-condition
+            set2(ls, up - 1, i);
+            return i;
+        }
+        // otherwise, swap a[i] - a[j] to restore invariant and repeat
+        set2(ls, i, j);
+    }
+}
+
+// Choose an element in the middle (2nd-3th quarters) of [lo,up]
+// "randomized" by `rnd`.
+fn choose_pivot(lo: u64, up: u64, rnd: u64) -> u64 {
+    let r4 = (up - lo) / 4; // range/4
+    rnd % (r4 * 2) + (lo + r4)
+}
+
+// Quicksort algorithm (recursive function)
+fn aux_sort(ls: &mut dyn LuaAPI, mut lo: u64, mut up: u64, mut rnd: u64) {
+    while lo < up {
+        // loop for tail recursion
+        // sort elements 'lo', 'p', and 'up'
+        ls.get_i(1, lo as i64);
+        ls.get_i(1, up as i64);
+        if sort_comp(ls, -1, -2) {
+            // a[up] < a[lo]?
+            set2(ls, lo, up); // swap a[lo] - a[up]
+        } else {
+            ls.pop(2); // remove both values
+        }
+        if up - lo == 1 {
+            break; // only 2 elements, already sorted
+        }
+        let p = if up - lo < RANLIMIT || rnd == 0 {
+            (lo + up) / 2 // small interval or no randomize? use middle point
+        } else {
+            choose_pivot(lo, up, rnd)
+        };
+        ls.get_i(1, p as i64);
+        ls.get_i(1, lo as i64);
+        if sort_comp(ls, -2, -1) {
+            // a[p] < a[lo]?
+            set2(ls, p, lo); // swap a[p] - a[lo]
+        } else {
+            ls.pop(1); // remove second element
+            ls.get_i(1, up as i64);
+            if sort_comp(ls, -1, -2) {
+                // a[up] < a[p]?
+                set2(ls, p, up); // swap up - p
+            } else {
+                ls.pop(2); // clean stack
+            }
+        }
+        if up - lo == 2 {
+            break; // only 3 elements, already sorted
+        }
+        ls.get_i(1, p as i64); // get median (Pivot)
+        ls.push_value(-1); // push Pivot
+        ls.get_i(1, (up - 1) as i64); // push a[up - 1]
+        set2(ls, p, up - 1); // a[p] = a[up - 1]; a[up - 1] = a[p]
+        let p = partition(ls, lo, up);
+        let n;
+        // a[lo .. p - 1] <= a[p] == P <= a[p + 1 .. up]
+        if p - lo < up - p {
+            // lower interval is shorter?
+            aux_sort(ls, lo, p - 1, rnd); // call recursively for lower interval
+            n = p - lo; // size of smaller interval
+            lo = p + 1; // tail call for [p + 1 .. up] (upper interval)
+        } else {
+            aux_sort(ls, p + 1, up, rnd); // call recursively for upper interval
+            n = up - p; // size of smaller interval
+            up = p - 1; // tail call for [lo .. p - 1]  (lower interval)
+        }
+        if (up.wrapping_sub(lo)) / 128 > n {
+            // partition too imbalanced?
+            rnd = randomize_pivot(); // try a new randomization
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LuaState;
+    use crate::stdlib::base::open_base;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /* Lua source code:
+        local t = {1, 2, 3}
+        table.insert(t, 4)
+        table.insert(t, 1, 0)
+        local s1 = table.concat(t, ",")
+        local r = table.remove(t, 1)
+        local s2 = table.concat(t, "-", 2, 3)
+        local p = table.pack(10, nil, 30)
+        local a, b, c = table.unpack({7, 8, 9})
+        local m = table.move({1, 2, 3}, 1, 3, 2)
+        local s3 = table.concat(m, ",")
+        local big = {5, 3, 9, 1, 7, 2, 8, 6, 4, 0, 15, 11, 13, 12, 14, 10}
+        table.sort(big)
+        local s4 = table.concat(big, " ")
+        table.sort(big, function(x, y) return x > y end)
+        local s5 = table.concat(big, " ")
+        local words = {"pear", "apple", "fig"}
+        table.sort(words)
+        local s6 = table.concat(words, " ")
+        -- proxy table
+        local store = {}
+        local proxy = setmetatable({}, {
+          __index = function(_, k) return store[k] end,
+          __newindex = function(_, k, v) store[k] = v end,
+          __len = function() return #store end,
+        })
+        table.insert(proxy, "c")
+        table.insert(proxy, "a")
+        table.insert(proxy, "b")
+        table.sort(proxy)
+        local s7 = table.concat(store, "")
+        local ok1, e1 = pcall(table.sort, {3, 2, 1, 4, 5, 6}, function(x, y) return true end)
+        local ok2, e2 = pcall(table.insert, {1}, 5, 2)
+        local ok3, e3 = pcall(table.insert, {1}, 1, 2, 3)
+        local ok4, e4 = pcall(table.concat, {1, {}, 3})
+        return s1, r, s2, p.n, p[3], a + b + c, s3, s4, s5, s6, s7, e1, e2, e3, e4
+    */
+    const LUA_TABLE_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x07, 0x40,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x29, 0xd0, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x80, 0x01,
+        0x41, 0x00, 0x00, 0x00, 0x81, 0x40, 0x00, 0x00, 0xc1, 0x80, 0x00, 0x00,
+        0x2b, 0x40, 0x80, 0x01, 0x46, 0xc0, 0x40, 0x00, 0x47, 0x00, 0xc1, 0x00,
+        0x80, 0x00, 0x00, 0x00, 0xc1, 0x40, 0x01, 0x00, 0x64, 0x40, 0x80, 0x01,
+        0x46, 0xc0, 0x40, 0x00, 0x47, 0x00, 0xc1, 0x00, 0x80, 0x00, 0x00, 0x00,
+        0xc1, 0x00, 0x00, 0x00, 0x01, 0x81, 0x01, 0x00, 0x64, 0x40, 0x00, 0x02,
+        0x46, 0xc0, 0x40, 0x00, 0x47, 0xc0, 0xc1, 0x00, 0x80, 0x00, 0x00, 0x00,
+        0xc1, 0x00, 0x02, 0x00, 0x64, 0x80, 0x80, 0x01, 0x86, 0xc0, 0x40, 0x00,
+        0x87, 0x40, 0x42, 0x01, 0xc0, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00,
+        0xa4, 0x80, 0x80, 0x01, 0xc6, 0xc0, 0x40, 0x00, 0xc7, 0xc0, 0xc1, 0x01,
+        0x00, 0x01, 0x00, 0x00, 0x41, 0x81, 0x02, 0x00, 0x81, 0x41, 0x00, 0x00,
+        0xc1, 0x81, 0x00, 0x00, 0xe4, 0x80, 0x80, 0x02, 0x06, 0xc1, 0x40, 0x00,
+        0x07, 0xc1, 0x42, 0x02, 0x41, 0x01, 0x03, 0x00, 0x84, 0x01, 0x00, 0x00,
+        0xc1, 0x41, 0x03, 0x00, 0x24, 0x81, 0x00, 0x02, 0x46, 0xc1, 0x40, 0x00,
+        0x47, 0x81, 0xc3, 0x02, 0x8b, 0x01, 0x80, 0x01, 0xc1, 0xc1, 0x03, 0x00,
+        0x01, 0x02, 0x04, 0x00, 0x41, 0x42, 0x04, 0x00, 0xab, 0x41, 0x80, 0x01,
+        0x64, 0x01, 0x01, 0x01, 0x06, 0xc2, 0x40, 0x00, 0x07, 0x82, 0x44, 0x04,
+        0x4b, 0x02, 0x80, 0x01, 0x81, 0x02, 0x00, 0x00, 0xc1, 0x42, 0x00, 0x00,
+        0x01, 0x83, 0x00, 0x00, 0x6b, 0x42, 0x80, 0x01, 0x81, 0x02, 0x00, 0x00,
+        0xc1, 0x82, 0x00, 0x00, 0x01, 0x43, 0x00, 0x00, 0x24, 0x82, 0x80, 0x02,
+        0x46, 0xc2, 0x40, 0x00, 0x47, 0xc2, 0xc1, 0x04, 0x80, 0x02, 0x00, 0x04,
+        0xc1, 0x02, 0x02, 0x00, 0x64, 0x82, 0x80, 0x01, 0x8b, 0x02, 0x00, 0x08,
+        0xc1, 0xc2, 0x04, 0x00, 0x01, 0x83, 0x00, 0x00, 0x41, 0x43, 0x04, 0x00,
+        0x81, 0x03, 0x00, 0x00, 0xc1, 0xc3, 0x03, 0x00, 0x01, 0x44, 0x00, 0x00,
+        0x41, 0x04, 0x04, 0x00, 0x81, 0x04, 0x05, 0x00, 0xc1, 0x44, 0x01, 0x00,
+        0x01, 0x85, 0x01, 0x00, 0x41, 0x45, 0x05, 0x00, 0x81, 0x85, 0x05, 0x00,
+        0xc1, 0xc5, 0x05, 0x00, 0x01, 0x06, 0x06, 0x00, 0x41, 0x46, 0x06, 0x00,
+        0x81, 0x06, 0x03, 0x00, 0xab, 0x42, 0x00, 0x08, 0xc6, 0xc2, 0x40, 0x00,
+        0xc7, 0x82, 0xc6, 0x05, 0x00, 0x03, 0x00, 0x05, 0xe4, 0x42, 0x00, 0x01,
+        0xc6, 0xc2, 0x40, 0x00, 0xc7, 0xc2, 0xc1, 0x05, 0x00, 0x03, 0x00, 0x05,
+        0x41, 0xc3, 0x06, 0x00, 0xe4, 0x82, 0x80, 0x01, 0x06, 0xc3, 0x40, 0x00,
+        0x07, 0x83, 0x46, 0x06, 0x40, 0x03, 0x00, 0x05, 0xac, 0x03, 0x00, 0x00,
+        0x24, 0x43, 0x80, 0x01, 0x06, 0xc3, 0x40, 0x00, 0x07, 0xc3, 0x41, 0x06,
+        0x40, 0x03, 0x00, 0x05, 0x81, 0xc3, 0x06, 0x00, 0x24, 0x83, 0x80, 0x01,
+        0x4b, 0x03, 0x80, 0x01, 0x81, 0x03, 0x07, 0x00, 0xc1, 0x43, 0x07, 0x00,
+        0x01, 0x84, 0x07, 0x00, 0x6b, 0x43, 0x80, 0x01, 0x86, 0xc3, 0x40, 0x00,
+        0x87, 0x83, 0x46, 0x07, 0xc0, 0x03, 0x80, 0x06, 0xa4, 0x43, 0x00, 0x01,
+        0x86, 0xc3, 0x40, 0x00, 0x87, 0xc3, 0x41, 0x07, 0xc0, 0x03, 0x80, 0x06,
+        0x01, 0xc4, 0x06, 0x00, 0xa4, 0x83, 0x80, 0x01, 0xcb, 0x03, 0x00, 0x00,
+        0x06, 0xc4, 0x47, 0x00, 0x4b, 0x04, 0x00, 0x00, 0x8b, 0xc4, 0x00, 0x00,
+        0xec, 0x44, 0x00, 0x00, 0x8a, 0xc4, 0x04, 0x90, 0xec, 0x84, 0x00, 0x00,
+        0x8a, 0xc4, 0x84, 0x90, 0xec, 0xc4, 0x00, 0x00, 0x8a, 0xc4, 0x04, 0x91,
+        0x24, 0x84, 0x80, 0x01, 0x46, 0xc4, 0x40, 0x00, 0x47, 0x04, 0xc1, 0x08,
+        0x80, 0x04, 0x00, 0x08, 0xc1, 0xc4, 0x08, 0x00, 0x64, 0x44, 0x80, 0x01,
+        0x46, 0xc4, 0x40, 0x00, 0x47, 0x04, 0xc1, 0x08, 0x80, 0x04, 0x00, 0x08,
+        0xc1, 0x04, 0x09, 0x00, 0x64, 0x44, 0x80, 0x01, 0x46, 0xc4, 0x40, 0x00,
+        0x47, 0x04, 0xc1, 0x08, 0x80, 0x04, 0x00, 0x08, 0xc1, 0x44, 0x09, 0x00,
+        0x64, 0x44, 0x80, 0x01, 0x46, 0xc4, 0x40, 0x00, 0x47, 0x84, 0xc6, 0x08,
+        0x80, 0x04, 0x00, 0x08, 0x64, 0x44, 0x00, 0x01, 0x46, 0xc4, 0x40, 0x00,
+        0x47, 0xc4, 0xc1, 0x08, 0x80, 0x04, 0x80, 0x07, 0xc1, 0x84, 0x09, 0x00,
+        0x64, 0x84, 0x80, 0x01, 0x86, 0xc4, 0x49, 0x00, 0xc6, 0xc4, 0x40, 0x00,
+        0xc7, 0x84, 0xc6, 0x09, 0x0b, 0x05, 0x00, 0x03, 0x41, 0x85, 0x00, 0x00,
+        0x81, 0x45, 0x00, 0x00, 0xc1, 0x05, 0x00, 0x00, 0x01, 0x46, 0x01, 0x00,
+        0x41, 0xc6, 0x04, 0x00, 0x81, 0x06, 0x05, 0x00, 0x2b, 0x45, 0x00, 0x03,
+        0x6c, 0x05, 0x01, 0x00, 0xa4, 0xc4, 0x00, 0x02, 0x06, 0xc5, 0x49, 0x00,
+        0x46, 0xc5, 0x40, 0x00, 0x47, 0x05, 0xc1, 0x0a, 0x8b, 0x05, 0x80, 0x00,
+        0xc1, 0x05, 0x00, 0x00, 0xab, 0x45, 0x80, 0x00, 0xc1, 0xc5, 0x04, 0x00,
+        0x01, 0x46, 0x00, 0x00, 0x24, 0xc5, 0x80, 0x02, 0x86, 0xc5, 0x49, 0x00,
+        0xc6, 0xc5, 0x40, 0x00, 0xc7, 0x05, 0xc1, 0x0b, 0x0b, 0x06, 0x80, 0x00,
+        0x41, 0x06, 0x00, 0x00, 0x2b, 0x46, 0x80, 0x00, 0x41, 0x06, 0x00, 0x00,
+        0x81, 0x46, 0x00, 0x00, 0xc1, 0x86, 0x00, 0x00, 0xa4, 0xc5, 0x00, 0x03,
+        0x06, 0xc6, 0x49, 0x00, 0x46, 0xc6, 0x40, 0x00, 0x47, 0xc6, 0xc1, 0x0c,
+        0x8b, 0x06, 0x80, 0x01, 0xc1, 0x06, 0x00, 0x00, 0x0b, 0x07, 0x00, 0x00,
+        0x41, 0x87, 0x00, 0x00, 0xab, 0x46, 0x80, 0x01, 0x24, 0xc6, 0x80, 0x01,
+        0x80, 0x06, 0x80, 0x00, 0xc0, 0x06, 0x00, 0x01, 0x00, 0x07, 0x80, 0x01,
+        0x47, 0x07, 0x4a, 0x02, 0x87, 0x87, 0x40, 0x02, 0xcd, 0x87, 0x81, 0x02,
+        0xcd, 0xc7, 0x81, 0x0f, 0x00, 0x08, 0x80, 0x04, 0x40, 0x08, 0x80, 0x05,
+        0x80, 0x08, 0x00, 0x06, 0xc0, 0x08, 0x00, 0x07, 0x00, 0x09, 0x80, 0x08,
+        0x40, 0x09, 0x80, 0x09, 0x80, 0x09, 0x80, 0x0a, 0xc0, 0x09, 0x80, 0x0b,
+        0x00, 0x0a, 0x80, 0x0c, 0xa6, 0x06, 0x00, 0x08, 0x26, 0x00, 0x80, 0x00,
+        0x29, 0x00, 0x00, 0x00, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x13, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x03,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x06, 0x74, 0x61, 0x62,
+        0x6c, 0x65, 0x04, 0x07, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x13, 0x04,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x07, 0x63, 0x6f, 0x6e, 0x63, 0x61, 0x74,
+        0x04, 0x02, 0x2c, 0x04, 0x07, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x04,
+        0x02, 0x2d, 0x04, 0x05, 0x70, 0x61, 0x63, 0x6b, 0x13, 0x0a, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x04, 0x07, 0x75, 0x6e, 0x70, 0x61, 0x63, 0x6b, 0x13, 0x07,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x08, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x13, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x05, 0x6d, 0x6f, 0x76, 0x65, 0x13, 0x05, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x13, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x13, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x0b,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x0d, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x13, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x13, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x05,
+        0x73, 0x6f, 0x72, 0x74, 0x04, 0x02, 0x20, 0x04, 0x05, 0x70, 0x65, 0x61,
+        0x72, 0x04, 0x06, 0x61, 0x70, 0x70, 0x6c, 0x65, 0x04, 0x04, 0x66, 0x69,
+        0x67, 0x04, 0x0d, 0x73, 0x65, 0x74, 0x6d, 0x65, 0x74, 0x61, 0x74, 0x61,
+        0x62, 0x6c, 0x65, 0x04, 0x08, 0x5f, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78,
+        0x04, 0x0b, 0x5f, 0x5f, 0x6e, 0x65, 0x77, 0x69, 0x6e, 0x64, 0x65, 0x78,
+        0x04, 0x06, 0x5f, 0x5f, 0x6c, 0x65, 0x6e, 0x04, 0x02, 0x63, 0x04, 0x02,
+        0x61, 0x04, 0x02, 0x62, 0x04, 0x01, 0x04, 0x06, 0x70, 0x63, 0x61, 0x6c,
+        0x6c, 0x04, 0x02, 0x6e, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x03, 0x06, 0x00, 0x00, 0x00, 0x60, 0x00, 0x80, 0x00, 0x1e, 0x00,
+        0x00, 0x80, 0x83, 0x40, 0x00, 0x00, 0x83, 0x00, 0x80, 0x00, 0xa6, 0x00,
+        0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x78, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x02, 0x79, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x03, 0x03, 0x00, 0x00, 0x00, 0x86, 0x40, 0x00, 0x00, 0xa6, 0x00,
+        0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x5f, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x02, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x06, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x00, 0x17,
+        0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x03, 0x00, 0x03, 0x02, 0x00,
+        0x00, 0x00, 0x08, 0x80, 0x80, 0x00, 0x26, 0x00, 0x80, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x0f, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x02, 0x5f, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x76, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x06, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x00, 0x18, 0x00, 0x00,
+        0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x04, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x26, 0x00, 0x00, 0x01,
+        0x26, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x18, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x06, 0x73,
+        0x74, 0x6f, 0x72, 0x65, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x03, 0x03, 0x00, 0x00, 0x00, 0x83, 0x00, 0x80, 0x00,
+        0xa6, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x78, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x02, 0x79, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+        0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00,
+        0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00,
+        0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00,
+        0x0e, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00,
+        0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00,
+        0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00,
+        0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00,
+        0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+        0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00,
+        0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00,
+        0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00,
+        0x17, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00,
+        0x15, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00,
+        0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00,
+        0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00,
+        0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00,
+        0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00,
+        0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00,
+        0x1e, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00,
+        0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00,
+        0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00,
+        0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00,
+        0x1f, 0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+        0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+        0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+        0x20, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00,
+        0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00,
+        0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00,
+        0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00,
+        0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00,
+        0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00,
+        0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00,
+        0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00,
+        0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00,
+        0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00,
+        0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00,
+        0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00,
+        0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00,
+        0x1a, 0x00, 0x00, 0x00, 0x02, 0x74, 0x05, 0x00, 0x00, 0x00, 0xd0, 0x00,
+        0x00, 0x00, 0x03, 0x73, 0x31, 0x15, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00,
+        0x00, 0x02, 0x72, 0x1a, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x03,
+        0x73, 0x32, 0x21, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x02, 0x70,
+        0x27, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x02, 0x61, 0x2f, 0x00,
+        0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x02, 0x62, 0x2f, 0x00, 0x00, 0x00,
+        0xd0, 0x00, 0x00, 0x00, 0x02, 0x63, 0x2f, 0x00, 0x00, 0x00, 0xd0, 0x00,
+        0x00, 0x00, 0x02, 0x6d, 0x3a, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00,
+        0x03, 0x73, 0x33, 0x3f, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x04,
+        0x62, 0x69, 0x67, 0x51, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x03,
+        0x73, 0x34, 0x5a, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x03, 0x73,
+        0x35, 0x64, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x06, 0x77, 0x6f,
+        0x72, 0x64, 0x73, 0x69, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x03,
+        0x73, 0x36, 0x72, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x06, 0x73,
+        0x74, 0x6f, 0x72, 0x65, 0x73, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00,
+        0x06, 0x70, 0x72, 0x6f, 0x78, 0x79, 0x7d, 0x00, 0x00, 0x00, 0xd0, 0x00,
+        0x00, 0x00, 0x03, 0x73, 0x37, 0x95, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00,
+        0x00, 0x04, 0x6f, 0x6b, 0x31, 0xa2, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00,
+        0x00, 0x03, 0x65, 0x31, 0xa2, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00,
+        0x04, 0x6f, 0x6b, 0x32, 0xab, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00,
+        0x03, 0x65, 0x32, 0xab, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x04,
+        0x6f, 0x6b, 0x33, 0xb5, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x03,
+        0x65, 0x33, 0xb5, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x04, 0x6f,
+        0x6b, 0x34, 0xbe, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x03, 0x65,
+        0x34, 0xbe, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
+
+    #[test]
+    fn test_table_lib() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        ls.requiref("_G", open_base, true);
+        ls.requiref("table", open_table, true);
+        ls.pop(2);
+
+        ls.load(LUA_TABLE_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 15, 0);
+        assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+        assert_eq!(ls.to_string(1), "0,1,2,3,4");
+        assert_eq!(ls.to_integer(2), 0);
+        assert_eq!(ls.to_string(3), "2-3");
+        assert_eq!(ls.to_integer(4), 3);
+        assert_eq!(ls.to_integer(5), 30);
+        assert_eq!(ls.to_integer(6), 24);
+        assert_eq!(ls.to_string(7), "1,1,2,3");
+        assert_eq!(ls.to_string(8), "0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15");
+        assert_eq!(ls.to_string(9), "15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0");
+        assert_eq!(ls.to_string(10), "apple fig pear");
+        assert_eq!(ls.to_string(11), "abc");
+        assert_eq!(ls.to_string(12), "invalid order function for sorting");
+        assert_eq!(ls.to_string(13), "bad argument #2 to 'table.insert' (position out of bounds)");
+        assert_eq!(ls.to_string(14), "wrong number of arguments to 'insert'");
+        assert_eq!(ls.to_string(15), "invalid value (table) at index 2 in table for 'concat'");
+    }
+}
@@ -0,0 +1,676 @@
+use super::string::posrelat;
+use crate::api::{LuaAPI, LuaAuxLib, RustFn};
+
+// Largest value accepted by the lax decoder (up to 6-byte sequences)
+const MAXUTF: u32 = 0x7FFF_FFFF;
+
+// pattern to match a single UTF-8 character
+const UTF8PATT: &[u8] = b"[\x00-\x7F\xC2-\xFD][\x80-\xBF]*";
+
+const UTF8_FUNCS: &[(&str, RustFn)] = &[
+    ("offset", byte_offset),
+    ("codepoint", codepoint),
+    ("char", utf_char),
+    ("len", utf_len),
+    ("codes", iter_codes),
+];
+
+pub fn open_utf8(ls: &mut dyn LuaAPI) -> usize {
+    ls.new_lib(UTF8_FUNCS);
+    ls.push_bytes(UTF8PATT.to_vec());
+    ls.set_field(-2, "charpattern");
+    1
+}
+
+// Byte at `i`, where positions past the end read as the C string's final '\0'.
+fn byte_at(s: &[u8], i: usize) -> u8 {
+    s.get(i).copied().unwrap_or(0)
+}
+
+fn iscont(s: &[u8], i: usize) -> bool {
+    byte_at(s, i) & 0xC0 == 0x80
+}
+
+/*
+    Decode one UTF-8 sequence starting at `i`, returning the position just
+    after it and its code, or None if the sequence is invalid. Sequences
+    may be up to 6 bytes long, but overlong encodings are rejected.
+*/
+fn utf8_decode(s: &[u8], i: usize) -> Option<(usize, u32)> {
+    const LIMITS: [u32; 6] = [!0, 0x80, 0x800, 0x1_0000, 0x20_0000, 0x400_0000];
+    let mut c = byte_at(s, i) as u32;
+    let mut res: u32 = 0; // final result
+    if c < 0x80 {
+        // ascii?
+        res = c;
+    } else {
+        let mut count = 0; // to count number of continuation bytes
+        while c & 0x40 != 0 {
+            // while it needs continuation bytes...
+            count += 1;
+            let cc = byte_at(s, i + count) as u32; // read next byte
+            if cc & 0xC0 != 0x80 {
+                // not a continuation byte?
+                return None; // invalid byte sequence
+            }
+            res = (res << 6) | (cc & 0x3F); // add lower 6 bits from cont. byte
+            c <<= 1; // to test next bit
+        }
+        if count > 5 {
+            return None; // too many continuation bytes
+        }
+        res |= (c & 0x7F) << (count * 5); // add first byte
+        if res > MAXUTF || res < LIMITS[count] {
+            return None; // invalid byte sequence
+        }
+        return Some((i + count + 1, res));
+    }
+    Some((i + 1, res))
+}
+
+// Encode `x` (at most MAXUTF) as a UTF-8 byte sequence.
+fn utf8_esc(mut x: u32) -> Vec<u8> {
+    if x < 0x80 {
+        // ascii?
+        return vec![x as u8];
+    }
+    let mut buff = Vec::with_capacity(6); // filled backwards
+    let mut mfb = 0x3f; // maximum that fits in first byte
+    loop {
+        // add continuation bytes
+        buff.push(0x80 | (x & 0x3f) as u8);
+        x >>= 6; // remove added bits
+        mfb >>= 1; // now there is one less bit available in first byte
+        if x <= mfb {
+            break; // fits in first byte?
+        }
+    }
+    buff.push(((!mfb << 1) | x) as u8); // add first byte
+    buff.reverse();
+    buff
+}
+
+// utf8.len (s [, i [, j]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-utf8.len
+// Returns the number of UTF-8 characters that start between positions
+// `i` and `j` (both inclusive), or nil plus the position of the first
+// invalid byte.
+fn utf_len(ls: &mut dyn LuaAPI) -> usize {
+    let s = ls.check_shared_bytes(1);
+    let len = s.len() as i64;
+    let mut posi = posrelat(ls.opt_integer(2, 1), s.len());
+    let mut posj = posrelat(ls.opt_integer(3, -1), s.len());
+    ls.arg_check(1 <= posi && posi - 1 <= len, 2, "initial position out of string");
+    posi -= 1;
+    posj -= 1;
+    ls.arg_check(posj < len, 3, "final position out of string");
+    let mut n = 0;
+    while posi <= posj {
+        match utf8_decode(&s, posi as usize) {
+            Some((next, _)) => posi = next as i64,
+            None => {
+                // conversion error?
+                ls.push_nil(); // return nil ...
+                ls.push_integer(posi + 1); // ... and current position
+                return 2;
+            }
+        }
+        n += 1;
+    }
+    ls.push_integer(n);
+    1
+}
+
+// utf8.codepoint (s [, i [, j]])
+// http://www.lua.org/manual/5.3/manual.html#pdf-utf8.codepoint
+fn codepoint(ls: &mut dyn LuaAPI) -> usize {
+    let s = ls.check_shared_bytes(1);
+    let posi = posrelat(ls.opt_integer(2, 1), s.len());
+    let pose = posrelat(ls.opt_integer(3, posi), s.len());
+    ls.arg_check(posi >= 1, 2, "out of range");
+    ls.arg_check(pose <= s.len() as i64, 3, "out of range");
+    if posi > pose {
+        return 0; // empty interval; return no values
+    }
+    let n = (pose - posi) as usize + 1;
+    if pose - posi >= i32::MAX as i64 || !ls.check_stack(n) {
+        // (lua_Integer -> int) overflow?
+        ls.error_l("string slice too long");
+    }
+    let mut n = 0;
+    let se = pose as usize;
+    let mut i = posi as usize - 1;
+    while i < se {
+        match utf8_decode(&s, i) {
+            Some((next, code)) => {
+                ls.push_integer(code as i64);
+                i = next;
+            }
+            None => ls.error_l("invalid UTF-8 code"),
+        }
+        n += 1;
+    }
+    n
+}
+
+fn check_utf_char(ls: &mut dyn LuaAPI, arg: isize) -> Vec<u8> {
+    let code = ls.check_integer(arg);
+    ls.arg_check(0 <= code && code <= MAXUTF as i64, arg, "value out of range");
+    utf8_esc(code as u32)
+}
+
+// utf8.char (···)
+// http://www.lua.org/manual/5.3/manual.html#pdf-utf8.char
+fn utf_char(ls: &mut dyn LuaAPI) -> usize {
+    let n = ls.get_top(); // number of arguments
+    let mut b = Vec::new();
+    for i in 1..=n {
+        b.extend(check_utf_char(ls, i));
+    }
+    ls.push_bytes(b);
+    1
+}
+
+// utf8.offset (s, n [, i])
+// http://www.lua.org/manual/5.3/manual.html#pdf-utf8.offset
+// offset(s, n, i) -> index where n-th character counting from position
+// `i` starts; 0 means character at `i`.
+fn byte_offset(ls: &mut dyn LuaAPI) -> usize {
+    let s = ls.check_shared_bytes(1);
+    let len = s.len() as i64;
+    let mut n = ls.check_integer(2);
+    let posi = if n >= 0 { 1 } else { len + 1 };
+    let mut posi = posrelat(ls.opt_integer(3, posi), s.len());
+    ls.arg_check(1 <= posi && posi - 1 <= len, 3, "position out of range");
+    posi -= 1;
+    if n == 0 {
+        // find beginning of current byte sequence
+        while posi > 0 && iscont(&s, posi as usize) {
+            posi -= 1;
+        }
+    } else {
+        if iscont(&s, posi as usize) {
+            ls.error_l("initial position is a continuation byte");
+        }
+        if n < 0 {
+            while n < 0 && posi > 0 {
+                // move back
+                loop {
+                    // find beginning of previous character
+                    posi -= 1;
+                    if !(posi > 0 && iscont(&s, posi as usize)) {
+                        break;
+                    }
+                }
+                n += 1;
+            }
+        } else {
+            n -= 1; // do not move for 1st character
+            while n > 0 && posi < len {
+                loop {
+                    // find beginning of next character
+                    posi += 1;
+                    if !iscont(&s, posi as usize) {
+                        break; // (cannot pass final '\0')
+                    }
+                }
+                n -= 1;
+            }
+        }
+    }
+    if n == 0 {
+        // did it find given character?
+        ls.push_integer(posi + 1);
+    } else {
+        // no such character
+        ls.push_nil();
+    }
+    1
+}
+
+fn iter_aux(ls: &mut dyn LuaAPI) -> usize {
+    let s = ls.check_shared_bytes(1);
+    let len = s.len() as i64;
+    let mut n = ls.to_integer(2) - 1;
+    if n < 0 {
+        // first iteration?
+        n = 0; // start from here
+    } else if n < len {
+        n += 1; // skip current byte
+        while iscont(&s, n as usize) {
+            n += 1; // and its continuations
+        }
+    }
+    if n >= len {
+        return 0; // no more codepoints
+    }
+    match utf8_decode(&s, n as usize) {
+        Some((next, code)) if !iscont(&s, next) => {
+            ls.push_integer(n + 1);
+            ls.push_integer(code as i64);
+            2
+        }
+        _ => ls.error_l("invalid UTF-8 code"),
+    }
+}
+
+// utf8.codes (s)
+// http://www.lua.org/manual/5.3/manual.html#pdf-utf8.codes
+fn iter_codes(ls: &mut dyn LuaAPI) -> usize {
+    ls.check_shared_bytes(1);
+    ls.push_rust_fn(iter_aux);
+    ls.push_value(1);
+    ls.push_integer(0);
+    3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::consts::*;
+    use crate::state::LuaState;
+    use crate::stdlib::base::open_base;
+    use crate::stdlib::string::open_string;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /* Lua source code:
+        local s = "h\u{E9}llo, \u{4E16}\u{754C}!"
+        assert(utf8.len(s) == 10 and #s == 15)
+        assert(utf8.len(s, 4) == 8 and utf8.len(s, 3) == nil and utf8.len(s, -4) == 2 and utf8.len("") == 0)
+        local n, pos = utf8.len("ab\xffcd")
+        assert(n == nil and pos == 3)
+        assert(select(2, utf8.len("\xC0\x80")) == 1)
+        assert(select(2, pcall(utf8.len, "abc", 5)) == "bad argument #2 to 'utf8.len' (initial position out of string)")
+        assert(utf8.char(72, 0xE9, 0x4E16) == "H\u{E9}\u{4E16}" and utf8.char() == "")
+        assert(select(2, pcall(utf8.char, -1)) == "bad argument #1 to 'utf8.char' (value out of range)")
+        local a, b, c = utf8.codepoint(s, 1, 3)
+        assert(a == 104 and b == 0xE9 and c == nil)
+        assert(utf8.codepoint(s, -4) == 0x754C)
+        assert(select(2, pcall(utf8.codepoint, "\xff")) == "invalid UTF-8 code")
+        assert(select(2, pcall(utf8.codepoint, "abc", 4)) == "bad argument #3 to 'utf8.codepoint' (out of range)")
+        assert(utf8.offset(s, 3) == 4 and utf8.offset(s, -1) == 15 and utf8.offset(s, -3) == 9)
+        assert(utf8.offset(s, 0, 3) == 2 and utf8.offset(s, 11) == 16 and utf8.offset(s, 12) == nil)
+        assert(select(2, pcall(utf8.offset, "\x80", 1)) == "initial position is a continuation byte")
+        local t = {}
+        for p, c in utf8.codes(s) do t[#t + 1] = p .. ":" .. c end
+        assert(#t == 10 and t[2] == "2:233" and t[9] == "12:30028")
+        assert(not pcall(function() for _ in utf8.codes("a\xffb") do end end))
+        local chars = 0
+        for _ in string.gmatch(s, utf8.charpattern) do chars = chars + 1 end
+        assert(chars == 10)
+        -- lax decoding: surrogates and sequences up to 6 bytes
+        assert(utf8.len("\xED\xA0\x80") == 1 and utf8.codepoint("\xED\xA0\x80") == 0xD800)
+        assert(utf8.char(0x7FFFFFFF) == "\xFD\xBF\xBF\xBF\xBF\xBF" and utf8.codepoint("\xFD\xBF\xBF\xBF\xBF\xBF") == 0x7FFFFFFF)
+        assert(utf8.char(0x110000) == "\xF4\x90\x80\x80" and utf8.len("\xF8\x88\x80\x80\x80") == 1)
+        assert(select(2, utf8.len("\xF8\x80\x80\x80\x80")) == 1 and select(2, utf8.len("\xFE\x80")) == 1)
+        assert(select(2, pcall(utf8.char, 0x80000000)) == "bad argument #1 to 'utf8.char' (value out of range)")
+    */
+    const LUA_UTF8_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x07, 0x40,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x10, 0x76, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x46, 0x40, 0x40, 0x00, 0x86, 0x80, 0x40, 0x00, 0x87, 0xc0, 0x40, 0x01,
+        0xc0, 0x00, 0x00, 0x00, 0xa4, 0x80, 0x00, 0x01, 0x1f, 0x00, 0x41, 0x01,
+        0x1e, 0x80, 0x00, 0x80, 0x9c, 0x00, 0x00, 0x00, 0x5f, 0x40, 0x41, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x40, 0x00, 0x00, 0x83, 0x00, 0x80, 0x00,
+        0x64, 0x40, 0x00, 0x01, 0x46, 0x40, 0x40, 0x00, 0x86, 0x80, 0x40, 0x00,
+        0x87, 0xc0, 0x40, 0x01, 0xc0, 0x00, 0x00, 0x00, 0x01, 0x81, 0x01, 0x00,
+        0xa4, 0x80, 0x80, 0x01, 0x1f, 0xc0, 0x41, 0x01, 0x1e, 0xc0, 0x04, 0x80,
+        0x86, 0x80, 0x40, 0x00, 0x87, 0xc0, 0x40, 0x01, 0xc0, 0x00, 0x00, 0x00,
+        0x01, 0x01, 0x02, 0x00, 0xa4, 0x80, 0x80, 0x01, 0x1f, 0x40, 0x42, 0x01,
+        0x1e, 0x00, 0x03, 0x80, 0x86, 0x80, 0x40, 0x00, 0x87, 0xc0, 0x40, 0x01,
+        0xc0, 0x00, 0x00, 0x00, 0x01, 0x81, 0x02, 0x00, 0xa4, 0x80, 0x80, 0x01,
+        0x1f, 0xc0, 0x42, 0x01, 0x1e, 0x40, 0x01, 0x80, 0x86, 0x80, 0x40, 0x00,
+        0x87, 0xc0, 0x40, 0x01, 0xc1, 0x00, 0x03, 0x00, 0xa4, 0x80, 0x00, 0x01,
+        0x5f, 0x40, 0x43, 0x01, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x40, 0x00, 0x00,
+        0x83, 0x00, 0x80, 0x00, 0x64, 0x40, 0x00, 0x01, 0x46, 0x80, 0x40, 0x00,
+        0x47, 0xc0, 0xc0, 0x00, 0x81, 0x80, 0x03, 0x00, 0x64, 0xc0, 0x00, 0x01,
+        0xc6, 0x40, 0x40, 0x00, 0x1f, 0x40, 0xc2, 0x00, 0x1e, 0x40, 0x00, 0x80,
+        0x5f, 0x00, 0x42, 0x01, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00,
+        0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x40, 0x40, 0x00,
+        0x06, 0xc1, 0x43, 0x00, 0x41, 0xc1, 0x02, 0x00, 0x86, 0x81, 0x40, 0x00,
+        0x87, 0xc1, 0x40, 0x03, 0xc1, 0x01, 0x04, 0x00, 0xa4, 0x01, 0x00, 0x01,
+        0x24, 0x81, 0x00, 0x00, 0x5f, 0x40, 0x44, 0x02, 0x1e, 0x00, 0x00, 0x80,
+        0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01,
+        0xc6, 0x40, 0x40, 0x00, 0x06, 0xc1, 0x43, 0x00, 0x41, 0xc1, 0x02, 0x00,
+        0x86, 0x81, 0x44, 0x00, 0xc6, 0x81, 0x40, 0x00, 0xc7, 0xc1, 0xc0, 0x03,
+        0x01, 0xc2, 0x04, 0x00, 0x41, 0x02, 0x05, 0x00, 0xa4, 0x01, 0x00, 0x02,
+        0x24, 0x81, 0x00, 0x00, 0x5f, 0x40, 0x45, 0x02, 0x1e, 0x00, 0x00, 0x80,
+        0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01,
+        0xc6, 0x40, 0x40, 0x00, 0x06, 0x81, 0x40, 0x00, 0x07, 0x81, 0x45, 0x02,
+        0x41, 0xc1, 0x05, 0x00, 0x81, 0x01, 0x06, 0x00, 0xc1, 0x41, 0x06, 0x00,
+        0x24, 0x81, 0x00, 0x02, 0x1f, 0x80, 0x46, 0x02, 0x1e, 0x00, 0x01, 0x80,
+        0x06, 0x81, 0x40, 0x00, 0x07, 0x81, 0x45, 0x02, 0x24, 0x81, 0x80, 0x00,
+        0x5f, 0x00, 0x43, 0x02, 0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00,
+        0x03, 0x01, 0x80, 0x00, 0xe4, 0x40, 0x00, 0x01, 0xc6, 0x40, 0x40, 0x00,
+        0x06, 0xc1, 0x43, 0x00, 0x41, 0xc1, 0x02, 0x00, 0x86, 0x81, 0x44, 0x00,
+        0xc6, 0x81, 0x40, 0x00, 0xc7, 0x81, 0xc5, 0x03, 0x01, 0xc2, 0x06, 0x00,
+        0xa4, 0x01, 0x80, 0x01, 0x24, 0x81, 0x00, 0x00, 0x5f, 0x00, 0x47, 0x02,
+        0x1e, 0x00, 0x00, 0x80, 0x03, 0x41, 0x00, 0x00, 0x03, 0x01, 0x80, 0x00,
+        0xe4, 0x40, 0x00, 0x01, 0xc6, 0x80, 0x40, 0x00, 0xc7, 0x40, 0xc7, 0x01,
+        0x00, 0x01, 0x00, 0x00, 0x41, 0x41, 0x04, 0x00, 0x81, 0x01, 0x02, 0x00,
+        0xe4, 0x00, 0x01, 0x02, 0x86, 0x41, 0x40, 0x00, 0x1f, 0x80, 0xc7, 0x01,
+        0x1e, 0xc0, 0x00, 0x80, 0x1f, 0x00, 0x46, 0x02, 0x1e, 0x40, 0x00, 0x80,
+        0x5f, 0x40, 0xc2, 0x02, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00,
+        0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01, 0x86, 0x41, 0x40, 0x00,
+        0xc6, 0x81, 0x40, 0x00, 0xc7, 0x41, 0xc7, 0x03, 0x00, 0x02, 0x00, 0x00,
+        0x41, 0x82, 0x02, 0x00, 0xe4, 0x81, 0x80, 0x01, 0x5f, 0xc0, 0xc7, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00,
+        0xa4, 0x41, 0x00, 0x01, 0x86, 0x41, 0x40, 0x00, 0xc6, 0xc1, 0x43, 0x00,
+        0x01, 0xc2, 0x02, 0x00, 0x46, 0x82, 0x44, 0x00, 0x86, 0x82, 0x40, 0x00,
+        0x87, 0x42, 0x47, 0x05, 0xc1, 0x02, 0x08, 0x00, 0x64, 0x02, 0x80, 0x01,
+        0xe4, 0x81, 0x00, 0x00, 0x5f, 0x40, 0xc8, 0x03, 0x1e, 0x00, 0x00, 0x80,
+        0xc3, 0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01,
+        0x86, 0x41, 0x40, 0x00, 0xc6, 0xc1, 0x43, 0x00, 0x01, 0xc2, 0x02, 0x00,
+        0x46, 0x82, 0x44, 0x00, 0x86, 0x82, 0x40, 0x00, 0x87, 0x42, 0x47, 0x05,
+        0xc1, 0xc2, 0x04, 0x00, 0x01, 0x83, 0x01, 0x00, 0x64, 0x02, 0x00, 0x02,
+        0xe4, 0x81, 0x00, 0x00, 0x5f, 0x80, 0xc8, 0x03, 0x1e, 0x00, 0x00, 0x80,
+        0xc3, 0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01,
+        0x86, 0x41, 0x40, 0x00, 0xc6, 0x81, 0x40, 0x00, 0xc7, 0xc1, 0xc8, 0x03,
+        0x00, 0x02, 0x00, 0x00, 0x41, 0x02, 0x02, 0x00, 0xe4, 0x81, 0x80, 0x01,
+        0x1f, 0x80, 0xc1, 0x03, 0x1e, 0x40, 0x03, 0x80, 0xc6, 0x81, 0x40, 0x00,
+        0xc7, 0xc1, 0xc8, 0x03, 0x00, 0x02, 0x00, 0x00, 0x41, 0xc2, 0x06, 0x00,
+        0xe4, 0x81, 0x80, 0x01, 0x1f, 0x40, 0xc1, 0x03, 0x1e, 0x80, 0x01, 0x80,
+        0xc6, 0x81, 0x40, 0x00, 0xc7, 0xc1, 0xc8, 0x03, 0x00, 0x02, 0x00, 0x00,
+        0x41, 0x02, 0x09, 0x00, 0xe4, 0x81, 0x80, 0x01, 0x5f, 0x40, 0xc9, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00,
+        0xa4, 0x41, 0x00, 0x01, 0x86, 0x41, 0x40, 0x00, 0xc6, 0x81, 0x40, 0x00,
+        0xc7, 0xc1, 0xc8, 0x03, 0x00, 0x02, 0x00, 0x00, 0x41, 0x42, 0x03, 0x00,
+        0x81, 0x02, 0x02, 0x00, 0xe4, 0x81, 0x00, 0x02, 0x1f, 0xc0, 0xc2, 0x03,
+        0x1e, 0x40, 0x03, 0x80, 0xc6, 0x81, 0x40, 0x00, 0xc7, 0xc1, 0xc8, 0x03,
+        0x00, 0x02, 0x00, 0x00, 0x41, 0x82, 0x09, 0x00, 0xe4, 0x81, 0x80, 0x01,
+        0x1f, 0xc0, 0xc9, 0x03, 0x1e, 0x80, 0x01, 0x80, 0xc6, 0x81, 0x40, 0x00,
+        0xc7, 0xc1, 0xc8, 0x03, 0x00, 0x02, 0x00, 0x00, 0x41, 0x02, 0x0a, 0x00,
+        0xe4, 0x81, 0x80, 0x01, 0x5f, 0x40, 0xc2, 0x03, 0x1e, 0x00, 0x00, 0x80,
+        0xc3, 0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01,
+        0x86, 0x41, 0x40, 0x00, 0xc6, 0xc1, 0x43, 0x00, 0x01, 0xc2, 0x02, 0x00,
+        0x46, 0x82, 0x44, 0x00, 0x86, 0x82, 0x40, 0x00, 0x87, 0xc2, 0x48, 0x05,
+        0xc1, 0x42, 0x0a, 0x00, 0x01, 0x43, 0x04, 0x00, 0x64, 0x02, 0x00, 0x02,
+        0xe4, 0x81, 0x00, 0x00, 0x5f, 0x80, 0xca, 0x03, 0x1e, 0x00, 0x00, 0x80,
+        0xc3, 0x41, 0x00, 0x00, 0xc3, 0x01, 0x80, 0x00, 0xa4, 0x41, 0x00, 0x01,
+        0x8b, 0x01, 0x00, 0x00, 0xc6, 0x81, 0x40, 0x00, 0xc7, 0xc1, 0xca, 0x03,
+        0x00, 0x02, 0x00, 0x00, 0xe4, 0x01, 0x01, 0x01, 0x1e, 0x80, 0x01, 0x80,
+        0x1c, 0x03, 0x00, 0x03, 0x0d, 0x43, 0x44, 0x06, 0x40, 0x03, 0x00, 0x05,
+        0x81, 0x03, 0x0b, 0x00, 0xc0, 0x03, 0x80, 0x05, 0x5d, 0xc3, 0x83, 0x06,
+        0x8a, 0x41, 0x03, 0x06, 0xe9, 0x81, 0x00, 0x00, 0x6a, 0x82, 0xfd, 0x7f,
+        0xc6, 0x41, 0x40, 0x00, 0x1c, 0x02, 0x00, 0x03, 0x1f, 0x00, 0x41, 0x04,
+        0x1e, 0x40, 0x01, 0x80, 0x07, 0xc2, 0x42, 0x03, 0x1f, 0x40, 0x4b, 0x04,
+        0x1e, 0x80, 0x00, 0x80, 0x07, 0x42, 0x49, 0x03, 0x5f, 0x80, 0x4b, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x03, 0x42, 0x00, 0x00, 0x03, 0x02, 0x80, 0x00,
+        0xe4, 0x41, 0x00, 0x01, 0xc6, 0x41, 0x40, 0x00, 0x06, 0x82, 0x44, 0x00,
+        0x6c, 0x02, 0x00, 0x00, 0x24, 0x82, 0x00, 0x01, 0x1b, 0x02, 0x00, 0x04,
+        0xe4, 0x41, 0x00, 0x01, 0xc1, 0x41, 0x03, 0x00, 0x06, 0xc2, 0x4b, 0x00,
+        0x07, 0x02, 0x4c, 0x04, 0x40, 0x02, 0x00, 0x00, 0x86, 0x42, 0x4c, 0x00,
+        0x87, 0x82, 0x4c, 0x05, 0x24, 0x02, 0x81, 0x01, 0x1e, 0x00, 0x00, 0x80,
+        0xcd, 0x41, 0xc4, 0x03, 0x29, 0x42, 0x00, 0x00, 0xaa, 0x02, 0xff, 0x7f,
+        0x06, 0x42, 0x40, 0x00, 0x5f, 0x00, 0xc1, 0x03, 0x1e, 0x00, 0x00, 0x80,
+        0x43, 0x42, 0x00, 0x00, 0x43, 0x02, 0x80, 0x00, 0x24, 0x42, 0x00, 0x01,
+        0x06, 0x42, 0x40, 0x00, 0x46, 0x42, 0x4c, 0x00, 0x47, 0xc2, 0xc0, 0x04,
+        0x81, 0xc2, 0x0c, 0x00, 0x64, 0x82, 0x00, 0x01, 0x1f, 0x40, 0xc4, 0x04,
+        0x1e, 0x40, 0x01, 0x80, 0x46, 0x42, 0x4c, 0x00, 0x47, 0x42, 0xc7, 0x04,
+        0x81, 0xc2, 0x0c, 0x00, 0x64, 0x82, 0x00, 0x01, 0x5f, 0x00, 0xcd, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x42, 0x00, 0x00, 0x43, 0x02, 0x80, 0x00,
+        0x24, 0x42, 0x00, 0x01, 0x06, 0x42, 0x40, 0x00, 0x46, 0x42, 0x4c, 0x00,
+        0x47, 0x82, 0xc5, 0x04, 0x81, 0x42, 0x0d, 0x00, 0x64, 0x82, 0x00, 0x01,
+        0x1f, 0x80, 0xcd, 0x04, 0x1e, 0x40, 0x01, 0x80, 0x46, 0x42, 0x4c, 0x00,
+        0x47, 0x42, 0xc7, 0x04, 0x81, 0x82, 0x0d, 0x00, 0x64, 0x82, 0x00, 0x01,
+        0x5f, 0x40, 0xcd, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x42, 0x00, 0x00,
+        0x43, 0x02, 0x80, 0x00, 0x24, 0x42, 0x00, 0x01, 0x06, 0x42, 0x40, 0x00,
+        0x46, 0x42, 0x4c, 0x00, 0x47, 0x82, 0xc5, 0x04, 0x81, 0xc2, 0x0d, 0x00,
+        0x64, 0x82, 0x00, 0x01, 0x1f, 0x00, 0xce, 0x04, 0x1e, 0x40, 0x01, 0x80,
+        0x46, 0x42, 0x4c, 0x00, 0x47, 0xc2, 0xc0, 0x04, 0x81, 0x42, 0x0e, 0x00,
+        0x64, 0x82, 0x00, 0x01, 0x5f, 0x40, 0xc4, 0x04, 0x1e, 0x00, 0x00, 0x80,
+        0x43, 0x42, 0x00, 0x00, 0x43, 0x02, 0x80, 0x00, 0x24, 0x42, 0x00, 0x01,
+        0x06, 0x42, 0x40, 0x00, 0x46, 0xc2, 0x43, 0x00, 0x81, 0xc2, 0x02, 0x00,
+        0xc6, 0x42, 0x4c, 0x00, 0xc7, 0xc2, 0xc0, 0x05, 0x01, 0x83, 0x0e, 0x00,
+        0xe4, 0x02, 0x00, 0x01, 0x64, 0x82, 0x00, 0x00, 0x1f, 0x40, 0xc4, 0x04,
+        0x1e, 0x00, 0x02, 0x80, 0x46, 0xc2, 0x43, 0x00, 0x81, 0xc2, 0x02, 0x00,
+        0xc6, 0x42, 0x4c, 0x00, 0xc7, 0xc2, 0xc0, 0x05, 0x01, 0xc3, 0x0e, 0x00,
+        0xe4, 0x02, 0x00, 0x01, 0x64, 0x82, 0x00, 0x00, 0x5f, 0x40, 0xc4, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x42, 0x00, 0x00, 0x43, 0x02, 0x80, 0x00,
+        0x24, 0x42, 0x00, 0x01, 0x06, 0x42, 0x40, 0x00, 0x46, 0xc2, 0x43, 0x00,
+        0x81, 0xc2, 0x02, 0x00, 0xc6, 0x82, 0x44, 0x00, 0x06, 0x43, 0x4c, 0x00,
+        0x07, 0x83, 0x45, 0x06, 0x41, 0x03, 0x0f, 0x00, 0xe4, 0x02, 0x80, 0x01,
+        0x64, 0x82, 0x00, 0x00, 0x5f, 0x00, 0xc7, 0x04, 0x1e, 0x00, 0x00, 0x80,
+        0x43, 0x42, 0x00, 0x00, 0x43, 0x02, 0x80, 0x00, 0x24, 0x42, 0x00, 0x01,
+        0x26, 0x00, 0x80, 0x00, 0x3d, 0x00, 0x00, 0x00, 0x04, 0x10, 0x68, 0xc3,
+        0xa9, 0x6c, 0x6c, 0x6f, 0x2c, 0x20, 0xe4, 0xb8, 0x96, 0xe7, 0x95, 0x8c,
+        0x21, 0x04, 0x07, 0x61, 0x73, 0x73, 0x65, 0x72, 0x74, 0x04, 0x05, 0x75,
+        0x74, 0x66, 0x38, 0x04, 0x04, 0x6c, 0x65, 0x6e, 0x13, 0x0a, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x13, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13,
+        0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x03, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0xfc, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0x13, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x04, 0x01, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x06, 0x61, 0x62, 0xff, 0x63, 0x64, 0x04, 0x07, 0x73, 0x65, 0x6c, 0x65,
+        0x63, 0x74, 0x04, 0x03, 0xc0, 0x80, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x06, 0x70, 0x63, 0x61, 0x6c, 0x6c, 0x04, 0x04,
+        0x61, 0x62, 0x63, 0x13, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x14, 0x3f, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65,
+        0x6e, 0x74, 0x20, 0x23, 0x32, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x75, 0x74,
+        0x66, 0x38, 0x2e, 0x6c, 0x65, 0x6e, 0x27, 0x20, 0x28, 0x69, 0x6e, 0x69,
+        0x74, 0x69, 0x61, 0x6c, 0x20, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x69, 0x6f,
+        0x6e, 0x20, 0x6f, 0x75, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x73, 0x74, 0x72,
+        0x69, 0x6e, 0x67, 0x29, 0x04, 0x05, 0x63, 0x68, 0x61, 0x72, 0x13, 0x48,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0xe9, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x13, 0x16, 0x4e, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x07, 0x48, 0xc3, 0xa9, 0xe4, 0xb8, 0x96, 0x13, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x14, 0x34, 0x62, 0x61, 0x64, 0x20,
+        0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x31, 0x20,
+        0x74, 0x6f, 0x20, 0x27, 0x75, 0x74, 0x66, 0x38, 0x2e, 0x63, 0x68, 0x61,
+        0x72, 0x27, 0x20, 0x28, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x6f, 0x75,
+        0x74, 0x20, 0x6f, 0x66, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x29, 0x04,
+        0x0a, 0x63, 0x6f, 0x64, 0x65, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x13, 0x68,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x4c, 0x75, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0xff, 0x04, 0x13, 0x69, 0x6e, 0x76,
+        0x61, 0x6c, 0x69, 0x64, 0x20, 0x55, 0x54, 0x46, 0x2d, 0x38, 0x20, 0x63,
+        0x6f, 0x64, 0x65, 0x14, 0x33, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67,
+        0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x33, 0x20, 0x74, 0x6f, 0x20,
+        0x27, 0x75, 0x74, 0x66, 0x38, 0x2e, 0x63, 0x6f, 0x64, 0x65, 0x70, 0x6f,
+        0x69, 0x6e, 0x74, 0x27, 0x20, 0x28, 0x6f, 0x75, 0x74, 0x20, 0x6f, 0x66,
+        0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x29, 0x04, 0x07, 0x6f, 0x66, 0x66,
+        0x73, 0x65, 0x74, 0x13, 0xfd, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0x13, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x0b, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x10, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x13, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x04, 0x02, 0x80, 0x04, 0x28, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c,
+        0x20, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x73,
+        0x20, 0x61, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x69, 0x6e, 0x75, 0x61, 0x74,
+        0x69, 0x6f, 0x6e, 0x20, 0x62, 0x79, 0x74, 0x65, 0x04, 0x06, 0x63, 0x6f,
+        0x64, 0x65, 0x73, 0x04, 0x02, 0x3a, 0x04, 0x06, 0x32, 0x3a, 0x32, 0x33,
+        0x33, 0x04, 0x09, 0x31, 0x32, 0x3a, 0x33, 0x30, 0x30, 0x32, 0x38, 0x04,
+        0x07, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x04, 0x07, 0x67, 0x6d, 0x61,
+        0x74, 0x63, 0x68, 0x04, 0x05, 0x75, 0x74, 0x66, 0x38, 0x04, 0x0c, 0x63,
+        0x68, 0x61, 0x72, 0x70, 0x61, 0x74, 0x74, 0x65, 0x72, 0x6e, 0x04, 0x04,
+        0xed, 0xa0, 0x80, 0x13, 0x00, 0xd8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x13, 0xff, 0xff, 0xff, 0x7f, 0x00, 0x00, 0x00, 0x00, 0x04, 0x07, 0xfd,
+        0xbf, 0xbf, 0xbf, 0xbf, 0xbf, 0x13, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x04, 0x05, 0xf4, 0x90, 0x80, 0x80, 0x04, 0x06, 0xf8, 0x88,
+        0x80, 0x80, 0x80, 0x04, 0x06, 0xf8, 0x80, 0x80, 0x80, 0x80, 0x04, 0x03,
+        0xfe, 0x80, 0x13, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x08, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x40, 0x00, 0x07, 0x40, 0x40, 0x00, 0x41, 0x80, 0x00,
+        0x00, 0x24, 0x00, 0x01, 0x01, 0x1e, 0xc0, 0xff, 0x7f, 0x29, 0x40, 0x00,
+        0x00, 0xaa, 0x40, 0xff, 0x7f, 0x26, 0x00, 0x80, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x04, 0x05, 0x75, 0x74, 0x66, 0x38, 0x04, 0x06, 0x63, 0x6f, 0x64,
+        0x65, 0x73, 0x04, 0x04, 0x61, 0xff, 0x62, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00,
+        0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00,
+        0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00,
+        0x00, 0x15, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x10, 0x28, 0x66,
+        0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72,
+        0x29, 0x04, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x0c, 0x28, 0x66,
+        0x6f, 0x72, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x29, 0x04, 0x00, 0x00,
+        0x00, 0x07, 0x00, 0x00, 0x00, 0x0e, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x63,
+        0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x29, 0x04, 0x00, 0x00, 0x00, 0x07,
+        0x00, 0x00, 0x00, 0x02, 0x5f, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56, 0x76, 0x01,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00,
+        0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00,
+        0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00,
+        0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00,
+        0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x16, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00,
+        0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1b, 0x00,
+        0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00,
+        0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00,
+        0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00,
+        0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00,
+        0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00,
+        0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00,
+        0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00,
+        0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x02, 0x73, 0x01, 0x00, 0x00, 0x00, 0x76, 0x01, 0x00, 0x00,
+        0x02, 0x6e, 0x31, 0x00, 0x00, 0x00, 0x76, 0x01, 0x00, 0x00, 0x04, 0x70,
+        0x6f, 0x73, 0x31, 0x00, 0x00, 0x00, 0x76, 0x01, 0x00, 0x00, 0x02, 0x61,
+        0x7a, 0x00, 0x00, 0x00, 0x76, 0x01, 0x00, 0x00, 0x02, 0x62, 0x7a, 0x00,
+        0x00, 0x00, 0x76, 0x01, 0x00, 0x00, 0x02, 0x63, 0x7a, 0x00, 0x00, 0x00,
+        0x76, 0x01, 0x00, 0x00, 0x02, 0x74, 0xef, 0x00, 0x00, 0x00, 0x76, 0x01,
+        0x00, 0x00, 0x10, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65,
+        0x72, 0x61, 0x74, 0x6f, 0x72, 0x29, 0xf3, 0x00, 0x00, 0x00, 0xfd, 0x00,
+        0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74, 0x61, 0x74,
+        0x65, 0x29, 0xf3, 0x00, 0x00, 0x00, 0xfd, 0x00, 0x00, 0x00, 0x0e, 0x28,
+        0x66, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x29,
+        0xf3, 0x00, 0x00, 0x00, 0xfd, 0x00, 0x00, 0x00, 0x02, 0x70, 0xf4, 0x00,
+        0x00, 0x00, 0xfb, 0x00, 0x00, 0x00, 0x02, 0x63, 0xf4, 0x00, 0x00, 0x00,
+        0xfb, 0x00, 0x00, 0x00, 0x06, 0x63, 0x68, 0x61, 0x72, 0x73, 0x11, 0x01,
+        0x00, 0x00, 0x76, 0x01, 0x00, 0x00, 0x10, 0x28, 0x66, 0x6f, 0x72, 0x20,
+        0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x29, 0x17, 0x01,
+        0x00, 0x00, 0x1b, 0x01, 0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20,
+        0x73, 0x74, 0x61, 0x74, 0x65, 0x29, 0x17, 0x01, 0x00, 0x00, 0x1b, 0x01,
+        0x00, 0x00, 0x0e, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74,
+        0x72, 0x6f, 0x6c, 0x29, 0x17, 0x01, 0x00, 0x00, 0x1b, 0x01, 0x00, 0x00,
+        0x02, 0x5f, 0x18, 0x01, 0x00, 0x00, 0x19, 0x01, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
+
+    #[test]
+    fn test_utf8_lib() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        ls.requiref("_G", open_base, true);
+        ls.requiref("string", open_string, true);
+        ls.requiref("utf8", open_utf8, true);
+        ls.pop(3);
+
+        ls.load(LUA_UTF8_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 0, 0);
+        assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+    }
+}
@@ -0,0 +1,624 @@
+use crate::api::consts::*;
+use crate::api::{LuaAPI, LuaAuxLib, LuaUserdata};
+use crate::convert::{FromLua, FromLuaMulti, IntoLua, IntoLuaMulti};
+use crate::error::{LuaError, LuaResult};
+use std::any::Any;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+// A Rust closure called from Lua, kept in a userdata upvalue of `call_callback`.
+type Callback = dyn Fn(&mut dyn LuaAPI) -> usize;
+
+/*
+    A Rust type that scripts use as an object: pushed as a full userdata
+    holding a `RefCell<Self>`, whose metatable (`registry[NAME]`) is built
+    from the registered methods and fields the first time one is pushed.
+
+        impl UserData for Counter {
+            const NAME: &'static str = "Counter";
+            fn add_methods(methods: &mut UserDataMethods<Self>) {
+                methods.add_method("get", |c, ()| Ok(c.n));
+            }
+        }
+
+    Methods borrow the object like a `RefCell`, a method taking `&mut self`
+    fails with an error while the object is borrowed, so it cannot alias.
+*/
+pub trait UserData: Any + Sized {
+    // Name of the type in error messages, and key of its metatable.
+    const NAME: &'static str;
+
+    // Methods called as `obj:name(...)`.
+    fn add_methods(_methods: &mut UserDataMethods<Self>) {}
+
+    // Fields read as `obj.name` and assigned as `obj.name = v`.
+    fn add_fields(_fields: &mut UserDataFields<Self>) {}
+
+    // Metamethods, like "__tostring" or "__add". An "__index" or a
+    // "__newindex" is only used for the keys that are not methods or fields.
+    fn add_meta_methods(_methods: &mut UserDataMethods<Self>) {}
+}
+
+pub struct UserDataMethods<T> {
+    funcs: Vec<(String, Box<Callback>)>,
+    marker: PhantomData<T>,
+}
+
+impl<T: UserData> UserDataMethods<T> {
+    fn new() -> UserDataMethods<T> {
+        UserDataMethods { funcs: Vec::new(), marker: PhantomData }
+    }
+
+    // A method borrowing the object.
+    pub fn add_method<A, R, F>(&mut self, name: &str, f: F)
+    where
+        A: FromLuaMulti,
+        R: IntoLuaMulti,
+        F: Fn(&T, A) -> LuaResult<R> + 'static,
+    {
+        let callback = move |ls: &mut dyn LuaAPI| {
+            let ud = ls.check_userdata::<T>(1, T::NAME);
+            let args = check_args::<A>(ls, 2);
+            let result = match ud.try_borrow() {
+                Ok(this) => f(&this, args),
+                Err(_) => ls.error_l(&format!("{} already mutably borrowed", T::NAME)),
+            };
+            push_results(ls, result)
+        };
+        self.funcs.push((name.to_string(), Box::new(callback)));
+    }
+
+    // A method borrowing the object mutably.
+    pub fn add_method_mut<A, R, F>(&mut self, name: &str, f: F)
+    where
+        A: FromLuaMulti,
+        R: IntoLuaMulti,
+        F: Fn(&mut T, A) -> LuaResult<R> + 'static,
+    {
+        let callback = move |ls: &mut dyn LuaAPI| {
+            let ud = ls.check_userdata::<T>(1, T::NAME);
+            let args = check_args::<A>(ls, 2);
+            let result = match ud.try_borrow_mut() {
+                Ok(mut this) => f(&mut this, args),
+                Err(_) => ls.error_l(&format!("{} already borrowed", T::NAME)),
+            };
+            push_results(ls, result)
+        };
+        self.funcs.push((name.to_string(), Box::new(callback)));
+    }
+
+    // A function that gets all the arguments, `self` included.
+    pub fn add_function<A, R, F>(&mut self, name: &str, f: F)
+    where
+        A: FromLuaMulti,
+        R: IntoLuaMulti,
+        F: Fn(A) -> LuaResult<R> + 'static,
+    {
+        let callback = move |ls: &mut dyn LuaAPI| {
+            let args = check_args::<A>(ls, 1);
+            let result = f(args);
+            push_results(ls, result)
+        };
+        self.funcs.push((name.to_string(), Box::new(callback)));
+    }
+}
+
+pub struct UserDataFields<T> {
+    getters: Vec<(String, Box<Callback>)>,
+    setters: Vec<(String, Box<Callback>)>,
+    marker: PhantomData<T>,
+}
+
+impl<T: UserData> UserDataFields<T> {
+    fn new() -> UserDataFields<T> {
+        UserDataFields { getters: Vec::new(), setters: Vec::new(), marker: PhantomData }
+    }
+
+    pub fn add_field_method_get<R, F>(&mut self, name: &str, f: F)
+    where
+        R: IntoLua,
+        F: Fn(&T) -> LuaResult<R> + 'static,
+    {
+        let callback = move |ls: &mut dyn LuaAPI| {
+            let ud = ls.check_userdata::<T>(1, T::NAME);
+            let result = match ud.try_borrow() {
+                Ok(this) => f(&this),
+                Err(_) => field_error(ls, &format!("{} already mutably borrowed", T::NAME)),
+            };
+            match result {
+                Ok(r) => r.push_into_multi(ls),
+                Err(e) => field_error(ls, &e.to_string()),
+            }
+        };
+        self.getters.push((name.to_string(), Box::new(callback)));
+    }
+
+    pub fn add_field_method_set<A, F>(&mut self, name: &str, f: F)
+    where
+        A: FromLua,
+        F: Fn(&mut T, A) -> LuaResult<()> + 'static,
+    {
+        let field = name.to_string();
+        let callback = move |ls: &mut dyn LuaAPI| {
+            let ud = ls.check_userdata::<T>(1, T::NAME);
+            let value = match A::from_lua(ls, 2) {
+                Ok(value) => value,
+                Err(e) => field_error(ls, &format!("bad argument #1 to '{}' ({})", field, conversion_problem(&e))),
+            };
+            let result = match ud.try_borrow_mut() {
+                Ok(mut this) => f(&mut this, value),
+                Err(_) => field_error(ls, &format!("{} already borrowed", T::NAME)),
+            };
+            if let Err(e) = result {
+                field_error(ls, &e.to_string());
+            }
+            0
+        };
+        self.setters.push((name.to_string(), Box::new(callback)));
+    }
+}
+
+// Convert the arguments from `first` on, raising an error if they do not fit.
+fn check_args<A: FromLuaMulti>(ls: &mut dyn LuaAPI, first: isize) -> A {
+    let n = (ls.get_top() - first + 1).max(0) as usize;
+    match A::from_lua_args(ls, first, n) {
+        Ok(args) => args,
+        Err((arg, LuaError::FromLua { to, message: None, .. })) => ls.type_error(arg, lua_type(to)),
+        Err((arg, e)) => ls.arg_error(arg, &conversion_problem(&e)),
+    }
+}
+
+// What an argument error says of a value that did not convert, as in
+// "number expected, got string".
+fn conversion_problem(e: &LuaError) -> String {
+    match e {
+        LuaError::FromLua { message: Some(msg), .. } => msg.clone(),
+        LuaError::FromLua { from, to, .. } => format!("{} expected, got {}", lua_type(to), from),
+        e => e.to_string(),
+    }
+}
+
+// The Lua type expected by a conversion to the Rust type `to`.
+fn lua_type(to: &str) -> &str {
+    match to {
+        "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize" | "f32" | "f64" => "number",
+        "String" => "string",
+        "Vec" | "HashMap" | "LuaTable" => "table",
+        "LuaFunction" => "function",
+        _ => to, // userdata names
+    }
+}
+
+fn push_results<R: IntoLuaMulti>(ls: &mut dyn LuaAPI, result: LuaResult<R>) -> usize {
+    match result {
+        Ok(r) => r.push_into_multi(ls),
+        Err(e) => ls.error_l(&e.to_string()),
+    }
+}
+
+// Getters and setters run under `__index`/`__newindex`, so their errors
+// point one level further up, at the Lua code accessing the field.
+fn field_error(ls: &mut dyn LuaAPI, msg: &str) -> ! {
+    let msg = format!("{}{}", ls.where_l(2), msg);
+    ls.push_string(msg);
+    ls.error()
+}
+
+fn push_callback(ls: &mut dyn LuaAPI, f: Box<Callback>) {
+    ls.new_userdata(f);
+    ls.push_rust_closure(call_callback, 1);
+}
+
+fn call_callback(ls: &mut dyn LuaAPI) -> usize {
+    let f = ls.to_userdata::<Box<Callback>>(upvalue_index(1)).unwrap();
+    let f = f.borrow();
+    f(ls)
+}
+
+// Push `value` as a userdata with the metatable of `T`.
+pub fn push_userdata<T: UserData>(ls: &mut dyn LuaAPI, value: T) {
+    ls.new_userdata(value);
+    if ls.new_metatable(T::NAME) {
+        build_metatable::<T>(ls);
+    }
+    ls.set_metatable(-2);
+}
+
+// Fill the metatable on top of the stack.
+fn build_metatable<T: UserData>(ls: &mut dyn LuaAPI) {
+    let mt = ls.abs_index(-1);
+    let mut methods = UserDataMethods::<T>::new();
+    T::add_methods(&mut methods);
+    let mut fields = UserDataFields::<T>::new();
+    T::add_fields(&mut fields);
+    let mut meta = UserDataMethods::<T>::new();
+    T::add_meta_methods(&mut meta);
+
+    let (mut index, mut newindex) = (None, None);
+    for (name, f) in meta.funcs {
+        match name.as_str() {
+            "__index" => index = Some(f),
+            "__newindex" => newindex = Some(f),
+            _ => {
+                push_callback(ls, f);
+                ls.set_field(mt, &name);
+            }
+        }
+    }
+
+    push_table(ls, fields.getters);
+    push_table(ls, methods.funcs);
+    match index {
+        Some(f) => push_callback(ls, f),
+        None => ls.push_nil(),
+    }
+    ls.push_rust_closure(userdata_index, 3);
+    ls.set_field(mt, "__index");
+
+    push_table(ls, fields.setters);
+    match newindex {
+        Some(f) => push_callback(ls, f),
+        None => ls.push_nil(),
+    }
+    ls.push_string(T::NAME.to_string());
+    ls.push_rust_closure(userdata_newindex, 3);
+    ls.set_field(mt, "__newindex");
+}
+
+fn push_table(ls: &mut dyn LuaAPI, funcs: Vec<(String, Box<Callback>)>) {
+    ls.create_table(0, funcs.len());
+    for (name, f) in funcs {
+        push_callback(ls, f);
+        ls.set_field(-2, &name);
+    }
+}
+
+// __index (ud, key), with the getters, the methods and the fallback
+// `__index` as upvalues
+fn userdata_index(ls: &mut dyn LuaAPI) -> usize {
+    ls.push_value(2);
+    if ls.raw_get(upvalue_index(1)) != LUA_TNIL {
+        ls.push_value(1);
+        ls.call(1, 1); // getter(ud)
+        return 1;
+    }
+    ls.pop(1);
+    ls.push_value(2);
+    if ls.raw_get(upvalue_index(2)) != LUA_TNIL {
+        return 1; // method
+    }
+    if !ls.is_nil(upvalue_index(3)) {
+        ls.push_value(upvalue_index(3));
+        ls.push_value(1);
+        ls.push_value(2);
+        ls.call(2, 1);
+    }
+    1
+}
+
+// __newindex (ud, key, value), with the setters, the fallback `__newindex`
+// and the type name as upvalues
+fn userdata_newindex(ls: &mut dyn LuaAPI) -> usize {
+    ls.push_value(2);
+    if ls.raw_get(upvalue_index(1)) != LUA_TNIL {
+        ls.push_value(1);
+        ls.push_value(3);
+        ls.call(2, 0); // setter(ud, value)
+        return 0;
+    }
+    if ls.is_nil(upvalue_index(2)) {
+        let key = ls.tolstring_l(2);
+        let tname = ls.to_string(upvalue_index(3));
+        ls.error_l(&format!("no writable field '{}' in {}", key, tname));
+    }
+    ls.push_value(upvalue_index(2));
+    ls.push_value(1);
+    ls.push_value(2);
+    ls.push_value(3);
+    ls.call(3, 0);
+    0
+}
+
+impl<T: UserData> IntoLua for T {
+    fn push_into(self, ls: &mut dyn LuaAPI) {
+        push_userdata(ls, self);
+    }
+}
+
+// The object itself, shared with Lua.
+impl<T: UserData> FromLua for Rc<RefCell<T>> {
+    fn from_lua(ls: &mut dyn LuaAPI, idx: isize) -> LuaResult<Rc<RefCell<T>>> {
+        match ls.test_udata(idx, T::NAME).map(|ud| ud.downcast::<RefCell<T>>()) {
+            Some(Ok(ud)) => Ok(ud),
+            _ => Err(LuaError::FromLua {
+                from: ls.type_name(LuaAPI::type_id(ls, idx)).to_string(),
+                to: T::NAME,
+                message: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lua;
+
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    impl UserData for Point {
+        const NAME: &'static str = "Point";
+
+        fn add_methods(methods: &mut UserDataMethods<Self>) {
+            methods.add_method("len2", |p, ()| Ok(p.x * p.x + p.y * p.y));
+            methods.add_method_mut("scale", |p, k: f64| {
+                p.x *= k;
+                p.y *= k;
+                Ok(())
+            });
+        }
+
+        fn add_fields(fields: &mut UserDataFields<Self>) {
+            fields.add_field_method_get("x", |p| Ok(p.x));
+            fields.add_field_method_get("y", |p| Ok(p.y));
+            fields.add_field_method_set("x", |p, x| {
+                p.x = x;
+                Ok(())
+            });
+            fields.add_field_method_set("y", |p, y| {
+                p.y = y;
+                Ok(())
+            });
+        }
+
+        fn add_meta_methods(methods: &mut UserDataMethods<Self>) {
+            methods.add_method("__tostring", |p, ()| Ok(format!("Point({}, {})", p.x, p.y)));
+            methods.add_function("__add", |(a, b): (Rc<RefCell<Point>>, Rc<RefCell<Point>>)| {
+                let (a, b) = (a.borrow(), b.borrow());
+                Ok(Point { x: a.x + b.x, y: a.y + b.y })
+            });
+            methods.add_function("__index", |(_, key): (Rc<RefCell<Point>>, String)| Ok(format!("no {}", key)));
+        }
+    }
+
+    fn new_point(ls: &mut dyn LuaAPI) -> usize {
+        let (x, y) = (ls.check_number(1), ls.check_number(2));
+        push_userdata(ls, Point { x, y });
+        1
+    }
+
+    #[test]
+    fn test_userdata() {
+        let lua = Lua::new();
+        let mut ls = lua.state();
+        ls.register("new_point", new_point);
+        ls.load(LUA_USERDATA_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 0, 0);
+        assert_eq!(status, LUA_OK, "{}", ls.to_string(-1));
+
+        // the object is shared with Rust, and borrowed by methods
+        ls.get_global("P");
+        let p = Rc::<RefCell<Point>>::from_lua(&mut *ls, -1).unwrap();
+        assert_eq!(p.borrow().x, 20.0);
+        let guard = p.borrow_mut();
+        ls.get_field(-1, "len2");
+        ls.push_value(-2);
+        assert_eq!(ls.pcall(1, 1, 0), LUA_ERRRUN);
+        assert_eq!(ls.to_string(-1), "Point already mutably borrowed");
+        drop(guard);
+        ls.get_field(-2, "len2");
+        ls.push_value(-3);
+        assert_eq!(ls.pcall(1, 1, 0), LUA_OK);
+        assert_eq!(ls.to_number(-1), 416.0);
+
+        ls.new_table();
+        let err = Rc::<RefCell<Point>>::from_lua(&mut *ls, -1).err().unwrap();
+        assert_eq!(err.to_string(), "cannot convert a Lua table to Point");
+    }
+
+    /* Lua source code:
+        local p = new_point(1, 2)
+        assert(type(p) == "userdata" and p.x == 1 and p.y == 2)
+        p.x = 10
+        assert(p:len2() == 104)
+        p:scale(2)
+        assert(p.x == 20 and p.y == 4)
+        assert(tostring(p) == "Point(20, 4)")
+
+        local q = p + new_point(1, 1)
+        assert(q.x == 21 and q.y == 5 and p.x == 20)
+        assert(getmetatable(q) == getmetatable(p))
+
+        -- keys that are not fields or methods go to the __index metamethod
+        assert(p.other == "no other")
+
+        -- errors
+        local ok, err = pcall(function() return p.scale({}, 2) end)
+        assert(not ok and err == "chunk:17: bad argument #1 to 'scale' (Point expected, got table)", err)
+        ok, err = pcall(function() return p:scale("x") end)
+        assert(not ok and err == "chunk:19: bad argument #1 to 'scale' (number expected, got string)", err)
+        ok, err = pcall(function() p.len2 = 1 end)
+        assert(not ok and err == "chunk:21: no writable field 'len2' in Point", err)
+        ok, err = pcall(function() p.y = "y" end)
+        assert(not ok and err == "chunk:23: bad argument #1 to 'y' (number expected, got string)", err)
+        P = p
+    */
+    const LUA_USERDATA_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x07, 0x40,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x07, 0x8f, 0x00, 0x00, 0x00, 0x06, 0x00, 0x40, 0x00,
+        0x41, 0x40, 0x00, 0x00, 0x81, 0x80, 0x00, 0x00, 0x24, 0x80, 0x80, 0x01,
+        0x46, 0xc0, 0x40, 0x00, 0x86, 0x00, 0x41, 0x00, 0xc0, 0x00, 0x00, 0x00,
+        0xa4, 0x80, 0x00, 0x01, 0x1f, 0x40, 0x41, 0x01, 0x1e, 0x40, 0x01, 0x80,
+        0x87, 0x80, 0x41, 0x00, 0x1f, 0x40, 0x40, 0x01, 0x1e, 0x80, 0x00, 0x80,
+        0x87, 0xc0, 0x41, 0x00, 0x5f, 0x80, 0x40, 0x01, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x40, 0x00, 0x00, 0x83, 0x00, 0x80, 0x00, 0x64, 0x40, 0x00, 0x01,
+        0x0a, 0x00, 0x42, 0x83, 0x46, 0xc0, 0x40, 0x00, 0x8c, 0x40, 0x42, 0x00,
+        0xa4, 0x80, 0x00, 0x01, 0x5f, 0x80, 0x42, 0x01, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x40, 0x00, 0x00, 0x83, 0x00, 0x80, 0x00, 0x64, 0x40, 0x00, 0x01,
+        0x4c, 0xc0, 0x42, 0x00, 0xc1, 0x80, 0x00, 0x00, 0x64, 0x40, 0x80, 0x01,
+        0x46, 0xc0, 0x40, 0x00, 0x87, 0x80, 0x41, 0x00, 0x1f, 0x00, 0x43, 0x01,
+        0x1e, 0x80, 0x00, 0x80, 0x87, 0xc0, 0x41, 0x00, 0x5f, 0x40, 0x43, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x40, 0x00, 0x00, 0x83, 0x00, 0x80, 0x00,
+        0x64, 0x40, 0x00, 0x01, 0x46, 0xc0, 0x40, 0x00, 0x86, 0x80, 0x43, 0x00,
+        0xc0, 0x00, 0x00, 0x00, 0xa4, 0x80, 0x00, 0x01, 0x5f, 0xc0, 0x43, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x40, 0x00, 0x00, 0x83, 0x00, 0x80, 0x00,
+        0x64, 0x40, 0x00, 0x01, 0x46, 0x00, 0x40, 0x00, 0x81, 0x40, 0x00, 0x00,
+        0xc1, 0x40, 0x00, 0x00, 0x64, 0x80, 0x80, 0x01, 0x4d, 0x40, 0x00, 0x00,
+        0x86, 0xc0, 0x40, 0x00, 0xc7, 0x80, 0xc1, 0x00, 0x1f, 0x00, 0xc4, 0x01,
+        0x1e, 0x40, 0x01, 0x80, 0xc7, 0xc0, 0xc1, 0x00, 0x1f, 0x40, 0xc4, 0x01,
+        0x1e, 0x80, 0x00, 0x80, 0xc7, 0x80, 0x41, 0x00, 0x5f, 0x00, 0xc3, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0xc0, 0x40, 0x00, 0xc6, 0x80, 0x44, 0x00,
+        0x00, 0x01, 0x80, 0x00, 0xe4, 0x80, 0x00, 0x01, 0x06, 0x81, 0x44, 0x00,
+        0x40, 0x01, 0x00, 0x00, 0x24, 0x81, 0x00, 0x01, 0x5f, 0x00, 0x81, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00, 0xc3, 0x00, 0x80, 0x00,
+        0xa4, 0x40, 0x00, 0x01, 0x86, 0xc0, 0x40, 0x00, 0xc7, 0xc0, 0x44, 0x00,
+        0x5f, 0x00, 0xc5, 0x01, 0x1e, 0x00, 0x00, 0x80, 0xc3, 0x40, 0x00, 0x00,
+        0xc3, 0x00, 0x80, 0x00, 0xa4, 0x40, 0x00, 0x01, 0x86, 0x40, 0x45, 0x00,
+        0xec, 0x00, 0x00, 0x00, 0xa4, 0xc0, 0x00, 0x01, 0x06, 0xc1, 0x40, 0x00,
+        0xa2, 0x40, 0x00, 0x00, 0x1e, 0x40, 0x00, 0x80, 0x5f, 0x80, 0xc5, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x41, 0x00, 0x00, 0x43, 0x01, 0x80, 0x00,
+        0x80, 0x01, 0x80, 0x01, 0x24, 0x41, 0x80, 0x01, 0x06, 0x41, 0x45, 0x00,
+        0x6c, 0x41, 0x00, 0x00, 0x24, 0xc1, 0x00, 0x01, 0xc0, 0x00, 0x80, 0x02,
+        0x80, 0x00, 0x00, 0x02, 0x06, 0xc1, 0x40, 0x00, 0xa2, 0x40, 0x00, 0x00,
+        0x1e, 0x40, 0x00, 0x80, 0x5f, 0xc0, 0xc5, 0x01, 0x1e, 0x00, 0x00, 0x80,
+        0x43, 0x41, 0x00, 0x00, 0x43, 0x01, 0x80, 0x00, 0x80, 0x01, 0x80, 0x01,
+        0x24, 0x41, 0x80, 0x01, 0x06, 0x41, 0x45, 0x00, 0x6c, 0x81, 0x00, 0x00,
+        0x24, 0xc1, 0x00, 0x01, 0xc0, 0x00, 0x80, 0x02, 0x80, 0x00, 0x00, 0x02,
+        0x06, 0xc1, 0x40, 0x00, 0xa2, 0x40, 0x00, 0x00, 0x1e, 0x40, 0x00, 0x80,
+        0x5f, 0x00, 0xc6, 0x01, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x41, 0x00, 0x00,
+        0x43, 0x01, 0x80, 0x00, 0x80, 0x01, 0x80, 0x01, 0x24, 0x41, 0x80, 0x01,
+        0x06, 0x41, 0x45, 0x00, 0x6c, 0xc1, 0x00, 0x00, 0x24, 0xc1, 0x00, 0x01,
+        0xc0, 0x00, 0x80, 0x02, 0x80, 0x00, 0x00, 0x02, 0x06, 0xc1, 0x40, 0x00,
+        0xa2, 0x40, 0x00, 0x00, 0x1e, 0x40, 0x00, 0x80, 0x5f, 0x40, 0xc6, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x41, 0x00, 0x00, 0x43, 0x01, 0x80, 0x00,
+        0x80, 0x01, 0x80, 0x01, 0x24, 0x41, 0x80, 0x01, 0x08, 0x00, 0x00, 0x8d,
+        0x26, 0x00, 0x80, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x04, 0x0a, 0x6e, 0x65,
+        0x77, 0x5f, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x13, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x13, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x07, 0x61, 0x73, 0x73, 0x65, 0x72, 0x74, 0x04, 0x05, 0x74,
+        0x79, 0x70, 0x65, 0x04, 0x09, 0x75, 0x73, 0x65, 0x72, 0x64, 0x61, 0x74,
+        0x61, 0x04, 0x02, 0x78, 0x04, 0x02, 0x79, 0x13, 0x0a, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x05, 0x6c, 0x65, 0x6e, 0x32, 0x13, 0x68,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x06, 0x73, 0x63, 0x61,
+        0x6c, 0x65, 0x13, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13,
+        0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x09, 0x74, 0x6f,
+        0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x04, 0x0d, 0x50, 0x6f, 0x69, 0x6e,
+        0x74, 0x28, 0x32, 0x30, 0x2c, 0x20, 0x34, 0x29, 0x13, 0x15, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x04, 0x0d, 0x67, 0x65, 0x74, 0x6d, 0x65, 0x74, 0x61, 0x74,
+        0x61, 0x62, 0x6c, 0x65, 0x04, 0x06, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x04,
+        0x09, 0x6e, 0x6f, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x04, 0x06, 0x70,
+        0x63, 0x61, 0x6c, 0x6c, 0x14, 0x41, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x3a,
+        0x31, 0x37, 0x3a, 0x20, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75,
+        0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23, 0x31, 0x20, 0x74, 0x6f, 0x20, 0x27,
+        0x73, 0x63, 0x61, 0x6c, 0x65, 0x27, 0x20, 0x28, 0x50, 0x6f, 0x69, 0x6e,
+        0x74, 0x20, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x2c, 0x20,
+        0x67, 0x6f, 0x74, 0x20, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x29, 0x14, 0x43,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x3a, 0x31, 0x39, 0x3a, 0x20, 0x62, 0x61,
+        0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x23,
+        0x31, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x73, 0x63, 0x61, 0x6c, 0x65, 0x27,
+        0x20, 0x28, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x65, 0x78, 0x70,
+        0x65, 0x63, 0x74, 0x65, 0x64, 0x2c, 0x20, 0x67, 0x6f, 0x74, 0x20, 0x73,
+        0x74, 0x72, 0x69, 0x6e, 0x67, 0x29, 0x14, 0x2c, 0x63, 0x68, 0x75, 0x6e,
+        0x6b, 0x3a, 0x32, 0x31, 0x3a, 0x20, 0x6e, 0x6f, 0x20, 0x77, 0x72, 0x69,
+        0x74, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20,
+        0x27, 0x6c, 0x65, 0x6e, 0x32, 0x27, 0x20, 0x69, 0x6e, 0x20, 0x50, 0x6f,
+        0x69, 0x6e, 0x74, 0x14, 0x3f, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x3a, 0x32,
+        0x33, 0x3a, 0x20, 0x62, 0x61, 0x64, 0x20, 0x61, 0x72, 0x67, 0x75, 0x6d,
+        0x65, 0x6e, 0x74, 0x20, 0x23, 0x31, 0x20, 0x74, 0x6f, 0x20, 0x27, 0x79,
+        0x27, 0x20, 0x28, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x65, 0x78,
+        0x70, 0x65, 0x63, 0x74, 0x65, 0x64, 0x2c, 0x20, 0x67, 0x6f, 0x74, 0x20,
+        0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x29, 0x04, 0x02, 0x50, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00,
+        0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x40, 0x00, 0x4b, 0x00, 0x00, 0x00, 0x81, 0x40, 0x00, 0x00,
+        0x25, 0x00, 0x80, 0x01, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x80, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x04, 0x06, 0x73, 0x63, 0x61, 0x6c, 0x65, 0x13,
+        0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x70, 0x00, 0x13, 0x00, 0x00,
+        0x00, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x06, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x40, 0x00, 0x81, 0x40, 0x00, 0x00,
+        0x25, 0x00, 0x80, 0x01, 0x26, 0x00, 0x00, 0x00, 0x26, 0x00, 0x80, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x04, 0x06, 0x73, 0x63, 0x61, 0x6c, 0x65, 0x04,
+        0x02, 0x78, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00,
+        0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00,
+        0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x02, 0x70, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x08, 0x40, 0x40, 0x80, 0x26, 0x00,
+        0x80, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0x05, 0x6c, 0x65, 0x6e, 0x32,
+        0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x15,
+        0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x02, 0x70, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x40,
+        0x80, 0x26, 0x00, 0x80, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x02, 0x79,
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x70, 0x8f, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+        0x09, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00,
+        0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00,
+        0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00,
+        0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00,
+        0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+        0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+        0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+        0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00,
+        0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00,
+        0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00,
+        0x15, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00,
+        0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00,
+        0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00,
+        0x16, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00,
+        0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00,
+        0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x02, 0x70, 0x04, 0x00, 0x00, 0x00, 0x8f, 0x00, 0x00, 0x00, 0x02, 0x71,
+        0x37, 0x00, 0x00, 0x00, 0x8f, 0x00, 0x00, 0x00, 0x03, 0x6f, 0x6b, 0x5a,
+        0x00, 0x00, 0x00, 0x8f, 0x00, 0x00, 0x00, 0x04, 0x65, 0x72, 0x72, 0x5a,
+        0x00, 0x00, 0x00, 0x8f, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05,
+        0x5f, 0x45, 0x4e, 0x56,
+    ];
+}
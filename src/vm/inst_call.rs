@@ -181,7 +181,6 @@ pub fn self_(i: u32, vm: &mut dyn LuaVM) {
     let a = a + 1;
     let b = b + 1;
     vm.copy(b, a + 1);
-    vm.get_rk(c);
-    vm.get_table(b);
+    vm.get_table_rk(b, c);
     vm.replace(a);
 }
\ No newline at end of file
@@ -21,19 +21,15 @@ use super::instruction::Instruction;
 */
 pub fn for_prep(i: u32, vm: &mut dyn LuaVM) {
     let (a, sbx) = i.a_sbx();
-    let a = a + 1;
 
-    for o in 0..=2 {
-        if vm.type_id(a + o) == LUA_TSTRING {
-            vm.push_number(vm.to_number(a + o));
-            vm.replace(a + o);
+    for idx in a + 1..=a + 3 {
+        if vm.type_id(idx) == LUA_TSTRING {
+            vm.push_number(vm.to_number(idx));
+            vm.replace(idx);
         }
     }
     // R(A) -= R(A+2)
-    vm.push_value(a);
-    vm.push_value(a + 2);
-    vm.arith(LUA_OPSUB);
-    vm.replace(a);
+    vm.arith_rk(LUA_OPSUB, a, a, a + 2);
     // pc += sBx
     vm.add_pc(sbx);
 }
@@ -60,16 +56,53 @@ pub fn for_prep(i: u32, vm: &mut dyn LuaVM) {
 */
 pub fn for_loop(i: u32, vm: &mut dyn LuaVM) {
     let (a, sbx) = i.a_sbx();
-    let a = a + 1;
     // R(A) += R(A+2)
-    vm.push_value(a + 2);
-    vm.push_value(a);
-    vm.arith(LUA_OPADD);
-    vm.replace(a);
+    vm.arith_rk(LUA_OPADD, a, a, a + 2);
     // R(A) <?= R(A+1) [if step is positive, `<?=` means `<=`, else means `>=`]
-    let is_positive_step = vm.to_number(a + 2) >= 0.0;
-    if  is_positive_step && vm.compare(a, a + 1, LUA_OPLE) || !is_positive_step && vm.compare(a + 1, a, LUA_OPLE) {
-            vm.add_pc(sbx);     // pc += sBx
-            vm.copy(a, a + 3);  // R(A+3) = R(A)
-        }
-}
\ No newline at end of file
+    let is_positive_step = vm.to_number(a + 3) >= 0.0; // R(A+2), the step
+    if is_positive_step && vm.compare_rk(LUA_OPLE, a, a + 1) || !is_positive_step && vm.compare_rk(LUA_OPLE, a + 1, a) {
+        vm.add_pc(sbx);         // pc += sBx
+        vm.move_reg(a + 3, a);  // R(A+3) = R(A)
+    }
+}
+/*              TFORCALL instruction
+        R(A+3), ..., R(A+2+C) := R(A)(R(A+1), R(A+2))
+    +---------+---------+---------+---------+
+    |   B:    |   C: 2  |   A: 0  |TFORCALL |
+    +---------+---------+---------+---------+
+
+        +----------+          +----------+
+  A+4-> |          |     +--->|    v     |
+        +----------+     |    +----------+
+  A+3-> |          |     +--->|    k     |
+        +----------+     |    +----------+
+  A+2-> | (ctrl)   |--+  |    | (ctrl)   |
+        +----------+  |  |    +----------+
+  A+1-> | (state)  |--+  |    | (state)  |
+        +----------+  |  |    +----------+
+    A-> |  (iter)  |-f(s,c)   |  (iter)  |
+        +----------+          +----------+
+         registers             registers
+*/
+pub fn tfor_call(i: u32, vm: &mut dyn LuaVM) {
+    let (a, _, c) = i.abc();
+    let a = a + 1;
+    vm.check_stack(3);
+    vm.push_value(a);
+    vm.push_value(a + 1);
+    vm.push_value(a + 2);
+    vm.call(2, c);
+    for i in (a + 3..a + 3 + c).rev() {
+        vm.replace(i);
+    }
+}
+
+// if R(A+1) ~= nil then { R(A)=R(A+1); pc += sBx }
+pub fn tfor_loop(i: u32, vm: &mut dyn LuaVM) {
+    let (a, sbx) = i.a_sbx();
+    let a = a + 1;
+    if !vm.is_nil(a + 1) {
+        vm.copy(a + 1, a);
+        vm.add_pc(sbx);
+    }
+}
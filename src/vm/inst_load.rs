@@ -78,13 +78,11 @@ pub fn load_bool(i: u32, vm: &mut dyn LuaVM) {
 */
 pub fn load_k(i: u32, vm: &mut dyn LuaVM) {
     let (a, bx) = i.a_bx();
-    vm.get_const(bx);
-    vm.replace(a + 1);
+    vm.load_const(a, bx);
 }
 
 pub fn load_kx(i: u32, vm: &mut dyn LuaVM) {
     let (a, _) = i.a_bx();
     let ax = vm.fetch().ax();
-    vm.get_const(ax);
-    vm.replace(a + 1);
+    vm.load_const(a, ax);
 }
@@ -21,7 +21,7 @@ use super::instruction::Instruction;
 */
 pub fn move_(i: u32, vm: &mut dyn LuaVM) {
     let (a, b, _) = i.abc();
-    vm.copy(b + 1, a + 1);
+    vm.move_reg(a, b);
 }
 
 pub fn jmp(i: u32, vm: &mut dyn LuaVM) {
@@ -29,6 +29,6 @@ pub fn jmp(i: u32, vm: &mut dyn LuaVM) {
     vm.add_pc(sbx);
 
     if a != 0 {
-        todo!();
+        vm.close_upvals(a - 1);
     }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use crate::api::consts::*;
-use crate::ArithOp;
+use crate::api::consts::ArithOp;
 use crate::api::LuaVM;
 use super::instruction::Instruction;
 
@@ -23,11 +23,8 @@ use super::instruction::Instruction;
          registers             registers
 */
 fn _binary_arith(i: u32, vm: &mut dyn LuaVM, op: ArithOp) {
-    let (a, b, c) = i.abc();    
-    vm.get_rk(b);
-    vm.get_rk(c);
-    vm.arith(op);
-    vm.replace(a + 1);
+    let (a, b, c) = i.abc();
+    vm.arith_rk(op, a, b, c);
 }
 
 /*               unary arith
@@ -49,9 +46,7 @@ fn _binary_arith(i: u32, vm: &mut dyn LuaVM, op: ArithOp) {
 */
 fn _unary_arith(i: u32, vm: &mut dyn LuaVM, op: ArithOp) {
     let (a, b, _) = i.abc();
-    vm.push_value(b + 1);
-    vm.arith(op);
-    vm.replace(a + 1);
+    vm.arith_rk(op, a, b, b);
 }
 
 // arith
@@ -67,8 +62,8 @@ pub fn bor(i: u32, vm: &mut dyn LuaVM) { _binary_arith(i, vm, LUA_OPBOR); } // |
 pub fn bxor(i: u32, vm: &mut dyn LuaVM) { _binary_arith(i, vm, LUA_OPBXOR); }   // ~
 pub fn bshl(i: u32, vm: &mut dyn LuaVM) { _binary_arith(i, vm, LUA_OPSHL); }// <<
 pub fn bshr(i: u32, vm: &mut dyn LuaVM) { _binary_arith(i, vm, LUA_OPSHR); }// >>
-pub fn unm(i: u32, vm: &mut dyn LuaVM) { _binary_arith(i, vm, LUA_OPUNM); } // -
-pub fn bnot(i: u32, vm: &mut dyn LuaVM) { _binary_arith(i, vm, LUA_OPBNOT); }   // ~
+pub fn unm(i: u32, vm: &mut dyn LuaVM) { _unary_arith(i, vm, LUA_OPUNM); } // -
+pub fn bnot(i: u32, vm: &mut dyn LuaVM) { _unary_arith(i, vm, LUA_OPBNOT); }   // ~
 
 /*               LEN instruction
             R(A) := length of R(B)
@@ -145,12 +140,9 @@ pub fn concat(i: u32, vm: &mut dyn LuaVM) {
 */
 fn _compare(i: u32, vm: &mut dyn LuaVM, op: CompareOp) {
     let (a, b, c) = i.abc();
-    vm.get_rk(b);
-    vm.get_rk(c);
-    if vm.compare(-2, -1, op) != (a != 0) {
+    if vm.compare_rk(op, b, c) != (a != 0) {
         vm.add_pc(1);
     }
-    vm.pop(2);
 }
 
 /* compare */
@@ -205,10 +197,8 @@ pub fn not(i: u32, vm: &mut dyn LuaVM) {
 */
 pub fn test_set(i: u32, vm: &mut dyn LuaVM) {
     let (a, b, c) = i.abc();
-    let a = a + 1;
-    let b = b + 1;
-    if vm.to_boolean(b) == (c != 0) {
-        vm.copy(b, a);
+    if vm.reg_truth(b) == (c != 0) {
+        vm.move_reg(a, b);
     } else {
         vm.add_pc(1);
     }
@@ -233,7 +223,7 @@ pub fn test_set(i: u32, vm: &mut dyn LuaVM) {
 */
 pub fn test(i: u32, vm: &mut dyn LuaVM) {
     let (a, _, c) = i.abc();
-    if vm.to_boolean(a + 1) != (c != 0) {
+    if vm.reg_truth(a) != (c != 0) {
         vm.add_pc(1);
     }
 }
\ No newline at end of file
@@ -66,11 +66,8 @@ pub fn new_table(i: u32, vm: &mut dyn LuaVM) {
 */
 pub fn get_table(i: u32, vm: &mut dyn LuaVM) {
     let (a, b, c) = i.abc();
-    let a = a + 1;
-    let b = b + 1;
-    vm.get_rk(c);
-    vm.get_table(b);
-    vm.replace(a);
+    vm.get_table_rk(b + 1, c);
+    vm.replace(a + 1);
 }
 
 /*          SET_TABLE Instruction
@@ -93,9 +90,7 @@ pub fn get_table(i: u32, vm: &mut dyn LuaVM) {
 */
 pub fn set_table(i: u32, vm: &mut dyn LuaVM) {
     let (a, b, c) = i.abc();
-    vm.get_rk(b);
-    vm.get_rk(c);
-    vm.set_table(a + 1);
+    vm.set_table_rk(a + 1, b, c);
 }
 
 /*          SET_LIST Instruction
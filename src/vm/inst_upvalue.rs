@@ -1,12 +1,32 @@
-
 use crate::api::LuaVM;
 use super::instruction::Instruction;
 
+// R(A) := UpValue[B]
+pub fn get_upval(i: u32, vm: &mut dyn LuaVM) {
+    let (a, b, _) = i.abc();
+    vm.load_upval(a, b);
+}
+
+// UpValue[B] := R(A)
+pub fn set_upval(i: u32, vm: &mut dyn LuaVM) {
+    let (a, b, _) = i.abc();
+    vm.push_value(a + 1);
+    vm.set_upval(b);
+}
+
+// R(A) := UpValue[B][RK(C)]
 pub fn get_tab_up(i: u32, vm: &mut dyn LuaVM) {
-    let (a, _, c) = i.abc();
-    vm.push_global_table();
-    vm.get_rk(c);
-    vm.get_table(-2);
+    let (a, b, c) = i.abc();
+    vm.get_upval(b);
+    vm.get_table_rk(-1, c);
     vm.replace(a + 1);
     vm.pop(1);
 }
+
+// UpValue[A][RK(B)] := RK(C)
+pub fn set_tab_up(i: u32, vm: &mut dyn LuaVM) {
+    let (a, b, c) = i.abc();
+    vm.get_upval(a);
+    vm.set_table_rk(-1, b, c);
+    vm.pop(1);
+}
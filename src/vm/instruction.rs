@@ -97,11 +97,11 @@ impl Instruction for u32 {
             OP_LOADKX => load_kx(self, vm),
             OP_LOADBOOL => load_bool(self, vm),
             OP_LOADNIL => load_nil(self, vm),
-            // OP_GETUPVAL => (),
+            OP_GETUPVAL => get_upval(self, vm),
             OP_GETTABUP => get_tab_up(self, vm),
             OP_GETTABLE => get_table(self, vm),
-            // OP_SETTABUP => (),
-            // OP_SETUPVAL => (),
+            OP_SETTABUP => set_tab_up(self, vm),
+            OP_SETUPVAL => set_upval(self, vm),
             OP_SETTABLE => set_table(self, vm),
             OP_NEWTABLE => new_table(self, vm),
             OP_SELF => self_(self, vm),
@@ -133,8 +133,8 @@ impl Instruction for u32 {
             OP_RETURN => return_(self, vm),
             OP_FORLOOP => for_loop(self, vm),
             OP_FORPREP => for_prep(self, vm),
-            // OP_TFORCALL => (),
-            // OP_TFORLOOP => (),
+            OP_TFORCALL => tfor_call(self, vm),
+            OP_TFORLOOP => tfor_loop(self, vm),
             OP_SETLIST => set_list(self, vm),
             OP_CLOSURE => closure(self, vm),
             OP_VARARG => vararg(self, vm),
@@ -151,7 +151,8 @@ impl Instruction for u32 {
 mod tests {
     use crate::state;
     use crate::binary::{chunk, undump};
-    use crate::api::LuaAPI;
+    use crate::api::consts::*;
+    use crate::api::{LuaAPI, LuaAuxLib};
     use crate::LuaState;
     use crate::binary::reader::tests::LUA_FOR_LOOP;
     use super::*;
@@ -341,6 +342,448 @@ mod tests {
       ];
 
 
+    /* Lua source code:
+        local mt = {
+            __index = function(t, k) return k .. "!" end,
+            __add = function(a, b) return 40 + b end,
+            __call = function(self, x) return x * 2 end,
+            __len = function() return 7 end,
+        }
+        local v = setmetatable({}, mt)
+        return v.foo .. (v + 2) .. v(5) .. #v
+    */
+    const LUA_META_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x0a, 0x40,
+        0x6d, 0x65, 0x74, 0x61, 0x2e, 0x6c, 0x75, 0x61, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x06, 0x16, 0x00, 0x00, 0x00, 0x0b,
+        0x00, 0x01, 0x00, 0x6c, 0x00, 0x00, 0x00, 0x0a, 0x40, 0x00, 0x80, 0x6c,
+        0x40, 0x00, 0x00, 0x0a, 0x40, 0x80, 0x80, 0x6c, 0x80, 0x00, 0x00, 0x0a,
+        0x40, 0x00, 0x81, 0x6c, 0xc0, 0x00, 0x00, 0x0a, 0x40, 0x80, 0x81, 0x46,
+        0x00, 0x41, 0x00, 0x8b, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00, 0x64,
+        0x80, 0x80, 0x01, 0x87, 0x40, 0xc1, 0x00, 0xcd, 0x80, 0xc1, 0x00, 0x00,
+        0x01, 0x80, 0x00, 0x41, 0xc1, 0x01, 0x00, 0x24, 0x81, 0x00, 0x01, 0x5c,
+        0x01, 0x80, 0x00, 0x9d, 0x40, 0x01, 0x01, 0xa6, 0x00, 0x00, 0x01, 0x26,
+        0x00, 0x80, 0x00, 0x08, 0x00, 0x00, 0x00, 0x04, 0x08, 0x5f, 0x5f, 0x69,
+        0x6e, 0x64, 0x65, 0x78, 0x04, 0x06, 0x5f, 0x5f, 0x61, 0x64, 0x64, 0x04,
+        0x07, 0x5f, 0x5f, 0x63, 0x61, 0x6c, 0x6c, 0x04, 0x06, 0x5f, 0x5f, 0x6c,
+        0x65, 0x6e, 0x04, 0x0d, 0x73, 0x65, 0x74, 0x6d, 0x65, 0x74, 0x61, 0x74,
+        0x61, 0x62, 0x6c, 0x65, 0x04, 0x04, 0x66, 0x6f, 0x6f, 0x13, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x05, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x00, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x04, 0x05, 0x00, 0x00, 0x00, 0x80, 0x00, 0x80, 0x00, 0xc1, 0x00, 0x00,
+        0x00, 0x9d, 0xc0, 0x00, 0x01, 0xa6, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x02, 0x21, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x74, 0x00, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x02, 0x6b, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x02, 0x00, 0x03, 0x03, 0x00, 0x00, 0x00,
+        0x8d, 0x40, 0x00, 0x80, 0xa6, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x13, 0x28, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x61, 0x00, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x02, 0x62, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x03, 0x03, 0x00, 0x00, 0x00, 0x8f, 0x00, 0xc0,
+        0x00, 0xa6, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x13, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x05, 0x73, 0x65, 0x6c, 0x66, 0x00, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x02, 0x78, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x26, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x13, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x6d, 0x74, 0x09, 0x00, 0x00,
+        0x00, 0x16, 0x00, 0x00, 0x00, 0x02, 0x76, 0x0d, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
+
+    /* Lua source code:
+        local t
+        return t.x
+    */
+    const LUA_ERROR_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x09, 0x40,
+        0x65, 0x72, 0x72, 0x2e, 0x6c, 0x75, 0x61, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x47, 0x00, 0x40, 0x00, 0x66, 0x00, 0x00, 0x01, 0x26, 0x00,
+        0x80, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x02, 0x78, 0x01, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x74, 0x01, 0x00, 0x00,
+        0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45,
+        0x4e, 0x56,
+    ];
+
+    /* Lua source code:
+        local mode = ...
+        local p = {add = add}
+        if mode == 1 then
+          return add(1, {})
+        elseif mode == 2 then
+          return p:add(2)
+        elseif mode == 3 then
+          return p.add(1.5, 2)
+        end
+        return add(3, 4.0) + p.add(1, 2)
+    */
+    const LUA_AUX_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x09, 0x40,
+        0x61, 0x75, 0x78, 0x2e, 0x6c, 0x75, 0x61, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x06, 0x25, 0x00, 0x00, 0x00, 0x2d, 0x00,
+        0x00, 0x01, 0x4b, 0x40, 0x00, 0x00, 0x86, 0x00, 0x40, 0x00, 0x4a, 0x80,
+        0x00, 0x80, 0x1f, 0x40, 0x40, 0x00, 0x1e, 0x40, 0x01, 0x80, 0x86, 0x00,
+        0x40, 0x00, 0xc1, 0x40, 0x00, 0x00, 0x0b, 0x01, 0x00, 0x00, 0xa5, 0x00,
+        0x80, 0x01, 0xa6, 0x00, 0x00, 0x00, 0x1e, 0x40, 0x03, 0x80, 0x1f, 0x80,
+        0x40, 0x00, 0x1e, 0x00, 0x01, 0x80, 0x8c, 0x00, 0xc0, 0x00, 0x01, 0x81,
+        0x00, 0x00, 0xa5, 0x00, 0x80, 0x01, 0xa6, 0x00, 0x00, 0x00, 0x1e, 0x80,
+        0x01, 0x80, 0x1f, 0xc0, 0x40, 0x00, 0x1e, 0x00, 0x01, 0x80, 0x87, 0x00,
+        0xc0, 0x00, 0xc1, 0x00, 0x01, 0x00, 0x01, 0x81, 0x00, 0x00, 0xa5, 0x00,
+        0x80, 0x01, 0xa6, 0x00, 0x00, 0x00, 0x86, 0x00, 0x40, 0x00, 0xc1, 0xc0,
+        0x00, 0x00, 0x01, 0x41, 0x01, 0x00, 0xa4, 0x80, 0x80, 0x01, 0xc7, 0x00,
+        0xc0, 0x00, 0x01, 0x41, 0x00, 0x00, 0x41, 0x81, 0x00, 0x00, 0xe4, 0x80,
+        0x80, 0x01, 0x8d, 0xc0, 0x00, 0x01, 0xa6, 0x00, 0x00, 0x01, 0x26, 0x00,
+        0x80, 0x00, 0x06, 0x00, 0x00, 0x00, 0x04, 0x04, 0x61, 0x64, 0x64, 0x13,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x02, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x3f, 0x03,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x40, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00,
+        0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, 0x6d, 0x6f, 0x64, 0x65, 0x01,
+        0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x02, 0x70, 0x04, 0x00, 0x00,
+        0x00, 0x25, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45,
+        0x4e, 0x56,
+    ];
+
+
+    /* Lua source code:
+        local function get(t) return t.x end
+        local function set(t, v) t.x = v end
+        local out = {}
+        local function put(v) out[#out + 1] = tostring(v) end
+
+        local a, b = {w = 0, x = 1, y = 2}, {y = 3, x = 4}
+        put(get(a)); put(get(b)); put(get(a))
+        set(a, 5); set(b, 6); put(get(a) + get(b))
+
+        -- a deleted field
+        set(a, nil); put(get(a))
+        set(a, 7); put(get(a))
+
+        -- a rehash, which moves x to another node
+        a.w = nil
+        for i = 1, 20 do a["k" .. i] = i end
+        for i = 1, 20 do a["k" .. i] = nil end
+        a.z = 0
+        put(get(a)); set(a, 8); put(a.x)
+
+        -- metatable changes
+        local c = {}
+        put(get(c))
+        setmetatable(c, {__index = {x = "idx"}})
+        put(get(c))
+        set(c, "own"); put(get(c))
+        set(c, nil)
+        setmetatable(c, {__newindex = function(t, k, v) rawset(t, k, v .. "!") end})
+        set(c, "new"); put(get(c))
+        set(c, "old"); put(get(c))
+
+        -- methods and globals
+        local obj = {n = 0}
+        function obj:inc() self.n = self.n + 1 return self end
+        obj:inc():inc()
+        put(obj.n)
+        g = 1; g = g + 1; put(g)
+
+        -- clearing fields while traversing
+        local n = 0
+        for k in pairs(a) do a[k] = nil; n = n + 1 end
+        local arr = {1, 2, 3, p = 4}
+        for k in pairs(arr) do arr[k] = nil end
+        put(n); put(next(a)); put(next(arr))
+        return table.concat(out, " ")
+    */
+    const LUA_CACHE_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x0c, 0x40,
+        0x63, 0x61, 0x63, 0x68, 0x65, 0x2e, 0x6c, 0x75, 0x61, 0x63, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x10, 0xd0, 0x00, 0x00,
+        0x00, 0x2c, 0x00, 0x00, 0x00, 0x6c, 0x40, 0x00, 0x00, 0x8b, 0x00, 0x00,
+        0x00, 0xec, 0x80, 0x00, 0x00, 0x0b, 0xc1, 0x00, 0x00, 0x0a, 0x41, 0x40,
+        0x80, 0x0a, 0xc1, 0x40, 0x81, 0x0a, 0x41, 0x41, 0x82, 0x4b, 0x81, 0x00,
+        0x00, 0x4a, 0x81, 0x41, 0x82, 0x4a, 0xc1, 0x41, 0x81, 0x80, 0x01, 0x80,
+        0x01, 0xc0, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0xe4, 0x01, 0x00,
+        0x01, 0xa4, 0x41, 0x00, 0x00, 0x80, 0x01, 0x80, 0x01, 0xc0, 0x01, 0x00,
+        0x00, 0x00, 0x02, 0x80, 0x02, 0xe4, 0x01, 0x00, 0x01, 0xa4, 0x41, 0x00,
+        0x00, 0x80, 0x01, 0x80, 0x01, 0xc0, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x02, 0xe4, 0x01, 0x00, 0x01, 0xa4, 0x41, 0x00, 0x00, 0x80, 0x01, 0x80,
+        0x00, 0xc0, 0x01, 0x00, 0x02, 0x01, 0x02, 0x02, 0x00, 0xa4, 0x41, 0x80,
+        0x01, 0x80, 0x01, 0x80, 0x00, 0xc0, 0x01, 0x80, 0x02, 0x01, 0x42, 0x02,
+        0x00, 0xa4, 0x41, 0x80, 0x01, 0x80, 0x01, 0x80, 0x01, 0xc0, 0x01, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x02, 0xe4, 0x81, 0x00, 0x01, 0x00, 0x02, 0x00,
+        0x00, 0x40, 0x02, 0x80, 0x02, 0x24, 0x82, 0x00, 0x01, 0xcd, 0x01, 0x82,
+        0x03, 0xa4, 0x41, 0x00, 0x01, 0x80, 0x01, 0x80, 0x00, 0xc0, 0x01, 0x00,
+        0x02, 0x04, 0x02, 0x00, 0x00, 0xa4, 0x41, 0x80, 0x01, 0x80, 0x01, 0x80,
+        0x01, 0xc0, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0xe4, 0x01, 0x00,
+        0x01, 0xa4, 0x41, 0x00, 0x00, 0x80, 0x01, 0x80, 0x00, 0xc0, 0x01, 0x00,
+        0x02, 0x01, 0x82, 0x02, 0x00, 0xa4, 0x41, 0x80, 0x01, 0x80, 0x01, 0x80,
+        0x01, 0xc0, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0xe4, 0x01, 0x00,
+        0x01, 0xa4, 0x41, 0x00, 0x00, 0x0a, 0xc1, 0x42, 0x80, 0x81, 0xc1, 0x00,
+        0x00, 0xc1, 0x01, 0x03, 0x00, 0x01, 0xc2, 0x00, 0x00, 0xa8, 0xc1, 0x00,
+        0x80, 0x81, 0x42, 0x03, 0x00, 0xc0, 0x02, 0x80, 0x04, 0x9d, 0xc2, 0x02,
+        0x05, 0x0a, 0x41, 0x02, 0x05, 0xa7, 0x81, 0xfe, 0x7f, 0x81, 0xc1, 0x00,
+        0x00, 0xc1, 0x01, 0x03, 0x00, 0x01, 0xc2, 0x00, 0x00, 0xa8, 0xc1, 0x00,
+        0x80, 0x81, 0x42, 0x03, 0x00, 0xc0, 0x02, 0x80, 0x04, 0x9d, 0xc2, 0x02,
+        0x05, 0x0a, 0xc1, 0x42, 0x05, 0xa7, 0x81, 0xfe, 0x7f, 0x0a, 0x41, 0x40,
+        0x87, 0x80, 0x01, 0x80, 0x01, 0xc0, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x02, 0xe4, 0x01, 0x00, 0x01, 0xa4, 0x41, 0x00, 0x00, 0x80, 0x01, 0x80,
+        0x00, 0xc0, 0x01, 0x00, 0x02, 0x01, 0xc2, 0x03, 0x00, 0xa4, 0x41, 0x80,
+        0x01, 0x80, 0x01, 0x80, 0x01, 0xc7, 0x81, 0x40, 0x02, 0xa4, 0x41, 0x00,
+        0x01, 0x8b, 0x01, 0x00, 0x00, 0xc0, 0x01, 0x80, 0x01, 0x00, 0x02, 0x00,
+        0x00, 0x40, 0x02, 0x00, 0x03, 0x24, 0x02, 0x00, 0x01, 0xe4, 0x41, 0x00,
+        0x00, 0xc6, 0x01, 0x44, 0x00, 0x00, 0x02, 0x00, 0x03, 0x4b, 0x42, 0x00,
+        0x00, 0x8b, 0x42, 0x00, 0x00, 0x8a, 0x82, 0x44, 0x81, 0x4a, 0x82, 0x82,
+        0x88, 0xe4, 0x41, 0x80, 0x01, 0xc0, 0x01, 0x80, 0x01, 0x00, 0x02, 0x00,
+        0x00, 0x40, 0x02, 0x00, 0x03, 0x24, 0x02, 0x00, 0x01, 0xe4, 0x41, 0x00,
+        0x00, 0xc0, 0x01, 0x80, 0x00, 0x00, 0x02, 0x00, 0x03, 0x41, 0xc2, 0x04,
+        0x00, 0xe4, 0x41, 0x80, 0x01, 0xc0, 0x01, 0x80, 0x01, 0x00, 0x02, 0x00,
+        0x00, 0x40, 0x02, 0x00, 0x03, 0x24, 0x02, 0x00, 0x01, 0xe4, 0x41, 0x00,
+        0x00, 0xc0, 0x01, 0x80, 0x00, 0x00, 0x02, 0x00, 0x03, 0x44, 0x02, 0x00,
+        0x00, 0xe4, 0x41, 0x80, 0x01, 0xc6, 0x01, 0x44, 0x00, 0x00, 0x02, 0x00,
+        0x03, 0x4b, 0x42, 0x00, 0x00, 0xac, 0xc2, 0x00, 0x00, 0x4a, 0x82, 0x02,
+        0x8a, 0xe4, 0x41, 0x80, 0x01, 0xc0, 0x01, 0x80, 0x00, 0x00, 0x02, 0x00,
+        0x03, 0x41, 0x42, 0x05, 0x00, 0xe4, 0x41, 0x80, 0x01, 0xc0, 0x01, 0x80,
+        0x01, 0x00, 0x02, 0x00, 0x00, 0x40, 0x02, 0x00, 0x03, 0x24, 0x02, 0x00,
+        0x01, 0xe4, 0x41, 0x00, 0x00, 0xc0, 0x01, 0x80, 0x00, 0x00, 0x02, 0x00,
+        0x03, 0x41, 0x82, 0x05, 0x00, 0xe4, 0x41, 0x80, 0x01, 0xc0, 0x01, 0x80,
+        0x01, 0x00, 0x02, 0x00, 0x00, 0x40, 0x02, 0x00, 0x03, 0x24, 0x02, 0x00,
+        0x01, 0xe4, 0x41, 0x00, 0x00, 0xcb, 0x41, 0x00, 0x00, 0xca, 0x41, 0xc0,
+        0x8b, 0x2c, 0x02, 0x01, 0x00, 0xca, 0x01, 0x02, 0x8c, 0x0c, 0x02, 0xc6,
+        0x03, 0x24, 0x82, 0x00, 0x01, 0x0c, 0x02, 0x46, 0x04, 0x24, 0x42, 0x00,
+        0x01, 0x00, 0x02, 0x80, 0x01, 0x47, 0x42, 0xc6, 0x03, 0x24, 0x42, 0x00,
+        0x01, 0x08, 0xc0, 0x46, 0x8d, 0x06, 0x82, 0x46, 0x00, 0x0d, 0xc2, 0x46,
+        0x04, 0x08, 0x00, 0x02, 0x8d, 0x00, 0x02, 0x80, 0x01, 0x46, 0x82, 0x46,
+        0x00, 0x24, 0x42, 0x00, 0x01, 0x01, 0x42, 0x00, 0x00, 0x46, 0x02, 0x47,
+        0x00, 0x80, 0x02, 0x00, 0x02, 0x64, 0x02, 0x01, 0x01, 0x1e, 0x40, 0x00,
+        0x80, 0x0a, 0xc1, 0x42, 0x06, 0x0d, 0xc2, 0x46, 0x04, 0x69, 0x42, 0x00,
+        0x00, 0xea, 0xc2, 0xfe, 0x7f, 0x4b, 0x42, 0x80, 0x01, 0x81, 0xc2, 0x06,
+        0x00, 0xc1, 0x42, 0x01, 0x00, 0x01, 0x83, 0x01, 0x00, 0x4a, 0xc2, 0xc1,
+        0x8e, 0x6b, 0x42, 0x80, 0x01, 0x86, 0x02, 0x47, 0x00, 0xc0, 0x02, 0x80,
+        0x04, 0xa4, 0x02, 0x01, 0x01, 0x1e, 0x00, 0x00, 0x80, 0x4a, 0xc2, 0xc2,
+        0x06, 0xa9, 0x42, 0x00, 0x00, 0x2a, 0x03, 0xff, 0x7f, 0x80, 0x02, 0x80,
+        0x01, 0xc0, 0x02, 0x00, 0x04, 0xa4, 0x42, 0x00, 0x01, 0x80, 0x02, 0x80,
+        0x01, 0xc6, 0x82, 0x47, 0x00, 0x00, 0x03, 0x00, 0x02, 0xe4, 0x02, 0x00,
+        0x01, 0xa4, 0x42, 0x00, 0x00, 0x80, 0x02, 0x80, 0x01, 0xc6, 0x82, 0x47,
+        0x00, 0x00, 0x03, 0x80, 0x04, 0xe4, 0x02, 0x00, 0x01, 0xa4, 0x42, 0x00,
+        0x00, 0x86, 0xc2, 0x47, 0x00, 0x87, 0x02, 0x48, 0x05, 0xc0, 0x02, 0x00,
+        0x01, 0x01, 0x43, 0x08, 0x00, 0xa5, 0x02, 0x80, 0x01, 0xa6, 0x02, 0x00,
+        0x00, 0x26, 0x00, 0x80, 0x00, 0x22, 0x00, 0x00, 0x00, 0x04, 0x02, 0x77,
+        0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x78,
+        0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x79,
+        0x13, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x04, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x13, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x13, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x14, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x02, 0x6b, 0x04, 0x02, 0x7a, 0x13, 0x08,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x0d, 0x73, 0x65, 0x74,
+        0x6d, 0x65, 0x74, 0x61, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x04, 0x08, 0x5f,
+        0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x04, 0x04, 0x69, 0x64, 0x78, 0x04,
+        0x04, 0x6f, 0x77, 0x6e, 0x04, 0x0b, 0x5f, 0x5f, 0x6e, 0x65, 0x77, 0x69,
+        0x6e, 0x64, 0x65, 0x78, 0x04, 0x04, 0x6e, 0x65, 0x77, 0x04, 0x04, 0x6f,
+        0x6c, 0x64, 0x04, 0x02, 0x6e, 0x04, 0x04, 0x69, 0x6e, 0x63, 0x04, 0x02,
+        0x6e, 0x04, 0x02, 0x67, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x06, 0x70, 0x61, 0x69, 0x72, 0x73, 0x04, 0x02, 0x70, 0x04,
+        0x05, 0x6e, 0x65, 0x78, 0x74, 0x04, 0x06, 0x74, 0x61, 0x62, 0x6c, 0x65,
+        0x04, 0x07, 0x63, 0x6f, 0x6e, 0x63, 0x61, 0x74, 0x04, 0x02, 0x20, 0x01,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x03, 0x00, 0x00,
+        0x00, 0x47, 0x00, 0x40, 0x00, 0x66, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x02, 0x78, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x02, 0x74, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x0a, 0x40, 0x00, 0x80, 0x26, 0x00,
+        0x80, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x02, 0x78, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x74, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x76, 0x00, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x08, 0x00, 0x00,
+        0x00, 0x45, 0x00, 0x00, 0x00, 0x5c, 0x00, 0x80, 0x00, 0x4d, 0x00, 0xc0,
+        0x00, 0x86, 0x40, 0xc0, 0x00, 0xc0, 0x00, 0x00, 0x00, 0xa4, 0x80, 0x00,
+        0x01, 0x08, 0x80, 0x80, 0x00, 0x26, 0x00, 0x80, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x09,
+        0x74, 0x6f, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x02, 0x00, 0x00, 0x00,
+        0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x02, 0x76, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x04, 0x6f, 0x75, 0x74, 0x05, 0x5f, 0x45, 0x4e, 0x56, 0x00,
+        0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x03, 0x00, 0x08, 0x08,
+        0x00, 0x00, 0x00, 0xc6, 0x00, 0x40, 0x00, 0x00, 0x01, 0x00, 0x00, 0x40,
+        0x01, 0x80, 0x00, 0x80, 0x01, 0x00, 0x01, 0xc1, 0x41, 0x00, 0x00, 0x9d,
+        0xc1, 0x01, 0x03, 0xe4, 0x40, 0x00, 0x02, 0x26, 0x00, 0x80, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x04, 0x07, 0x72, 0x61, 0x77, 0x73, 0x65, 0x74, 0x04,
+        0x02, 0x21, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x02, 0x74, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x02, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x02, 0x76, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56, 0x00, 0x22, 0x00, 0x00, 0x00,
+        0x22, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x05, 0x00, 0x00, 0x00, 0x47,
+        0x00, 0x40, 0x00, 0x4d, 0x40, 0xc0, 0x00, 0x0a, 0x40, 0x00, 0x80, 0x26,
+        0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04,
+        0x02, 0x6e, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x22,
+        0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05,
+        0x73, 0x65, 0x6c, 0x66, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x07, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+        0x0f, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0x10, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00,
+        0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00,
+        0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00,
+        0x11, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00,
+        0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00,
+        0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00,
+        0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00,
+        0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00,
+        0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00,
+        0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00,
+        0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00,
+        0x19, 0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00,
+        0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00,
+        0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00,
+        0x1a, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00,
+        0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x1b, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00,
+        0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00,
+        0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00,
+        0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x1d, 0x00, 0x00, 0x00,
+        0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00,
+        0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00,
+        0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x00, 0x00,
+        0x21, 0x00, 0x00, 0x00, 0x21, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00,
+        0x22, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00,
+        0x23, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00,
+        0x24, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00,
+        0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00,
+        0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00,
+        0x28, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00,
+        0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00,
+        0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00, 0x29, 0x00, 0x00, 0x00,
+        0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00,
+        0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00,
+        0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00,
+        0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00,
+        0x2b, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00,
+        0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00,
+        0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00,
+        0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00,
+        0x2c, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00,
+        0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00,
+        0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00, 0x2d, 0x00, 0x00, 0x00,
+        0x1a, 0x00, 0x00, 0x00, 0x04, 0x67, 0x65, 0x74, 0x01, 0x00, 0x00, 0x00,
+        0xd0, 0x00, 0x00, 0x00, 0x04, 0x73, 0x65, 0x74, 0x02, 0x00, 0x00, 0x00,
+        0xd0, 0x00, 0x00, 0x00, 0x04, 0x6f, 0x75, 0x74, 0x03, 0x00, 0x00, 0x00,
+        0xd0, 0x00, 0x00, 0x00, 0x04, 0x70, 0x75, 0x74, 0x04, 0x00, 0x00, 0x00,
+        0xd0, 0x00, 0x00, 0x00, 0x02, 0x61, 0x0b, 0x00, 0x00, 0x00, 0xd0, 0x00,
+        0x00, 0x00, 0x02, 0x62, 0x0b, 0x00, 0x00, 0x00, 0xd0, 0x00, 0x00, 0x00,
+        0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x29,
+        0x41, 0x00, 0x00, 0x00, 0x47, 0x00, 0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f,
+        0x72, 0x20, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x29, 0x41, 0x00, 0x00, 0x00,
+        0x47, 0x00, 0x00, 0x00, 0x0b, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74,
+        0x65, 0x70, 0x29, 0x41, 0x00, 0x00, 0x00, 0x47, 0x00, 0x00, 0x00, 0x02,
+        0x69, 0x42, 0x00, 0x00, 0x00, 0x46, 0x00, 0x00, 0x00, 0x0c, 0x28, 0x66,
+        0x6f, 0x72, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x29, 0x4a, 0x00, 0x00,
+        0x00, 0x50, 0x00, 0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x6c,
+        0x69, 0x6d, 0x69, 0x74, 0x29, 0x4a, 0x00, 0x00, 0x00, 0x50, 0x00, 0x00,
+        0x00, 0x0b, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74, 0x65, 0x70, 0x29,
+        0x4a, 0x00, 0x00, 0x00, 0x50, 0x00, 0x00, 0x00, 0x02, 0x69, 0x4b, 0x00,
+        0x00, 0x00, 0x4f, 0x00, 0x00, 0x00, 0x02, 0x63, 0x5e, 0x00, 0x00, 0x00,
+        0xd0, 0x00, 0x00, 0x00, 0x04, 0x6f, 0x62, 0x6a, 0x96, 0x00, 0x00, 0x00,
+        0xd0, 0x00, 0x00, 0x00, 0x02, 0x6e, 0xa7, 0x00, 0x00, 0x00, 0xd0, 0x00,
+        0x00, 0x00, 0x10, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65,
+        0x72, 0x61, 0x74, 0x6f, 0x72, 0x29, 0xaa, 0x00, 0x00, 0x00, 0xaf, 0x00,
+        0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74, 0x61, 0x74,
+        0x65, 0x29, 0xaa, 0x00, 0x00, 0x00, 0xaf, 0x00, 0x00, 0x00, 0x0e, 0x28,
+        0x66, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x29,
+        0xaa, 0x00, 0x00, 0x00, 0xaf, 0x00, 0x00, 0x00, 0x02, 0x6b, 0xab, 0x00,
+        0x00, 0x00, 0xad, 0x00, 0x00, 0x00, 0x04, 0x61, 0x72, 0x72, 0xb5, 0x00,
+        0x00, 0x00, 0xd0, 0x00, 0x00, 0x00, 0x10, 0x28, 0x66, 0x6f, 0x72, 0x20,
+        0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x29, 0xb8, 0x00,
+        0x00, 0x00, 0xbc, 0x00, 0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20,
+        0x73, 0x74, 0x61, 0x74, 0x65, 0x29, 0xb8, 0x00, 0x00, 0x00, 0xbc, 0x00,
+        0x00, 0x00, 0x0e, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74,
+        0x72, 0x6f, 0x6c, 0x29, 0xb8, 0x00, 0x00, 0x00, 0xbc, 0x00, 0x00, 0x00,
+        0x02, 0x6b, 0xb9, 0x00, 0x00, 0x00, 0xba, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
+
     #[test]
     fn test_forloop() {
         let proto = undump(LUA_FOR_LOOP.to_vec());
@@ -363,7 +806,7 @@ mod tests {
         let ls = Rc::new(RefCell::new(LuaState::new()));
         ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
 
-        ls.borrow_mut().load(data, "dummy", "b");
+        ls.borrow_mut().load(data, "dummy", "b", None);
         ls.borrow_mut().call(0, 0);
     }
 
@@ -374,10 +817,309 @@ mod tests {
         ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
 
         ls.borrow_mut().register("print", _print); // Register print function
-        ls.borrow_mut().load(data, "chunk", "b");
+        ls.borrow_mut().load(data, "chunk", "b", None);
         ls.borrow_mut().call(0, 0);
     }
 
+    #[test]
+    fn test_metamethods() {
+        let data = LUA_META_CHUNK.to_vec();
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+
+        ls.borrow_mut().register("setmetatable", _setmetatable);
+        ls.borrow_mut().load(data, "chunk", "b", None);
+        ls.borrow_mut().call(0, 1);
+        assert_eq!(ls.borrow().to_string(-1), "foo!42107");
+    }
+
+    #[test]
+    fn test_runtime_error() {
+        let data = LUA_ERROR_CHUNK.to_vec();
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+
+        ls.borrow_mut().load(data, "chunk", "b", None);
+        let status = ls.borrow_mut().pcall(0, 1, 0);
+        assert_eq!(status, crate::api::consts::LUA_ERRRUN);
+        assert_eq!(ls.borrow().to_string(-1), "err.lua:2: attempt to index a nil value");
+        assert_eq!(ls.borrow().get_top(), 1);
+    }
+
+    #[test]
+    fn test_arg_error() {
+        let ls = Rc::new(RefCell::new(LuaState::new()));
+        ls.borrow_mut().stack_mut().state = Some(Rc::downgrade(&ls));
+        let mut ls = ls.borrow_mut();
+        ls.register("add", _add);
+
+        let mut run = |mode: i64| {
+            ls.load(LUA_AUX_CHUNK.to_vec(), "chunk", "b", None);
+            ls.push_integer(mode);
+            let status = ls.pcall(1, 1, 0);
+            let ret = ls.to_string(-1);
+            ls.pop(1);
+            (status, ret)
+        };
+        assert_eq!(run(0), (LUA_OK, "10".to_string()));
+        assert_eq!(
+            run(1),
+            (LUA_ERRRUN, "aux.lua:4: bad argument #2 to 'add' (number expected, got table)".to_string())
+        );
+        assert_eq!(
+            run(2),
+            (LUA_ERRRUN, "aux.lua:6: calling 'add' on bad self (number expected, got table)".to_string())
+        );
+        assert_eq!(
+            run(3),
+            (LUA_ERRRUN, "aux.lua:8: bad argument #1 to 'add' (number has no integer representation)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_field_cache() {
+        let lua = crate::Lua::new();
+        let mut ls = lua.state();
+        ls.load(LUA_CACHE_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 1, 0);
+        assert_eq!(ls.to_string(-1), "1 4 1 11 nil 7 7 8 nil idx own new! old 2 2 3 nil nil");
+        assert_eq!(status, LUA_OK);
+    }
+
+    #[test]
+    fn test_arith_compare() {
+        let lua = crate::Lua::new();
+        let mut ls = lua.state();
+        ls.load(LUA_ARITH_CHUNK.to_vec(), "chunk", "b", None);
+        let status = ls.pcall(0, 1, 0);
+        assert_eq!(
+            ls.to_string(-1),
+            "chunk:11: attempt to perform 'n//0' chunk:11: attempt to perform 'n%0' \
+             -4 -1 -4 1 inf true -2 true 9.5 2.0 2.0 integer float true true true true false \
+             false true false false true false add add true false true false"
+        );
+        assert_eq!(status, LUA_OK);
+    }
+
+    /* Lua source code:
+        local out = {}
+        local function put(...)
+          for k = 1, select("#", ...) do out[#out + 1] = tostring((select(k, ...))) end
+        end
+        local function err(f)
+          local ok, e = pcall(f)
+          return e
+        end
+
+        local i, z, f, big = 7, 0, 2.5, math.maxinteger
+        put(err(function() return i // z end), err(function() return i % z end))
+        put(i // -2, i % -2, -i // 2, -i % 2, i / z)
+        put(big + 1 == math.mininteger, big * 2, -math.mininteger == math.mininteger)
+        put(i + f, i // f, i % f, math.type(i * 2), math.type(i * 1.0), i == 7.0)
+        put(i < 7.5, 7.0 <= i, 1 < f, f <= 2)
+        local nan = z / z
+        put(nan == nan, nan ~= nan, nan < 1, nan <= nan, not (nan < 1), 1 > nan)
+        local t = setmetatable({}, {
+          __add = function(a, b) return "add" end,
+          __lt = function(a, b) return a == 7 end,
+        })
+        put(t + i, i + t, i < t, t < i, i <= t, t == i)
+        return table.concat(out, " ")
+    */
+    const LUA_ARITH_CHUNK: &[u8] = &[
+        0x1b, 0x4c, 0x75, 0x61, 0x53, 0x00, 0x19, 0x93, 0x0d, 0x0a, 0x1a, 0x0a,
+        0x04, 0x08, 0x04, 0x08, 0x08, 0x78, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x77, 0x40, 0x01, 0x07, 0x40,
+        0x63, 0x68, 0x75, 0x6e, 0x6b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x10, 0x8d, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+        0x6c, 0x00, 0x00, 0x00, 0xac, 0x40, 0x00, 0x00, 0xc1, 0x00, 0x00, 0x00,
+        0x01, 0x41, 0x00, 0x00, 0x41, 0x81, 0x00, 0x00, 0x86, 0xc1, 0x40, 0x00,
+        0x87, 0x01, 0x41, 0x03, 0xc0, 0x01, 0x80, 0x00, 0x00, 0x02, 0x00, 0x01,
+        0x6c, 0x82, 0x00, 0x00, 0x24, 0x82, 0x00, 0x01, 0x40, 0x02, 0x00, 0x01,
+        0xac, 0xc2, 0x00, 0x00, 0x64, 0x02, 0x00, 0x01, 0xe4, 0x41, 0x00, 0x00,
+        0xc0, 0x01, 0x80, 0x00, 0x13, 0x42, 0xc1, 0x01, 0x50, 0x42, 0xc1, 0x01,
+        0x99, 0x02, 0x80, 0x01, 0x93, 0x82, 0x41, 0x05, 0xd9, 0x02, 0x80, 0x01,
+        0xd0, 0x82, 0xc1, 0x05, 0x12, 0x03, 0x81, 0x01, 0xe4, 0x41, 0x00, 0x03,
+        0xc0, 0x01, 0x80, 0x00, 0x0d, 0xc2, 0x41, 0x03, 0x46, 0xc2, 0x40, 0x00,
+        0x47, 0x02, 0xc2, 0x04, 0x5f, 0x40, 0x02, 0x04, 0x1e, 0x00, 0x00, 0x80,
+        0x03, 0x42, 0x00, 0x00, 0x03, 0x02, 0x80, 0x00, 0x4f, 0x82, 0x41, 0x03,
+        0x86, 0xc2, 0x40, 0x00, 0x87, 0x02, 0x42, 0x05, 0x99, 0x02, 0x00, 0x05,
+        0xc6, 0xc2, 0x40, 0x00, 0xc7, 0x02, 0xc2, 0x05, 0x5f, 0xc0, 0x02, 0x05,
+        0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00, 0x83, 0x02, 0x80, 0x00,
+        0xe4, 0x41, 0x00, 0x02, 0xc0, 0x01, 0x80, 0x00, 0x0d, 0x42, 0x81, 0x01,
+        0x53, 0x42, 0x81, 0x01, 0x90, 0x42, 0x81, 0x01, 0xc6, 0xc2, 0x40, 0x00,
+        0xc7, 0x42, 0xc2, 0x05, 0x0f, 0x83, 0xc1, 0x01, 0xe4, 0x82, 0x00, 0x01,
+        0x06, 0xc3, 0x40, 0x00, 0x07, 0x43, 0x42, 0x06, 0x4f, 0x83, 0xc2, 0x01,
+        0x24, 0x83, 0x00, 0x01, 0x5f, 0xc0, 0xc2, 0x01, 0x1e, 0x00, 0x00, 0x80,
+        0x43, 0x43, 0x00, 0x00, 0x43, 0x03, 0x80, 0x00, 0xe4, 0x41, 0x80, 0x03,
+        0xc0, 0x01, 0x80, 0x00, 0x60, 0x00, 0xc3, 0x01, 0x1e, 0x00, 0x00, 0x80,
+        0x03, 0x42, 0x00, 0x00, 0x03, 0x02, 0x80, 0x00, 0x61, 0xc0, 0x80, 0x85,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x42, 0x00, 0x00, 0x43, 0x02, 0x80, 0x00,
+        0x60, 0x40, 0x81, 0x83, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00,
+        0x83, 0x02, 0x80, 0x00, 0x61, 0x80, 0xc1, 0x02, 0x1e, 0x00, 0x00, 0x80,
+        0xc3, 0x42, 0x00, 0x00, 0xc3, 0x02, 0x80, 0x00, 0xe4, 0x41, 0x80, 0x02,
+        0xd2, 0x01, 0x01, 0x02, 0x00, 0x02, 0x80, 0x00, 0x5f, 0xc0, 0x81, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0x43, 0x42, 0x00, 0x00, 0x43, 0x02, 0x80, 0x00,
+        0x1f, 0xc0, 0x81, 0x03, 0x1e, 0x00, 0x00, 0x80, 0x83, 0x42, 0x00, 0x00,
+        0x83, 0x02, 0x80, 0x00, 0x60, 0xc0, 0xc1, 0x03, 0x1e, 0x00, 0x00, 0x80,
+        0xc3, 0x42, 0x00, 0x00, 0xc3, 0x02, 0x80, 0x00, 0x61, 0xc0, 0x81, 0x03,
+        0x1e, 0x00, 0x00, 0x80, 0x03, 0x43, 0x00, 0x00, 0x03, 0x03, 0x80, 0x00,
+        0x20, 0xc0, 0xc1, 0x03, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x43, 0x00, 0x00,
+        0x43, 0x03, 0x80, 0x00, 0x60, 0xc0, 0xc1, 0x03, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x43, 0x00, 0x00, 0x83, 0x03, 0x80, 0x00, 0x24, 0x42, 0x80, 0x03,
+        0x06, 0x42, 0x43, 0x00, 0x4b, 0x02, 0x00, 0x00, 0x8b, 0x82, 0x00, 0x00,
+        0xec, 0x02, 0x01, 0x00, 0x8a, 0xc2, 0x02, 0x87, 0xec, 0x42, 0x01, 0x00,
+        0x8a, 0xc2, 0x82, 0x87, 0x24, 0x82, 0x80, 0x01, 0x40, 0x02, 0x80, 0x00,
+        0x8d, 0xc2, 0x00, 0x04, 0xcd, 0x02, 0x82, 0x01, 0x60, 0x00, 0x82, 0x01,
+        0x1e, 0x00, 0x00, 0x80, 0x03, 0x43, 0x00, 0x00, 0x03, 0x03, 0x80, 0x00,
+        0x60, 0xc0, 0x00, 0x04, 0x1e, 0x00, 0x00, 0x80, 0x43, 0x43, 0x00, 0x00,
+        0x43, 0x03, 0x80, 0x00, 0x61, 0x00, 0x82, 0x01, 0x1e, 0x00, 0x00, 0x80,
+        0x83, 0x43, 0x00, 0x00, 0x83, 0x03, 0x80, 0x00, 0x5f, 0xc0, 0x00, 0x04,
+        0x1e, 0x00, 0x00, 0x80, 0xc3, 0x43, 0x00, 0x00, 0xc3, 0x03, 0x80, 0x00,
+        0x64, 0x42, 0x80, 0x03, 0x46, 0x02, 0x44, 0x00, 0x47, 0x42, 0xc4, 0x04,
+        0x80, 0x02, 0x00, 0x00, 0xc1, 0x82, 0x04, 0x00, 0x65, 0x02, 0x80, 0x01,
+        0x66, 0x02, 0x00, 0x00, 0x26, 0x00, 0x80, 0x00, 0x13, 0x00, 0x00, 0x00,
+        0x13, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x40, 0x04, 0x05, 0x6d, 0x61, 0x74, 0x68, 0x04, 0x0b, 0x6d,
+        0x61, 0x78, 0x69, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x13, 0xfe, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x13, 0x02, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x04, 0x0b, 0x6d, 0x69, 0x6e, 0x69, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72,
+        0x04, 0x05, 0x74, 0x79, 0x70, 0x65, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xf0, 0x3f, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1c, 0x40,
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1e, 0x40, 0x04, 0x0d, 0x73,
+        0x65, 0x74, 0x6d, 0x65, 0x74, 0x61, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x04,
+        0x06, 0x5f, 0x5f, 0x61, 0x64, 0x64, 0x04, 0x05, 0x5f, 0x5f, 0x6c, 0x74,
+        0x04, 0x06, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x04, 0x07, 0x63, 0x6f, 0x6e,
+        0x63, 0x61, 0x74, 0x04, 0x02, 0x20, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x09, 0x13, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x46, 0x40, 0x40, 0x00, 0x81, 0x80, 0x00, 0x00, 0xed, 0x00, 0x00, 0x00,
+        0x64, 0x80, 0x00, 0x00, 0x81, 0x00, 0x00, 0x00, 0x28, 0x40, 0x02, 0x80,
+        0x05, 0x01, 0x80, 0x00, 0x1c, 0x01, 0x00, 0x02, 0x0d, 0x01, 0x40, 0x02,
+        0x46, 0xc1, 0x40, 0x00, 0x86, 0x41, 0x40, 0x00, 0xc0, 0x01, 0x80, 0x01,
+        0x2d, 0x02, 0x00, 0x00, 0xa4, 0x81, 0x00, 0x00, 0x64, 0x81, 0x00, 0x01,
+        0x48, 0x40, 0x01, 0x02, 0x27, 0x00, 0xfd, 0x7f, 0x26, 0x00, 0x80, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x13, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x04, 0x07, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x04, 0x02, 0x23,
+        0x04, 0x09, 0x74, 0x6f, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x0c, 0x28,
+        0x66, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x29, 0x06, 0x00,
+        0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x0c, 0x28, 0x66, 0x6f, 0x72, 0x20,
+        0x6c, 0x69, 0x6d, 0x69, 0x74, 0x29, 0x06, 0x00, 0x00, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x0b, 0x28, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x74, 0x65, 0x70,
+        0x29, 0x06, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x02, 0x6b, 0x07,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05,
+        0x5f, 0x45, 0x4e, 0x56, 0x04, 0x6f, 0x75, 0x74, 0x00, 0x05, 0x00, 0x00,
+        0x00, 0x08, 0x00, 0x00, 0x00, 0x01, 0x00, 0x03, 0x05, 0x00, 0x00, 0x00,
+        0x46, 0x00, 0x40, 0x00, 0x80, 0x00, 0x00, 0x00, 0x64, 0xc0, 0x00, 0x01,
+        0xa6, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x04, 0x06, 0x70, 0x63, 0x61, 0x6c, 0x6c, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00,
+        0x00, 0x08, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x02, 0x66, 0x00,
+        0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x03, 0x6f, 0x6b, 0x03, 0x00,
+        0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x02, 0x65, 0x03, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e,
+        0x56, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x45, 0x00, 0x80,
+        0x00, 0x13, 0x40, 0x00, 0x00, 0x26, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x03, 0x01,
+        0x04, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00,
+        0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00,
+        0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x02, 0x69, 0x02, 0x7a, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x05, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+        0x00, 0x45, 0x00, 0x80, 0x00, 0x10, 0x40, 0x00, 0x00, 0x26, 0x00, 0x00,
+        0x01, 0x26, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x01, 0x03, 0x01, 0x04, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00,
+        0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00,
+        0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x69, 0x02, 0x7a, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x02, 0x00, 0x03, 0x03, 0x00, 0x00,
+        0x00, 0x81, 0x00, 0x00, 0x00, 0xa6, 0x00, 0x00, 0x01, 0x26, 0x00, 0x80,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x04, 0x61, 0x64, 0x64, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x13, 0x00,
+        0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x02, 0x61, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x02, 0x62, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x03, 0x06, 0x00, 0x00, 0x00, 0x5f, 0x00, 0x40, 0x00, 0x1e, 0x00,
+        0x00, 0x80, 0x83, 0x40, 0x00, 0x00, 0x83, 0x00, 0x80, 0x00, 0xa6, 0x00,
+        0x00, 0x01, 0x26, 0x00, 0x80, 0x00, 0x01, 0x00, 0x00, 0x00, 0x13, 0x07,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02,
+        0x61, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x02, 0x62, 0x00,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x8d,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x08,
+        0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a,
+        0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x0b,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0b,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0c,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d,
+        0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0e,
+        0x00, 0x00, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x11,
+        0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x12,
+        0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00, 0x13, 0x00, 0x00, 0x00, 0x13,
+        0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x12,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16,
+        0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x16, 0x00, 0x00, 0x00, 0x17,
+        0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17,
+        0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00, 0x17,
+        0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x04, 0x6f, 0x75, 0x74, 0x01,
+        0x00, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x00, 0x04, 0x70, 0x75, 0x74, 0x02,
+        0x00, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x00, 0x04, 0x65, 0x72, 0x72, 0x03,
+        0x00, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x00, 0x02, 0x69, 0x08, 0x00, 0x00,
+        0x00, 0x8d, 0x00, 0x00, 0x00, 0x02, 0x7a, 0x08, 0x00, 0x00, 0x00, 0x8d,
+        0x00, 0x00, 0x00, 0x02, 0x66, 0x08, 0x00, 0x00, 0x00, 0x8d, 0x00, 0x00,
+        0x00, 0x04, 0x62, 0x69, 0x67, 0x08, 0x00, 0x00, 0x00, 0x8d, 0x00, 0x00,
+        0x00, 0x04, 0x6e, 0x61, 0x6e, 0x50, 0x00, 0x00, 0x00, 0x8d, 0x00, 0x00,
+        0x00, 0x02, 0x74, 0x72, 0x00, 0x00, 0x00, 0x8d, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x05, 0x5f, 0x45, 0x4e, 0x56,
+    ];
+
     fn execute(proto: Rc<chunk::Prototype>) -> Rc<RefCell<LuaState>> {
         let regs_size = proto.max_stack_size;
         let ls = state::new_lua_state((regs_size + 8) as usize, proto);
@@ -396,7 +1138,20 @@ mod tests {
         ls 
     }
 
-    fn _print(ls: &dyn crate::api::LuaAPI) -> usize {
+    fn _setmetatable(ls: &mut dyn crate::api::LuaAPI) -> usize {
+        ls.set_top(2);
+        ls.set_metatable(1);
+        1
+    }
+
+    fn _add(ls: &mut dyn crate::api::LuaAPI) -> usize {
+        let a = ls.check_integer(1);
+        let b = ls.check_integer(2);
+        ls.push_integer(a + b);
+        1
+    }
+
+    fn _print(ls: &mut dyn crate::api::LuaAPI) -> usize {
         let nargs = ls.get_top();
         for i in 1..=nargs {
             if ls.is_boolean(i) {